@@ -412,22 +412,30 @@ impl PackageManifest {
         // While creating a `ManifestFile` we need to check if the given path corresponds to a
         // package or a workspace. While doing so, we should be printing the warnings if the given
         // file parses so that we only see warnings for the correct type of manifest.
-        let mut warnings = vec![];
         let manifest_str = std::fs::read_to_string(path)
             .map_err(|e| anyhow!("failed to read manifest at {:?}: {}", path, e))?;
-        let toml_de = &mut toml::de::Deserializer::new(&manifest_str);
+        let (manifest, unused_keys) = Self::from_string_with_unused_keys(&manifest_str)?;
+        for key in unused_keys {
+            println_yellow_err(&format!("  WARNING! unused manifest key: {key}"));
+        }
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Parse a `PackageManifest` from the contents of a `Forc.toml`, along with the paths of the
+    /// keys that are not part of the manifest format, e.g. `project.nmae`.
+    ///
+    /// Unlike `from_file`, this does not `validate` the manifest.
+    pub fn from_string_with_unused_keys(manifest_str: &str) -> Result<(Self, Vec<String>)> {
+        let mut unused_keys = vec![];
+        let toml_de = &mut toml::de::Deserializer::new(manifest_str);
         let mut manifest: Self = serde_ignored::deserialize(toml_de, |path| {
-            let warning = format!("  WARNING! unused manifest key: {path}");
-            warnings.push(warning);
+            unused_keys.push(path.to_string());
         })
         .map_err(|e| anyhow!("failed to parse manifest: {}.", e))?;
-        for warning in warnings {
-            println_yellow_err(&warning);
-        }
         manifest.implicitly_include_std_if_missing();
         manifest.implicitly_include_default_build_profiles_if_missing();
-        manifest.validate()?;
-        Ok(manifest)
+        Ok((manifest, unused_keys))
     }
 
     /// Validate the `PackageManifest`.
@@ -812,19 +820,25 @@ impl WorkspaceManifest {
         // While creating a `ManifestFile` we need to check if the given path corresponds to a
         // package or a workspace. While doing so, we should be printing the warnings if the given
         // file parses so that we only see warnings for the correct type of manifest.
-        let mut warnings = vec![];
         let manifest_str = std::fs::read_to_string(path)
             .map_err(|e| anyhow!("failed to read manifest at {:?}: {}", path, e))?;
-        let toml_de = &mut toml::de::Deserializer::new(&manifest_str);
+        let (manifest, unused_keys) = Self::from_string_with_unused_keys(&manifest_str)?;
+        for key in unused_keys {
+            println_yellow_err(&format!("  WARNING! unused manifest key: {key}"));
+        }
+        Ok(manifest)
+    }
+
+    /// Parse a `WorkspaceManifest` from the contents of a `Forc.toml`, along with the paths of the
+    /// keys that are not part of the manifest format.
+    pub fn from_string_with_unused_keys(manifest_str: &str) -> Result<(Self, Vec<String>)> {
+        let mut unused_keys = vec![];
+        let toml_de = &mut toml::de::Deserializer::new(manifest_str);
         let manifest: Self = serde_ignored::deserialize(toml_de, |path| {
-            let warning = format!("  WARNING! unused manifest key: {path}");
-            warnings.push(warning);
+            unused_keys.push(path.to_string());
         })
         .map_err(|e| anyhow!("failed to parse manifest: {}.", e))?;
-        for warning in warnings {
-            println_yellow_err(&warning);
-        }
-        Ok(manifest)
+        Ok((manifest, unused_keys))
     }
 
     /// Validate the `WorkspaceManifest`
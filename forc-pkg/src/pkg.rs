@@ -26,6 +26,7 @@ use std::{
     hash::{Hash, Hasher},
     path::{Path, PathBuf},
     str::FromStr,
+    sync::Arc,
 };
 use sway_core::{
     abi_generation::{
@@ -46,7 +47,7 @@ use sway_core::{
     semantic_analysis::namespace,
     source_map::SourceMap,
     transform::AttributeKind,
-    BuildTarget, CompileResult, Engines, FinalizedEntry, TypeEngine,
+    BuildTarget, CompileResult, Engines, FinalizedEntry, SourceOverlay, TypeEngine,
 };
 use sway_error::{error::CompileError, warning::CompileWarning};
 use sway_types::{Ident, Span, Spanned};
@@ -314,12 +315,30 @@ pub struct MemberFilter {
 }
 
 /// Contains the lexed, parsed, and typed compilation stages of a program.
+#[derive(Clone)]
 pub struct Programs {
     pub lexed: LexedProgram,
     pub parsed: ParseProgram,
     pub typed: Option<ty::TyProgram>,
 }
 
+/// The results of checking the packages of a build plan, so that later checks only need to check
+/// the packages that have changed since, along with the packages depending on them.
+///
+/// The typed programs refer to the types and declarations of the engines they were checked with,
+/// so a cache must only be used with those same engines.
+#[derive(Default)]
+pub struct CheckCache {
+    packages: HashMap<PinnedId, CheckedPackage>,
+}
+
+/// The result of checking a single package.
+struct CheckedPackage {
+    manifest_dir: PathBuf,
+    result: Arc<CompileResult<Programs>>,
+    lib_namespace: Option<namespace::Module>,
+}
+
 impl Default for MemberFilter {
     fn default() -> Self {
         Self {
@@ -745,6 +764,40 @@ impl BuildPlan {
     }
 }
 
+impl fmt::Debug for CheckCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CheckCache")
+            .field("packages", &self.packages.keys())
+            .finish()
+    }
+}
+
+impl CheckCache {
+    /// Discard the result of the package in the given manifest directory, so that it is checked
+    /// again by the next check.
+    pub fn invalidate(&mut self, manifest_dir: &Path) {
+        self.packages
+            .retain(|_, package| package.manifest_dir != manifest_dir);
+    }
+
+    /// Discard the results of all packages.
+    pub fn clear(&mut self) {
+        self.packages.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Returns the cached result of the package in the given manifest directory.
+    pub fn result(&self, manifest_dir: &Path) -> Option<&Arc<CompileResult<Programs>>> {
+        self.packages
+            .values()
+            .find(|package| package.manifest_dir == manifest_dir)
+            .map(|package| &package.result)
+    }
+}
+
 impl Programs {
     pub fn new(
         lexed: LexedProgram,
@@ -1761,10 +1814,7 @@ pub fn compile(
             ProgramABI::Fuel(time_expr!(
                 "generate JSON ABI program",
                 fuel_json_abi::generate_json_abi_program(
-                    &mut JsonAbiContext {
-                        program: typed_program,
-                        json_abi_with_callpaths: profile.json_abi_with_callpaths,
-                    },
+                    &mut JsonAbiContext::new(typed_program, profile.json_abi_with_callpaths),
                     engines.te(),
                     engines.de(),
                     &mut types
@@ -2471,15 +2521,93 @@ pub fn check(
     include_tests: bool,
     engines: Engines<'_>,
 ) -> anyhow::Result<Vec<CompileResult<Programs>>> {
-    let mut lib_namespace_map = Default::default();
+    check_with_source_overlay(
+        plan,
+        build_target,
+        terse_mode,
+        include_tests,
+        Default::default(),
+        engines,
+    )
+}
+
+/// Like [check], but modules with a source in the `source_overlay` are compiled from that
+/// source rather than from the file on disk, in all packages of the plan.
+pub fn check_with_source_overlay(
+    plan: &BuildPlan,
+    build_target: BuildTarget,
+    terse_mode: bool,
+    include_tests: bool,
+    source_overlay: Arc<SourceOverlay>,
+    engines: Engines<'_>,
+) -> anyhow::Result<Vec<CompileResult<Programs>>> {
+    let mut cache = CheckCache::default();
+    let results = check_with_cache(
+        plan,
+        build_target,
+        terse_mode,
+        include_tests,
+        source_overlay,
+        &mut cache,
+        &|| false,
+        engines,
+    )?;
+    // Without the cache, the results aren't shared anymore.
+    drop(cache);
+    Ok(results
+        .into_iter()
+        .map(|result| Arc::try_unwrap(result).unwrap_or_else(|result| (*result).clone()))
+        .collect())
+}
+
+/// Like [check_with_source_overlay], but reuses the results of the packages in the `cache` that
+/// haven't been invalidated, as long as none of their dependencies had to be checked again.
+///
+/// The results of the packages that are checked successfully are stored in the `cache`, and
+/// shared with the returned results.
+///
+/// `cancelled` is called before checking each package, and the check fails once it returns
+/// `true`. The results of the packages checked until then are kept in the `cache`.
+#[allow(clippy::too_many_arguments)]
+pub fn check_with_cache(
+    plan: &BuildPlan,
+    build_target: BuildTarget,
+    terse_mode: bool,
+    include_tests: bool,
+    source_overlay: Arc<SourceOverlay>,
+    cache: &mut CheckCache,
+    cancelled: &dyn Fn() -> bool,
+    engines: Engines<'_>,
+) -> anyhow::Result<Vec<Arc<CompileResult<Programs>>>> {
+    let mut lib_namespace_map: HashMap<NodeIx, namespace::Module> = Default::default();
     let mut source_map = SourceMap::new();
     // During `check`, we don't compile so this stays empty.
     let compiled_contract_deps = HashMap::new();
+    // The packages that have been checked again rather than taken from the cache.
+    let mut rechecked: HashSet<NodeIx> = HashSet::new();
 
     let mut results = vec![];
     for &node in plan.compilation_order.iter() {
+        if cancelled() {
+            bail!("unable to check sway program: the check was cancelled")
+        }
         let pkg = &plan.graph[node];
         let manifest = &plan.manifest_map()[&pkg.id()];
+
+        let deps_unchanged = plan
+            .graph
+            .edges_directed(node, Direction::Outgoing)
+            .all(|edge| !rechecked.contains(&edge.target()));
+        if let Some(checked) = cache.packages.get(&pkg.id()).filter(|_| deps_unchanged) {
+            if let Some(namespace) = &checked.lib_namespace {
+                lib_namespace_map.insert(node, namespace.clone());
+            }
+            source_map.insert_dependency(manifest.dir());
+            results.push(checked.result.clone());
+            continue;
+        }
+        rechecked.insert(node);
+
         let constants = manifest.config_time_constants();
         let dep_namespace = dependency_namespace(
             &lib_namespace_map,
@@ -2495,11 +2623,18 @@ pub fn check(
             value,
             mut warnings,
             mut errors,
-        } = parse(manifest, build_target, terse_mode, include_tests, engines)?;
+        } = parse_with_source_overlay(
+            manifest,
+            build_target,
+            terse_mode,
+            include_tests,
+            source_overlay.clone(),
+            engines,
+        )?;
 
         let (lexed, parsed) = match value {
             None => {
-                results.push(CompileResult::new(None, warnings, errors));
+                results.push(Arc::new(CompileResult::new(None, warnings, errors)));
                 return Ok(results);
             }
             Some(modules) => modules,
@@ -2512,31 +2647,36 @@ pub fn check(
         let typed_program = match ast_result.value {
             None => {
                 let value = Some(Programs::new(lexed, parsed, None));
-                results.push(CompileResult::new(value, warnings, errors));
+                results.push(Arc::new(CompileResult::new(value, warnings, errors)));
                 return Ok(results);
             }
             Some(typed_program) => typed_program,
         };
 
-        if let TreeType::Library = typed_program.kind.tree_type() {
-            let mut namespace = typed_program.root.namespace.clone();
-            namespace.name = Some(Ident::new_no_span(pkg.name.clone()));
-            namespace.span = Some(
-                Span::new(
-                    manifest.entry_string()?,
-                    0,
-                    0,
-                    Some(manifest.entry_path().into()),
-                )
-                .unwrap(),
-            );
-            lib_namespace_map.insert(node, namespace);
-        }
+        let lib_namespace = match typed_program.kind.tree_type() {
+            TreeType::Library => {
+                let mut namespace = typed_program.root.namespace.clone();
+                namespace.name = Some(Ident::new_no_span(pkg.name.clone()));
+                namespace.span = Some(parsed.root.span.clone());
+                lib_namespace_map.insert(node, namespace.clone());
+                Some(namespace)
+            }
+            _ => None,
+        };
 
         source_map.insert_dependency(manifest.dir());
 
         let value = Some(Programs::new(lexed, parsed, Some(typed_program)));
-        results.push(CompileResult::new(value, warnings, errors));
+        let result = Arc::new(CompileResult::new(value, warnings, errors));
+        cache.packages.insert(
+            pkg.id(),
+            CheckedPackage {
+                manifest_dir: manifest.dir().to_path_buf(),
+                result: result.clone(),
+                lib_namespace,
+            },
+        );
+        results.push(result);
     }
 
     if results.is_empty() {
@@ -2553,19 +2693,40 @@ pub fn parse(
     terse_mode: bool,
     include_tests: bool,
     engines: Engines<'_>,
+) -> anyhow::Result<CompileResult<(LexedProgram, ParseProgram)>> {
+    parse_with_source_overlay(
+        manifest,
+        build_target,
+        terse_mode,
+        include_tests,
+        Default::default(),
+        engines,
+    )
+}
+
+/// Like [parse], but modules with a source in the `source_overlay` are parsed from that source
+/// rather than from the file on disk.
+pub fn parse_with_source_overlay(
+    manifest: &PackageManifestFile,
+    build_target: BuildTarget,
+    terse_mode: bool,
+    include_tests: bool,
+    source_overlay: Arc<SourceOverlay>,
+    engines: Engines<'_>,
 ) -> anyhow::Result<CompileResult<(LexedProgram, ParseProgram)>> {
     let profile = BuildProfile {
         terse: terse_mode,
         ..BuildProfile::debug()
     };
-    let source = manifest.entry_string()?;
     let sway_build_config = sway_build_config(
         manifest.dir(),
         &manifest.entry_path(),
         build_target,
         &profile,
     )?
-    .include_tests(include_tests);
+    .include_tests(include_tests)
+    .source_overlay(source_overlay);
+    let source = sway_build_config.read_source(&manifest.entry_path())?;
     Ok(sway_core::parse(source, engines, Some(&sway_build_config)))
 }
 
@@ -128,6 +128,8 @@ impl Descriptor {
                                     .into_iter()
                                     .flat_map(|item| match item {
                                         TyTraitInterfaceItem::TraitFn(fn_decl) => Some(fn_decl),
+                                        _ => None,
+                                        _ => None,
                                     })
                                     .collect::<Vec<_>>()
                                     .to_methods(decl_engine),
@@ -170,6 +172,7 @@ impl Descriptor {
                             .into_iter()
                             .flat_map(|item| match item {
                                 TyTraitInterfaceItem::TraitFn(fn_decl) => Some(fn_decl),
+                                _ => None,
                             })
                             .collect::<Vec<_>>()
                             .to_methods(decl_engine),
@@ -318,6 +321,36 @@ impl Descriptor {
                     }))
                 }
             }
+            TypeAliasDeclaration { decl_id, .. } => {
+                let type_alias_decl = decl_engine.get_type_alias(decl_id);
+                if !document_private_items && type_alias_decl.visibility.is_private() {
+                    Ok(Descriptor::NonDocumentable)
+                } else {
+                    let item_name = type_alias_decl.call_path.suffix;
+                    let attrs_opt = (!type_alias_decl.attributes.is_empty())
+                        .then(|| type_alias_decl.attributes.to_html_string());
+
+                    Ok(Descriptor::Documentable(Document {
+                        module_info: module_info.clone(),
+                        item_header: ItemHeader {
+                            module_info: module_info.clone(),
+                            friendly_name: ty_decl.friendly_type_name(),
+                            item_name: item_name.clone(),
+                        },
+                        item_body: ItemBody {
+                            module_info,
+                            ty_decl: ty_decl.clone(),
+                            item_name,
+                            code_str: parse::parse_format::<sway_ast::ItemTypeAlias>(
+                                type_alias_decl.span.as_str(),
+                            ),
+                            attrs_opt: attrs_opt.clone(),
+                            item_context: ItemContext { context_opt: None },
+                        },
+                        raw_attributes: attrs_opt,
+                    }))
+                }
+            }
             _ => Ok(Descriptor::NonDocumentable),
         }
     }
@@ -116,6 +116,14 @@ impl RenderedDocumentation {
                                 }
                             }
                         }
+                        TypeAliasDeclaration { .. } => {
+                            match doc_links.get_mut(&BlockTitle::TypeAliases) {
+                                Some(links) => links.push(doc.link()),
+                                None => {
+                                    doc_links.insert(BlockTitle::TypeAliases, vec![doc.link()]);
+                                }
+                            }
+                        }
                         _ => {} // TODO: ImplTraitDeclaration
                     }
                 }
@@ -143,6 +151,9 @@ impl RenderedDocumentation {
                         ConstantDeclaration { .. } => {
                             doc_links.insert(BlockTitle::Constants, vec![doc.link()]);
                         }
+                        TypeAliasDeclaration { .. } => {
+                            doc_links.insert(BlockTitle::TypeAliases, vec![doc.link()]);
+                        }
                         _ => {} // TODO: ImplTraitDeclaration
                     }
                     module_map.insert(location.clone(), doc_links);
@@ -241,6 +252,16 @@ impl RenderedDocumentation {
                         }
                     }
                 }
+                TypeAliasDeclaration { .. } => {
+                    match all_docs.links.get_mut(&BlockTitle::TypeAliases) {
+                        Some(links) => links.push(doc.link()),
+                        None => {
+                            all_docs
+                                .links
+                                .insert(BlockTitle::TypeAliases, vec![doc.link()]);
+                        }
+                    }
+                }
                 _ => {} // TODO: ImplTraitDeclaration
             }
         }
@@ -858,10 +879,14 @@ fn render_type_anchor(
                 render_plan,
                 current_module_info,
             )?;
+            let len = match len.resolve(&render_plan.type_engine) {
+                Some(len) => len.to_string(),
+                None => len.span().as_str().to_string(),
+            };
             Ok(box_html! {
                 : "[";
                 : inner;
-                : format!("; {}]", len.val());
+                : format!("; {len}]");
             })
         }
         TypeInfo::Tuple(ty_args) => {
@@ -915,9 +940,11 @@ fn render_type_anchor(
                 })
             }
         }
-        TypeInfo::UnknownGeneric { name, .. } => Ok(box_html! {
-            : name.as_str();
-        }),
+        TypeInfo::UnknownGeneric { name, .. } | TypeInfo::ConstGeneric { name, .. } => {
+            Ok(box_html! {
+                : name.as_str();
+            })
+        }
         TypeInfo::Str(len) => Ok(box_html! {
             : len.span().as_str();
         }),
@@ -928,6 +955,7 @@ fn render_type_anchor(
                 IntegerBits::Sixteen => "u16",
                 IntegerBits::ThirtyTwo => "u32",
                 IntegerBits::SixtyFour => "u64",
+                IntegerBits::V256 => "u256",
             };
             Ok(box_html! {
                 : uint;
@@ -955,6 +983,9 @@ fn render_type_anchor(
         TypeInfo::B256 => Ok(box_html! {
             : "b256";
         }),
+        TypeInfo::StringSlice => Ok(box_html! {
+            : "str";
+        }),
         _ => Err(anyhow!("Undetermined or unusable TypeInfo")),
     }
 }
@@ -1093,6 +1124,7 @@ enum BlockTitle {
     Abi,
     ContractStorage,
     Constants,
+    TypeAliases,
     Functions,
     Fields,
     Variants,
@@ -1108,6 +1140,7 @@ impl BlockTitle {
             Self::Abi => "Abi",
             Self::ContractStorage => "Contract Storage",
             Self::Constants => "Constants",
+            Self::TypeAliases => "Type Aliases",
             Self::Functions => "Functions",
             Self::Fields => "Fields",
             Self::Variants => "Variants",
@@ -1123,6 +1156,7 @@ impl BlockTitle {
             Self::Abi => "Abi",
             Self::ContractStorage => "Contract Storage",
             Self::Constants => "Constant",
+            Self::TypeAliases => "Type Alias",
             Self::Functions => "Function",
             Self::Fields => "Fields",
             Self::Variants => "Variants",
@@ -1138,6 +1172,7 @@ impl BlockTitle {
             Self::Abi => "abi",
             Self::ContractStorage => "storage",
             Self::Constants => "constant",
+            Self::TypeAliases => "type",
             Self::Functions => "fn",
             _ => unimplemented!("These titles are unimplemented, and should not be used this way."),
         }
@@ -1164,6 +1199,7 @@ impl DocBlockTitle for TyDeclaration {
             TyDeclaration::AbiDeclaration { .. } => BlockTitle::Abi,
             TyDeclaration::StorageDeclaration { .. } => BlockTitle::ContractStorage,
             TyDeclaration::ConstantDeclaration { .. } => BlockTitle::Constants,
+            TyDeclaration::TypeAliasDeclaration { .. } => BlockTitle::TypeAliases,
             TyDeclaration::FunctionDeclaration { .. } => BlockTitle::Functions,
             _ => {
                 unreachable!("All other TyDecls are non-documentable and will never be matched on")
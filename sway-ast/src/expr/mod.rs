@@ -36,13 +36,24 @@ pub enum Expr {
     },
     While {
         while_token: WhileToken,
-        condition: Box<Expr>,
+        condition: IfCondition,
+        block: Braces<CodeBlockContents>,
+    },
+    For {
+        for_token: ForToken,
+        value_pattern: Pattern,
+        in_token: InToken,
+        iterator: Box<Expr>,
         block: Braces<CodeBlockContents>,
     },
     FuncApp {
         func: Box<Expr>,
         args: Parens<Punctuated<Expr, CommaToken>>,
     },
+    Closure {
+        params: ClosureParams,
+        body: Box<Expr>,
+    },
     Index {
         target: Box<Expr>,
         arg: SquareBrackets<Box<Expr>>,
@@ -218,7 +229,11 @@ impl Spanned for Expr {
             Expr::While {
                 while_token, block, ..
             } => Span::join(while_token.span(), block.span()),
+            Expr::For {
+                for_token, block, ..
+            } => Span::join(for_token.span(), block.span()),
             Expr::FuncApp { func, args } => Span::join(func.span(), args.span()),
+            Expr::Closure { params, body } => Span::join(params.span(), body.span()),
             Expr::Index { target, arg } => Span::join(target.span(), arg.span()),
             Expr::MethodCall { target, args, .. } => Span::join(target.span(), args.span()),
             Expr::FieldProjection { target, name, .. } => Span::join(target.span(), name.span()),
@@ -306,6 +321,8 @@ pub struct IfExpr {
     )>,
 }
 
+/// The condition of an `if` or a `while` expression. Either a boolean expression, or a
+/// `let <pattern> = <expr>` which holds if the value of the expression matches the pattern.
 #[derive(Clone, Debug, Serialize)]
 pub enum IfCondition {
     Expr(Box<Expr>),
@@ -421,6 +438,54 @@ impl Spanned for ExprStructField {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub enum ClosureParams {
+    /// The `||` of a closure that takes no parameters.
+    Empty(DoublePipeToken),
+    Params {
+        open_pipe_token: PipeToken,
+        params: Punctuated<ClosureParam, CommaToken>,
+        close_pipe_token: PipeToken,
+    },
+}
+
+impl ClosureParams {
+    pub fn into_params(self) -> Vec<ClosureParam> {
+        match self {
+            ClosureParams::Empty(_) => vec![],
+            ClosureParams::Params { params, .. } => params.into_iter().collect(),
+        }
+    }
+}
+
+impl Spanned for ClosureParams {
+    fn span(&self) -> Span {
+        match self {
+            ClosureParams::Empty(double_pipe_token) => double_pipe_token.span(),
+            ClosureParams::Params {
+                open_pipe_token,
+                close_pipe_token,
+                ..
+            } => Span::join(open_pipe_token.span(), close_pipe_token.span()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ClosureParam {
+    pub name: Ident,
+    pub ty_opt: Option<(ColonToken, Ty)>,
+}
+
+impl Spanned for ClosureParam {
+    fn span(&self) -> Span {
+        match &self.ty_opt {
+            None => self.name.span(),
+            Some((_colon_token, ty)) => Span::join(self.name.span(), ty.span()),
+        }
+    }
+}
+
 impl Expr {
     pub fn try_into_assignable(self) -> Result<Assignable, Expr> {
         match self {
@@ -484,7 +549,8 @@ impl Expr {
                 | Expr::Asm(..)
                 | Expr::If(..)
                 | Expr::Match { .. }
-                | Expr::While { .. },
+                | Expr::While { .. }
+                | Expr::For { .. },
         )
     }
 }
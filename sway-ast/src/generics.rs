@@ -2,7 +2,40 @@ use crate::priv_prelude::*;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct GenericParams {
-    pub parameters: AngleBrackets<Punctuated<Ident, CommaToken>>,
+    pub parameters: AngleBrackets<Punctuated<GenericParam, CommaToken>>,
+}
+
+/// A single parameter in a list of [GenericParams], either a type parameter
+/// like `T`, or a const parameter like `const N: u64`.
+#[derive(Clone, Debug, Serialize)]
+pub enum GenericParam {
+    Type(Ident),
+    Const {
+        const_token: ConstToken,
+        name: Ident,
+        colon_token: ColonToken,
+        ty: Box<Ty>,
+    },
+}
+
+impl GenericParam {
+    pub fn name(&self) -> &Ident {
+        match self {
+            GenericParam::Type(name) => name,
+            GenericParam::Const { name, .. } => name,
+        }
+    }
+}
+
+impl Spanned for GenericParam {
+    fn span(&self) -> Span {
+        match self {
+            GenericParam::Type(name) => name.span(),
+            GenericParam::Const {
+                const_token, ty, ..
+            } => Span::join(const_token.span(), ty.span()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -4,6 +4,7 @@ use crate::{priv_prelude::*, ItemTraitItem};
 pub struct ItemAbi {
     pub abi_token: AbiToken,
     pub name: Ident,
+    pub generics: Option<GenericParams>,
     pub super_traits: Option<(ColonToken, Traits)>,
     pub abi_items: Braces<Vec<(Annotated<ItemTraitItem>, SemicolonToken)>>,
     pub abi_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>>,
@@ -1,8 +1,10 @@
-use crate::priv_prelude::*;
+use crate::{priv_prelude::*, TraitType};
 
 #[derive(Clone, Debug, Serialize)]
 pub enum ItemImplItem {
     Fn(ItemFn),
+    Const(ItemConst),
+    Type(TraitType),
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -25,6 +27,8 @@ impl Spanned for ItemImplItem {
     fn span(&self) -> Span {
         match self {
             ItemImplItem::Fn(fn_decl) => fn_decl.span(),
+            ItemImplItem::Const(const_decl) => const_decl.span(),
+            ItemImplItem::Type(trait_type) => trait_type.span(),
         }
     }
 }
@@ -3,6 +3,28 @@ use crate::priv_prelude::*;
 #[derive(Clone, Debug, Serialize)]
 pub enum ItemTraitItem {
     Fn(FnSignature),
+    Const(ItemConst),
+    Type(TraitType),
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TraitType {
+    pub type_token: TypeToken,
+    pub name: Ident,
+    pub eq_token_opt: Option<EqToken>,
+    pub ty_opt: Option<Ty>,
+    pub semicolon_token: SemicolonToken,
+}
+
+impl Spanned for TraitType {
+    fn span(&self) -> Span {
+        let start = self.type_token.span();
+        let end = match &self.ty_opt {
+            Some(ty) => ty.span(),
+            None => self.name.span(),
+        };
+        Span::join(start, end)
+    }
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -35,6 +57,8 @@ impl Spanned for ItemTraitItem {
     fn span(&self) -> Span {
         match self {
             ItemTraitItem::Fn(fn_decl) => fn_decl.span(),
+            ItemTraitItem::Const(const_decl) => const_decl.span(),
+            ItemTraitItem::Type(trait_type) => trait_type.span(),
         }
     }
 }
@@ -0,0 +1,23 @@
+use crate::priv_prelude::*;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ItemTypeAlias {
+    pub visibility: Option<PubToken>,
+    pub type_token: TypeToken,
+    pub name: Ident,
+    pub generics: Option<GenericParams>,
+    pub eq_token: EqToken,
+    pub ty: Ty,
+    pub semicolon_token: SemicolonToken,
+}
+
+impl Spanned for ItemTypeAlias {
+    fn span(&self) -> Span {
+        let start = match &self.visibility {
+            Some(pub_token) => pub_token.span(),
+            None => self.type_token.span(),
+        };
+        let end = self.semicolon_token.span();
+        Span::join(start, end)
+    }
+}
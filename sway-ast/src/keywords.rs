@@ -81,6 +81,9 @@ define_keyword!(FalseToken, "false");
 define_keyword!(BreakToken, "break");
 define_keyword!(ContinueToken, "continue");
 define_keyword!(ConfigurableToken, "configurable");
+define_keyword!(TypeToken, "type");
+define_keyword!(InToken, "in");
+define_keyword!(DynToken, "dyn");
 
 /// The type is a keyword.
 pub trait Token: Spanned + Sized {
@@ -25,10 +25,10 @@ pub use crate::{
     expr::{
         asm::{AsmBlock, AsmRegisterDeclaration},
         op_code::Instruction,
-        AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-        ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
+        AbiCastArgs, ClosureParam, ClosureParams, CodeBlockContents, Expr, ExprArrayDescriptor,
+        ExprStructField, ExprTupleDescriptor, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
     },
-    generics::{GenericArgs, GenericParams},
+    generics::{GenericArgs, GenericParam, GenericParams},
     intrinsics::*,
     item::{
         item_abi::ItemAbi,
@@ -39,7 +39,8 @@ pub use crate::{
         item_impl::{ItemImpl, ItemImplItem},
         item_storage::{ItemStorage, StorageField},
         item_struct::ItemStruct,
-        item_trait::{ItemTrait, ItemTraitItem, Traits},
+        item_trait::{ItemTrait, ItemTraitItem, TraitType, Traits},
+        item_type_alias::ItemTypeAlias,
         item_use::{ItemUse, UseTree},
         FnArg, FnArgs, FnSignature, Item, ItemKind, TypeField,
     },
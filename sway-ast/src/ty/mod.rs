@@ -10,9 +10,22 @@ pub enum Ty {
         str_token: StrToken,
         length: SquareBrackets<Box<Expr>>,
     },
+    /// A string slice of dynamic length, `str`.
+    StringSlice(StrToken),
     Infer {
         underscore_token: UnderscoreToken,
     },
+    /// The type of a closure parameter, e.g. `fn(u64) -> bool`.
+    Fn {
+        fn_token: FnToken,
+        parameters: Parens<Punctuated<Ty, CommaToken>>,
+        return_type_opt: Option<(RightArrowToken, Box<Ty>)>,
+    },
+    /// A trait object type, e.g. `dyn Shape`.
+    TraitObject {
+        dyn_token: DynToken,
+        trait_path: PathType,
+    },
 }
 
 impl Spanned for Ty {
@@ -22,7 +35,22 @@ impl Spanned for Ty {
             Ty::Tuple(tuple_type) => tuple_type.span(),
             Ty::Array(array_type) => array_type.span(),
             Ty::Str { str_token, length } => Span::join(str_token.span(), length.span()),
+            Ty::StringSlice(str_token) => str_token.span(),
             Ty::Infer { underscore_token } => underscore_token.span(),
+            Ty::Fn {
+                fn_token,
+                parameters,
+                return_type_opt,
+            } => match return_type_opt {
+                Some((_right_arrow_token, return_type)) => {
+                    Span::join(fn_token.span(), return_type.span())
+                }
+                None => Span::join(fn_token.span(), parameters.span()),
+            },
+            Ty::TraitObject {
+                dyn_token,
+                trait_path,
+            } => Span::join(dyn_token.span(), trait_path.span()),
         }
     }
 }
@@ -62,8 +62,13 @@ fn get_json_type_str(
                 format!("({})", field_strs.join(", "))
             }
             (TypeInfo::Array(_, count), TypeInfo::Array(_, resolved_count)) => {
-                assert_eq!(count.val(), resolved_count.val());
-                format!("[_; {}]", count.val())
+                if count.type_id().is_none() {
+                    assert_eq!(count.val(), resolved_count.val());
+                }
+                format!(
+                    "[_; {}]",
+                    Engines::new(type_engine, decl_engine).help_out(resolved_count)
+                )
             }
             (TypeInfo::Custom { .. }, _) => {
                 format!(
@@ -93,6 +98,7 @@ pub fn json_abi_str(
             IntegerBits::Sixteen => "uint16",
             IntegerBits::ThirtyTwo => "uint32",
             IntegerBits::SixtyFour => "uint64",
+            IntegerBits::V256 => "uint256",
         }
         .into(),
         Boolean => "bool".into(),
@@ -117,19 +123,38 @@ pub fn json_abi_str(
             let decl = decl_engine.get_struct(decl_ref);
             format!("struct {}", decl.call_path.suffix)
         }
-        ContractCaller { abi_name, .. } => {
-            format!("contract caller {abi_name}")
+        ContractCaller {
+            abi_name,
+            type_arguments,
+            ..
+        } => {
+            if type_arguments.is_empty() {
+                format!("contract caller {abi_name}")
+            } else {
+                let type_arg_strs = type_arguments
+                    .iter()
+                    .map(|type_arg| json_abi_str_type_arg(type_arg, type_engine, decl_engine))
+                    .collect::<Vec<String>>();
+                format!("contract caller {abi_name}<{}>", type_arg_strs.join(", "))
+            }
         }
         Array(elem_ty, length) => {
             format!(
                 "{}[{}]",
                 json_abi_str_type_arg(elem_ty, type_engine, decl_engine),
-                length.val()
+                Engines::new(type_engine, decl_engine).help_out(length)
             )
         }
         Storage { .. } => "contract storage".into(),
         RawUntypedPtr => "raw untyped ptr".into(),
         RawUntypedSlice => "raw untyped slice".into(),
+        StringSlice => "str".into(),
+        Closure { .. } => "closure".into(),
+        ConstGeneric { name, value } => match value {
+            Some(value) => value.to_string(),
+            None => name.to_string(),
+        },
+        TraitObject { trait_name, .. } => format!("dyn {}", trait_name.suffix),
     }
 }
 
@@ -141,11 +166,13 @@ pub fn json_abi_param_type(
     use TypeInfo::*;
     match type_info {
         Str(x) => ethabi::ParamType::FixedArray(Box::new(ethabi::ParamType::String), x.val()),
+        StringSlice => ethabi::ParamType::String,
         UnsignedInteger(x) => match x {
             IntegerBits::Eight => ethabi::ParamType::Uint(8),
             IntegerBits::Sixteen => ethabi::ParamType::Uint(16),
             IntegerBits::ThirtyTwo => ethabi::ParamType::Uint(32),
             IntegerBits::SixtyFour => ethabi::ParamType::Uint(64),
+            IntegerBits::V256 => ethabi::ParamType::Uint(256),
         },
         Boolean => ethabi::ParamType::Bool,
         B256 => ethabi::ParamType::Uint(256),
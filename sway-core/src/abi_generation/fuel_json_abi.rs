@@ -1,5 +1,5 @@
 use fuel_abi_types::program_abi;
-use sway_types::integer_bits::IntegerBits;
+use sway_types::{integer_bits::IntegerBits, Span};
 
 use crate::{
     decl_engine::DeclEngine,
@@ -8,12 +8,25 @@ use crate::{
         CallPath,
     },
     transform::AttributesMap,
-    TypeArgument, TypeEngine, TypeId, TypeInfo, TypeParameter,
+    Engines, TypeArgument, TypeEngine, TypeId, TypeInfo, TypeParameter,
 };
 
 pub struct JsonAbiContext<'a> {
     pub program: &'a TyProgram,
     pub json_abi_with_callpaths: bool,
+    /// The spans of the structs and enums whose components are being
+    /// generated, used to stop at recursive types.
+    in_progress: Vec<Span>,
+}
+
+impl<'a> JsonAbiContext<'a> {
+    pub fn new(program: &'a TyProgram, json_abi_with_callpaths: bool) -> Self {
+        JsonAbiContext {
+            program,
+            json_abi_with_callpaths,
+            in_progress: vec![],
+        }
+    }
 }
 
 pub fn generate_json_abi_program(
@@ -282,8 +295,13 @@ impl TypeId {
                     format!("({})", field_strs.join(", "))
                 }
                 (TypeInfo::Array(_, count), TypeInfo::Array(_, resolved_count)) => {
-                    assert_eq!(count.val(), resolved_count.val());
-                    format!("[_; {}]", count.val())
+                    if count.type_id().is_none() {
+                        assert_eq!(count.val(), resolved_count.val());
+                    }
+                    format!(
+                        "[_; {}]",
+                        Engines::new(type_engine, decl_engine).help_out(resolved_count)
+                    )
                 }
                 (TypeInfo::Custom { .. }, _) => {
                     format!(
@@ -335,6 +353,56 @@ impl TypeId {
         decl_engine: &DeclEngine,
         types: &mut Vec<program_abi::TypeDeclaration>,
         resolved_type_id: TypeId,
+    ) -> Option<Vec<program_abi::TypeApplication>> {
+        // Structs and enums may be recursive. Their components are generated by the outermost
+        // occurrence only, and then filled in for the nested ones.
+        let span = match type_engine.get(*self) {
+            TypeInfo::Enum(decl_ref) => Some(decl_engine.get_enum(&decl_ref).span),
+            TypeInfo::Struct(decl_ref) => Some(decl_engine.get_struct(&decl_ref).span),
+            _ => None,
+        };
+        let span = match span {
+            Some(span) => span,
+            None => {
+                return self.get_json_type_components_inner(
+                    ctx,
+                    type_engine,
+                    decl_engine,
+                    types,
+                    resolved_type_id,
+                )
+            }
+        };
+        if ctx.in_progress.contains(&span) {
+            return None;
+        }
+
+        ctx.in_progress.push(span);
+        let components = self.get_json_type_components_inner(
+            ctx,
+            type_engine,
+            decl_engine,
+            types,
+            resolved_type_id,
+        );
+        ctx.in_progress.pop();
+
+        let type_field = self.get_json_type_str(ctx, type_engine, decl_engine, resolved_type_id);
+        for ty in types.iter_mut() {
+            if ty.components.is_none() && ty.type_field == type_field {
+                ty.components = components.clone();
+            }
+        }
+        components
+    }
+
+    fn get_json_type_components_inner(
+        &self,
+        ctx: &mut JsonAbiContext,
+        type_engine: &TypeEngine,
+        decl_engine: &DeclEngine,
+        types: &mut Vec<program_abi::TypeDeclaration>,
+        resolved_type_id: TypeId,
     ) -> Option<Vec<program_abi::TypeApplication>> {
         match type_engine.get(*self) {
             TypeInfo::Enum(decl_ref) => {
@@ -783,6 +851,7 @@ impl TypeInfo {
                 IntegerBits::Sixteen => "u16",
                 IntegerBits::ThirtyTwo => "u32",
                 IntegerBits::SixtyFour => "u64",
+                IntegerBits::V256 => "u256",
             }
             .into(),
             Boolean => "bool".into(),
@@ -807,19 +876,40 @@ impl TypeInfo {
                 let decl = decl_engine.get_struct(decl_ref);
                 format!("struct {}", call_path_display(ctx, &decl.call_path))
             }
-            ContractCaller { abi_name, .. } => {
-                format!("contract caller {abi_name}")
+            ContractCaller {
+                abi_name,
+                type_arguments,
+                ..
+            } => {
+                if type_arguments.is_empty() {
+                    format!("contract caller {abi_name}")
+                } else {
+                    let type_arg_strs = type_arguments
+                        .iter()
+                        .map(|type_arg| type_arg.json_abi_str(ctx, type_engine, decl_engine))
+                        .collect::<Vec<String>>();
+                    format!("contract caller {abi_name}<{}>", type_arg_strs.join(", "))
+                }
             }
             Array(elem_ty, length) => {
                 format!(
                     "[{}; {}]",
                     elem_ty.json_abi_str(ctx, type_engine, decl_engine),
-                    length.val()
+                    Engines::new(type_engine, decl_engine).help_out(length)
                 )
             }
             Storage { .. } => "contract storage".into(),
             RawUntypedPtr => "raw untyped ptr".into(),
             RawUntypedSlice => "raw untyped slice".into(),
+            StringSlice => "str".into(),
+            Closure { .. } => "closure".into(),
+            ConstGeneric { name, value } => match value {
+                Some(value) => value.to_string(),
+                None => name.to_string(),
+            },
+            TraitObject { trait_name, .. } => {
+                format!("dyn {}", call_path_display(ctx, trait_name))
+            }
         }
     }
 }
@@ -308,6 +308,7 @@ impl<'ir> EvmAsmBuilder<'ir> {
                 }
                 Instruction::Branch(to_block) => self.compile_branch(to_block),
                 Instruction::Call(func, args) => self.compile_call(instr_val, func, args),
+                Instruction::CallIndirect { .. } => todo!(),
                 Instruction::CastPtr(val, ty, offs) => {
                     self.compile_cast_ptr(instr_val, val, ty, *offs)
                 }
@@ -389,6 +390,7 @@ impl<'ir> EvmAsmBuilder<'ir> {
                     warnings,
                     errors
                 ),
+                Instruction::VTable(_) => todo!(),
             }
         } else {
             errors.push(CompileError::Internal(
@@ -202,8 +202,8 @@ pub enum StateAccessType {
 pub(crate) fn ir_type_size_in_bytes(context: &Context, ty: &Type) -> u64 {
     match ty.get_content(context) {
         TypeContent::Unit | TypeContent::Bool | TypeContent::Uint(_) => 8,
-        TypeContent::Slice => 16,
-        TypeContent::B256 => 32,
+        TypeContent::Slice | TypeContent::StringSlice => 16,
+        TypeContent::U256 | TypeContent::B256 => 32,
         TypeContent::String(n) => size_bytes_round_up_to_word_alignment!(*n),
         TypeContent::Array(el_ty, cnt) => cnt * ir_type_size_in_bytes(context, el_ty),
         TypeContent::Struct(field_tys) => {
@@ -419,6 +419,21 @@ impl AllocatedAbstractInstructionSet {
                             comment,
                         });
                     }
+                    ControlFlowOp::LoadVTable(r1, ref labs) => {
+                        let entries = labs
+                            .iter()
+                            .map(|lab| {
+                                Entry::new_word(label_offsets.get(lab).unwrap().offs, None, None)
+                            })
+                            .collect();
+                        let data_id = data_section
+                            .insert_data_value(Entry::new_collection(entries, None, None));
+                        realized_ops.push(RealizedOp {
+                            opcode: AllocatedOpcode::LWDataId(r1, data_id),
+                            owning_span,
+                            comment,
+                        });
+                    }
                     ControlFlowOp::Comment => continue,
                     ControlFlowOp::Label(..) => continue,
 
@@ -538,6 +553,9 @@ impl AllocatedAbstractInstructionSet {
 
                 Either::Right(Comment) => (),
 
+                // The vtable is not a single word, so LWDataId will load a pointer to it.
+                Either::Right(LoadVTable(..)) => cur_offset += 2,
+
                 Either::Right(DataSectionOffsetPlaceholder) => {
                     // If the placeholder is 32 bits, this is 1. if 64, this should be 2. We use LW
                     // to load the data, which loads a whole word, so for now this is 2.
@@ -181,6 +181,9 @@ impl<'ir> FuelAsmBuilder<'ir> {
                 }
                 Instruction::Branch(to_block) => self.compile_branch(to_block),
                 Instruction::Call(func, args) => self.compile_call(instr_val, func, args),
+                Instruction::CallIndirect { fn_addr, args, .. } => {
+                    self.compile_call_indirect(instr_val, fn_addr, args)
+                }
                 Instruction::CastPtr(val, ty, offs) => {
                     self.compile_cast_ptr(instr_val, val, ty, *offs)
                 }
@@ -343,6 +346,7 @@ impl<'ir> FuelAsmBuilder<'ir> {
                     warnings,
                     errors
                 ),
+                Instruction::VTable(functions) => self.compile_vtable(instr_val, functions),
             }
         } else {
             errors.push(CompileError::Internal(
@@ -1386,6 +1390,39 @@ impl<'ir> FuelAsmBuilder<'ir> {
                 )),
                 comment: "".into(),
             });
+        } else if log_ty.is_string_slice(self.context) {
+            // A string slice is logged as the bytes it refers to, whereas a `raw_slice` is logged
+            // as its pointer and length, like any other reference type below.
+            let ptr_reg = self.reg_seqr.next();
+            let len_reg = self.reg_seqr.next();
+            self.cur_bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::LW(
+                    ptr_reg.clone(),
+                    log_val_reg.clone(),
+                    VirtualImmediate12 { value: 0 },
+                )),
+                owning_span: owning_span.clone(),
+                comment: "loading string slice pointer for LOGD".into(),
+            });
+            self.cur_bytecode.push(Op {
+                opcode: Either::Left(VirtualOp::LW(
+                    len_reg.clone(),
+                    log_val_reg,
+                    VirtualImmediate12 { value: 1 },
+                )),
+                owning_span: owning_span.clone(),
+                comment: "loading string slice length for LOGD".into(),
+            });
+            self.cur_bytecode.push(Op {
+                owning_span,
+                opcode: Either::Left(VirtualOp::LOGD(
+                    VirtualRegister::Constant(ConstantRegister::Zero),
+                    log_id_reg,
+                    ptr_reg,
+                    len_reg,
+                )),
+                comment: "".into(),
+            });
         } else {
             // If the type not a reference type then we use LOGD to log the data. First put the
             // size into the data section, then add a LW to get it, then add a LOGD which uses
@@ -1468,7 +1505,7 @@ impl<'ir> FuelAsmBuilder<'ir> {
             } else {
                 // If the type is not a copy type then we use RETD to return data.
                 let size_reg = self.reg_seqr.next();
-                if ret_type.is_slice(self.context) {
+                if ret_type.is_slice(self.context) || ret_type.is_string_slice(self.context) {
                     // If this is a slice then return what it points to.
                     self.cur_bytecode.push(Op {
                         opcode: Either::Left(VirtualOp::LW(
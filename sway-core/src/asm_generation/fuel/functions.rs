@@ -54,23 +54,7 @@ use sway_types::Ident;
 
 impl<'ir> FuelAsmBuilder<'ir> {
     pub(super) fn compile_call(&mut self, instr_val: &Value, function: &Function, args: &[Value]) {
-        // Put the args into the args registers.
-        for (idx, arg_val) in args.iter().enumerate() {
-            if idx < compiler_constants::NUM_ARG_REGISTERS as usize {
-                let arg_reg = self.value_to_register(arg_val);
-                self.cur_bytecode.push(Op::register_move(
-                    VirtualRegister::Constant(ConstantRegister::ARG_REGS[idx]),
-                    arg_reg,
-                    format!("pass arg {idx}"),
-                    self.md_mgr.val_to_span(self.context, *arg_val),
-                ));
-            } else {
-                todo!(
-                    "can't do more than {} args yet",
-                    compiler_constants::NUM_ARG_REGISTERS
-                );
-            }
-        }
+        self.compile_call_args(args);
 
         // Set a new return address.
         let ret_label = self.reg_seqr.get_label();
@@ -90,6 +74,77 @@ impl<'ir> FuelAsmBuilder<'ir> {
         });
         self.cur_bytecode.push(Op::unowned_jump_label(ret_label));
 
+        self.compile_call_return_value(instr_val);
+    }
+
+    /// Calls the function whose address is in `fn_addr` using the same convention as
+    /// `compile_call()`, but jumping to the register rather than a label.
+    pub(super) fn compile_call_indirect(
+        &mut self,
+        instr_val: &Value,
+        fn_addr: &Value,
+        args: &[Value],
+    ) {
+        let fn_addr_reg = self.value_to_register(fn_addr);
+        self.compile_call_args(args);
+
+        // Set a new return address.
+        let ret_label = self.reg_seqr.get_label();
+        self.cur_bytecode.push(Op::move_address(
+            VirtualRegister::Constant(ConstantRegister::CallReturnAddress),
+            ret_label,
+            "set new return addr",
+            None,
+        ));
+
+        // Jump to the function address and insert return label.
+        self.cur_bytecode.push(Op::jump_to_register(
+            fn_addr_reg,
+            "call indirect",
+            self.md_mgr.val_to_span(self.context, *instr_val),
+        ));
+        self.cur_bytecode.push(Op::unowned_jump_label(ret_label));
+
+        self.compile_call_return_value(instr_val);
+    }
+
+    /// Returns the address of a table of the code addresses of `functions`, kept in the data
+    /// section.
+    pub(super) fn compile_vtable(&mut self, instr_val: &Value, functions: &[Function]) {
+        let labels = functions
+            .iter()
+            .map(|function| self.func_to_labels(function).0)
+            .collect();
+        let vtable_reg = self.reg_seqr.next();
+        self.cur_bytecode.push(Op {
+            opcode: Either::Right(OrganizationalOp::LoadVTable(vtable_reg.clone(), labels)),
+            comment: "load vtable address".into(),
+            owning_span: self.md_mgr.val_to_span(self.context, *instr_val),
+        });
+        self.reg_map.insert(*instr_val, vtable_reg);
+    }
+
+    fn compile_call_args(&mut self, args: &[Value]) {
+        // Put the args into the args registers.
+        for (idx, arg_val) in args.iter().enumerate() {
+            if idx < compiler_constants::NUM_ARG_REGISTERS as usize {
+                let arg_reg = self.value_to_register(arg_val);
+                self.cur_bytecode.push(Op::register_move(
+                    VirtualRegister::Constant(ConstantRegister::ARG_REGS[idx]),
+                    arg_reg,
+                    format!("pass arg {idx}"),
+                    self.md_mgr.val_to_span(self.context, *arg_val),
+                ));
+            } else {
+                todo!(
+                    "can't do more than {} args yet",
+                    compiler_constants::NUM_ARG_REGISTERS
+                );
+            }
+        }
+    }
+
+    fn compile_call_return_value(&mut self, instr_val: &Value) {
         // Save the return value.
         let ret_reg = self.reg_seqr.next();
         self.cur_bytecode.push(Op {
@@ -615,11 +670,11 @@ impl<'ir> FuelAsmBuilder<'ir> {
                         self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
                         stack_base += 1;
                     }
-                    TypeContent::Slice => {
+                    TypeContent::Slice | TypeContent::StringSlice => {
                         self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
                         stack_base += 2;
                     }
-                    TypeContent::B256 => {
+                    TypeContent::U256 | TypeContent::B256 => {
                         // XXX Like strings, should we just reserve space for a pointer?
                         self.ptr_map.insert(*ptr, Storage::Stack(stack_base));
                         stack_base += 4;
@@ -281,6 +281,7 @@ impl<'ir> MidenVMAsmBuilder<'ir> {
                 }
                 Instruction::Branch(to_block) => todo!(),
                 Instruction::Call(func, args) => self.compile_call(instr_val, func, args),
+                Instruction::CallIndirect { .. } => todo!(),
                 Instruction::CastPtr(val, ty, offs) => {
                     todo!()
                 }
@@ -334,6 +335,7 @@ impl<'ir> MidenVMAsmBuilder<'ir> {
                     dst_val,
                     stored_val,
                 } => todo!(),
+                Instruction::VTable(_) => todo!(),
             }
         } else {
             panic!(
@@ -628,6 +630,7 @@ impl<'ir> MidenVMAsmBuilder<'ir> {
             Unit => vec![DirectOp::push(MidenStackValue::Unit)],
             Bool(b) => vec![DirectOp::push(b)],
             Uint(x) => vec![DirectOp::push(x)],
+            U256(_) => todo!(),
             B256(_) => todo!(),
             String(_) => todo!(),
             Array(_) => todo!(),
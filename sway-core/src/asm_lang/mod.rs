@@ -1562,6 +1562,8 @@ pub(crate) enum ControlFlowOp<Reg> {
     DataSectionOffsetPlaceholder,
     // Placeholder for loading an address from the data section.
     LoadLabel(Reg, Label),
+    // Placeholder for loading the address of a table of label addresses in the data section.
+    LoadVTable(Reg, Vec<Label>),
     // Save all currently live general purpose registers, using a label as a handle.
     PushAll(Label),
     // Restore all previously saved general purpose registers.
@@ -1587,6 +1589,13 @@ impl<Reg: fmt::Display> fmt::Display for ControlFlowOp<Reg> {
                 DataSectionOffsetPlaceholder =>
                     "DATA SECTION OFFSET[0..32]\nDATA SECTION OFFSET[32..64]".into(),
                 LoadLabel(r1, lab) => format!("lwlab {r1} {lab}"),
+                LoadVTable(r1, labs) => format!(
+                    "lwvt {r1} [{}]",
+                    labs.iter()
+                        .map(|lab| lab.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
                 PushAll(lab) => format!("pusha {lab}"),
                 PopAll(lab) => format!("popa {lab}"),
             }
@@ -1607,7 +1616,9 @@ impl<Reg: Clone + Eq + Ord + Hash> ControlFlowOp<Reg> {
             | PopAll(_) => vec![],
 
             JumpIfNotEq(r1, r2, _) => vec![r1, r2],
-            JumpIfNotZero(r1, _) | MoveAddress(r1, _) | LoadLabel(r1, _) => vec![r1],
+            JumpIfNotZero(r1, _) | MoveAddress(r1, _) | LoadLabel(r1, _) | LoadVTable(r1, _) => {
+                vec![r1]
+            }
         })
         .into_iter()
         .collect()
@@ -1623,6 +1634,7 @@ impl<Reg: Clone + Eq + Ord + Hash> ControlFlowOp<Reg> {
             | MoveAddress(..)
             | DataSectionOffsetPlaceholder
             | LoadLabel(..)
+            | LoadVTable(..)
             | PushAll(_)
             | PopAll(_) => vec![],
 
@@ -1636,7 +1648,7 @@ impl<Reg: Clone + Eq + Ord + Hash> ControlFlowOp<Reg> {
     pub(crate) fn def_registers(&self) -> BTreeSet<&Reg> {
         use ControlFlowOp::*;
         (match self {
-            MoveAddress(reg, _) | LoadLabel(reg, _) => vec![reg],
+            MoveAddress(reg, _) | LoadLabel(reg, _) | LoadVTable(reg, _) => vec![reg],
 
             Label(_)
             | Comment
@@ -1669,6 +1681,7 @@ impl<Reg: Clone + Eq + Ord + Hash> ControlFlowOp<Reg> {
             JumpIfNotZero(r1, label) => Self::JumpIfNotZero(update_reg(r1), *label),
             MoveAddress(r1, label) => Self::MoveAddress(update_reg(r1), *label),
             LoadLabel(r1, label) => Self::LoadLabel(update_reg(r1), *label),
+            LoadVTable(r1, labels) => Self::LoadVTable(update_reg(r1), labels.clone()),
         }
     }
 
@@ -1693,6 +1706,7 @@ impl<Reg: Clone + Eq + Ord + Hash> ControlFlowOp<Reg> {
             | MoveAddress(..)
             | DataSectionOffsetPlaceholder
             | LoadLabel(..)
+            | LoadVTable(..)
             | PushAll(_)
             | PopAll(_) => (),
 
@@ -1755,6 +1769,7 @@ impl ControlFlowOp<VirtualRegister> {
             JumpIfNotZero(r1, label) => JumpIfNotZero(map_reg(r1), *label),
             MoveAddress(r1, label) => MoveAddress(map_reg(r1), *label),
             LoadLabel(r1, label) => LoadLabel(map_reg(r1), *label),
+            LoadVTable(r1, labels) => LoadVTable(map_reg(r1), labels.clone()),
         }
     }
 }
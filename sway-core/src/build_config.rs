@@ -1,4 +1,8 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +16,10 @@ pub enum BuildTarget {
     MidenVM,
 }
 
+/// The sources of modules to use in place of the files on disk, keyed by the canonical paths of
+/// the files. E.g. the unsaved contents of the files open in an editor.
+pub type SourceOverlay = HashMap<PathBuf, Arc<str>>;
+
 /// Configuration for the overall build and compilation process.
 #[derive(Clone)]
 pub struct BuildConfig {
@@ -25,6 +33,7 @@ pub struct BuildConfig {
     pub(crate) print_finalized_asm: bool,
     pub(crate) print_ir: bool,
     pub(crate) include_tests: bool,
+    pub(crate) source_overlay: Arc<SourceOverlay>,
 }
 
 impl BuildConfig {
@@ -66,6 +75,7 @@ impl BuildConfig {
             print_finalized_asm: false,
             print_ir: false,
             include_tests: false,
+            source_overlay: Default::default(),
         }
     }
 
@@ -109,7 +119,28 @@ impl BuildConfig {
         }
     }
 
+    /// The sources to read modules from instead of the files on disk.
+    ///
+    /// Modules whose paths are not in the overlay are read from disk.
+    ///
+    /// Default: empty
+    pub fn source_overlay(self, source_overlay: Arc<SourceOverlay>) -> Self {
+        Self {
+            source_overlay,
+            ..self
+        }
+    }
+
     pub fn canonical_root_module(&self) -> Arc<PathBuf> {
         self.canonical_root_module.clone()
     }
+
+    /// Returns the source of the module at the given `path`, preferring the source overlay over
+    /// the file on disk.
+    pub fn read_source(&self, path: &Path) -> std::io::Result<Arc<str>> {
+        match self.source_overlay.get(path) {
+            Some(src) => Ok(src.clone()),
+            None => std::fs::read_to_string(path).map(Arc::from),
+        }
+    }
 }
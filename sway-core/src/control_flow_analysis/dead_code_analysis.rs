@@ -418,7 +418,11 @@ fn connect_declaration<'eng: 'cfg, 'cfg>(
             connect_storage_declaration(&storage, graph, entry_node, tree_type);
             Ok(leaves.to_vec())
         }
-        ErrorRecovery(_) | GenericTypeForFunctionScope { .. } => Ok(leaves.to_vec()),
+        // Type aliases are resolved to the aliased type during type checking,
+        // so their uses can not be tracked here.
+        ErrorRecovery(_) | GenericTypeForFunctionScope { .. } | TypeAliasDeclaration { .. } => {
+            Ok(leaves.to_vec())
+        }
     }
 }
 
@@ -528,6 +532,7 @@ fn connect_impl_trait<'eng: 'cfg, 'cfg>(
                 )?;
                 methods_and_indexes.push((fn_decl.name.clone(), fn_decl_entry_node));
             }
+            TyImplItem::Constant(_) | TyImplItem::Type(_) => {}
         }
     }
     // we also want to add an edge from the methods back to the trait, so if a method gets called,
@@ -607,6 +612,7 @@ fn connect_abi_declaration(
                     }
                 }
             }
+            ty::TyTraitInterfaceItem::Constant(_) | ty::TyTraitInterfaceItem::Type(_) => {}
         }
     }
 
@@ -1019,7 +1025,7 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             )?;
             Ok([lhs_expr, rhs_expr].concat())
         }
-        Literal(_) => {
+        Literal(_) | ConstGenericValue { .. } => {
             let node = graph.add_node("Literal value".into());
             for leaf in leaves {
                 graph.add_edge(*leaf, node, "".into());
@@ -1407,6 +1413,32 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             exp.span.clone(),
             options,
         ),
+        TraitObject { exp, methods } => {
+            let leaves = connect_expression(
+                engines,
+                &exp.expression,
+                graph,
+                leaves,
+                exit_node,
+                "trait object exp",
+                tree_type,
+                exp.span.clone(),
+                options,
+            )?;
+            // every method of the trait object may be called through it, so
+            // they are all treated as used here
+            for method in methods {
+                let fn_decl = decl_engine.get_function(method);
+                if let Some(FunctionNamespaceEntry { entry_point, .. }) =
+                    graph.namespace.get_function(&fn_decl).cloned()
+                {
+                    for leaf in leaves.iter() {
+                        graph.add_edge(*leaf, entry_point, "trait object method".into());
+                    }
+                }
+            }
+            Ok(leaves)
+        }
         WhileLoop {
             body, condition, ..
         } => {
@@ -1517,6 +1549,69 @@ fn connect_expression<'eng: 'cfg, 'cfg>(
             }
             Ok(vec![])
         }
+        Closure { fn_ref, captures } => {
+            let mut current_leaf = leaves.to_vec();
+            for (_name, capture) in captures {
+                current_leaf = connect_expression(
+                    engines,
+                    &capture.expression,
+                    graph,
+                    &current_leaf,
+                    exit_node,
+                    "closure capture",
+                    tree_type,
+                    capture.span.clone(),
+                    options,
+                )?;
+            }
+            // The body may run whenever the closure is called, so it is connected at the
+            // point where the closure is created. Returns in the body leave the closure,
+            // not the enclosing function, so there is no exit node.
+            let fn_decl = decl_engine.get_function(fn_ref);
+            connect_code_block(
+                engines,
+                &fn_decl.body,
+                graph,
+                &current_leaf,
+                None,
+                tree_type,
+                options,
+            )?;
+            Ok(current_leaf)
+        }
+        ClosureCall {
+            closure: object,
+            arguments,
+        }
+        | DynamicMethodCall {
+            object, arguments, ..
+        } => {
+            let mut current_leaf = connect_expression(
+                engines,
+                &object.expression,
+                graph,
+                leaves,
+                exit_node,
+                "indirect call",
+                tree_type,
+                object.span.clone(),
+                options,
+            )?;
+            for arg in arguments {
+                current_leaf = connect_expression(
+                    engines,
+                    &arg.expression,
+                    graph,
+                    &current_leaf,
+                    exit_node,
+                    "arg eval",
+                    tree_type,
+                    arg.span.clone(),
+                    options,
+                )?;
+            }
+            Ok(current_leaf)
+        }
     }
 }
 
@@ -1751,6 +1846,13 @@ fn construct_dead_code_warning_from_node(
             content: ty::TyAstNodeContent::Declaration(ty::TyDeclaration::StorageDeclaration { .. }),
             ..
         } => return None,
+        // Type aliases are resolved away during type checking, so uses of an alias never show up
+        // as edges in the graph. Don't warn about them.
+        ty::TyAstNode {
+            content:
+                ty::TyAstNodeContent::Declaration(ty::TyDeclaration::TypeAliasDeclaration { .. }),
+            ..
+        } => return None,
         // If there is already an error for the declaration, we don't need to emit a dead code warning.
         ty::TyAstNode {
             content: ty::TyAstNodeContent::Declaration(ty::TyDeclaration::ErrorRecovery(..)),
@@ -1836,6 +1938,9 @@ fn allow_dead_code_ast_node(decl_engine: &DeclEngine, node: &ty::TyAstNode) -> b
             ty::TyDeclaration::GenericTypeForFunctionScope { .. } => false,
             ty::TyDeclaration::ErrorRecovery(_) => false,
             ty::TyDeclaration::StorageDeclaration { .. } => false,
+            ty::TyDeclaration::TypeAliasDeclaration { decl_id, .. } => {
+                allow_dead_code(decl_engine.get_type_alias(decl_id).attributes)
+            }
         },
         ty::TyAstNodeContent::Expression(_) => false,
         ty::TyAstNodeContent::ImplicitReturnExpression(_) => false,
@@ -12,6 +12,7 @@ use crate::{
     language::ty::{
         self, TyAbiDeclaration, TyConstantDeclaration, TyEnumDeclaration, TyFunctionDeclaration,
         TyImplTrait, TyStorageDeclaration, TyStructDeclaration, TyTraitDeclaration, TyTraitFn,
+        TyTraitType, TyTypeAliasDeclaration,
     },
 };
 
@@ -21,12 +22,14 @@ pub struct DeclEngine {
     function_slab: ConcurrentSlab<TyFunctionDeclaration>,
     trait_slab: ConcurrentSlab<TyTraitDeclaration>,
     trait_fn_slab: ConcurrentSlab<TyTraitFn>,
+    trait_type_slab: ConcurrentSlab<TyTraitType>,
     impl_trait_slab: ConcurrentSlab<TyImplTrait>,
     struct_slab: ConcurrentSlab<TyStructDeclaration>,
     storage_slab: ConcurrentSlab<TyStorageDeclaration>,
     abi_slab: ConcurrentSlab<TyAbiDeclaration>,
     constant_slab: ConcurrentSlab<TyConstantDeclaration>,
     enum_slab: ConcurrentSlab<TyEnumDeclaration>,
+    type_alias_slab: ConcurrentSlab<TyTypeAliasDeclaration>,
 
     parents: RwLock<HashMap<FunctionalDeclId, Vec<FunctionalDeclId>>>,
 }
@@ -65,12 +68,14 @@ macro_rules! decl_engine_index {
 decl_engine_index!(function_slab, ty::TyFunctionDeclaration);
 decl_engine_index!(trait_slab, ty::TyTraitDeclaration);
 decl_engine_index!(trait_fn_slab, ty::TyTraitFn);
+decl_engine_index!(trait_type_slab, ty::TyTraitType);
 decl_engine_index!(impl_trait_slab, ty::TyImplTrait);
 decl_engine_index!(struct_slab, ty::TyStructDeclaration);
 decl_engine_index!(storage_slab, ty::TyStorageDeclaration);
 decl_engine_index!(abi_slab, ty::TyAbiDeclaration);
 decl_engine_index!(constant_slab, ty::TyConstantDeclaration);
 decl_engine_index!(enum_slab, ty::TyEnumDeclaration);
+decl_engine_index!(type_alias_slab, ty::TyTypeAliasDeclaration);
 
 impl DeclEngine {
     /// Given a [DeclRef] `index`, finds all the parents of `index` and all the
@@ -151,6 +156,13 @@ impl DeclEngine {
         self.trait_fn_slab.get(DeclId::from(index).inner())
     }
 
+    pub fn get_trait_type<'a, T>(&self, index: &'a T) -> ty::TyTraitType
+    where
+        DeclId<ty::TyTraitType>: From<&'a T>,
+    {
+        self.trait_type_slab.get(DeclId::from(index).inner())
+    }
+
     pub fn get_impl_trait<'a, T>(&self, index: &'a T) -> ty::TyImplTrait
     where
         DeclId<ty::TyImplTrait>: From<&'a T>,
@@ -192,4 +204,11 @@ impl DeclEngine {
     {
         self.enum_slab.get(DeclId::from(index).inner())
     }
+
+    pub fn get_type_alias<'a, T>(&self, index: &'a T) -> ty::TyTypeAliasDeclaration
+    where
+        DeclId<ty::TyTypeAliasDeclaration>: From<&'a T>,
+    {
+        self.type_alias_slab.get(DeclId::from(index).inner())
+    }
 }
@@ -48,6 +48,12 @@ impl DeclMapping {
         self.mapping.is_empty()
     }
 
+    pub(crate) fn destinations_mut(&mut self) -> impl Iterator<Item = &mut DestinationDecl> {
+        self.mapping
+            .iter_mut()
+            .map(|(_, dest_decl_ref)| dest_decl_ref)
+    }
+
     pub(crate) fn from_interface_and_item_and_impld_decl_refs(
         interface_decl_refs: InterfaceItemMap,
         item_decl_refs: ItemMap,
@@ -55,28 +61,20 @@ impl DeclMapping {
     ) -> DeclMapping {
         let mut mapping: Vec<(SourceDecl, DestinationDecl)> = vec![];
         for (interface_decl_name, interface_item) in interface_decl_refs.into_iter() {
-            if let Some(new_item) = impld_decl_refs.get(&interface_decl_name) {
-                #[allow(clippy::infallible_destructuring_match)]
-                let interface_decl_ref = match interface_item {
-                    TyTraitInterfaceItem::TraitFn(decl_ref) => decl_ref,
-                };
-                #[allow(clippy::infallible_destructuring_match)]
-                let new_decl_ref = match new_item {
-                    TyTraitItem::Fn(decl_ref) => decl_ref,
-                };
+            // Only methods are called through decl refs, so associated
+            // constants and types do not need to be mapped.
+            if let (
+                TyTraitInterfaceItem::TraitFn(interface_decl_ref),
+                Some(TyTraitItem::Fn(new_decl_ref)),
+            ) = (interface_item, impld_decl_refs.get(&interface_decl_name))
+            {
                 mapping.push(((*interface_decl_ref.id()).into(), *new_decl_ref.id()));
             }
         }
         for (decl_name, item) in item_decl_refs.into_iter() {
-            if let Some(new_item) = impld_decl_refs.get(&decl_name) {
-                #[allow(clippy::infallible_destructuring_match)]
-                let interface_decl_ref = match item {
-                    TyTraitItem::Fn(decl_ref) => decl_ref,
-                };
-                #[allow(clippy::infallible_destructuring_match)]
-                let new_decl_ref = match new_item {
-                    TyTraitItem::Fn(decl_ref) => decl_ref,
-                };
+            if let (TyTraitItem::Fn(interface_decl_ref), Some(TyTraitItem::Fn(new_decl_ref))) =
+                (item, impld_decl_refs.get(&decl_name))
+            {
                 mapping.push(((*interface_decl_ref.id()).into(), new_decl_ref.into()));
             }
         }
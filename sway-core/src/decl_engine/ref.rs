@@ -30,6 +30,7 @@ use crate::{
     language::ty::{
         self, TyAbiDeclaration, TyConstantDeclaration, TyEnumDeclaration, TyFunctionDeclaration,
         TyImplTrait, TyStorageDeclaration, TyStructDeclaration, TyTraitDeclaration, TyTraitFn,
+        TyTraitType, TyTypeAliasDeclaration,
     },
     type_system::*,
 };
@@ -37,12 +38,14 @@ use crate::{
 pub type DeclRefFunction = DeclRef<DeclId<TyFunctionDeclaration>>;
 pub type DeclRefTrait = DeclRef<DeclId<TyTraitDeclaration>>;
 pub type DeclRefTraitFn = DeclRef<DeclId<TyTraitFn>>;
+pub type DeclRefTraitType = DeclRef<DeclId<TyTraitType>>;
 pub type DeclRefImplTrait = DeclRef<DeclId<TyImplTrait>>;
 pub type DeclRefStruct = DeclRef<DeclId<TyStructDeclaration>>;
 pub type DeclRefStorage = DeclRef<DeclId<TyStorageDeclaration>>;
 pub type DeclRefAbi = DeclRef<DeclId<TyAbiDeclaration>>;
 pub type DeclRefConstant = DeclRef<DeclId<TyConstantDeclaration>>;
 pub type DeclRefEnum = DeclRef<DeclId<TyEnumDeclaration>>;
+pub type DeclRefTypeAlias = DeclRef<DeclId<TyTypeAliasDeclaration>>;
 
 pub type DeclRefMixedFunctional = DeclRef<FunctionalDeclId>;
 pub type DeclRefMixedInterface = DeclRef<InterfaceDeclId>;
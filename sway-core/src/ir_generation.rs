@@ -6,6 +6,7 @@ mod lexical_map;
 mod purity;
 pub mod storage;
 mod types;
+mod u256;
 
 use sway_error::error::CompileError;
 use sway_ir::Context;
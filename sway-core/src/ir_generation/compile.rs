@@ -10,7 +10,7 @@ use crate::{
 use super::{
     const_eval::{compile_const_decl, LookupEnv},
     convert::convert_resolved_typeid,
-    function::FnCompiler,
+    function::{FnCompiler, FnKey},
 };
 
 use sway_error::error::CompileError;
@@ -290,6 +290,7 @@ fn compile_declarations(
             | ty::TyDeclaration::AbiDeclaration { .. }
             | ty::TyDeclaration::GenericTypeForFunctionScope { .. }
             | ty::TyDeclaration::StorageDeclaration { .. }
+            | ty::TyDeclaration::TypeAliasDeclaration { .. }
             | ty::TyDeclaration::ErrorRecovery(_) => (),
         }
     }
@@ -308,36 +309,69 @@ pub(super) fn compile_function(
     is_entry: bool,
     test_decl_ref: Option<DeclRefFunction>,
 ) -> Result<Option<Function>, CompileError> {
-    let type_engine = engines.te();
-    let decl_engine = engines.de();
     // Currently monomorphization of generics is inlined into main() and the functions with generic
     // args are still present in the AST declarations, but they can be ignored.
     if !ast_fn_decl.type_parameters.is_empty() {
         Ok(None)
     } else {
-        let args = ast_fn_decl
-            .parameters
-            .iter()
-            .map(|param| convert_fn_param(type_engine, decl_engine, context, param))
-            .collect::<Result<Vec<(String, Type, bool, Span)>, CompileError>>()?;
-
-        compile_fn_with_args(
+        compile_fn_instance(
             engines,
             context,
             md_mgr,
             module,
             ast_fn_decl,
-            is_entry,
-            args,
-            None,
             logged_types_map,
             messages_types_map,
+            is_entry,
             test_decl_ref,
+            Vec::new(),
         )
         .map(Some)
     }
 }
 
+/// Compiles an instance of `ast_fn_decl` whose type parameters, if any, have all been resolved.
+///
+/// `callers` are the function instances which are still being compiled further up the call
+/// stack.  Recursive calls to any of them call the existing instance.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn compile_fn_instance(
+    engines: Engines<'_>,
+    context: &mut Context,
+    md_mgr: &mut MetadataManager,
+    module: Module,
+    ast_fn_decl: &ty::TyFunctionDeclaration,
+    logged_types_map: &HashMap<TypeId, LogId>,
+    messages_types_map: &HashMap<TypeId, MessageId>,
+    is_entry: bool,
+    test_decl_ref: Option<DeclRefFunction>,
+    callers: Vec<(FnKey, Function)>,
+) -> Result<Function, CompileError> {
+    let type_engine = engines.te();
+    let decl_engine = engines.de();
+
+    let args = ast_fn_decl
+        .parameters
+        .iter()
+        .map(|param| convert_fn_param(type_engine, decl_engine, context, param))
+        .collect::<Result<Vec<(String, Type, bool, Span)>, CompileError>>()?;
+
+    compile_fn_with_args(
+        engines,
+        context,
+        md_mgr,
+        module,
+        ast_fn_decl,
+        is_entry,
+        args,
+        None,
+        logged_types_map,
+        messages_types_map,
+        test_decl_ref,
+        callers,
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn compile_entry_function(
     engines: Engines<'_>,
@@ -423,6 +457,7 @@ fn compile_fn_with_args(
     logged_types_map: &HashMap<TypeId, LogId>,
     messages_types_map: &HashMap<TypeId, MessageId>,
     test_decl_ref: Option<DeclRefFunction>,
+    mut callers: Vec<(FnKey, Function)>,
 ) -> Result<Function, CompileError> {
     let type_engine = engines.te();
     let decl_engine = engines.de();
@@ -488,6 +523,12 @@ fn compile_fn_with_args(
         metadata,
     );
 
+    // Any recursive calls made from the body must call this instance, so it is added to the
+    // callers before the body is compiled.  Entry functions can't be called.
+    if !is_entry {
+        callers.push((FnKey::from_fn_decl(ast_fn_decl), func));
+    }
+
     let mut compiler = FnCompiler::new(
         engines,
         context,
@@ -496,6 +537,7 @@ fn compile_fn_with_args(
         returns_by_ref,
         logged_types_map,
         messages_types_map,
+        callers,
     );
     let mut ret_val = compiler.compile_code_block(context, md_mgr, body)?;
 
@@ -629,5 +671,6 @@ fn compile_abi_method(
         logged_types_map,
         messages_types_map,
         None,
+        Vec::new(),
     )
 }
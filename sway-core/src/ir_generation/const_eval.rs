@@ -6,16 +6,16 @@ use crate::{
     engine_threading::*,
     language::{
         ty::{self, TyIntrinsicFunctionKind},
-        CallPath,
+        CallPath, LazyOp, Literal,
     },
     metadata::MetadataManager,
     semantic_analysis::*,
-    TypeEngine,
+    TypeEngine, TypeId, TypeInfo,
 };
 
 use super::{
-    convert::{convert_literal_to_constant, convert_resolved_typeid},
-    function::FnCompiler,
+    convert::{convert_literal_to_constant, convert_resolved_typeid, resolve_u256_literal},
+    function::{resolve_recursive_callee, FnCompiler},
     types::*,
 };
 
@@ -28,7 +28,7 @@ use sway_ir::{
     value::Value,
     Instruction, Type,
 };
-use sway_types::{ident::Ident, span::Spanned};
+use sway_types::{ident::Ident, span::Span, span::Spanned, u256::U256};
 use sway_utils::mapped_stack::MappedStack;
 
 pub(crate) struct LookupEnv<'a> {
@@ -205,180 +205,221 @@ pub(crate) fn compile_constant_expression_to_constant(
         lookup: compile_const_decl,
     };
 
-    let err = match &const_expr.expression {
-        // Special case functions because the span in `const_expr` is to the inlined function
-        // definition, rather than the actual call site.
-        ty::TyExpressionVariant::FunctionApplication { call_path, .. } => {
+    let mut known_consts = KnownConsts::default();
+    match const_eval_typed_expr(lookup, &mut known_consts, const_expr) {
+        Ok(constant) => Ok(constant),
+        Err(ConstEvalError::CompileError(error)) => Err(error),
+        Err(ConstEvalError::NonConstant { span }) => {
+            Err(CompileError::NonConstantDeclValue { span })
+        }
+        // `return`, `break` and `continue` only unwind up to the enclosing function or loop, so
+        // these can't escape the initializer itself.
+        Err(ConstEvalError::Return(_) | ConstEvalError::Break | ConstEvalError::Continue) => {
             Err(CompileError::NonConstantDeclValue {
-                span: call_path.span(),
+                span: const_expr.span.clone(),
             })
         }
-        _otherwise => Err(CompileError::NonConstantDeclValue {
-            span: const_expr.span.clone(),
-        }),
-    };
-    let mut known_consts = MappedStack::<Ident, Constant>::new();
+    }
+}
+
+/// The maximum number of iterations of a single loop when evaluating an expression at compile
+/// time. This keeps the compiler from hanging on loops which never terminate.
+const MAX_LOOP_ITERATIONS: usize = 100_000;
 
-    const_eval_typed_expr(lookup, &mut known_consts, const_expr)?.map_or(err, Ok)
+/// The maximum depth of nested function calls when evaluating an expression at compile time.
+const MAX_CALL_DEPTH: usize = 64;
+
+/// The variables in scope while evaluating an expression, and the depth of the function call
+/// being evaluated.
+#[derive(Default)]
+struct KnownConsts {
+    vars: MappedStack<Ident, Constant>,
+    call_depth: usize,
+}
+
+/// The reasons why the evaluation of an expression stops before producing a value.
+enum ConstEvalError {
+    /// The expression at `span` can't be evaluated at compile time.
+    NonConstant { span: Span },
+    /// An error which is reported as is.
+    CompileError(CompileError),
+    /// A `return` was evaluated, which unwinds up to the enclosing function call.
+    Return(Constant),
+    /// A `break` was evaluated, which unwinds up to the enclosing loop.
+    Break,
+    /// A `continue` was evaluated, which unwinds up to the enclosing loop.
+    Continue,
+}
+
+impl From<CompileError> for ConstEvalError {
+    fn from(error: CompileError) -> Self {
+        ConstEvalError::CompileError(error)
+    }
+}
+
+fn non_constant<T>(span: &Span) -> Result<T, ConstEvalError> {
+    Err(ConstEvalError::NonConstant { span: span.clone() })
 }
 
 /// Given an environment mapping names to constants,
 /// attempt to evaluate a typed expression to a constant.
 fn const_eval_typed_expr(
     lookup: &mut LookupEnv,
-    known_consts: &mut MappedStack<Ident, Constant>,
+    known_consts: &mut KnownConsts,
     expr: &ty::TyExpression,
-) -> Result<Option<Constant>, CompileError> {
+) -> Result<Constant, ConstEvalError> {
     Ok(match &expr.expression {
-        ty::TyExpressionVariant::Literal(l) => Some(convert_literal_to_constant(lookup.context, l)),
+        // A string slice points into memory, which can't be known at compile time.
+        ty::TyExpressionVariant::Literal(Literal::String(_))
+            if matches!(
+                lookup.type_engine.get(expr.return_type),
+                TypeInfo::StringSlice
+            ) =>
+        {
+            return non_constant(&expr.span)
+        }
+        ty::TyExpressionVariant::Literal(l) => {
+            let l = resolve_u256_literal(lookup.type_engine, l, expr.return_type);
+            convert_literal_to_constant(lookup.context, &l)
+        }
         ty::TyExpressionVariant::FunctionApplication {
-            arguments, fn_ref, ..
+            call_path,
+            arguments,
+            fn_ref,
+            selector,
+            ..
         } => {
-            let mut actuals_const: Vec<_> = vec![];
-            for arg in arguments {
-                let (name, sub_expr) = arg;
-                let eval_expr_opt = const_eval_typed_expr(lookup, known_consts, sub_expr)?;
-                if let Some(sub_const) = eval_expr_opt {
-                    actuals_const.push((name, sub_const));
-                }
-            }
+            // The span in `expr` is to the inlined function definition, rather than the actual
+            // call site.
+            let call_span = call_path.span();
 
-            // If all actual arguments don't evaluate a constant, bail out.
-            // TODO: Explore if we could continue here and if it'll be useful.
-            if actuals_const.len() < arguments.len() {
-                return Ok(None);
+            // Contract calls are made at run time.
+            if selector.is_some() {
+                return non_constant(&call_span);
             }
-            for (name, cval) in actuals_const.into_iter() {
-                known_consts.push(name.clone(), cval);
+
+            // Modifications of `ref mut` parameters aren't visible to the caller.
+            let function_decl = resolve_recursive_callee(
+                lookup.type_engine,
+                lookup.decl_engine,
+                fn_ref,
+                &lookup.decl_engine.get_function(fn_ref),
+            );
+            if function_decl
+                .parameters
+                .iter()
+                .any(|param| param.is_reference && param.is_mutable)
+            {
+                return non_constant(&call_span);
             }
 
-            // TODO: Handle more than one statement in the block.
-            let function_decl = lookup.decl_engine.get_function(fn_ref);
-            if function_decl.body.contents.len() > 1 {
-                return Ok(None);
+            if known_consts.call_depth >= MAX_CALL_DEPTH {
+                return Err(CompileError::ConstEvalCallDepthLimit {
+                    limit: MAX_CALL_DEPTH,
+                    span: call_span,
+                }
+                .into());
             }
-            let body_contents_opt = function_decl.body.contents.last();
-            let res = if let Some(first_expr) = body_contents_opt {
-                const_eval_typed_ast_node(lookup, known_consts, first_expr)?
-            } else {
-                None
+
+            // The function body only sees its own parameters and locals.
+            let mut callee_consts = KnownConsts {
+                vars: MappedStack::new(),
+                call_depth: known_consts.call_depth + 1,
             };
-            for (name, _) in arguments {
-                known_consts.pop(name);
+            for (name, arg) in arguments {
+                let value = const_eval_typed_expr(lookup, known_consts, arg)?;
+                callee_consts.vars.push(name.clone(), value);
+            }
+
+            // Nor does it see the locals of the function being compiled, if any.
+            let function_compiler = lookup.function_compiler.take();
+            let res = const_eval_codeblock(lookup, &mut callee_consts, &function_decl.body);
+            lookup.function_compiler = function_compiler;
+            match res {
+                Ok(value) | Err(ConstEvalError::Return(value)) => value,
+                // The body of the callee may well be in another module, so it is the call which
+                // is reported.
+                Err(ConstEvalError::NonConstant { .. }) => return non_constant(&call_span),
+                Err(error) => return Err(error),
             }
-            res
         }
         ty::TyExpressionVariant::VariableExpression {
             name, call_path, ..
-        } => match known_consts.get(name) {
+        } => match known_consts.vars.get(name) {
             // 1. Check if name/call_path is in known_consts.
-            Some(cvs) => Some(cvs.clone()),
+            Some(cvs) => cvs.clone(),
             None => {
                 let call_path = match call_path {
                     Some(call_path) => call_path.clone(),
                     None => CallPath::from(name.clone()),
                 };
                 // 2. Check if name is a global constant.
-                (lookup.lookup)(lookup, &call_path)
+                match (lookup.lookup)(lookup, &call_path)
                     .ok()
                     .flatten()
                     .and_then(|v| v.get_constant(lookup.context).cloned())
+                {
+                    Some(constant) => constant,
+                    None => return non_constant(&expr.span),
+                }
             }
         },
         ty::TyExpressionVariant::StructExpression { fields, .. } => {
-            let (mut field_typs, mut field_vals): (Vec<_>, Vec<_>) = (vec![], vec![]);
+            // The fields are in the declared order, regardless of how they are initialised in
+            // source.
+            let mut field_vals = Vec::with_capacity(fields.len());
             for field in fields {
-                let ty::TyStructExpressionField { name: _, value, .. } = field;
-                let eval_expr_opt = const_eval_typed_expr(lookup, known_consts, value)?;
-                if let Some(cv) = eval_expr_opt {
-                    field_typs.push(value.return_type);
-                    field_vals.push(cv);
-                }
-            }
-
-            if field_vals.len() < fields.len() {
-                // We couldn't evaluate all fields to a constant.
-                return Ok(None);
+                field_vals.push(const_eval_typed_expr(lookup, known_consts, &field.value)?);
             }
-            get_aggregate_for_types(
+            let struct_ty = get_aggregate_for_types(
                 lookup.type_engine,
                 lookup.decl_engine,
                 lookup.context,
-                &field_typs,
+                &fields
+                    .iter()
+                    .map(|field| field.value.return_type)
+                    .collect::<Vec<_>>(),
+            )?;
+            Constant::new_struct(
+                lookup.context,
+                struct_ty.get_field_types(lookup.context),
+                field_vals,
             )
-            .map_or(None, |struct_ty| {
-                Some(Constant::new_struct(
-                    lookup.context,
-                    struct_ty.get_field_types(lookup.context),
-                    field_vals,
-                ))
-            })
         }
         ty::TyExpressionVariant::Tuple { fields } => {
-            let (mut field_typs, mut field_vals): (Vec<_>, Vec<_>) = (vec![], vec![]);
+            let mut field_vals = Vec::with_capacity(fields.len());
             for value in fields {
-                let eval_expr_opt = const_eval_typed_expr(lookup, known_consts, value)?;
-                if let Some(cv) = eval_expr_opt {
-                    field_typs.push(value.return_type);
-                    field_vals.push(cv);
-                }
+                field_vals.push(const_eval_typed_expr(lookup, known_consts, value)?);
             }
-            if field_vals.len() < fields.len() {
-                // We couldn't evaluate all fields to a constant.
-                return Ok(None);
-            }
-            create_tuple_aggregate(
+            let tuple_ty = create_tuple_aggregate(
                 lookup.type_engine,
                 lookup.decl_engine,
                 lookup.context,
-                field_typs,
+                fields.iter().map(|value| value.return_type).collect(),
+            )?;
+            Constant::new_struct(
+                lookup.context,
+                tuple_ty.get_field_types(lookup.context),
+                field_vals,
             )
-            .map_or(None, |tuple_ty| {
-                Some(Constant::new_struct(
-                    lookup.context,
-                    tuple_ty.get_field_types(lookup.context),
-                    field_vals,
-                ))
-            })
         }
         ty::TyExpressionVariant::Array { contents } => {
-            let (mut element_typs, mut element_vals): (Vec<_>, Vec<_>) = (vec![], vec![]);
+            let mut element_vals = Vec::with_capacity(contents.len());
             for value in contents {
-                let eval_expr_opt = const_eval_typed_expr(lookup, known_consts, value)?;
-                if let Some(cv) = eval_expr_opt {
-                    element_typs.push(value.return_type);
-                    element_vals.push(cv);
-                }
+                element_vals.push(const_eval_typed_expr(lookup, known_consts, value)?);
             }
-            if element_vals.len() < contents.len() || element_typs.is_empty() {
-                // We couldn't evaluate all fields to a constant or cannot determine element type.
-                return Ok(None);
-            }
-            let mut element_iter = element_typs.iter();
-            let element_type_id = *element_iter.next().unwrap();
-            if !element_iter.all(|tid| {
-                lookup.type_engine.get(*tid).eq(
-                    &lookup.type_engine.get(element_type_id),
-                    Engines::new(lookup.type_engine, lookup.decl_engine),
-                )
-            }) {
-                // This shouldn't happen if the type checker did its job.
-                return Ok(None);
-            }
-            create_array_aggregate(
+            let array_ty = convert_resolved_typeid(
                 lookup.type_engine,
                 lookup.decl_engine,
                 lookup.context,
-                element_type_id,
-                element_typs.len().try_into().unwrap(),
-            )
-            .map_or(None, |array_ty| {
-                Some(Constant::new_array(
-                    lookup.context,
-                    array_ty.get_array_elem_type(lookup.context).unwrap(),
-                    element_vals,
-                ))
-            })
+                &expr.return_type,
+                &expr.span,
+            )?;
+            let element_ty = match array_ty.get_array_elem_type(lookup.context) {
+                Some(element_ty) => element_ty,
+                None => return non_constant(&expr.span),
+            };
+            Constant::new_array(lookup.context, element_ty, element_vals)
         }
         ty::TyExpressionVariant::EnumInstantiation {
             enum_ref,
@@ -387,32 +428,22 @@ fn const_eval_typed_expr(
             ..
         } => {
             let enum_decl = lookup.decl_engine.get_enum(enum_ref);
-            let aggregate = create_enum_aggregate(
+            let enum_ty = create_enum_aggregate(
                 lookup.type_engine,
                 lookup.decl_engine,
                 lookup.context,
                 &enum_decl.variants,
-            );
-            if let Ok(enum_ty) = aggregate {
-                let tag_value = Constant::new_uint(lookup.context, 64, *tag as u64);
-                let mut fields: Vec<Constant> = vec![tag_value];
-                match contents {
-                    None => fields.push(Constant::new_unit(lookup.context)),
-                    Some(subexpr) => {
-                        let eval_expr = const_eval_typed_expr(lookup, known_consts, subexpr)?;
-                        eval_expr.into_iter().for_each(|enum_val| {
-                            fields.push(enum_val);
-                        })
-                    }
-                }
-                Some(Constant::new_struct(
-                    lookup.context,
-                    enum_ty.get_field_types(lookup.context),
-                    fields,
-                ))
-            } else {
-                None
-            }
+            )?;
+            let tag_value = Constant::new_uint(lookup.context, 64, *tag as u64);
+            let contents_value = match contents {
+                None => Constant::new_unit(lookup.context),
+                Some(subexpr) => const_eval_typed_expr(lookup, known_consts, subexpr)?,
+            };
+            Constant::new_struct(
+                lookup.context,
+                enum_ty.get_field_types(lookup.context),
+                vec![tag_value, contents_value],
+            )
         }
         ty::TyExpressionVariant::StructFieldAccess {
             prefix,
@@ -420,14 +451,14 @@ fn const_eval_typed_expr(
             resolved_type_of_parent,
             ..
         } => match const_eval_typed_expr(lookup, known_consts, prefix)? {
-            Some(Constant {
+            Constant {
                 value: ConstantValue::Struct(fields),
                 ..
-            }) => {
+            } => {
                 let field_kind = ty::ProjectionKind::StructField {
                     name: field_to_access.name.clone(),
                 };
-                get_struct_name_field_index_and_type(
+                match get_struct_name_field_index_and_type(
                     lookup.type_engine,
                     lookup.decl_engine,
                     *resolved_type_of_parent,
@@ -437,61 +468,285 @@ fn const_eval_typed_expr(
                     field_idx_and_type_opt.map(|(field_idx, _field_type)| field_idx)
                 })
                 .and_then(|field_idx| fields.get(field_idx as usize).cloned())
+                {
+                    Some(field) => field,
+                    None => return non_constant(&expr.span),
+                }
             }
-            _ => None,
+            _ => return non_constant(&expr.span),
         },
         ty::TyExpressionVariant::TupleElemAccess {
             prefix,
             elem_to_access_num,
             ..
         } => match const_eval_typed_expr(lookup, known_consts, prefix)? {
-            Some(Constant {
+            Constant {
                 value: ConstantValue::Struct(fields),
                 ..
-            }) => fields.get(*elem_to_access_num).cloned(),
-            _ => None,
+            } => match fields.get(*elem_to_access_num) {
+                Some(field) => field.clone(),
+                None => return non_constant(&expr.span),
+            },
+            _ => return non_constant(&expr.span),
         },
-        ty::TyExpressionVariant::Return(exp) => const_eval_typed_expr(lookup, known_consts, exp)?,
+        ty::TyExpressionVariant::ArrayIndex { prefix, index } => {
+            let array = const_eval_typed_expr(lookup, known_consts, prefix)?;
+            let index_value = const_eval_typed_expr(lookup, known_consts, index)?;
+            match (array.value, index_value.value) {
+                (ConstantValue::Array(elements), ConstantValue::Uint(index_value)) => {
+                    match elements.get(index_value as usize) {
+                        Some(element) => element.clone(),
+                        None => {
+                            return Err(CompileError::ArrayOutOfBounds {
+                                index: index_value,
+                                count: elements.len() as u64,
+                                span: index.span.clone(),
+                            }
+                            .into())
+                        }
+                    }
+                }
+                _ => return non_constant(&expr.span),
+            }
+        }
+        ty::TyExpressionVariant::CodeBlock(codeblock) => {
+            const_eval_codeblock(lookup, known_consts, codeblock)?
+        }
+        ty::TyExpressionVariant::IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            if const_eval_condition(lookup, known_consts, condition)? {
+                const_eval_typed_expr(lookup, known_consts, then)?
+            } else {
+                match r#else {
+                    Some(r#else) => const_eval_typed_expr(lookup, known_consts, r#else)?,
+                    None => Constant::new_unit(lookup.context),
+                }
+            }
+        }
+        ty::TyExpressionVariant::LazyOperator { op, lhs, rhs } => {
+            let lhs_value = const_eval_condition(lookup, known_consts, lhs)?;
+            let value = match (op, lhs_value) {
+                (LazyOp::And, false) => false,
+                (LazyOp::Or, true) => true,
+                _ => const_eval_condition(lookup, known_consts, rhs)?,
+            };
+            Constant::new_bool(lookup.context, value)
+        }
+        ty::TyExpressionVariant::WhileLoop { condition, body } => {
+            let mut iterations = 0;
+            while const_eval_condition(lookup, known_consts, condition)? {
+                iterations += 1;
+                if iterations > MAX_LOOP_ITERATIONS {
+                    return Err(CompileError::ConstEvalLoopLimit {
+                        limit: MAX_LOOP_ITERATIONS,
+                        span: expr.span.clone(),
+                    }
+                    .into());
+                }
+                match const_eval_codeblock(lookup, known_consts, body) {
+                    Ok(_) | Err(ConstEvalError::Continue) => (),
+                    Err(ConstEvalError::Break) => break,
+                    Err(error) => return Err(error),
+                }
+            }
+            Constant::new_unit(lookup.context)
+        }
+        ty::TyExpressionVariant::Break => return Err(ConstEvalError::Break),
+        ty::TyExpressionVariant::Continue => return Err(ConstEvalError::Continue),
+        ty::TyExpressionVariant::Return(exp) => {
+            let value = const_eval_typed_expr(lookup, known_consts, exp)?;
+            return Err(ConstEvalError::Return(value));
+        }
+        ty::TyExpressionVariant::Reassignment(reassignment) => {
+            let ty::TyReassignment {
+                lhs_base_name,
+                lhs_type,
+                lhs_indices,
+                rhs,
+            } = &**reassignment;
+            let rhs_value = const_eval_typed_expr(lookup, known_consts, rhs)?;
+            let base_value = match known_consts.vars.get(lhs_base_name) {
+                Some(base_value) => base_value.clone(),
+                None => return non_constant(&lhs_base_name.span()),
+            };
+            let new_value = const_eval_projection_update(
+                lookup,
+                known_consts,
+                base_value,
+                *lhs_type,
+                lhs_indices,
+                rhs_value,
+            )?;
+            known_consts.vars.pop(lhs_base_name);
+            known_consts.vars.push(lhs_base_name.clone(), new_value);
+            Constant::new_unit(lookup.context)
+        }
         ty::TyExpressionVariant::MatchExp { desugared, .. } => {
             const_eval_typed_expr(lookup, known_consts, desugared)?
         }
+        ty::TyExpressionVariant::EnumTag { exp } => {
+            match const_eval_typed_expr(lookup, known_consts, exp)?.value {
+                ConstantValue::Struct(mut fields) if fields.len() == 2 => fields.swap_remove(0),
+                _ => return non_constant(&expr.span),
+            }
+        }
+        ty::TyExpressionVariant::UnsafeDowncast { exp, .. } => {
+            match const_eval_typed_expr(lookup, known_consts, exp)?.value {
+                ConstantValue::Struct(mut fields) if fields.len() == 2 => fields.swap_remove(1),
+                _ => return non_constant(&expr.span),
+            }
+        }
         ty::TyExpressionVariant::IntrinsicFunction(kind) => {
-            const_eval_intrinsic(lookup, known_consts, kind)?
+            const_eval_intrinsic(lookup, known_consts, kind, &expr.span)?
         }
-        ty::TyExpressionVariant::ArrayIndex { .. }
-        | ty::TyExpressionVariant::CodeBlock(_)
-        | ty::TyExpressionVariant::Reassignment(_)
-        | ty::TyExpressionVariant::StorageReassignment(_)
+        ty::TyExpressionVariant::ConstGenericValue { type_id, .. } => {
+            match lookup.type_engine.get(*type_id) {
+                TypeInfo::ConstGeneric {
+                    value: Some(value), ..
+                } => Constant::new_uint(lookup.context, 64, value as u64),
+                _ => return non_constant(&expr.span),
+            }
+        }
+        ty::TyExpressionVariant::StorageReassignment(_)
         | ty::TyExpressionVariant::FunctionParameter
-        | ty::TyExpressionVariant::IfExp { .. }
         | ty::TyExpressionVariant::AsmExpression { .. }
-        | ty::TyExpressionVariant::LazyOperator { .. }
         | ty::TyExpressionVariant::AbiCast { .. }
         | ty::TyExpressionVariant::StorageAccess(_)
         | ty::TyExpressionVariant::AbiName(_)
-        | ty::TyExpressionVariant::EnumTag { .. }
-        | ty::TyExpressionVariant::UnsafeDowncast { .. }
-        | ty::TyExpressionVariant::Break
-        | ty::TyExpressionVariant::Continue
-        | ty::TyExpressionVariant::WhileLoop { .. } => None,
+        | ty::TyExpressionVariant::Closure { .. }
+        | ty::TyExpressionVariant::ClosureCall { .. }
+        | ty::TyExpressionVariant::TraitObject { .. }
+        | ty::TyExpressionVariant::DynamicMethodCall { .. } => return non_constant(&expr.span),
+    })
+}
+
+/// Evaluates `expr`, which has to be of type `bool`.
+fn const_eval_condition(
+    lookup: &mut LookupEnv,
+    known_consts: &mut KnownConsts,
+    expr: &ty::TyExpression,
+) -> Result<bool, ConstEvalError> {
+    match const_eval_typed_expr(lookup, known_consts, expr)?.value {
+        ConstantValue::Bool(value) => Ok(value),
+        _ => non_constant(&expr.span),
+    }
+}
+
+/// Evaluates `codeblock` in a new scope. Its value is the value of its implicit return
+/// expression, if it has one, and unit otherwise.
+fn const_eval_codeblock(
+    lookup: &mut LookupEnv,
+    known_consts: &mut KnownConsts,
+    codeblock: &ty::TyCodeBlock,
+) -> Result<Constant, ConstEvalError> {
+    let mut declared_names = vec![];
+    let mut nodes = codeblock.contents.iter();
+    let res = loop {
+        let node = match nodes.next() {
+            Some(node) => node,
+            None => break Ok(Constant::new_unit(lookup.context)),
+        };
+        match const_eval_typed_ast_node(lookup, known_consts, &mut declared_names, node) {
+            Ok(Some(value)) => break Ok(value),
+            Ok(None) => (),
+            Err(error) => break Err(error),
+        }
+    };
+
+    for name in declared_names.iter() {
+        known_consts.vars.pop(name);
+    }
+    res
+}
+
+/// Returns a copy of `aggregate`, of type `type_id`, in which the element found by following
+/// `indices` is replaced with `value`.
+fn const_eval_projection_update(
+    lookup: &mut LookupEnv,
+    known_consts: &mut KnownConsts,
+    aggregate: Constant,
+    type_id: TypeId,
+    indices: &[ty::ProjectionKind],
+    value: Constant,
+) -> Result<Constant, ConstEvalError> {
+    let (projection, rest) = match indices.split_first() {
+        Some(split) => split,
+        None => return Ok(value),
+    };
+    let index_and_type = match (projection, lookup.type_engine.get(type_id)) {
+        (ty::ProjectionKind::StructField { .. }, TypeInfo::Struct(_)) => {
+            get_struct_name_field_index_and_type(
+                lookup.type_engine,
+                lookup.decl_engine,
+                type_id,
+                projection.clone(),
+            )
+            .and_then(|(_struct_name, field_idx_and_type_opt)| field_idx_and_type_opt)
+            .map(|(field_idx, field_type)| (field_idx as usize, field_type))
+        }
+        (ty::ProjectionKind::TupleField { index, .. }, TypeInfo::Tuple(fields)) => {
+            fields.get(*index).map(|field| (*index, field.type_id))
+        }
+        (ty::ProjectionKind::ArrayIndex { index, .. }, TypeInfo::Array(elem_ty, _)) => {
+            match const_eval_typed_expr(lookup, known_consts, index)?.value {
+                ConstantValue::Uint(index_value) => Some((index_value as usize, elem_ty.type_id)),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+    let (index, elem_type_id) = match index_and_type {
+        Some(index_and_type) => index_and_type,
+        None => return non_constant(&projection.span()),
+    };
+
+    let Constant {
+        ty,
+        value: aggregate,
+    } = aggregate;
+    let (mut elements, is_array) = match aggregate {
+        ConstantValue::Struct(fields) => (fields, false),
+        ConstantValue::Array(elements) => (elements, true),
+        _ => return non_constant(&projection.span()),
+    };
+    let element = match elements.get(index) {
+        Some(element) => element.clone(),
+        None if is_array => {
+            return Err(CompileError::ArrayOutOfBounds {
+                index: index as u64,
+                count: elements.len() as u64,
+                span: projection.span(),
+            }
+            .into())
+        }
+        None => return non_constant(&projection.span()),
+    };
+    elements[index] =
+        const_eval_projection_update(lookup, known_consts, element, elem_type_id, rest, value)?;
+    Ok(Constant {
+        ty,
+        value: if is_array {
+            ConstantValue::Array(elements)
+        } else {
+            ConstantValue::Struct(elements)
+        },
     })
 }
 
 fn const_eval_intrinsic(
     lookup: &mut LookupEnv,
-    known_consts: &mut MappedStack<Ident, Constant>,
+    known_consts: &mut KnownConsts,
     intrinsic: &TyIntrinsicFunctionKind,
-) -> Result<Option<Constant>, CompileError> {
-    let args = intrinsic
-        .arguments
-        .iter()
-        .filter_map(|arg| const_eval_typed_expr(lookup, known_consts, arg).transpose())
-        .collect::<Result<Vec<_>, CompileError>>()?;
-
-    if args.len() != intrinsic.arguments.len() {
-        // We couldn't const-eval all arguments.
-        return Ok(None);
+    span: &Span,
+) -> Result<Constant, ConstEvalError> {
+    let mut args = Vec::with_capacity(intrinsic.arguments.len());
+    for arg in intrinsic.arguments.iter() {
+        args.push(const_eval_typed_expr(lookup, known_consts, arg)?);
     }
+
     match intrinsic.kind {
         sway_ast::Intrinsic::Add
         | sway_ast::Intrinsic::Sub
@@ -502,29 +757,46 @@ fn const_eval_intrinsic(
         | sway_ast::Intrinsic::Xor => {
             let ty = args[0].ty;
             assert!(
-                args.len() == 2 && ty.is_uint(lookup.context) && ty.eq(lookup.context, &args[1].ty)
+                args.len() == 2
+                    && (ty.is_uint(lookup.context) || ty.is_uint256(lookup.context))
+                    && ty.eq(lookup.context, &args[1].ty)
             );
-            let (ConstantValue::Uint(arg1), ConstantValue::Uint(ref arg2)) = (&args[0].value, &args[1].value)
-            else {
-                panic!("Type checker allowed incorrect args to binary op");
-            };
-            // All arithmetic is done as if it were u64
-            let result = match intrinsic.kind {
-                sway_ast::Intrinsic::Add => arg1.checked_add(*arg2),
-                sway_ast::Intrinsic::Sub => arg1.checked_sub(*arg2),
-                sway_ast::Intrinsic::Mul => arg1.checked_mul(*arg2),
-                sway_ast::Intrinsic::Div => arg1.checked_div(*arg2),
-                sway_ast::Intrinsic::And => Some(arg1.bitand(arg2)),
-                sway_ast::Intrinsic::Or => Some(arg1.bitor(*arg2)),
-                sway_ast::Intrinsic::Xor => Some(arg1.bitxor(*arg2)),
-                _ => unreachable!(),
+            let result = match (&args[0].value, &args[1].value) {
+                // All arithmetic is done as if it were u64
+                (ConstantValue::Uint(arg1), ConstantValue::Uint(ref arg2)) => {
+                    match intrinsic.kind {
+                        sway_ast::Intrinsic::Add => arg1.checked_add(*arg2),
+                        sway_ast::Intrinsic::Sub => arg1.checked_sub(*arg2),
+                        sway_ast::Intrinsic::Mul => arg1.checked_mul(*arg2),
+                        sway_ast::Intrinsic::Div => arg1.checked_div(*arg2),
+                        sway_ast::Intrinsic::And => Some(arg1.bitand(arg2)),
+                        sway_ast::Intrinsic::Or => Some(arg1.bitor(*arg2)),
+                        sway_ast::Intrinsic::Xor => Some(arg1.bitxor(*arg2)),
+                        _ => unreachable!(),
+                    }
+                    .map(ConstantValue::Uint)
+                }
+                (ConstantValue::U256(arg1), ConstantValue::U256(arg2)) => {
+                    let arg1 = U256::from_be_bytes(arg1);
+                    let arg2 = U256::from_be_bytes(arg2);
+                    match intrinsic.kind {
+                        sway_ast::Intrinsic::Add => arg1.checked_add(&arg2),
+                        sway_ast::Intrinsic::Sub => arg1.checked_sub(&arg2),
+                        sway_ast::Intrinsic::Mul => arg1.checked_mul(&arg2),
+                        sway_ast::Intrinsic::Div => arg1.checked_div(&arg2),
+                        sway_ast::Intrinsic::And => Some(arg1.bitand(&arg2)),
+                        sway_ast::Intrinsic::Or => Some(arg1.bitor(&arg2)),
+                        sway_ast::Intrinsic::Xor => Some(arg1.bitxor(&arg2)),
+                        _ => unreachable!(),
+                    }
+                    .map(|result| ConstantValue::U256(result.to_be_bytes()))
+                }
+                _ => panic!("Type checker allowed incorrect args to binary op"),
             };
             match result {
-                Some(sum) => Ok(Some(Constant {
-                    ty,
-                    value: ConstantValue::Uint(sum),
-                })),
-                None => Ok(None),
+                Some(value) => Ok(Constant { ty, value }),
+                // An overflow, or a division by zero, reverts at run time.
+                None => non_constant(span),
             }
         }
         sway_ast::Intrinsic::SizeOfType => {
@@ -536,10 +808,10 @@ fn const_eval_intrinsic(
                 &targ.type_id,
                 &targ.span,
             )?;
-            Ok(Some(Constant {
+            Ok(Constant {
                 ty: Type::get_uint64(lookup.context),
                 value: ConstantValue::Uint(ir_type_size_in_bytes(lookup.context, &ir_type)),
-            }))
+            })
         }
         sway_ast::Intrinsic::SizeOfVal => {
             let val = &intrinsic.arguments[0];
@@ -551,43 +823,50 @@ fn const_eval_intrinsic(
                 &type_id,
                 &val.span,
             )?;
-            Ok(Some(Constant {
+            Ok(Constant {
                 ty: Type::get_uint64(lookup.context),
                 value: ConstantValue::Uint(ir_type_size_in_bytes(lookup.context, &ir_type)),
-            }))
+            })
+        }
+        sway_ast::Intrinsic::IsReferenceType => {
+            let targ = &intrinsic.type_arguments[0];
+            let is_reference_type = !lookup.type_engine.get(targ.type_id).is_copy_type();
+            Ok(Constant::new_bool(lookup.context, is_reference_type))
         }
         sway_ast::Intrinsic::Eq => {
             assert!(args.len() == 2);
-            Ok(Some(Constant {
+            Ok(Constant {
                 ty: Type::get_bool(lookup.context),
                 value: ConstantValue::Bool(args[0].eq(lookup.context, &args[1])),
-            }))
+            })
         }
         sway_ast::Intrinsic::Gt => {
-            let (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) = (&args[0].value, &args[1].value)
-                else {
-                    unreachable!("Type checker allowed non integer value for GreaterThan")
-                };
-            Ok(Some(Constant {
+            let result = match (&args[0].value, &args[1].value) {
+                (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) => val1 > val2,
+                // Big-endian bytes compare in the same order as the numbers they encode.
+                (ConstantValue::U256(val1), ConstantValue::U256(val2)) => val1 > val2,
+                _ => unreachable!("Type checker allowed non integer value for GreaterThan"),
+            };
+            Ok(Constant {
                 ty: Type::get_bool(lookup.context),
-                value: ConstantValue::Bool(val1 > val2),
-            }))
+                value: ConstantValue::Bool(result),
+            })
         }
         sway_ast::Intrinsic::Lt => {
-            let (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) = (&args[0].value, &args[1].value)
-                else {
-                    unreachable!("Type checker allowed non integer value for LessThan")
-                };
-            Ok(Some(Constant {
+            let result = match (&args[0].value, &args[1].value) {
+                (ConstantValue::Uint(val1), ConstantValue::Uint(val2)) => val1 < val2,
+                (ConstantValue::U256(val1), ConstantValue::U256(val2)) => val1 < val2,
+                _ => unreachable!("Type checker allowed non integer value for LessThan"),
+            };
+            Ok(Constant {
                 ty: Type::get_bool(lookup.context),
-                value: ConstantValue::Bool(val1 < val2),
-            }))
-        }
-        sway_ast::Intrinsic::AddrOf => Ok(None),
-        sway_ast::Intrinsic::PtrAdd => Ok(None),
-        sway_ast::Intrinsic::PtrSub => Ok(None),
-        sway_ast::Intrinsic::GetStorageKey
-        | sway_ast::Intrinsic::IsReferenceType
+                value: ConstantValue::Bool(result),
+            })
+        }
+        sway_ast::Intrinsic::AddrOf
+        | sway_ast::Intrinsic::PtrAdd
+        | sway_ast::Intrinsic::PtrSub
+        | sway_ast::Intrinsic::GetStorageKey
         | sway_ast::Intrinsic::Gtf
         | sway_ast::Intrinsic::StateClear
         | sway_ast::Intrinsic::StateLoadWord
@@ -596,22 +875,51 @@ fn const_eval_intrinsic(
         | sway_ast::Intrinsic::StateStoreQuad
         | sway_ast::Intrinsic::Log
         | sway_ast::Intrinsic::Revert
-        | sway_ast::Intrinsic::Smo => Ok(None),
+        | sway_ast::Intrinsic::Smo => non_constant(span),
     }
 }
 
+/// Evaluates `node`, adding the names of any variables it declares to `declared_names`. Returns
+/// the value of `node` if it is an implicit return expression.
 fn const_eval_typed_ast_node(
     lookup: &mut LookupEnv,
-    known_consts: &mut MappedStack<Ident, Constant>,
-    expr: &ty::TyAstNode,
-) -> Result<Option<Constant>, CompileError> {
-    match &expr.content {
-        ty::TyAstNodeContent::Declaration(_) => {
-            // TODO: add the binding to known_consts (if it's a const) and proceed.
+    known_consts: &mut KnownConsts,
+    declared_names: &mut Vec<Ident>,
+    node: &ty::TyAstNode,
+) -> Result<Option<Constant>, ConstEvalError> {
+    match &node.content {
+        ty::TyAstNodeContent::Declaration(ty::TyDeclaration::VariableDeclaration(var_decl)) => {
+            let value = const_eval_typed_expr(lookup, known_consts, &var_decl.body)?;
+            known_consts.vars.push(var_decl.name.clone(), value);
+            declared_names.push(var_decl.name.clone());
+            Ok(None)
+        }
+        ty::TyAstNodeContent::Declaration(ty::TyDeclaration::ConstantDeclaration {
+            decl_id,
+            ..
+        }) => {
+            let ty::TyConstantDeclaration {
+                call_path,
+                value,
+                span,
+                ..
+            } = lookup.decl_engine.get_constant(decl_id);
+            let value = match value {
+                Some(value) => const_eval_typed_expr(lookup, known_consts, &value)?,
+                None => return non_constant(&span),
+            };
+            known_consts.vars.push(call_path.suffix.clone(), value);
+            declared_names.push(call_path.suffix);
+            Ok(None)
+        }
+        // Other declarations, such as those of types, have no value to evaluate.
+        ty::TyAstNodeContent::Declaration(_) => Ok(None),
+        ty::TyAstNodeContent::Expression(e) => {
+            const_eval_typed_expr(lookup, known_consts, e)?;
             Ok(None)
         }
-        ty::TyAstNodeContent::Expression(e) | ty::TyAstNodeContent::ImplicitReturnExpression(e) => {
-            const_eval_typed_expr(lookup, known_consts, e)
+        ty::TyAstNodeContent::ImplicitReturnExpression(e) => {
+            Ok(Some(const_eval_typed_expr(lookup, known_consts, e)?))
         }
         ty::TyAstNodeContent::SideEffect(_) => Ok(None),
     }
@@ -9,7 +9,7 @@ use super::types::{create_enum_aggregate, create_tuple_aggregate};
 
 use sway_error::error::CompileError;
 use sway_ir::{Constant, Context, Type, Value};
-use sway_types::span::Span;
+use sway_types::{integer_bits::IntegerBits, span::Span, u256::U256};
 
 pub(super) fn convert_literal_to_value(context: &mut Context, ast_literal: &Literal) -> Value {
     match ast_literal {
@@ -26,6 +26,7 @@ pub(super) fn convert_literal_to_value(context: &mut Context, ast_literal: &Lite
         Literal::Numeric(n) => Constant::get_uint(context, 64, *n),
         Literal::String(s) => Constant::get_string(context, s.as_str().as_bytes().to_vec()),
         Literal::Boolean(b) => Constant::get_bool(context, *b),
+        Literal::U256(n) => Constant::get_uint256(context, n.to_be_bytes()),
         Literal::B256(bs) => Constant::get_b256(context, *bs),
     }
 }
@@ -43,10 +44,27 @@ pub(super) fn convert_literal_to_constant(
         Literal::Numeric(n) => Constant::new_uint(context, 64, *n),
         Literal::String(s) => Constant::new_string(context, s.as_str().as_bytes().to_vec()),
         Literal::Boolean(b) => Constant::new_bool(context, *b),
+        Literal::U256(n) => Constant::new_uint256(context, n.to_be_bytes()),
         Literal::B256(bs) => Constant::new_b256(context, *bs),
     }
 }
 
+/// Numeric literals whose type is only inferred to be `u256` after they have been type checked
+/// remain `Literal::Numeric`.  Widens such a literal to a `Literal::U256` so it isn't compiled to
+/// a `u64` constant.
+pub(super) fn resolve_u256_literal(
+    type_engine: &TypeEngine,
+    ast_literal: &Literal,
+    ast_type: TypeId,
+) -> Literal {
+    match (ast_literal, type_engine.get(ast_type)) {
+        (Literal::Numeric(n), TypeInfo::UnsignedInteger(IntegerBits::V256)) => {
+            Literal::U256(U256::from(*n))
+        }
+        _ => ast_literal.clone(),
+    }
+}
+
 pub(super) fn convert_resolved_typeid(
     type_engine: &TypeEngine,
     decl_engine: &DeclEngine,
@@ -96,7 +114,9 @@ fn convert_resolved_type(
     }
 
     Ok(match ast_type {
-        // All integers are `u64`, see comment in convert_literal_to_value() above.
+        // All integers are `u64`, see comment in convert_literal_to_value() above.  The exception
+        // is `u256`, which doesn't fit in a register and is passed by reference like `b256`.
+        TypeInfo::UnsignedInteger(IntegerBits::V256) => Type::get_uint256(context),
         TypeInfo::UnsignedInteger(_) => Type::get_uint64(context),
         TypeInfo::Numeric => Type::get_uint64(context),
         TypeInfo::Boolean => Type::get_bool(context),
@@ -128,7 +148,11 @@ fn convert_resolved_type(
                 &elem_type.type_id,
                 span,
             )?;
-            Type::new_array(context, elem_type, length.val() as u64)
+            let length = match length.resolve(type_engine) {
+                Some(length) => length,
+                None => reject_type!("Array with a generic length"),
+            };
+            Type::new_array(context, elem_type, length as u64)
         }
         TypeInfo::Tuple(fields) => {
             if fields.is_empty() {
@@ -143,6 +167,26 @@ fn convert_resolved_type(
         }
         TypeInfo::RawUntypedPtr => Type::get_uint64(context),
         TypeInfo::RawUntypedSlice => Type::get_slice(context),
+        TypeInfo::StringSlice => Type::get_string_slice(context),
+        TypeInfo::Closure { fn_ref, captures } => {
+            // A closure value is the aggregate of the values it captured.
+            let decl = decl_engine.get_function(fn_ref);
+            if *captures == 0 {
+                Type::get_unit(context)
+            } else {
+                let new_fields = decl.parameters[..*captures]
+                    .iter()
+                    .map(|param| param.type_argument.type_id)
+                    .collect();
+                create_tuple_aggregate(type_engine, decl_engine, context, new_fields)?
+            }
+        }
+        TypeInfo::TraitObject { .. } => {
+            // A trait object is a word for its value, followed by the address
+            // of its vtable.
+            let word = Type::get_uint64(context);
+            Type::new_struct(context, vec![word, word])
+        }
 
         // Unsupported types which shouldn't exist in the AST after type checking and
         // monomorphisation.
@@ -152,6 +196,7 @@ fn convert_resolved_type(
         TypeInfo::ContractCaller { .. } => reject_type!("ContractCaller"),
         TypeInfo::Unknown => reject_type!("Unknown"),
         TypeInfo::UnknownGeneric { .. } => reject_type!("Generic"),
+        TypeInfo::ConstGeneric { .. } => reject_type!("Const generic"),
         TypeInfo::Placeholder(_) => reject_type!("Placeholder"),
         TypeInfo::TypeParam(_) => reject_type!("TypeParam"),
         TypeInfo::ErrorRecovery => reject_type!("Error recovery"),
@@ -1,10 +1,10 @@
 use super::{
-    compile::compile_function, convert::*, lexical_map::LexicalMap, storage::get_storage_key,
-    types::*,
+    compile::compile_fn_instance, convert::*, lexical_map::LexicalMap, storage::get_storage_key,
+    types::*, u256::lower_u256_intrinsic,
 };
 use crate::{
     asm_generation::from_ir::ir_type_size_in_bytes,
-    decl_engine::DeclEngine,
+    decl_engine::{DeclEngine, DeclId, DeclRefFunction},
     engine_threading::*,
     ir_generation::const_eval::{
         compile_constant_expression, compile_constant_expression_to_constant,
@@ -14,7 +14,7 @@ use crate::{
         *,
     },
     metadata::MetadataManager,
-    type_system::{LogId, MessageId, TypeId, TypeInfo},
+    type_system::{LogId, MessageId, SubstTypes, TypeId, TypeInfo, TypeSubstMap},
     types::DeterministicallyAborts,
     TypeEngine,
 };
@@ -24,12 +24,57 @@ use sway_ir::{Context, *};
 use sway_types::{
     constants,
     ident::Ident,
+    integer_bits::IntegerBits,
     span::{Span, Spanned},
     state::StateIndex,
 };
 
 use std::collections::HashMap;
 
+/// Identifies an instance of a function by its span and the type IDs of its parameters and type
+/// parameters.  It's using the Sway types rather than IR types, which would be more accurate but
+/// also more fiddly.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(super) struct FnKey {
+    span: Span,
+    param_types: Vec<TypeId>,
+    type_params: Vec<TypeId>,
+}
+
+impl FnKey {
+    pub(super) fn from_fn_decl(fn_decl: &ty::TyFunctionDeclaration) -> Self {
+        FnKey {
+            span: fn_decl.span(),
+            param_types: fn_decl
+                .parameters
+                .iter()
+                .map(|p| p.type_argument.type_id)
+                .collect(),
+            type_params: fn_decl
+                .type_parameters
+                .iter()
+                .map(|tp| tp.type_id)
+                .collect(),
+        }
+    }
+
+    /// Whether `self` and `other` identify the same instance of a function, comparing their types
+    /// rather than their type IDs.  Instantiating the same function twice will often create new
+    /// type IDs for the same types.
+    fn is_same_instance(&self, other: &Self, engines: Engines<'_>) -> bool {
+        let types_eq = |lhs: &[TypeId], rhs: &[TypeId]| {
+            lhs.len() == rhs.len()
+                && lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .all(|(l, r)| engines.te().get(*l).eq(&engines.te().get(*r), engines))
+        };
+        self.span == other.span
+            && types_eq(&self.param_types, &other.param_types)
+            && types_eq(&self.type_params, &other.type_params)
+    }
+}
+
 pub(crate) struct FnCompiler<'eng> {
     type_engine: &'eng TypeEngine,
     decl_engine: &'eng DeclEngine,
@@ -41,13 +86,75 @@ pub(crate) struct FnCompiler<'eng> {
     current_fn_param: Option<ty::TyFunctionParameter>,
     returns_by_ref: bool,
     lexical_map: LexicalMap,
-    recreated_fns: HashMap<(Span, Vec<TypeId>, Vec<TypeId>), Function>,
+    recreated_fns: HashMap<FnKey, Function>,
+    // The function instances being compiled further up the call stack, including this one.
+    callers: Vec<(FnKey, Function)>,
     // This is a map from the type IDs of a logged type and the ID of the corresponding log
     logged_types_map: HashMap<TypeId, LogId>,
     // This is a map from the type IDs of a message data type and the ID of the corresponding smo
     messages_types_map: HashMap<TypeId, MessageId>,
 }
 
+/// Recursive calls are type checked before the body of the function they call, and so refer to
+/// a copy of the function's signature with an empty body.  Here we find the complete
+/// declaration such a copy was made from and instantiate it with the types of the callee.
+pub(super) fn resolve_recursive_callee(
+    type_engine: &TypeEngine,
+    decl_engine: &DeclEngine,
+    fn_ref: &DeclRefFunction,
+    callee: &ty::TyFunctionDeclaration,
+) -> ty::TyFunctionDeclaration {
+    if !callee.body.contents.is_empty() {
+        return callee.clone();
+    }
+    let engines = Engines::new(type_engine, decl_engine);
+    let full_decl = decl_engine
+        .find_all_parents(engines, fn_ref.id())
+        .iter()
+        .filter_map(|parent| DeclId::try_from(parent).ok())
+        .map(|parent_id| decl_engine.get_function(&parent_id))
+        .find(|parent| parent.span == callee.span && !parent.body.contents.is_empty());
+    match full_decl {
+        Some(mut full_decl) => {
+            let type_mapping = TypeSubstMap::from_type_parameters_and_type_arguments(
+                full_decl
+                    .type_parameters
+                    .iter()
+                    .map(|tp| tp.type_id)
+                    .collect(),
+                callee.type_parameters.iter().map(|tp| tp.type_id).collect(),
+            );
+            full_decl.subst(&type_mapping, engines);
+            full_decl
+        }
+        None => callee.clone(),
+    }
+}
+
+/// Whether `index` may be evaluated at compile time to check it against the length of the array
+/// it indexes. An index which depends on a mutable variable, e.g., the counter of a loop, or on
+/// control flow, may take a different value each time it is evaluated, and so is only checked at
+/// run time.
+fn is_const_evaluable_index(index: &ty::TyExpression) -> bool {
+    match &index.expression {
+        ty::TyExpressionVariant::Literal(_) => true,
+        ty::TyExpressionVariant::VariableExpression { mutability, .. } => {
+            *mutability == ty::VariableMutability::Immutable
+        }
+        ty::TyExpressionVariant::FunctionApplication { arguments, .. } => arguments
+            .iter()
+            .all(|(_, arg)| is_const_evaluable_index(arg)),
+        ty::TyExpressionVariant::IntrinsicFunction(kind) => {
+            kind.arguments.iter().all(is_const_evaluable_index)
+        }
+        ty::TyExpressionVariant::StructFieldAccess { prefix, .. }
+        | ty::TyExpressionVariant::TupleElemAccess { prefix, .. } => {
+            is_const_evaluable_index(prefix)
+        }
+        _ => false,
+    }
+}
+
 impl<'eng> FnCompiler<'eng> {
     pub(super) fn new(
         engines: Engines<'eng>,
@@ -57,6 +164,7 @@ impl<'eng> FnCompiler<'eng> {
         returns_by_ref: bool,
         logged_types_map: &HashMap<TypeId, LogId>,
         messages_types_map: &HashMap<TypeId, MessageId>,
+        callers: Vec<(FnKey, Function)>,
     ) -> Self {
         let (type_engine, decl_engine) = engines.unwrap();
         let lexical_map = LexicalMap::from_iter(
@@ -75,6 +183,7 @@ impl<'eng> FnCompiler<'eng> {
             lexical_map,
             returns_by_ref,
             recreated_fns: HashMap::new(),
+            callers,
             current_fn_param: None,
             logged_types_map: logged_types_map.clone(),
             messages_types_map: messages_types_map.clone(),
@@ -205,6 +314,9 @@ impl<'eng> FnCompiler<'eng> {
                         span: ast_node.span.clone(),
                     })
                 }
+                // Type aliases are resolved during type checking, so there is
+                // nothing left to compile.
+                ty::TyDeclaration::TypeAliasDeclaration { .. } => Ok(None),
             },
             ty::TyAstNodeContent::Expression(te) => {
                 // An expression with an ignored return value... I assume.
@@ -232,8 +344,17 @@ impl<'eng> FnCompiler<'eng> {
     ) -> Result<Value, CompileError> {
         let span_md_idx = md_mgr.span_to_md(context, &ast_expr.span);
         match &ast_expr.expression {
+            ty::TyExpressionVariant::Literal(Literal::String(s))
+                if matches!(
+                    self.type_engine.get(ast_expr.return_type),
+                    TypeInfo::StringSlice
+                ) =>
+            {
+                self.compile_string_slice_literal(context, s.as_str(), span_md_idx)
+            }
             ty::TyExpressionVariant::Literal(l) => {
-                Ok(convert_literal_to_value(context, l).add_metadatum(context, span_md_idx))
+                let l = resolve_u256_literal(self.type_engine, l, ast_expr.return_type);
+                Ok(convert_literal_to_value(context, &l).add_metadatum(context, span_md_idx))
             }
             ty::TyExpressionVariant::FunctionApplication {
                 call_path: name,
@@ -256,12 +377,11 @@ impl<'eng> FnCompiler<'eng> {
                         span_md_idx,
                     )
                 } else {
-                    let function_decl = self.decl_engine.get_function(fn_ref);
                     self.compile_fn_call(
                         context,
                         md_mgr,
                         arguments,
-                        &function_decl,
+                        fn_ref,
                         *self_state_idx,
                         span_md_idx,
                     )
@@ -435,6 +555,46 @@ impl<'eng> FnCompiler<'eng> {
             ty::TyExpressionVariant::Return(exp) => {
                 self.compile_return_statement(context, md_mgr, exp)
             }
+            ty::TyExpressionVariant::Closure { captures, .. } => {
+                // A closure value is the aggregate of the values it captured.
+                let captures = captures
+                    .iter()
+                    .map(|(_name, capture)| capture.clone())
+                    .collect::<Vec<_>>();
+                self.compile_tuple_expr(context, md_mgr, &captures, span_md_idx)
+            }
+            ty::TyExpressionVariant::ClosureCall { closure, arguments } => {
+                self.compile_closure_call(context, md_mgr, closure, arguments, span_md_idx)
+            }
+            ty::TyExpressionVariant::TraitObject { exp, methods } => {
+                self.compile_trait_object(context, md_mgr, exp, methods, span_md_idx)
+            }
+            ty::TyExpressionVariant::DynamicMethodCall {
+                object,
+                method_index,
+                arguments,
+                ..
+            } => self.compile_dynamic_method_call(
+                context,
+                md_mgr,
+                object,
+                *method_index,
+                arguments,
+                ast_expr.return_type,
+                span_md_idx,
+            ),
+            ty::TyExpressionVariant::ConstGenericValue { type_id, .. } => {
+                match self.type_engine.get(*type_id) {
+                    TypeInfo::ConstGeneric {
+                        value: Some(value), ..
+                    } => Ok(Constant::get_uint(context, 64, value as u64)
+                        .add_metadatum(context, span_md_idx)),
+                    _ => Err(CompileError::Internal(
+                        "Const generic parameter was not monomorphized.",
+                        ast_expr.span.clone(),
+                    )),
+                }
+            }
         }
     }
 
@@ -539,6 +699,11 @@ impl<'eng> FnCompiler<'eng> {
                 let rhs = &arguments[1];
                 let lhs_value = self.compile_expression(context, md_mgr, lhs)?;
                 let rhs_value = self.compile_expression(context, md_mgr, rhs)?;
+                if self.is_u256(lhs.return_type) {
+                    return self.compile_u256_intrinsic(
+                        context, md_mgr, kind, lhs_value, rhs_value, &span,
+                    );
+                }
                 let pred = match kind {
                     Intrinsic::Eq => Predicate::Equal,
                     Intrinsic::Gt => Predicate::GreaterThan,
@@ -759,6 +924,11 @@ impl<'eng> FnCompiler<'eng> {
                 let rhs = &arguments[1];
                 let lhs_value = self.compile_expression(context, md_mgr, lhs)?;
                 let rhs_value = self.compile_expression(context, md_mgr, rhs)?;
+                if self.is_u256(lhs.return_type) {
+                    return self.compile_u256_intrinsic(
+                        context, md_mgr, kind, lhs_value, rhs_value, &span,
+                    );
+                }
                 Ok(self
                     .current_block
                     .ins(context)
@@ -1250,6 +1420,30 @@ impl<'eng> FnCompiler<'eng> {
             &return_type,
         )?;
 
+        // A string slice is returned as the bytes it refers to, so the call itself yields a
+        // pointer to them in `$ret` while their length is in `$retl`.
+        if return_type.is_string_slice(context) {
+            let u64_ty = Type::get_uint64(context);
+            let data = self
+                .current_block
+                .ins(context)
+                .contract_call(
+                    u64_ty,
+                    ast_name.to_string(),
+                    ra_struct_val,
+                    coins,
+                    asset_id,
+                    gas,
+                )
+                .add_metadatum(context, span_md_idx);
+            let len = self
+                .current_block
+                .ins(context)
+                .read_register(sway_ir::Register::Retl)
+                .add_metadatum(context, span_md_idx);
+            return self.compile_string_slice(context, data, len, span_md_idx);
+        }
+
         // Insert the contract_call instruction
         Ok(self
             .current_block
@@ -1271,10 +1465,45 @@ impl<'eng> FnCompiler<'eng> {
         context: &mut Context,
         md_mgr: &mut MetadataManager,
         ast_args: &[(Ident, ty::TyExpression)],
-        callee: &ty::TyFunctionDeclaration,
+        fn_ref: &DeclRefFunction,
         self_state_idx: Option<StateIndex>,
         span_md_idx: Option<MetadataIndex>,
     ) -> Result<Value, CompileError> {
+        let callee = self.decl_engine.get_function(fn_ref);
+        let new_callee = self.get_or_compile_callee(context, md_mgr, fn_ref, &callee)?;
+
+        // Now actually call the new function.
+        let args = {
+            let mut args = Vec::with_capacity(ast_args.len());
+            for ((_, expr), param) in ast_args.iter().zip(callee.parameters.iter()) {
+                self.current_fn_param = Some(param.clone());
+                let arg = self.compile_expression(context, md_mgr, expr)?;
+                if arg.is_diverging(context) {
+                    return Ok(arg);
+                }
+                self.current_fn_param = None;
+                args.push(arg);
+            }
+            args
+        };
+
+        self.compile_call(
+            context,
+            md_mgr,
+            new_callee,
+            args,
+            self_state_idx,
+            span_md_idx,
+        )
+    }
+
+    fn get_or_compile_callee(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        fn_ref: &DeclRefFunction,
+        callee: &ty::TyFunctionDeclaration,
+    ) -> Result<Function, CompileError> {
         // The compiler inlines everything very lazily.  Function calls include the body of the
         // callee (i.e., the callee_body arg above). Library functions are provided in an initial
         // namespace from Forc and when the parser builds the AST (or is it during type checking?)
@@ -1289,64 +1518,60 @@ impl<'eng> FnCompiler<'eng> {
 
         // Get the callee from the cache if we've already compiled it.  We can't insert it with
         // .entry() since `compile_function()` returns a Result we need to handle.  The key to our
-        // cache, to uniquely identify a function instance, is the span and the type IDs of any
-        // args and type parameters.  It's using the Sway types rather than IR types, which would
-        // be more accurate but also more fiddly.
-        let fn_key = (
-            callee.span(),
-            callee
-                .parameters
-                .iter()
-                .map(|p| p.type_argument.type_id)
-                .collect(),
-            callee.type_parameters.iter().map(|tp| tp.type_id).collect(),
-        );
-        let new_callee = match self.recreated_fns.get(&fn_key).copied() {
-            Some(func) => func,
-            None => {
-                let callee_fn_decl = ty::TyFunctionDeclaration {
-                    type_parameters: Vec::new(),
-                    name: Ident::new(Span::from_string(format!(
-                        "{}_{}",
-                        callee.name,
-                        context.get_unique_id()
-                    ))),
-                    parameters: callee.parameters.clone(),
-                    ..callee.clone()
-                };
-                let is_entry = false;
-                let new_func = compile_function(
-                    Engines::new(self.type_engine, self.decl_engine),
-                    context,
-                    md_mgr,
-                    self.module,
-                    &callee_fn_decl,
-                    &self.logged_types_map,
-                    &self.messages_types_map,
-                    is_entry,
-                    None,
-                )?
-                .unwrap();
-                self.recreated_fns.insert(fn_key, new_func);
-                new_func
-            }
-        };
+        // cache, to uniquely identify a function instance, is a `FnKey`.
+        let engines = Engines::new(self.type_engine, self.decl_engine);
+        let fn_key = FnKey::from_fn_decl(callee);
+        if let Some(func) = self.recreated_fns.get(&fn_key).copied() {
+            return Ok(func);
+        }
 
-        // Now actually call the new function.
-        let mut args = {
-            let mut args = Vec::with_capacity(ast_args.len());
-            for ((_, expr), param) in ast_args.iter().zip(callee.parameters.iter()) {
-                self.current_fn_param = Some(param.clone());
-                let arg = self.compile_expression(context, md_mgr, expr)?;
-                if arg.is_diverging(context) {
-                    return Ok(arg);
-                }
-                self.current_fn_param = None;
-                args.push(arg);
-            }
-            args
+        // A recursive call must call the instance which is already being compiled.
+        if let Some((_, func)) = self
+            .callers
+            .iter()
+            .find(|(caller_key, _)| caller_key.is_same_instance(&fn_key, engines))
+        {
+            self.recreated_fns.insert(fn_key, *func);
+            return Ok(*func);
+        }
+
+        let callee = resolve_recursive_callee(self.type_engine, self.decl_engine, fn_ref, callee);
+        let callee_fn_decl = ty::TyFunctionDeclaration {
+            name: Ident::new(Span::from_string(format!(
+                "{}_{}",
+                callee.name,
+                context.get_unique_id()
+            ))),
+            ..callee
         };
+        let is_entry = false;
+        let new_func = compile_fn_instance(
+            engines,
+            context,
+            md_mgr,
+            self.module,
+            &callee_fn_decl,
+            &self.logged_types_map,
+            &self.messages_types_map,
+            is_entry,
+            None,
+            self.callers.clone(),
+        )?;
+        self.recreated_fns.insert(fn_key, new_func);
+        Ok(new_func)
+    }
 
+    /// Calls `new_callee` with `args`, passing the pointer for the returned
+    /// value as the last argument if the callee returns by reference.
+    fn compile_call(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        new_callee: Function,
+        mut args: Vec<Value>,
+        self_state_idx: Option<StateIndex>,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
         // If there is an 'unexpected' extra arg in the callee and it's a in/out then we need to
         // set up returning by reference.
         if args.len() + 1 == new_callee.num_args(context) {
@@ -1380,6 +1605,397 @@ impl<'eng> FnCompiler<'eng> {
             .add_metadatum(context, state_idx_md_idx))
     }
 
+    fn compile_closure_call(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        closure: &ty::TyExpression,
+        ast_args: &[ty::TyExpression],
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let (fn_ref, num_captures) = match self.type_engine.get(closure.return_type) {
+            TypeInfo::Closure { fn_ref, captures } => (fn_ref, captures),
+            _ => {
+                return Err(CompileError::Internal(
+                    "Attempted to call a value which is not a closure.",
+                    closure.span.clone(),
+                ))
+            }
+        };
+        let callee = self.decl_engine.get_function(&fn_ref);
+        let new_callee = self.get_or_compile_callee(context, md_mgr, &fn_ref, &callee)?;
+
+        // The captured values are passed before the closure's own arguments.
+        let closure_value = self.compile_expression(context, md_mgr, closure)?;
+        if closure_value.is_diverging(context) {
+            return Ok(closure_value);
+        }
+        let mut args = Vec::with_capacity(callee.parameters.len());
+        if num_captures > 0 {
+            let closure_type = convert_resolved_typeid(
+                self.type_engine,
+                self.decl_engine,
+                context,
+                &closure.return_type,
+                &closure.span,
+            )?;
+            for idx in 0..num_captures {
+                args.push(
+                    self.current_block
+                        .ins(context)
+                        .extract_value(closure_value, closure_type, vec![idx as u64])
+                        .add_metadatum(context, span_md_idx),
+                );
+            }
+        }
+        for (expr, param) in ast_args
+            .iter()
+            .zip(callee.parameters[num_captures..].iter())
+        {
+            self.current_fn_param = Some(param.clone());
+            let arg = self.compile_expression(context, md_mgr, expr)?;
+            if arg.is_diverging(context) {
+                return Ok(arg);
+            }
+            self.current_fn_param = None;
+            args.push(arg);
+        }
+
+        self.compile_call(context, md_mgr, new_callee, args, None, span_md_idx)
+    }
+
+    /// A trait object is a pair of a data word and a pointer to its vtable.
+    /// The data word holds a copy type value as is, and otherwise points to a
+    /// copy of the value on the heap, which is how methods take such values as
+    /// their receiver.
+    fn is_u256(&self, type_id: TypeId) -> bool {
+        matches!(
+            self.type_engine.get(type_id),
+            TypeInfo::UnsignedInteger(IntegerBits::V256)
+        )
+    }
+
+    /// Compiles an arithmetic, bitwise or comparison intrinsic on two `u256` operands to an ASM
+    /// block, as the IR has no instructions working on `u256` values.
+    fn compile_u256_intrinsic(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        kind: &Intrinsic,
+        lhs_value: Value,
+        rhs_value: Value,
+        span: &Span,
+    ) -> Result<Value, CompileError> {
+        let span_md_idx = md_mgr.span_to_md(context, span);
+        let block = lower_u256_intrinsic(kind, span_md_idx).ok_or_else(|| {
+            CompileError::Internal("Unsupported intrinsic on u256 operands.", span.clone())
+        })?;
+
+        let asm_arg = |name: &str, initializer: Option<Value>| AsmArg {
+            name: Ident::new_no_span(name.to_string()),
+            initializer,
+        };
+        let mut args = vec![
+            asm_arg("lhs", Some(lhs_value)),
+            asm_arg("rhs", Some(rhs_value)),
+        ];
+        let ret_ty = if block.returns_u256 {
+            let u256_ty = Type::get_uint256(context);
+            let temp_name = self.lexical_map.insert_anon();
+            let res_var = self
+                .function
+                .new_local_var(context, temp_name, u256_ty, None)
+                .map_err(|ir_error| {
+                    CompileError::InternalOwned(ir_error.to_string(), Span::dummy())
+                })?;
+            let res = self
+                .current_block
+                .ins(context)
+                .get_local(res_var)
+                .add_metadatum(context, span_md_idx);
+            args.push(asm_arg("res", Some(res)));
+            u256_ty
+        } else {
+            Type::get_bool(context)
+        };
+        args.extend(
+            block
+                .scratch_registers
+                .iter()
+                .map(|name| asm_arg(name, None)),
+        );
+        Ok(self
+            .current_block
+            .ins(context)
+            .asm_block(
+                args,
+                block.ops,
+                ret_ty,
+                Some(Ident::new_no_span(block.ret_reg.to_string())),
+            )
+            .add_metadatum(context, span_md_idx))
+    }
+
+    /// A string slice literal is a pointer to the bytes of a string constant, followed by its
+    /// length.
+    fn compile_string_slice_literal(
+        &mut self,
+        context: &mut Context,
+        s: &str,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let data = Constant::get_string(context, s.as_bytes().to_vec());
+        let len = Constant::get_uint(context, 64, s.len() as u64);
+        self.compile_string_slice(context, data, len, span_md_idx)
+    }
+
+    /// Builds a string slice out of a pointer to its bytes and their length.
+    fn compile_string_slice(
+        &mut self,
+        context: &mut Context,
+        data: Value,
+        len: Value,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let slice_ty = Type::get_string_slice(context);
+        let temp_name = self.lexical_map.insert_anon();
+        let slice_var = self
+            .function
+            .new_local_var(context, temp_name, slice_ty, None)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+        let slice = self
+            .current_block
+            .ins(context)
+            .get_local(slice_var)
+            .add_metadatum(context, span_md_idx);
+
+        let asm_arg = |name: &str, initializer: Value| AsmArg {
+            name: Ident::new_no_span(name.to_string()),
+            initializer: Some(initializer),
+        };
+        let store_word = |arg: &str, offset: &str| AsmInstruction {
+            name: Ident::new_no_span("sw".into()),
+            args: vec![
+                Ident::new_no_span("ptr".into()),
+                Ident::new_no_span(arg.into()),
+            ],
+            immediate: Some(Ident::new_no_span(offset.into())),
+            metadata: span_md_idx,
+        };
+        Ok(self
+            .current_block
+            .ins(context)
+            .asm_block(
+                vec![
+                    asm_arg("ptr", slice),
+                    asm_arg("data", data),
+                    asm_arg("len", len),
+                ],
+                vec![store_word("data", "i0"), store_word("len", "i1")],
+                slice_ty,
+                Some(Ident::new_no_span("ptr".into())),
+            )
+            .add_metadatum(context, span_md_idx))
+    }
+
+    fn compile_trait_object(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        exp: &ty::TyExpression,
+        methods: &[DeclRefFunction],
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let value = self.compile_expression(context, md_mgr, exp)?;
+        if value.is_diverging(context) {
+            return Ok(value);
+        }
+
+        let u64_ty = Type::get_uint64(context);
+        let data = match self.type_engine.get(exp.return_type) {
+            TypeInfo::Tuple(fields) if fields.is_empty() => Constant::get_uint(context, 64, 0),
+            type_info if type_info.is_copy_type() => self
+                .current_block
+                .ins(context)
+                .bitcast(value, u64_ty)
+                .add_metadatum(context, span_md_idx),
+            _ => {
+                let value_ty = convert_resolved_typeid(
+                    self.type_engine,
+                    self.decl_engine,
+                    context,
+                    &exp.return_type,
+                    &exp.span,
+                )?;
+                let size = ir_type_size_in_bytes(context, &value_ty);
+                let size_arg = Constant::get_uint(context, 64, size);
+                let alloc_size_arg = Constant::get_uint(context, 64, size + 1);
+                let asm_arg = |name: &str, initializer: Option<Value>| AsmArg {
+                    name: Ident::new_no_span(name.to_string()),
+                    initializer,
+                };
+                let asm_op = |name: &str, args: &[&str], immediate: Option<&str>| AsmInstruction {
+                    name: Ident::new_no_span(name.to_string()),
+                    args: args
+                        .iter()
+                        .map(|arg| Ident::new_no_span(arg.to_string()))
+                        .collect(),
+                    immediate: immediate.map(|imm| Ident::new_no_span(imm.to_string())),
+                    metadata: span_md_idx,
+                };
+                self.current_block
+                    .ins(context)
+                    .asm_block(
+                        vec![
+                            asm_arg("src", Some(value)),
+                            asm_arg("size", Some(size_arg)),
+                            asm_arg("alloc_size", Some(alloc_size_arg)),
+                            asm_arg("ptr", None),
+                        ],
+                        vec![
+                            asm_op("aloc", &["alloc_size"], None),
+                            asm_op("addi", &["ptr", "hp"], Some("i1")),
+                            asm_op("mcp", &["ptr", "src", "size"], None),
+                        ],
+                        u64_ty,
+                        Some(Ident::new_no_span("ptr".to_string())),
+                    )
+                    .add_metadatum(context, span_md_idx)
+            }
+        };
+
+        let mut functions = Vec::with_capacity(methods.len());
+        for method in methods {
+            let callee = self.decl_engine.get_function(method);
+            functions.push(self.get_or_compile_callee(context, md_mgr, method, &callee)?);
+        }
+        let vtable = self
+            .current_block
+            .ins(context)
+            .vtable(functions)
+            .add_metadatum(context, span_md_idx);
+
+        let trait_object_ty = Type::new_struct(context, vec![u64_ty, u64_ty]);
+        let temp_name = self.lexical_map.insert_anon();
+        let trait_object_var = self
+            .function
+            .new_local_var(context, temp_name, trait_object_ty, None)
+            .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
+        let trait_object = self
+            .current_block
+            .ins(context)
+            .get_local(trait_object_var)
+            .add_metadatum(context, span_md_idx);
+        let trait_object = self
+            .current_block
+            .ins(context)
+            .insert_value(trait_object, trait_object_ty, data, vec![0])
+            .add_metadatum(context, span_md_idx);
+        Ok(self
+            .current_block
+            .ins(context)
+            .insert_value(trait_object, trait_object_ty, vtable, vec![1])
+            .add_metadatum(context, span_md_idx))
+    }
+
+    /// Calls the method at `method_index` in the vtable of `object`, passing
+    /// the data word of `object` as the receiver.
+    #[allow(clippy::too_many_arguments)]
+    fn compile_dynamic_method_call(
+        &mut self,
+        context: &mut Context,
+        md_mgr: &mut MetadataManager,
+        object: &ty::TyExpression,
+        method_index: usize,
+        ast_args: &[ty::TyExpression],
+        return_type: TypeId,
+        span_md_idx: Option<MetadataIndex>,
+    ) -> Result<Value, CompileError> {
+        let object_value = self.compile_expression(context, md_mgr, object)?;
+        if object_value.is_diverging(context) {
+            return Ok(object_value);
+        }
+        let object_ty = convert_resolved_typeid(
+            self.type_engine,
+            self.decl_engine,
+            context,
+            &object.return_type,
+            &object.span,
+        )?;
+        let data = self
+            .current_block
+            .ins(context)
+            .extract_value(object_value, object_ty, vec![0])
+            .add_metadatum(context, span_md_idx);
+        let vtable = self
+            .current_block
+            .ins(context)
+            .extract_value(object_value, object_ty, vec![1])
+            .add_metadatum(context, span_md_idx);
+
+        // The vtable is a sequence of words holding the method addresses.
+        let u64_ty = Type::get_uint64(context);
+        let fn_addr = self
+            .current_block
+            .ins(context)
+            .asm_block(
+                vec![
+                    AsmArg {
+                        name: Ident::new_no_span("vtable".to_string()),
+                        initializer: Some(vtable),
+                    },
+                    AsmArg {
+                        name: Ident::new_no_span("fn_addr".to_string()),
+                        initializer: None,
+                    },
+                ],
+                vec![AsmInstruction {
+                    name: Ident::new_no_span("lw".to_string()),
+                    args: vec![
+                        Ident::new_no_span("fn_addr".to_string()),
+                        Ident::new_no_span("vtable".to_string()),
+                    ],
+                    immediate: Some(Ident::new_no_span(format!("i{method_index}"))),
+                    metadata: span_md_idx,
+                }],
+                u64_ty,
+                Some(Ident::new_no_span("fn_addr".to_string())),
+            )
+            .add_metadatum(context, span_md_idx);
+
+        let mut args = Vec::with_capacity(ast_args.len() + 2);
+        args.push(data);
+        for expr in ast_args {
+            let arg = self.compile_expression(context, md_mgr, expr)?;
+            if arg.is_diverging(context) {
+                return Ok(arg);
+            }
+            args.push(arg);
+        }
+
+        let ret_ty = convert_resolved_typeid_no_span(
+            self.type_engine,
+            self.decl_engine,
+            context,
+            &return_type,
+        )?;
+        // Methods returning a value by reference take a pointer to it as
+        // their last argument, as in `compile_call`.
+        if !self.type_engine.get(return_type).is_copy_type() {
+            let local_name = format!("__ret_val_dyn_{method_index}");
+            let local_ptr = self
+                .function
+                .new_unique_local_var(context, local_name, ret_ty, None);
+            args.push(self.current_block.ins(context).get_local(local_ptr));
+        }
+
+        Ok(self
+            .current_block
+            .ins(context)
+            .call_indirect(fn_addr, &args, ret_ty)
+            .add_metadatum(context, span_md_idx))
+    }
+
     fn compile_if(
         &mut self,
         context: &mut Context,
@@ -2093,25 +2709,27 @@ impl<'eng> FnCompiler<'eng> {
 
         let index_expr_span = index_expr.span.clone();
 
-        if let Ok(Constant {
-            value: ConstantValue::Uint(constant_value),
-            ..
-        }) = compile_constant_expression_to_constant(
-            Engines::new(self.type_engine, self.decl_engine),
-            context,
-            md_mgr,
-            self.module,
-            None,
-            Some(self),
-            index_expr,
-        ) {
-            let count = aggregate.get_array_len(context).unwrap();
-            if constant_value >= count {
-                return Err(CompileError::ArrayOutOfBounds {
-                    index: constant_value,
-                    count,
-                    span: index_expr_span,
-                });
+        if is_const_evaluable_index(index_expr) {
+            if let Ok(Constant {
+                value: ConstantValue::Uint(constant_value),
+                ..
+            }) = compile_constant_expression_to_constant(
+                Engines::new(self.type_engine, self.decl_engine),
+                context,
+                md_mgr,
+                self.module,
+                None,
+                Some(self),
+                index_expr,
+            ) {
+                let count = aggregate.get_array_len(context).unwrap();
+                if constant_value >= count {
+                    return Err(CompileError::ArrayOutOfBounds {
+                        index: constant_value,
+                        count,
+                        span: index_expr_span,
+                    });
+                }
             }
         }
 
@@ -2584,7 +3202,7 @@ impl<'eng> FnCompiler<'eng> {
                         "Arrays in storage have not been implemented yet.",
                         Span::dummy(),
                     )),
-                    TypeContent::Slice => Err(CompileError::Internal(
+                    TypeContent::Slice | TypeContent::StringSlice => Err(CompileError::Internal(
                         "Slices in storage have not been implemented yet.",
                         Span::dummy(),
                     )),
@@ -2594,7 +3212,7 @@ impl<'eng> FnCompiler<'eng> {
                     TypeContent::Bool | TypeContent::Uint(_) => {
                         self.compile_uint_or_bool_storage_read(context, &key_val, ty, span_md_idx)
                     }
-                    TypeContent::String(_) | TypeContent::Union(_) => self
+                    TypeContent::U256 | TypeContent::String(_) | TypeContent::Union(_) => self
                         .compile_union_or_string_storage_read(
                             context,
                             ix,
@@ -2693,7 +3311,7 @@ impl<'eng> FnCompiler<'eng> {
                         "Arrays in storage have not been implemented yet.",
                         Span::dummy(),
                     )),
-                    TypeContent::Slice => Err(CompileError::Internal(
+                    TypeContent::Slice | TypeContent::StringSlice => Err(CompileError::Internal(
                         "Slices in storage have not been implemented yet.",
                         Span::dummy(),
                     )),
@@ -2708,7 +3326,7 @@ impl<'eng> FnCompiler<'eng> {
                     TypeContent::Bool | TypeContent::Uint(_) => {
                         self.compile_uint_or_bool_storage_write(context, &key_val, rhs, span_md_idx)
                     }
-                    TypeContent::String(_) | TypeContent::Union(_) => self
+                    TypeContent::U256 | TypeContent::String(_) | TypeContent::Union(_) => self
                         .compile_union_or_string_storage_write(
                             context,
                             ix,
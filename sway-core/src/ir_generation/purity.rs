@@ -31,6 +31,10 @@ pub(crate) fn check_function_purity(
     md_mgr: &mut MetadataManager,
     function: &Function,
 ) -> (bool, bool) {
+    // Recursive calls back into this function, which is still being checked, add nothing to what
+    // its own instructions do.
+    env.memos.insert(*function, (false, false));
+
     // Iterate for each instruction in the function and gather whether we have read and/or
     // write storage operations:
     // - via the storage IR instructions,
@@ -98,6 +98,12 @@ pub fn serialize_to_storage_slots(
                 ),
             )]
         }
+        ConstantValue::U256(b) if ty.is_uint256(context) => {
+            vec![StorageSlot::new(
+                get_storage_key(ix, indices),
+                Bytes32::new(*b),
+            )]
+        }
         ConstantValue::B256(b) if ty.is_b256(context) => {
             vec![StorageSlot::new(
                 get_storage_key(ix, indices),
@@ -177,6 +183,9 @@ pub fn serialize_to_words(constant: &Constant, context: &Context, ty: &Type) ->
         ConstantValue::Uint(n) if ty.is_uint(context) => {
             vec![Bytes8::new(n.to_be_bytes())]
         }
+        ConstantValue::U256(b) if ty.is_uint256(context) => {
+            Vec::from_iter((0..4).map(|i| Bytes8::new(b[8 * i..8 * i + 8].try_into().unwrap())))
+        }
         ConstantValue::B256(b) if ty.is_b256(context) => {
             Vec::from_iter((0..4).map(|i| Bytes8::new(b[8 * i..8 * i + 8].try_into().unwrap())))
         }
@@ -0,0 +1,223 @@
+//! Lowering of the arithmetic, bitwise and comparison intrinsics on `u256` values.
+//!
+//! A `u256` doesn't fit in a register and is passed by reference, like a `b256`.  In memory it is
+//! four big-endian words, the most significant word first.  The Fuel VM targeted here has no wide
+//! math instructions, so each operation is expanded into an ASM block which works on one word at
+//! a time.  Additions, subtractions and multiplications run with the `F_WRAPPING` flag set and
+//! derive carries and borrows explicitly.  Once the original flags are restored, an overflow of
+//! the full 256 bit result is turned into a regular arithmetic overflow panic.
+
+use sway_ast::Intrinsic;
+use sway_ir::{AsmInstruction, MetadataIndex};
+use sway_types::Ident;
+
+/// Registers with a fixed meaning within the ASM blocks built here.  Any other register used by
+/// the instructions is a scratch register.
+const LHS: &str = "lhs";
+const RHS: &str = "rhs";
+const RES: &str = "res";
+const RESERVED: [&str; 4] = ["zero", "one", "of", "flag"];
+
+/// The `F_WRAPPING` bit of the `$flag` register.
+const F_WRAPPING: u64 = 2;
+
+/// The words of a `u256` in memory, least significant word first.
+const LIMBS: [u64; 4] = [3, 2, 1, 0];
+
+/// The body of an ASM block implementing a `u256` intrinsic.
+///
+/// The block expects `lhs` and `rhs` to be initialized with pointers to the operands.  If
+/// `returns_u256` is set the block also expects `res` to point to memory for the result, and
+/// returns it.  Otherwise the block returns a `bool` in `ret_reg`.
+pub(super) struct U256AsmBlock {
+    pub(super) ops: Vec<AsmInstruction>,
+    pub(super) scratch_registers: Vec<&'static str>,
+    pub(super) returns_u256: bool,
+    pub(super) ret_reg: &'static str,
+}
+
+struct Builder {
+    ops: Vec<AsmInstruction>,
+    scratch_registers: Vec<&'static str>,
+    span_md_idx: Option<MetadataIndex>,
+}
+
+impl Builder {
+    fn op(&mut self, name: &str, args: &[&'static str], imm: Option<u64>) {
+        for arg in args {
+            if ![LHS, RHS, RES].contains(arg)
+                && !RESERVED.contains(arg)
+                && !self.scratch_registers.contains(arg)
+            {
+                self.scratch_registers.push(arg);
+            }
+        }
+        self.ops.push(AsmInstruction {
+            name: Ident::new_no_span(name.to_string()),
+            args: args
+                .iter()
+                .map(|arg| Ident::new_no_span(arg.to_string()))
+                .collect(),
+            immediate: imm.map(|imm| Ident::new_no_span(format!("i{imm}"))),
+            metadata: self.span_md_idx,
+        });
+    }
+
+    /// Saves the flags in `saved` and enables wrapping arithmetic.
+    fn enable_wrapping(&mut self) {
+        self.op("move", &["saved", "flag"], None);
+        self.op("movi", &["wrap"], Some(F_WRAPPING));
+        self.op("or", &["wrap", "wrap", "saved"], None);
+        self.op("flag", &["wrap"], None);
+    }
+
+    /// Restores the flags saved by `enable_wrapping()` and panics with an arithmetic overflow if
+    /// `overflow` isn't zero, unless wrapping was enabled by the caller already.
+    fn restore_flags_and_check(&mut self, overflow: &'static str) {
+        self.op("flag", &["saved"], None);
+        self.op("not", &["max", "zero"], None);
+        self.op("add", &["max", "max", overflow], None);
+    }
+
+    fn finish(self, returns_u256: bool, ret_reg: &'static str) -> U256AsmBlock {
+        U256AsmBlock {
+            ops: self.ops,
+            scratch_registers: self.scratch_registers,
+            returns_u256,
+            ret_reg,
+        }
+    }
+}
+
+/// Returns the ASM block implementing `kind` on two `u256` operands, or `None` if the intrinsic
+/// isn't supported on `u256`.
+pub(super) fn lower_u256_intrinsic(
+    kind: &Intrinsic,
+    span_md_idx: Option<MetadataIndex>,
+) -> Option<U256AsmBlock> {
+    let mut b = Builder {
+        ops: Vec::new(),
+        scratch_registers: Vec::new(),
+        span_md_idx,
+    };
+    Some(match kind {
+        Intrinsic::Add => {
+            b.enable_wrapping();
+            b.op("move", &["carry", "zero"], None);
+            for word in LIMBS {
+                b.op("lw", &["a", LHS], Some(word));
+                b.op("lw", &["b", RHS], Some(word));
+                b.op("add", &["sum", "a", "b"], None);
+                b.op("lt", &["c1", "sum", "a"], None);
+                b.op("add", &["sum", "sum", "carry"], None);
+                b.op("lt", &["c2", "sum", "carry"], None);
+                b.op("add", &["carry", "c1", "c2"], None);
+                b.op("sw", &[RES, "sum"], Some(word));
+            }
+            b.restore_flags_and_check("carry");
+            b.finish(true, RES)
+        }
+        Intrinsic::Sub => {
+            b.enable_wrapping();
+            b.op("move", &["borrow", "zero"], None);
+            for word in LIMBS {
+                b.op("lw", &["a", LHS], Some(word));
+                b.op("lw", &["b", RHS], Some(word));
+                b.op("sub", &["diff", "a", "b"], None);
+                b.op("lt", &["b1", "a", "b"], None);
+                b.op("lt", &["b2", "diff", "borrow"], None);
+                b.op("sub", &["diff", "diff", "borrow"], None);
+                b.op("add", &["borrow", "b1", "b2"], None);
+                b.op("sw", &[RES, "diff"], Some(word));
+            }
+            b.restore_flags_and_check("borrow");
+            b.finish(true, RES)
+        }
+        Intrinsic::Mul => {
+            // Schoolbook multiplication.  With wrapping enabled `mul` leaves the high word of the
+            // 128 bit product in `$of`.  Any product or carry which lands beyond the fourth word
+            // is an overflow.
+            const RHS_WORDS: [&str; 4] = ["b0", "b1", "b2", "b3"];
+            const RES_WORDS: [&str; 4] = ["r0", "r1", "r2", "r3"];
+            b.enable_wrapping();
+            for (rhs_word, word) in RHS_WORDS.into_iter().zip(LIMBS) {
+                b.op("lw", &[rhs_word, RHS], Some(word));
+            }
+            for res_word in RES_WORDS {
+                b.op("move", &[res_word, "zero"], None);
+            }
+            b.op("move", &["overflow", "zero"], None);
+            for (i, word) in LIMBS.into_iter().enumerate() {
+                b.op("lw", &["a", LHS], Some(word));
+                b.op("move", &["carry", "zero"], None);
+                for (j, rhs_word) in RHS_WORDS.into_iter().enumerate() {
+                    b.op("mul", &["lo", "a", rhs_word], None);
+                    b.op("move", &["hi", "of"], None);
+                    match RES_WORDS.get(i + j) {
+                        Some(res_word) => {
+                            b.op("add", &[res_word, res_word, "lo"], None);
+                            b.op("lt", &["c1", res_word, "lo"], None);
+                            b.op("add", &[res_word, res_word, "carry"], None);
+                            b.op("lt", &["c2", res_word, "carry"], None);
+                            b.op("add", &["carry", "hi", "c1"], None);
+                            b.op("add", &["carry", "carry", "c2"], None);
+                        }
+                        None => {
+                            b.op("or", &["overflow", "overflow", "lo"], None);
+                            b.op("or", &["overflow", "overflow", "hi"], None);
+                        }
+                    }
+                }
+                b.op("or", &["overflow", "overflow", "carry"], None);
+            }
+            for (res_word, word) in RES_WORDS.into_iter().zip(LIMBS) {
+                b.op("sw", &[RES, res_word], Some(word));
+            }
+            b.restore_flags_and_check("overflow");
+            b.finish(true, RES)
+        }
+        Intrinsic::And | Intrinsic::Or | Intrinsic::Xor => {
+            let op = match kind {
+                Intrinsic::And => "and",
+                Intrinsic::Or => "or",
+                _ => "xor",
+            };
+            for word in LIMBS {
+                b.op("lw", &["a", LHS], Some(word));
+                b.op("lw", &["b", RHS], Some(word));
+                b.op(op, &["a", "a", "b"], None);
+                b.op("sw", &[RES, "a"], Some(word));
+            }
+            b.finish(true, RES)
+        }
+        Intrinsic::Eq => {
+            b.op("movi", &["len"], Some(32));
+            b.op("meq", &["is_eq", LHS, RHS, "len"], None);
+            b.finish(false, "is_eq")
+        }
+        Intrinsic::Gt | Intrinsic::Lt => {
+            // Compare the words from the most significant one down, and take the result of the
+            // first pair of words which differ.
+            let (x, y) = if matches!(kind, Intrinsic::Gt) {
+                (LHS, RHS)
+            } else {
+                (RHS, LHS)
+            };
+            b.op("move", &["is_gt", "zero"], None);
+            b.op("move", &["decided", "zero"], None);
+            for word in LIMBS.into_iter().rev() {
+                b.op("lw", &["a", x], Some(word));
+                b.op("lw", &["b", y], Some(word));
+                b.op("gt", &["g", "a", "b"], None);
+                b.op("lt", &["l", "a", "b"], None);
+                b.op("eq", &["undecided", "decided", "zero"], None);
+                b.op("and", &["g", "g", "undecided"], None);
+                b.op("or", &["is_gt", "is_gt", "g"], None);
+                b.op("or", &["decided", "decided", "g"], None);
+                b.op("or", &["decided", "decided", "l"], None);
+            }
+            b.finish(false, "is_gt")
+        }
+        _ => return None,
+    })
+}
@@ -1,4 +1,4 @@
-use crate::transform;
+use crate::{transform, type_system::TypeParameter};
 
 use super::{FunctionDeclaration, Supertrait, TraitItem};
 
@@ -10,6 +10,9 @@ use sway_types::{ident::Ident, span::Span};
 pub struct AbiDeclaration {
     /// The name of the abi trait (also known as a "contract trait")
     pub name: Ident,
+    /// The type parameters of a generic ABI, which implementations and
+    /// callers of the ABI set to concrete types
+    pub type_parameters: Vec<TypeParameter>,
     /// The methods a contract is required to implement in order opt in to this interface
     pub interface_surface: Vec<TraitItem>,
     pub supertraits: Vec<Supertrait>,
@@ -1,4 +1,4 @@
-use super::FunctionDeclaration;
+use super::{ConstantDeclaration, FunctionDeclaration, TraitTypeDeclaration};
 use crate::{language::CallPath, type_system::TypeParameter, TypeArgument};
 
 use sway_types::span::Span;
@@ -6,6 +6,8 @@ use sway_types::span::Span;
 #[derive(Debug, Clone)]
 pub enum ImplItem {
     Fn(FunctionDeclaration),
+    Constant(ConstantDeclaration),
+    Type(TraitTypeDeclaration),
 }
 
 #[derive(Debug, Clone)]
@@ -1,6 +1,6 @@
 use std::hash::{Hash, Hasher};
 
-use super::{FunctionDeclaration, FunctionParameter};
+use super::{ConstantDeclaration, FunctionDeclaration, FunctionParameter};
 
 use crate::{
     decl_engine::DeclRefTrait, engine_threading::*, language::*, transform, type_system::*,
@@ -10,6 +10,8 @@ use sway_types::{ident::Ident, span::Span, Spanned};
 #[derive(Debug, Clone)]
 pub enum TraitItem {
     TraitFn(TraitFn),
+    Constant(ConstantDeclaration),
+    Type(TraitTypeDeclaration),
 }
 
 #[derive(Debug, Clone)]
@@ -68,3 +70,13 @@ pub struct TraitFn {
     pub return_type: TypeInfo,
     pub return_type_span: Span,
 }
+
+/// An associated type, e.g. `type Item;` in a trait or `type Item = u64;` in
+/// an impl block.
+#[derive(Debug, Clone)]
+pub struct TraitTypeDeclaration {
+    pub name: Ident,
+    pub attributes: transform::AttributesMap,
+    pub ty_opt: Option<TypeArgument>,
+    pub span: Span,
+}
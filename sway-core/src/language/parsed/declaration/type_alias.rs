@@ -0,0 +1,12 @@
+use crate::{language::Visibility, transform, type_system::TypeParameter, TypeArgument};
+use sway_types::{ident::Ident, span::Span};
+
+#[derive(Debug, Clone)]
+pub struct TypeAliasDeclaration {
+    pub name: Ident,
+    pub attributes: transform::AttributesMap,
+    pub type_parameters: Vec<TypeParameter>,
+    pub ty: TypeArgument,
+    pub visibility: Visibility,
+    pub span: Span,
+}
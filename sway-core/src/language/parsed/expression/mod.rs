@@ -1,6 +1,6 @@
 use crate::{
     language::{parsed::CodeBlock, *},
-    type_system::TypeBinding,
+    type_system::{TypeArgument, TypeBinding},
 };
 use sway_types::{ident::Ident, Span, Spanned};
 
@@ -64,6 +64,10 @@ pub struct IfExpression {
 pub struct MatchExpression {
     pub value: Box<Expression>,
     pub branches: Vec<MatchBranch>,
+    /// Whether the match was desugared from a pattern which must be irrefutable, such as the
+    /// pattern of a `let` statement. Non-exhaustive irrefutable matches are reported as
+    /// refutable patterns.
+    pub irrefutable: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -117,6 +121,8 @@ pub struct DelineatedPathExpression {
 #[derive(Debug, Clone)]
 pub struct AbiCastExpression {
     pub abi_name: CallPath,
+    /// The type arguments of a generic ABI, as in `abi(Token<Meta>, address)`.
+    pub type_arguments: Vec<TypeArgument>,
     pub address: Box<Expression>,
 }
 
@@ -144,6 +150,21 @@ pub struct WhileLoopExpression {
     pub body: CodeBlock,
 }
 
+#[derive(Debug, Clone)]
+pub struct ClosureExpression {
+    pub parameters: Vec<ClosureParameter>,
+    pub body: Box<Expression>,
+}
+
+/// A parameter of a [ClosureExpression]. Parameters declared without a type
+/// have their `type_argument` set to [TypeInfo::Unknown](crate::TypeInfo::Unknown)
+/// and are inferred during type checking.
+#[derive(Debug, Clone)]
+pub struct ClosureParameter {
+    pub name: Ident,
+    pub type_argument: TypeArgument,
+}
+
 #[derive(Debug, Clone)]
 pub struct ReassignmentExpression {
     pub lhs: ReassignmentTarget,
@@ -215,6 +236,8 @@ pub enum ExpressionKind {
     Continue,
     Reassignment(ReassignmentExpression),
     Return(Box<Expression>),
+    /// A closure expression, e.g. `|x: u64| x + offset`.
+    Closure(Box<ClosureExpression>),
 }
 
 /// Represents the left hand side of a reassignment, which could either be a regular variable
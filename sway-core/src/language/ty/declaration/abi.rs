@@ -10,6 +10,7 @@ use super::{TyTraitInterfaceItem, TyTraitItem};
 pub struct TyAbiDeclaration {
     /// The name of the abi trait (also known as a "contract trait")
     pub name: Ident,
+    pub type_parameters: Vec<TypeParameter>,
     /// The methods a contract is required to implement in order opt in to this interface
     pub interface_surface: Vec<TyTraitInterfaceItem>,
     pub supertraits: Vec<parsed::Supertrait>,
@@ -23,6 +24,7 @@ impl PartialEqWithEngines for TyAbiDeclaration {
     fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
         let TyAbiDeclaration {
             name: ln,
+            type_parameters: ltp,
             interface_surface: lis,
             supertraits: ls,
             items: li,
@@ -33,6 +35,7 @@ impl PartialEqWithEngines for TyAbiDeclaration {
         } = self;
         let TyAbiDeclaration {
             name: rn,
+            type_parameters: rtp,
             interface_surface: ris,
             supertraits: rs,
             items: ri,
@@ -41,7 +44,11 @@ impl PartialEqWithEngines for TyAbiDeclaration {
             attributes: _,
             span: _,
         } = other;
-        ln == rn && lis.eq(ris, engines) && li.eq(ri, engines) && ls.eq(rs, engines)
+        ln == rn
+            && ltp.eq(rtp, engines)
+            && lis.eq(ris, engines)
+            && li.eq(ri, engines)
+            && ls.eq(rs, engines)
     }
 }
 
@@ -49,6 +56,7 @@ impl HashWithEngines for TyAbiDeclaration {
     fn hash<H: Hasher>(&self, state: &mut H, engines: Engines<'_>) {
         let TyAbiDeclaration {
             name,
+            type_parameters,
             interface_surface,
             items,
             supertraits,
@@ -58,6 +66,7 @@ impl HashWithEngines for TyAbiDeclaration {
             span: _,
         } = self;
         name.hash(state);
+        type_parameters.hash(state, engines);
         interface_surface.hash(state, engines);
         items.hash(state, engines);
         supertraits.hash(state, engines);
@@ -70,6 +79,16 @@ impl CreateTypeId for TyAbiDeclaration {
         let decl_engine = engines.de();
         let ty = TypeInfo::ContractCaller {
             abi_name: AbiName::Known(self.name.clone().into()),
+            type_arguments: self
+                .type_parameters
+                .iter()
+                .map(|type_param| TypeArgument {
+                    type_id: type_param.type_id,
+                    initial_type_id: type_param.initial_type_id,
+                    span: type_param.name_ident.span(),
+                    call_path_tree: None,
+                })
+                .collect(),
             address: None,
         };
         type_engine.insert(decl_engine, ty)
@@ -87,3 +106,55 @@ impl Named for TyAbiDeclaration {
         &self.name
     }
 }
+
+impl SubstTypes for TyAbiDeclaration {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: Engines<'_>) {
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.subst(type_mapping, engines));
+        self.interface_surface
+            .iter_mut()
+            .for_each(|item| match item {
+                TyTraitInterfaceItem::TraitFn(item_ref) => {
+                    let new_item_ref = item_ref
+                        .clone()
+                        .subst_types_and_insert_new_with_parent(type_mapping, engines);
+                    item_ref.replace_id((&new_item_ref).into());
+                }
+                TyTraitInterfaceItem::Constant(item_ref) => {
+                    let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                    item_ref.replace_id((&new_item_ref).into());
+                }
+                TyTraitInterfaceItem::Type(item_ref) => {
+                    let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                    item_ref.replace_id((&new_item_ref).into());
+                }
+            });
+        self.items.iter_mut().for_each(|item| match item {
+            TyTraitItem::Fn(item_ref) => {
+                let new_item_ref = item_ref
+                    .clone()
+                    .subst_types_and_insert_new_with_parent(type_mapping, engines);
+                item_ref.replace_id((&new_item_ref).into());
+            }
+            TyTraitItem::Constant(item_ref) => {
+                let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                item_ref.replace_id((&new_item_ref).into());
+            }
+            TyTraitItem::Type(item_ref) => {
+                let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                item_ref.replace_id((&new_item_ref).into());
+            }
+        });
+    }
+}
+
+impl MonomorphizeHelper for TyAbiDeclaration {
+    fn name(&self) -> &Ident {
+        &self.name
+    }
+
+    fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+}
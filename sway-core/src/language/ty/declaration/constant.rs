@@ -63,3 +63,21 @@ impl Spanned for TyConstantDeclaration {
         self.span.clone()
     }
 }
+
+impl SubstTypes for TyConstantDeclaration {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: Engines<'_>) {
+        self.type_ascription.subst(type_mapping, engines);
+        if let Some(value) = self.value.as_mut() {
+            value.subst(type_mapping, engines);
+        }
+    }
+}
+
+impl ReplaceSelfType for TyConstantDeclaration {
+    fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
+        self.type_ascription.replace_self_type(engines, self_type);
+        if let Some(value) = self.value.as_mut() {
+            value.replace_self_type(engines, self_type);
+        }
+    }
+}
@@ -68,6 +68,11 @@ pub enum TyDeclaration {
         decl_id: DeclId<TyStorageDeclaration>,
         decl_span: Span,
     },
+    TypeAliasDeclaration {
+        name: Ident,
+        decl_id: DeclId<TyTypeAliasDeclaration>,
+        decl_span: Span,
+    },
 }
 
 impl EqWithEngines for TyDeclaration {}
@@ -168,6 +173,18 @@ impl PartialEqWithEngines for TyDeclaration {
                 Self::StorageDeclaration { decl_id: lid, .. },
                 Self::StorageDeclaration { decl_id: rid, .. },
             ) => decl_engine.get(*lid).eq(&decl_engine.get(*rid), engines),
+            (
+                Self::TypeAliasDeclaration {
+                    name: ln,
+                    decl_id: lid,
+                    ..
+                },
+                Self::TypeAliasDeclaration {
+                    name: rn,
+                    decl_id: rid,
+                    ..
+                },
+            ) => ln == rn && decl_engine.get(*lid).eq(&decl_engine.get(*rid), engines),
             (
                 Self::GenericTypeForFunctionScope {
                     name: xn,
@@ -218,6 +235,9 @@ impl HashWithEngines for TyDeclaration {
             StorageDeclaration { decl_id, .. } => {
                 decl_engine.get(*decl_id).hash(state, engines);
             }
+            TypeAliasDeclaration { decl_id, .. } => {
+                decl_engine.get(*decl_id).hash(state, engines);
+            }
             GenericTypeForFunctionScope { name, type_id } => {
                 name.hash(state);
                 type_engine.get(*type_id).hash(state, engines);
@@ -251,6 +271,7 @@ impl SubstTypes for TyDeclaration {
             AbiDeclaration { .. }
             | ConstantDeclaration { .. }
             | StorageDeclaration { .. }
+            | TypeAliasDeclaration { .. }
             | GenericTypeForFunctionScope { .. }
             | ErrorRecovery(_) => (),
         }
@@ -281,6 +302,7 @@ impl ReplaceSelfType for TyDeclaration {
             AbiDeclaration { .. }
             | ConstantDeclaration { .. }
             | StorageDeclaration { .. }
+            | TypeAliasDeclaration { .. }
             | GenericTypeForFunctionScope { .. }
             | ErrorRecovery(_) => (),
         }
@@ -315,7 +337,8 @@ impl Spanned for TyDeclaration {
             | StorageDeclaration { decl_span, .. }
             | AbiDeclaration { decl_span, .. }
             | StructDeclaration { decl_span, .. }
-            | EnumDeclaration { decl_span, .. } => decl_span.clone(),
+            | EnumDeclaration { decl_span, .. }
+            | TypeAliasDeclaration { decl_span, .. } => decl_span.clone(),
             GenericTypeForFunctionScope { name, .. } => name.span(),
             ErrorRecovery(span) => span.clone(),
         }
@@ -358,7 +381,8 @@ impl DisplayWithEngines for TyDeclaration {
                 TyDeclaration::FunctionDeclaration { name, .. }
                 | TyDeclaration::TraitDeclaration { name, .. }
                 | TyDeclaration::StructDeclaration { name, .. }
-                | TyDeclaration::EnumDeclaration { name, .. } => name.as_str().into(),
+                | TyDeclaration::EnumDeclaration { name, .. }
+                | TyDeclaration::TypeAliasDeclaration { name, .. } => name.as_str().into(),
                 _ => String::new(),
             }
         )
@@ -420,6 +444,7 @@ impl CollectTypesMetadata for TyDeclaration {
             | EnumDeclaration { .. }
             | ImplTrait { .. }
             | AbiDeclaration { .. }
+            | TypeAliasDeclaration { .. }
             | GenericTypeForFunctionScope { .. } => vec![],
         };
         if errors.is_empty() {
@@ -441,7 +466,8 @@ impl GetDeclIdent for TyDeclaration {
             | TyDeclaration::AbiDeclaration { name, .. }
             | TyDeclaration::GenericTypeForFunctionScope { name, .. }
             | TyDeclaration::StructDeclaration { name, .. }
-            | TyDeclaration::EnumDeclaration { name, .. } => Some(name.clone()),
+            | TyDeclaration::EnumDeclaration { name, .. }
+            | TyDeclaration::TypeAliasDeclaration { name, .. } => Some(name.clone()),
             TyDeclaration::ErrorRecovery(_) => None,
             TyDeclaration::StorageDeclaration { .. } => None,
         }
@@ -640,6 +666,7 @@ impl TyDeclaration {
             GenericTypeForFunctionScope { .. } => "generic type parameter",
             ErrorRecovery(_) => "error",
             StorageDeclaration { .. } => "contract storage declaration",
+            TypeAliasDeclaration { .. } => "type alias",
         }
     }
 
@@ -655,6 +682,7 @@ impl TyDeclaration {
             ImplTrait { .. } => "impl_trait",
             FunctionDeclaration { .. } => "fn",
             ConstantDeclaration { .. } => "constant",
+            TypeAliasDeclaration { .. } => "type",
             _ => unreachable!("these items are non-documentable"),
         }
     }
@@ -697,6 +725,10 @@ impl TyDeclaration {
                     },
                 )
             }
+            TyDeclaration::TypeAliasDeclaration { decl_id, .. } => {
+                let decl = decl_engine.get_type_alias(decl_id);
+                decl.ty.type_id
+            }
             TyDeclaration::GenericTypeForFunctionScope { type_id, .. } => *type_id,
             decl => {
                 errors.push(CompileError::NotAType {
@@ -710,7 +742,7 @@ impl TyDeclaration {
         ok(type_id, warnings, errors)
     }
 
-    pub(crate) fn visibility(&self, decl_engine: &DeclEngine) -> Visibility {
+    pub fn visibility(&self, decl_engine: &DeclEngine) -> Visibility {
         use TyDeclaration::*;
         match self {
             TraitDeclaration { decl_id, .. } => {
@@ -733,6 +765,10 @@ impl TyDeclaration {
                 let TyFunctionDeclaration { visibility, .. } = decl_engine.get_function(decl_id);
                 visibility
             }
+            TypeAliasDeclaration { decl_id, .. } => {
+                let TyTypeAliasDeclaration { visibility, .. } = decl_engine.get_type_alias(decl_id);
+                visibility
+            }
             GenericTypeForFunctionScope { .. }
             | ImplTrait { .. }
             | StorageDeclaration { .. }
@@ -826,3 +862,13 @@ impl From<DeclRef<DeclId<TyStorageDeclaration>>> for TyDeclaration {
         }
     }
 }
+
+impl From<DeclRef<DeclId<TyTypeAliasDeclaration>>> for TyDeclaration {
+    fn from(decl_ref: DeclRef<DeclId<TyTypeAliasDeclaration>>) -> Self {
+        TyDeclaration::TypeAliasDeclaration {
+            name: decl_ref.name().clone(),
+            decl_id: *decl_ref.id(),
+            decl_span: decl_ref.decl_span().clone(),
+        }
+    }
+}
@@ -34,9 +34,11 @@ impl EqWithEngines for TyEnumDeclaration {}
 impl PartialEqWithEngines for TyEnumDeclaration {
     fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
         self.call_path.suffix == other.call_path.suffix
-            && self.type_parameters.eq(&other.type_parameters, engines)
-            && self.variants.eq(&other.variants, engines)
             && self.visibility == other.visibility
+            // An enum is identified by its declaration and its type arguments, without
+            // comparing its variants, as those may refer back to the enum itself.
+            && self.span == other.span
+            && self.type_parameters.eq(&other.type_parameters, engines)
     }
 }
 
@@ -45,15 +47,14 @@ impl HashWithEngines for TyEnumDeclaration {
         let TyEnumDeclaration {
             call_path,
             type_parameters,
-            variants,
             visibility,
             // these fields are not hashed because they aren't relevant/a
             // reliable source of obj v. obj distinction
+            variants: _,
             span: _,
             attributes: _,
         } = self;
         call_path.suffix.hash(state);
-        variants.hash(state, engines);
         type_parameters.hash(state, engines);
         visibility.hash(state);
     }
@@ -9,6 +9,8 @@ mod storage;
 mod r#struct;
 mod r#trait;
 mod trait_fn;
+mod trait_type;
+mod type_alias;
 mod variable;
 
 pub use abi::*;
@@ -21,4 +23,6 @@ pub use r#struct::*;
 pub use r#trait::*;
 pub use storage::*;
 pub use trait_fn::*;
+pub use trait_type::*;
+pub use type_alias::*;
 pub use variable::*;
@@ -34,9 +34,11 @@ impl EqWithEngines for TyStructDeclaration {}
 impl PartialEqWithEngines for TyStructDeclaration {
     fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
         self.call_path.suffix == other.call_path.suffix
-            && self.fields.eq(&other.fields, engines)
-            && self.type_parameters.eq(&other.type_parameters, engines)
             && self.visibility == other.visibility
+            // A struct is identified by its declaration and its type arguments, without
+            // comparing its fields, as those may refer back to the struct itself.
+            && self.span == other.span
+            && self.type_parameters.eq(&other.type_parameters, engines)
     }
 }
 
@@ -44,16 +46,15 @@ impl HashWithEngines for TyStructDeclaration {
     fn hash<H: Hasher>(&self, state: &mut H, engines: Engines<'_>) {
         let TyStructDeclaration {
             call_path,
-            fields,
             type_parameters,
             visibility,
             // these fields are not hashed because they aren't relevant/a
             // reliable source of obj v. obj distinction
+            fields: _,
             span: _,
             attributes: _,
         } = self;
         call_path.suffix.hash(state);
-        fields.hash(state, engines);
         type_parameters.hash(state, engines);
         visibility.hash(state);
     }
@@ -3,9 +3,12 @@ use std::hash::{Hash, Hasher};
 use sway_types::{Ident, Named, Span, Spanned};
 
 use crate::{
-    decl_engine::{DeclRefFunction, DeclRefTraitFn, ReplaceFunctionImplementingType},
+    decl_engine::{
+        DeclRefConstant, DeclRefFunction, DeclRefTraitFn, DeclRefTraitType,
+        ReplaceFunctionImplementingType,
+    },
     engine_threading::*,
-    language::{parsed, Visibility},
+    language::{parsed, Purity, Visibility},
     transform,
     type_system::*,
 };
@@ -27,11 +30,39 @@ pub struct TyTraitDeclaration {
 #[derive(Clone, Debug)]
 pub enum TyTraitInterfaceItem {
     TraitFn(DeclRefTraitFn),
+    Constant(DeclRefConstant),
+    Type(DeclRefTraitType),
 }
 
 #[derive(Clone, Debug)]
 pub enum TyTraitItem {
     Fn(DeclRefFunction),
+    Constant(DeclRefConstant),
+    Type(DeclRefTraitType),
+}
+
+impl TyTraitInterfaceItem {
+    /// The name of the interface item, used to match it with the
+    /// corresponding item of an implementation.
+    pub fn name(&self) -> &Ident {
+        match self {
+            TyTraitInterfaceItem::TraitFn(decl_ref) => decl_ref.name(),
+            TyTraitInterfaceItem::Constant(decl_ref) => decl_ref.name(),
+            TyTraitInterfaceItem::Type(decl_ref) => decl_ref.name(),
+        }
+    }
+}
+
+impl TyTraitItem {
+    /// The name of the item, used to match it with the corresponding item of
+    /// the trait interface.
+    pub fn name(&self) -> &Ident {
+        match self {
+            TyTraitItem::Fn(decl_ref) => decl_ref.name(),
+            TyTraitItem::Constant(decl_ref) => decl_ref.name(),
+            TyTraitItem::Type(decl_ref) => decl_ref.name(),
+        }
+    }
 }
 
 impl Named for TyTraitDeclaration {
@@ -88,6 +119,13 @@ impl PartialEqWithEngines for TyTraitInterfaceItem {
             (TyTraitInterfaceItem::TraitFn(id), TyTraitInterfaceItem::TraitFn(other_id)) => {
                 id.eq(other_id, engines)
             }
+            (TyTraitInterfaceItem::Constant(id), TyTraitInterfaceItem::Constant(other_id)) => {
+                id.eq(other_id, engines)
+            }
+            (TyTraitInterfaceItem::Type(id), TyTraitInterfaceItem::Type(other_id)) => {
+                id.eq(other_id, engines)
+            }
+            _ => false,
         }
     }
 }
@@ -97,6 +135,11 @@ impl PartialEqWithEngines for TyTraitItem {
     fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
         match (self, other) {
             (TyTraitItem::Fn(id), TyTraitItem::Fn(other_id)) => id.eq(other_id, engines),
+            (TyTraitItem::Constant(id), TyTraitItem::Constant(other_id)) => {
+                id.eq(other_id, engines)
+            }
+            (TyTraitItem::Type(id), TyTraitItem::Type(other_id)) => id.eq(other_id, engines),
+            _ => false,
         }
     }
 }
@@ -105,6 +148,8 @@ impl HashWithEngines for TyTraitInterfaceItem {
     fn hash<H: Hasher>(&self, state: &mut H, engines: Engines<'_>) {
         match self {
             TyTraitInterfaceItem::TraitFn(fn_decl) => fn_decl.hash(state, engines),
+            TyTraitInterfaceItem::Constant(const_decl) => const_decl.hash(state, engines),
+            TyTraitInterfaceItem::Type(type_decl) => type_decl.hash(state, engines),
         }
     }
 }
@@ -113,6 +158,8 @@ impl HashWithEngines for TyTraitItem {
     fn hash<H: Hasher>(&self, state: &mut H, engines: Engines<'_>) {
         match self {
             TyTraitItem::Fn(fn_decl) => fn_decl.hash(state, engines),
+            TyTraitItem::Constant(const_decl) => const_decl.hash(state, engines),
+            TyTraitItem::Type(type_decl) => type_decl.hash(state, engines),
         }
     }
 }
@@ -131,6 +178,14 @@ impl SubstTypes for TyTraitDeclaration {
                         .subst_types_and_insert_new_with_parent(type_mapping, engines);
                     item_ref.replace_id((&new_item_ref).into());
                 }
+                TyTraitInterfaceItem::Constant(item_ref) => {
+                    let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                    item_ref.replace_id((&new_item_ref).into());
+                }
+                TyTraitInterfaceItem::Type(item_ref) => {
+                    let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                    item_ref.replace_id((&new_item_ref).into());
+                }
             });
         self.items.iter_mut().for_each(|item| match item {
             TyTraitItem::Fn(item_ref) => {
@@ -139,6 +194,14 @@ impl SubstTypes for TyTraitDeclaration {
                     .subst_types_and_insert_new_with_parent(type_mapping, engines);
                 item_ref.replace_id((&new_item_ref).into());
             }
+            TyTraitItem::Constant(item_ref) => {
+                let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                item_ref.replace_id((&new_item_ref).into());
+            }
+            TyTraitItem::Type(item_ref) => {
+                let new_item_ref = item_ref.subst_types_and_insert_new(type_mapping, engines);
+                item_ref.replace_id((&new_item_ref).into());
+            }
         });
     }
 }
@@ -147,6 +210,8 @@ impl SubstTypes for TyTraitItem {
     fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: Engines<'_>) {
         match self {
             TyTraitItem::Fn(fn_decl) => fn_decl.subst(type_mapping, engines),
+            TyTraitItem::Constant(const_decl) => const_decl.subst(type_mapping, engines),
+            TyTraitItem::Type(type_decl) => type_decl.subst(type_mapping, engines),
         }
     }
 }
@@ -165,6 +230,16 @@ impl ReplaceSelfType for TyTraitDeclaration {
                         .replace_self_type_and_insert_new_with_parent(engines, self_type);
                     item_ref.replace_id((&new_item_ref).into());
                 }
+                TyTraitInterfaceItem::Constant(item_ref) => {
+                    let new_item_ref =
+                        item_ref.replace_self_type_and_insert_new(engines, self_type);
+                    item_ref.replace_id((&new_item_ref).into());
+                }
+                TyTraitInterfaceItem::Type(item_ref) => {
+                    let new_item_ref =
+                        item_ref.replace_self_type_and_insert_new(engines, self_type);
+                    item_ref.replace_id((&new_item_ref).into());
+                }
             });
         self.items.iter_mut().for_each(|item| match item {
             TyTraitItem::Fn(item_ref) => {
@@ -173,6 +248,14 @@ impl ReplaceSelfType for TyTraitDeclaration {
                     .replace_self_type_and_insert_new_with_parent(engines, self_type);
                 item_ref.replace_id((&new_item_ref).into());
             }
+            TyTraitItem::Constant(item_ref) => {
+                let new_item_ref = item_ref.replace_self_type_and_insert_new(engines, self_type);
+                item_ref.replace_id((&new_item_ref).into());
+            }
+            TyTraitItem::Type(item_ref) => {
+                let new_item_ref = item_ref.replace_self_type_and_insert_new(engines, self_type);
+                item_ref.replace_id((&new_item_ref).into());
+            }
         });
     }
 }
@@ -181,6 +264,12 @@ impl ReplaceSelfType for TyTraitInterfaceItem {
     fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
         match self {
             TyTraitInterfaceItem::TraitFn(fn_decl) => fn_decl.replace_self_type(engines, self_type),
+            TyTraitInterfaceItem::Constant(const_decl) => {
+                const_decl.replace_self_type(engines, self_type)
+            }
+            TyTraitInterfaceItem::Type(type_decl) => {
+                type_decl.replace_self_type(engines, self_type)
+            }
         }
     }
 }
@@ -189,6 +278,8 @@ impl ReplaceSelfType for TyTraitItem {
     fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
         match self {
             TyTraitItem::Fn(fn_decl) => fn_decl.replace_self_type(engines, self_type),
+            TyTraitItem::Constant(const_decl) => const_decl.replace_self_type(engines, self_type),
+            TyTraitItem::Type(type_decl) => type_decl.replace_self_type(engines, self_type),
         }
     }
 }
@@ -203,7 +294,74 @@ impl ReplaceFunctionImplementingType for TyTraitItem {
             TyTraitItem::Fn(decl_ref) => {
                 decl_ref.replace_implementing_type(engines, implementing_type)
             }
+            TyTraitItem::Constant(_) | TyTraitItem::Type(_) => {}
+        }
+    }
+}
+
+impl TyTraitDeclaration {
+    /// Returns the reason why values of types implementing this trait cannot
+    /// be made into `dyn` trait objects, or `None` when the trait is object
+    /// safe. Trait objects dispatch through a vtable, so every method must be
+    /// callable on an erased `self` without knowing its concrete type.
+    pub(crate) fn object_safety_violation(&self, engines: Engines<'_>) -> Option<String> {
+        let type_engine = engines.te();
+        let decl_engine = engines.de();
+        if !self.type_parameters.is_empty() {
+            return Some("it has type parameters".to_string());
+        }
+        let mentions_self = |type_id: TypeId| {
+            let type_info = type_engine.get(type_id);
+            matches!(type_info, TypeInfo::SelfType)
+                || type_info
+                    .extract_inner_types(type_engine, decl_engine)
+                    .into_iter()
+                    .any(|inner| matches!(type_engine.get(inner), TypeInfo::SelfType))
+        };
+        for item in self.interface_surface.iter() {
+            let decl_ref = match item {
+                TyTraitInterfaceItem::TraitFn(decl_ref) => decl_ref,
+                TyTraitInterfaceItem::Constant(decl_ref) => {
+                    return Some(format!("it declares the constant \"{}\"", decl_ref.name()));
+                }
+                TyTraitInterfaceItem::Type(decl_ref) => {
+                    return Some(format!(
+                        "it declares the associated type \"{}\"",
+                        decl_ref.name()
+                    ));
+                }
+            };
+            let method = decl_engine.get_trait_fn(decl_ref);
+            if !method.parameters.first().map_or(false, |p| p.is_self()) {
+                return Some(format!("method \"{}\" does not take `self`", method.name));
+            }
+            if method.purity != Purity::Pure {
+                return Some(format!("method \"{}\" accesses storage", method.name));
+            }
+            if method
+                .parameters
+                .iter()
+                .any(|p| p.is_reference && p.is_mutable)
+            {
+                return Some(format!(
+                    "method \"{}\" takes a `ref mut` parameter",
+                    method.name
+                ));
+            }
+            if method
+                .parameters
+                .iter()
+                .skip(1)
+                .any(|p| mentions_self(p.type_argument.type_id))
+                || mentions_self(method.return_type)
+            {
+                return Some(format!(
+                    "method \"{}\" mentions `Self` outside of its receiver",
+                    method.name
+                ));
+            }
         }
+        None
     }
 }
 
@@ -0,0 +1,71 @@
+use std::hash::{Hash, Hasher};
+
+use sway_types::{Ident, Named, Span, Spanned};
+
+use crate::{engine_threading::*, transform, type_system::*};
+
+/// An associated type of a trait, e.g. `type Item;` in a trait declaration or
+/// `type Item = u64;` in an implementation of that trait.
+///
+/// Inside of a trait declaration, `ty` holds a placeholder generic type that
+/// stands in for the associated type until the trait is implemented. Inside of
+/// an impl block, `ty` holds the concrete type chosen by the implementation.
+#[derive(Clone, Debug)]
+pub struct TyTraitType {
+    pub name: Ident,
+    pub attributes: transform::AttributesMap,
+    pub ty: TypeArgument,
+    pub span: Span,
+}
+
+/// Returns the symbol under which the associated item `name` of `Self` is
+/// bound inside of trait and impl scopes, e.g. `Self::Item`.
+pub(crate) fn self_associated_item_symbol(name: &Ident) -> Ident {
+    Ident::new_with_override(format!("Self::{name}"), name.span())
+}
+
+impl Named for TyTraitType {
+    fn name(&self) -> &Ident {
+        &self.name
+    }
+}
+
+impl Spanned for TyTraitType {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl EqWithEngines for TyTraitType {}
+impl PartialEqWithEngines for TyTraitType {
+    fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
+        self.name == other.name && self.ty.eq(&other.ty, engines)
+    }
+}
+
+impl HashWithEngines for TyTraitType {
+    fn hash<H: Hasher>(&self, state: &mut H, engines: Engines<'_>) {
+        let TyTraitType {
+            name,
+            ty,
+            // these fields are not hashed because they aren't relevant/a
+            // reliable source of obj v. obj distinction
+            attributes: _,
+            span: _,
+        } = self;
+        name.hash(state);
+        ty.hash(state, engines);
+    }
+}
+
+impl SubstTypes for TyTraitType {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: Engines<'_>) {
+        self.ty.subst(type_mapping, engines);
+    }
+}
+
+impl ReplaceSelfType for TyTraitType {
+    fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
+        self.ty.replace_self_type(engines, self_type);
+    }
+}
@@ -0,0 +1,89 @@
+use std::hash::{Hash, Hasher};
+
+use sway_types::{Ident, Named, Span, Spanned};
+
+use crate::{
+    engine_threading::*,
+    language::{CallPath, Visibility},
+    transform,
+    type_system::*,
+};
+
+#[derive(Clone, Debug)]
+pub struct TyTypeAliasDeclaration {
+    pub call_path: CallPath,
+    pub attributes: transform::AttributesMap,
+    pub type_parameters: Vec<TypeParameter>,
+    pub ty: TypeArgument,
+    pub visibility: Visibility,
+    pub span: Span,
+}
+
+impl Named for TyTypeAliasDeclaration {
+    fn name(&self) -> &Ident {
+        &self.call_path.suffix
+    }
+}
+
+impl EqWithEngines for TyTypeAliasDeclaration {}
+impl PartialEqWithEngines for TyTypeAliasDeclaration {
+    fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
+        self.call_path.suffix == other.call_path.suffix
+            && self.type_parameters.eq(&other.type_parameters, engines)
+            && self.ty.eq(&other.ty, engines)
+            && self.visibility == other.visibility
+    }
+}
+
+impl HashWithEngines for TyTypeAliasDeclaration {
+    fn hash<H: Hasher>(&self, state: &mut H, engines: Engines<'_>) {
+        let TyTypeAliasDeclaration {
+            call_path,
+            type_parameters,
+            ty,
+            visibility,
+            // these fields are not hashed because they aren't relevant/a
+            // reliable source of obj v. obj distinction
+            span: _,
+            attributes: _,
+        } = self;
+        call_path.suffix.hash(state);
+        type_parameters.hash(state, engines);
+        ty.hash(state, engines);
+        visibility.hash(state);
+    }
+}
+
+impl SubstTypes for TyTypeAliasDeclaration {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: Engines<'_>) {
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.subst(type_mapping, engines));
+        self.ty.subst(type_mapping, engines);
+    }
+}
+
+impl ReplaceSelfType for TyTypeAliasDeclaration {
+    fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
+        self.type_parameters
+            .iter_mut()
+            .for_each(|x| x.replace_self_type(engines, self_type));
+        self.ty.replace_self_type(engines, self_type);
+    }
+}
+
+impl Spanned for TyTypeAliasDeclaration {
+    fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
+
+impl MonomorphizeHelper for TyTypeAliasDeclaration {
+    fn type_parameters(&self) -> &[TypeParameter] {
+        &self.type_parameters
+    }
+
+    fn name(&self) -> &Ident {
+        &self.call_path.suffix
+    }
+}
@@ -331,7 +331,7 @@ impl CollectTypesMetadata for TyExpression {
                     errors
                 ));
             }
-            EnumTag { exp } => {
+            EnumTag { exp } | TraitObject { exp, .. } => {
                 res.append(&mut check!(
                     exp.collect_types_metadata(ctx),
                     return err(warnings, errors),
@@ -375,6 +375,47 @@ impl CollectTypesMetadata for TyExpression {
                 warnings,
                 errors
             )),
+            Closure { fn_ref, captures } => {
+                for capture in captures.iter() {
+                    res.append(&mut check!(
+                        capture.1.collect_types_metadata(ctx),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+                let function_decl = decl_engine.get_function(fn_ref);
+                for content in function_decl.body.contents.iter() {
+                    res.append(&mut check!(
+                        content.collect_types_metadata(ctx),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+            }
+            ClosureCall {
+                closure: object,
+                arguments,
+            }
+            | DynamicMethodCall {
+                object, arguments, ..
+            } => {
+                res.append(&mut check!(
+                    object.collect_types_metadata(ctx),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                ));
+                for arg in arguments.iter() {
+                    res.append(&mut check!(
+                        arg.collect_types_metadata(ctx),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    ));
+                }
+            }
             // storage access can never be generic
             // variable expressions don't ever have return types themselves, they're stored in
             // `TyExpression::return_type`. Variable expressions are just names of variables.
@@ -384,7 +425,8 @@ impl CollectTypesMetadata for TyExpression {
             | AbiName(_)
             | Break
             | Continue
-            | FunctionParameter => {}
+            | FunctionParameter
+            | ConstGenericValue { .. } => {}
             Reassignment(reassignment) => {
                 res.append(&mut check!(
                     reassignment.rhs.collect_types_metadata(ctx),
@@ -485,7 +527,9 @@ impl DeterministicallyAborts for TyExpression {
                             .unwrap_or(false))
             }
             AbiName(_) => false,
-            EnumTag { exp } => exp.deterministically_aborts(decl_engine, check_call_body),
+            EnumTag { exp } | TraitObject { exp, .. } => {
+                exp.deterministically_aborts(decl_engine, check_call_body)
+            }
             UnsafeDowncast { exp, .. } => {
                 exp.deterministically_aborts(decl_engine, check_call_body)
             }
@@ -495,6 +539,7 @@ impl DeterministicallyAborts for TyExpression {
             }
             Break => false,
             Continue => false,
+            ConstGenericValue { .. } => false,
             Reassignment(reassignment) => reassignment
                 .rhs
                 .deterministically_aborts(decl_engine, check_call_body),
@@ -510,6 +555,25 @@ impl DeterministicallyAborts for TyExpression {
             // someone could write `return break;` in a loop, which would mean the return never
             // gets executed.
             Return(..) => true,
+            Closure { captures, .. } => captures
+                .iter()
+                .any(|(_, x)| x.deterministically_aborts(decl_engine, check_call_body)),
+            // The body of the called closure is not known here, as only the
+            // type of `closure` refers to it.
+            // Likewise, the method called through a trait object is only
+            // known at runtime.
+            ClosureCall {
+                closure: object,
+                arguments,
+            }
+            | DynamicMethodCall {
+                object, arguments, ..
+            } => {
+                object.deterministically_aborts(decl_engine, check_call_body)
+                    || arguments
+                        .iter()
+                        .any(|x| x.deterministically_aborts(decl_engine, check_call_body))
+            }
         }
     }
 }
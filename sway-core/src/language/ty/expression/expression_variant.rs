@@ -128,6 +128,40 @@ pub enum TyExpressionVariant {
     Reassignment(Box<TyReassignment>),
     StorageReassignment(Box<TyStorageReassignment>),
     Return(Box<TyExpression>),
+    /// A closure literal. Its body was lifted into the function `fn_ref`,
+    /// whose first parameters receive the `captures`.
+    Closure {
+        fn_ref: DeclRefFunction,
+        captures: Vec<(Ident, TyExpression)>,
+    },
+    /// A call of a value of a closure type.
+    ClosureCall {
+        closure: Box<TyExpression>,
+        arguments: Vec<TyExpression>,
+    },
+    /// The conversion of `exp` into a `dyn` trait object, whose vtable holds
+    /// `methods`, the implementations of the trait's interface methods for
+    /// the type of `exp`, in the order they are declared in the trait.
+    TraitObject {
+        exp: Box<TyExpression>,
+        methods: Vec<DeclRefFunction>,
+    },
+    /// A call of the trait method at `method_index` in the vtable of the
+    /// trait object `object`. `arguments` doesn't include `object`.
+    DynamicMethodCall {
+        object: Box<TyExpression>,
+        method_name: Ident,
+        method_index: usize,
+        arguments: Vec<TyExpression>,
+    },
+    /// The value of a const generic parameter, like the `N` in
+    /// `fn len<const N: u64>(a: [u64; N]) -> u64 { N }`. The value is known
+    /// once `type_id`, the [TypeInfo::ConstGeneric] of the parameter, has
+    /// been monomorphized.
+    ConstGenericValue {
+        name: Ident,
+        type_id: TypeId,
+    },
 }
 
 impl EqWithEngines for TyExpressionVariant {}
@@ -370,6 +404,76 @@ impl PartialEqWithEngines for TyExpressionVariant {
                     condition: r_condition,
                 },
             ) => l_body.eq(r_body, engines) && l_condition.eq(r_condition, engines),
+            (
+                Self::Closure {
+                    fn_ref: l_fn_ref,
+                    captures: l_captures,
+                },
+                Self::Closure {
+                    fn_ref: r_fn_ref,
+                    captures: r_captures,
+                },
+            ) => {
+                l_fn_ref.eq(r_fn_ref, engines)
+                    && l_captures.len() == r_captures.len()
+                    && l_captures
+                        .iter()
+                        .zip(r_captures.iter())
+                        .all(|((xa, xb), (ya, yb))| xa == ya && xb.eq(yb, engines))
+            }
+            (
+                Self::ClosureCall {
+                    closure: l_closure,
+                    arguments: l_arguments,
+                },
+                Self::ClosureCall {
+                    closure: r_closure,
+                    arguments: r_arguments,
+                },
+            ) => l_closure.eq(r_closure, engines) && l_arguments.eq(r_arguments, engines),
+            (
+                Self::TraitObject {
+                    exp: l_exp,
+                    methods: l_methods,
+                },
+                Self::TraitObject {
+                    exp: r_exp,
+                    methods: r_methods,
+                },
+            ) => l_exp.eq(r_exp, engines) && l_methods.eq(r_methods, engines),
+            (
+                Self::DynamicMethodCall {
+                    object: l_object,
+                    method_index: l_method_index,
+                    arguments: l_arguments,
+                    ..
+                },
+                Self::DynamicMethodCall {
+                    object: r_object,
+                    method_index: r_method_index,
+                    arguments: r_arguments,
+                    ..
+                },
+            ) => {
+                l_object.eq(r_object, engines)
+                    && l_method_index == r_method_index
+                    && l_arguments.eq(r_arguments, engines)
+            }
+            (
+                Self::ConstGenericValue {
+                    name: l_name,
+                    type_id: l_type_id,
+                },
+                Self::ConstGenericValue {
+                    name: r_name,
+                    type_id: r_type_id,
+                },
+            ) => {
+                l_name == r_name
+                    && type_engine
+                        .get(*l_type_id)
+                        .eq(&type_engine.get(*r_type_id), engines)
+            }
             (l, r) => std::mem::discriminant(l) == std::mem::discriminant(r),
         }
     }
@@ -557,6 +661,35 @@ impl HashWithEngines for TyExpressionVariant {
             Self::Return(exp) => {
                 exp.hash(state, engines);
             }
+            Self::Closure { fn_ref, captures } => {
+                fn_ref.hash(state, engines);
+                captures.iter().for_each(|(name, capture)| {
+                    name.hash(state);
+                    capture.hash(state, engines);
+                });
+            }
+            Self::ClosureCall { closure, arguments } => {
+                closure.hash(state, engines);
+                arguments.hash(state, engines);
+            }
+            Self::TraitObject { exp, methods } => {
+                exp.hash(state, engines);
+                methods.hash(state, engines);
+            }
+            Self::DynamicMethodCall {
+                object,
+                method_index,
+                arguments,
+                ..
+            } => {
+                object.hash(state, engines);
+                method_index.hash(state);
+                arguments.hash(state, engines);
+            }
+            Self::ConstGenericValue { name, type_id } => {
+                name.hash(state);
+                type_engine.get(*type_id).hash(state, engines);
+            }
         }
     }
 }
@@ -689,6 +822,42 @@ impl SubstTypes for TyExpressionVariant {
             Reassignment(reassignment) => reassignment.subst(type_mapping, engines),
             StorageReassignment(..) => (),
             Return(stmt) => stmt.subst(type_mapping, engines),
+            Closure {
+                ref mut fn_ref,
+                captures,
+            } => {
+                captures
+                    .iter_mut()
+                    .for_each(|(_ident, expr)| expr.subst(type_mapping, engines));
+                let new_decl_ref = fn_ref
+                    .clone()
+                    .subst_types_and_insert_new_with_parent(type_mapping, engines);
+                fn_ref.replace_id((&new_decl_ref).into());
+            }
+            ClosureCall { closure, arguments } => {
+                closure.subst(type_mapping, engines);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.subst(type_mapping, engines));
+            }
+            TraitObject { exp, methods } => {
+                exp.subst(type_mapping, engines);
+                for method in methods.iter_mut() {
+                    let new_decl_ref = method
+                        .clone()
+                        .subst_types_and_insert_new_with_parent(type_mapping, engines);
+                    method.replace_id((&new_decl_ref).into());
+                }
+            }
+            DynamicMethodCall {
+                object, arguments, ..
+            } => {
+                object.subst(type_mapping, engines);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.subst(type_mapping, engines));
+            }
+            ConstGenericValue { type_id, .. } => type_id.subst(type_mapping, engines),
         }
     }
 }
@@ -815,6 +984,42 @@ impl ReplaceSelfType for TyExpressionVariant {
             Reassignment(reassignment) => reassignment.replace_self_type(engines, self_type),
             StorageReassignment(..) => (),
             Return(stmt) => stmt.replace_self_type(engines, self_type),
+            Closure {
+                ref mut fn_ref,
+                captures,
+            } => {
+                captures
+                    .iter_mut()
+                    .for_each(|(_ident, expr)| expr.replace_self_type(engines, self_type));
+                let new_decl_ref = fn_ref
+                    .clone()
+                    .replace_self_type_and_insert_new_with_parent(engines, self_type);
+                fn_ref.replace_id((&new_decl_ref).into());
+            }
+            ClosureCall { closure, arguments } => {
+                closure.replace_self_type(engines, self_type);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.replace_self_type(engines, self_type));
+            }
+            TraitObject { exp, methods } => {
+                exp.replace_self_type(engines, self_type);
+                for method in methods.iter_mut() {
+                    let new_decl_ref = method
+                        .clone()
+                        .replace_self_type_and_insert_new_with_parent(engines, self_type);
+                    method.replace_id((&new_decl_ref).into());
+                }
+            }
+            DynamicMethodCall {
+                object, arguments, ..
+            } => {
+                object.replace_self_type(engines, self_type);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.replace_self_type(engines, self_type));
+            }
+            ConstGenericValue { .. } => (),
         }
     }
 }
@@ -829,11 +1034,16 @@ impl ReplaceDecls for TyExpressionVariant {
                 ref mut arguments,
                 ..
             } => {
+                let old_id = *fn_ref.id();
                 fn_ref.replace_decls(decl_mapping, engines);
-                let new_decl_ref = fn_ref
-                    .clone()
-                    .replace_decls_and_insert_new_with_parent(decl_mapping, engines);
-                fn_ref.replace_id((&new_decl_ref).into());
+                // A callee the mapping redirected to is already the implementation for the
+                // mapped types, so the mapping does not apply to its body.
+                if *fn_ref.id() == old_id {
+                    let new_decl_ref = fn_ref
+                        .clone()
+                        .replace_decls_and_insert_new_with_parent(decl_mapping, engines);
+                    fn_ref.replace_id((&new_decl_ref).into());
+                }
                 for (_, arg) in arguments.iter_mut() {
                     arg.replace_decls(decl_mapping, engines);
                 }
@@ -917,6 +1127,34 @@ impl ReplaceDecls for TyExpressionVariant {
             Reassignment(reassignment) => reassignment.replace_decls(decl_mapping, engines),
             StorageReassignment(..) => (),
             Return(stmt) => stmt.replace_decls(decl_mapping, engines),
+            Closure {
+                ref mut fn_ref,
+                captures,
+            } => {
+                let new_decl_ref = fn_ref
+                    .clone()
+                    .replace_decls_and_insert_new_with_parent(decl_mapping, engines);
+                fn_ref.replace_id((&new_decl_ref).into());
+                for (_, capture) in captures.iter_mut() {
+                    capture.replace_decls(decl_mapping, engines);
+                }
+            }
+            ClosureCall { closure, arguments } => {
+                closure.replace_decls(decl_mapping, engines);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.replace_decls(decl_mapping, engines));
+            }
+            TraitObject { exp, .. } => exp.replace_decls(decl_mapping, engines),
+            DynamicMethodCall {
+                object, arguments, ..
+            } => {
+                object.replace_decls(decl_mapping, engines);
+                arguments
+                    .iter_mut()
+                    .for_each(|x| x.replace_decls(decl_mapping, engines));
+            }
+            ConstGenericValue { .. } => (),
         }
     }
 }
@@ -1043,6 +1281,19 @@ impl DisplayWithEngines for TyExpressionVariant {
             TyExpressionVariant::Return(exp) => {
                 format!("return {}", engines.help_out(&**exp))
             }
+            TyExpressionVariant::Closure { .. } => "closure".into(),
+            TyExpressionVariant::ClosureCall { closure, .. } => {
+                format!("call of {}", engines.help_out(&**closure))
+            }
+            TyExpressionVariant::TraitObject { exp, .. } => {
+                format!("trait object of {}", engines.help_out(&**exp))
+            }
+            TyExpressionVariant::DynamicMethodCall { method_name, .. } => {
+                format!("\"{method_name}\" dynamic method call")
+            }
+            TyExpressionVariant::ConstGenericValue { name, .. } => {
+                format!("const generic {name}")
+            }
         };
         write!(f, "{s}")
     }
@@ -1157,6 +1408,23 @@ impl TyExpressionVariant {
             TyExpressionVariant::Return(exp) => {
                 vec![exp]
             }
+            // Returns in the body of a closure return from the closure, so
+            // only the captured values are searched.
+            TyExpressionVariant::Closure { captures, .. } => captures
+                .iter()
+                .flat_map(|(_name, expr)| expr.gather_return_statements())
+                .collect(),
+            TyExpressionVariant::ClosureCall { closure, arguments } => std::iter::once(&**closure)
+                .chain(arguments.iter())
+                .flat_map(|expr| expr.gather_return_statements())
+                .collect(),
+            TyExpressionVariant::TraitObject { exp, .. } => exp.gather_return_statements(),
+            TyExpressionVariant::DynamicMethodCall {
+                object, arguments, ..
+            } => std::iter::once(&**object)
+                .chain(arguments.iter())
+                .flat_map(|expr| expr.gather_return_statements())
+                .collect(),
             // if it is impossible for an expression to contain a return _statement_ (not an
             // implicit return!), put it in the pattern below.
             TyExpressionVariant::Literal(_)
@@ -1166,7 +1434,8 @@ impl TyExpressionVariant {
             | TyExpressionVariant::AbiName(_)
             | TyExpressionVariant::StorageAccess { .. }
             | TyExpressionVariant::Break
-            | TyExpressionVariant::Continue => vec![],
+            | TyExpressionVariant::Continue
+            | TyExpressionVariant::ConstGenericValue { .. } => vec![],
         }
     }
 }
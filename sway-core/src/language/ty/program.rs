@@ -119,6 +119,7 @@ impl TyProgram {
                                             let method = decl_engine.get_function(&method_ref);
                                             abi_entries.push(method);
                                         }
+                                        TyImplItem::Constant(_) | TyImplItem::Type(_) => {}
                                     }
                                 }
                             }
@@ -278,6 +279,15 @@ impl TyProgram {
                         span: main_func.return_type.span.clone(),
                     });
                 }
+                if !matches!(main_return_type_info, TypeInfo::StringSlice)
+                    && nested_types
+                        .iter()
+                        .any(|ty| matches!(ty, TypeInfo::StringSlice))
+                {
+                    errors.push(CompileError::NestedStringSliceReturnNotAllowedInMain {
+                        span: main_func.return_type.span.clone(),
+                    });
+                }
                 TyProgramKind::Script {
                     main_function: main_func,
                 }
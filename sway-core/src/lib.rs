@@ -25,7 +25,7 @@ use crate::{error::*, source_map::SourceMap};
 pub use asm_generation::from_ir::compile_ir_to_asm;
 use asm_generation::FinalizedAsm;
 pub use asm_generation::{CompiledBytecode, FinalizedEntry};
-pub use build_config::{BuildConfig, BuildTarget};
+pub use build_config::{BuildConfig, BuildTarget, SourceOverlay};
 use control_flow_analysis::ControlFlowGraph;
 use metadata::MetadataManager;
 use std::collections::HashMap;
@@ -80,15 +80,22 @@ pub fn parse(
         None => parse_in_memory(h, engines, input),
         // When a `BuildConfig` is given,
         // the module source may declare `dep`s that must be parsed from other files.
-        Some(config) => parse_module_tree(h, engines, input, config.canonical_root_module(), None)
-            .map(|(kind, lexed, parsed)| {
-                let lexed = lexed::LexedProgram {
-                    kind: kind.clone(),
-                    root: lexed,
-                };
-                let parsed = parsed::ParseProgram { kind, root: parsed };
-                (lexed, parsed)
-            }),
+        Some(config) => parse_module_tree(
+            h,
+            engines,
+            config,
+            input,
+            config.canonical_root_module(),
+            None,
+        )
+        .map(|(kind, lexed, parsed)| {
+            let lexed = lexed::LexedProgram {
+                kind: kind.clone(),
+                root: lexed,
+            };
+            let parsed = parsed::ParseProgram { kind, root: parsed };
+            (lexed, parsed)
+        }),
     })
 }
 
@@ -193,6 +200,7 @@ struct Submodules {
 fn parse_submodules(
     handler: &Handler,
     engines: Engines<'_>,
+    config: &BuildConfig,
     module_name: Option<&str>,
     module: &sway_ast::Module,
     module_dir: &Path,
@@ -205,8 +213,8 @@ fn parse_submodules(
         // Read the source code from the dependency.
         // If we cannot, record as an error, but continue with other files.
         let submod_path = Arc::new(module_path(module_dir, module_name, submod));
-        let submod_str: Arc<str> = match std::fs::read_to_string(&*submod_path) {
-            Ok(s) => Arc::from(s),
+        let submod_str: Arc<str> = match config.read_source(&submod_path) {
+            Ok(s) => s,
             Err(e) => {
                 handler.emit_err(CompileError::FileCouldNotBeRead {
                     span: submod.name.span(),
@@ -220,6 +228,7 @@ fn parse_submodules(
         if let Ok((kind, lexed_module, parse_module)) = parse_module_tree(
             handler,
             engines,
+            config,
             submod_str.clone(),
             submod_path.clone(),
             Some(submod.name.as_str()),
@@ -253,6 +262,7 @@ fn parse_submodules(
 fn parse_module_tree(
     handler: &Handler,
     engines: Engines<'_>,
+    config: &BuildConfig,
     src: Arc<str>,
     path: Arc<PathBuf>,
     module_name: Option<&str>,
@@ -263,7 +273,14 @@ fn parse_module_tree(
 
     // Parse all submodules before converting to the `ParseTree`.
     // This always recovers on parse errors for the file itself by skipping that file.
-    let submodules = parse_submodules(handler, engines, module_name, &module.value, module_dir);
+    let submodules = parse_submodules(
+        handler,
+        engines,
+        config,
+        module_name,
+        &module.value,
+        module_dir,
+    );
 
     // Convert from the raw parsed module to the `ParseTree` ready for type-check.
     let (kind, tree) = to_parsed_lang::convert_parse_tree(
@@ -1,4 +1,4 @@
-use sway_error::error::CompileError;
+use sway_error::{convert_parse_tree_error::ConvertParseTreeError, error::CompileError};
 use sway_types::Spanned;
 
 use crate::{
@@ -9,6 +9,7 @@ use crate::{
         ty::{self, TyTraitItem},
     },
     semantic_analysis::{declaration::insert_supertraits_into_namespace, Mode, TypeCheckContext},
+    type_system::*,
     CompileResult,
 };
 
@@ -22,8 +23,9 @@ impl ty::TyAbiDeclaration {
 
         let AbiDeclaration {
             name,
+            type_parameters,
             interface_surface,
-            supertraits,
+            mut supertraits,
             methods,
             span,
             attributes,
@@ -37,14 +39,23 @@ impl ty::TyAbiDeclaration {
         // A temporary namespace for checking within this scope.
         let type_engine = ctx.type_engine;
         let decl_engine = ctx.decl_engine;
-        let contract_type = type_engine.insert(decl_engine, crate::TypeInfo::Contract);
+        let contract_type = type_engine.insert(decl_engine, TypeInfo::Contract);
         let mut abi_namespace = ctx.namespace.clone();
         let mut ctx = ctx.scoped(&mut abi_namespace).with_mode(Mode::ImplAbiFn);
 
+        // Type check the type parameters. This will also insert them into the
+        // current namespace.
+        let new_type_parameters = check!(
+            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
         // Recursively make the interface surfaces and methods of the
         // supertraits available to this abi.
         check!(
-            insert_supertraits_into_namespace(ctx.by_ref(), contract_type, &supertraits),
+            insert_supertraits_into_namespace(ctx.by_ref(), contract_type, &mut supertraits),
             return err(warnings, errors),
             warnings,
             errors
@@ -73,6 +84,12 @@ impl ty::TyAbiDeclaration {
                         ctx.decl_engine.insert(method),
                     ));
                 }
+                TraitItem::Constant(ConstantDeclaration { span, .. })
+                | TraitItem::Type(TraitTypeDeclaration { span, .. }) => {
+                    errors.push(
+                        ConvertParseTreeError::AssociatedItemsInAbiNotSupported { span }.into(),
+                    );
+                }
             }
         }
 
@@ -100,6 +117,7 @@ impl ty::TyAbiDeclaration {
         // into the interface surface, we do not want supertrait methods to be available to
         // the ABI user, only the contract methods can use supertrait methods
         let abi_decl = ty::TyAbiDeclaration {
+            type_parameters: new_type_parameters,
             interface_surface: new_interface_surface,
             supertraits,
             items: new_items,
@@ -0,0 +1,104 @@
+use sway_error::warning::{CompileWarning, Warning};
+use sway_types::{style::is_screaming_snake_case, Spanned};
+
+use crate::{
+    error::*,
+    language::{parsed, ty, CallPath},
+    semantic_analysis::TypeCheckContext,
+    type_system::*,
+};
+
+impl ty::TyConstantDeclaration {
+    pub(crate) fn type_check(
+        mut ctx: TypeCheckContext,
+        decl: parsed::ConstantDeclaration,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let type_engine = ctx.type_engine;
+        let decl_engine = ctx.decl_engine;
+        let engines = ctx.engines();
+
+        let parsed::ConstantDeclaration {
+            name,
+            mut type_ascription,
+            value,
+            visibility,
+            attributes,
+            is_configurable,
+            span,
+        } = decl;
+
+        type_ascription.type_id = check!(
+            ctx.resolve_type_with_self(
+                type_ascription.type_id,
+                &span,
+                EnforceTypeArguments::No,
+                None
+            ),
+            type_engine.insert(decl_engine, TypeInfo::ErrorRecovery),
+            warnings,
+            errors,
+        );
+
+        let mut ctx = ctx
+            .by_ref()
+            .with_type_annotation(type_ascription.type_id)
+            .with_help_text(
+                "This declaration's type annotation does not match up with the assigned \
+                expression's type.",
+            );
+
+        let value = match value {
+            Some(value) => {
+                let result = ty::TyExpression::type_check(ctx.by_ref(), value);
+
+                if !is_screaming_snake_case(name.as_str()) {
+                    warnings.push(CompileWarning {
+                        span: name.span(),
+                        warning_content: Warning::NonScreamingSnakeCaseConstName {
+                            name: name.clone(),
+                        },
+                    })
+                }
+
+                let value = check!(
+                    result,
+                    ty::TyExpression::error(name.span(), engines),
+                    warnings,
+                    errors
+                );
+
+                Some(value)
+            }
+            None => None,
+        };
+
+        // Integers are special in the sense that we can't only rely on the type of `body`
+        // to get the type of the variable. The type of the variable *has* to follow
+        // `type_ascription` if `type_ascription` is a concrete integer type that does not
+        // conflict with the type of `body` (i.e. passes the type checking above).
+        type_ascription.type_id = match type_engine.get(type_ascription.type_id) {
+            TypeInfo::UnsignedInteger(_) => type_ascription.type_id,
+            _ => match &value {
+                Some(value) => value.return_type,
+                None => type_ascription.type_id,
+            },
+        };
+
+        let mut call_path: CallPath = name.into();
+        call_path = call_path.to_fullpath(ctx.namespace);
+
+        let decl = ty::TyConstantDeclaration {
+            call_path,
+            value,
+            visibility,
+            attributes,
+            type_ascription,
+            is_configurable,
+            span,
+        };
+        ok(decl, warnings, errors)
+    }
+}
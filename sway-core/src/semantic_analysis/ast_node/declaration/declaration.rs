@@ -1,13 +1,16 @@
-use sway_error::warning::{CompileWarning, Warning};
-use sway_types::{style::is_screaming_snake_case, Spanned};
+use sway_error::error::CompileError;
+use sway_types::{Ident, Span, Spanned};
+
+use std::collections::HashSet;
 
 use crate::{
     decl_engine::{DeclEngineIndex, DeclRef, ReplaceFunctionImplementingType},
+    engine_threading::PartialEqWithEngines,
     error::*,
-    language::{parsed, ty, CallPath},
-    semantic_analysis::TypeCheckContext,
+    language::{parsed, ty},
+    semantic_analysis::{node_dependencies, TypeCheckContext},
     type_system::*,
-    CompileResult,
+    CompileResult, Engines,
 };
 
 impl ty::TyDeclaration {
@@ -78,88 +81,18 @@ impl ty::TyDeclaration {
                 );
                 typed_var_decl
             }
-            parsed::Declaration::ConstantDeclaration(parsed::ConstantDeclaration {
-                name,
-                mut type_ascription,
-                value,
-                visibility,
-                attributes,
-                is_configurable,
-                span,
-            }) => {
-                type_ascription.type_id = check!(
-                    ctx.resolve_type_with_self(
-                        type_ascription.type_id,
-                        &span,
-                        EnforceTypeArguments::No,
-                        None
-                    ),
-                    type_engine.insert(decl_engine, TypeInfo::ErrorRecovery),
+            parsed::Declaration::ConstantDeclaration(decl) => {
+                let span = decl.span.clone();
+                let const_decl = check!(
+                    ty::TyConstantDeclaration::type_check(ctx.by_ref(), decl),
+                    return ok(ty::TyDeclaration::ErrorRecovery(span), warnings, errors),
                     warnings,
-                    errors,
+                    errors
                 );
-
-                let mut ctx = ctx
-                    .by_ref()
-                    .with_type_annotation(type_ascription.type_id)
-                    .with_help_text(
-                        "This declaration's type annotation does not match up with the assigned \
-                        expression's type.",
-                    );
-
-                let value = match value {
-                    Some(value) => {
-                        let result = ty::TyExpression::type_check(ctx.by_ref(), value);
-
-                        if !is_screaming_snake_case(name.as_str()) {
-                            warnings.push(CompileWarning {
-                                span: name.span(),
-                                warning_content: Warning::NonScreamingSnakeCaseConstName {
-                                    name: name.clone(),
-                                },
-                            })
-                        }
-
-                        let value = check!(
-                            result,
-                            ty::TyExpression::error(name.span(), engines),
-                            warnings,
-                            errors
-                        );
-
-                        Some(value)
-                    }
-                    None => None,
-                };
-
-                // Integers are special in the sense that we can't only rely on the type of `body`
-                // to get the type of the variable. The type of the variable *has* to follow
-                // `type_ascription` if `type_ascription` is a concrete integer type that does not
-                // conflict with the type of `body` (i.e. passes the type checking above).
-                type_ascription.type_id = match type_engine.get(type_ascription.type_id) {
-                    TypeInfo::UnsignedInteger(_) => type_ascription.type_id,
-                    _ => match &value {
-                        Some(value) => value.return_type,
-                        None => type_ascription.type_id,
-                    },
-                };
-
-                let mut path: CallPath = name.into();
-                path = path.to_fullpath(ctx.namespace);
-
-                let decl = ty::TyConstantDeclaration {
-                    call_path: path.clone(),
-                    value,
-                    visibility,
-                    attributes,
-                    type_ascription,
-                    is_configurable,
-                    span,
-                };
-                let typed_const_decl: ty::TyDeclaration = decl_engine.insert(decl).into();
+                let name = const_decl.call_path.suffix.clone();
+                let typed_const_decl: ty::TyDeclaration = decl_engine.insert(const_decl).into();
                 check!(
-                    ctx.namespace
-                        .insert_symbol(path.suffix, typed_const_decl.clone()),
+                    ctx.namespace.insert_symbol(name, typed_const_decl.clone()),
                     return err(warnings, errors),
                     warnings,
                     errors
@@ -175,14 +108,60 @@ impl ty::TyDeclaration {
                     errors
                 );
                 let call_path = enum_decl.call_path.clone();
-                let decl: ty::TyDeclaration = decl_engine.insert(enum_decl).into();
-                check!(
-                    ctx.namespace.insert_symbol(call_path.suffix, decl.clone()),
-                    return err(warnings, errors),
-                    warnings,
-                    errors
-                );
-                decl
+                match enum_decl.forward_declaration(&ctx) {
+                    Some(decl) => {
+                        // Complete the forward declaration of a recursive type.
+                        let field_types = enum_decl
+                            .variants
+                            .iter()
+                            .map(|variant| variant.type_argument.type_id)
+                            .collect::<Vec<_>>();
+                        if let ty::TyDeclaration::EnumDeclaration { decl_id, .. } = &decl {
+                            decl_engine.replace(*decl_id, enum_decl.clone());
+                            // The copies of a generic enum which it refers to are completed
+                            // along with it.
+                            for copy in find_self_references(engines, &enum_decl.span, &field_types)
+                            {
+                                let TypeInfo::Enum(copy_ref) = copy else {
+                                    continue;
+                                };
+                                if copy_ref.id() == decl_id {
+                                    continue;
+                                }
+                                let copy = decl_engine.get_enum(&copy_ref);
+                                if type_parameters_are_equal(
+                                    engines,
+                                    &copy.type_parameters,
+                                    &enum_decl.type_parameters,
+                                ) {
+                                    decl_engine.replace(*copy_ref.id(), enum_decl.clone());
+                                } else {
+                                    errors.push(CompileError::RecursiveGenericTypeArguments {
+                                        name: call_path.suffix.clone(),
+                                        span: call_path.suffix.span(),
+                                    });
+                                }
+                            }
+                        }
+                        check!(
+                            check_type_has_finite_size(engines, &call_path.suffix, &field_types),
+                            (),
+                            warnings,
+                            errors
+                        );
+                        decl
+                    }
+                    None => {
+                        let decl: ty::TyDeclaration = decl_engine.insert(enum_decl).into();
+                        check!(
+                            ctx.namespace.insert_symbol(call_path.suffix, decl.clone()),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+                        decl
+                    }
+                }
             }
             parsed::Declaration::FunctionDeclaration(fn_decl) => {
                 let span = fn_decl.span.clone();
@@ -269,10 +248,8 @@ impl ty::TyDeclaration {
                 );
                 let impl_trait_decl: ty::TyDeclaration =
                     decl_engine.insert(impl_trait.clone()).into();
-                impl_trait.items.iter_mut().for_each(|item| match item {
-                    ty::TyImplItem::Fn(method) => {
-                        method.replace_implementing_type(engines, impl_trait_decl.clone())
-                    }
+                impl_trait.items.iter_mut().for_each(|item| {
+                    item.replace_implementing_type(engines, impl_trait_decl.clone())
                 });
                 impl_trait_decl
             }
@@ -300,10 +277,8 @@ impl ty::TyDeclaration {
                 );
                 let impl_trait_decl: ty::TyDeclaration =
                     decl_engine.insert(impl_trait.clone()).into();
-                impl_trait.items.iter_mut().for_each(|item| match item {
-                    ty::TyImplItem::Fn(method) => {
-                        method.replace_implementing_type(engines, impl_trait_decl.clone())
-                    }
+                impl_trait.items.iter_mut().for_each(|item| {
+                    item.replace_implementing_type(engines, impl_trait_decl.clone())
                 });
                 impl_trait_decl
             }
@@ -316,15 +291,61 @@ impl ty::TyDeclaration {
                     errors
                 );
                 let call_path = decl.call_path.clone();
-                let decl: ty::TyDeclaration = decl_engine.insert(decl).into();
-                // insert the struct decl into namespace
-                check!(
-                    ctx.namespace.insert_symbol(call_path.suffix, decl.clone()),
-                    return err(warnings, errors),
-                    warnings,
-                    errors
-                );
-                decl
+                match decl.forward_declaration(&ctx) {
+                    Some(forward_decl) => {
+                        // Complete the forward declaration of a recursive type.
+                        let field_types = decl
+                            .fields
+                            .iter()
+                            .map(|field| field.type_argument.type_id)
+                            .collect::<Vec<_>>();
+                        if let ty::TyDeclaration::StructDeclaration { decl_id, .. } = &forward_decl
+                        {
+                            decl_engine.replace(*decl_id, decl.clone());
+                            // The copies of a generic struct which it refers to are completed
+                            // along with it.
+                            for copy in find_self_references(engines, &decl.span, &field_types) {
+                                let TypeInfo::Struct(copy_ref) = copy else {
+                                    continue;
+                                };
+                                if copy_ref.id() == decl_id {
+                                    continue;
+                                }
+                                let copy = decl_engine.get_struct(&copy_ref);
+                                if type_parameters_are_equal(
+                                    engines,
+                                    &copy.type_parameters,
+                                    &decl.type_parameters,
+                                ) {
+                                    decl_engine.replace(*copy_ref.id(), decl.clone());
+                                } else {
+                                    errors.push(CompileError::RecursiveGenericTypeArguments {
+                                        name: call_path.suffix.clone(),
+                                        span: call_path.suffix.span(),
+                                    });
+                                }
+                            }
+                        }
+                        check!(
+                            check_type_has_finite_size(engines, &call_path.suffix, &field_types),
+                            (),
+                            warnings,
+                            errors
+                        );
+                        forward_decl
+                    }
+                    None => {
+                        let decl: ty::TyDeclaration = decl_engine.insert(decl).into();
+                        // insert the struct decl into namespace
+                        check!(
+                            ctx.namespace.insert_symbol(call_path.suffix, decl.clone()),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+                        decl
+                    }
+                }
             }
             parsed::Declaration::AbiDeclaration(abi_decl) => {
                 let span = abi_decl.span.clone();
@@ -429,8 +450,192 @@ impl ty::TyDeclaration {
                 );
                 decl_ref.into()
             }
+            parsed::Declaration::TypeAliasDeclaration(decl) => {
+                let span = decl.span.clone();
+                let type_alias_decl = check!(
+                    ty::TyTypeAliasDeclaration::type_check(ctx.by_ref(), decl),
+                    return ok(ty::TyDeclaration::ErrorRecovery(span), warnings, errors),
+                    warnings,
+                    errors
+                );
+                let call_path = type_alias_decl.call_path.clone();
+                let decl: ty::TyDeclaration = decl_engine.insert(type_alias_decl).into();
+                check!(
+                    ctx.namespace.insert_symbol(call_path.suffix, decl.clone()),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
+                decl
+            }
         };
 
         ok(decl, warnings, errors)
     }
 }
+
+/// Returns the types declared at `span` which occur in `field_types`, i.e., the copies of a
+/// recursive type which were monomorphized while it was only forward declared.
+fn find_self_references(
+    engines: Engines<'_>,
+    span: &Span,
+    field_types: &[TypeId],
+) -> Vec<TypeInfo> {
+    fn helper(
+        engines: Engines<'_>,
+        type_id: TypeId,
+        span: &Span,
+        visited: &mut HashSet<TypeId>,
+        found: &mut Vec<TypeInfo>,
+    ) {
+        if !visited.insert(type_id) {
+            return;
+        }
+        let type_engine = engines.te();
+        let decl_engine = engines.de();
+        let type_info = type_engine.get(type_id);
+        let inner_types = match &type_info {
+            TypeInfo::Enum(decl_ref) => {
+                let decl = decl_engine.get_enum(decl_ref);
+                if decl.span == *span {
+                    found.push(type_info);
+                    return;
+                }
+                decl.type_parameters
+                    .iter()
+                    .map(|type_param| type_param.type_id)
+                    .chain(
+                        decl.variants
+                            .iter()
+                            .map(|variant| variant.type_argument.type_id),
+                    )
+                    .collect::<Vec<_>>()
+            }
+            TypeInfo::Struct(decl_ref) => {
+                let decl = decl_engine.get_struct(decl_ref);
+                if decl.span == *span {
+                    found.push(type_info);
+                    return;
+                }
+                decl.type_parameters
+                    .iter()
+                    .map(|type_param| type_param.type_id)
+                    .chain(decl.fields.iter().map(|field| field.type_argument.type_id))
+                    .collect::<Vec<_>>()
+            }
+            TypeInfo::Tuple(elems) => elems.iter().map(|elem| elem.type_id).collect(),
+            TypeInfo::Array(elem_ty, _) => vec![elem_ty.type_id],
+            _ => vec![],
+        };
+        for type_id in inner_types {
+            helper(engines, type_id, span, visited, found);
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let mut found = vec![];
+    for type_id in field_types {
+        helper(engines, *type_id, span, &mut visited, &mut found);
+    }
+    found
+}
+
+fn type_parameters_are_equal(
+    engines: Engines<'_>,
+    l: &[TypeParameter],
+    r: &[TypeParameter],
+) -> bool {
+    let type_engine = engines.te();
+    l.len() == r.len()
+        && l.iter().zip(r.iter()).all(|(l, r)| {
+            type_engine
+                .get(l.type_id)
+                .eq(&type_engine.get(r.type_id), engines)
+        })
+}
+
+/// Checks that a recursive type, whose fields or variants are of the types `field_types`, has a
+/// finite size, i.e., that it refers to itself only through an indirection such as `Box<T>`.
+///
+/// A type which contains itself directly is rejected before type checking, but one which
+/// contains itself via the type arguments of another type, e.g., `Option<T>`, is only detected
+/// here, once it is known how those type arguments are stored.
+fn check_type_has_finite_size(
+    engines: Engines<'_>,
+    name: &Ident,
+    field_types: &[TypeId],
+) -> CompileResult<()> {
+    fn contains_by_value(
+        engines: Engines<'_>,
+        type_id: TypeId,
+        name: &Ident,
+        visited: &mut Vec<TypeInfo>,
+        chain: &mut Vec<Ident>,
+    ) -> bool {
+        let type_engine = engines.te();
+        let decl_engine = engines.de();
+        let type_info = type_engine.get(type_id);
+        let (decl_name, inner_types) = match &type_info {
+            TypeInfo::Struct(decl_ref) => {
+                let decl = decl_engine.get_struct(decl_ref);
+                let inner_types = decl
+                    .fields
+                    .iter()
+                    .map(|field| field.type_argument.type_id)
+                    .collect::<Vec<_>>();
+                (decl.call_path.suffix, inner_types)
+            }
+            TypeInfo::Enum(decl_ref) => {
+                let decl = decl_engine.get_enum(decl_ref);
+                let inner_types = decl
+                    .variants
+                    .iter()
+                    .map(|variant| variant.type_argument.type_id)
+                    .collect::<Vec<_>>();
+                (decl.call_path.suffix, inner_types)
+            }
+            TypeInfo::Tuple(elems) => {
+                return elems
+                    .iter()
+                    .any(|elem| contains_by_value(engines, elem.type_id, name, visited, chain))
+            }
+            TypeInfo::Array(elem_ty, _) => {
+                return contains_by_value(engines, elem_ty.type_id, name, visited, chain)
+            }
+            _ => return false,
+        };
+        if decl_name == *name && decl_name.span() == name.span() {
+            return true;
+        }
+        // Other recursive types are checked when they are declared.
+        if visited.iter().any(|ty| ty.eq(&type_info, engines)) {
+            return false;
+        }
+        visited.push(type_info);
+        chain.push(decl_name);
+        if inner_types
+            .into_iter()
+            .any(|type_id| contains_by_value(engines, type_id, name, visited, chain))
+        {
+            return true;
+        }
+        chain.pop();
+        false
+    }
+
+    let mut chain = vec![];
+    if field_types
+        .iter()
+        .any(|type_id| contains_by_value(engines, *type_id, name, &mut vec![], &mut chain))
+    {
+        err(
+            vec![],
+            vec![node_dependencies::build_recursive_type_error(
+                name.clone(),
+                &chain,
+            )],
+        )
+    } else {
+        ok((), vec![], vec![])
+    }
+}
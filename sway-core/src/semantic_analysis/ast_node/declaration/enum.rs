@@ -1,4 +1,8 @@
+use sway_error::error::CompileError;
+use sway_types::Spanned;
+
 use crate::{
+    decl_engine::DeclEngineIndex,
     error::*,
     language::{parsed::*, ty, CallPath},
     semantic_analysis::*,
@@ -27,7 +31,7 @@ impl ty::TyEnumDeclaration {
         // Type check the type parameters. This will also insert them into the
         // current namespace.
         let new_type_parameters = check!(
-            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters, true),
+            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters),
             return err(warnings, errors),
             warnings,
             errors
@@ -58,6 +62,80 @@ impl ty::TyEnumDeclaration {
         };
         ok(decl, warnings, errors)
     }
+
+    /// Declares the enum `decl` in the current namespace, without any variants, ahead of
+    /// type checking it. This allows recursive types to refer to themselves, and to each other,
+    /// through an indirection such as `Box<T>`.
+    ///
+    /// The forward declaration is completed once `decl` is type checked in full.
+    ///
+    /// A generic type may only be recursive on its own, i.e., when `is_mutually_recursive` is
+    /// `false`, as the copies of a type which refer to it are only completed along with it.
+    pub(crate) fn forward_declare(
+        mut ctx: TypeCheckContext,
+        decl: &EnumDeclaration,
+        is_mutually_recursive: bool,
+    ) -> CompileResult<()> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        // The type is still declared, so that the types which refer to it don't report it as
+        // missing.
+        if is_mutually_recursive && !decl.type_parameters.is_empty() {
+            errors.push(CompileError::RecursiveGenericType {
+                name: decl.name.clone(),
+                span: decl.name.span(),
+            });
+        }
+
+        // Any name clashes are reported when the type is declared in full.
+        if ctx.namespace.symbols().contains_key(&decl.name) {
+            return ok((), warnings, errors);
+        }
+
+        // Any errors in the type parameters are reported when the type is declared in full.
+        let mut decl_namespace = ctx.namespace.clone();
+        let type_parameters = match TypeParameter::type_check_type_params(
+            ctx.by_ref().scoped(&mut decl_namespace),
+            decl.type_parameters.clone(),
+        )
+        .value
+        {
+            Some(type_parameters) => type_parameters,
+            None => return ok((), warnings, errors),
+        };
+
+        let mut call_path: CallPath = decl.name.clone().into();
+        call_path = call_path.to_fullpath(ctx.namespace);
+        let decl_ref = ctx.decl_engine.insert(ty::TyEnumDeclaration {
+            call_path,
+            type_parameters,
+            variants: vec![],
+            visibility: decl.visibility,
+            span: decl.span.clone(),
+            attributes: decl.attributes.clone(),
+        });
+        check!(
+            ctx.namespace
+                .insert_symbol(decl.name.clone(), decl_ref.into()),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok((), warnings, errors)
+    }
+
+    /// Returns the forward declaration of `self` in the current namespace, if there is one.
+    pub(crate) fn forward_declaration(&self, ctx: &TypeCheckContext) -> Option<ty::TyDeclaration> {
+        match ctx.namespace.symbols().get(&self.call_path.suffix) {
+            Some(decl @ ty::TyDeclaration::EnumDeclaration { decl_id, .. })
+                if ctx.decl_engine.get(*decl_id).span == self.span =>
+            {
+                Some(decl.clone())
+            }
+            _ => None,
+        }
+    }
 }
 
 impl ty::TyEnumVariant {
@@ -7,6 +7,7 @@ use sway_error::{
 };
 
 use crate::{
+    decl_engine::{DeclEngineIndex, DeclId, DeclRefFunction},
     error::*,
     language::{parsed::*, ty, Visibility},
     semantic_analysis::*,
@@ -30,7 +31,7 @@ impl ty::TyFunctionDeclaration {
             parameters,
             span,
             attributes,
-            mut return_type,
+            return_type,
             type_parameters,
             visibility,
             purity,
@@ -65,41 +66,45 @@ impl ty::TyFunctionDeclaration {
             .with_purity(purity)
             .disallow_functions();
 
-        // Type check the type parameters. This will also insert them into the
-        // current namespace.
-        let new_type_parameters = check!(
-            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters, false),
+        let (new_type_parameters, new_parameters, return_type) = check!(
+            Self::type_check_signature(
+                ctx.by_ref(),
+                type_parameters,
+                parameters,
+                return_type,
+                is_method
+            ),
             return err(warnings, errors),
             warnings,
             errors
         );
 
-        // type check the function parameters, which will also insert them into the namespace
-        let mut new_parameters = vec![];
-        for parameter in parameters.into_iter() {
-            new_parameters.push(check!(
-                ty::TyFunctionParameter::type_check(ctx.by_ref(), parameter, is_method),
-                continue,
-                warnings,
-                errors
-            ));
-        }
-        if !errors.is_empty() {
-            return err(warnings, errors);
-        }
+        let (visibility, is_contract_call) =
+            visibility_and_is_contract_call(&ctx, visibility, is_method, is_in_impl_self);
 
-        // type check the return type
-        return_type.type_id = check!(
-            ctx.resolve_type_with_self(
-                return_type.type_id,
-                &return_type.span,
-                EnforceTypeArguments::Yes,
-                None
-            ),
-            type_engine.insert(decl_engine, TypeInfo::ErrorRecovery),
-            warnings,
-            errors,
-        );
+        let mut function_decl = ty::TyFunctionDeclaration {
+            name,
+            body: ty::TyCodeBlock { contents: vec![] },
+            parameters: new_parameters,
+            implementing_type: None,
+            span,
+            attributes,
+            return_type,
+            type_parameters: new_type_parameters,
+            visibility,
+            is_contract_call,
+            purity,
+            where_clause,
+        };
+
+        // Declare the function before type checking its body, so that the body may call it
+        // recursively. Methods are resolved through the trait map instead, in which they are
+        // declared by the impl block, see [Self::forward_declare_method].
+        let signature_decl_id = if is_method {
+            None
+        } else {
+            declare_signature(ctx.by_ref(), &function_decl)
+        };
 
         // type check the function body
         //
@@ -110,7 +115,7 @@ impl ty::TyFunctionDeclaration {
                 .by_ref()
                 .with_purity(purity)
                 .with_help_text("Function body's return type does not match up with its return type annotation.")
-                .with_type_annotation(return_type.type_id);
+                .with_type_annotation(function_decl.return_type.type_id);
             check!(
                 ty::TyCodeBlock::type_check(ctx, body),
                 (
@@ -130,39 +135,245 @@ impl ty::TyFunctionDeclaration {
             .collect();
 
         check!(
-            unify_return_statements(ctx.by_ref(), &return_statements, return_type.type_id),
+            unify_return_statements(
+                ctx.by_ref(),
+                &return_statements,
+                function_decl.return_type.type_id
+            ),
             return err(warnings, errors),
             warnings,
             errors
         );
 
-        let (visibility, is_contract_call) = if is_method {
-            if is_in_impl_self {
-                (visibility, false)
-            } else {
-                (Visibility::Public, false)
-            }
-        } else {
-            (visibility, ctx.mode() == Mode::ImplAbiFn)
-        };
+        function_decl.body = body;
+
+        // Recursive calls in the body were made to the signature declared above, which is now
+        // completed with the body.
+        if let Some(decl_id) = signature_decl_id {
+            decl_engine.replace(decl_id, function_decl.clone());
+        }
+
+        ok(function_decl, warnings, errors)
+    }
+
+    /// Type checks the signature of `fn_decl` and declares it in the current namespace, ahead of
+    /// type checking its body. This allows functions which call each other to be type checked, as
+    /// the body of each may then call the others.
+    pub(crate) fn forward_declare(
+        mut ctx: TypeCheckContext,
+        fn_decl: FunctionDeclaration,
+    ) -> CompileResult<()> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let signature = check!(
+            Self::signature_declaration(ctx.by_ref(), fn_decl, false, false),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let name = signature.name.clone();
+        let decl: ty::TyDeclaration = ctx.decl_engine.insert(signature).into();
+        check!(
+            ctx.namespace.insert_symbol(name, decl),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok((), warnings, errors)
+    }
+
+    /// Type checks the signature of the method `fn_decl` ahead of type checking its body, and
+    /// returns a declaration of it. Once an impl block has declared its methods in the trait map
+    /// this way, their bodies may call themselves and each other. The declaration must then be
+    /// replaced with the complete method.
+    pub(crate) fn forward_declare_method(
+        mut ctx: TypeCheckContext,
+        fn_decl: FunctionDeclaration,
+        is_in_impl_self: bool,
+    ) -> CompileResult<DeclRefFunction> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let signature = check!(
+            Self::signature_declaration(ctx.by_ref(), fn_decl, true, is_in_impl_self),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        ok(ctx.decl_engine.insert(signature), warnings, errors)
+    }
+
+    /// Type checks the signature of `fn_decl` in a namespace of its own, and returns a
+    /// declaration of the function with an empty body.
+    fn signature_declaration(
+        mut ctx: TypeCheckContext,
+        fn_decl: FunctionDeclaration,
+        is_method: bool,
+        is_in_impl_self: bool,
+    ) -> CompileResult<Self> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
 
-        let function_decl = ty::TyFunctionDeclaration {
+        let FunctionDeclaration {
             name,
-            body,
-            parameters: new_parameters,
-            implementing_type: None,
+            parameters,
             span,
             attributes,
             return_type,
-            type_parameters: new_type_parameters,
+            type_parameters,
             visibility,
-            is_contract_call,
             purity,
             where_clause,
-        };
+            ..
+        } = fn_decl;
 
-        ok(function_decl, warnings, errors)
+        let mut fn_namespace = ctx.namespace.clone();
+        let mut ctx = ctx
+            .by_ref()
+            .scoped(&mut fn_namespace)
+            .with_purity(purity)
+            .disallow_functions();
+        let (type_parameters, parameters, return_type) = check!(
+            Self::type_check_signature(
+                ctx.by_ref(),
+                type_parameters,
+                parameters,
+                return_type,
+                is_method
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let (visibility, is_contract_call) =
+            visibility_and_is_contract_call(&ctx, visibility, is_method, is_in_impl_self);
+        ok(
+            ty::TyFunctionDeclaration {
+                name,
+                body: ty::TyCodeBlock { contents: vec![] },
+                parameters,
+                implementing_type: None,
+                span,
+                attributes,
+                return_type,
+                type_parameters,
+                visibility,
+                is_contract_call,
+                purity,
+                where_clause,
+            },
+            warnings,
+            errors,
+        )
+    }
+
+    /// Type checks the type parameters, parameters and return type of a function, inserting the
+    /// type parameters and parameters into the current namespace.
+    fn type_check_signature(
+        mut ctx: TypeCheckContext,
+        type_parameters: Vec<TypeParameter>,
+        parameters: Vec<FunctionParameter>,
+        mut return_type: TypeArgument,
+        is_method: bool,
+    ) -> CompileResult<(
+        Vec<TypeParameter>,
+        Vec<ty::TyFunctionParameter>,
+        TypeArgument,
+    )> {
+        let mut warnings = Vec::new();
+        let mut errors = Vec::new();
+
+        let type_engine = ctx.type_engine;
+        let decl_engine = ctx.decl_engine;
+
+        // Type check the type parameters. This will also insert them into the
+        // current namespace.
+        let new_type_parameters = check!(
+            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        // type check the function parameters, which will also insert them into the namespace
+        let mut new_parameters = vec![];
+        for parameter in parameters.into_iter() {
+            new_parameters.push(check!(
+                ty::TyFunctionParameter::type_check(ctx.by_ref(), parameter, is_method),
+                continue,
+                warnings,
+                errors
+            ));
+        }
+        if !errors.is_empty() {
+            return err(warnings, errors);
+        }
+
+        // type check the return type
+        return_type.type_id = check!(
+            ctx.resolve_type_with_self(
+                return_type.type_id,
+                &return_type.span,
+                EnforceTypeArguments::Yes,
+                None
+            ),
+            type_engine.insert(decl_engine, TypeInfo::ErrorRecovery),
+            warnings,
+            errors,
+        );
+
+        ok(
+            (new_type_parameters, new_parameters, return_type),
+            warnings,
+            errors,
+        )
+    }
+}
+
+/// Returns the visibility of a function, and whether it is a contract call. Methods of traits are
+/// always public.
+fn visibility_and_is_contract_call(
+    ctx: &TypeCheckContext,
+    visibility: Visibility,
+    is_method: bool,
+    is_in_impl_self: bool,
+) -> (Visibility, bool) {
+    if is_method {
+        if is_in_impl_self {
+            (visibility, false)
+        } else {
+            (Visibility::Public, false)
+        }
+    } else {
+        (visibility, ctx.mode() == Mode::ImplAbiFn)
+    }
+}
+
+/// Declares the signature of a function in the current namespace, so that the function's body
+/// may refer to it, and returns the [DeclId] the complete declaration must replace.
+///
+/// The signature may already have been declared by [ty::TyFunctionDeclaration::forward_declare].
+/// It isn't declared if its name is shadowed by one of its parameters.
+fn declare_signature(
+    ctx: TypeCheckContext,
+    signature: &ty::TyFunctionDeclaration,
+) -> Option<DeclId<ty::TyFunctionDeclaration>> {
+    let decl_engine = ctx.decl_engine;
+    match ctx.namespace.symbols().get(&signature.name) {
+        Some(ty::TyDeclaration::FunctionDeclaration { decl_id, .. })
+            if decl_engine.get_function(decl_id).span == signature.span =>
+        {
+            return Some(*decl_id);
+        }
+        Some(ty::TyDeclaration::FunctionDeclaration { .. }) | None => {}
+        Some(_) => return None,
     }
+    let decl_ref = decl_engine.insert(signature.clone());
+    let decl_id = *decl_ref.id();
+    ctx.namespace
+        .insert_symbol(signature.name.clone(), decl_ref.into());
+    Some(decl_id)
 }
 
 /// Unifies the types of the return statements and the return type of the
@@ -60,7 +60,7 @@ impl ty::TyFunctionParameter {
             type_argument,
         };
 
-        insert_into_namespace(ctx, &typed_parameter);
+        typed_parameter.insert_into_namespace(ctx);
 
         ok(typed_parameter, warnings, errors)
     }
@@ -105,24 +105,25 @@ impl ty::TyFunctionParameter {
 
         ok(typed_parameter, warnings, errors)
     }
-}
 
-fn insert_into_namespace(ctx: TypeCheckContext, typed_parameter: &ty::TyFunctionParameter) {
-    ctx.namespace.insert_symbol(
-        typed_parameter.name.clone(),
-        ty::TyDeclaration::VariableDeclaration(Box::new(ty::TyVariableDeclaration {
-            name: typed_parameter.name.clone(),
-            body: ty::TyExpression {
-                expression: ty::TyExpressionVariant::FunctionParameter,
-                return_type: typed_parameter.type_argument.type_id,
-                span: typed_parameter.name.span(),
-            },
-            mutability: ty::VariableMutability::new_from_ref_mut(
-                typed_parameter.is_reference,
-                typed_parameter.is_mutable,
-            ),
-            return_type: typed_parameter.type_argument.type_id,
-            type_ascription: typed_parameter.type_argument.clone(),
-        })),
-    );
+    /// Inserts the parameter into the namespace as a variable declaration.
+    pub(crate) fn insert_into_namespace(&self, ctx: TypeCheckContext) {
+        ctx.namespace.insert_symbol(
+            self.name.clone(),
+            ty::TyDeclaration::VariableDeclaration(Box::new(ty::TyVariableDeclaration {
+                name: self.name.clone(),
+                body: ty::TyExpression {
+                    expression: ty::TyExpressionVariant::FunctionParameter,
+                    return_type: self.type_argument.type_id,
+                    span: self.name.span(),
+                },
+                mutability: ty::VariableMutability::new_from_ref_mut(
+                    self.is_reference,
+                    self.is_mutable,
+                ),
+                return_type: self.type_argument.type_id,
+                type_ascription: self.type_argument.clone(),
+            })),
+        );
+    }
 }
@@ -44,7 +44,7 @@ impl ty::TyImplTrait {
         // Type check the type parameters. This will also insert them into the
         // current namespace.
         let new_impl_type_parameters = check!(
-            TypeParameter::type_check_type_params(ctx.by_ref(), impl_type_parameters, true),
+            TypeParameter::type_check_type_params(ctx.by_ref(), impl_type_parameters),
             return err(warnings, errors),
             warnings,
             errors
@@ -155,11 +155,35 @@ impl ty::TyImplTrait {
             }
             Some(ty::TyDeclaration::AbiDeclaration { decl_id, .. }) => {
                 // if you are comparing this with the `impl_trait` branch above, note that
-                // there are no type arguments here because we don't support generic types
-                // in contract ABIs yet (or ever?) due to the complexity of communicating
-                // the ABI layout in the descriptor file.
+                // the implementation itself can't be generic: the type arguments of a generic
+                // ABI have to be concrete so that the ABI layout can be communicated in the
+                // descriptor file.
+                if let Some(span) = new_impl_type_parameters
+                    .iter()
+                    .map(|type_param| type_param.name_ident.span())
+                    .reduce(Span::join)
+                {
+                    errors.push(CompileError::GenericImplAbi {
+                        abi_name: trait_name.suffix.to_string(),
+                        span,
+                    });
+                    return err(warnings, errors);
+                }
+
+                let mut abi = decl_engine.get_abi(&decl_id);
 
-                let abi = decl_engine.get_abi(&decl_id);
+                // monomorphize the abi declaration
+                check!(
+                    ctx.monomorphize(
+                        &mut abi,
+                        &mut trait_type_arguments,
+                        EnforceTypeArguments::Yes,
+                        &trait_name.span()
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                );
 
                 if !type_engine
                     .get(implementing_for.type_id)
@@ -176,9 +200,9 @@ impl ty::TyImplTrait {
                 let new_items = check!(
                     type_check_trait_implementation(
                         ctx.by_ref(),
-                        &[], // this is empty because abi definitions don't support generics,
-                        &[], // this is empty because abi definitions don't support generics,
-                        &[], // this is empty because abi definitions don't support generics,
+                        &[], // this is empty because abi implementations can't be generic
+                        &abi.type_parameters,
+                        &trait_type_arguments,
                         &abi.supertraits,
                         &abi.interface_surface,
                         &abi.items,
@@ -192,9 +216,9 @@ impl ty::TyImplTrait {
                     errors
                 );
                 ty::TyImplTrait {
-                    impl_type_parameters: vec![], // this is empty because abi definitions don't support generics
+                    impl_type_parameters: vec![], // this is empty because abi implementations can't be generic
                     trait_name,
-                    trait_type_arguments: vec![], // this is empty because abi definitions don't support generics
+                    trait_type_arguments,
                     trait_decl_ref: Some(DeclRef::new(abi.name.clone(), decl_id.into(), abi.span)),
                     span: block_span,
                     items: new_items,
@@ -251,7 +275,8 @@ impl ty::TyImplTrait {
                 | ty::TyExpressionVariant::Break
                 | ty::TyExpressionVariant::Continue
                 | ty::TyExpressionVariant::StorageAccess(_)
-                | ty::TyExpressionVariant::AbiName(_) => false,
+                | ty::TyExpressionVariant::AbiName(_)
+                | ty::TyExpressionVariant::ConstGenericValue { .. } => false,
                 ty::TyExpressionVariant::FunctionApplication { arguments, .. } => {
                     for f in arguments.iter() {
                         let b = expr_contains_get_storage_index(decl_engine, &f.1, access_span)?;
@@ -315,7 +340,8 @@ impl ty::TyImplTrait {
                 | ty::TyExpressionVariant::TupleElemAccess { prefix: exp, .. }
                 | ty::TyExpressionVariant::AbiCast { address: exp, .. }
                 | ty::TyExpressionVariant::EnumTag { exp }
-                | ty::TyExpressionVariant::UnsafeDowncast { exp, .. } => {
+                | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+                | ty::TyExpressionVariant::TraitObject { exp, .. } => {
                     expr_contains_get_storage_index(decl_engine, exp, access_span)?
                 }
                 ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
@@ -345,6 +371,31 @@ impl ty::TyImplTrait {
                 ty::TyExpressionVariant::Return(exp) => {
                     expr_contains_get_storage_index(decl_engine, exp, access_span)?
                 }
+                ty::TyExpressionVariant::Closure { fn_ref, captures } => {
+                    for (_, capture) in captures.iter() {
+                        let b = expr_contains_get_storage_index(decl_engine, capture, access_span)?;
+                        if b {
+                            return Ok(true);
+                        }
+                    }
+                    let body = decl_engine.get_function(fn_ref).body;
+                    codeblock_contains_get_storage_index(decl_engine, &body, access_span)?
+                }
+                ty::TyExpressionVariant::ClosureCall {
+                    closure: object,
+                    arguments,
+                }
+                | ty::TyExpressionVariant::DynamicMethodCall {
+                    object, arguments, ..
+                } => {
+                    for f in std::iter::once(&**object).chain(arguments.iter()) {
+                        let b = expr_contains_get_storage_index(decl_engine, f, access_span)?;
+                        if b {
+                            return Ok(true);
+                        }
+                    }
+                    false
+                }
             };
             Ok(res)
         }
@@ -377,6 +428,7 @@ impl ty::TyImplTrait {
                 | ty::TyDeclaration::ImplTrait { .. }
                 | ty::TyDeclaration::AbiDeclaration { .. }
                 | ty::TyDeclaration::GenericTypeForFunctionScope { .. }
+                | ty::TyDeclaration::TypeAliasDeclaration { .. }
                 | ty::TyDeclaration::ErrorRecovery(_)
                 | ty::TyDeclaration::StorageDeclaration { .. } => Ok(false),
             }
@@ -409,6 +461,15 @@ impl ty::TyImplTrait {
                     let method = decl_engine.get_function(fn_decl);
                     codeblock_contains_get_storage_index(decl_engine, &method.body, access_span)?
                 }
+                ty::TyTraitItem::Constant(decl_ref) => {
+                    match decl_engine.get_constant(decl_ref).value {
+                        Some(expr) => {
+                            expr_contains_get_storage_index(decl_engine, &expr, access_span)?
+                        }
+                        None => false,
+                    }
+                }
+                ty::TyTraitItem::Type(_) => false,
             };
             if contains_get_storage_index {
                 type_engine.set_type_as_storage_only(impl_typ);
@@ -454,7 +515,7 @@ impl ty::TyImplTrait {
         // Type check the type parameters. This will also insert them into the
         // current namespace.
         let new_impl_type_parameters = check!(
-            TypeParameter::type_check_type_params(ctx.by_ref(), impl_type_parameters, true),
+            TypeParameter::type_check_type_params(ctx.by_ref(), impl_type_parameters),
             return err(warnings, errors),
             warnings,
             errors
@@ -500,7 +561,16 @@ impl ty::TyImplTrait {
         // type check the items inside of the impl block
         let mut new_items = vec![];
 
-        for item in items.into_iter() {
+        // Associated constants are type checked first, so that the methods
+        // of the impl block can refer to them as `Self::<name>`.
+        let (const_items, fn_items): (Vec<_>, Vec<_>) = items
+            .into_iter()
+            .partition(|item| !matches!(item, ImplItem::Fn(_)));
+
+        let method_signatures =
+            forward_declare_methods(ctx.by_ref(), &fn_items, &trait_name, &[], &block_span, true);
+
+        for item in const_items.into_iter().chain(fn_items) {
             match item {
                 ImplItem::Fn(fn_decl) => {
                     let fn_decl = check!(
@@ -509,7 +579,36 @@ impl ty::TyImplTrait {
                         warnings,
                         errors
                     );
-                    new_items.push(TyImplItem::Fn(decl_engine.insert(fn_decl)));
+                    new_items.push(TyImplItem::Fn(complete_method(
+                        decl_engine,
+                        &method_signatures,
+                        fn_decl,
+                    )));
+                }
+                ImplItem::Constant(const_decl) => {
+                    let const_decl = check!(
+                        ty::TyConstantDeclaration::type_check(ctx.by_ref(), const_decl),
+                        continue,
+                        warnings,
+                        errors
+                    );
+                    let name = const_decl.call_path.suffix.clone();
+                    let decl_ref = decl_engine.insert(const_decl);
+                    check!(
+                        ctx.namespace.insert_symbol(
+                            ty::self_associated_item_symbol(&name),
+                            decl_ref.clone().into()
+                        ),
+                        continue,
+                        warnings,
+                        errors
+                    );
+                    new_items.push(TyImplItem::Constant(decl_ref));
+                }
+                ImplItem::Type(type_decl) => {
+                    errors.push(CompileError::AssociatedTypeNotSupportedInImplSelf {
+                        span: type_decl.span,
+                    });
                 }
             }
         }
@@ -559,6 +658,7 @@ fn type_check_trait_implementation(
     let mut errors = vec![];
     let mut warnings = vec![];
 
+    let type_engine = ctx.type_engine;
     let decl_engine = ctx.decl_engine;
     let engines = ctx.engines();
     let self_type = ctx.self_type();
@@ -586,6 +686,11 @@ fn type_check_trait_implementation(
     // that still need to be implemented for the trait to be fully implemented.
     let mut method_checklist: BTreeMap<Ident, ty::TyTraitFn> = BTreeMap::new();
 
+    // These maps keep track of the associated constants and types in the
+    // interface surface that still need to be provided by the impl block.
+    let mut constant_checklist: BTreeMap<Ident, ty::TyConstantDeclaration> = BTreeMap::new();
+    let mut type_checklist: BTreeMap<Ident, ty::TyTraitType> = BTreeMap::new();
+
     // This map keeps track of the interface declaration id's of the trait
     // definition.
     let mut interface_item_refs: InterfaceItemMap = BTreeMap::new();
@@ -643,11 +748,184 @@ fn type_check_trait_implementation(
                 // Add this method to the "interface items".
                 interface_item_refs.insert(name, item.clone());
             }
+            TyTraitInterfaceItem::Constant(decl_ref) => {
+                let const_decl = decl_engine.get_constant(decl_ref);
+                constant_checklist.insert(const_decl.call_path.suffix.clone(), const_decl);
+                interface_item_refs.insert(decl_ref.name().clone(), item.clone());
+            }
+            TyTraitInterfaceItem::Type(decl_ref) => {
+                let type_decl = decl_engine.get_trait_type(decl_ref);
+                type_checklist.insert(type_decl.name.clone(), type_decl);
+                interface_item_refs.insert(decl_ref.name().clone(), item.clone());
+            }
         }
     }
 
+    let interface_name = || -> InterfaceName {
+        if is_contract {
+            InterfaceName::Abi(trait_name.suffix.clone())
+        } else {
+            InterfaceName::Trait(trait_name.suffix.clone())
+        }
+    };
+
+    // Type check the associated types first, so that the constants and
+    // methods of the impl block can refer to them as `Self::<name>`. Each
+    // associated type of the trait is then replaced by the concrete type
+    // provided by this impl block.
+    let mut assoc_type_placeholders = vec![];
+    let mut assoc_type_impls = vec![];
+    for item in impl_items {
+        if let ImplItem::Type(type_decl) = item {
+            let type_decl = check!(
+                ty::TyTraitType::type_check(ctx.by_ref(), type_decl.clone()),
+                continue,
+                warnings,
+                errors
+            );
+            let name = type_decl.name.clone();
+            if impld_item_refs.contains_key(&name) {
+                errors.push(CompileError::MultipleDefinitionsOfName {
+                    name: name.clone(),
+                    span: name.span(),
+                });
+                continue;
+            }
+            match type_checklist.remove(&name) {
+                Some(trait_type) => {
+                    assoc_type_placeholders.push(trait_type.ty.type_id);
+                    assoc_type_impls.push(type_decl.ty.type_id);
+                }
+                None => {
+                    errors.push(CompileError::TypeNotAPartOfInterfaceSurface {
+                        name: name.clone(),
+                        interface_name: interface_name(),
+                        span: name.span(),
+                    });
+                    continue;
+                }
+            }
+            check!(
+                type_decl.insert_into_namespace(ctx.by_ref()),
+                continue,
+                warnings,
+                errors
+            );
+            impld_item_refs.insert(name, TyTraitItem::Type(decl_engine.insert(type_decl)));
+        }
+    }
+    let assoc_type_mapping = TypeSubstMap::from_type_parameters_and_type_arguments(
+        assoc_type_placeholders.clone(),
+        assoc_type_impls.clone(),
+    );
+    method_checklist
+        .values_mut()
+        .for_each(|method| method.subst(&assoc_type_mapping, engines));
+    constant_checklist.values_mut().for_each(|const_decl| {
+        const_decl.subst(&assoc_type_mapping, engines);
+        const_decl.replace_self_type(engines, self_type);
+    });
+
+    for item in impl_items {
+        if let ImplItem::Constant(const_decl) = item {
+            let const_decl = check!(
+                ty::TyConstantDeclaration::type_check(ctx.by_ref(), const_decl.clone()),
+                continue,
+                warnings,
+                errors
+            );
+            let name = const_decl.call_path.suffix.clone();
+            if impld_item_refs.contains_key(&name) {
+                errors.push(CompileError::MultipleDefinitionsOfName {
+                    name: name.clone(),
+                    span: name.span(),
+                });
+                continue;
+            }
+            match constant_checklist.remove(&name) {
+                Some(trait_const) => {
+                    if !type_engine.get(const_decl.type_ascription.type_id).eq(
+                        &type_engine.get(trait_const.type_ascription.type_id),
+                        engines,
+                    ) {
+                        errors.push(CompileError::MismatchedTypeInInterfaceSurface {
+                            interface_name: interface_name(),
+                            span: const_decl.type_ascription.span.clone(),
+                            decl_type: "constant".to_string(),
+                            given: engines
+                                .help_out(const_decl.type_ascription.type_id)
+                                .to_string(),
+                            expected: engines
+                                .help_out(trait_const.type_ascription.type_id)
+                                .to_string(),
+                        });
+                        continue;
+                    }
+                }
+                None => {
+                    errors.push(CompileError::ConstantNotAPartOfInterfaceSurface {
+                        name: name.clone(),
+                        interface_name: interface_name(),
+                        span: name.span(),
+                    });
+                    continue;
+                }
+            }
+            let decl_ref = decl_engine.insert(const_decl);
+            check!(
+                ctx.namespace.insert_symbol(
+                    ty::self_associated_item_symbol(&name),
+                    decl_ref.clone().into()
+                ),
+                continue,
+                warnings,
+                errors
+            );
+            impld_item_refs.insert(name, TyTraitItem::Constant(decl_ref));
+        }
+    }
+
+    // Associated constants that were not provided by the impl block fall
+    // back to the default value given in the trait declaration, if any.
+    let missing_constants = constant_checklist
+        .iter()
+        .filter(|(_, const_decl)| const_decl.value.is_none())
+        .map(|(name, _)| name.to_string())
+        .collect::<Vec<_>>();
+    for (name, const_decl) in constant_checklist.into_iter() {
+        if const_decl.value.is_none() {
+            continue;
+        }
+        let decl_ref = decl_engine.insert(const_decl);
+        check!(
+            ctx.namespace.insert_symbol(
+                ty::self_associated_item_symbol(&name),
+                decl_ref.clone().into()
+            ),
+            continue,
+            warnings,
+            errors
+        );
+        impld_item_refs.insert(name, TyTraitItem::Constant(decl_ref));
+    }
+
+    // The methods of contracts can't be called from within the contract.
+    let method_signatures = if is_contract {
+        vec![]
+    } else {
+        forward_declare_methods(
+            ctx.by_ref(),
+            impl_items,
+            trait_name,
+            trait_type_arguments,
+            block_span,
+            false,
+        )
+    };
+
     for item in impl_items {
         match item {
+            ImplItem::Constant(_) | ImplItem::Type(_) => {}
             ImplItem::Fn(impl_method) => {
                 let impl_method = check!(
                     type_check_impl_method(
@@ -669,7 +947,7 @@ fn type_check_trait_implementation(
                 method_checklist.remove(&name);
 
                 // Add this method to the "impld items".
-                let decl_ref = decl_engine.insert(impl_method);
+                let decl_ref = complete_method(decl_engine, &method_signatures, impl_method);
                 impld_item_refs.insert(name, TyTraitItem::Fn(decl_ref));
             }
         }
@@ -687,10 +965,12 @@ fn type_check_trait_implementation(
         trait_type_parameters
             .iter()
             .map(|type_param| type_param.type_id)
+            .chain(assoc_type_placeholders)
             .collect(),
         trait_type_arguments
             .iter()
             .map(|type_arg| type_arg.type_id)
+            .chain(assoc_type_impls)
             .collect(),
     );
     interface_item_refs.extend(supertrait_interface_item_refs);
@@ -713,6 +993,8 @@ fn type_check_trait_implementation(
                         .with_parent(decl_engine, (*decl_ref.id()).into()),
                 ));
             }
+            // Trait declarations only contain methods in their items.
+            TyImplItem::Constant(_) | TyImplItem::Type(_) => {}
         }
     }
 
@@ -721,6 +1003,22 @@ fn type_check_trait_implementation(
         errors.push(CompileError::MissingInterfaceSurfaceMethods {
             span: block_span.clone(),
             missing_functions: method_checklist
+                .into_keys()
+                .map(|ident| ident.as_str().to_string())
+                .collect(),
+            interface_name: interface_name(),
+        });
+    }
+    if !missing_constants.is_empty() {
+        errors.push(CompileError::MissingInterfaceSurfaceConstants {
+            span: block_span.clone(),
+            missing_constants: missing_constants.join("\n"),
+        });
+    }
+    if !type_checklist.is_empty() {
+        errors.push(CompileError::MissingInterfaceSurfaceTypes {
+            span: block_span.clone(),
+            missing_types: type_checklist
                 .into_keys()
                 .map(|ident| ident.as_str().to_string())
                 .collect::<Vec<_>>()
@@ -735,6 +1033,75 @@ fn type_check_trait_implementation(
     }
 }
 
+/// Type checks the signatures of the methods among `items`, and declares them in the trait map for
+/// the self type ahead of type checking their bodies, so that the methods may call themselves and
+/// each other. Returns the declarations of the signatures, which [complete_method] replaces with
+/// the complete methods.
+///
+/// Any errors in the signatures are reported when each method is type checked in full.
+fn forward_declare_methods(
+    mut ctx: TypeCheckContext,
+    items: &[ImplItem],
+    trait_name: &CallPath,
+    trait_type_arguments: &[TypeArgument],
+    block_span: &Span,
+    is_impl_self: bool,
+) -> Vec<DeclRefFunction> {
+    let engines = ctx.engines();
+    let self_type = ctx.self_type();
+
+    let method_signatures: Vec<DeclRefFunction> = items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(fn_decl) => {
+                ty::TyFunctionDeclaration::forward_declare_method(
+                    ctx.by_ref(),
+                    fn_decl.clone(),
+                    is_impl_self,
+                )
+                .value
+            }
+            ImplItem::Constant(_) | ImplItem::Type(_) => None,
+        })
+        .collect();
+
+    let _ = ctx.namespace.insert_trait_implementation(
+        trait_name.clone(),
+        trait_type_arguments.to_vec(),
+        self_type,
+        &method_signatures
+            .iter()
+            .cloned()
+            .map(TyImplItem::Fn)
+            .collect::<Vec<_>>(),
+        block_span,
+        is_impl_self,
+        engines,
+    );
+
+    method_signatures
+}
+
+/// Returns a reference to the type checked `method`. The signature of the method was declared by
+/// [forward_declare_methods] if it was type checked successfully, in which case the declaration is
+/// replaced, so that the calls of the method in the bodies of the impl block refer to it.
+fn complete_method(
+    decl_engine: &DeclEngine,
+    method_signatures: &[DeclRefFunction],
+    method: ty::TyFunctionDeclaration,
+) -> DeclRefFunction {
+    match method_signatures
+        .iter()
+        .find(|signature| signature.decl_span() == &method.span)
+    {
+        Some(signature) => {
+            decl_engine.replace(*signature.id(), method);
+            signature.clone()
+        }
+        None => decl_engine.insert(method),
+    }
+}
+
 fn type_check_impl_method(
     mut ctx: TypeCheckContext,
     impl_type_parameters: &[TypeParameter],
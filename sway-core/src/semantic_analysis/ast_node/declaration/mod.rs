@@ -1,4 +1,5 @@
 mod abi;
+mod constant;
 #[allow(clippy::module_inception)]
 mod declaration;
 mod r#enum;
@@ -9,6 +10,8 @@ mod r#struct;
 mod supertrait;
 mod r#trait;
 mod trait_fn;
+mod trait_type;
+mod type_alias;
 
 pub use abi::*;
 pub use function::*;
@@ -2,6 +2,7 @@ use sway_error::error::CompileError;
 use sway_types::Spanned;
 
 use crate::{
+    decl_engine::DeclRef,
     error::*,
     language::{parsed, ty},
     semantic_analysis::TypeCheckContext,
@@ -10,17 +11,19 @@ use crate::{
 
 /// Recursively insert the interface surfaces and methods from supertraits to
 /// the given namespace.
+///
+/// The declarations of the supertraits are recorded in `supertraits`.
 pub(crate) fn insert_supertraits_into_namespace(
     mut ctx: TypeCheckContext,
     type_id: TypeId,
-    supertraits: &[parsed::Supertrait],
+    supertraits: &mut [parsed::Supertrait],
 ) -> CompileResult<()> {
     let mut warnings = vec![];
     let mut errors = vec![];
 
     let decl_engine = ctx.decl_engine;
 
-    for supertrait in supertraits.iter() {
+    for supertrait in supertraits.iter_mut() {
         // Right now we don't have the ability to support defining a supertrait
         // using a callpath directly, so we check to see if the user has done
         // this and we disallow it.
@@ -39,7 +42,13 @@ pub(crate) fn insert_supertraits_into_namespace(
             .ok(&mut warnings, &mut errors)
             .cloned()
         {
-            Some(ty::TyDeclaration::TraitDeclaration { decl_id, .. }) => {
+            Some(ty::TyDeclaration::TraitDeclaration {
+                name,
+                decl_id,
+                decl_span,
+                ..
+            }) => {
+                supertrait.decl_ref = Some(DeclRef::new(name, decl_id, decl_span));
                 let mut trait_decl = decl_engine.get_trait(&decl_id);
 
                 // Right now we don't parse type arguments for supertraits, so
@@ -83,7 +92,7 @@ pub(crate) fn insert_supertraits_into_namespace(
                     insert_supertraits_into_namespace(
                         ctx.by_ref(),
                         type_id,
-                        &trait_decl.supertraits
+                        &mut trait_decl.supertraits
                     ),
                     continue,
                     warnings,
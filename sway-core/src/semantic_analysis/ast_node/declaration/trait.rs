@@ -32,7 +32,7 @@ impl ty::TyTraitDeclaration {
             attributes,
             interface_surface,
             methods,
-            supertraits,
+            mut supertraits,
             visibility,
             span,
         } = trait_decl;
@@ -56,7 +56,7 @@ impl ty::TyTraitDeclaration {
         // Type check the type parameters. This will also insert them into the
         // current namespace.
         let new_type_parameters = check!(
-            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters, true),
+            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters),
             return err(warnings, errors),
             warnings,
             errors
@@ -65,7 +65,7 @@ impl ty::TyTraitDeclaration {
         // Recursively make the interface surfaces and methods of the
         // supertraits available to this trait.
         check!(
-            insert_supertraits_into_namespace(ctx.by_ref(), self_type, &supertraits),
+            insert_supertraits_into_namespace(ctx.by_ref(), self_type, &mut supertraits),
             return err(warnings, errors),
             warnings,
             errors
@@ -77,7 +77,13 @@ impl ty::TyTraitDeclaration {
 
         let mut ids: HashSet<Ident> = HashSet::default();
 
-        for item in interface_surface.into_iter() {
+        // Associated types are type checked first and made available as
+        // `Self::<name>` so that the rest of the interface can refer to them.
+        let (type_items, other_items): (Vec<_>, Vec<_>) = interface_surface
+            .into_iter()
+            .partition(|item| matches!(item, TraitItem::Type(_)));
+
+        for item in type_items.into_iter().chain(other_items) {
             let decl_name = match item {
                 TraitItem::TraitFn(method) => {
                     let method = check!(
@@ -95,6 +101,52 @@ impl ty::TyTraitDeclaration {
                     new_interface_surface.push(ty::TyTraitInterfaceItem::TraitFn(decl_ref));
                     method.name.clone()
                 }
+                TraitItem::Constant(const_decl) => {
+                    let const_decl = check!(
+                        ty::TyConstantDeclaration::type_check(ctx.by_ref(), const_decl),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    let name = const_decl.call_path.suffix.clone();
+
+                    // The value of an associated constant can be overridden by
+                    // each implementation, so it is unknown within the trait.
+                    let mut scoped_const_decl = const_decl.clone();
+                    scoped_const_decl.value = None;
+                    check!(
+                        ctx.namespace.insert_symbol(
+                            ty::self_associated_item_symbol(&name),
+                            decl_engine.insert(scoped_const_decl).into()
+                        ),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    new_interface_surface.push(ty::TyTraitInterfaceItem::Constant(
+                        decl_engine.insert(const_decl),
+                    ));
+                    name
+                }
+                TraitItem::Type(type_decl) => {
+                    let type_decl = check!(
+                        ty::TyTraitType::type_check(ctx.by_ref(), type_decl),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    check!(
+                        type_decl.insert_into_namespace(ctx.by_ref()),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    let name = type_decl.name.clone();
+                    new_interface_surface.push(ty::TyTraitInterfaceItem::Type(
+                        decl_engine.insert(type_decl),
+                    ));
+                    name
+                }
             };
 
             if !ids.insert(decl_name.clone()) {
@@ -169,11 +221,7 @@ impl ty::TyTraitDeclaration {
 
         // Retrieve the interface surface for this trait.
         for item in interface_surface.iter() {
-            match item {
-                ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
-                    interface_surface_item_refs.insert(decl_ref.name().clone(), item.clone());
-                }
-            }
+            interface_surface_item_refs.insert(item.name().clone(), item.clone());
         }
 
         // Retrieve the implemented items for this type.
@@ -182,11 +230,7 @@ impl ty::TyTraitDeclaration {
             .get_items_for_type_and_trait_name(engines, type_id, call_path)
             .into_iter()
         {
-            #[allow(clippy::infallible_destructuring_match)]
-            let decl_ref = match &item {
-                ty::TyTraitItem::Fn(decl_ref) => decl_ref,
-            };
-            impld_item_refs.insert(decl_ref.name().clone(), item.clone());
+            impld_item_refs.insert(item.name().clone(), item.clone());
         }
 
         (interface_surface_item_refs, impld_item_refs)
@@ -217,20 +261,12 @@ impl ty::TyTraitDeclaration {
 
         // Retrieve the interface surface for this trait.
         for item in interface_surface.iter() {
-            match item {
-                ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
-                    interface_surface_item_refs.insert(decl_ref.name().clone(), item.clone());
-                }
-            }
+            interface_surface_item_refs.insert(item.name().clone(), item.clone());
         }
 
         // Retrieve the trait items for this trait.
         for item in items.iter() {
-            match item {
-                ty::TyTraitItem::Fn(decl_ref) => {
-                    item_refs.insert(decl_ref.name().clone(), item.clone());
-                }
-            }
+            item_refs.insert(item.name().clone(), item.clone());
         }
 
         // Retrieve the implemented items for this type.
@@ -262,6 +298,22 @@ impl ty::TyTraitDeclaration {
                         ),
                     );
                 }
+                ty::TyTraitItem::Constant(decl_ref) => {
+                    let mut const_decl = decl_engine.get_constant(&decl_ref);
+                    const_decl.subst(&type_mapping, engines);
+                    impld_item_refs.insert(
+                        const_decl.call_path.suffix.clone(),
+                        TyTraitItem::Constant(decl_engine.insert(const_decl)),
+                    );
+                }
+                ty::TyTraitItem::Type(decl_ref) => {
+                    let mut type_decl = decl_engine.get_trait_type(&decl_ref);
+                    type_decl.subst(&type_mapping, engines);
+                    impld_item_refs.insert(
+                        type_decl.name.clone(),
+                        TyTraitItem::Type(decl_engine.insert(type_decl)),
+                    );
+                }
             }
         }
 
@@ -313,6 +365,18 @@ impl ty::TyTraitDeclaration {
                             .with_parent(ctx.decl_engine, (*decl_ref.id()).into()),
                     ));
                 }
+                ty::TyTraitInterfaceItem::Constant(decl_ref) => {
+                    let mut const_decl = decl_engine.get_constant(decl_ref);
+                    const_decl.replace_self_type(engines, type_id);
+                    const_decl.subst(&type_mapping, engines);
+                    all_items.push(TyImplItem::Constant(decl_engine.insert(const_decl)));
+                }
+                ty::TyTraitInterfaceItem::Type(decl_ref) => {
+                    let mut type_decl = decl_engine.get_trait_type(decl_ref);
+                    type_decl.replace_self_type(engines, type_id);
+                    type_decl.subst(&type_mapping, engines);
+                    all_items.push(TyImplItem::Type(decl_engine.insert(type_decl)));
+                }
             }
         }
         for item in items.iter() {
@@ -327,6 +391,18 @@ impl ty::TyTraitDeclaration {
                             .with_parent(ctx.decl_engine, (*decl_ref.id()).into()),
                     ));
                 }
+                ty::TyTraitItem::Constant(decl_ref) => {
+                    let mut const_decl = decl_engine.get_constant(decl_ref);
+                    const_decl.replace_self_type(engines, type_id);
+                    const_decl.subst(&type_mapping, engines);
+                    all_items.push(TyImplItem::Constant(decl_engine.insert(const_decl)));
+                }
+                ty::TyTraitItem::Type(decl_ref) => {
+                    let mut type_decl = decl_engine.get_trait_type(decl_ref);
+                    type_decl.replace_self_type(engines, type_id);
+                    type_decl.subst(&type_mapping, engines);
+                    all_items.push(TyImplItem::Type(decl_engine.insert(type_decl)));
+                }
             }
         }
 
@@ -0,0 +1,87 @@
+use crate::{
+    error::*,
+    language::{parsed, ty},
+    semantic_analysis::TypeCheckContext,
+    type_system::*,
+};
+use sway_types::Spanned;
+
+impl ty::TyTraitType {
+    /// Type checks an associated type.
+    ///
+    /// When `decl.ty_opt` is `None`, as in `type Item;` inside of a trait
+    /// declaration, a fresh placeholder type is created that stands in for
+    /// the associated type until the trait is implemented for some type.
+    pub(crate) fn type_check(
+        mut ctx: TypeCheckContext,
+        decl: parsed::TraitTypeDeclaration,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let type_engine = ctx.type_engine;
+        let decl_engine = ctx.decl_engine;
+
+        let parsed::TraitTypeDeclaration {
+            name,
+            attributes,
+            ty_opt,
+            span,
+        } = decl;
+
+        let ty = match ty_opt {
+            Some(mut ty) => {
+                ty.type_id = check!(
+                    ctx.resolve_type_with_self(
+                        ty.type_id,
+                        &ty.span,
+                        EnforceTypeArguments::Yes,
+                        None
+                    ),
+                    type_engine.insert(decl_engine, TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors,
+                );
+                ty
+            }
+            None => {
+                let type_id = type_engine.insert(
+                    decl_engine,
+                    TypeInfo::UnknownGeneric {
+                        name: ty::self_associated_item_symbol(&name),
+                        trait_constraints: VecSet(vec![]),
+                    },
+                );
+                TypeArgument {
+                    type_id,
+                    initial_type_id: type_id,
+                    span: name.span(),
+                    call_path_tree: None,
+                }
+            }
+        };
+
+        ok(
+            ty::TyTraitType {
+                name,
+                attributes,
+                ty,
+                span,
+            },
+            warnings,
+            errors,
+        )
+    }
+
+    /// Makes this associated type available in the current scope as
+    /// `Self::<name>`.
+    pub(crate) fn insert_into_namespace(&self, ctx: TypeCheckContext) -> CompileResult<()> {
+        ctx.namespace.insert_symbol(
+            ty::self_associated_item_symbol(&self.name),
+            ty::TyDeclaration::GenericTypeForFunctionScope {
+                name: self.name.clone(),
+                type_id: self.ty.type_id,
+            },
+        )
+    }
+}
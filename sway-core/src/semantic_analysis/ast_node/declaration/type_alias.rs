@@ -0,0 +1,60 @@
+use crate::{
+    error::*,
+    language::{parsed::*, ty, CallPath},
+    semantic_analysis::*,
+    type_system::*,
+};
+
+impl ty::TyTypeAliasDeclaration {
+    pub(crate) fn type_check(
+        ctx: TypeCheckContext,
+        decl: TypeAliasDeclaration,
+    ) -> CompileResult<Self> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let TypeAliasDeclaration {
+            name,
+            attributes,
+            type_parameters,
+            mut ty,
+            visibility,
+            span,
+        } = decl;
+
+        // create a namespace for the decl, used to create a scope for generics
+        let mut decl_namespace = ctx.namespace.clone();
+        let mut ctx = ctx.scoped(&mut decl_namespace);
+
+        // Type check the type parameters. This will also insert them into the
+        // current namespace.
+        let new_type_parameters = check!(
+            TypeParameter::type_check_type_params(ctx.by_ref(), type_parameters),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        // resolve the aliased type
+        ty.type_id = check!(
+            ctx.resolve_type_without_self(ty.type_id, &ty.span, None),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
+        let mut path: CallPath = name.into();
+        path = path.to_fullpath(ctx.namespace);
+
+        let decl = ty::TyTypeAliasDeclaration {
+            call_path: path,
+            attributes,
+            type_parameters: new_type_parameters,
+            ty,
+            visibility,
+            span,
+        };
+
+        ok(decl, warnings, errors)
+    }
+}
@@ -1075,6 +1075,17 @@ fn type_check_binary_op(
         });
         return err(warnings, errors);
     }
+    // There is no division instruction for `u256` values, it is implemented by the core library.
+    if matches!(kind, Intrinsic::Div)
+        && matches!(arg_ty, TypeInfo::UnsignedInteger(IntegerBits::V256))
+    {
+        errors.push(CompileError::IntrinsicUnsupportedArgType {
+            name: kind.to_string(),
+            span: lhs.span,
+            hint: Hint::new("Use the `/` operator to divide `u256` values.".to_string()),
+        });
+        return err(warnings, errors);
+    }
 
     let rhs = arguments[1].clone();
     let ctx = ctx
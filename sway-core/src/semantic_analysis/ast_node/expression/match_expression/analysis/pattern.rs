@@ -2,7 +2,7 @@ use std::{cmp::Ordering, fmt};
 
 use std::fmt::Write;
 use sway_error::error::CompileError;
-use sway_types::Span;
+use sway_types::{u256::U256, Span};
 
 use crate::decl_engine::DeclEngine;
 use crate::{error::*, language::ty, language::Literal, TypeInfo};
@@ -102,6 +102,7 @@ pub(crate) enum Pattern {
     U32(Range<u32>),
     U64(Range<u64>),
     B256([u8; 32]),
+    U256(U256),
     Boolean(bool),
     Numeric(Range<u64>),
     String(String),
@@ -184,6 +185,7 @@ impl Pattern {
             Literal::U32(x) => Pattern::U32(Range::from_single(x)),
             Literal::U64(x) => Pattern::U64(Range::from_single(x)),
             Literal::B256(x) => Pattern::B256(x),
+            Literal::U256(x) => Pattern::U256(x),
             Literal::Boolean(b) => Pattern::Boolean(b),
             Literal::Numeric(x) => Pattern::Numeric(Range::from_single(x)),
             Literal::String(s) => Pattern::String(s.as_str().to_string()),
@@ -371,6 +373,16 @@ impl Pattern {
                 }
                 Pattern::B256(*b)
             }
+            Pattern::U256(n) => {
+                if !args.is_empty() {
+                    errors.push(CompileError::Internal(
+                        "malformed constructor request",
+                        span.clone(),
+                    ));
+                    return err(warnings, errors);
+                }
+                Pattern::U256(n.clone())
+            }
             Pattern::Boolean(b) => {
                 if !args.is_empty() {
                     errors.push(CompileError::Internal(
@@ -516,6 +528,7 @@ impl Pattern {
             Pattern::U32(_) => 0,
             Pattern::U64(_) => 0,
             Pattern::B256(_) => 0,
+            Pattern::U256(_) => 0,
             Pattern::Boolean(_) => 0,
             Pattern::Numeric(_) => 0,
             Pattern::String(_) => 0,
@@ -563,6 +576,7 @@ impl Pattern {
             (Pattern::U32(a), Pattern::U32(b)) => a == b,
             (Pattern::U64(a), Pattern::U64(b)) => a == b,
             (Pattern::B256(a), Pattern::B256(b)) => a == b,
+            (Pattern::U256(a), Pattern::U256(b)) => a == b,
             (Pattern::Boolean(a), Pattern::Boolean(b)) => a == b,
             (Pattern::Numeric(a), Pattern::Numeric(b)) => a == b,
             (Pattern::String(a), Pattern::String(b)) => a == b,
@@ -663,6 +677,7 @@ impl Pattern {
             Pattern::U32(n) => Pattern::U32(n),
             Pattern::U64(n) => Pattern::U64(n),
             Pattern::B256(n) => Pattern::B256(n),
+            Pattern::U256(n) => Pattern::U256(n),
             Pattern::Boolean(b) => Pattern::Boolean(b),
             Pattern::Numeric(n) => Pattern::Numeric(n),
             Pattern::String(s) => Pattern::String(s),
@@ -716,6 +731,7 @@ impl Pattern {
             Pattern::Enum(_) => 10,
             Pattern::Tuple(_) => 11,
             Pattern::Or(_) => 12,
+            Pattern::U256(_) => 13,
         }
     }
 }
@@ -730,6 +746,7 @@ impl fmt::Display for Pattern {
             Pattern::U64(range) => format!("{range}"),
             Pattern::Numeric(range) => format!("{range}"),
             Pattern::B256(n) => format!("{n:#?}"),
+            Pattern::U256(n) => format!("{n}"),
             Pattern::Boolean(b) => format!("{b}"),
             Pattern::String(s) => s.clone(),
             Pattern::Struct(struct_pattern) => format!("{struct_pattern}"),
@@ -762,6 +779,7 @@ impl std::cmp::Ord for Pattern {
             (Pattern::U32(x), Pattern::U32(y)) => x.cmp(y),
             (Pattern::U64(x), Pattern::U64(y)) => x.cmp(y),
             (Pattern::B256(x), Pattern::B256(y)) => x.cmp(y),
+            (Pattern::U256(x), Pattern::U256(y)) => x.cmp(y),
             (Pattern::Boolean(x), Pattern::Boolean(y)) => x.cmp(y),
             (Pattern::Numeric(x), Pattern::Numeric(y)) => x.cmp(y),
             (Pattern::String(x), Pattern::String(y)) => x.cmp(y),
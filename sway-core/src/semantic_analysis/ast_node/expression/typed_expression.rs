@@ -1,3 +1,4 @@
+mod closure;
 mod constant_declaration;
 mod enum_instantiation;
 mod function_application;
@@ -6,19 +7,20 @@ mod lazy_operator;
 mod method_application;
 mod struct_field_access;
 mod struct_instantiation;
+mod trait_object;
 mod tuple_index_access;
 mod unsafe_downcast;
 
-use self::constant_declaration::instantiate_constant_decl;
+use self::constant_declaration::{instantiate_associated_constant_decl, instantiate_constant_decl};
 pub(crate) use self::{
-    enum_instantiation::*, function_application::*, if_expression::*, lazy_operator::*,
-    method_application::*, struct_field_access::*, struct_instantiation::*, tuple_index_access::*,
-    unsafe_downcast::*,
+    closure::*, enum_instantiation::*, function_application::*, if_expression::*, lazy_operator::*,
+    method_application::*, struct_field_access::*, struct_instantiation::*, trait_object::*,
+    tuple_index_access::*, unsafe_downcast::*,
 };
 
 use crate::{
     asm_lang::{virtual_ops::VirtualOp, virtual_register::VirtualRegister},
-    decl_engine::{DeclEngineIndex, DeclRef},
+    decl_engine::{DeclEngineIndex, DeclRef, DeclRefConstant},
     error::*,
     language::{
         parsed::*,
@@ -37,7 +39,7 @@ use sway_error::{
     error::CompileError,
     warning::{CompileWarning, Warning},
 };
-use sway_types::{integer_bits::IntegerBits, Ident, Span, Spanned};
+use sway_types::{integer_bits::IntegerBits, u256::U256, Ident, Span, Spanned};
 
 use rustc_hash::FxHashSet;
 
@@ -112,16 +114,26 @@ impl ty::TyExpression {
         ok(exp, warnings, errors)
     }
 
-    pub(crate) fn type_check(mut ctx: TypeCheckContext, expr: Expression) -> CompileResult<Self> {
+    pub(crate) fn type_check(ctx: TypeCheckContext, expr: Expression) -> CompileResult<Self> {
         let type_engine = ctx.type_engine;
         let decl_engine = ctx.decl_engine;
         let engines = ctx.engines();
         let expr_span = expr.span();
         let span = expr_span.clone();
+
+        // A value annotated with a `dyn Trait` type is type checked as a value of its own type,
+        // and then coerced into a trait object below.
+        let type_annotation = ctx.type_annotation();
+        let mut ctx = match type_engine.get(type_annotation) {
+            TypeInfo::TraitObject {
+                decl_id: Some(_), ..
+            } => ctx.with_type_annotation(type_engine.insert(decl_engine, TypeInfo::Unknown)),
+            _ => ctx,
+        };
         let res = match expr.kind {
             // We've already emitted an error for the `::Error` case.
             ExpressionKind::Error(_) => ok(ty::TyExpression::error(span, engines), vec![], vec![]),
-            ExpressionKind::Literal(lit) => Self::type_check_literal(engines, lit, span),
+            ExpressionKind::Literal(lit) => Self::type_check_literal(ctx.by_ref(), lit, span),
             ExpressionKind::Variable(name) => {
                 Self::type_check_variable_expression(ctx.by_ref(), name, span)
             }
@@ -159,14 +171,17 @@ impl ty::TyExpression {
                 r#else.map(|e| *e),
                 span,
             ),
-            ExpressionKind::Match(MatchExpression { value, branches }) => {
-                Self::type_check_match_expression(
-                    ctx.by_ref().with_help_text(""),
-                    *value,
-                    branches,
-                    span,
-                )
-            }
+            ExpressionKind::Match(MatchExpression {
+                value,
+                branches,
+                irrefutable,
+            }) => Self::type_check_match_expression(
+                ctx.by_ref().with_help_text(""),
+                *value,
+                branches,
+                irrefutable,
+                span,
+            ),
             ExpressionKind::Asm(asm) => Self::type_check_asm_expression(ctx.by_ref(), *asm, span),
             ExpressionKind::Struct(struct_expression) => {
                 let StructExpression {
@@ -216,8 +231,12 @@ impl ty::TyExpression {
                 Self::type_check_delineated_path(ctx.by_ref(), call_path_binding, span, args)
             }
             ExpressionKind::AbiCast(abi_cast_expression) => {
-                let AbiCastExpression { abi_name, address } = *abi_cast_expression;
-                Self::type_check_abi_cast(ctx.by_ref(), abi_name, *address, span)
+                let AbiCastExpression {
+                    abi_name,
+                    type_arguments,
+                    address,
+                } = *abi_cast_expression;
+                Self::type_check_abi_cast(ctx.by_ref(), abi_name, type_arguments, *address, span)
             }
             ExpressionKind::Array(array_expression) => {
                 Self::type_check_array(ctx.by_ref(), array_expression.contents, span)
@@ -263,6 +282,9 @@ impl ty::TyExpression {
             ExpressionKind::Reassignment(ReassignmentExpression { lhs, rhs }) => {
                 Self::type_check_reassignment(ctx.by_ref(), lhs, *rhs, span)
             }
+            ExpressionKind::Closure(closure_expression) => {
+                type_check_closure(ctx.by_ref(), *closure_expression, span, None)
+            }
             ExpressionKind::Return(expr) => {
                 let ctx = ctx
                     // we use "unknown" here because return statements do not
@@ -304,6 +326,14 @@ impl ty::TyExpression {
         let mut warnings = res.warnings;
         let mut errors = res.errors;
 
+        let mut ctx = ctx.with_type_annotation(type_annotation);
+        typed_expression = check!(
+            coerce_into_trait_object(ctx.by_ref(), typed_expression, type_annotation),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+
         // if the return type cannot be cast into the annotation type then it is a type error
         append!(
             ctx.unify_with_self(typed_expression.return_type, &expr_span),
@@ -351,19 +381,29 @@ impl ty::TyExpression {
     }
 
     fn type_check_literal(
-        engines: Engines<'_>,
+        ctx: TypeCheckContext,
         lit: Literal,
         span: Span,
     ) -> CompileResult<ty::TyExpression> {
-        let type_engine = engines.te();
-        let decl_engine = engines.de();
+        let type_engine = ctx.type_engine;
+        let decl_engine = ctx.decl_engine;
         let return_type = match &lit {
+            // A string literal is a `str[N]` unless a string slice is expected.
+            Literal::String(_)
+                if matches!(
+                    type_engine.get(ctx.type_annotation()),
+                    TypeInfo::StringSlice
+                ) =>
+            {
+                TypeInfo::StringSlice
+            }
             Literal::String(s) => TypeInfo::Str(Length::new(s.as_str().len(), s.clone())),
             Literal::Numeric(_) => TypeInfo::Numeric,
             Literal::U8(_) => TypeInfo::UnsignedInteger(IntegerBits::Eight),
             Literal::U16(_) => TypeInfo::UnsignedInteger(IntegerBits::Sixteen),
             Literal::U32(_) => TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo),
             Literal::U64(_) => TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+            Literal::U256(_) => TypeInfo::UnsignedInteger(IntegerBits::V256),
             Literal::Boolean(_) => TypeInfo::Boolean,
             Literal::B256(_) => TypeInfo::B256,
         };
@@ -435,6 +475,22 @@ impl ty::TyExpression {
                     span,
                 }
             }
+            Some(ty::TyDeclaration::GenericTypeForFunctionScope {
+                name: generic_name,
+                type_id,
+            }) if matches!(ctx.type_engine.get(*type_id), TypeInfo::ConstGeneric { .. }) => {
+                ty::TyExpression {
+                    return_type: ctx.type_engine.insert(
+                        decl_engine,
+                        TypeInfo::UnsignedInteger(IntegerBits::SixtyFour),
+                    ),
+                    expression: ty::TyExpressionVariant::ConstGenericValue {
+                        name: generic_name.clone(),
+                        type_id: *type_id,
+                    },
+                    span,
+                }
+            }
             Some(a) => {
                 errors.push(CompileError::NotAVariable {
                     name: name.clone(),
@@ -463,6 +519,22 @@ impl ty::TyExpression {
         let mut warnings = vec![];
         let mut errors = vec![];
 
+        // A call of a plain name that refers to a variable is a call of the
+        // closure held by that variable.
+        if !call_path_binding.inner.is_absolute
+            && call_path_binding.inner.prefixes.is_empty()
+            && call_path_binding.type_arguments.to_vec().is_empty()
+        {
+            let name = call_path_binding.inner.suffix.clone();
+            let is_variable = matches!(
+                ctx.namespace.resolve_symbol(&name).value,
+                Some(ty::TyDeclaration::VariableDeclaration(_))
+            );
+            if is_variable {
+                return type_check_closure_call(ctx, name, arguments, span);
+            }
+        }
+
         // Grab the declaration.
         let unknown_decl = check!(
             TypeBinding::type_check_with_ident(&mut call_path_binding, ctx.by_ref()),
@@ -614,6 +686,7 @@ impl ty::TyExpression {
         mut ctx: TypeCheckContext,
         value: Expression,
         branches: Vec<MatchBranch>,
+        irrefutable: bool,
         span: Span,
     ) -> CompileResult<ty::TyExpression> {
         let mut warnings = vec![];
@@ -680,9 +753,17 @@ impl ty::TyExpression {
             }
         }
         if witness_report.has_witnesses() {
-            errors.push(CompileError::MatchExpressionNonExhaustive {
-                missing_patterns: format!("{witness_report}"),
-                span,
+            let missing_patterns = format!("{witness_report}");
+            errors.push(if irrefutable {
+                CompileError::RefutablePattern {
+                    missing_patterns,
+                    span,
+                }
+            } else {
+                CompileError::MatchExpressionNonExhaustive {
+                    missing_patterns,
+                    span,
+                }
             });
             return err(warnings, errors);
         }
@@ -1126,8 +1207,44 @@ impl ty::TyExpression {
                 .map(|const_ref| (const_ref, call_path_binding))
         };
 
+        // Check if this could be an associated constant
+        let mut assoc_const_probe_warnings = vec![];
+        let mut assoc_const_probe_errors = vec![];
+        let maybe_assoc_const = if unknown_call_path_binding.inner.prefixes.is_empty() {
+            None
+        } else {
+            Self::resolve_associated_constant(ctx.by_ref(), &unknown_call_path_binding.inner)
+                .ok(
+                    &mut assoc_const_probe_warnings,
+                    &mut assoc_const_probe_errors,
+                )
+                .flatten()
+        };
+
         // compare the results of the checks
         let exp = match (is_module, maybe_function, maybe_enum, maybe_const) {
+            (false, None, None, None) if maybe_assoc_const.is_some() => {
+                warnings.append(&mut assoc_const_probe_warnings);
+                errors.append(&mut assoc_const_probe_errors);
+                if !unknown_call_path_binding.type_arguments.to_vec().is_empty() {
+                    errors.push(
+                        ConvertParseTreeError::GenericsNotSupportedHere {
+                            span: unknown_call_path_binding.type_arguments.span(),
+                        }
+                        .into(),
+                    );
+                }
+                check!(
+                    instantiate_associated_constant_decl(
+                        ctx,
+                        maybe_assoc_const.unwrap(),
+                        unknown_call_path_binding
+                    ),
+                    return err(warnings, errors),
+                    warnings,
+                    errors
+                )
+            }
             (false, None, Some((enum_ref, variant_name, call_path_binding)), None) => {
                 warnings.append(&mut enum_probe_warnings);
                 errors.append(&mut enum_probe_errors);
@@ -1200,10 +1317,65 @@ impl ty::TyExpression {
         ok(exp, warnings, errors)
     }
 
+    /// Resolves a path like `Self::X` or `MyType::X` to the associated
+    /// constant `X` of the type it is prefixed with, if there is one.
+    fn resolve_associated_constant(
+        mut ctx: TypeCheckContext,
+        call_path: &CallPath,
+    ) -> CompileResult<Option<DeclRefConstant>> {
+        let mut warnings = vec![];
+        let mut errors = vec![];
+
+        let type_engine = ctx.type_engine;
+        let decl_engine = ctx.decl_engine;
+        let engines = ctx.engines();
+
+        if call_path.prefixes.len() == 1 && call_path.prefixes[0].as_str() == "Self" {
+            // Inside of trait declarations and impl blocks, the associated
+            // constants of `Self` are in scope.
+            let symbol = ty::self_associated_item_symbol(&call_path.suffix);
+            let const_ref = ctx
+                .namespace
+                .resolve_symbol(&symbol)
+                .ok(&mut warnings, &mut errors)
+                .and_then(|decl| decl.to_const_ref().ok(&mut warnings, &mut errors));
+            return ok(const_ref, warnings, errors);
+        }
+
+        let type_call_path = call_path.rshift();
+        let type_span = type_call_path.span();
+        let type_id = type_engine.insert(
+            decl_engine,
+            TypeInfo::Custom {
+                call_path: type_call_path,
+                type_arguments: None,
+            },
+        );
+        let type_id = check!(
+            ctx.resolve_type_with_self(type_id, &type_span, EnforceTypeArguments::No, None),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        let const_ref = ctx
+            .namespace
+            .implemented_traits
+            .get_items_for_type(engines, type_id)
+            .into_iter()
+            .find_map(|item| match item {
+                ty::TyTraitItem::Constant(decl_ref) if decl_ref.name() == &call_path.suffix => {
+                    Some(decl_ref)
+                }
+                _ => None,
+            });
+        ok(const_ref, warnings, errors)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn type_check_abi_cast(
         mut ctx: TypeCheckContext,
         abi_name: CallPath,
+        mut type_arguments: Vec<TypeArgument>,
         address: Expression,
         span: Span,
     ) -> CompileResult<Self> {
@@ -1247,7 +1419,18 @@ impl ty::TyExpression {
                 let ty::TyVariableDeclaration { body: expr, .. } = &**decl;
                 let ret_ty = type_engine.get(expr.return_type);
                 let abi_name = match ret_ty {
-                    TypeInfo::ContractCaller { abi_name, .. } => abi_name,
+                    TypeInfo::ContractCaller {
+                        abi_name,
+                        type_arguments: caller_type_arguments,
+                        ..
+                    } => {
+                        // Casting to the type of a contract caller reuses the
+                        // type arguments of its ABI.
+                        if type_arguments.is_empty() {
+                            type_arguments = caller_type_arguments;
+                        }
+                        abi_name
+                    }
                     _ => {
                         errors.push(CompileError::NotAnAbi {
                             span: abi_name.span(),
@@ -1279,6 +1462,7 @@ impl ty::TyExpression {
                                     decl_engine,
                                     TypeInfo::ContractCaller {
                                         abi_name: AbiName::Deferred,
+                                        type_arguments: vec![],
                                         address: None,
                                     },
                                 ),
@@ -1299,17 +1483,33 @@ impl ty::TyExpression {
                 return err(warnings, errors);
             }
         };
+        let mut abi_decl = decl_engine.get_abi(abi_ref.id());
+
+        // Monomorphize the abi with the type arguments of the cast, which
+        // are required if the abi is generic.
+        check!(
+            ctx.monomorphize(
+                &mut abi_decl,
+                &mut type_arguments,
+                EnforceTypeArguments::Yes,
+                &abi_name.span()
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
         let ty::TyAbiDeclaration {
             interface_surface,
             items,
             span,
             ..
-        } = decl_engine.get_abi(abi_ref.id());
+        } = abi_decl;
 
         let return_type = type_engine.insert(
             decl_engine,
             TypeInfo::ContractCaller {
                 abi_name: AbiName::Known(abi_name.clone()),
+                type_arguments,
                 address: Some(Box::new(address_expr.clone())),
             },
         );
@@ -1327,6 +1527,8 @@ impl ty::TyExpression {
                             .with_parent(decl_engine, (*decl_ref.id()).into()),
                     ));
                 }
+                // ABIs cannot declare associated constants or types.
+                ty::TyTraitInterfaceItem::Constant(_) | ty::TyTraitInterfaceItem::Type(_) => {}
             }
         }
 
@@ -1398,14 +1600,27 @@ impl ty::TyExpression {
 
         let mut warnings = Vec::new();
         let mut errors = Vec::new();
+        // The elements of an array annotated with a trait object element type
+        // are coerced into trait objects of that type.
+        let elem_annotation = match type_engine.get(ctx.type_annotation()) {
+            TypeInfo::Array(elem_type, _)
+                if matches!(
+                    type_engine.get(elem_type.type_id),
+                    TypeInfo::TraitObject { .. }
+                ) =>
+            {
+                Some(elem_type.type_id)
+            }
+            _ => None,
+        };
         let typed_contents: Vec<ty::TyExpression> = contents
             .into_iter()
             .map(|expr| {
                 let span = expr.span();
-                let ctx = ctx
-                    .by_ref()
-                    .with_help_text("")
-                    .with_type_annotation(type_engine.insert(decl_engine, TypeInfo::Unknown));
+                let ctx = ctx.by_ref().with_help_text("").with_type_annotation(
+                    elem_annotation
+                        .unwrap_or_else(|| type_engine.insert(decl_engine, TypeInfo::Unknown)),
+                );
                 check!(
                     Self::type_check(ctx, expr),
                     ty::TyExpression::error(span, engines),
@@ -1803,6 +2018,7 @@ impl ty::TyExpression {
                         }),
                         new_type,
                     ),
+                    IntegerBits::V256 => (Ok(Literal::U256(U256::from(num))), new_type),
                 },
                 TypeInfo::Numeric => (
                     num.to_string().parse().map(Literal::U64).map_err(|e| {
@@ -1841,6 +2057,56 @@ impl ty::TyExpression {
     }
 }
 
+/// Gives a string literal the type `str` if `expected` is a string slice.
+///
+/// Arguments are type checked before the parameter types are known, so unlike
+/// annotated literals they start out as a `str[N]`.
+pub(crate) fn coerce_string_literal(
+    engines: Engines<'_>,
+    exp: ty::TyExpression,
+    expected: TypeId,
+) -> ty::TyExpression {
+    let type_engine = engines.te();
+    let is_string_literal = matches!(
+        exp.expression,
+        ty::TyExpressionVariant::Literal(Literal::String(_))
+    );
+    if is_string_literal && matches!(type_engine.get(expected), TypeInfo::StringSlice) {
+        ty::TyExpression {
+            return_type: type_engine.insert(engines.de(), TypeInfo::StringSlice),
+            ..exp
+        }
+    } else {
+        exp
+    }
+}
+
+/// Gives a `u64` literal the type `u256` if `expected` is a `u256`.
+///
+/// Like string literals, numeric literals in arguments are type checked before
+/// the parameter types are known, and are resolved to `u64` by default.
+pub(crate) fn coerce_u256_literal(
+    engines: Engines<'_>,
+    exp: ty::TyExpression,
+    expected: TypeId,
+) -> ty::TyExpression {
+    let type_engine = engines.te();
+    match (&exp.expression, type_engine.get(expected)) {
+        (
+            ty::TyExpressionVariant::Literal(Literal::U64(num)),
+            TypeInfo::UnsignedInteger(IntegerBits::V256),
+        ) => ty::TyExpression {
+            expression: ty::TyExpressionVariant::Literal(Literal::U256(U256::from(*num))),
+            return_type: type_engine.insert(
+                engines.de(),
+                TypeInfo::UnsignedInteger(IntegerBits::V256),
+            ),
+            ..exp
+        },
+        _ => exp,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,694 @@
+use crate::{
+    decl_engine::DeclEngineIndex,
+    error::*,
+    language::{parsed::*, ty, Visibility},
+    semantic_analysis::{namespace::Namespace, TypeCheckContext},
+    transform::AttributesMap,
+    type_system::*,
+    Engines,
+};
+
+use sway_error::error::CompileError;
+use sway_types::{Ident, Span, Spanned};
+
+use rustc_hash::FxHashSet;
+
+/// The parameter types and the return type of a closure.
+#[derive(Clone, Debug)]
+pub(crate) struct ClosureSignature {
+    pub(crate) parameters: Vec<TypeArgument>,
+    pub(crate) return_type: TypeArgument,
+}
+
+impl ClosureSignature {
+    fn from_trait_constraints(trait_constraints: &[TraitConstraint]) -> Option<Self> {
+        trait_constraints
+            .iter()
+            .find_map(|constraint| constraint.fn_signature())
+            .map(|(parameters, return_type)| ClosureSignature {
+                parameters: parameters.to_vec(),
+                return_type: return_type.clone(),
+            })
+    }
+
+    /// Returns the signature of the closures that can be held by a value of
+    /// type `type_id`, which is either the type of a closure expression or a
+    /// type parameter created for a parameter with a function type.
+    pub(crate) fn from_type_id(engines: Engines<'_>, type_id: TypeId) -> Option<Self> {
+        let type_engine = engines.te();
+        let decl_engine = engines.de();
+        match type_engine.get(type_id) {
+            TypeInfo::Closure { fn_ref, captures } => {
+                let decl = decl_engine.get_function(&fn_ref);
+                Some(ClosureSignature {
+                    parameters: decl.parameters[captures..]
+                        .iter()
+                        .map(|param| param.type_argument.clone())
+                        .collect(),
+                    return_type: decl.return_type,
+                })
+            }
+            TypeInfo::UnknownGeneric {
+                trait_constraints, ..
+            } => Self::from_trait_constraints(&trait_constraints),
+            TypeInfo::Placeholder(type_parameter) => {
+                Self::from_trait_constraints(&type_parameter.trait_constraints)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the signature that a closure passed for a parameter of type
+    /// `type_id` must have, if `type_id` is one of `type_parameters` and was
+    /// created for a parameter with a function type.
+    pub(crate) fn expected_for_parameter(
+        type_parameters: &[TypeParameter],
+        type_id: TypeId,
+    ) -> Option<Self> {
+        type_parameters
+            .iter()
+            .find(|type_parameter| type_parameter.type_id == type_id)
+            .and_then(|type_parameter| {
+                Self::from_trait_constraints(&type_parameter.trait_constraints)
+            })
+    }
+
+    pub(crate) fn to_string(&self, engines: Engines<'_>) -> String {
+        print_fn_signature(engines, &self.parameters, &self.return_type)
+    }
+}
+
+/// Type checks a closure expression.
+///
+/// The body of the closure is lifted into a function whose first parameters
+/// receive the values of the variables the closure captures, followed by the
+/// closure's own parameters. Parameters without a type annotation take their
+/// types from `expected`, the signature required by the function that the
+/// closure is passed to.
+pub(crate) fn type_check_closure(
+    mut ctx: TypeCheckContext,
+    closure: ClosureExpression,
+    span: Span,
+    expected: Option<ClosureSignature>,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let decl_engine = ctx.decl_engine;
+
+    let ClosureExpression { parameters, body } = closure;
+
+    if let Some(expected) = &expected {
+        if expected.parameters.len() != parameters.len() {
+            errors.push(CompileError::ClosureArityMismatch {
+                expected: expected.parameters.len(),
+                received: parameters.len(),
+                span,
+            });
+            return err(warnings, errors);
+        }
+    }
+    let expected_return_type = match &expected {
+        Some(expected) => expected.return_type.type_id,
+        None => type_engine.insert(decl_engine, TypeInfo::Unknown),
+    };
+
+    // The closure works on copies of the variables it captures, so none of
+    // them can be reassigned from within its body.
+    let mut closure_namespace = ctx.namespace.clone();
+    make_variables_immutable(&mut closure_namespace);
+    let mut closure_ctx = ctx.by_ref().scoped(&mut closure_namespace);
+
+    let mut typed_parameters = vec![];
+    for (index, parameter) in parameters.into_iter().enumerate() {
+        let ClosureParameter {
+            name,
+            mut type_argument,
+        } = parameter;
+        let expected_type_id = expected
+            .as_ref()
+            .map(|expected| expected.parameters[index].type_id);
+        let is_annotated = !matches!(type_engine.get(type_argument.type_id), TypeInfo::Unknown);
+        match expected_type_id {
+            Some(expected_type_id) if !is_annotated => {
+                type_argument.type_id = expected_type_id;
+            }
+            _ => {
+                type_argument.type_id = check!(
+                    closure_ctx.resolve_type_with_self(
+                        type_argument.type_id,
+                        &type_argument.span,
+                        EnforceTypeArguments::Yes,
+                        None
+                    ),
+                    type_engine.insert(decl_engine, TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors,
+                );
+                if let Some(expected_type_id) = expected_type_id {
+                    check!(
+                        CompileResult::from(type_engine.unify_with_self(
+                            decl_engine,
+                            expected_type_id,
+                            type_argument.type_id,
+                            closure_ctx.self_type(),
+                            &type_argument.span,
+                            "This closure parameter's type does not match the type expected \
+                            by the function that the closure is passed to.",
+                            None
+                        )),
+                        (),
+                        warnings,
+                        errors
+                    );
+                }
+            }
+        }
+        let typed_parameter = ty::TyFunctionParameter {
+            mutability_span: name.span(),
+            name,
+            is_reference: false,
+            is_mutable: false,
+            type_argument,
+        };
+        typed_parameter.insert_into_namespace(closure_ctx.by_ref());
+        typed_parameters.push(typed_parameter);
+    }
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+
+    // type check the closure body
+    let body_span = body.span();
+    let typed_body = {
+        let ctx = closure_ctx
+            .by_ref()
+            .with_help_text("Closure body's type does not match up with its expected return type.")
+            .with_type_annotation(expected_return_type);
+        check!(
+            ty::TyExpression::type_check(ctx, *body),
+            return err(warnings, errors),
+            warnings,
+            errors
+        )
+    };
+
+    // `return` inside the closure body returns from the closure
+    for stmt in typed_body.gather_return_statements() {
+        check!(
+            CompileResult::from(type_engine.unify_with_self(
+                decl_engine,
+                stmt.return_type,
+                expected_return_type,
+                closure_ctx.self_type(),
+                &stmt.span,
+                "Return statement must return the closure's return type.",
+                None,
+            )),
+            continue,
+            warnings,
+            errors
+        );
+    }
+
+    // Parameters are only known from the closure body if nothing else
+    // pinned them down.
+    for parameter in typed_parameters.iter() {
+        if matches!(
+            type_engine.get(parameter.type_argument.type_id),
+            TypeInfo::Unknown
+        ) {
+            errors.push(CompileError::UnableToInferGeneric {
+                ty: parameter.name.to_string(),
+                span: parameter.name.span(),
+            });
+        }
+    }
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+
+    // Every variable of the enclosing scope that the body uses is captured
+    // by value.
+    let bound_names = typed_parameters
+        .iter()
+        .map(|param| param.name.clone())
+        .collect::<FxHashSet<_>>();
+    let mut free_names = vec![];
+    gather_free_variables(&typed_body, &bound_names, &mut free_names);
+    let mut captures = vec![];
+    for name in free_names {
+        let is_variable = matches!(
+            ctx.namespace.resolve_symbol(&name).value,
+            Some(ty::TyDeclaration::VariableDeclaration(_))
+        );
+        if !is_variable {
+            continue;
+        }
+        let capture = check!(
+            ty::TyExpression::type_check_variable_expression(
+                ctx.by_ref(),
+                name.clone(),
+                name.span()
+            ),
+            continue,
+            warnings,
+            errors
+        );
+        captures.push((name, capture));
+    }
+
+    let capture_parameters = captures
+        .iter()
+        .map(|(name, capture)| ty::TyFunctionParameter {
+            name: name.clone(),
+            is_reference: false,
+            is_mutable: false,
+            mutability_span: name.span(),
+            type_argument: capture.return_type.into(),
+        });
+    let lifted_fn = ty::TyFunctionDeclaration {
+        name: Ident::new_with_override("__closure".to_string(), span.clone()),
+        body: ty::TyCodeBlock {
+            contents: vec![ty::TyAstNode {
+                content: ty::TyAstNodeContent::ImplicitReturnExpression(typed_body),
+                span: body_span,
+            }],
+        },
+        parameters: capture_parameters.chain(typed_parameters).collect(),
+        implementing_type: None,
+        span: span.clone(),
+        attributes: AttributesMap::default(),
+        type_parameters: vec![],
+        return_type: expected_return_type.into(),
+        visibility: Visibility::Private,
+        is_contract_call: false,
+        purity: ctx.purity(),
+        where_clause: vec![],
+    };
+    let fn_ref = decl_engine.insert(lifted_fn);
+
+    let return_type = type_engine.insert(
+        decl_engine,
+        TypeInfo::Closure {
+            fn_ref: fn_ref.clone(),
+            captures: captures.len(),
+        },
+    );
+    let exp = ty::TyExpression {
+        expression: ty::TyExpressionVariant::Closure { fn_ref, captures },
+        return_type,
+        span,
+    };
+    ok(exp, warnings, errors)
+}
+
+/// A closure passed as an argument, which is type checked only after the
+/// function it is passed to is known, together with its position among the
+/// arguments.
+pub(crate) struct DeferredClosureArgument {
+    pub(crate) index: usize,
+    pub(crate) closure: ClosureExpression,
+    pub(crate) span: Span,
+}
+
+impl DeferredClosureArgument {
+    /// Returns the expression standing in for the closure until it is type
+    /// checked.
+    pub(crate) fn placeholder(&self, engines: Engines<'_>) -> ty::TyExpression {
+        let mut exp = ty::TyExpression::error(self.span.clone(), engines);
+        exp.return_type = engines.te().insert(engines.de(), TypeInfo::Unknown);
+        exp
+    }
+}
+
+/// Type checks the closures passed to `function_decl`, replacing their
+/// placeholders in `typed_arguments`.
+///
+/// Closures passed for parameters with a function type take the types of
+/// their parameters from the signature that the parameter requires. As that
+/// signature may mention type parameters inferred from the other arguments,
+/// those arguments are unified with their parameters first. Mismatches are
+/// reported when all arguments are unified with their parameters.
+///
+/// A closure which fails to type check is replaced by an error expression, so
+/// the call itself can still be type checked.
+pub(crate) fn type_check_closure_arguments(
+    mut ctx: TypeCheckContext,
+    function_decl: &ty::TyFunctionDeclaration,
+    typed_arguments: &mut [ty::TyExpression],
+    closure_arguments: Vec<DeferredClosureArgument>,
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    if closure_arguments.is_empty() {
+        return ok((), warnings, errors);
+    }
+
+    let type_engine = ctx.type_engine;
+    let decl_engine = ctx.decl_engine;
+    let engines = ctx.engines();
+
+    if typed_arguments.len() == function_decl.parameters.len() {
+        for (index, (arg, param)) in typed_arguments
+            .iter()
+            .zip(function_decl.parameters.iter())
+            .enumerate()
+        {
+            if closure_arguments
+                .iter()
+                .any(|closure| closure.index == index)
+            {
+                continue;
+            }
+            let _ = type_engine.unify(
+                decl_engine,
+                arg.return_type,
+                param.type_argument.type_id,
+                &arg.span,
+                "",
+                None,
+            );
+        }
+    }
+
+    for DeferredClosureArgument {
+        index,
+        closure,
+        span,
+    } in closure_arguments
+    {
+        let expected = function_decl.parameters.get(index).and_then(|param| {
+            ClosureSignature::expected_for_parameter(
+                &function_decl.type_parameters,
+                param.type_argument.type_id,
+            )
+        });
+        let ctx = ctx.by_ref().with_help_text("");
+        typed_arguments[index] = check!(
+            type_check_closure(ctx, closure, span.clone(), expected),
+            ty::TyExpression::error(span, engines),
+            warnings,
+            errors
+        );
+    }
+
+    ok((), warnings, errors)
+}
+
+/// Type checks a call of the closure held by the variable `name`.
+pub(crate) fn type_check_closure_call(
+    mut ctx: TypeCheckContext,
+    name: Ident,
+    arguments: Vec<Expression>,
+    span: Span,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let engines = ctx.engines();
+
+    let closure = check!(
+        ty::TyExpression::type_check_variable_expression(ctx.by_ref(), name.clone(), name.span()),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    let signature = match ClosureSignature::from_type_id(engines, closure.return_type) {
+        Some(signature) => signature,
+        None => {
+            errors.push(CompileError::NotAClosure {
+                ty: engines.help_out(closure.return_type).to_string(),
+                span: name.span(),
+                name,
+            });
+            return err(warnings, errors);
+        }
+    };
+
+    if signature.parameters.len() != arguments.len() {
+        errors.push(CompileError::ClosureCallArityMismatch {
+            parameters: signature.parameters.len(),
+            arguments: arguments.len(),
+            span,
+        });
+        return err(warnings, errors);
+    }
+
+    let typed_arguments = arguments
+        .into_iter()
+        .zip(signature.parameters.iter())
+        .map(|(arg, param)| {
+            let ctx = ctx
+                .by_ref()
+                .with_help_text(
+                    "The argument that has been provided to this closure's type does \
+                    not match the declared type of the closure's parameter.",
+                )
+                .with_type_annotation(param.type_id);
+            check!(
+                ty::TyExpression::type_check(ctx, arg.clone()),
+                ty::TyExpression::error(arg.span(), engines),
+                warnings,
+                errors
+            )
+        })
+        .collect();
+
+    let exp = ty::TyExpression {
+        expression: ty::TyExpressionVariant::ClosureCall {
+            closure: Box::new(closure),
+            arguments: typed_arguments,
+        },
+        return_type: signature.return_type.type_id,
+        span,
+    };
+    if errors.is_empty() {
+        ok(exp, warnings, errors)
+    } else {
+        err(warnings, errors)
+    }
+}
+
+/// Checks that every argument passed for a parameter with a function type
+/// holds a closure with the signature that the parameter requires.
+pub(crate) fn check_closure_arguments(
+    ctx: TypeCheckContext,
+    arguments: &[(Ident, ty::TyExpression)],
+    function_decl: &ty::TyFunctionDeclaration,
+) -> CompileResult<()> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let decl_engine = ctx.decl_engine;
+    let engines = ctx.engines();
+
+    for ((_, arg), param) in arguments.iter().zip(function_decl.parameters.iter()) {
+        let expected = match ClosureSignature::expected_for_parameter(
+            &function_decl.type_parameters,
+            param.type_argument.type_id,
+        ) {
+            Some(expected) => expected,
+            None => continue,
+        };
+        let provided = match ClosureSignature::from_type_id(engines, arg.return_type) {
+            Some(provided) if provided.parameters.len() == expected.parameters.len() => provided,
+            _ => {
+                if !matches!(type_engine.get(arg.return_type), TypeInfo::ErrorRecovery) {
+                    errors.push(CompileError::ArgumentParameterTypeMismatch {
+                        span: arg.span.clone(),
+                        should_be: expected.to_string(engines),
+                        provided: engines.help_out(arg.return_type).to_string(),
+                    });
+                }
+                continue;
+            }
+        };
+        let expected_types = expected
+            .parameters
+            .iter()
+            .chain(std::iter::once(&expected.return_type));
+        let provided_types = provided
+            .parameters
+            .iter()
+            .chain(std::iter::once(&provided.return_type));
+        for (provided_type, expected_type) in provided_types.zip(expected_types) {
+            check!(
+                CompileResult::from(type_engine.unify_with_self(
+                    decl_engine,
+                    provided_type.type_id,
+                    expected_type.type_id,
+                    ctx.self_type(),
+                    &arg.span,
+                    "This closure's signature does not match the function type of \
+                    the parameter it is passed to.",
+                    Some(CompileError::ArgumentParameterTypeMismatch {
+                        span: arg.span.clone(),
+                        should_be: expected.to_string(engines),
+                        provided: provided.to_string(engines),
+                    })
+                )),
+                break,
+                warnings,
+                errors
+            );
+        }
+    }
+
+    if errors.is_empty() {
+        ok((), warnings, errors)
+    } else {
+        err(warnings, errors)
+    }
+}
+
+/// Makes every variable in the current scope immutable.
+fn make_variables_immutable(namespace: &mut Namespace) {
+    let variable_names = namespace
+        .symbols()
+        .iter()
+        .filter(|(_, decl)| matches!(decl, ty::TyDeclaration::VariableDeclaration(_)))
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    for name in variable_names {
+        if let Some(ty::TyDeclaration::VariableDeclaration(decl)) = namespace.symbols.get_mut(&name)
+        {
+            decl.mutability = ty::VariableMutability::Immutable;
+        }
+    }
+}
+
+/// Collects, in order of first use, the names of the variables that `exp`
+/// uses but that are neither in `bound_names` nor declared within `exp`.
+fn gather_free_variables(
+    exp: &ty::TyExpression,
+    bound_names: &FxHashSet<Ident>,
+    free_names: &mut Vec<Ident>,
+) {
+    use ty::TyExpressionVariant::*;
+    let mut gather = |exp: &ty::TyExpression| gather_free_variables(exp, bound_names, free_names);
+    match &exp.expression {
+        VariableExpression { name, .. } => {
+            if !bound_names.contains(name) && !free_names.contains(name) {
+                free_names.push(name.clone());
+            }
+        }
+        CodeBlock(code_block) => {
+            gather_free_variables_in_code_block(code_block, bound_names, free_names)
+        }
+        WhileLoop { condition, body } => {
+            gather(condition);
+            gather_free_variables_in_code_block(body, bound_names, free_names);
+        }
+        FunctionApplication {
+            contract_call_params,
+            arguments,
+            selector,
+            ..
+        } => {
+            contract_call_params
+                .values()
+                .chain(arguments.iter().map(|(_name, arg)| arg))
+                .chain(
+                    selector
+                        .iter()
+                        .map(|contract_call_params| &*contract_call_params.contract_address),
+                )
+                .for_each(gather);
+        }
+        LazyOperator { lhs, rhs, .. } => {
+            gather(lhs);
+            gather(rhs);
+        }
+        Tuple { fields } => fields.iter().for_each(gather),
+        Array { contents } => contents.iter().for_each(gather),
+        ArrayIndex { prefix, index } => {
+            gather(prefix);
+            gather(index);
+        }
+        StructExpression { fields, .. } => fields.iter().for_each(|field| gather(&field.value)),
+        MatchExp { desugared, .. } => gather(desugared),
+        IfExp {
+            condition,
+            then,
+            r#else,
+        } => {
+            gather(condition);
+            gather(then);
+            if let Some(r#else) = r#else {
+                gather(r#else);
+            }
+        }
+        AsmExpression { registers, .. } => registers
+            .iter()
+            .filter_map(|register| register.initializer.as_ref())
+            .for_each(gather),
+        StructFieldAccess { prefix, .. } | TupleElemAccess { prefix, .. } => gather(prefix),
+        EnumInstantiation { contents, .. } => {
+            if let Some(contents) = contents {
+                gather(contents);
+            }
+        }
+        AbiCast { address, .. } => gather(address),
+        IntrinsicFunction(kind) => kind.arguments.iter().for_each(gather),
+        EnumTag { exp } | UnsafeDowncast { exp, .. } | TraitObject { exp, .. } | Return(exp) => {
+            gather(exp)
+        }
+        Reassignment(reassignment) => {
+            if !bound_names.contains(&reassignment.lhs_base_name)
+                && !free_names.contains(&reassignment.lhs_base_name)
+            {
+                free_names.push(reassignment.lhs_base_name.clone());
+            }
+            for projection in reassignment.lhs_indices.iter() {
+                if let ty::ProjectionKind::ArrayIndex { index, .. } = projection {
+                    gather_free_variables(index, bound_names, free_names);
+                }
+            }
+            gather_free_variables(&reassignment.rhs, bound_names, free_names);
+        }
+        StorageReassignment(storage_reassignment) => gather(&storage_reassignment.rhs),
+        Closure { captures, .. } => captures.iter().for_each(|(_name, exp)| gather(exp)),
+        ClosureCall {
+            closure: object,
+            arguments,
+        }
+        | DynamicMethodCall {
+            object, arguments, ..
+        } => {
+            gather(object);
+            arguments.iter().for_each(gather);
+        }
+        Literal(_)
+        | FunctionParameter
+        | AbiName(_)
+        | StorageAccess(_)
+        | Break
+        | Continue
+        | ConstGenericValue { .. } => {}
+    }
+}
+
+fn gather_free_variables_in_code_block(
+    code_block: &ty::TyCodeBlock,
+    bound_names: &FxHashSet<Ident>,
+    free_names: &mut Vec<Ident>,
+) {
+    let mut bound_names = bound_names.clone();
+    for node in code_block.contents.iter() {
+        match &node.content {
+            ty::TyAstNodeContent::Declaration(ty::TyDeclaration::VariableDeclaration(decl)) => {
+                gather_free_variables(&decl.body, &bound_names, free_names);
+                bound_names.insert(decl.name.clone());
+            }
+            ty::TyAstNodeContent::Expression(exp)
+            | ty::TyAstNodeContent::ImplicitReturnExpression(exp) => {
+                gather_free_variables(exp, &bound_names, free_names)
+            }
+            ty::TyAstNodeContent::Declaration(_) | ty::TyAstNodeContent::SideEffect(_) => {}
+        }
+    }
+}
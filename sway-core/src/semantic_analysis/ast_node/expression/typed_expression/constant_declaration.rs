@@ -1,3 +1,4 @@
+use sway_error::error::CompileError;
 use sway_types::Spanned;
 
 use crate::{
@@ -29,3 +30,26 @@ pub(crate) fn instantiate_constant_decl(
         vec![],
     )
 }
+
+/// Instantiates a reference to an associated constant, e.g. `Self::X` or
+/// `MyType::X`, by inlining the value provided by the implementation.
+pub(crate) fn instantiate_associated_constant_decl(
+    ctx: TypeCheckContext,
+    const_ref: DeclRef<DeclId<ty::TyConstantDeclaration>>,
+    call_path_binding: TypeBinding<CallPath>,
+) -> CompileResult<ty::TyExpression> {
+    let const_decl = ctx.decl_engine.get_constant(const_ref.id());
+    match const_decl.value {
+        Some(mut value) => {
+            value.span = call_path_binding.span();
+            ok(value, vec![], vec![])
+        }
+        None => err(
+            vec![],
+            vec![CompileError::AssociatedConstantWithoutValue {
+                name: const_decl.call_path.suffix,
+                span: call_path_binding.span(),
+            }],
+        ),
+    }
+}
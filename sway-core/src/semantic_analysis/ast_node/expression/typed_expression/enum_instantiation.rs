@@ -76,11 +76,11 @@ pub(crate) fn instantiate_enum(
             errors,
         ),
         ([single_expr], _) => {
-            let ctx = ctx
+            let mut ctx = ctx
                 .with_help_text("Enum instantiator must match its declared variant type.")
                 .with_type_annotation(type_engine.insert(decl_engine, TypeInfo::Unknown));
             let typed_expr = check!(
-                ty::TyExpression::type_check(ctx, single_expr.clone()),
+                ty::TyExpression::type_check(ctx.by_ref(), single_expr.clone()),
                 return err(warnings, errors),
                 warnings,
                 errors
@@ -104,6 +104,21 @@ pub(crate) fn instantiate_enum(
             // we now know that the instantiator type matches the declared type, via the above tpe
             // check
 
+            // check that the instantiator type satisfies the trait constraints of the enum's type
+            // parameters
+            check!(
+                ctx.namespace
+                    .implemented_traits
+                    .check_if_trait_constraints_are_satisfied_for_type_parameters(
+                        &enum_decl.type_parameters,
+                        span,
+                        engines
+                    ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+
             ok(
                 ty::TyExpression {
                     return_type: type_engine.insert(decl_engine, TypeInfo::Enum(enum_ref.clone())),
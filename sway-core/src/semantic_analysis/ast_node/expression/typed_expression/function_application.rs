@@ -1,3 +1,7 @@
+use super::{
+    check_closure_arguments, coerce_into_trait_object, coerce_string_literal, coerce_u256_literal,
+    type_check_closure_arguments, DeferredClosureArgument,
+};
 use crate::{
     decl_engine::{DeclEngineIndex, DeclRefFunction, ReplaceDecls},
     error::*,
@@ -55,7 +59,7 @@ pub(crate) fn instantiate_function_application(
     );
 
     let typed_arguments = check!(
-        type_check_arguments(ctx.by_ref(), arguments),
+        type_check_arguments(ctx.by_ref(), arguments, &function_decl),
         return err(warnings, errors),
         warnings,
         errors
@@ -68,6 +72,13 @@ pub(crate) fn instantiate_function_application(
         errors
     );
 
+    check!(
+        check_closure_arguments(ctx.by_ref(), &typed_arguments_with_names, &function_decl),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
     // Retrieve the implemented traits for the type of the return type and
     // insert them in the broader namespace.
     ctx.namespace
@@ -113,6 +124,7 @@ pub(crate) fn instantiate_function_application(
 fn type_check_arguments(
     mut ctx: TypeCheckContext,
     arguments: Vec<parsed::Expression>,
+    function_decl: &ty::TyFunctionDeclaration,
 ) -> CompileResult<Vec<ty::TyExpression>> {
     let mut warnings = vec![];
     let mut errors = vec![];
@@ -121,25 +133,50 @@ fn type_check_arguments(
     let decl_engine = ctx.decl_engine;
     let engines = ctx.engines();
 
-    let typed_arguments = arguments
-        .into_iter()
-        .map(|arg| {
-            let ctx = ctx
-                .by_ref()
-                .with_help_text("")
-                .with_type_annotation(type_engine.insert(decl_engine, TypeInfo::Unknown));
-            check!(
-                ty::TyExpression::type_check(ctx, arg.clone()),
-                ty::TyExpression::error(arg.span(), engines),
-                warnings,
-                errors
-            )
-        })
-        .collect();
+    let mut closure_arguments = vec![];
+    let mut typed_arguments = vec![];
+    for (index, arg) in arguments.into_iter().enumerate() {
+        if let parsed::ExpressionKind::Closure(closure) = arg.kind {
+            let closure_argument = DeferredClosureArgument {
+                index,
+                closure: *closure,
+                span: arg.span,
+            };
+            typed_arguments.push(closure_argument.placeholder(engines));
+            closure_arguments.push(closure_argument);
+            continue;
+        }
+        let ctx = ctx
+            .by_ref()
+            .with_help_text("")
+            .with_type_annotation(type_engine.insert(decl_engine, TypeInfo::Unknown));
+        typed_arguments.push(check!(
+            ty::TyExpression::type_check(ctx, arg.clone()),
+            ty::TyExpression::error(arg.span(), engines),
+            warnings,
+            errors
+        ));
+    }
+
+    // The closures which fail to type check don't stop the call from being
+    // type checked, so that the function is still known to be called.
+    let mut closure_errors = vec![];
+    check!(
+        type_check_closure_arguments(
+            ctx.by_ref(),
+            function_decl,
+            &mut typed_arguments,
+            closure_arguments
+        ),
+        (),
+        warnings,
+        closure_errors
+    );
 
     if errors.is_empty() {
-        ok(typed_arguments, warnings, errors)
+        ok(typed_arguments, warnings, closure_errors)
     } else {
+        errors.append(&mut closure_errors);
         err(warnings, errors)
     }
 }
@@ -147,7 +184,7 @@ fn type_check_arguments(
 /// Unifies the types of the arguments with the types of the parameters. Returns
 /// a list of the arguments with the names of the corresponding parameters.
 fn unify_arguments_and_parameters(
-    ctx: TypeCheckContext,
+    mut ctx: TypeCheckContext,
     typed_arguments: Vec<ty::TyExpression>,
     parameters: &[ty::TyFunctionParameter],
 ) -> CompileResult<Vec<(Ident, ty::TyExpression)>> {
@@ -159,6 +196,15 @@ fn unify_arguments_and_parameters(
     let mut typed_arguments_and_names = vec![];
 
     for (arg, param) in typed_arguments.into_iter().zip(parameters.iter()) {
+        let arg = coerce_string_literal(ctx.engines(), arg, param.type_argument.type_id);
+        let arg = coerce_u256_literal(ctx.engines(), arg, param.type_argument.type_id);
+        let arg = check!(
+            coerce_into_trait_object(ctx.by_ref(), arg, param.type_argument.type_id),
+            continue,
+            warnings,
+            errors
+        );
+
         // unify the type of the argument with the type of the param
         check!(
             CompileResult::from(type_engine.unify(
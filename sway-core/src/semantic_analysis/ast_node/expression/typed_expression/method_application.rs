@@ -1,11 +1,15 @@
 use crate::{
-    decl_engine::{DeclEngineIndex, DeclRefFunction},
+    decl_engine::{DeclEngineIndex, DeclMapping, DeclRefFunction, ReplaceDecls},
     error::*,
     language::{parsed::*, ty, *},
     semantic_analysis::*,
     type_system::*,
 };
-use ast_node::typed_expression::check_function_arguments_arity;
+use ast_node::typed_expression::{
+    check_closure_arguments, check_function_arguments_arity, coerce_into_trait_object,
+    coerce_string_literal, coerce_u256_literal, instantiate_dynamic_method_call,
+    type_check_closure_arguments, DeferredClosureArgument,
+};
 use std::collections::{HashMap, VecDeque};
 use sway_error::error::CompileError;
 use sway_types::{constants, integer_bits::IntegerBits};
@@ -29,7 +33,20 @@ pub(crate) fn type_check_method_application(
 
     // type check the function arguments
     let mut args_buf = VecDeque::new();
-    for arg in &arguments {
+    let mut closure_arguments = vec![];
+    for (index, arg) in arguments.iter().enumerate() {
+        // Closures are type checked once the method is known, see
+        // `type_check_closure_arguments`.
+        if let ExpressionKind::Closure(closure) = &arg.kind {
+            let closure_argument = DeferredClosureArgument {
+                index,
+                closure: (**closure).clone(),
+                span: arg.span.clone(),
+            };
+            args_buf.push_back(closure_argument.placeholder(engines));
+            closure_arguments.push(closure_argument);
+            continue;
+        }
         let ctx = ctx
             .by_ref()
             .with_help_text("")
@@ -42,6 +59,32 @@ pub(crate) fn type_check_method_application(
         ));
     }
 
+    // methods called on a trait object are dispatched through its vtable
+    if let MethodName::FromModule { method_name } = &method_name_binding.inner {
+        let is_trait_object = args_buf.front().map_or(false, |object| {
+            matches!(
+                type_engine.get(object.return_type),
+                TypeInfo::TraitObject { .. }
+            )
+        });
+        if is_trait_object && closure_arguments.is_empty() {
+            let object = args_buf.pop_front().unwrap();
+            let exp = check!(
+                instantiate_dynamic_method_call(
+                    ctx,
+                    object,
+                    method_name.clone(),
+                    args_buf.into(),
+                    span,
+                ),
+                return err(warnings, errors),
+                warnings,
+                errors
+            );
+            return ok(exp, warnings, errors);
+        }
+    }
+
     // resolve the method name to a typed function declaration and type_check
     let decl_ref = check!(
         resolve_method_name(ctx.by_ref(), &mut method_name_binding, args_buf.clone()),
@@ -51,6 +94,18 @@ pub(crate) fn type_check_method_application(
     );
     let method = decl_engine.get_function(&decl_ref);
 
+    check!(
+        type_check_closure_arguments(
+            ctx.by_ref(),
+            &method,
+            args_buf.make_contiguous(),
+            closure_arguments
+        ),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
     // check the method visibility
     if span.path() != method.span.path() && method.visibility.is_private() {
         errors.push(CompileError::CallingPrivateLibraryMethod {
@@ -336,6 +391,13 @@ pub(crate) fn type_check_method_application(
         errors
     );
 
+    check!(
+        check_closure_arguments(ctx.by_ref(), &typed_arguments_with_names, &method),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
     // Retrieve the implemented traits for the type of the return type and
     // insert them in the broader namespace.
     ctx.namespace
@@ -361,7 +423,7 @@ pub(crate) fn type_check_method_application(
 /// Unifies the types of the arguments with the types of the parameters. Returns
 /// a list of the arguments with the names of the corresponding parameters.
 fn unify_arguments_and_parameters(
-    ctx: TypeCheckContext,
+    mut ctx: TypeCheckContext,
     arguments: VecDeque<ty::TyExpression>,
     parameters: &[ty::TyFunctionParameter],
 ) -> CompileResult<Vec<(Ident, ty::TyExpression)>> {
@@ -374,6 +436,15 @@ fn unify_arguments_and_parameters(
     let mut typed_arguments_and_names = vec![];
 
     for (arg, param) in arguments.into_iter().zip(parameters.iter()) {
+        let arg = coerce_string_literal(ctx.engines(), arg, param.type_argument.type_id);
+        let arg = coerce_u256_literal(ctx.engines(), arg, param.type_argument.type_id);
+        let arg = check!(
+            coerce_into_trait_object(ctx.by_ref(), arg, param.type_argument.type_id),
+            continue,
+            warnings,
+            errors
+        );
+
         // unify the type of the argument with the type of the param
         check!(
             CompileResult::from(type_engine.unify_with_self(
@@ -417,7 +488,7 @@ pub(crate) fn resolve_method_name(
     let engines = ctx.engines();
 
     // retrieve the function declaration using the components of the method name
-    let decl_ref = match &method_name.inner {
+    let (decl_ref, type_id) = match &method_name.inner {
         MethodName::FromType {
             call_path_binding,
             method_name,
@@ -442,7 +513,7 @@ pub(crate) fn resolve_method_name(
             );
 
             // find the method
-            check!(
+            let decl_ref = check!(
                 ctx.namespace.find_method_for_type(
                     type_id,
                     &type_info_prefix,
@@ -454,7 +525,8 @@ pub(crate) fn resolve_method_name(
                 return err(warnings, errors),
                 warnings,
                 errors
-            )
+            );
+            (decl_ref, type_id)
         }
         MethodName::FromTrait { call_path } => {
             // find the module that the symbol is in
@@ -467,7 +539,7 @@ pub(crate) fn resolve_method_name(
                 .unwrap_or_else(|| type_engine.insert(decl_engine, TypeInfo::Unknown));
 
             // find the method
-            check!(
+            let decl_ref = check!(
                 ctx.namespace.find_method_for_type(
                     type_id,
                     &module_path,
@@ -479,7 +551,8 @@ pub(crate) fn resolve_method_name(
                 return err(warnings, errors),
                 warnings,
                 errors
-            )
+            );
+            (decl_ref, type_id)
         }
         MethodName::FromModule { method_name } => {
             // find the module that the symbol is in
@@ -492,7 +565,7 @@ pub(crate) fn resolve_method_name(
                 .unwrap_or_else(|| type_engine.insert(decl_engine, TypeInfo::Unknown));
 
             // find the method
-            check!(
+            let decl_ref = check!(
                 ctx.namespace.find_method_for_type(
                     type_id,
                     &module_path,
@@ -504,7 +577,8 @@ pub(crate) fn resolve_method_name(
                 return err(warnings, errors),
                 warnings,
                 errors
-            )
+            );
+            (decl_ref, type_id)
         }
     };
 
@@ -524,6 +598,21 @@ pub(crate) fn resolve_method_name(
         errors
     );
 
+    // Calls to the trait methods of the constrained type parameters of an impl block must call
+    // the implementations for the types the impl block has been instantiated with.
+    let decl_mapping = check!(
+        gather_decl_mapping_from_impl_trait_constraints(
+            ctx.by_ref(),
+            &func_decl,
+            type_id,
+            &method_name_span
+        ),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    func_decl.replace_decls(&decl_mapping, engines);
+
     let decl_ref = ctx
         .decl_engine
         .insert(func_decl)
@@ -531,3 +620,81 @@ pub(crate) fn resolve_method_name(
 
     ok(decl_ref, warnings, errors)
 }
+
+/// Gathers the [DeclMapping] from the trait constraints of the type parameters of the impl block
+/// declaring `method`, given that the impl block is implemented for `self_type`.
+fn gather_decl_mapping_from_impl_trait_constraints(
+    mut ctx: TypeCheckContext,
+    method: &ty::TyFunctionDeclaration,
+    self_type: TypeId,
+    access_span: &Span,
+) -> CompileResult<DeclMapping> {
+    let type_engine = ctx.type_engine;
+    let decl_engine = ctx.decl_engine;
+    let engines = ctx.engines();
+
+    let impl_type_parameters = match &method.implementing_type {
+        Some(ty::TyDeclaration::ImplTrait { decl_id, .. }) => {
+            let impl_trait = decl_engine.get_impl_trait(decl_id);
+            let type_mapping = TypeSubstMap::from_superset_and_subset(
+                type_engine,
+                decl_engine,
+                impl_trait.implementing_for.type_id,
+                self_type,
+            );
+            impl_trait
+                .impl_type_parameters
+                .into_iter()
+                .filter(|type_param| !type_param.trait_constraints.is_empty())
+                .map(|mut type_param| {
+                    type_param.subst(&type_mapping, engines);
+                    type_param
+                })
+                .collect()
+        }
+        _ => vec![],
+    };
+    let mut warnings = vec![];
+    let mut errors = vec![];
+    let mut decl_mapping = check!(
+        TypeParameter::gather_decl_mapping_from_trait_constraints(
+            ctx.by_ref(),
+            &impl_type_parameters,
+            access_span,
+        ),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
+    // The implementations the trait methods are mapped to may themselves be declared in impl
+    // blocks with constrained type parameters, e.g. when those type parameters are nested.
+    for dest_decl_id in decl_mapping.destinations_mut() {
+        let mut dest_decl = decl_engine.get_function(dest_decl_id);
+        let dest_self_type = match dest_decl.parameters.iter().find(|param| param.is_self()) {
+            Some(param) => param.type_argument.type_id,
+            None => continue,
+        };
+        let dest_decl_mapping = check!(
+            gather_decl_mapping_from_impl_trait_constraints(
+                ctx.by_ref(),
+                &dest_decl,
+                dest_self_type,
+                access_span
+            ),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+        if dest_decl_mapping.is_empty() {
+            continue;
+        }
+        dest_decl.replace_decls(&dest_decl_mapping, engines);
+        *dest_decl_id = *decl_engine
+            .insert(dest_decl)
+            .with_parent(decl_engine, (*dest_decl_id).into())
+            .id();
+    }
+
+    ok(decl_mapping, warnings, errors)
+}
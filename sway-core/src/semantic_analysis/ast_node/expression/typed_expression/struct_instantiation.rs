@@ -1,6 +1,7 @@
 use sway_error::error::CompileError;
 use sway_types::{Ident, Span, Spanned};
 
+use super::coerce_string_literal;
 use crate::{
     error::*,
     language::{parsed::*, ty, CallPath},
@@ -93,9 +94,8 @@ pub(crate) fn struct_instantiation(
         errors
     );
     let struct_decl = decl_engine.get_struct(&struct_ref);
-    let struct_name = struct_decl.call_path.suffix;
-    let struct_fields = struct_decl.fields;
-    let mut struct_fields = struct_fields;
+    let struct_name = struct_decl.call_path.suffix.clone();
+    let mut struct_fields = struct_decl.fields.clone();
 
     let typed_fields = check!(
         type_check_field_arguments(
@@ -117,6 +117,21 @@ pub(crate) fn struct_instantiation(
         errors
     );
 
+    // check that the types of the fields satisfy the trait constraints of the
+    // struct's type parameters
+    check!(
+        ctx.namespace
+            .implemented_traits
+            .check_if_trait_constraints_are_satisfied_for_type_parameters(
+                &struct_decl.type_parameters,
+                &span,
+                engines
+            ),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+
     // check that there are no extra fields
     for field in fields {
         if !struct_fields.iter().any(|x| x.name == field.name) {
@@ -155,6 +170,7 @@ fn type_check_field_arguments(
 
     let type_engine = ctx.type_engine;
     let decl_engine = ctx.decl_engine;
+    let engines = ctx.engines();
 
     let mut typed_fields = vec![];
 
@@ -171,6 +187,8 @@ fn type_check_field_arguments(
                     warnings,
                     errors
                 );
+                let value =
+                    coerce_string_literal(engines, value, struct_field.type_argument.type_id);
                 typed_fields.push(ty::TyStructExpressionField {
                     value,
                     name: field.name.clone(),
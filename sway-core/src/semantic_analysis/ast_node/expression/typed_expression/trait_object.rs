@@ -0,0 +1,286 @@
+use sway_error::error::CompileError;
+use sway_types::{Ident, Span, Spanned};
+
+use super::{coerce_string_literal, coerce_u256_literal};
+use crate::{
+    decl_engine::{DeclEngineIndex, DeclRefFunction},
+    error::*,
+    language::ty,
+    semantic_analysis::TypeCheckContext,
+    type_system::*,
+};
+
+/// Coerces `exp` into a trait object if `expected` is a `dyn Trait` type and
+/// `exp` is a value of a concrete type implementing `Trait`. Any other
+/// expression is returned unchanged and left for the caller to unify.
+///
+/// The resulting trait object holds the methods of the implementation in the
+/// order in which the trait declares them, which is the layout of its vtable.
+pub(crate) fn coerce_into_trait_object(
+    mut ctx: TypeCheckContext,
+    exp: ty::TyExpression,
+    expected: TypeId,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let decl_engine = ctx.decl_engine;
+    let engines = ctx.engines();
+
+    let (trait_name, trait_decl_id) = match type_engine.get(expected) {
+        TypeInfo::TraitObject {
+            trait_name,
+            decl_id: Some(decl_id),
+        } => (trait_name, decl_id),
+        _ => return ok(exp, warnings, errors),
+    };
+
+    match type_engine.get(exp.return_type) {
+        // Already a trait object, or an error that has been reported already.
+        TypeInfo::TraitObject { .. } | TypeInfo::ErrorRecovery => return ok(exp, warnings, errors),
+        // Only values of a known, concrete type have an implementation whose
+        // methods can be put in a vtable.
+        TypeInfo::Unknown
+        | TypeInfo::UnknownGeneric { .. }
+        | TypeInfo::Placeholder(_)
+        | TypeInfo::SelfType
+        | TypeInfo::Numeric => {
+            errors.push(CompileError::InvalidTraitObjectSource {
+                ty: engines.help_out(exp.return_type).to_string(),
+                span: exp.span.clone(),
+            });
+            return err(warnings, errors);
+        }
+        _ => {}
+    }
+
+    let impl_items =
+        ctx.namespace
+            .get_items_for_type_and_trait_name(engines, exp.return_type, &trait_name);
+    if impl_items.is_empty() {
+        errors.push(CompileError::TraitConstraintNotSatisfied {
+            ty: engines.help_out(exp.return_type).to_string(),
+            trait_name: trait_name.suffix.to_string(),
+            span: exp.span.clone(),
+        });
+        return err(warnings, errors);
+    }
+
+    let trait_decl = decl_engine.get_trait(&trait_decl_id);
+    let mut methods = vec![];
+    for item in trait_decl.interface_surface.iter() {
+        let trait_fn_ref = match item {
+            ty::TyTraitInterfaceItem::TraitFn(decl_ref) => decl_ref,
+            // Object safety rules out the other kinds of items.
+            ty::TyTraitInterfaceItem::Constant(_) | ty::TyTraitInterfaceItem::Type(_) => continue,
+        };
+        let method_ref = impl_items.iter().find_map(|item| match item {
+            ty::TyTraitItem::Fn(decl_ref) if decl_ref.name() == trait_fn_ref.name() => {
+                Some(decl_ref.clone())
+            }
+            _ => None,
+        });
+        let method_ref = match method_ref {
+            Some(method_ref) => method_ref,
+            None => {
+                errors.push(CompileError::TraitConstraintNotSatisfied {
+                    ty: engines.help_out(exp.return_type).to_string(),
+                    trait_name: trait_name.suffix.to_string(),
+                    span: exp.span.clone(),
+                });
+                return err(warnings, errors);
+            }
+        };
+        methods.push(check!(
+            instantiate_method_for_type(ctx.by_ref(), method_ref, exp.return_type, &exp.span),
+            return err(warnings, errors),
+            warnings,
+            errors
+        ));
+    }
+
+    let span = exp.span.clone();
+    let trait_object = ty::TyExpression {
+        expression: ty::TyExpressionVariant::TraitObject {
+            exp: Box::new(exp),
+            methods,
+        },
+        return_type: expected,
+        span,
+    };
+    ok(trait_object, warnings, errors)
+}
+
+/// Monomorphizes the implementation method `method_ref` for receivers of type
+/// `self_type`.
+fn instantiate_method_for_type(
+    mut ctx: TypeCheckContext,
+    method_ref: DeclRefFunction,
+    self_type: TypeId,
+    span: &Span,
+) -> CompileResult<DeclRefFunction> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let decl_engine = ctx.decl_engine;
+
+    let mut method = decl_engine.get_function(&method_ref);
+    if method.type_parameters.is_empty() {
+        return ok(method_ref, warnings, errors);
+    }
+
+    check!(
+        ctx.monomorphize(&mut method, &mut [], EnforceTypeArguments::No, span),
+        return err(warnings, errors),
+        warnings,
+        errors
+    );
+    if let Some(receiver) = method.parameters.first() {
+        check!(
+            CompileResult::from(type_engine.unify(
+                decl_engine,
+                self_type,
+                receiver.type_argument.type_id,
+                span,
+                "",
+                None
+            )),
+            return err(warnings, errors),
+            warnings,
+            errors
+        );
+    }
+
+    let decl_ref = decl_engine
+        .insert(method)
+        .with_parent(decl_engine, (*method_ref.id()).into());
+    ok(decl_ref, warnings, errors)
+}
+
+/// Type checks a call of the method `method_name` on the trait object
+/// `object`, which dispatches through the vtable of the trait object at
+/// runtime. `arguments` doesn't include `object`.
+pub(crate) fn instantiate_dynamic_method_call(
+    mut ctx: TypeCheckContext,
+    object: ty::TyExpression,
+    method_name: Ident,
+    arguments: Vec<ty::TyExpression>,
+    span: Span,
+) -> CompileResult<ty::TyExpression> {
+    let mut warnings = vec![];
+    let mut errors = vec![];
+
+    let type_engine = ctx.type_engine;
+    let decl_engine = ctx.decl_engine;
+    let engines = ctx.engines();
+
+    let trait_decl_id = match type_engine.get(object.return_type) {
+        TypeInfo::TraitObject {
+            decl_id: Some(decl_id),
+            ..
+        } => decl_id,
+        _ => {
+            errors.push(CompileError::Internal(
+                "Attempted a dynamic method call on a value which is not a trait object.",
+                object.span.clone(),
+            ));
+            return err(warnings, errors);
+        }
+    };
+
+    let trait_decl = decl_engine.get_trait(&trait_decl_id);
+    let found = trait_decl
+        .interface_surface
+        .iter()
+        .filter_map(|item| match item {
+            ty::TyTraitInterfaceItem::TraitFn(decl_ref) => Some(decl_ref),
+            _ => None,
+        })
+        .enumerate()
+        .find(|(_, decl_ref)| decl_ref.name() == &method_name);
+    let (method_index, trait_fn) = match found {
+        Some((method_index, decl_ref)) => (method_index, decl_engine.get_trait_fn(decl_ref)),
+        None => {
+            errors.push(CompileError::MethodNotFound {
+                method_name: method_name.clone(),
+                type_name: engines.help_out(object.return_type).to_string(),
+                span: method_name.span(),
+            });
+            return err(warnings, errors);
+        }
+    };
+
+    let expected = trait_fn.parameters.len();
+    let received = arguments.len() + 1;
+    if received != expected {
+        let method_name = method_name.clone();
+        errors.push(if received > expected {
+            CompileError::TooManyArgumentsForFunction {
+                span,
+                method_name,
+                dot_syntax_used: true,
+                expected,
+                received,
+            }
+        } else {
+            CompileError::TooFewArgumentsForFunction {
+                span,
+                method_name,
+                dot_syntax_used: true,
+                expected,
+                received,
+            }
+        });
+        return err(warnings, errors);
+    }
+
+    let mut typed_arguments = vec![];
+    for (arg, param) in arguments
+        .into_iter()
+        .zip(trait_fn.parameters.iter().skip(1))
+    {
+        let arg = coerce_string_literal(ctx.engines(), arg, param.type_argument.type_id);
+        let arg = coerce_u256_literal(ctx.engines(), arg, param.type_argument.type_id);
+        let arg = check!(
+            coerce_into_trait_object(ctx.by_ref(), arg, param.type_argument.type_id),
+            continue,
+            warnings,
+            errors
+        );
+        check!(
+            CompileResult::from(type_engine.unify(
+                decl_engine,
+                arg.return_type,
+                param.type_argument.type_id,
+                &arg.span,
+                "This argument's type is not castable to the declared parameter type.",
+                Some(CompileError::ArgumentParameterTypeMismatch {
+                    span: arg.span.clone(),
+                    provided: engines.help_out(arg.return_type).to_string(),
+                    should_be: engines.help_out(param.type_argument.type_id).to_string(),
+                })
+            )),
+            continue,
+            warnings,
+            errors
+        );
+        typed_arguments.push(arg);
+    }
+    if !errors.is_empty() {
+        return err(warnings, errors);
+    }
+
+    let exp = ty::TyExpression {
+        expression: ty::TyExpressionVariant::DynamicMethodCall {
+            object: Box::new(object),
+            method_name,
+            method_index,
+            arguments: typed_arguments,
+        },
+        return_type: trait_fn.return_type,
+        span,
+    };
+    ok(exp, warnings, errors)
+}
@@ -126,6 +126,7 @@ fn impl_trait_methods(
         .iter()
         .flat_map(|item| match item {
             ty::TyImplItem::Fn(fn_decl) => Some(fn_decl),
+            ty::TyImplItem::Constant(_) | ty::TyImplItem::Type(_) => None,
         })
         .flat_map(|fn_decl| decl_id_to_fn_decls(decl_engine, &fn_decl.id().clone()))
         .collect()
@@ -216,7 +217,8 @@ fn analyze_expression(
         | StorageAccess(_)
         | Break
         | Continue
-        | AbiName(_) => effects_of_expression(engines, expr),
+        | AbiName(_)
+        | ConstGenericValue { .. } => effects_of_expression(engines, expr),
         Reassignment(reassgn) => analyze_expression(engines, &reassgn.rhs, block_name, warnings),
         StorageReassignment(reassgn) => {
             let storage_effs = HashSet::from([Effect::StorageWrite]);
@@ -315,6 +317,7 @@ fn analyze_expression(
         | Return(expr)
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
+        | TraitObject { exp: expr, .. }
         | AbiCast { address: expr, .. } => analyze_expression(engines, expr, block_name, warnings),
         EnumInstantiation { contents, .. } => match contents {
             Some(expr) => analyze_expression(engines, expr, block_name, warnings),
@@ -365,6 +368,38 @@ fn analyze_expression(
             }
             set_union(init_effs, asmblock_effs)
         }
+        Closure { captures, .. } => analyze_expressions(
+            engines,
+            captures.iter().map(|(_, e)| e).collect(),
+            block_name,
+            warnings,
+        ),
+        ClosureCall { closure, arguments } => {
+            let closure_effs = effects_of_closure_body(engines, closure);
+            // assuming left-to-right evaluation of the closure and its arguments
+            let args_effs = analyze_expressions(
+                engines,
+                std::iter::once(&**closure)
+                    .chain(arguments.iter())
+                    .collect(),
+                block_name,
+                warnings,
+            );
+            if args_effs.contains(&Effect::Interaction) {
+                warn_after_interaction(&closure_effs, &expr.span, &expr.span, block_name, warnings)
+            }
+            set_union(closure_effs, args_effs)
+        }
+        // Trait object methods can't access storage, so a dynamic call has
+        // only the effects of evaluating its receiver and arguments.
+        DynamicMethodCall {
+            object, arguments, ..
+        } => analyze_expressions(
+            engines,
+            std::iter::once(&**object).chain(arguments.iter()).collect(),
+            block_name,
+            warnings,
+        ),
     }
 }
 
@@ -507,7 +542,8 @@ fn effects_of_expression(engines: Engines<'_>, expr: &ty::TyExpression) -> HashS
         | FunctionParameter
         | Break
         | Continue
-        | AbiName(_) => HashSet::new(),
+        | AbiName(_)
+        | ConstGenericValue { .. } => HashSet::new(),
         // this type of assignment only mutates local variables and not storage
         Reassignment(reassgn) => effects_of_expression(engines, &reassgn.rhs),
         StorageAccess(_) => match type_engine.get(expr.return_type) {
@@ -567,6 +603,7 @@ fn effects_of_expression(engines: Engines<'_>, expr: &ty::TyExpression) -> HashS
         | TupleElemAccess { prefix: expr, .. }
         | EnumTag { exp: expr }
         | UnsafeDowncast { exp: expr, .. }
+        | TraitObject { exp: expr, .. }
         | Return(expr) => effects_of_expression(engines, expr),
         EnumInstantiation { contents, .. } => match contents {
             Some(expr) => effects_of_expression(engines, expr),
@@ -606,6 +643,32 @@ fn effects_of_expression(engines: Engines<'_>, expr: &ty::TyExpression) -> HashS
             .union(&effects_of_asm_ops(body))
             .cloned()
             .collect(),
+        Closure { captures, .. } => {
+            map_hashsets_union(captures, |e| effects_of_expression(engines, &e.1))
+        }
+        ClosureCall { closure, arguments } => {
+            let mut effs = effects_of_closure_body(engines, closure);
+            effs.extend(effects_of_expression(engines, closure));
+            effs.extend(effects_of_expressions(engines, arguments));
+            effs
+        }
+        DynamicMethodCall {
+            object, arguments, ..
+        } => {
+            let mut effs = effects_of_expression(engines, object);
+            effs.extend(effects_of_expressions(engines, arguments));
+            effs
+        }
+    }
+}
+
+// The effects of calling a closure are the effects of the function its body was lifted into.
+fn effects_of_closure_body(engines: Engines<'_>, closure: &ty::TyExpression) -> HashSet<Effect> {
+    match engines.te().get(closure.return_type) {
+        crate::TypeInfo::Closure { fn_ref, .. } => {
+            effects_of_codeblock(engines, &engines.de().get_function(&fn_ref).body)
+        }
+        _ => HashSet::new(),
     }
 }
 
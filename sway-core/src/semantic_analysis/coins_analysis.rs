@@ -54,7 +54,9 @@ pub fn possibly_nonzero_u64_expression(
         | StructFieldAccess { .. }
         | TupleElemAccess { .. }
         | StorageAccess(_)
-        | WhileLoop { .. } => true,
+        | WhileLoop { .. }
+        | ClosureCall { .. }
+        | DynamicMethodCall { .. } => true,
         // The following expression variants are unreachable, because of the type system
         // but we still consider these as non-zero to be on the safe side
         LazyOperator { .. }
@@ -67,11 +69,14 @@ pub fn possibly_nonzero_u64_expression(
         | IntrinsicFunction(_)
         | AbiName(_)
         | UnsafeDowncast { .. }
+        | TraitObject { .. }
         | EnumTag { .. }
         | Break
         | Continue
         | Reassignment(_)
         | Return(_)
-        | StorageReassignment(_) => true,
+        | StorageReassignment(_)
+        | Closure { .. }
+        | ConstGenericValue { .. } => true,
     }
 }
@@ -54,11 +54,76 @@ impl ty::TyModule {
     ) -> CompileResult<Vec<ty::TyAstNode>> {
         let mut warnings = Vec::new();
         let mut errors = Vec::new();
-        let typed_nodes = nodes
-            .into_iter()
-            .map(|node| ty::TyAstNode::type_check(ctx.by_ref(), node))
-            .filter_map(|res| res.ok(&mut warnings, &mut errors))
-            .collect();
+
+        // Functions which call each other are each declared before the first of them is type
+        // checked, so that their bodies may refer to one another.
+        let mut recursive_fn_groups =
+            node_dependencies::find_mutually_recursive_fns(ctx.engines(), &nodes);
+
+        // Likewise, types which refer to each other, or to themselves, are declared before the
+        // first of them is type checked.
+        let mut recursive_type_groups =
+            node_dependencies::find_recursive_types(ctx.engines(), &nodes);
+
+        let mut typed_nodes = Vec::new();
+        for node in nodes {
+            match &node.content {
+                AstNodeContent::Declaration(Declaration::FunctionDeclaration(fn_decl)) => {
+                    if let Some(idx) = recursive_fn_groups
+                        .iter()
+                        .position(|group| group.iter().any(|member| member.name == fn_decl.name))
+                    {
+                        for member in recursive_fn_groups.swap_remove(idx) {
+                            // Any errors in the signatures are reported when each function is
+                            // type checked in full.
+                            let _ =
+                                ty::TyFunctionDeclaration::forward_declare(ctx.by_ref(), member);
+                        }
+                    }
+                }
+                AstNodeContent::Declaration(
+                    Declaration::StructDeclaration(StructDeclaration { span, .. })
+                    | Declaration::EnumDeclaration(EnumDeclaration { span, .. }),
+                ) => {
+                    if let Some(idx) = recursive_type_groups.iter().position(|group| {
+                        group.iter().any(|member| match member {
+                            Declaration::StructDeclaration(member) => member.span == *span,
+                            Declaration::EnumDeclaration(member) => member.span == *span,
+                            _ => false,
+                        })
+                    }) {
+                        let group = recursive_type_groups.swap_remove(idx);
+                        let is_mutually_recursive = group.len() > 1;
+                        for member in group {
+                            let res = match member {
+                                Declaration::StructDeclaration(decl) => {
+                                    ty::TyStructDeclaration::forward_declare(
+                                        ctx.by_ref(),
+                                        &decl,
+                                        is_mutually_recursive,
+                                    )
+                                }
+                                Declaration::EnumDeclaration(decl) => {
+                                    ty::TyEnumDeclaration::forward_declare(
+                                        ctx.by_ref(),
+                                        &decl,
+                                        is_mutually_recursive,
+                                    )
+                                }
+                                _ => continue,
+                            };
+                            res.ok(&mut warnings, &mut errors);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            if let Some(typed_node) =
+                ty::TyAstNode::type_check(ctx.by_ref(), node).ok(&mut warnings, &mut errors)
+            {
+                typed_nodes.push(typed_node);
+            }
+        }
         ok(typed_nodes, warnings, errors)
     }
 }
@@ -92,6 +92,14 @@ impl Items {
         self.symbols().keys()
     }
 
+    /// Returns the symbols imported into this scope with `use` statements, along with their
+    /// declarations. Aliased symbols are returned under their alias.
+    pub fn get_all_imported_symbols(&self) -> impl Iterator<Item = (&Ident, &ty::TyDeclaration)> {
+        self.use_synonyms
+            .iter()
+            .map(|(symbol, (_, _, decl))| (symbol, decl))
+    }
+
     pub(crate) fn insert_symbol(
         &mut self,
         name: Ident,
@@ -117,10 +125,12 @@ impl Items {
                 | (
                     StructDeclaration { .. }
                     | EnumDeclaration { .. }
+                    | TypeAliasDeclaration { .. }
                     | TraitDeclaration { .. }
                     | AbiDeclaration { .. },
                     StructDeclaration { .. }
                     | EnumDeclaration { .. }
+                    | TypeAliasDeclaration { .. }
                     | TraitDeclaration { .. }
                     | AbiDeclaration { .. },
                 ) => errors.push(CompileError::NameDefinedMultipleTimes { name: name.to_string(), span: name.span() }),
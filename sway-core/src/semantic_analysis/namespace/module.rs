@@ -4,6 +4,7 @@ use crate::{
     language::{parsed::*, ty},
     semantic_analysis::*,
     transform::to_parsed_lang,
+    type_system::TypeId,
     Ident, Namespace,
 };
 
@@ -167,6 +168,19 @@ impl Module {
         &self.submodules
     }
 
+    /// Returns the implementing type and the span of the impl block of the implementations of
+    /// traits and ABIs named `trait_name` within this module and all of its submodules.
+    ///
+    /// Implementations are shared between modules by imports, so the same impl block may be
+    /// returned more than once.
+    pub fn get_impls_for_trait_name(&self, trait_name: &Ident) -> Vec<(TypeId, Span)> {
+        let mut impls = self.implemented_traits.get_impls_for_trait_name(trait_name);
+        for submodule in self.submodules.values() {
+            impls.extend(submodule.get_impls_for_trait_name(trait_name));
+        }
+        impls
+    }
+
     /// Insert a submodule into this `Module`.
     pub fn insert_submodule(&mut self, name: String, submodule: Module) {
         self.submodules.insert(name, submodule);
@@ -187,6 +187,12 @@ impl Namespace {
             return err(warnings, errors);
         }
 
+        // arrays aren't declared anywhere, so the methods of the generic impls
+        // for arrays are copied to the concrete array type when it is used
+        if let TypeInfo::Array(..) = type_engine.get(type_id) {
+            self.insert_trait_implementation_for_type(engines, type_id);
+        }
+
         // grab the local module
         let local_module = check!(
             self.root().check_submodule(&self.mod_path),
@@ -15,7 +15,7 @@ use crate::{
         CallPath,
     },
     type_system::{SubstTypes, TypeId},
-    ReplaceSelfType, TraitConstraint, TypeArgument, TypeInfo, TypeSubstMap,
+    ReplaceSelfType, TraitConstraint, TypeArgument, TypeInfo, TypeParameter, TypeSubstMap,
 };
 
 #[derive(Clone, Debug)]
@@ -58,6 +58,8 @@ type TraitName = CallPath<TraitSuffix>;
 struct TraitKey {
     name: TraitName,
     type_id: TypeId,
+    /// The span of the impl block, which is not part of the ordering of the keys.
+    impl_span: Span,
 }
 
 impl OrdWithEngines for TraitKey {
@@ -116,11 +118,7 @@ impl TraitMap {
 
         let mut trait_items: TraitItems = im::HashMap::new();
         for item in items.iter() {
-            match item {
-                TyImplItem::Fn(decl_ref) => {
-                    trait_items.insert(decl_ref.name().clone().to_string(), item.clone());
-                }
-            }
+            trait_items.insert(item.name().clone().to_string(), item.clone());
         }
 
         // check to see if adding this trait will produce a conflicting definition
@@ -140,6 +138,7 @@ impl TraitMap {
                 TraitKey {
                     name: map_trait_name,
                     type_id: map_type_id,
+                    ..
                 },
             value: map_trait_items,
         } in self.trait_impls.iter()
@@ -195,17 +194,18 @@ impl TraitMap {
                 });
             } else if types_are_subset && (traits_are_subset || is_impl_self) {
                 for (name, item) in trait_items.iter() {
-                    match item {
-                        ty::TyTraitItem::Fn(decl_ref) => {
-                            if map_trait_items.get(name).is_some() {
-                                errors.push(CompileError::DuplicateDeclDefinedForType {
-                                    decl_kind: "method".into(),
-                                    decl_name: decl_ref.name().to_string(),
-                                    type_implementing_for: engines.help_out(type_id).to_string(),
-                                    span: decl_ref.name().span(),
-                                });
-                            }
-                        }
+                    let decl_kind = match item {
+                        ty::TyTraitItem::Fn(_) => "method",
+                        ty::TyTraitItem::Constant(_) => "constant",
+                        ty::TyTraitItem::Type(_) => "type",
+                    };
+                    if map_trait_items.get(name).is_some() {
+                        errors.push(CompileError::DuplicateDeclDefinedForType {
+                            decl_kind: decl_kind.into(),
+                            decl_name: item.name().to_string(),
+                            type_implementing_for: engines.help_out(type_id).to_string(),
+                            span: item.name().span(),
+                        });
                     }
                 }
             }
@@ -220,7 +220,7 @@ impl TraitMap {
         };
 
         // even if there is a conflicting definition, add the trait anyway
-        self.insert_inner(trait_name, type_id, trait_items, engines);
+        self.insert_inner(trait_name, type_id, impl_span.clone(), trait_items, engines);
 
         if errors.is_empty() {
             ok((), warnings, errors)
@@ -233,12 +233,14 @@ impl TraitMap {
         &mut self,
         trait_name: TraitName,
         type_id: TypeId,
+        impl_span: Span,
         trait_methods: TraitItems,
         engines: Engines<'_>,
     ) {
         let key = TraitKey {
             name: trait_name,
             type_id,
+            impl_span,
         };
         let entry = TraitEntry {
             key,
@@ -578,6 +580,7 @@ impl TraitMap {
                 TraitKey {
                     name: map_trait_name,
                     type_id: map_type_id,
+                    impl_span,
                 },
             value: map_trait_items,
         } in self.trait_impls.iter()
@@ -588,6 +591,7 @@ impl TraitMap {
                     trait_map.insert_inner(
                         map_trait_name.clone(),
                         *type_id,
+                        impl_span.clone(),
                         map_trait_items.clone(),
                         engines,
                     );
@@ -598,29 +602,51 @@ impl TraitMap {
                         *map_type_id,
                         *type_id,
                     );
+                    // Items of impl blocks with trait constraints on their type
+                    // parameters only exist for types that satisfy those constraints.
+                    if !self.type_mapping_satisfies_trait_constraints(&type_mapping, engines) {
+                        continue;
+                    }
                     let new_self_type = type_engine.insert(decl_engine, TypeInfo::SelfType);
                     type_id.replace_self_type(engines, new_self_type);
                     let trait_items: TraitItems = map_trait_items
                         .clone()
                         .into_iter()
                         .map(|(name, item)| {
-                            #[allow(clippy::infallible_destructuring_match)]
-                            let decl_ref = match &item {
-                                ty::TyTraitItem::Fn(decl_ref) => decl_ref,
-                            };
-                            let mut decl = decl_engine.get(*decl_ref.id());
-                            decl.subst(&type_mapping, engines);
-                            decl.replace_self_type(engines, new_self_type);
-                            let new_ref = decl_engine
-                                .insert(decl)
-                                .with_parent(decl_engine, (*decl_ref.id()).into());
                             let item = match item {
-                                ty::TyTraitItem::Fn(_) => TyImplItem::Fn(new_ref),
+                                ty::TyTraitItem::Fn(decl_ref) => {
+                                    let mut decl = decl_engine.get(*decl_ref.id());
+                                    decl.subst(&type_mapping, engines);
+                                    decl.replace_self_type(engines, new_self_type);
+                                    TyImplItem::Fn(
+                                        decl_engine
+                                            .insert(decl)
+                                            .with_parent(decl_engine, (*decl_ref.id()).into()),
+                                    )
+                                }
+                                ty::TyTraitItem::Constant(decl_ref) => {
+                                    let mut decl = decl_engine.get(*decl_ref.id());
+                                    decl.subst(&type_mapping, engines);
+                                    decl.replace_self_type(engines, new_self_type);
+                                    TyImplItem::Constant(decl_engine.insert(decl))
+                                }
+                                ty::TyTraitItem::Type(decl_ref) => {
+                                    let mut decl = decl_engine.get(*decl_ref.id());
+                                    decl.subst(&type_mapping, engines);
+                                    decl.replace_self_type(engines, new_self_type);
+                                    TyImplItem::Type(decl_engine.insert(decl))
+                                }
                             };
                             (name, item)
                         })
                         .collect();
-                    trait_map.insert_inner(map_trait_name.clone(), *type_id, trait_items, engines);
+                    trait_map.insert_inner(
+                        map_trait_name.clone(),
+                        *type_id,
+                        impl_span.clone(),
+                        trait_items,
+                        engines,
+                    );
                 }
             }
         }
@@ -641,29 +667,46 @@ impl TraitMap {
         engines: Engines<'_>,
         type_id: TypeId,
     ) -> Vec<DeclRefFunction> {
+        self.get_items_for_type(engines, type_id)
+            .into_iter()
+            .flat_map(|item| match item {
+                ty::TyTraitItem::Fn(decl_ref) => Some(decl_ref),
+                ty::TyTraitItem::Constant(_) | ty::TyTraitItem::Type(_) => None,
+            })
+            .collect()
+    }
+
+    /// Find the entries in `self` that are equivalent to `type_id`, and
+    /// return all of their items (methods, associated constants and
+    /// associated types).
+    ///
+    /// Notes:
+    /// - equivalency is defined (1) based on whether the types contains types
+    ///     that are dynamic and can change and (2) whether the types hold
+    ///     equivalency after (1) is fulfilled
+    /// - this method does not translate types from the found entries to the
+    ///     `type_id` (like in `filter_by_type()`). This is because the only
+    ///     entries that qualify as hits are equivalents of `type_id`
+    pub(crate) fn get_items_for_type(
+        &self,
+        engines: Engines<'_>,
+        type_id: TypeId,
+    ) -> Vec<ty::TyTraitItem> {
         let type_engine = engines.te();
-        let mut methods = vec![];
+        let mut items = vec![];
         // small performance gain in bad case
         if type_engine
             .get(type_id)
             .eq(&TypeInfo::ErrorRecovery, engines)
         {
-            return methods;
+            return items;
         }
         for entry in self.trait_impls.iter() {
             if are_equal_minus_dynamic_types(engines, type_id, entry.key.type_id) {
-                let mut trait_items = entry
-                    .value
-                    .values()
-                    .cloned()
-                    .flat_map(|item| match item {
-                        ty::TyTraitItem::Fn(decl_ref) => Some(decl_ref),
-                    })
-                    .collect::<Vec<_>>();
-                methods.append(&mut trait_items);
+                items.extend(entry.value.values().cloned());
             }
         }
-        methods
+        items
     }
 
     /// Find the entries in `self` that are equivalent to `type_id` with trait
@@ -707,6 +750,21 @@ impl TraitMap {
         items
     }
 
+    /// Find the entries in `self` implementing a trait with the name
+    /// `trait_name`, and return the type and the span of the impl block of
+    /// each of them.
+    ///
+    /// Notes:
+    /// - only the names of the traits are compared, so the returned entries
+    ///     may be implementations of different traits sharing the same name
+    pub(crate) fn get_impls_for_trait_name(&self, trait_name: &Ident) -> Vec<(TypeId, Span)> {
+        self.trait_impls
+            .iter()
+            .filter(|e| e.key.name.suffix.name == *trait_name)
+            .map(|e| (e.key.type_id, e.key.impl_span.clone()))
+            .collect()
+    }
+
     /// Checks to see if the trait constraints are satisfied for a given type.
     pub(crate) fn check_if_trait_constraints_are_satisfied_for_type(
         &self,
@@ -748,6 +806,9 @@ impl TraitMap {
 
         let required_traits: BTreeMap<Ident, TypeId> = constraints
             .iter()
+            // Function signatures are checked by unifying the closure's
+            // signature when type checking the function application.
+            .filter(|c| c.fn_signature().is_none())
             .map(|c| {
                 let TraitConstraint {
                     trait_name: constraint_trait_name,
@@ -801,6 +862,126 @@ impl TraitMap {
             err(warnings, errors)
         }
     }
+
+    /// Checks to see if the trait constraints of the [TypeParameter]s
+    /// `type_parameters` are satisfied by the types they have been
+    /// instantiated with.
+    ///
+    /// Type parameters whose types are not known yet are not checked.
+    pub(crate) fn check_if_trait_constraints_are_satisfied_for_type_parameters(
+        &self,
+        type_parameters: &[TypeParameter],
+        access_span: &Span,
+        engines: Engines<'_>,
+    ) -> CompileResult<()> {
+        let warnings = vec![];
+        let mut errors = vec![];
+
+        for type_param in type_parameters.iter() {
+            for trait_name in self.unsatisfied_trait_constraints(
+                type_param.type_id,
+                &type_param.trait_constraints,
+                engines,
+            ) {
+                errors.push(CompileError::TraitConstraintNotSatisfied {
+                    ty: engines.help_out(type_param.type_id).to_string(),
+                    trait_name: trait_name.to_string(),
+                    span: access_span.clone(),
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            ok((), warnings, errors)
+        } else {
+            err(warnings, errors)
+        }
+    }
+
+    /// Returns `true` if every type that `type_mapping` substitutes for a
+    /// generic type satisfies the trait constraints of that generic type.
+    fn type_mapping_satisfies_trait_constraints(
+        &self,
+        type_mapping: &TypeSubstMap,
+        engines: Engines<'_>,
+    ) -> bool {
+        let type_engine = engines.te();
+        type_mapping.iter().all(
+            |(source_type, dest_type)| match type_engine.get(*source_type) {
+                TypeInfo::UnknownGeneric {
+                    trait_constraints, ..
+                } => self
+                    .unsatisfied_trait_constraints(*dest_type, &trait_constraints, engines)
+                    .is_empty(),
+                _ => true,
+            },
+        )
+    }
+
+    /// Returns the names of the traits in `constraints` that are not
+    /// implemented for `type_id`.
+    ///
+    /// Unlike [TraitMap::check_if_trait_constraints_are_satisfied_for_type],
+    /// this also considers implementations for generic types, so that
+    /// `Option<u64>` satisfies a constraint implemented by `impl<T> Eq for
+    /// Option<T>`. Types that are not known yet satisfy every constraint, as
+    /// they are checked again once they are known.
+    fn unsatisfied_trait_constraints(
+        &self,
+        type_id: TypeId,
+        constraints: &[TraitConstraint],
+        engines: Engines<'_>,
+    ) -> Vec<Ident> {
+        let type_engine = engines.te();
+        let type_info = type_engine.get(type_id);
+        let own_constraints = match &type_info {
+            TypeInfo::Unknown
+            | TypeInfo::Placeholder(_)
+            | TypeInfo::Numeric
+            | TypeInfo::SelfType
+            | TypeInfo::ErrorRecovery => return vec![],
+            TypeInfo::UnknownGeneric {
+                trait_constraints, ..
+            } => trait_constraints.0.clone(),
+            _ => vec![],
+        };
+
+        constraints
+            .iter()
+            // Function signatures are checked by unifying the closure's
+            // signature when type checking the function application.
+            .filter(|c| c.fn_signature().is_none())
+            .filter(|c| {
+                // A generic type satisfies the constraints it was declared with.
+                let declared = own_constraints
+                    .iter()
+                    .any(|own| own.trait_name.suffix == c.trait_name.suffix);
+                let implemented = self.trait_impls.iter().any(|e| {
+                    let suffix = &e.key.name.suffix;
+                    suffix.name == c.trait_name.suffix
+                        && suffix.args.len() == c.type_arguments.len()
+                        && suffix
+                            .args
+                            .iter()
+                            .zip(c.type_arguments.iter())
+                            .all(|(l, r)| {
+                                are_equal_minus_dynamic_types(engines, l.type_id, r.type_id)
+                            })
+                        && match type_engine.get(e.key.type_id) {
+                            // Entries for generic types come from the trait
+                            // constraints of the enclosing declaration and
+                            // only apply to that very type.
+                            TypeInfo::UnknownGeneric { .. } => {
+                                are_equal_minus_dynamic_types(engines, type_id, e.key.type_id)
+                            }
+                            map_type_info => type_info.is_subset_of(&map_type_info, engines),
+                        }
+                });
+                !declared && !implemented
+            })
+            .map(|c| c.trait_name.suffix.clone())
+            .collect()
+    }
 }
 
 pub(crate) fn are_equal_minus_dynamic_types(
@@ -829,10 +1010,14 @@ pub(crate) fn are_equal_minus_dynamic_types(
         (TypeInfo::Boolean, TypeInfo::Boolean) => true,
         (TypeInfo::B256, TypeInfo::B256) => true,
         (TypeInfo::ErrorRecovery, TypeInfo::ErrorRecovery) => true,
-        (TypeInfo::Str(l), TypeInfo::Str(r)) => l.val() == r.val(),
+        (TypeInfo::Str(l), TypeInfo::Str(r)) => l.eq(&r, engines),
         (TypeInfo::UnsignedInteger(l), TypeInfo::UnsignedInteger(r)) => l == r,
         (TypeInfo::RawUntypedPtr, TypeInfo::RawUntypedPtr) => true,
         (TypeInfo::RawUntypedSlice, TypeInfo::RawUntypedSlice) => true,
+        (TypeInfo::StringSlice, TypeInfo::StringSlice) => true,
+        (l @ TypeInfo::Closure { .. }, r @ TypeInfo::Closure { .. }) => l.eq(&r, engines),
+        (l @ TypeInfo::ConstGeneric { .. }, r @ TypeInfo::ConstGeneric { .. }) => l.eq(&r, engines),
+        (l @ TypeInfo::TraitObject { .. }, r @ TypeInfo::TraitObject { .. }) => l.eq(&r, engines),
         (
             TypeInfo::UnknownGeneric {
                 name: rn,
@@ -868,19 +1053,9 @@ pub(crate) fn are_equal_minus_dynamic_types(
         (TypeInfo::Enum(l_decl_ref), TypeInfo::Enum(r_decl_ref)) => {
             let l_decl = decl_engine.get_enum(&l_decl_ref);
             let r_decl = decl_engine.get_enum(&r_decl_ref);
-            l_decl.call_path.suffix == r_decl.call_path.suffix
-                && l_decl.call_path.suffix.span() == r_decl.call_path.suffix.span()
-                && l_decl.variants.iter().zip(r_decl.variants.iter()).fold(
-                    true,
-                    |acc, (left, right)| {
-                        acc && left.name == right.name
-                            && are_equal_minus_dynamic_types(
-                                engines,
-                                left.type_argument.type_id,
-                                right.type_argument.type_id,
-                            )
-                    },
-                )
+            // An enum is identified by its declaration and its type
+            // arguments, as its variants may refer back to the enum itself
+            l_decl.span == r_decl.span
                 && l_decl
                     .type_parameters
                     .iter()
@@ -893,19 +1068,9 @@ pub(crate) fn are_equal_minus_dynamic_types(
         (TypeInfo::Struct(l_decl_ref), TypeInfo::Struct(r_decl_ref)) => {
             let l_decl = decl_engine.get_struct(&l_decl_ref);
             let r_decl = decl_engine.get_struct(&r_decl_ref);
-            l_decl.call_path.suffix == r_decl.call_path.suffix
-                && l_decl.call_path.suffix.span() == r_decl.call_path.suffix.span()
-                && l_decl.fields.iter().zip(r_decl.fields.iter()).fold(
-                    true,
-                    |acc, (left, right)| {
-                        acc && left.name == right.name
-                            && are_equal_minus_dynamic_types(
-                                engines,
-                                left.type_argument.type_id,
-                                right.type_argument.type_id,
-                            )
-                    },
-                )
+            // A struct is identified by its declaration and its type
+            // arguments, as its fields may refer back to the struct itself
+            l_decl.span == r_decl.span
                 && l_decl
                     .type_parameters
                     .iter()
@@ -927,14 +1092,21 @@ pub(crate) fn are_equal_minus_dynamic_types(
         (
             TypeInfo::ContractCaller {
                 abi_name: l_abi_name,
+                type_arguments: l_type_arguments,
                 address: l_address,
             },
             TypeInfo::ContractCaller {
                 abi_name: r_abi_name,
+                type_arguments: r_type_arguments,
                 address: r_address,
             },
         ) => {
             l_abi_name == r_abi_name
+                && l_type_arguments.len() == r_type_arguments.len()
+                && l_type_arguments
+                    .iter()
+                    .zip(r_type_arguments.iter())
+                    .all(|(l, r)| are_equal_minus_dynamic_types(engines, l.type_id, r.type_id))
                 && Option::zip(l_address, r_address)
                     .map(|(l_address, r_address)| {
                         are_equal_minus_dynamic_types(
@@ -946,7 +1118,7 @@ pub(crate) fn are_equal_minus_dynamic_types(
                     .unwrap_or(true)
         }
         (TypeInfo::Array(l0, l1), TypeInfo::Array(r0, r1)) => {
-            l1.val() == r1.val() && are_equal_minus_dynamic_types(engines, l0.type_id, r0.type_id)
+            l1.eq(&r1, engines) && are_equal_minus_dynamic_types(engines, l0.type_id, r0.type_id)
         }
         _ => false,
     }
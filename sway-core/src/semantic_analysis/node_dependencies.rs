@@ -9,9 +9,9 @@ use crate::{
 };
 
 use sway_error::error::CompileError;
+use sway_types::ident::Ident;
 use sway_types::integer_bits::IntegerBits;
 use sway_types::Spanned;
-use sway_types::{ident::Ident, span::Span};
 
 // -------------------------------------------------------------------------------------------------
 /// Take a list of nodes and reorder them so that they may be semantically analysed without any
@@ -29,7 +29,10 @@ pub(crate) fn order_ast_nodes_by_dependency(
             .filter_map(|node| Dependencies::gather_from_decl_node(engines, node)),
     );
 
-    // Check here for recursive calls now that we have a nice map of the dependencies to help us.
+    // Check here for recursive types now that we have a nice map of the dependencies to help us.
+    // Recursive functions are fine, they're declared ahead of type checking their bodies. So are
+    // types which only refer to themselves via type arguments, e.g., `Box<T>`; whether those have
+    // a finite size is checked once they are type checked.
     let mut errors = find_recursive_decls(&decl_dependencies);
     if !errors.is_empty() {
         // Because we're pulling these errors out of a HashMap they'll probably be in a funny
@@ -67,10 +70,6 @@ fn find_recursive_decl(
     dep_sym: &DependentSymbol,
 ) -> Option<CompileError> {
     match dep_sym {
-        DependentSymbol::Fn(_, Some(fn_span)) => {
-            let mut chain = Vec::new();
-            find_recursive_call_chain(decl_dependencies, dep_sym, fn_span, &mut chain)
-        }
         DependentSymbol::Symbol(_) => {
             let mut chain = Vec::new();
             find_recursive_type_chain(decl_dependencies, dep_sym, &mut chain)
@@ -79,40 +78,6 @@ fn find_recursive_decl(
     }
 }
 
-fn find_recursive_call_chain(
-    decl_dependencies: &DependencyMap,
-    fn_sym: &DependentSymbol,
-    fn_span: &Span,
-    chain: &mut Vec<Ident>,
-) -> Option<CompileError> {
-    if let DependentSymbol::Fn(fn_sym_ident, _) = fn_sym {
-        if chain.contains(fn_sym_ident) {
-            // We've found a recursive loop, but it's possible this function is not actually in the
-            // loop, but is instead just calling into the loop.  Only if this function is at the
-            // start of the chain do we need to report it.
-            return if &chain[0] != fn_sym_ident {
-                None
-            } else {
-                Some(build_recursion_error(
-                    fn_sym_ident.clone(),
-                    fn_span.clone(),
-                    &chain[1..],
-                ))
-            };
-        }
-        decl_dependencies.get(fn_sym).and_then(|deps_set| {
-            chain.push(fn_sym_ident.clone());
-            let result = deps_set.deps.iter().find_map(|dep_sym| {
-                find_recursive_call_chain(decl_dependencies, dep_sym, fn_span, chain)
-            });
-            chain.pop();
-            result
-        })
-    } else {
-        None
-    }
-}
-
 fn find_recursive_type_chain(
     decl_dependencies: &DependencyMap,
     dep_sym: &DependentSymbol,
@@ -130,7 +95,7 @@ fn find_recursive_type_chain(
         decl_dependencies.get(dep_sym).and_then(|deps_set| {
             chain.push(sym_ident.clone());
             let result = deps_set
-                .deps
+                .layout_deps
                 .iter()
                 .find_map(|dep_sym| find_recursive_type_chain(decl_dependencies, dep_sym, chain));
             chain.pop();
@@ -141,37 +106,7 @@ fn find_recursive_type_chain(
     }
 }
 
-fn build_recursion_error(fn_sym: Ident, span: Span, chain: &[Ident]) -> CompileError {
-    match chain.len() {
-        // An empty chain indicates immediate recursion.
-        0 => CompileError::RecursiveCall {
-            fn_name: fn_sym,
-            span,
-        },
-        // Chain entries indicate mutual recursion.
-        1 => CompileError::RecursiveCallChain {
-            fn_name: fn_sym,
-            call_chain: chain[0].as_str().to_string(),
-            span,
-        },
-        n => {
-            let mut msg = chain[0].as_str().to_string();
-            for ident in &chain[1..(n - 1)] {
-                msg.push_str(", ");
-                msg.push_str(ident.as_str());
-            }
-            msg.push_str(" and ");
-            msg.push_str(chain[n - 1].as_str());
-            CompileError::RecursiveCallChain {
-                fn_name: fn_sym,
-                call_chain: msg,
-                span,
-            }
-        }
-    }
-}
-
-fn build_recursive_type_error(name: Ident, chain: &[Ident]) -> CompileError {
+pub(crate) fn build_recursive_type_error(name: Ident, chain: &[Ident]) -> CompileError {
     let span = name.span();
     match chain.len() {
         // An empty chain indicates immediate recursion.
@@ -199,6 +134,112 @@ fn build_recursive_type_error(name: Ident, chain: &[Ident]) -> CompileError {
     }
 }
 
+/// Find the groups of functions declared by `nodes` which call each other, either directly or
+/// via other functions. A function which only calls itself is not part of a group.
+///
+/// The functions in a group must all be declared before the body of any of them is type checked.
+pub(crate) fn find_mutually_recursive_fns(
+    engines: Engines<'_>,
+    nodes: &[AstNode],
+) -> Vec<Vec<FunctionDeclaration>> {
+    let decl_dependencies = DependencyMap::from_iter(
+        nodes
+            .iter()
+            .filter_map(|node| Dependencies::gather_from_decl_node(engines, node)),
+    );
+    let fn_decls = nodes
+        .iter()
+        .filter_map(|node| match &node.content {
+            AstNodeContent::Declaration(Declaration::FunctionDeclaration(fn_decl)) => Some(fn_decl),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let depends_on_fn = |dependant: &FunctionDeclaration, dependee: &FunctionDeclaration| {
+        decl_dependencies
+            .get(&DependentSymbol::Fn(dependant.name.clone()))
+            .map(|deps_set| {
+                recursively_depends_on(
+                    &deps_set.deps,
+                    &DependentSymbol::Fn(dependee.name.clone()),
+                    &decl_dependencies,
+                )
+            })
+            .unwrap_or(false)
+    };
+
+    let mut groups: Vec<Vec<FunctionDeclaration>> = Vec::new();
+    for fn_decl in &fn_decls {
+        if groups
+            .iter()
+            .any(|group| group.iter().any(|member| member.name == fn_decl.name))
+        {
+            continue;
+        }
+        let group = fn_decls
+            .iter()
+            .filter(|other| {
+                other.name == fn_decl.name
+                    || (depends_on_fn(fn_decl, other) && depends_on_fn(other, fn_decl))
+            })
+            .map(|member| (*member).clone())
+            .collect::<Vec<_>>();
+        if group.len() > 1 {
+            groups.push(group);
+        }
+    }
+    groups
+}
+
+/// Find the groups of structs and enums declared by `nodes` which refer to each other, either
+/// directly or via other types. Unlike with functions, a type which only refers to itself forms a
+/// group of its own.
+///
+/// The types in a group must all be declared before any of them is type checked.
+pub(crate) fn find_recursive_types(
+    engines: Engines<'_>,
+    nodes: &[AstNode],
+) -> Vec<Vec<Declaration>> {
+    let type_engine = engines.te();
+    let decl_dependencies = DependencyMap::from_iter(
+        nodes
+            .iter()
+            .filter_map(|node| Dependencies::gather_from_decl_node(engines, node)),
+    );
+    let type_decls = nodes
+        .iter()
+        .filter_map(|node| match &node.content {
+            AstNodeContent::Declaration(
+                decl @ (Declaration::StructDeclaration(_) | Declaration::EnumDeclaration(_)),
+            ) => decl_name(type_engine, decl).map(|name| (name, decl)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let depends_on_type = |dependant: &DependentSymbol, dependee: &DependentSymbol| {
+        decl_dependencies
+            .get(dependant)
+            .map(|deps_set| recursively_depends_on(&deps_set.deps, dependee, &decl_dependencies))
+            .unwrap_or(false)
+    };
+
+    let mut groups: Vec<Vec<Declaration>> = Vec::new();
+    let mut grouped: HashSet<&DependentSymbol> = HashSet::new();
+    for (name, _) in &type_decls {
+        if grouped.contains(name) || !depends_on_type(name, name) {
+            continue;
+        }
+        let group = type_decls
+            .iter()
+            .filter(|(other, _)| depends_on_type(name, other) && depends_on_type(other, name))
+            .map(|(other, decl)| {
+                grouped.insert(other);
+                (*decl).clone()
+            })
+            .collect::<Vec<_>>();
+        groups.push(group);
+    }
+    groups
+}
+
 // -------------------------------------------------------------------------------------------------
 // Dependency gathering.
 
@@ -271,9 +312,15 @@ fn depends_on(
 // -------------------------------------------------------------------------------------------------
 // Dependencies are just a collection of dependee symbols.
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 struct Dependencies {
     deps: HashSet<DependentSymbol>,
+    /// The subset of `deps` which are types stored by value, e.g., the `A` in `a: A` or
+    /// `a: (A, u64)` but not in `a: Box<A>`. Only these make up the layout of a type, so only
+    /// these may not refer back to the type itself.
+    layout_deps: HashSet<DependentSymbol>,
+    /// Whether we are currently gathering from the type arguments of another type.
+    in_type_arguments: bool,
 }
 
 impl Dependencies {
@@ -286,10 +333,7 @@ impl Dependencies {
             AstNodeContent::Declaration(decl) => decl_name(type_engine, decl).map(|name| {
                 (
                     name,
-                    Dependencies {
-                        deps: HashSet::new(),
-                    }
-                    .gather_from_decl(engines, decl),
+                    Dependencies::default().gather_from_decl(engines, decl),
                 )
             }),
             _ => None,
@@ -340,6 +384,12 @@ impl Dependencies {
                             deps.gather_from_type_argument(engines, &param.type_argument)
                         })
                         .gather_from_typeinfo(engines, &sig.return_type),
+                    TraitItem::Constant(const_decl) => {
+                        deps.gather_from_constant_decl(engines, const_decl)
+                    }
+                    TraitItem::Type(type_decl) => {
+                        deps.gather_from_trait_type_decl(engines, type_decl)
+                    }
                 })
                 .gather_from_iter(methods.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(engines, fn_decl)
@@ -356,6 +406,12 @@ impl Dependencies {
                 .gather_from_type_parameters(impl_type_parameters)
                 .gather_from_iter(items.iter(), |deps, item| match item {
                     ImplItem::Fn(fn_decl) => deps.gather_from_fn_decl(engines, fn_decl),
+                    ImplItem::Constant(const_decl) => {
+                        deps.gather_from_constant_decl(engines, const_decl)
+                    }
+                    ImplItem::Type(type_decl) => {
+                        deps.gather_from_trait_type_decl(engines, type_decl)
+                    }
                 }),
             Declaration::ImplSelf(ImplSelf {
                 implementing_for,
@@ -365,6 +421,12 @@ impl Dependencies {
                 .gather_from_type_argument(engines, implementing_for)
                 .gather_from_iter(items.iter(), |deps, item| match item {
                     ImplItem::Fn(fn_decl) => deps.gather_from_fn_decl(engines, fn_decl),
+                    ImplItem::Constant(const_decl) => {
+                        deps.gather_from_constant_decl(engines, const_decl)
+                    }
+                    ImplItem::Type(type_decl) => {
+                        deps.gather_from_trait_type_decl(engines, type_decl)
+                    }
                 }),
             Declaration::AbiDeclaration(AbiDeclaration {
                 interface_surface,
@@ -381,10 +443,23 @@ impl Dependencies {
                             deps.gather_from_type_argument(engines, &param.type_argument)
                         })
                         .gather_from_typeinfo(engines, &sig.return_type),
+                    TraitItem::Constant(const_decl) => {
+                        deps.gather_from_constant_decl(engines, const_decl)
+                    }
+                    TraitItem::Type(type_decl) => {
+                        deps.gather_from_trait_type_decl(engines, type_decl)
+                    }
                 })
                 .gather_from_iter(methods.iter(), |deps, fn_decl| {
                     deps.gather_from_fn_decl(engines, fn_decl)
                 }),
+            Declaration::TypeAliasDeclaration(TypeAliasDeclaration {
+                ty,
+                type_parameters,
+                ..
+            }) => self
+                .gather_from_type_argument(engines, ty)
+                .gather_from_type_parameters(type_parameters),
             Declaration::StorageDeclaration(StorageDeclaration { fields, .. }) => self
                 .gather_from_iter(
                     fields.iter(),
@@ -412,6 +487,17 @@ impl Dependencies {
             Some(value) => self
                 .gather_from_type_argument(engines, type_ascription)
                 .gather_from_expr(engines, value),
+            None => self.gather_from_type_argument(engines, type_ascription),
+        }
+    }
+
+    fn gather_from_trait_type_decl(
+        self,
+        engines: Engines<'_>,
+        type_decl: &TraitTypeDeclaration,
+    ) -> Self {
+        match &type_decl.ty_opt {
+            Some(ty) => self.gather_from_type_argument(engines, ty),
             None => self,
         }
     }
@@ -539,9 +625,9 @@ impl Dependencies {
 
             // we should do address someday, but due to the whole `re_parse_expression` thing
             // it isn't possible right now
-            ExpressionKind::AbiCast(abi_cast_expression) => {
-                self.gather_from_call_path(&abi_cast_expression.abi_name, false, false)
-            }
+            ExpressionKind::AbiCast(abi_cast_expression) => self
+                .gather_from_call_path(&abi_cast_expression.abi_name, false, false)
+                .gather_from_type_arguments(engines, &abi_cast_expression.type_arguments),
 
             ExpressionKind::Literal(_)
             | ExpressionKind::Break
@@ -569,6 +655,11 @@ impl Dependencies {
                 self.gather_from_expr(engines, &reassignment.rhs)
             }
             ExpressionKind::Return(expr) => self.gather_from_expr(engines, expr),
+            ExpressionKind::Closure(closure) => self
+                .gather_from_iter(closure.parameters.iter(), |deps, param| {
+                    deps.gather_from_type_argument(engines, &param.type_argument)
+                })
+                .gather_from_expr(engines, &closure.body),
         }
     }
 
@@ -617,7 +708,7 @@ impl Dependencies {
         if call_path.prefixes.is_empty() {
             // We can just use the suffix.
             self.deps.insert(if is_fn_app {
-                DependentSymbol::Fn(call_path.suffix.clone(), None)
+                DependentSymbol::Fn(call_path.suffix.clone())
             } else {
                 DependentSymbol::Symbol(call_path.suffix.clone())
             });
@@ -659,17 +750,32 @@ impl Dependencies {
         match type_info {
             TypeInfo::ContractCaller {
                 abi_name: AbiName::Known(abi_name),
+                type_arguments,
                 ..
-            } => self.gather_from_call_path(abi_name, false, false),
+            } => self
+                .gather_from_call_path(abi_name, false, false)
+                .gather_from_type_arguments(engines, type_arguments),
+            TypeInfo::TraitObject { trait_name, .. } => {
+                self.gather_from_call_path(trait_name, false, false)
+            }
             TypeInfo::Custom {
                 call_path: name,
                 type_arguments,
             } => {
-                self.deps
-                    .insert(DependentSymbol::Symbol(name.clone().suffix));
+                let sym = DependentSymbol::Symbol(name.clone().suffix);
+                if !self.in_type_arguments {
+                    self.layout_deps.insert(sym.clone());
+                }
+                self.deps.insert(sym);
                 match type_arguments {
                     Some(type_arguments) => {
-                        self.gather_from_type_arguments(engines, type_arguments)
+                        // Whether a type argument is stored by value depends on the type it is
+                        // applied to, which isn't known until type checking.
+                        let in_type_arguments = self.in_type_arguments;
+                        self.in_type_arguments = true;
+                        let mut deps = self.gather_from_type_arguments(engines, type_arguments);
+                        deps.in_type_arguments = in_type_arguments;
+                        deps
                     }
                     None => self,
                 }
@@ -704,44 +810,13 @@ impl Dependencies {
 // they themselves depend on other declarations, no declarations depend on them.  This is
 // illustrated in DependentSymbol::is().
 
-#[derive(Debug, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum DependentSymbol {
     Symbol(Ident),
-    Fn(Ident, Option<Span>),
+    Fn(Ident),
     Impl(Ident, String, String), // Trait or self, type implementing for, and method names concatenated.
 }
 
-// We'll use a custom Hash and PartialEq here to explicitly ignore the span in the Fn variant.
-
-impl PartialEq for DependentSymbol {
-    fn eq(&self, rhs: &Self) -> bool {
-        match (self, rhs) {
-            (DependentSymbol::Symbol(l), DependentSymbol::Symbol(r)) => l.eq(r),
-            (DependentSymbol::Fn(l, _), DependentSymbol::Fn(r, _)) => l.eq(r),
-            (DependentSymbol::Impl(lt, ls, lm), DependentSymbol::Impl(rt, rs, rm)) => {
-                lt.eq(rt) && ls.eq(rs) && lm.eq(rm)
-            }
-            _ => false,
-        }
-    }
-}
-
-use std::hash::{Hash, Hasher};
-
-impl Hash for DependentSymbol {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        match self {
-            DependentSymbol::Symbol(s) => s.hash(state),
-            DependentSymbol::Fn(s, _) => s.hash(state),
-            DependentSymbol::Impl(t, s, m) => {
-                t.hash(state);
-                s.hash(state);
-                m.hash(state)
-            }
-        }
-    }
-}
-
 fn decl_name(type_engine: &TypeEngine, decl: &Declaration) -> Option<DependentSymbol> {
     let dep_sym = |name| Some(DependentSymbol::Symbol(name));
     // `method_names` is the concatenation of all the method names defined in an impl block.
@@ -757,15 +832,13 @@ fn decl_name(type_engine: &TypeEngine, decl: &Declaration) -> Option<DependentSy
 
     match decl {
         // These declarations can depend upon other declarations.
-        Declaration::FunctionDeclaration(decl) => Some(DependentSymbol::Fn(
-            decl.name.clone(),
-            Some(decl.span.clone()),
-        )),
+        Declaration::FunctionDeclaration(decl) => Some(DependentSymbol::Fn(decl.name.clone())),
         Declaration::ConstantDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::StructDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::EnumDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::TraitDeclaration(decl) => dep_sym(decl.name.clone()),
         Declaration::AbiDeclaration(decl) => dep_sym(decl.name.clone()),
+        Declaration::TypeAliasDeclaration(decl) => dep_sym(decl.name.clone()),
 
         // These have the added complexity of converting CallPath and/or TypeInfo into a name.
         Declaration::ImplSelf(decl) => {
@@ -777,6 +850,8 @@ fn decl_name(type_engine: &TypeEngine, decl: &Declaration) -> Option<DependentSy
                     .iter()
                     .map(|item| match item {
                         ImplItem::Fn(fn_decl) => fn_decl.name.as_str(),
+                        ImplItem::Constant(const_decl) => const_decl.name.as_str(),
+                        ImplItem::Type(type_decl) => type_decl.name.as_str(),
                     })
                     .collect::<Vec<&str>>()
                     .join(""),
@@ -791,6 +866,8 @@ fn decl_name(type_engine: &TypeEngine, decl: &Declaration) -> Option<DependentSy
                         .iter()
                         .map(|item| match item {
                             ImplItem::Fn(fn_decl) => fn_decl.name.as_str(),
+                            ImplItem::Constant(const_decl) => const_decl.name.as_str(),
+                            ImplItem::Type(type_decl) => type_decl.name.as_str(),
                         })
                         .collect::<Vec<&str>>()
                         .join(""),
@@ -817,6 +894,7 @@ fn type_info_name(type_info: &TypeInfo) -> String {
             IntegerBits::Sixteen => "uint16",
             IntegerBits::ThirtyTwo => "uint32",
             IntegerBits::SixtyFour => "uint64",
+            IntegerBits::V256 => "uint256",
         },
         TypeInfo::Boolean => "bool",
         TypeInfo::Custom {
@@ -842,6 +920,10 @@ fn type_info_name(type_info: &TypeInfo) -> String {
         TypeInfo::Storage { .. } => "contract storage",
         TypeInfo::RawUntypedPtr => "raw untyped ptr",
         TypeInfo::RawUntypedSlice => "raw untyped slice",
+        TypeInfo::StringSlice => "str",
+        TypeInfo::Closure { .. } => "closure",
+        TypeInfo::ConstGeneric { name, .. } => return format!("const generic {name}"),
+        TypeInfo::TraitObject { trait_name, .. } => return format!("dyn {trait_name}"),
     }
     .to_string()
 }
@@ -852,13 +934,23 @@ fn recursively_depends_on(
     dependee: &DependentSymbol,
     decl_dependencies: &DependencyMap,
 ) -> bool {
-    set.contains(dependee)
-        || set.iter().any(|dep| {
-            decl_dependencies
-                .get(dep)
-                .map(|dep| recursively_depends_on(&dep.deps, dependee, decl_dependencies))
-                .unwrap_or(false)
-        })
+    // Functions may call each other, so we must take care not to follow a cycle forever.
+    fn visit<'a>(
+        set: &'a HashSet<DependentSymbol>,
+        dependee: &DependentSymbol,
+        decl_dependencies: &'a DependencyMap,
+        visited: &mut HashSet<&'a DependentSymbol>,
+    ) -> bool {
+        set.contains(dependee)
+            || set.iter().any(|dep| {
+                visited.insert(dep)
+                    && decl_dependencies
+                        .get(dep)
+                        .map(|dep| visit(&dep.deps, dependee, decl_dependencies, visited))
+                        .unwrap_or(false)
+            })
+    }
+    visit(set, dependee, decl_dependencies, &mut HashSet::new())
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -89,7 +89,8 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
         | ty::TyExpressionVariant::TupleElemAccess { prefix: exp, .. }
         | ty::TyExpressionVariant::AbiCast { address: exp, .. }
         | ty::TyExpressionVariant::EnumTag { exp }
-        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. } => {
+        | ty::TyExpressionVariant::UnsafeDowncast { exp, .. }
+        | ty::TyExpressionVariant::TraitObject { exp, .. } => {
             check!(expr_validate(engines, exp), (), warnings, errors)
         }
         ty::TyExpressionVariant::EnumInstantiation { contents, .. } => {
@@ -108,6 +109,7 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
         }
         ty::TyExpressionVariant::Break => (),
         ty::TyExpressionVariant::Continue => (),
+        ty::TyExpressionVariant::ConstGenericValue { .. } => (),
         ty::TyExpressionVariant::Reassignment(reassignment) => {
             let ty::TyReassignment {
                 lhs_base_name, rhs, ..
@@ -134,6 +136,30 @@ fn expr_validate(engines: Engines<'_>, expr: &ty::TyExpression) -> CompileResult
         ty::TyExpressionVariant::Return(exp) => {
             check!(expr_validate(engines, exp), (), warnings, errors)
         }
+        ty::TyExpressionVariant::Closure { fn_ref, captures } => {
+            for (_, capture) in captures {
+                check!(expr_validate(engines, capture), continue, warnings, errors);
+            }
+            let body = engines.de().get_function(fn_ref).body;
+            check!(
+                validate_decls_for_storage_only_types_in_codeblock(engines, &body),
+                (),
+                warnings,
+                errors
+            );
+        }
+        ty::TyExpressionVariant::ClosureCall {
+            closure: object,
+            arguments,
+        }
+        | ty::TyExpressionVariant::DynamicMethodCall {
+            object, arguments, ..
+        } => {
+            check!(expr_validate(engines, object), (), warnings, errors);
+            for f in arguments {
+                check!(expr_validate(engines, f), continue, warnings, errors);
+            }
+        }
     }
     ok((), warnings, errors)
 }
@@ -221,6 +247,15 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDeclaration) -> CompileResul
                             errors
                         );
                     }
+                    ty::TyImplItem::Constant(decl_ref) => {
+                        check!(
+                            validate_const_decl(engines, decl_ref.id()),
+                            (),
+                            warnings,
+                            errors
+                        );
+                    }
+                    ty::TyImplItem::Type(_) => {}
                 }
             }
         }
@@ -276,6 +311,7 @@ fn decl_validate(engines: Engines<'_>, decl: &ty::TyDeclaration) -> CompileResul
             }
         }
         ty::TyDeclaration::GenericTypeForFunctionScope { .. }
+        | ty::TyDeclaration::TypeAliasDeclaration { .. }
         | ty::TyDeclaration::ErrorRecovery(_) => {}
     }
     if errors.is_empty() {
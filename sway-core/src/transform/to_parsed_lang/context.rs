@@ -11,6 +11,16 @@ pub struct Context {
 
     /// Unique suffix used to generate unique names for vars returned from `match` expressions
     match_expression_return_var_unique_suffix: usize,
+
+    /// Unique suffix used to generate unique names for the vars introduced by `for` loops
+    for_loop_unique_suffix: usize,
+
+    /// Unique suffix used to generate unique names for the vars holding the values bound by
+    /// patterns that are checked for irrefutability
+    pattern_bindings_unique_suffix: usize,
+
+    /// Unique suffix used to generate unique names for destructured function parameters
+    fn_arg_unique_suffix: usize,
 }
 
 impl Context {
@@ -42,4 +52,25 @@ impl Context {
         self.match_expression_return_var_unique_suffix += 1;
         self.match_expression_return_var_unique_suffix
     }
+
+    /// Returns a unique suffix used to generate unique names for the vars introduced by a `for`
+    /// loop
+    pub fn next_for_loop_unique_suffix(&mut self) -> usize {
+        self.for_loop_unique_suffix += 1;
+        self.for_loop_unique_suffix
+    }
+
+    /// Returns a unique suffix used to generate a unique name for a var holding the values bound
+    /// by a pattern that is checked for irrefutability
+    pub fn next_pattern_bindings_unique_suffix(&mut self) -> usize {
+        self.pattern_bindings_unique_suffix += 1;
+        self.pattern_bindings_unique_suffix
+    }
+
+    /// Returns a unique suffix used to generate a unique name for a destructured function
+    /// parameter
+    pub fn next_fn_arg_unique_suffix(&mut self) -> usize {
+        self.fn_arg_unique_suffix += 1;
+        self.fn_arg_unique_suffix
+    }
 }
@@ -9,14 +9,15 @@ use sway_ast::{
     attribute::Annotated,
     expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant},
     ty::TyTupleDescriptor,
-    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, CodeBlockContents,
-    CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor,
-    FnArg, FnArgs, FnSignature, GenericArgs, GenericParams, IfCondition, IfExpr, Instruction,
-    Intrinsic, Item, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind,
-    ItemStorage, ItemStruct, ItemTrait, ItemTraitItem, ItemUse, LitInt, LitIntType,
-    MatchBranchKind, Module, ModuleKind, Parens, PathExpr, PathExprSegment, PathType,
-    PathTypeSegment, Pattern, PatternStructField, PubToken, Punctuated, QualifiedPathRoot,
-    Statement, StatementLet, Submodule, Traits, Ty, TypeField, UseTree, WhereClause,
+    AbiCastArgs, AngleBrackets, AsmBlock, Assignable, AttributeDecl, Braces, ClosureParam,
+    ClosureParams, CodeBlockContents, CommaToken, DoubleColonToken, Expr, ExprArrayDescriptor,
+    ExprStructField, ExprTupleDescriptor, FnArg, FnArgs, FnSignature, GenericArgs, GenericParam,
+    GenericParams, IfCondition, IfExpr, Instruction, Intrinsic, Item, ItemAbi, ItemConfigurable,
+    ItemConst, ItemEnum, ItemFn, ItemImpl, ItemKind, ItemStorage, ItemStruct, ItemTrait,
+    ItemTraitItem, ItemTypeAlias, ItemUse, LitInt, LitIntType, MatchBranchKind, Module, ModuleKind,
+    Parens, PathExpr, PathExprSegment, PathType, PathTypeSegment, Pattern, PatternStructField,
+    PubToken, Punctuated, QualifiedPathRoot, Statement, StatementLet, Submodule, TraitType, Traits,
+    Ty, TypeField, UseTree, WhereClause,
 };
 use sway_error::convert_parse_tree_error::ConvertParseTreeError;
 use sway_error::handler::{ErrorEmitted, Handler};
@@ -24,11 +25,13 @@ use sway_error::warning::{CompileWarning, Warning};
 use sway_types::{
     constants::{
         ALLOW_ATTRIBUTE_NAME, DESTRUCTURE_PREFIX, DOC_ATTRIBUTE_NAME, DOC_COMMENT_ATTRIBUTE_NAME,
-        INLINE_ATTRIBUTE_NAME, MATCH_RETURN_VAR_NAME_PREFIX, PAYABLE_ATTRIBUTE_NAME,
+        FN_ARG_NAME_PREFIX, FOR_LOOP_VAR_NAME_PREFIX, INLINE_ATTRIBUTE_NAME,
+        MATCH_RETURN_VAR_NAME_PREFIX, PATTERN_BINDINGS_VAR_NAME_PREFIX, PAYABLE_ATTRIBUTE_NAME,
         STORAGE_PURITY_ATTRIBUTE_NAME, STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME,
         TEST_ATTRIBUTE_NAME, TUPLE_NAME_PREFIX, VALID_ATTRIBUTE_NAMES,
     },
     integer_bits::IntegerBits,
+    u256::U256,
 };
 use sway_types::{Ident, Span, Spanned};
 
@@ -205,6 +208,15 @@ fn item_to_ast_nodes(
         .into_iter()
         .map(|decl| AstNodeContent::Declaration(Declaration::ConstantDeclaration(decl)))
         .collect(),
+        ItemKind::TypeAlias(item_type_alias) => decl(Declaration::TypeAliasDeclaration(
+            item_type_alias_to_type_alias_declaration(
+                context,
+                handler,
+                engines,
+                item_type_alias,
+                attributes,
+            )?,
+        )),
     };
 
     Ok(contents
@@ -322,12 +334,6 @@ fn item_struct_to_struct_declaration(
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    if fields.iter().any(
-        |field| matches!(&engines.te().get(field.type_argument.type_id), TypeInfo::Custom { call_path, ..} if call_path.suffix == item_struct.name),
-    ) {
-        errors.push(ConvertParseTreeError::RecursiveType { span: span.clone() });
-    }
-
     // Make sure each struct field is declared once
     let mut names_of_fields = std::collections::HashSet::new();
     fields.iter().for_each(|v| {
@@ -380,12 +386,6 @@ fn item_enum_to_enum_declaration(
         })
         .collect::<Result<Vec<_>, _>>()?;
 
-    if variants.iter().any(|variant| {
-       matches!(&engines.te().get(variant.type_argument.type_id), TypeInfo::Custom { call_path, ..} if call_path.suffix == item_enum.name)
-    }) {
-        errors.push(ConvertParseTreeError::RecursiveType { span: span.clone() });
-    }
-
     // Make sure each enum variant is declared once
     let mut names_of_variants = std::collections::HashSet::new();
     variants.iter().for_each(|v| {
@@ -440,27 +440,41 @@ fn item_fn_to_function_declaration(
             }
         }
     };
+    let fn_type_parameters = fn_type_params_to_type_parameters(
+        context,
+        handler,
+        engines,
+        &item_fn.fn_signature.arguments.inner,
+    )?;
+    let mut type_parameters = generic_params_opt_to_type_parameters(
+        context,
+        handler,
+        engines,
+        item_fn.fn_signature.generics,
+        item_fn.fn_signature.where_clause_opt.clone(),
+    )?;
+    type_parameters.extend(fn_type_parameters);
+    let (parameters, destructuring) = fn_args_to_function_parameters(
+        context,
+        handler,
+        engines,
+        item_fn.fn_signature.arguments.into_inner(),
+        true,
+    )?;
+    let mut body =
+        braced_code_block_contents_to_code_block(context, handler, engines, item_fn.body)?;
+    // Parameters bound to patterns are destructured before the rest of the body runs.
+    body.contents.splice(0..0, destructuring);
     Ok(FunctionDeclaration {
         purity: get_attributed_purity(context, handler, &attributes)?,
         attributes,
         name: item_fn.fn_signature.name,
         visibility: pub_token_opt_to_visibility(item_fn.fn_signature.visibility),
-        body: braced_code_block_contents_to_code_block(context, handler, engines, item_fn.body)?,
-        parameters: fn_args_to_function_parameters(
-            context,
-            handler,
-            engines,
-            item_fn.fn_signature.arguments.into_inner(),
-        )?,
+        body,
+        parameters,
         span,
         return_type,
-        type_parameters: generic_params_opt_to_type_parameters(
-            context,
-            handler,
-            engines,
-            item_fn.fn_signature.generics,
-            item_fn.fn_signature.where_clause_opt.clone(),
-        )?,
+        type_parameters,
         where_clause: item_fn
             .fn_signature
             .where_clause_opt
@@ -550,6 +564,14 @@ fn item_trait_to_trait_declaration(
                     fn_signature_to_trait_fn(context, handler, engines, fn_sig, attributes)
                         .map(TraitItem::TraitFn)
                 }
+                ItemTraitItem::Const(item_const) => item_const_to_constant_declaration(
+                    context, handler, engines, item_const, attributes, false,
+                )
+                .map(TraitItem::Constant),
+                ItemTraitItem::Type(trait_type) => trait_type_to_trait_type_declaration(
+                    context, handler, engines, trait_type, attributes, false,
+                )
+                .map(TraitItem::Type),
             }
         })
         .collect::<Result<_, _>>()?;
@@ -606,6 +628,14 @@ fn item_impl_to_declaration(
                     item_fn_to_function_declaration(context, handler, engines, fn_item, attributes)
                         .map(ImplItem::Fn)
                 }
+                sway_ast::ItemImplItem::Const(item_const) => item_const_to_constant_declaration(
+                    context, handler, engines, item_const, attributes, true,
+                )
+                .map(ImplItem::Constant),
+                sway_ast::ItemImplItem::Type(trait_type) => trait_type_to_trait_type_declaration(
+                    context, handler, engines, trait_type, attributes, true,
+                )
+                .map(ImplItem::Type),
             }
         })
         .collect::<Result<_, _>>()?;
@@ -681,8 +711,11 @@ fn item_abi_to_abi_declaration(
     attributes: AttributesMap,
 ) -> Result<AbiDeclaration, ErrorEmitted> {
     let span = item_abi.span();
+    let type_parameters =
+        generic_params_opt_to_type_parameters(context, handler, engines, item_abi.generics, None)?;
     Ok(AbiDeclaration {
         name: item_abi.name,
+        type_parameters,
         interface_surface: {
             item_abi
                 .abi_items
@@ -709,6 +742,18 @@ fn item_abi_to_abi_declaration(
                             )?;
                             Ok(TraitItem::TraitFn(trait_fn))
                         }
+                        ItemTraitItem::Const(item_const) => Err(handler.emit_err(
+                            ConvertParseTreeError::AssociatedItemsInAbiNotSupported {
+                                span: item_const.span(),
+                            }
+                            .into(),
+                        )),
+                        ItemTraitItem::Type(trait_type) => Err(handler.emit_err(
+                            ConvertParseTreeError::AssociatedItemsInAbiNotSupported {
+                                span: trait_type.span(),
+                            }
+                            .into(),
+                        )),
                     }
                 })
                 .collect::<Result<_, _>>()?
@@ -786,6 +831,35 @@ pub(crate) fn item_const_to_constant_declaration(
     })
 }
 
+fn trait_type_to_trait_type_declaration(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    trait_type: TraitType,
+    attributes: AttributesMap,
+    requires_type: bool,
+) -> Result<TraitTypeDeclaration, ErrorEmitted> {
+    let span = trait_type.span();
+    let ty_opt = match trait_type.ty_opt {
+        Some(ty) => Some(ty_to_type_argument(context, handler, engines, ty)?),
+        None => {
+            if requires_type {
+                let err = ConvertParseTreeError::AssociatedTypeRequiresType { span: span.clone() };
+                if let Some(errors) = emit_all(handler, vec![err]) {
+                    return Err(errors);
+                }
+            }
+            None
+        }
+    };
+    Ok(TraitTypeDeclaration {
+        name: trait_type.name,
+        attributes,
+        ty_opt,
+        span,
+    })
+}
+
 fn item_storage_to_storage_declaration(
     context: &mut Context,
     handler: &Handler,
@@ -887,6 +961,33 @@ fn item_configurable_to_constant_declarations(
     Ok(declarations)
 }
 
+fn item_type_alias_to_type_alias_declaration(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    item_type_alias: ItemTypeAlias,
+    attributes: AttributesMap,
+) -> Result<TypeAliasDeclaration, ErrorEmitted> {
+    let span = item_type_alias.span();
+    let ty = ty_to_type_argument(context, handler, engines, item_type_alias.ty)?;
+
+    let type_alias_declaration = TypeAliasDeclaration {
+        name: item_type_alias.name,
+        attributes,
+        type_parameters: generic_params_opt_to_type_parameters(
+            context,
+            handler,
+            engines,
+            item_type_alias.generics,
+            None,
+        )?,
+        ty,
+        visibility: pub_token_opt_to_visibility(item_type_alias.visibility),
+        span,
+    };
+    Ok(type_alias_declaration)
+}
+
 fn type_field_to_struct_field(
     context: &mut Context,
     handler: &Handler,
@@ -923,32 +1024,46 @@ fn generic_params_opt_to_type_parameters(
         None => Vec::new(),
     };
 
-    let mut params = match generic_params_opt {
-        Some(generic_params) => generic_params
-            .parameters
-            .into_inner()
-            .into_iter()
-            .map(|ident| {
-                let custom_type = type_engine.insert(
-                    decl_engine,
-                    TypeInfo::Custom {
-                        call_path: ident.clone().into(),
-                        type_arguments: None,
-                    },
-                );
-                TypeParameter {
-                    type_id: custom_type,
-                    initial_type_id: custom_type,
-                    name_ident: ident,
-                    trait_constraints: Vec::new(),
-                    trait_constraints_span: Span::dummy(),
+    let mut errors = Vec::new();
+    let mut params = Vec::new();
+    for generic_param in generic_params_opt
+        .map(|generic_params| generic_params.parameters.into_inner())
+        .into_iter()
+        .flatten()
+    {
+        let (ident, type_info) = match generic_param {
+            GenericParam::Type(ident) => {
+                let type_info = TypeInfo::Custom {
+                    call_path: ident.clone().into(),
+                    type_arguments: None,
+                };
+                (ident, type_info)
+            }
+            GenericParam::Const { name, ty, .. } => {
+                let ty_span = ty.span();
+                if !matches!(
+                    ty_to_type_info(context, handler, engines, *ty)?,
+                    TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)
+                ) {
+                    errors.push(ConvertParseTreeError::ConstGenericMustBeU64 { span: ty_span });
                 }
-            })
-            .collect::<Vec<_>>(),
-        None => Vec::new(),
-    };
+                let type_info = TypeInfo::ConstGeneric {
+                    name: name.clone(),
+                    value: None,
+                };
+                (name, type_info)
+            }
+        };
+        let type_id = type_engine.insert(decl_engine, type_info);
+        params.push(TypeParameter {
+            type_id,
+            initial_type_id: type_id,
+            name_ident: ident,
+            trait_constraints: Vec::new(),
+            trait_constraints_span: Span::dummy(),
+        });
+    }
 
-    let mut errors = Vec::new();
     for (ty_name, bounds) in trait_constraints.into_iter() {
         let param_to_edit = match params
             .iter_mut()
@@ -966,6 +1081,16 @@ fn generic_params_opt_to_type_parameters(
 
         param_to_edit.trait_constraints_span = Span::join(ty_name.span(), bounds.span());
 
+        if matches!(
+            type_engine.get(param_to_edit.type_id),
+            TypeInfo::ConstGeneric { .. }
+        ) {
+            errors.push(ConvertParseTreeError::ConstGenericTraitConstraint {
+                span: param_to_edit.trait_constraints_span.clone(),
+            });
+            continue;
+        }
+
         param_to_edit
             .trait_constraints
             .extend(traits_to_trait_constraints(
@@ -1032,16 +1157,26 @@ fn braced_code_block_contents_to_code_block(
     })
 }
 
+/// Converts function arguments to [FunctionParameter]s, together with the [AstNode]s that
+/// destructure the arguments bound to patterns, see [fn_arg_to_function_parameter].
 fn fn_args_to_function_parameters(
     context: &mut Context,
     handler: &Handler,
     engines: Engines<'_>,
     fn_args: FnArgs,
-) -> Result<Vec<FunctionParameter>, ErrorEmitted> {
+    destructuring_allowed: bool,
+) -> Result<(Vec<FunctionParameter>, Vec<AstNode>), ErrorEmitted> {
+    let mut destructuring = Vec::new();
+    let mut to_function_parameter = |context: &mut Context, fn_arg| {
+        let (function_parameter, ast_nodes) =
+            fn_arg_to_function_parameter(context, handler, engines, fn_arg, destructuring_allowed)?;
+        destructuring.extend(ast_nodes);
+        Ok::<_, ErrorEmitted>(function_parameter)
+    };
     let function_parameters = match fn_args {
         FnArgs::Static(args) => args
             .into_iter()
-            .map(|fn_arg| fn_arg_to_function_parameter(context, handler, engines, fn_arg))
+            .map(|fn_arg| to_function_parameter(context, fn_arg))
             .collect::<Result<_, _>>()?,
         FnArgs::NonStatic {
             self_token,
@@ -1070,8 +1205,7 @@ fn fn_args_to_function_parameters(
             }];
             if let Some((_comma_token, args)) = args_opt {
                 for arg in args {
-                    let function_parameter =
-                        fn_arg_to_function_parameter(context, handler, engines, arg)?;
+                    let function_parameter = to_function_parameter(context, arg)?;
                     function_parameters.push(function_parameter);
                 }
             }
@@ -1091,7 +1225,7 @@ fn fn_args_to_function_parameters(
         }
     }
 
-    Ok(function_parameters)
+    Ok((function_parameters, destructuring))
 }
 
 pub(crate) fn type_name_to_type_info_opt(name: &Ident) -> Option<TypeInfo> {
@@ -1100,6 +1234,7 @@ pub(crate) fn type_name_to_type_info_opt(name: &Ident) -> Option<TypeInfo> {
         "u16" => Some(TypeInfo::UnsignedInteger(IntegerBits::Sixteen)),
         "u32" => Some(TypeInfo::UnsignedInteger(IntegerBits::ThirtyTwo)),
         "u64" => Some(TypeInfo::UnsignedInteger(IntegerBits::SixtyFour)),
+        "u256" => Some(TypeInfo::UnsignedInteger(IntegerBits::V256)),
         "bool" => Some(TypeInfo::Boolean),
         "unit" => Some(TypeInfo::Tuple(Vec::new())),
         "b256" => Some(TypeInfo::B256),
@@ -1131,13 +1266,22 @@ fn ty_to_type_info(
             let ty_array_descriptor = bracketed_ty_array_descriptor.into_inner();
             TypeInfo::Array(
                 ty_to_type_argument(context, handler, engines, *ty_array_descriptor.ty)?,
-                expr_to_length(context, handler, *ty_array_descriptor.length)?,
+                expr_to_array_length(context, handler, engines, *ty_array_descriptor.length)?,
             )
         }
         Ty::Str { length, .. } => {
             TypeInfo::Str(expr_to_length(context, handler, *length.into_inner())?)
         }
+        Ty::StringSlice(..) => TypeInfo::StringSlice,
         Ty::Infer { .. } => TypeInfo::Unknown,
+        Ty::Fn { .. } => {
+            let error = ConvertParseTreeError::FnTypeNotSupportedHere { span: ty.span() };
+            return Err(handler.emit_err(error.into()));
+        }
+        Ty::TraitObject { trait_path, .. } => TypeInfo::TraitObject {
+            trait_name: path_type_to_call_path(context, handler, trait_path)?,
+            decl_id: None,
+        },
     };
     Ok(type_info)
 }
@@ -1229,6 +1373,14 @@ fn fn_signature_to_trait_fn(
     fn_signature: FnSignature,
     attributes: AttributesMap,
 ) -> Result<TraitFn, ErrorEmitted> {
+    // Trait and ABI methods cannot be generic, so they cannot take functions.
+    if let Some(fn_type_span) = fn_type_params(&fn_signature.arguments.inner)
+        .next()
+        .map(|(_name, ty)| ty.span())
+    {
+        let error = ConvertParseTreeError::FnTypeNotSupportedHere { span: fn_type_span };
+        return Err(handler.emit_err(error.into()));
+    }
     let return_type_span = match &fn_signature.return_type_opt {
         Some((_right_arrow_token, ty)) => ty.span(),
         None => fn_signature.span(),
@@ -1237,12 +1389,15 @@ fn fn_signature_to_trait_fn(
         name: fn_signature.name,
         purity: get_attributed_purity(context, handler, &attributes)?,
         attributes,
+        // Trait and ABI methods without a body cannot destructure their parameters.
         parameters: fn_args_to_function_parameters(
             context,
             handler,
             engines,
             fn_signature.arguments.into_inner(),
-        )?,
+            false,
+        )?
+        .0,
         return_type: match fn_signature.return_type_opt {
             Some((_right_arrow_token, ty)) => ty_to_type_info(context, handler, engines, ty)?,
             None => TypeInfo::Tuple(Vec::new()),
@@ -1356,9 +1511,14 @@ fn abi_cast_args_to_abi_cast_expression(
     args: Parens<AbiCastArgs>,
 ) -> Result<Box<AbiCastExpression>, ErrorEmitted> {
     let AbiCastArgs { name, address, .. } = args.into_inner();
-    let abi_name = path_type_to_call_path(context, handler, name)?;
+    let (abi_name, type_arguments) =
+        path_type_to_call_path_and_type_arguments(context, handler, engines, name)?;
     let address = Box::new(expr_to_expression(context, handler, engines, *address)?);
-    Ok(Box::new(AbiCastExpression { abi_name, address }))
+    Ok(Box::new(AbiCastExpression {
+        abi_name,
+        type_arguments,
+        address,
+    }))
 }
 
 fn struct_path_and_fields_to_struct_expression(
@@ -1695,71 +1855,19 @@ fn expr_to_expression(
             value, branches, ..
         } => {
             let value = expr_to_expression(context, handler, engines, *value)?;
-            let var_decl_span = value.span();
-
-            // Generate a deterministic name for the variable returned by the match expression.
-            let match_return_var_name = format!(
-                "{}{}",
-                MATCH_RETURN_VAR_NAME_PREFIX,
-                context.next_match_expression_return_var_unique_suffix(),
-            );
-            let var_decl_name =
-                Ident::new_with_override(match_return_var_name, var_decl_span.clone());
-
-            let var_decl_exp = Expression {
-                kind: ExpressionKind::Variable(var_decl_name.clone()),
-                span: var_decl_span,
-            };
-            let branches = {
-                branches
-                    .into_inner()
-                    .into_iter()
-                    .map(|match_branch| {
-                        match_branch_to_match_branch(context, handler, engines, match_branch)
-                    })
-                    .collect::<Result<_, _>>()?
-            };
-            Expression {
-                kind: ExpressionKind::CodeBlock(CodeBlock {
-                    contents: vec![
-                        AstNode {
-                            content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                                VariableDeclaration {
-                                    type_ascription: {
-                                        let type_id =
-                                            engines.te().insert(engines.de(), TypeInfo::Unknown);
-                                        TypeArgument {
-                                            type_id,
-                                            initial_type_id: type_id,
-                                            span: var_decl_name.span(),
-                                            call_path_tree: None,
-                                        }
-                                    },
-                                    name: var_decl_name,
-                                    is_mutable: false,
-                                    body: value,
-                                },
-                            )),
-                            span: span.clone(),
-                        },
-                        AstNode {
-                            content: AstNodeContent::ImplicitReturnExpression(Expression {
-                                kind: ExpressionKind::Match(MatchExpression {
-                                    value: Box::new(var_decl_exp),
-                                    branches,
-                                }),
-                                span: span.clone(),
-                            }),
-                            span: span.clone(),
-                        },
-                    ],
-                    whole_block_span: span.clone(),
-                }),
-                span,
-            }
+            let branches = branches
+                .into_inner()
+                .into_iter()
+                .map(|match_branch| {
+                    match_branch_to_match_branch(context, handler, engines, match_branch)
+                })
+                .collect::<Result<_, _>>()?;
+            match_to_expression(context, engines, value, branches, span)
         }
         Expr::While {
-            condition, block, ..
+            condition: IfCondition::Expr(condition),
+            block,
+            ..
         } => Expression {
             kind: ExpressionKind::WhileLoop(WhileLoopExpression {
                 condition: Box::new(expr_to_expression(context, handler, engines, *condition)?),
@@ -1767,10 +1875,37 @@ fn expr_to_expression(
             }),
             span,
         },
+        Expr::While {
+            condition: IfCondition::Let { lhs, rhs, .. },
+            block,
+            ..
+        } => while_let_to_expression(context, handler, engines, *lhs, *rhs, block, span)?,
+        Expr::For {
+            value_pattern,
+            iterator,
+            block,
+            ..
+        } => for_expr_to_expression(
+            context,
+            handler,
+            engines,
+            value_pattern,
+            *iterator,
+            block,
+            span,
+        )?,
         Expr::FuncApp { func, args } => {
             let kind = expr_func_app_to_expression_kind(context, handler, engines, func, args)?;
             Expression { kind, span }
         }
+        Expr::Closure { params, body } => {
+            let closure_expression =
+                closure_to_closure_expression(context, handler, engines, params, *body)?;
+            Expression {
+                kind: ExpressionKind::Closure(closure_expression),
+                span,
+            }
+        }
         Expr::Index { target, arg } => Expression {
             kind: ExpressionKind::ArrayIndex(ArrayIndexExpression {
                 prefix: Box::new(expr_to_expression(context, handler, engines, *target)?),
@@ -2200,23 +2335,57 @@ fn statement_to_ast_nodes(
     Ok(ast_nodes)
 }
 
+/// Converts a function argument to a [FunctionParameter].
+///
+/// If `destructuring_allowed` is set, arguments bound to a pattern other than a variable are
+/// given a generated name, and the returned [AstNode]s destructure that name using the pattern.
+/// These nodes have to be placed at the start of the function body.
 fn fn_arg_to_function_parameter(
     context: &mut Context,
     handler: &Handler,
     engines: Engines<'_>,
     fn_arg: FnArg,
-) -> Result<FunctionParameter, ErrorEmitted> {
+    destructuring_allowed: bool,
+) -> Result<(FunctionParameter, Vec<AstNode>), ErrorEmitted> {
     let pat_span = fn_arg.pattern.span();
-    let (reference, mutable, name) = match fn_arg.pattern {
-        Pattern::Wildcard { .. } => {
-            let error = ConvertParseTreeError::WildcardPatternsNotSupportedHere { span: pat_span };
-            return Err(handler.emit_err(error.into()));
-        }
+    let (reference, mutable, name, destructuring) = match fn_arg.pattern {
         Pattern::Var {
             reference,
             mutable,
             name,
-        } => (reference, mutable, name),
+        } => (reference, mutable, name, vec![]),
+        Pattern::Error(..) => {
+            let error =
+                ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span: pat_span };
+            return Err(handler.emit_err(error.into()));
+        }
+        pattern if destructuring_allowed && !matches!(fn_arg.ty, Ty::Fn { .. }) => {
+            let name = Ident::new_with_override(
+                format!(
+                    "{}{}",
+                    FN_ARG_NAME_PREFIX,
+                    context.next_fn_arg_unique_suffix()
+                ),
+                pat_span.clone(),
+            );
+            let destructuring = pattern_to_ast_nodes(
+                context,
+                handler,
+                engines,
+                pattern,
+                None,
+                Expression {
+                    kind: ExpressionKind::Variable(name.clone()),
+                    span: pat_span.clone(),
+                },
+                pat_span,
+            )?;
+            (None, None, name, destructuring)
+        }
+        Pattern::Wildcard { .. } => {
+            let error = ConvertParseTreeError::WildcardPatternsNotSupportedHere { span: pat_span };
+            return Err(handler.emit_err(error.into()));
+        }
         Pattern::Literal(..) => {
             let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
@@ -2225,7 +2394,7 @@ fn fn_arg_to_function_parameter(
             let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
         }
-        Pattern::Constructor { .. } | Pattern::Error(..) => {
+        Pattern::Constructor { .. } => {
             let error =
                 ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span: pat_span };
             return Err(handler.emit_err(error.into()));
@@ -2245,14 +2414,172 @@ fn fn_arg_to_function_parameter(
         (Some(reference), None) => reference.span(),
         (Some(reference), Some(mutable)) => Span::join(reference.span(), mutable.span()),
     };
+    let type_argument = match fn_arg.ty {
+        // Parameters of a function type are generic over the closure
+        // passed in, see `fn_type_params_to_type_parameters`.
+        ty @ Ty::Fn { .. } => {
+            let type_id = engines.te().insert(
+                engines.de(),
+                TypeInfo::Custom {
+                    call_path: fn_type_param_type_name(&name).into(),
+                    type_arguments: None,
+                },
+            );
+            TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: ty.span(),
+                call_path_tree: None,
+            }
+        }
+        ty => ty_to_type_argument(context, handler, engines, ty)?,
+    };
     let function_parameter = FunctionParameter {
         name,
         is_reference: reference.is_some(),
         is_mutable: mutable.is_some(),
+        type_argument,
         mutability_span,
-        type_argument: ty_to_type_argument(context, handler, engines, fn_arg.ty)?,
     };
-    Ok(function_parameter)
+    Ok((function_parameter, destructuring))
+}
+
+/// Returns the name and the type of every parameter declared with a function
+/// type, e.g. `f: fn(u64) -> u64`.
+fn fn_type_params(fn_args: &FnArgs) -> impl Iterator<Item = (&Ident, &Ty)> {
+    let args = match fn_args {
+        FnArgs::Static(args) => Some(args),
+        FnArgs::NonStatic { args_opt, .. } => args_opt.as_ref().map(|(_comma_token, args)| args),
+    };
+    args.into_iter()
+        .flatten()
+        .filter_map(|fn_arg| match (&fn_arg.pattern, &fn_arg.ty) {
+            (Pattern::Var { name, .. }, ty @ Ty::Fn { .. }) => Some((name, ty)),
+            _ => None,
+        })
+}
+
+/// The name of the implicit type parameter of a parameter with a function
+/// type. It starts with `__` so it cannot clash with user-written names.
+fn fn_type_param_type_name(param_name: &Ident) -> Ident {
+    Ident::new_with_override(format!("__fn_{param_name}"), param_name.span())
+}
+
+/// Creates an implicit type parameter for every parameter with a function
+/// type. A function `fn apply(f: fn(u64) -> u64)` is treated as
+/// `fn apply<__fn_f>(f: __fn_f)` where `__fn_f` is constrained to closures
+/// taking a `u64` and returning a `u64`.
+fn fn_type_params_to_type_parameters(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    fn_args: &FnArgs,
+) -> Result<Vec<TypeParameter>, ErrorEmitted> {
+    let mut type_parameters = vec![];
+    for (name, ty) in fn_type_params(fn_args) {
+        let (parameters, return_type_opt) = match ty.clone() {
+            Ty::Fn {
+                parameters,
+                return_type_opt,
+                ..
+            } => (parameters, return_type_opt),
+            _ => unreachable!("`fn_type_params` only yields function types"),
+        };
+        let parameters = parameters
+            .into_inner()
+            .into_iter()
+            .map(|ty| ty_to_type_argument(context, handler, engines, ty))
+            .collect::<Result<Vec<_>, _>>()?;
+        let return_type = match return_type_opt {
+            Some((_right_arrow, ty)) => ty_to_type_argument(context, handler, engines, *ty)?,
+            None => {
+                let type_id = engines
+                    .te()
+                    .insert(engines.de(), TypeInfo::Tuple(Vec::new()));
+                TypeArgument {
+                    type_id,
+                    initial_type_id: type_id,
+                    span: ty.span(),
+                    call_path_tree: None,
+                }
+            }
+        };
+        let name_ident = fn_type_param_type_name(name);
+        let type_id = engines.te().insert(
+            engines.de(),
+            TypeInfo::Custom {
+                call_path: name_ident.clone().into(),
+                type_arguments: None,
+            },
+        );
+        type_parameters.push(TypeParameter {
+            type_id,
+            initial_type_id: type_id,
+            name_ident,
+            trait_constraints: vec![TraitConstraint::new_fn_signature(
+                parameters,
+                return_type,
+                ty.span(),
+            )],
+            trait_constraints_span: ty.span(),
+        });
+    }
+    Ok(type_parameters)
+}
+
+fn closure_to_closure_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    params: ClosureParams,
+    body: Expr,
+) -> Result<Box<ClosureExpression>, ErrorEmitted> {
+    let parameters = params
+        .into_params()
+        .into_iter()
+        .map(|param| closure_param_to_closure_parameter(context, handler, engines, param))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut unique_params = HashSet::<Ident>::default();
+    for param in &parameters {
+        let already_used = !unique_params.insert(param.name.clone());
+        if already_used {
+            let error = ConvertParseTreeError::DuplicateParameterIdentifier {
+                name: param.name.clone(),
+                span: param.name.span(),
+            };
+            return Err(handler.emit_err(error.into()));
+        }
+    }
+
+    Ok(Box::new(ClosureExpression {
+        parameters,
+        body: Box::new(expr_to_expression(context, handler, engines, body)?),
+    }))
+}
+
+fn closure_param_to_closure_parameter(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    param: ClosureParam,
+) -> Result<ClosureParameter, ErrorEmitted> {
+    let type_argument = match param.ty_opt {
+        Some((_colon_token, ty)) => ty_to_type_argument(context, handler, engines, ty)?,
+        None => {
+            let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
+            TypeArgument {
+                type_id,
+                initial_type_id: type_id,
+                span: param.name.span(),
+                call_path_tree: None,
+            }
+        }
+    };
+    Ok(ClosureParameter {
+        name: param.name,
+        type_argument,
+    })
 }
 
 fn expr_to_length(
@@ -2264,6 +2591,31 @@ fn expr_to_length(
     Ok(Length::new(expr_to_usize(context, handler, expr)?, span))
 }
 
+/// Converts the length of an array type, which is either an integer literal or
+/// the name of a const generic parameter, like the `N` in `[u64; N]`.
+fn expr_to_array_length(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    expr: Expr,
+) -> Result<Length, ErrorEmitted> {
+    match expr {
+        Expr::Path(path_expr) => {
+            let span = path_expr.span();
+            let name = path_expr_to_ident(context, handler, path_expr)?;
+            let type_id = engines.te().insert(
+                engines.de(),
+                TypeInfo::Custom {
+                    call_path: name.into(),
+                    type_arguments: None,
+                },
+            );
+            Ok(Length::generic(type_id, span))
+        }
+        expr => expr_to_length(context, handler, expr),
+    }
+}
+
 fn expr_to_usize(
     _context: &mut Context,
     handler: &Handler,
@@ -2521,6 +2873,7 @@ fn if_expr_to_expression(
                 kind: ExpressionKind::Match(MatchExpression {
                     value: Box::new(expr_to_expression(context, handler, engines, *rhs)?),
                     branches,
+                    irrefutable: false,
                 }),
                 span,
             }
@@ -2529,15 +2882,314 @@ fn if_expr_to_expression(
     Ok(expression)
 }
 
-/// Determine if the path is in absolute form, e.g., `::foo::bar`.
-///
-/// Throws an error when given `<Foo as Bar>::baz`.
-fn path_root_opt_to_bool(
-    _context: &mut Context,
-    handler: &Handler,
-    root_opt: Option<(Option<AngleBrackets<QualifiedPathRoot>>, DoubleColonToken)>,
-) -> Result<bool, ErrorEmitted> {
-    Ok(match root_opt {
+/// Converts a `match` on `value` into a code block which first binds `value` to a variable, so
+/// that it is evaluated only once however many branches it is matched against.
+fn match_to_expression(
+    context: &mut Context,
+    engines: Engines<'_>,
+    value: Expression,
+    branches: Vec<MatchBranch>,
+    span: Span,
+) -> Expression {
+    let var_decl_span = value.span();
+
+    // Generate a deterministic name for the variable returned by the match expression.
+    let match_return_var_name = format!(
+        "{}{}",
+        MATCH_RETURN_VAR_NAME_PREFIX,
+        context.next_match_expression_return_var_unique_suffix(),
+    );
+    let var_decl_name = Ident::new_with_override(match_return_var_name, var_decl_span.clone());
+
+    let var_decl_exp = Expression {
+        kind: ExpressionKind::Variable(var_decl_name.clone()),
+        span: var_decl_span,
+    };
+    Expression {
+        kind: ExpressionKind::CodeBlock(CodeBlock {
+            contents: vec![
+                AstNode {
+                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                        VariableDeclaration {
+                            type_ascription: {
+                                let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
+                                TypeArgument {
+                                    type_id,
+                                    initial_type_id: type_id,
+                                    span: var_decl_name.span(),
+                                    call_path_tree: None,
+                                }
+                            },
+                            name: var_decl_name,
+                            is_mutable: false,
+                            body: value,
+                        },
+                    )),
+                    span: span.clone(),
+                },
+                AstNode {
+                    content: AstNodeContent::ImplicitReturnExpression(Expression {
+                        kind: ExpressionKind::Match(MatchExpression {
+                            value: Box::new(var_decl_exp),
+                            branches,
+                            irrefutable: false,
+                        }),
+                        span: span.clone(),
+                    }),
+                    span: span.clone(),
+                },
+            ],
+            whole_block_span: span.clone(),
+        }),
+        span,
+    }
+}
+
+/// Desugars `while let <pattern> = <value> { <body> }` into:
+///
+/// ```ignore
+/// while true {
+///     match <value> {
+///         <pattern> => { <body> },
+///         _ => { break; },
+///     }
+/// }
+/// ```
+///
+/// The `value` is evaluated anew on every iteration, and the loop ends at the first value which
+/// does not match the `pattern`.
+fn while_let_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    pattern: Pattern,
+    value: Expr,
+    block: Braces<CodeBlockContents>,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let value_span = value.span();
+    let scrutinee = pattern_to_scrutinee(context, handler, pattern)?;
+    let scrutinee_span = scrutinee.span();
+    let value = expr_to_expression(context, handler, engines, value)?;
+    let body_span = block.span();
+    let body = braced_code_block_contents_to_code_block(context, handler, engines, block)?;
+
+    let branches = vec![
+        MatchBranch {
+            scrutinee,
+            result: Expression {
+                kind: ExpressionKind::CodeBlock(body),
+                span: body_span.clone(),
+            },
+            span: Span::join(scrutinee_span, body_span),
+        },
+        // Any value which does not match the pattern ends the loop.
+        MatchBranch {
+            scrutinee: Scrutinee::CatchAll {
+                span: value_span.clone(),
+            },
+            result: Expression {
+                kind: ExpressionKind::CodeBlock(CodeBlock {
+                    contents: vec![AstNode {
+                        content: AstNodeContent::Expression(Expression {
+                            kind: ExpressionKind::Break,
+                            span: value_span.clone(),
+                        }),
+                        span: value_span.clone(),
+                    }],
+                    whole_block_span: value_span.clone(),
+                }),
+                span: value_span.clone(),
+            },
+            span: value_span,
+        },
+    ];
+    let match_expression = match_to_expression(context, engines, value, branches, span.clone());
+
+    Ok(Expression {
+        kind: ExpressionKind::WhileLoop(WhileLoopExpression {
+            condition: Box::new(Expression {
+                kind: ExpressionKind::Literal(Literal::Boolean(true)),
+                span: span.clone(),
+            }),
+            body: CodeBlock {
+                contents: vec![AstNode {
+                    content: AstNodeContent::Expression(match_expression),
+                    span: span.clone(),
+                }],
+                whole_block_span: span.clone(),
+            },
+        }),
+        span,
+    })
+}
+
+/// Desugars `for <pattern> in <iterator> { <body> }` into:
+///
+/// ```ignore
+/// {
+///     let mut __for_loop_iterator_N = <iterator>;
+///     while true {
+///         let __for_loop_next_N = __for_loop_iterator_N.next();
+///         if __for_loop_next_N.is_none() {
+///             break;
+///         }
+///         let <pattern> = __for_loop_next_N.unwrap();
+///         { <body> }
+///     }
+/// }
+/// ```
+fn for_expr_to_expression(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    value_pattern: Pattern,
+    iterator: Expr,
+    block: Braces<CodeBlockContents>,
+    span: Span,
+) -> Result<Expression, ErrorEmitted> {
+    let iterator_span = iterator.span();
+    let pattern_span = value_pattern.span();
+    let iterator = expr_to_expression(context, handler, engines, iterator)?;
+    let body_span = block.span();
+    let body = braced_code_block_contents_to_code_block(context, handler, engines, block)?;
+
+    // Generate deterministic names for the iterator and for the value returned by `next`.
+    let unique_suffix = context.next_for_loop_unique_suffix();
+    let iterator_name = Ident::new_with_override(
+        format!("{FOR_LOOP_VAR_NAME_PREFIX}iterator_{unique_suffix}"),
+        iterator_span.clone(),
+    );
+    let next_name = Ident::new_with_override(
+        format!("{FOR_LOOP_VAR_NAME_PREFIX}next_{unique_suffix}"),
+        iterator_span.clone(),
+    );
+
+    let variable = |name: &Ident| Expression {
+        kind: ExpressionKind::Variable(name.clone()),
+        span: name.span(),
+    };
+    let method_call = |method_name: &str, target: Expression, span: &Span| Expression {
+        kind: ExpressionKind::MethodApplication(Box::new(MethodApplicationExpression {
+            method_name_binding: TypeBinding {
+                inner: MethodName::FromModule {
+                    method_name: Ident::new_with_override(method_name.into(), span.clone()),
+                },
+                type_arguments: TypeArgs::Regular(vec![]),
+                span: span.clone(),
+            },
+            contract_call_params: vec![],
+            arguments: vec![target],
+        })),
+        span: span.clone(),
+    };
+    let variable_declaration = |name: Ident, body: Expression, is_mutable: bool| {
+        let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
+        AstNode {
+            content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                VariableDeclaration {
+                    type_ascription: TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: name.span(),
+                        call_path_tree: None,
+                    },
+                    name,
+                    is_mutable,
+                    body,
+                },
+            )),
+            span: span.clone(),
+        }
+    };
+
+    let mut loop_body = vec![
+        variable_declaration(
+            next_name.clone(),
+            method_call("next", variable(&iterator_name), &iterator_span),
+            false,
+        ),
+        AstNode {
+            content: AstNodeContent::Expression(Expression {
+                kind: ExpressionKind::If(IfExpression {
+                    condition: Box::new(method_call(
+                        "is_none",
+                        variable(&next_name),
+                        &iterator_span,
+                    )),
+                    then: Box::new(Expression {
+                        kind: ExpressionKind::CodeBlock(CodeBlock {
+                            contents: vec![AstNode {
+                                content: AstNodeContent::Expression(Expression {
+                                    kind: ExpressionKind::Break,
+                                    span: iterator_span.clone(),
+                                }),
+                                span: iterator_span.clone(),
+                            }],
+                            whole_block_span: iterator_span.clone(),
+                        }),
+                        span: iterator_span.clone(),
+                    }),
+                    r#else: None,
+                }),
+                span: iterator_span.clone(),
+            }),
+            span: iterator_span.clone(),
+        },
+    ];
+    loop_body.extend(pattern_to_ast_nodes(
+        context,
+        handler,
+        engines,
+        value_pattern,
+        None,
+        method_call("unwrap", variable(&next_name), &pattern_span),
+        pattern_span,
+    )?);
+    loop_body.push(AstNode {
+        content: AstNodeContent::Expression(Expression {
+            kind: ExpressionKind::CodeBlock(body),
+            span: body_span.clone(),
+        }),
+        span: body_span,
+    });
+
+    Ok(Expression {
+        kind: ExpressionKind::CodeBlock(CodeBlock {
+            contents: vec![
+                variable_declaration(iterator_name, iterator, true),
+                AstNode {
+                    content: AstNodeContent::ImplicitReturnExpression(Expression {
+                        kind: ExpressionKind::WhileLoop(WhileLoopExpression {
+                            condition: Box::new(Expression {
+                                kind: ExpressionKind::Literal(Literal::Boolean(true)),
+                                span: span.clone(),
+                            }),
+                            body: CodeBlock {
+                                contents: loop_body,
+                                whole_block_span: span.clone(),
+                            },
+                        }),
+                        span: span.clone(),
+                    }),
+                    span: span.clone(),
+                },
+            ],
+            whole_block_span: span.clone(),
+        }),
+        span,
+    })
+}
+
+/// Determine if the path is in absolute form, e.g., `::foo::bar`.
+///
+/// Throws an error when given `<Foo as Bar>::baz`.
+fn path_root_opt_to_bool(
+    _context: &mut Context,
+    handler: &Handler,
+    root_opt: Option<(Option<AngleBrackets<QualifiedPathRoot>>, DoubleColonToken)>,
+) -> Result<bool, ErrorEmitted> {
+    Ok(match root_opt {
         None => false,
         Some((None, _)) => true,
         Some((Some(qualified_path_root), _)) => {
@@ -2663,6 +3315,16 @@ fn literal_to_literal(
                         };
                         Literal::U64(value)
                     }
+                    LitIntType::U256 => {
+                        let value = match U256::from_big_uint(parsed) {
+                            Some(value) => value,
+                            None => {
+                                let error = ConvertParseTreeError::U256LiteralOutOfRange { span };
+                                return Err(handler.emit_err(error.into()));
+                            }
+                        };
+                        Literal::U256(value)
+                    }
                     LitIntType::I8 | LitIntType::I16 | LitIntType::I32 | LitIntType::I64 => {
                         let error = ConvertParseTreeError::SignedIntegersNotSupported { span };
                         return Err(handler.emit_err(error.into()));
@@ -2917,316 +3579,486 @@ fn statement_let_to_ast_nodes(
     engines: Engines<'_>,
     statement_let: StatementLet,
 ) -> Result<Vec<AstNode>, ErrorEmitted> {
-    fn unfold(
-        context: &mut Context,
-        handler: &Handler,
-        engines: Engines<'_>,
-        pattern: Pattern,
-        ty_opt: Option<Ty>,
-        expression: Expression,
-        span: Span,
-    ) -> Result<Vec<AstNode>, ErrorEmitted> {
-        let ast_nodes = match pattern {
-            Pattern::Wildcard { .. } | Pattern::Var { .. } => {
-                let (reference, mutable, name) = match pattern {
-                    Pattern::Var {
-                        reference,
-                        mutable,
-                        name,
-                    } => (reference, mutable, name),
-                    Pattern::Wildcard { .. } => (None, None, Ident::new_no_span("_".into())),
-                    _ => unreachable!(),
-                };
-                if reference.is_some() {
-                    let error = ConvertParseTreeError::RefVariablesNotSupported { span };
-                    return Err(handler.emit_err(error.into()));
-                }
-                let type_ascription = match ty_opt {
-                    Some(ty) => ty_to_type_argument(context, handler, engines, ty)?,
-                    None => {
-                        let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
-                        TypeArgument {
-                            type_id,
-                            initial_type_id: type_id,
-                            span: name.span(),
-                            call_path_tree: None,
-                        }
-                    }
-                };
-                let ast_node = AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        VariableDeclaration {
-                            name,
-                            type_ascription,
-                            body: expression,
-                            is_mutable: mutable.is_some(),
-                        },
-                    )),
-                    span,
-                };
-                vec![ast_node]
-            }
-            Pattern::Literal(..) => {
-                let error = ConvertParseTreeError::LiteralPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
-            Pattern::Constant(..) => {
-                let error = ConvertParseTreeError::ConstantPatternsNotSupportedHere { span };
-                return Err(handler.emit_err(error.into()));
-            }
-            Pattern::Constructor { .. } | Pattern::Error(..) => {
-                let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
+    let span = statement_let.span();
+    let initial_expression = expr_to_expression(context, handler, engines, statement_let.expr)?;
+    pattern_to_ast_nodes(
+        context,
+        handler,
+        engines,
+        statement_let.pattern,
+        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
+        initial_expression,
+        span,
+    )
+}
+
+/// Lowers the binding of `expression` to an irrefutable `pattern` into a series of variable
+/// declarations, destructuring structs and tuples along the way.
+fn pattern_to_ast_nodes(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    if pattern_may_be_refutable(&pattern) {
+        return refutable_pattern_to_ast_nodes(
+            context, handler, engines, pattern, ty_opt, expression, span,
+        );
+    }
+    let ast_nodes = match pattern {
+        Pattern::Wildcard { .. } | Pattern::Var { .. } => {
+            let (reference, mutable, name) = match pattern {
+                Pattern::Var {
+                    reference,
+                    mutable,
+                    name,
+                } => (reference, mutable, name),
+                Pattern::Wildcard { .. } => (None, None, Ident::new_no_span("_".into())),
+                _ => unreachable!(),
+            };
+            if reference.is_some() {
+                let error = ConvertParseTreeError::RefVariablesNotSupported { span };
                 return Err(handler.emit_err(error.into()));
             }
-            Pattern::Struct { path, fields, .. } => {
-                let mut ast_nodes = Vec::new();
-
-                // Generate a deterministic name for the destructured field
-                let destructured_name = format!(
-                    "{}{}",
-                    DESTRUCTURE_PREFIX,
-                    context.next_destructured_struct_unique_suffix()
-                );
-                let destructure_name =
-                    Ident::new_with_override(destructured_name, path.prefix.name.span());
-
-                // Parse the type ascription and the type ascription span.
-                // In the event that the user did not provide a type ascription,
-                // it is set to TypeInfo::Unknown and the span to None.
-                let type_ascription = match &ty_opt {
-                    Some(ty) => ty_to_type_argument(context, handler, engines, ty.clone())?,
-                    None => {
-                        let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
-                        TypeArgument {
-                            type_id,
-                            initial_type_id: type_id,
-                            span: destructure_name.span(),
-                            call_path_tree: None,
-                        }
+            let type_ascription = match ty_opt {
+                Some(ty) => ty_to_type_argument(context, handler, engines, ty)?,
+                None => {
+                    let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
+                    TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: name.span(),
+                        call_path_tree: None,
                     }
-                };
-
-                // Save the destructure to the new name as a new variable declaration
-                let save_body_first = VariableDeclaration {
-                    name: destructure_name.clone(),
-                    type_ascription,
-                    body: expression,
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
-
-                // create a new variable expression that points to the new destructured struct name that we just created
-                let new_expr = Expression {
-                    kind: ExpressionKind::Variable(destructure_name),
-                    span: span.clone(),
-                };
-
-                // for all of the fields of the struct destructuring on the LHS,
-                // recursively create variable declarations
-                for pattern_struct_field in fields.into_inner().into_iter() {
-                    let (field, recursive_pattern) = match pattern_struct_field {
-                        PatternStructField::Field {
-                            field_name,
-                            pattern_opt,
-                        } => {
-                            let recursive_pattern = match pattern_opt {
-                                Some((_colon_token, box_pattern)) => *box_pattern,
-                                None => Pattern::Var {
-                                    reference: None,
-                                    mutable: None,
-                                    name: field_name.clone(),
-                                },
-                            };
-                            (field_name, recursive_pattern)
-                        }
-                        PatternStructField::Rest { .. } => {
-                            continue;
-                        }
-                    };
+                }
+            };
+            let ast_node = AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    VariableDeclaration {
+                        name,
+                        type_ascription,
+                        body: expression,
+                        is_mutable: mutable.is_some(),
+                    },
+                )),
+                span,
+            };
+            vec![ast_node]
+        }
+        Pattern::Literal(..) | Pattern::Constant(..) | Pattern::Constructor { .. } => {
+            unreachable!("patterns which may be refutable are desugared into a `match`")
+        }
+        Pattern::Error(..) => {
+            let error = ConvertParseTreeError::ConstructorPatternsNotSupportedHere { span };
+            return Err(handler.emit_err(error.into()));
+        }
+        Pattern::Struct { path, fields, .. } => {
+            let mut ast_nodes = Vec::new();
 
-                    // recursively create variable declarations for the subpatterns on the LHS
-                    // and add them to the ast nodes
-                    ast_nodes.extend(unfold(
-                        context,
-                        handler,
-                        engines,
-                        recursive_pattern,
-                        None,
-                        Expression {
-                            kind: ExpressionKind::Subfield(SubfieldExpression {
-                                prefix: Box::new(new_expr.clone()),
-                                field_to_access: field,
-                            }),
-                            span: span.clone(),
-                        },
-                        span.clone(),
-                    )?);
+            // Generate a deterministic name for the destructured field
+            let destructured_name = format!(
+                "{}{}",
+                DESTRUCTURE_PREFIX,
+                context.next_destructured_struct_unique_suffix()
+            );
+            let destructure_name =
+                Ident::new_with_override(destructured_name, path.prefix.name.span());
+
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to TypeInfo::Unknown and the span to None.
+            let type_ascription = match &ty_opt {
+                Some(ty) => ty_to_type_argument(context, handler, engines, ty.clone())?,
+                None => {
+                    let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
+                    TypeArgument {
+                        type_id,
+                        initial_type_id: type_id,
+                        span: destructure_name.span(),
+                        call_path_tree: None,
+                    }
                 }
-                ast_nodes
-            }
-            Pattern::Tuple(pat_tuple) => {
-                let mut ast_nodes = Vec::new();
+            };
 
-                // Generate a deterministic name for the tuple.
-                let tuple_name = format!(
-                    "{}{}",
-                    TUPLE_NAME_PREFIX,
-                    context.next_destructured_tuple_unique_suffix()
-                );
-                let tuple_name = Ident::new_with_override(tuple_name, span.clone());
+            // Save the destructure to the new name as a new variable declaration
+            let save_body_first = VariableDeclaration {
+                name: destructure_name.clone(),
+                type_ascription,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
 
-                // Parse the type ascription and the type ascription span.
-                // In the event that the user did not provide a type ascription,
-                // it is set to TypeInfo::Unknown and the span to None.
-                let type_ascription = match &ty_opt {
-                    Some(ty) => ty_to_type_argument(context, handler, engines, ty.clone())?,
-                    None => {
-                        let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
-                        TypeArgument {
-                            type_id,
-                            initial_type_id: type_id,
-                            span: tuple_name.span(),
-                            call_path_tree: None,
-                        }
+            // create a new variable expression that points to the new destructured struct name that we just created
+            let new_expr = Expression {
+                kind: ExpressionKind::Variable(destructure_name),
+                span: span.clone(),
+            };
+
+            // for all of the fields of the struct destructuring on the LHS,
+            // recursively create variable declarations
+            for pattern_struct_field in fields.into_inner().into_iter() {
+                let (field, recursive_pattern) = match pattern_struct_field {
+                    PatternStructField::Field {
+                        field_name,
+                        pattern_opt,
+                    } => {
+                        let recursive_pattern = match pattern_opt {
+                            Some((_colon_token, box_pattern)) => *box_pattern,
+                            None => Pattern::Var {
+                                reference: None,
+                                mutable: None,
+                                name: field_name.clone(),
+                            },
+                        };
+                        (field_name, recursive_pattern)
+                    }
+                    PatternStructField::Rest { .. } => {
+                        continue;
                     }
                 };
 
-                // Save the tuple to the new name as a new variable declaration.
-                let save_body_first = VariableDeclaration {
-                    name: tuple_name.clone(),
-                    type_ascription,
-                    body: expression,
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        save_body_first,
-                    )),
-                    span: span.clone(),
-                });
+                // recursively create variable declarations for the subpatterns on the LHS
+                // and add them to the ast nodes
+                ast_nodes.extend(pattern_to_ast_nodes(
+                    context,
+                    handler,
+                    engines,
+                    recursive_pattern,
+                    None,
+                    Expression {
+                        kind: ExpressionKind::Subfield(SubfieldExpression {
+                            prefix: Box::new(new_expr.clone()),
+                            field_to_access: field,
+                        }),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+        Pattern::Tuple(pat_tuple) => {
+            let mut ast_nodes = Vec::new();
+
+            // Generate a deterministic name for the tuple.
+            let tuple_name = format!(
+                "{}{}",
+                TUPLE_NAME_PREFIX,
+                context.next_destructured_tuple_unique_suffix()
+            );
+            let tuple_name = Ident::new_with_override(tuple_name, span.clone());
 
-                // Acript a second declaration to a tuple of placeholders to check that the tuple
-                // is properly sized to the pattern
-                let placeholders_type_ascription = {
-                    let type_id = engines.te().insert(
-                        engines.de(),
-                        TypeInfo::Tuple(
-                            pat_tuple
-                                .clone()
-                                .into_inner()
-                                .into_iter()
-                                .map(|_| {
-                                    let initial_type_id =
-                                        engines.te().insert(engines.de(), TypeInfo::Unknown);
-                                    let dummy_type_param = TypeParameter {
-                                        type_id: initial_type_id,
-                                        initial_type_id,
-                                        name_ident: Ident::new_with_override(
-                                            "_".into(),
-                                            span.clone(),
-                                        ),
-                                        trait_constraints: vec![],
-                                        trait_constraints_span: Span::dummy(),
-                                    };
-                                    let initial_type_id = engines.te().insert(
-                                        engines.de(),
-                                        TypeInfo::Placeholder(dummy_type_param),
-                                    );
-                                    TypeArgument {
-                                        type_id: initial_type_id,
-                                        initial_type_id,
-                                        call_path_tree: None,
-                                        span: Span::dummy(),
-                                    }
-                                })
-                                .collect(),
-                        ),
-                    );
+            // Parse the type ascription and the type ascription span.
+            // In the event that the user did not provide a type ascription,
+            // it is set to TypeInfo::Unknown and the span to None.
+            let type_ascription = match &ty_opt {
+                Some(ty) => ty_to_type_argument(context, handler, engines, ty.clone())?,
+                None => {
+                    let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
                     TypeArgument {
                         type_id,
                         initial_type_id: type_id,
                         span: tuple_name.span(),
                         call_path_tree: None,
                     }
-                };
+                }
+            };
 
-                // create a variable expression that points to the new tuple name that we just created
-                let new_expr = Expression {
-                    kind: ExpressionKind::Variable(tuple_name.clone()),
-                    span: span.clone(),
-                };
+            // Save the tuple to the new name as a new variable declaration.
+            let save_body_first = VariableDeclaration {
+                name: tuple_name.clone(),
+                type_ascription,
+                body: expression,
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    save_body_first,
+                )),
+                span: span.clone(),
+            });
 
-                // Override the previous declaration with a tuple of placeholders to check the
-                // shape of the tuple
-                let check_tuple_shape_second = VariableDeclaration {
-                    name: tuple_name,
-                    type_ascription: placeholders_type_ascription,
-                    body: new_expr.clone(),
-                    is_mutable: false,
-                };
-                ast_nodes.push(AstNode {
-                    content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
-                        check_tuple_shape_second,
-                    )),
-                    span: span.clone(),
-                });
+            // Acript a second declaration to a tuple of placeholders to check that the tuple
+            // is properly sized to the pattern
+            let placeholders_type_ascription = {
+                let type_id = engines.te().insert(
+                    engines.de(),
+                    TypeInfo::Tuple(
+                        pat_tuple
+                            .clone()
+                            .into_inner()
+                            .into_iter()
+                            .map(|_| {
+                                let initial_type_id =
+                                    engines.te().insert(engines.de(), TypeInfo::Unknown);
+                                let dummy_type_param = TypeParameter {
+                                    type_id: initial_type_id,
+                                    initial_type_id,
+                                    name_ident: Ident::new_with_override("_".into(), span.clone()),
+                                    trait_constraints: vec![],
+                                    trait_constraints_span: Span::dummy(),
+                                };
+                                let initial_type_id = engines
+                                    .te()
+                                    .insert(engines.de(), TypeInfo::Placeholder(dummy_type_param));
+                                TypeArgument {
+                                    type_id: initial_type_id,
+                                    initial_type_id,
+                                    call_path_tree: None,
+                                    span: Span::dummy(),
+                                }
+                            })
+                            .collect(),
+                    ),
+                );
+                TypeArgument {
+                    type_id,
+                    initial_type_id: type_id,
+                    span: tuple_name.span(),
+                    call_path_tree: None,
+                }
+            };
 
-                // from the possible type annotation, if the annotation was a tuple annotation,
-                // extract the internal types of the annotation
-                let tuple_tys_opt = match ty_opt {
-                    Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
-                    _ => None,
-                };
+            // create a variable expression that points to the new tuple name that we just created
+            let new_expr = Expression {
+                kind: ExpressionKind::Variable(tuple_name.clone()),
+                span: span.clone(),
+            };
 
-                // for all of the elements in the tuple destructuring on the LHS,
-                // recursively create variable declarations
-                for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
-                    // from the possible type annotation, grab the type at the index of the current element
-                    // we are processing
-                    let ty_opt = tuple_tys_opt
-                        .as_ref()
-                        .and_then(|tys| tys.get(index).cloned());
-
-                    // recursively create variable declarations for the subpatterns on the LHS
-                    // and add them to the ast nodes
-                    ast_nodes.extend(unfold(
-                        context,
-                        handler,
-                        engines,
-                        pattern,
-                        ty_opt,
-                        Expression {
-                            kind: ExpressionKind::TupleIndex(TupleIndexExpression {
-                                prefix: Box::new(new_expr.clone()),
-                                index,
-                                index_span: span.clone(),
-                            }),
-                            span: span.clone(),
-                        },
-                        span.clone(),
-                    )?);
+            // Override the previous declaration with a tuple of placeholders to check the
+            // shape of the tuple
+            let check_tuple_shape_second = VariableDeclaration {
+                name: tuple_name,
+                type_ascription: placeholders_type_ascription,
+                body: new_expr.clone(),
+                is_mutable: false,
+            };
+            ast_nodes.push(AstNode {
+                content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+                    check_tuple_shape_second,
+                )),
+                span: span.clone(),
+            });
+
+            // from the possible type annotation, if the annotation was a tuple annotation,
+            // extract the internal types of the annotation
+            let tuple_tys_opt = match ty_opt {
+                Some(Ty::Tuple(tys)) => Some(tys.into_inner().to_tys()),
+                _ => None,
+            };
+
+            // for all of the elements in the tuple destructuring on the LHS,
+            // recursively create variable declarations
+            for (index, pattern) in pat_tuple.into_inner().into_iter().enumerate() {
+                // from the possible type annotation, grab the type at the index of the current element
+                // we are processing
+                let ty_opt = tuple_tys_opt
+                    .as_ref()
+                    .and_then(|tys| tys.get(index).cloned());
+
+                // recursively create variable declarations for the subpatterns on the LHS
+                // and add them to the ast nodes
+                ast_nodes.extend(pattern_to_ast_nodes(
+                    context,
+                    handler,
+                    engines,
+                    pattern,
+                    ty_opt,
+                    Expression {
+                        kind: ExpressionKind::TupleIndex(TupleIndexExpression {
+                            prefix: Box::new(new_expr.clone()),
+                            index,
+                            index_span: span.clone(),
+                        }),
+                        span: span.clone(),
+                    },
+                    span.clone(),
+                )?);
+            }
+            ast_nodes
+        }
+    };
+    Ok(ast_nodes)
+}
+
+/// Returns `true` if the `pattern` contains literal, constant or constructor patterns, which
+/// might not match every value of the pattern's type.
+fn pattern_may_be_refutable(pattern: &Pattern) -> bool {
+    match pattern {
+        Pattern::Wildcard { .. } | Pattern::Var { .. } | Pattern::Error(..) => false,
+        Pattern::Literal(..) | Pattern::Constant(..) | Pattern::Constructor { .. } => true,
+        Pattern::Struct { fields, .. } => fields.get().into_iter().any(|field| match field {
+            PatternStructField::Field {
+                pattern_opt: Some((_colon_token, pattern)),
+                ..
+            } => pattern_may_be_refutable(pattern),
+            _ => false,
+        }),
+        Pattern::Tuple(pat_tuple) => pat_tuple.get().into_iter().any(pattern_may_be_refutable),
+    }
+}
+
+/// Collects the variables bound by the `pattern`, together with their mutability, in the order
+/// in which they appear.
+fn pattern_bindings(pattern: &Pattern, bindings: &mut Vec<(Ident, bool)>) {
+    match pattern {
+        Pattern::Var { mutable, name, .. } => bindings.push((name.clone(), mutable.is_some())),
+        Pattern::Wildcard { .. }
+        | Pattern::Literal(..)
+        | Pattern::Constant(..)
+        | Pattern::Error(..) => {}
+        Pattern::Constructor { args, .. } | Pattern::Tuple(args) => {
+            for pattern in args.get() {
+                pattern_bindings(pattern, bindings);
+            }
+        }
+        Pattern::Struct { fields, .. } => {
+            for field in fields.get() {
+                match field {
+                    PatternStructField::Field {
+                        pattern_opt: Some((_colon_token, pattern)),
+                        ..
+                    } => pattern_bindings(pattern, bindings),
+                    PatternStructField::Field {
+                        field_name,
+                        pattern_opt: None,
+                    } => bindings.push((field_name.clone(), false)),
+                    PatternStructField::Rest { .. } => {}
                 }
-                ast_nodes
             }
-        };
-        Ok(ast_nodes)
+        }
     }
-    let span = statement_let.span();
-    let initial_expression = expr_to_expression(context, handler, engines, statement_let.expr)?;
-    unfold(
-        context,
-        handler,
-        engines,
-        statement_let.pattern,
-        statement_let.ty_opt.map(|(_colon_token, ty)| ty),
-        initial_expression,
-        span,
-    )
+}
+
+/// Desugars the binding of a `pattern` which may be refutable into a `match` expression which
+/// returns the values of the variables bound by the `pattern`. E.g., `let Some(x) = opt;` becomes:
+///
+/// ```ignore
+/// let __pattern_bindings_1 = opt;
+/// let __pattern_bindings_1 = match __pattern_bindings_1 {
+///     Some(x) => (x,),
+/// };
+/// let x = __pattern_bindings_1.0;
+/// ```
+///
+/// The `match` expression is marked as irrefutable, so that if it is not exhaustive the `pattern`
+/// is reported as refutable.
+fn refutable_pattern_to_ast_nodes(
+    context: &mut Context,
+    handler: &Handler,
+    engines: Engines<'_>,
+    pattern: Pattern,
+    ty_opt: Option<Ty>,
+    expression: Expression,
+    span: Span,
+) -> Result<Vec<AstNode>, ErrorEmitted> {
+    let pattern_span = pattern.span();
+    let mut bindings = Vec::new();
+    pattern_bindings(&pattern, &mut bindings);
+
+    // Generate a deterministic name for the matched value and the bound values.
+    let bindings_name = format!(
+        "{}{}",
+        PATTERN_BINDINGS_VAR_NAME_PREFIX,
+        context.next_pattern_bindings_unique_suffix()
+    );
+    let bindings_name = Ident::new_with_override(bindings_name, pattern_span.clone());
+    let bindings_expr = Expression {
+        kind: ExpressionKind::Variable(bindings_name.clone()),
+        span: pattern_span.clone(),
+    };
+    let unknown_type_argument = |engines: Engines<'_>| {
+        let type_id = engines.te().insert(engines.de(), TypeInfo::Unknown);
+        TypeArgument {
+            type_id,
+            initial_type_id: type_id,
+            span: bindings_name.span(),
+            call_path_tree: None,
+        }
+    };
+    let variable_declaration = |name, type_ascription, body, is_mutable| AstNode {
+        content: AstNodeContent::Declaration(Declaration::VariableDeclaration(
+            VariableDeclaration {
+                name,
+                type_ascription,
+                body,
+                is_mutable,
+            },
+        )),
+        span: span.clone(),
+    };
+
+    // Save the matched value to the new name, so that the type ascription applies to it.
+    let type_ascription = match ty_opt {
+        Some(ty) => ty_to_type_argument(context, handler, engines, ty)?,
+        None => unknown_type_argument(engines),
+    };
+    let mut ast_nodes = vec![variable_declaration(
+        bindings_name.clone(),
+        type_ascription,
+        expression,
+        false,
+    )];
+
+    // Match the value against the pattern and return the bound values in a tuple.
+    let match_branch = MatchBranch {
+        scrutinee: pattern_to_scrutinee(context, handler, pattern)?,
+        result: Expression {
+            kind: ExpressionKind::Tuple(
+                bindings
+                    .iter()
+                    .map(|(name, _is_mutable)| Expression {
+                        kind: ExpressionKind::Variable(name.clone()),
+                        span: name.span(),
+                    })
+                    .collect(),
+            ),
+            span: pattern_span.clone(),
+        },
+        span: pattern_span.clone(),
+    };
+    let match_expr = Expression {
+        kind: ExpressionKind::Match(MatchExpression {
+            value: Box::new(bindings_expr.clone()),
+            branches: vec![match_branch],
+            irrefutable: true,
+        }),
+        span: pattern_span,
+    };
+    ast_nodes.push(variable_declaration(
+        bindings_name.clone(),
+        unknown_type_argument(engines),
+        match_expr,
+        false,
+    ));
+
+    // Declare the bound variables from the elements of the tuple.
+    for (index, (name, is_mutable)) in bindings.into_iter().enumerate() {
+        let name_span = name.span();
+        ast_nodes.push(variable_declaration(
+            name,
+            unknown_type_argument(engines),
+            Expression {
+                kind: ExpressionKind::TupleIndex(TupleIndexExpression {
+                    prefix: Box::new(bindings_expr.clone()),
+                    index,
+                    index_span: name_span.clone(),
+                }),
+                span: name_span,
+            },
+            is_mutable,
+        ));
+    }
+    Ok(ast_nodes)
 }
 
 fn submodule_to_include_statement(dependency: &Submodule) -> IncludeStatement {
@@ -3400,7 +4232,11 @@ fn ty_to_type_parameter(
         }
         Ty::Tuple(..) => panic!("tuple types are not allowed in this position"),
         Ty::Array(..) => panic!("array types are not allowed in this position"),
-        Ty::Str { .. } => panic!("str types are not allowed in this position"),
+        Ty::Str { .. } | Ty::StringSlice(..) => {
+            panic!("str types are not allowed in this position")
+        }
+        Ty::Fn { .. } => panic!("fn types are not allowed in this position"),
+        Ty::TraitObject { .. } => panic!("trait object types are not allowed in this position"),
     };
     let custom_type = type_engine.insert(
         decl_engine,
@@ -3649,6 +4485,22 @@ fn path_type_to_type_info(
     } = path_type.clone();
 
     let type_info = match type_name_to_type_info_opt(&name) {
+        // `Self::Item` refers to an associated type of `Self`.
+        Some(TypeInfo::SelfType)
+            if root_opt.is_none() && generics_opt.is_none() && suffix.len() == 1 =>
+        {
+            let (_, assoc_type) = &suffix[0];
+            if let Some((_, generic_args)) = &assoc_type.generics_opt {
+                let error = ConvertParseTreeError::GenericsNotSupportedHere {
+                    span: generic_args.span(),
+                };
+                return Err(handler.emit_err(error.into()));
+            }
+            TypeInfo::Custom {
+                call_path: ty::self_associated_item_symbol(&assoc_type.name).into(),
+                type_arguments: None,
+            }
+        }
         Some(type_info) => {
             if root_opt.is_some() || !suffix.is_empty() {
                 let error = ConvertParseTreeError::FullySpecifiedTypesNotSupported { span };
@@ -3668,23 +4520,50 @@ fn path_type_to_type_info(
                     let error = ConvertParseTreeError::FullySpecifiedTypesNotSupported { span };
                     return Err(handler.emit_err(error.into()));
                 }
-                let generic_ty = match {
-                    generics_opt.and_then(|(_, generic_args)| {
-                        iter_to_array(generic_args.parameters.into_inner())
+                // The first generic argument is the ABI. The type arguments of
+                // a generic ABI are given either in its path, as in
+                // `ContractCaller<MyAbi<T>>`, or as the remaining generic
+                // arguments, as in `ContractCaller<MyAbi, T>`.
+                let mut generic_tys = generics_opt
+                    .map(|(_, generic_args)| {
+                        generic_args
+                            .parameters
+                            .into_inner()
+                            .into_iter()
+                            .collect::<Vec<_>>()
                     })
-                } {
-                    Some([ty]) => ty,
+                    .unwrap_or_default()
+                    .into_iter();
+                let generic_ty = match generic_tys.next() {
+                    Some(ty) => ty,
                     None => {
                         let error = ConvertParseTreeError::ContractCallerOneGenericArg { span };
                         return Err(handler.emit_err(error.into()));
                     }
                 };
-                let abi_name = match generic_ty {
+                let abi_type_arguments = generic_tys
+                    .map(|ty| ty_to_type_argument(context, handler, engines, ty))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let (abi_name, type_arguments) = match generic_ty {
                     Ty::Path(path_type) => {
-                        let call_path = path_type_to_call_path(context, handler, path_type)?;
-                        AbiName::Known(call_path)
+                        let (call_path, type_arguments) =
+                            path_type_to_call_path_and_type_arguments(
+                                context, handler, engines, path_type,
+                            )?;
+                        let type_arguments = if abi_type_arguments.is_empty() {
+                            type_arguments
+                        } else if type_arguments.is_empty() {
+                            abi_type_arguments
+                        } else {
+                            let error =
+                                ConvertParseTreeError::ContractCallerAbiTypeArgsGivenTwice { span };
+                            return Err(handler.emit_err(error.into()));
+                        };
+                        (AbiName::Known(call_path), type_arguments)
+                    }
+                    Ty::Infer { .. } if abi_type_arguments.is_empty() => {
+                        (AbiName::Deferred, vec![])
                     }
-                    Ty::Infer { .. } => AbiName::Deferred,
                     _ => {
                         let error =
                             ConvertParseTreeError::ContractCallerNamedTypeGenericArg { span };
@@ -3693,6 +4572,7 @@ fn path_type_to_type_info(
                 };
                 TypeInfo::ContractCaller {
                     abi_name,
+                    type_arguments,
                     address: None,
                 }
             } else {
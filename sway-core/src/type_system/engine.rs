@@ -22,6 +22,7 @@ pub struct TypeEngine {
     pub(super) slab: ConcurrentSlab<TypeInfo>,
     storage_only_types: ConcurrentSlab<TypeInfo>,
     id_map: RwLock<HashMap<TypeInfo, TypeId>>,
+    alias_names: RwLock<HashMap<TypeId, (Ident, Vec<TypeId>)>>,
 }
 
 fn make_hasher<'a: 'b, 'b, K>(
@@ -64,6 +65,45 @@ impl TypeEngine {
         }
     }
 
+    /// Inserts a copy of the type with the given [TypeId] which is displayed as the type alias
+    /// `name` instantiated with `type_arguments`, and returns the [TypeId] of the copy.
+    ///
+    /// Types which are yet to be inferred are returned as they are, since the copy wouldn't
+    /// follow their unification.
+    pub(crate) fn insert_alias(
+        &self,
+        name: Ident,
+        type_arguments: Vec<TypeId>,
+        type_id: TypeId,
+    ) -> TypeId {
+        let ty = self.get(type_id);
+        if matches!(
+            ty,
+            TypeInfo::Unknown
+                | TypeInfo::UnknownGeneric { .. }
+                | TypeInfo::Placeholder(_)
+                | TypeInfo::TypeParam(_)
+                | TypeInfo::Numeric
+                | TypeInfo::Custom { .. }
+                | TypeInfo::SelfType
+                | TypeInfo::ErrorRecovery
+        ) {
+            return type_id;
+        }
+        let alias_id = TypeId::new(self.slab.insert(ty));
+        self.alias_names
+            .write()
+            .unwrap()
+            .insert(alias_id, (name, type_arguments));
+        alias_id
+    }
+
+    /// Returns the name and the type arguments of the type alias that the given [TypeId] was
+    /// resolved from, if any.
+    pub(crate) fn get_alias_name(&self, id: TypeId) -> Option<(Ident, Vec<TypeId>)> {
+        self.alias_names.read().unwrap().get(&id).cloned()
+    }
+
     /// Performs a lookup of `id` into the [TypeEngine].
     pub fn get(&self, id: TypeId) -> TypeInfo {
         self.slab.get(id.index())
@@ -436,8 +476,19 @@ impl TypeEngine {
                             errors,
                         );
 
-                        // insert the new copy in the decl engine
-                        let new_decl_ref = decl_engine.insert(new_copy);
+                        // insert the new copy in the decl engine. A declaration
+                        // which isn't generic is referred to directly instead, so
+                        // that recursive types refer to the declaration which
+                        // replaces their forward declaration
+                        let new_decl_ref = if new_copy.type_parameters.is_empty() {
+                            DeclRef::new(
+                                new_copy.call_path.suffix.clone(),
+                                original_id,
+                                new_copy.span,
+                            )
+                        } else {
+                            decl_engine.insert(new_copy)
+                        };
 
                         // create the type id from the copy
                         let type_id = engines
@@ -473,8 +524,19 @@ impl TypeEngine {
                             errors
                         );
 
-                        // insert the new copy in the decl engine
-                        let new_decl_ref = decl_engine.insert(new_copy);
+                        // insert the new copy in the decl engine. A declaration
+                        // which isn't generic is referred to directly instead, so
+                        // that recursive types refer to the declaration which
+                        // replaces their forward declaration
+                        let new_decl_ref = if new_copy.type_parameters.is_empty() {
+                            DeclRef::new(
+                                new_copy.call_path.suffix.clone(),
+                                original_id,
+                                new_copy.span,
+                            )
+                        } else {
+                            decl_engine.insert(new_copy)
+                        };
 
                         // create the type id from the copy
                         let type_id = engines
@@ -487,6 +549,49 @@ impl TypeEngine {
                         // return the id
                         type_id
                     }
+                    Some(ty::TyDeclaration::TypeAliasDeclaration {
+                        decl_id: original_id,
+                        ..
+                    }) => {
+                        // get the copy from the declaration engine
+                        let mut new_copy = decl_engine.get_type_alias(&original_id);
+
+                        // monomorphize the copy, in place, which substitutes the
+                        // type arguments into the aliased type
+                        let mut type_arguments = type_arguments.unwrap_or_default();
+                        check!(
+                            self.monomorphize(
+                                decl_engine,
+                                &mut new_copy,
+                                &mut type_arguments,
+                                enforce_type_arguments,
+                                span,
+                                namespace,
+                                mod_path
+                            ),
+                            return err(warnings, errors),
+                            warnings,
+                            errors
+                        );
+
+                        // aliases are transparent, so a copy of the aliased type is used
+                        // in place of the alias, which keeps its name for diagnostics
+                        let type_id = self.insert_alias(
+                            call_path.suffix.clone(),
+                            new_copy
+                                .type_parameters
+                                .iter()
+                                .map(|type_param| type_param.type_id)
+                                .collect(),
+                            new_copy.ty.type_id,
+                        );
+
+                        // take any trait methods that apply to this type and copy them to the new type
+                        namespace.insert_trait_implementation_for_type(engines, type_id);
+
+                        // return the id
+                        type_id
+                    }
                     Some(ty::TyDeclaration::GenericTypeForFunctionScope { type_id, .. }) => type_id,
                     _ => {
                         errors.push(CompileError::UnknownTypeName {
@@ -497,7 +602,7 @@ impl TypeEngine {
                     }
                 }
             }
-            TypeInfo::Array(mut elem_ty, n) => {
+            TypeInfo::Array(mut elem_ty, mut n) => {
                 elem_ty.type_id = check!(
                     self.resolve(
                         decl_engine,
@@ -512,6 +617,32 @@ impl TypeEngine {
                     warnings,
                     errors
                 );
+                if let Some(length_id) = n.type_id() {
+                    // a length that is not a literal must name a const
+                    // generic parameter that is in scope
+                    let length_id = check!(
+                        self.resolve(
+                            decl_engine,
+                            length_id,
+                            span,
+                            enforce_type_arguments,
+                            None,
+                            namespace,
+                            mod_path
+                        ),
+                        self.insert(decl_engine, TypeInfo::ErrorRecovery),
+                        warnings,
+                        errors
+                    );
+                    match self.get(length_id) {
+                        TypeInfo::ConstGeneric { .. } => n = Length::generic(length_id, n.span()),
+                        TypeInfo::ErrorRecovery => {}
+                        _ => errors.push(CompileError::NotAConstGeneric {
+                            name: n.span().as_str().to_string(),
+                            span: n.span(),
+                        }),
+                    }
+                }
                 self.insert(decl_engine, TypeInfo::Array(elem_ty, n))
             }
             TypeInfo::Tuple(mut type_arguments) => {
@@ -533,6 +664,76 @@ impl TypeEngine {
                 }
                 self.insert(decl_engine, TypeInfo::Tuple(type_arguments))
             }
+            TypeInfo::ContractCaller {
+                abi_name,
+                mut type_arguments,
+                address,
+            } if !type_arguments.is_empty() => {
+                for type_argument in type_arguments.iter_mut() {
+                    type_argument.type_id = check!(
+                        self.resolve(
+                            decl_engine,
+                            type_argument.type_id,
+                            span,
+                            enforce_type_arguments,
+                            None,
+                            namespace,
+                            mod_path
+                        ),
+                        self.insert(decl_engine, TypeInfo::ErrorRecovery),
+                        warnings,
+                        errors
+                    );
+                }
+                self.insert(
+                    decl_engine,
+                    TypeInfo::ContractCaller {
+                        abi_name,
+                        type_arguments,
+                        address,
+                    },
+                )
+            }
+            TypeInfo::TraitObject {
+                trait_name,
+                decl_id: None,
+            } => {
+                match namespace
+                    .root()
+                    .resolve_call_path_with_visibility_check(engines, module_path, &trait_name)
+                    .ok(&mut warnings, &mut errors)
+                    .cloned()
+                {
+                    Some(ty::TyDeclaration::TraitDeclaration { decl_id, .. }) => {
+                        let trait_decl = decl_engine.get_trait(&decl_id);
+                        match trait_decl.object_safety_violation(engines) {
+                            Some(reason) => {
+                                errors.push(CompileError::TraitNotObjectSafe {
+                                    trait_name: trait_name.suffix.to_string(),
+                                    reason,
+                                    span: trait_name.span(),
+                                });
+                                self.insert(decl_engine, TypeInfo::ErrorRecovery)
+                            }
+                            None => self.insert(
+                                decl_engine,
+                                TypeInfo::TraitObject {
+                                    trait_name,
+                                    decl_id: Some(decl_id),
+                                },
+                            ),
+                        }
+                    }
+                    Some(decl) => {
+                        errors.push(CompileError::DeclIsNotATrait {
+                            actually: decl.friendly_type_name().to_string(),
+                            span: trait_name.span(),
+                        });
+                        self.insert(decl_engine, TypeInfo::ErrorRecovery)
+                    }
+                    None => self.insert(decl_engine, TypeInfo::ErrorRecovery),
+                }
+            }
             _ => type_id,
         };
         ok(type_id, warnings, errors)
@@ -12,7 +12,14 @@ pub struct TypeId(usize);
 
 impl DisplayWithEngines for TypeId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>, engines: Engines<'_>) -> fmt::Result {
-        write!(f, "{}", engines.help_out(engines.te().get(*self)))
+        match engines.te().get_alias_name(*self) {
+            Some((name, type_arguments)) => write!(
+                f,
+                "{}",
+                print_inner_types(engines, name.to_string(), type_arguments.into_iter())
+            ),
+            None => write!(f, "{}", engines.help_out(engines.te().get(*self))),
+        }
     }
 }
 
@@ -51,6 +58,9 @@ impl CollectTypesMetadata for TypeId {
                     ctx.call_site_get(self),
                 ));
             }
+            TypeInfo::ConstGeneric { name, value: None } => {
+                res.push(TypeMetadata::UnresolvedType(name, ctx.call_site_get(self)));
+            }
             _ => {}
         }
         if let TypeInfo::UnknownGeneric {
@@ -78,72 +88,92 @@ impl CollectTypesMetadata for TypeId {
 
 impl ReplaceSelfType for TypeId {
     fn replace_self_type(&mut self, engines: Engines<'_>, self_type: TypeId) {
-        fn helper(type_id: TypeId, engines: Engines<'_>, self_type: TypeId) -> Option<TypeId> {
+        fn helper(
+            type_id: TypeId,
+            engines: Engines<'_>,
+            self_type: TypeId,
+            in_progress: &mut CopiesInProgress,
+        ) -> Option<TypeId> {
             let type_engine = engines.te();
             let decl_engine = engines.de();
             match type_engine.get(type_id) {
                 TypeInfo::TypeParam(_) => None,
                 TypeInfo::SelfType => Some(self_type),
-                TypeInfo::Enum(decl_ref) => {
-                    let mut decl = decl_engine.get_enum(&decl_ref);
+                type_info @ TypeInfo::Enum(_) => {
+                    if let Some(copy) = in_progress.get(engines, &type_info) {
+                        return Some(copy);
+                    }
+                    let TypeInfo::Enum(decl_ref) = &type_info else {
+                        unreachable!()
+                    };
+                    let mut decl = decl_engine.get_enum(decl_ref);
+                    // `Self` only occurs in the variants through the type parameters.
                     let mut need_to_create_new = false;
-
-                    for variant in decl.variants.iter_mut() {
+                    for type_param in decl.type_parameters.iter_mut() {
                         if let Some(type_id) =
-                            helper(variant.type_argument.type_id, engines, self_type)
+                            helper(type_param.type_id, engines, self_type, in_progress)
                         {
                             need_to_create_new = true;
+                            type_param.type_id = type_id;
+                        }
+                    }
+                    if !need_to_create_new {
+                        return None;
+                    }
+                    in_progress.start(type_info);
+                    for variant in decl.variants.iter_mut() {
+                        if let Some(type_id) = helper(
+                            variant.type_argument.type_id,
+                            engines,
+                            self_type,
+                            in_progress,
+                        ) {
                             variant.type_argument.type_id = type_id;
                         }
                     }
-
+                    let new_decl_ref = decl_engine.insert(decl);
+                    Some(in_progress.finish(engines, TypeInfo::Enum(new_decl_ref)))
+                }
+                type_info @ TypeInfo::Struct(_) => {
+                    if let Some(copy) = in_progress.get(engines, &type_info) {
+                        return Some(copy);
+                    }
+                    let TypeInfo::Struct(decl_ref) = &type_info else {
+                        unreachable!()
+                    };
+                    let mut decl = decl_engine.get_struct(decl_ref);
+                    // `Self` only occurs in the fields through the type parameters.
+                    let mut need_to_create_new = false;
                     for type_param in decl.type_parameters.iter_mut() {
-                        if let Some(type_id) = helper(type_param.type_id, engines, self_type) {
+                        if let Some(type_id) =
+                            helper(type_param.type_id, engines, self_type, in_progress)
+                        {
                             need_to_create_new = true;
                             type_param.type_id = type_id;
                         }
                     }
-
-                    if need_to_create_new {
-                        let new_decl_ref = decl_engine.insert(decl);
-                        Some(type_engine.insert(decl_engine, TypeInfo::Enum(new_decl_ref)))
-                    } else {
-                        None
+                    if !need_to_create_new {
+                        return None;
                     }
-                }
-                TypeInfo::Struct(decl_ref) => {
-                    let mut decl = decl_engine.get_struct(&decl_ref);
-                    let mut need_to_create_new = false;
-
+                    in_progress.start(type_info);
                     for field in decl.fields.iter_mut() {
                         if let Some(type_id) =
-                            helper(field.type_argument.type_id, engines, self_type)
+                            helper(field.type_argument.type_id, engines, self_type, in_progress)
                         {
-                            need_to_create_new = true;
                             field.type_argument.type_id = type_id;
                         }
                     }
-
-                    for type_param in decl.type_parameters.iter_mut() {
-                        if let Some(type_id) = helper(type_param.type_id, engines, self_type) {
-                            need_to_create_new = true;
-                            type_param.type_id = type_id;
-                        }
-                    }
-
-                    if need_to_create_new {
-                        let new_decl_ref = decl_engine.insert(decl);
-                        Some(type_engine.insert(decl_engine, TypeInfo::Struct(new_decl_ref)))
-                    } else {
-                        None
-                    }
+                    let new_decl_ref = decl_engine.insert(decl);
+                    Some(in_progress.finish(engines, TypeInfo::Struct(new_decl_ref)))
                 }
                 TypeInfo::Tuple(fields) => {
                     let mut need_to_create_new = false;
                     let fields = fields
                         .into_iter()
                         .map(|mut field| {
-                            if let Some(type_id) = helper(field.type_id, engines, self_type) {
+                            if let Some(type_id) =
+                                helper(field.type_id, engines, self_type, in_progress)
+                            {
                                 need_to_create_new = true;
                                 field.type_id = type_id;
                             }
@@ -165,7 +195,8 @@ impl ReplaceSelfType for TypeId {
                         type_arguments
                             .into_iter()
                             .map(|mut type_arg| {
-                                if let Some(type_id) = helper(type_arg.type_id, engines, self_type)
+                                if let Some(type_id) =
+                                    helper(type_arg.type_id, engines, self_type, in_progress)
                                 {
                                     need_to_create_new = true;
                                     type_arg.type_id = type_id;
@@ -186,18 +217,19 @@ impl ReplaceSelfType for TypeId {
                         None
                     }
                 }
-                TypeInfo::Array(mut elem_ty, count) => helper(elem_ty.type_id, engines, self_type)
-                    .map(|type_id| {
+                TypeInfo::Array(mut elem_ty, count) => {
+                    helper(elem_ty.type_id, engines, self_type, in_progress).map(|type_id| {
                         elem_ty.type_id = type_id;
                         type_engine.insert(decl_engine, TypeInfo::Array(elem_ty, count))
-                    }),
+                    })
+                }
                 TypeInfo::Storage { fields } => {
                     let mut need_to_create_new = false;
                     let fields = fields
                         .into_iter()
                         .map(|mut field| {
                             if let Some(type_id) =
-                                helper(field.type_argument.type_id, engines, self_type)
+                                helper(field.type_argument.type_id, engines, self_type, in_progress)
                             {
                                 need_to_create_new = true;
                                 field.type_argument.type_id = type_id;
@@ -211,8 +243,31 @@ impl ReplaceSelfType for TypeId {
                         None
                     }
                 }
+                TypeInfo::Closure { fn_ref, captures } => {
+                    let mut decl = decl_engine.get_function(&fn_ref);
+                    let need_to_create_new = decl
+                        .parameters
+                        .iter()
+                        .map(|param| param.type_argument.type_id)
+                        .chain(std::iter::once(decl.return_type.type_id))
+                        .any(|type_id| helper(type_id, engines, self_type, in_progress).is_some());
+                    if need_to_create_new {
+                        decl.replace_self_type(engines, self_type);
+                        let new_decl_ref = decl_engine.insert(decl);
+                        Some(type_engine.insert(
+                            decl_engine,
+                            TypeInfo::Closure {
+                                fn_ref: new_decl_ref,
+                                captures,
+                            },
+                        ))
+                    } else {
+                        None
+                    }
+                }
                 TypeInfo::Unknown
                 | TypeInfo::UnknownGeneric { .. }
+                | TypeInfo::ConstGeneric { .. }
                 | TypeInfo::Str(_)
                 | TypeInfo::UnsignedInteger(_)
                 | TypeInfo::Boolean
@@ -221,13 +276,15 @@ impl ReplaceSelfType for TypeId {
                 | TypeInfo::Numeric
                 | TypeInfo::RawUntypedPtr
                 | TypeInfo::RawUntypedSlice
+                | TypeInfo::StringSlice
                 | TypeInfo::Contract
                 | TypeInfo::ErrorRecovery
-                | TypeInfo::Placeholder(_) => None,
+                | TypeInfo::Placeholder(_)
+                | TypeInfo::TraitObject { .. } => None,
             }
         }
 
-        if let Some(type_id) = helper(*self, engines, self_type) {
+        if let Some(type_id) = helper(*self, engines, self_type, &mut CopiesInProgress::default()) {
             *self = type_id;
         }
     }
@@ -1,6 +1,6 @@
 use super::*;
 use crate::{
-    decl_engine::{DeclEngine, DeclRefEnum, DeclRefStruct},
+    decl_engine::{DeclEngine, DeclId, DeclRefEnum, DeclRefFunction, DeclRefStruct},
     engine_threading::*,
     language::{ty, CallPath},
     Ident,
@@ -104,9 +104,11 @@ pub enum TypeInfo {
     Boolean,
     Tuple(Vec<TypeArgument>),
     /// Represents a type which contains methods to issue a contract call.
-    /// The specific contract is identified via the `Ident` within.
+    /// The specific contract is identified via the `Ident` within, and
+    /// `type_arguments` are the type arguments of a generic ABI.
     ContractCaller {
         abi_name: AbiName,
+        type_arguments: Vec<TypeArgument>,
         // boxed for size
         address: Option<Box<ty::TyExpression>>,
     },
@@ -142,6 +144,33 @@ pub enum TypeInfo {
     /// gtf instruction, or manipulating u64s.
     RawUntypedPtr,
     RawUntypedSlice,
+    /// A string slice of dynamic length, `str`. It is represented in memory like a
+    /// `raw_slice`, as a pointer to the UTF-8 bytes of the string and their count.
+    StringSlice,
+    /// The type of a closure expression. Every closure has its own type,
+    /// which refers to the function that the closure's body was lifted into.
+    /// The first `captures` parameters of that function receive the values
+    /// the closure captured, and the rest are the closure's own parameters.
+    Closure {
+        fn_ref: DeclRefFunction,
+        captures: usize,
+    },
+    /// Represents a const generic parameter, like the `N` in
+    /// `fn f<const N: u64>(a: [u64; N])`. The `value` is `None` while the
+    /// parameter is generic, and becomes known through monomorphization.
+    ConstGeneric {
+        name: Ident,
+        value: Option<usize>,
+    },
+    /// A trait object, i.e. `dyn Trait`. A value of this type pairs a value
+    /// of any type implementing the trait named `trait_name` with a vtable
+    /// holding the addresses of that type's implementations of the trait
+    /// methods. `decl_id` refers to the trait declaration once the type has
+    /// been resolved.
+    TraitObject {
+        trait_name: CallPath,
+        decl_id: Option<DeclId<ty::TyTraitDeclaration>>,
+    },
 }
 
 impl HashWithEngines for TypeInfo {
@@ -149,7 +178,7 @@ impl HashWithEngines for TypeInfo {
         self.discriminant_value().hash(state);
         match self {
             TypeInfo::Str(len) => {
-                len.hash(state);
+                len.hash(state, engines);
             }
             TypeInfo::UnsignedInteger(bits) => {
                 bits.hash(state);
@@ -163,8 +192,13 @@ impl HashWithEngines for TypeInfo {
             TypeInfo::Struct(decl_ref) => {
                 decl_ref.hash(state, engines);
             }
-            TypeInfo::ContractCaller { abi_name, address } => {
+            TypeInfo::ContractCaller {
+                abi_name,
+                type_arguments,
+                address,
+            } => {
                 abi_name.hash(state);
+                type_arguments.hash(state, engines);
                 let address = address
                     .as_ref()
                     .map(|x| x.span.as_str().to_string())
@@ -190,7 +224,7 @@ impl HashWithEngines for TypeInfo {
             }
             TypeInfo::Array(elem_ty, count) => {
                 elem_ty.hash(state, engines);
-                count.hash(state);
+                count.hash(state, engines);
             }
             TypeInfo::Placeholder(ty) => {
                 ty.hash(state, engines);
@@ -198,6 +232,17 @@ impl HashWithEngines for TypeInfo {
             TypeInfo::TypeParam(n) => {
                 n.hash(state);
             }
+            TypeInfo::Closure { fn_ref, captures } => {
+                fn_ref.hash(state, engines);
+                captures.hash(state);
+            }
+            TypeInfo::ConstGeneric { name, value } => {
+                name.hash(state);
+                value.hash(state);
+            }
+            TypeInfo::TraitObject { trait_name, .. } => {
+                trait_name.suffix.hash(state);
+            }
             TypeInfo::Numeric
             | TypeInfo::Boolean
             | TypeInfo::B256
@@ -206,7 +251,8 @@ impl HashWithEngines for TypeInfo {
             | TypeInfo::Unknown
             | TypeInfo::SelfType
             | TypeInfo::RawUntypedPtr
-            | TypeInfo::RawUntypedSlice => {}
+            | TypeInfo::RawUntypedSlice
+            | TypeInfo::StringSlice => {}
         }
     }
 }
@@ -241,23 +287,19 @@ impl PartialEqWithEngines for TypeInfo {
                 l_name.suffix == r_name.suffix
                     && l_type_args.as_deref().eq(&r_type_args.as_deref(), engines)
             }
-            (Self::Str(l), Self::Str(r)) => l.val() == r.val(),
+            (Self::Str(l), Self::Str(r)) => l.eq(r, engines),
             (Self::UnsignedInteger(l), Self::UnsignedInteger(r)) => l == r,
             (Self::Enum(l_decl_ref), Self::Enum(r_decl_ref)) => {
                 let l_decl = engines.de().get_enum(l_decl_ref);
                 let r_decl = engines.de().get_enum(r_decl_ref);
-                l_decl.call_path.suffix == r_decl.call_path.suffix
-                    && l_decl.call_path.suffix.span() == r_decl.call_path.suffix.span()
-                    && l_decl.variants.eq(&r_decl.variants, engines)
-                    && l_decl.type_parameters.eq(&r_decl.type_parameters, engines)
+                l_decl.call_path.suffix.span() == r_decl.call_path.suffix.span()
+                    && l_decl.eq(&r_decl, engines)
             }
             (Self::Struct(l_decl_ref), Self::Struct(r_decl_ref)) => {
                 let l_decl = engines.de().get_struct(l_decl_ref);
                 let r_decl = engines.de().get_struct(r_decl_ref);
-                l_decl.call_path.suffix == r_decl.call_path.suffix
-                    && l_decl.call_path.suffix.span() == r_decl.call_path.suffix.span()
-                    && l_decl.fields.eq(&r_decl.fields, engines)
-                    && l_decl.type_parameters.eq(&r_decl.type_parameters, engines)
+                l_decl.call_path.suffix.span() == r_decl.call_path.suffix.span()
+                    && l_decl.eq(&r_decl, engines)
             }
             (Self::Tuple(l), Self::Tuple(r)) => l
                 .iter()
@@ -271,24 +313,66 @@ impl PartialEqWithEngines for TypeInfo {
             (
                 Self::ContractCaller {
                     abi_name: l_abi_name,
+                    type_arguments: l_type_arguments,
                     address: l_address,
                 },
                 Self::ContractCaller {
                     abi_name: r_abi_name,
+                    type_arguments: r_type_arguments,
                     address: r_address,
                 },
             ) => {
-                l_abi_name == r_abi_name && l_address.as_deref().eq(&r_address.as_deref(), engines)
+                l_abi_name == r_abi_name
+                    && l_type_arguments.eq(r_type_arguments, engines)
+                    && l_address.as_deref().eq(&r_address.as_deref(), engines)
             }
             (Self::Array(l0, l1), Self::Array(r0, r1)) => {
                 type_engine
                     .get(l0.type_id)
                     .eq(&type_engine.get(r0.type_id), engines)
-                    && l1.val() == r1.val()
+                    && l1.eq(r1, engines)
             }
             (TypeInfo::Storage { fields: l_fields }, TypeInfo::Storage { fields: r_fields }) => {
                 l_fields.eq(r_fields, engines)
             }
+            (
+                Self::Closure {
+                    fn_ref: l_fn_ref,
+                    captures: l_captures,
+                },
+                Self::Closure {
+                    fn_ref: r_fn_ref,
+                    captures: r_captures,
+                },
+            ) => l_captures == r_captures && l_fn_ref.eq(r_fn_ref, engines),
+            (
+                Self::ConstGeneric {
+                    name: l_name,
+                    value: l_value,
+                },
+                Self::ConstGeneric {
+                    name: r_name,
+                    value: r_value,
+                },
+            ) => match (l_value, r_value) {
+                (Some(l), Some(r)) => l == r,
+                (None, None) => l_name == r_name && l_name.span() == r_name.span(),
+                _ => false,
+            },
+            (
+                Self::TraitObject {
+                    trait_name: l_trait_name,
+                    decl_id: l_decl_id,
+                },
+                Self::TraitObject {
+                    trait_name: r_trait_name,
+                    decl_id: r_decl_id,
+                },
+            ) => match (l_decl_id, r_decl_id) {
+                (Some(l_decl_id), Some(r_decl_id)) => l_decl_id == r_decl_id,
+                (None, None) => l_trait_name == r_trait_name,
+                _ => false,
+            },
             (l, r) => l.discriminant_value() == r.discriminant_value(),
         }
     }
@@ -323,7 +407,7 @@ impl OrdWithEngines for TypeInfo {
                 .suffix
                 .cmp(&r_call_path.suffix)
                 .then_with(|| l_type_args.as_deref().cmp(&r_type_args.as_deref(), engines)),
-            (Self::Str(l), Self::Str(r)) => l.val().cmp(&r.val()),
+            (Self::Str(l), Self::Str(r)) => l.cmp(r, engines),
             (Self::UnsignedInteger(l), Self::UnsignedInteger(r)) => l.cmp(r),
             (Self::Enum(l_decl_ref), Self::Enum(r_decl_ref)) => {
                 let l_decl = decl_engine.get_enum(l_decl_ref);
@@ -333,7 +417,9 @@ impl OrdWithEngines for TypeInfo {
                     .suffix
                     .cmp(&r_decl.call_path.suffix)
                     .then_with(|| l_decl.type_parameters.cmp(&r_decl.type_parameters, engines))
-                    .then_with(|| l_decl.variants.cmp(&r_decl.variants, engines))
+                    // Types may be recursive, so their variants aren't compared, see
+                    // `PartialEqWithEngines for TyEnumDeclaration`.
+                    .then_with(|| l_decl.span.cmp(&r_decl.span))
             }
             (Self::Struct(l_decl_ref), Self::Struct(r_decl_ref)) => {
                 let l_decl = decl_engine.get_struct(l_decl_ref);
@@ -343,29 +429,97 @@ impl OrdWithEngines for TypeInfo {
                     .suffix
                     .cmp(&r_decl.call_path.suffix)
                     .then_with(|| l_decl.type_parameters.cmp(&r_decl.type_parameters, engines))
-                    .then_with(|| l_decl.fields.cmp(&r_decl.fields, engines))
+                    // See the comment on enums above.
+                    .then_with(|| l_decl.span.cmp(&r_decl.span))
             }
             (Self::Tuple(l), Self::Tuple(r)) => l.cmp(r, engines),
             (
                 Self::ContractCaller {
                     abi_name: l_abi_name,
+                    type_arguments: l_type_arguments,
                     address: _,
                 },
                 Self::ContractCaller {
                     abi_name: r_abi_name,
+                    type_arguments: r_type_arguments,
                     address: _,
                 },
             ) => {
                 // NOTE: we assume all contract callers are unique
-                l_abi_name.cmp(r_abi_name)
+                l_abi_name
+                    .cmp(r_abi_name)
+                    .then_with(|| l_type_arguments.cmp(r_type_arguments, engines))
             }
             (Self::Array(l0, l1), Self::Array(r0, r1)) => type_engine
                 .get(l0.type_id)
                 .cmp(&type_engine.get(r0.type_id), engines)
-                .then_with(|| l1.val().cmp(&r1.val())),
+                .then_with(|| l1.cmp(r1, engines)),
             (TypeInfo::Storage { fields: l_fields }, TypeInfo::Storage { fields: r_fields }) => {
                 l_fields.cmp(r_fields, engines)
             }
+            (
+                Self::Closure {
+                    fn_ref: l_fn_ref,
+                    captures: l_captures,
+                },
+                Self::Closure {
+                    fn_ref: r_fn_ref,
+                    captures: r_captures,
+                },
+            ) => {
+                let l_decl = decl_engine.get_function(l_fn_ref);
+                let r_decl = decl_engine.get_function(r_fn_ref);
+                l_decl
+                    .name
+                    .cmp(&r_decl.name)
+                    .then_with(|| l_captures.cmp(r_captures))
+                    .then_with(|| {
+                        let l_types = l_decl
+                            .parameters
+                            .iter()
+                            .map(|param| &param.type_argument)
+                            .chain(std::iter::once(&l_decl.return_type))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        let r_types = r_decl
+                            .parameters
+                            .iter()
+                            .map(|param| &param.type_argument)
+                            .chain(std::iter::once(&r_decl.return_type))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        l_types.cmp(&r_types, engines)
+                    })
+            }
+            (
+                Self::ConstGeneric {
+                    name: l_name,
+                    value: l_value,
+                },
+                Self::ConstGeneric {
+                    name: r_name,
+                    value: r_value,
+                },
+            ) => match (l_value, r_value) {
+                (Some(l), Some(r)) => l.cmp(r),
+                (None, None) => l_name
+                    .cmp(r_name)
+                    .then_with(|| l_name.span().cmp(&r_name.span())),
+                (l, r) => l.cmp(r),
+            },
+            (
+                Self::TraitObject {
+                    trait_name: l_trait_name,
+                    decl_id: l_decl_id,
+                },
+                Self::TraitObject {
+                    trait_name: r_trait_name,
+                    decl_id: r_decl_id,
+                },
+            ) => match (l_decl_id, r_decl_id) {
+                (None, None) => l_trait_name.cmp(r_trait_name),
+                (l, r) => l.map(|id| id.inner()).cmp(&r.map(|id| id.inner())),
+            },
             (l, r) => l.discriminant_value().cmp(&r.discriminant_value()),
         }
     }
@@ -376,15 +530,27 @@ impl DisplayWithEngines for TypeInfo {
         use TypeInfo::*;
         let s = match self {
             Unknown => "unknown".into(),
-            UnknownGeneric { name, .. } => name.to_string(),
+            UnknownGeneric {
+                name,
+                trait_constraints,
+            } => match trait_constraints
+                .iter()
+                .find_map(|constraint| constraint.fn_signature())
+            {
+                Some((parameters, return_type)) => {
+                    print_fn_signature(engines, parameters, return_type)
+                }
+                None => name.to_string(),
+            },
             Placeholder(_) => "_".to_string(),
             TypeParam(n) => format!("typeparam({n})"),
-            Str(x) => format!("str[{}]", x.val()),
+            Str(x) => format!("str[{}]", engines.help_out(x)),
             UnsignedInteger(x) => match x {
                 IntegerBits::Eight => "u8",
                 IntegerBits::Sixteen => "u16",
                 IntegerBits::ThirtyTwo => "u32",
                 IntegerBits::SixtyFour => "u64",
+                IntegerBits::V256 => "u256",
             }
             .into(),
             Boolean => "bool".into(),
@@ -419,10 +585,18 @@ impl DisplayWithEngines for TypeInfo {
                     decl.type_parameters.iter().map(|x| x.type_id),
                 )
             }
-            ContractCaller { abi_name, address } => {
+            ContractCaller {
+                abi_name,
+                type_arguments,
+                address,
+            } => {
                 format!(
                     "contract caller {} ( {} )",
-                    abi_name,
+                    print_inner_types(
+                        engines,
+                        abi_name.to_string(),
+                        type_arguments.iter().map(|x| x.type_id)
+                    ),
                     address
                         .as_ref()
                         .map(|address| address.span.as_str().to_string())
@@ -430,11 +604,29 @@ impl DisplayWithEngines for TypeInfo {
                 )
             }
             Array(elem_ty, count) => {
-                format!("[{}; {}]", engines.help_out(elem_ty), count.val())
+                format!(
+                    "[{}; {}]",
+                    engines.help_out(elem_ty),
+                    engines.help_out(count)
+                )
             }
             Storage { .. } => "contract storage".into(),
             RawUntypedPtr => "raw untyped ptr".into(),
             RawUntypedSlice => "raw untyped slice".into(),
+            StringSlice => "str".into(),
+            Closure { fn_ref, captures } => {
+                let decl = engines.de().get_function(fn_ref);
+                let parameters = decl.parameters[*captures..]
+                    .iter()
+                    .map(|param| param.type_argument.clone())
+                    .collect::<Vec<_>>();
+                print_fn_signature(engines, &parameters, &decl.return_type)
+            }
+            ConstGeneric { name, value } => match value {
+                Some(value) => value.to_string(),
+                None => name.to_string(),
+            },
+            TraitObject { trait_name, .. } => format!("dyn {}", trait_name.suffix),
         };
         write!(f, "{s}")
     }
@@ -444,6 +636,7 @@ impl TypeInfo {
     pub fn display_name(&self) -> String {
         match self {
             TypeInfo::UnknownGeneric { name, .. } => name.to_string(),
+            TypeInfo::ConstGeneric { name, .. } => name.to_string(),
             TypeInfo::Placeholder(type_param) => type_param.name_ident.to_string(),
             TypeInfo::Enum(decl_ref) => decl_ref.name().clone().to_string(),
             TypeInfo::Struct(decl_ref) => decl_ref.name().clone().to_string(),
@@ -483,6 +676,10 @@ impl TypeInfo {
             TypeInfo::RawUntypedPtr => 18,
             TypeInfo::RawUntypedSlice => 19,
             TypeInfo::TypeParam(_) => 20,
+            TypeInfo::Closure { .. } => 21,
+            TypeInfo::ConstGeneric { .. } => 22,
+            TypeInfo::TraitObject { .. } => 23,
+            TypeInfo::StringSlice => 24,
         }
     }
 
@@ -492,6 +689,19 @@ impl TypeInfo {
         type_engine: &TypeEngine,
         decl_engine: &DeclEngine,
         error_msg_span: &Span,
+    ) -> CompileResult<String> {
+        self.to_selector_name_inner(type_engine, decl_engine, error_msg_span, &mut vec![])
+    }
+
+    /// Implements [TypeInfo::to_selector_name]. `in_progress` holds the structs
+    /// and enums whose names are being constructed, so that
+    /// recursive types are rejected instead of being expanded forever.
+    fn to_selector_name_inner(
+        &self,
+        type_engine: &TypeEngine,
+        decl_engine: &DeclEngine,
+        error_msg_span: &Span,
+        in_progress: &mut Vec<TypeInfo>,
     ) -> CompileResult<String> {
         use TypeInfo::*;
         let name = match self {
@@ -503,6 +713,7 @@ impl TypeInfo {
                     Sixteen => "u16",
                     ThirtyTwo => "u32",
                     SixtyFour => "u64",
+                    V256 => "u256",
                 }
                 .into()
             }
@@ -516,7 +727,12 @@ impl TypeInfo {
                             type_engine
                                 .to_typeinfo(field_type.type_id, error_msg_span)
                                 .expect("unreachable?")
-                                .to_selector_name(type_engine, decl_engine, error_msg_span)
+                                .to_selector_name_inner(
+                                    type_engine,
+                                    decl_engine,
+                                    error_msg_span,
+                                    in_progress,
+                                )
                         })
                         .collect::<Vec<CompileResult<String>>>();
                     let mut buf = vec![];
@@ -534,6 +750,16 @@ impl TypeInfo {
             B256 => "b256".into(),
             Struct(decl_ref) => {
                 let decl = decl_engine.get_struct(decl_ref);
+                let engines = Engines::new(type_engine, decl_engine);
+                if in_progress.iter().any(|ty| ty.eq(self, engines)) {
+                    return err(
+                        vec![],
+                        vec![CompileError::InvalidAbiType {
+                            span: error_msg_span.clone(),
+                        }],
+                    );
+                }
+                in_progress.push(self.clone());
                 let field_names = {
                     let names = decl
                         .fields
@@ -545,7 +771,12 @@ impl TypeInfo {
                                 Err(e) => return err(vec![], vec![e.into()]),
                                 Ok(ty) => ty,
                             };
-                            ty.to_selector_name(type_engine, decl_engine, error_msg_span)
+                            ty.to_selector_name_inner(
+                                type_engine,
+                                decl_engine,
+                                error_msg_span,
+                                in_progress,
+                            )
                         })
                         .collect::<Vec<CompileResult<String>>>();
                     let mut buf = vec![];
@@ -567,7 +798,12 @@ impl TypeInfo {
                                 Err(e) => return err(vec![], vec![e.into()]),
                                 Ok(ty) => ty,
                             };
-                            ty.to_selector_name(type_engine, decl_engine, error_msg_span)
+                            ty.to_selector_name_inner(
+                                type_engine,
+                                decl_engine,
+                                error_msg_span,
+                                in_progress,
+                            )
                         })
                         .collect::<Vec<CompileResult<String>>>();
                     let mut buf = vec![];
@@ -580,6 +816,8 @@ impl TypeInfo {
                     buf
                 };
 
+                in_progress.pop();
+
                 if type_arguments.is_empty() {
                     format!("s({})", field_names.join(","))
                 } else {
@@ -588,6 +826,16 @@ impl TypeInfo {
             }
             Enum(decl_ref) => {
                 let decl = decl_engine.get_enum(decl_ref);
+                let engines = Engines::new(type_engine, decl_engine);
+                if in_progress.iter().any(|ty| ty.eq(self, engines)) {
+                    return err(
+                        vec![],
+                        vec![CompileError::InvalidAbiType {
+                            span: error_msg_span.clone(),
+                        }],
+                    );
+                }
+                in_progress.push(self.clone());
                 let variant_names = {
                     let names = decl
                         .variants
@@ -599,7 +847,12 @@ impl TypeInfo {
                                 Err(e) => return err(vec![], vec![e.into()]),
                                 Ok(ty) => ty,
                             };
-                            ty.to_selector_name(type_engine, decl_engine, error_msg_span)
+                            ty.to_selector_name_inner(
+                                type_engine,
+                                decl_engine,
+                                error_msg_span,
+                                in_progress,
+                            )
                         })
                         .collect::<Vec<CompileResult<String>>>();
                     let mut buf = vec![];
@@ -621,7 +874,12 @@ impl TypeInfo {
                                 Err(e) => return err(vec![], vec![e.into()]),
                                 Ok(ty) => ty,
                             };
-                            ty.to_selector_name(type_engine, decl_engine, error_msg_span)
+                            ty.to_selector_name_inner(
+                                type_engine,
+                                decl_engine,
+                                error_msg_span,
+                                in_progress,
+                            )
                         })
                         .collect::<Vec<CompileResult<String>>>();
                     let mut buf = vec![];
@@ -633,6 +891,7 @@ impl TypeInfo {
                     }
                     buf
                 };
+                in_progress.pop();
                 if type_arguments.is_empty() {
                     format!("e({})", variant_names.join(","))
                 } else {
@@ -644,19 +903,32 @@ impl TypeInfo {
                 }
             }
             Array(elem_ty, length) => {
-                let name = type_engine.get(elem_ty.type_id).to_selector_name(
+                let name = type_engine.get(elem_ty.type_id).to_selector_name_inner(
                     type_engine,
                     decl_engine,
                     error_msg_span,
+                    in_progress,
                 );
                 let name = match name.value {
                     Some(name) => name,
                     None => return name,
                 };
-                format!("a[{};{}]", name, length.val())
+                let length = match length.resolve(type_engine) {
+                    Some(length) => length,
+                    None => {
+                        return err(
+                            vec![],
+                            vec![CompileError::InvalidAbiType {
+                                span: error_msg_span.clone(),
+                            }],
+                        )
+                    }
+                };
+                format!("a[{};{}]", name, length)
             }
             RawUntypedPtr => "rawptr".to_string(),
             RawUntypedSlice => "rawslice".to_string(),
+            StringSlice => "str".to_string(),
             _ => {
                 return err(
                     vec![],
@@ -686,7 +958,9 @@ impl TypeInfo {
             TypeInfo::Tuple(fields) => fields
                 .iter()
                 .any(|field_type| id_uninhabited(field_type.type_id)),
-            TypeInfo::Array(elem_ty, length) => length.val() > 0 && id_uninhabited(elem_ty.type_id),
+            TypeInfo::Array(elem_ty, length) => {
+                length.resolve(type_engine) != Some(0) && id_uninhabited(elem_ty.type_id)
+            }
             _ => false,
         }
     }
@@ -737,11 +1011,19 @@ impl TypeInfo {
                 all_zero_sized
             }
             TypeInfo::Array(elem_ty, length) => {
-                length.val() == 0
+                length.resolve(type_engine) == Some(0)
                     || type_engine
                         .get(elem_ty.type_id)
                         .is_zero_sized(type_engine, decl_engine)
             }
+            TypeInfo::Closure { fn_ref, captures } => decl_engine.get_function(fn_ref).parameters
+                [..*captures]
+                .iter()
+                .all(|param| {
+                    type_engine
+                        .get(param.type_argument.type_id)
+                        .is_zero_sized(type_engine, decl_engine)
+                }),
             _ => false,
         }
     }
@@ -757,7 +1039,7 @@ impl TypeInfo {
                     .can_safely_ignore(type_engine, decl_engine)
             }),
             TypeInfo::Array(elem_ty, length) => {
-                length.val() == 0
+                length.resolve(type_engine) == Some(0)
                     || type_engine
                         .get(elem_ty.type_id)
                         .can_safely_ignore(type_engine, decl_engine)
@@ -776,10 +1058,12 @@ impl TypeInfo {
     }
 
     pub fn is_copy_type(&self) -> bool {
-        matches!(
-            self,
-            TypeInfo::Boolean | TypeInfo::UnsignedInteger(_) | TypeInfo::RawUntypedPtr
-        ) || self.is_unit()
+        match self {
+            // `u256` doesn't fit in a register and is passed by reference.
+            TypeInfo::UnsignedInteger(bits) => *bits != IntegerBits::V256,
+            TypeInfo::Boolean | TypeInfo::RawUntypedPtr => true,
+            _ => self.is_unit(),
+        }
     }
 
     pub(crate) fn apply_type_arguments(
@@ -827,12 +1111,16 @@ impl TypeInfo {
             | TypeInfo::Numeric
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
+            | TypeInfo::StringSlice
             | TypeInfo::Contract
             | TypeInfo::ErrorRecovery
             | TypeInfo::Array(_, _)
             | TypeInfo::Storage { .. }
             | TypeInfo::Placeholder(_)
-            | TypeInfo::TypeParam(_) => {
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::Closure { .. }
+            | TypeInfo::ConstGeneric { .. }
+            | TypeInfo::TraitObject { .. } => {
                 errors.push(CompileError::TypeArgumentsNotAllowed { span: span.clone() });
                 err(warnings, errors)
             }
@@ -846,83 +1134,83 @@ impl TypeInfo {
         type_engine: &TypeEngine,
         decl_engine: &DeclEngine,
     ) -> HashSet<TypeId> {
-        let helper = |type_id: TypeId| {
-            let mut inner_types = HashSet::new();
+        let mut inner_types = HashSet::new();
+        self.extend_with_inner_types(type_engine, decl_engine, &mut inner_types);
+        inner_types
+    }
+
+    /// Adds all inner `TypeId`'s of `self`, not including `self`, to
+    /// `inner_types`.
+    ///
+    /// Structs and enums which are already in `inner_types` aren't analyzed
+    /// again, so that this terminates for recursive types.
+    fn extend_with_inner_types(
+        &self,
+        type_engine: &TypeEngine,
+        decl_engine: &DeclEngine,
+        inner_types: &mut HashSet<TypeId>,
+    ) {
+        fn helper(
+            type_id: TypeId,
+            type_engine: &TypeEngine,
+            decl_engine: &DeclEngine,
+            inner_types: &mut HashSet<TypeId>,
+        ) {
+            let extend = |type_id: TypeId, inner_types: &mut HashSet<TypeId>| {
+                type_engine.get(type_id).extend_with_inner_types(
+                    type_engine,
+                    decl_engine,
+                    inner_types,
+                )
+            };
             match type_engine.get(type_id) {
                 TypeInfo::Enum(decl_ref) => {
+                    if !inner_types.insert(type_id) {
+                        return;
+                    }
                     let decl = decl_engine.get_enum(&decl_ref);
-                    inner_types.insert(type_id);
                     for type_param in decl.type_parameters.iter() {
-                        inner_types.extend(
-                            type_engine
-                                .get(type_param.type_id)
-                                .extract_inner_types(type_engine, decl_engine),
-                        );
+                        extend(type_param.type_id, inner_types);
                     }
                     for variant in decl.variants.iter() {
-                        inner_types.extend(
-                            type_engine
-                                .get(variant.type_argument.type_id)
-                                .extract_inner_types(type_engine, decl_engine),
-                        );
+                        extend(variant.type_argument.type_id, inner_types);
                     }
                 }
                 TypeInfo::Struct(decl_ref) => {
+                    if !inner_types.insert(type_id) {
+                        return;
+                    }
                     let decl = decl_engine.get_struct(&decl_ref);
-                    inner_types.insert(type_id);
                     for type_param in decl.type_parameters.iter() {
-                        inner_types.extend(
-                            type_engine
-                                .get(type_param.type_id)
-                                .extract_inner_types(type_engine, decl_engine),
-                        );
+                        extend(type_param.type_id, inner_types);
                     }
                     for field in decl.fields.iter() {
-                        inner_types.extend(
-                            type_engine
-                                .get(field.type_argument.type_id)
-                                .extract_inner_types(type_engine, decl_engine),
-                        );
+                        extend(field.type_argument.type_id, inner_types);
                     }
                 }
                 TypeInfo::Custom { type_arguments, .. } => {
                     inner_types.insert(type_id);
                     if let Some(type_arguments) = type_arguments {
                         for type_arg in type_arguments.iter() {
-                            inner_types.extend(
-                                type_engine
-                                    .get(type_arg.type_id)
-                                    .extract_inner_types(type_engine, decl_engine),
-                            );
+                            extend(type_arg.type_id, inner_types);
                         }
                     }
                 }
                 TypeInfo::Array(elem_ty, _) => {
+                    inner_types.insert(type_id);
+                    extend(type_id, inner_types);
                     inner_types.insert(elem_ty.type_id);
-                    inner_types.extend(
-                        type_engine
-                            .get(type_id)
-                            .extract_inner_types(type_engine, decl_engine),
-                    );
                 }
                 TypeInfo::Tuple(elems) => {
                     inner_types.insert(type_id);
                     for elem in elems.iter() {
-                        inner_types.extend(
-                            type_engine
-                                .get(elem.type_id)
-                                .extract_inner_types(type_engine, decl_engine),
-                        );
+                        extend(elem.type_id, inner_types);
                     }
                 }
                 TypeInfo::Storage { fields } => {
                     inner_types.insert(type_id);
                     for field in fields.iter() {
-                        inner_types.extend(
-                            type_engine
-                                .get(field.type_argument.type_id)
-                                .extract_inner_types(type_engine, decl_engine),
-                        );
+                        extend(field.type_argument.type_id, inner_types);
                     }
                 }
                 TypeInfo::Unknown
@@ -936,53 +1224,70 @@ impl TypeInfo {
                 | TypeInfo::Numeric
                 | TypeInfo::RawUntypedPtr
                 | TypeInfo::RawUntypedSlice
+                | TypeInfo::StringSlice
                 | TypeInfo::Contract
-                | TypeInfo::Placeholder(_) => {
+                | TypeInfo::Placeholder(_)
+                | TypeInfo::Closure { .. }
+                | TypeInfo::ConstGeneric { .. }
+                | TypeInfo::TraitObject { .. } => {
                     inner_types.insert(type_id);
                 }
                 TypeInfo::TypeParam(_) | TypeInfo::ErrorRecovery => {}
             }
-            inner_types
-        };
+        }
 
-        let mut inner_types = HashSet::new();
         match self {
             TypeInfo::Enum(decl_ref) => {
                 let decl = decl_engine.get_enum(decl_ref);
                 for type_param in decl.type_parameters.iter() {
-                    inner_types.extend(helper(type_param.type_id));
+                    helper(type_param.type_id, type_engine, decl_engine, inner_types);
                 }
                 for variant in decl.variants.iter() {
-                    inner_types.extend(helper(variant.type_argument.type_id));
+                    helper(
+                        variant.type_argument.type_id,
+                        type_engine,
+                        decl_engine,
+                        inner_types,
+                    );
                 }
             }
             TypeInfo::Struct(decl_ref) => {
                 let decl = decl_engine.get_struct(decl_ref);
                 for type_param in decl.type_parameters.iter() {
-                    inner_types.extend(helper(type_param.type_id));
+                    helper(type_param.type_id, type_engine, decl_engine, inner_types);
                 }
                 for field in decl.fields.iter() {
-                    inner_types.extend(helper(field.type_argument.type_id));
+                    helper(
+                        field.type_argument.type_id,
+                        type_engine,
+                        decl_engine,
+                        inner_types,
+                    );
                 }
             }
             TypeInfo::Custom { type_arguments, .. } => {
                 if let Some(type_arguments) = type_arguments {
                     for type_arg in type_arguments.iter() {
-                        inner_types.extend(helper(type_arg.type_id));
+                        helper(type_arg.type_id, type_engine, decl_engine, inner_types);
                     }
                 }
             }
             TypeInfo::Array(elem_ty, _) => {
-                inner_types.extend(helper(elem_ty.type_id));
+                helper(elem_ty.type_id, type_engine, decl_engine, inner_types);
             }
             TypeInfo::Tuple(elems) => {
                 for elem in elems.iter() {
-                    inner_types.extend(helper(elem.type_id));
+                    helper(elem.type_id, type_engine, decl_engine, inner_types);
                 }
             }
             TypeInfo::Storage { fields } => {
                 for field in fields.iter() {
-                    inner_types.extend(helper(field.type_argument.type_id));
+                    helper(
+                        field.type_argument.type_id,
+                        type_engine,
+                        decl_engine,
+                        inner_types,
+                    );
                 }
             }
             TypeInfo::Unknown
@@ -997,11 +1302,14 @@ impl TypeInfo {
             | TypeInfo::Contract
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
+            | TypeInfo::StringSlice
             | TypeInfo::ErrorRecovery
             | TypeInfo::Placeholder(_)
-            | TypeInfo::TypeParam(_) => {}
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::Closure { .. }
+            | TypeInfo::ConstGeneric { .. }
+            | TypeInfo::TraitObject { .. } => {}
         }
-        inner_types
     }
 
     /// Given a `TypeInfo` `self`, check to see if `self` is currently
@@ -1024,6 +1332,7 @@ impl TypeInfo {
             TypeInfo::Unknown
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
+            | TypeInfo::StringSlice
             | TypeInfo::ContractCaller { .. }
             | TypeInfo::Custom { .. }
             | TypeInfo::SelfType
@@ -1032,7 +1341,10 @@ impl TypeInfo {
             | TypeInfo::Array(_, _)
             | TypeInfo::Storage { .. }
             | TypeInfo::Placeholder(_)
-            | TypeInfo::TypeParam(_) => {
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::Closure { .. }
+            | TypeInfo::ConstGeneric { .. }
+            | TypeInfo::TraitObject { .. } => {
                 errors.push(CompileError::Unimplemented(
                     "matching on this type is unsupported right now",
                     span.clone(),
@@ -1060,6 +1372,7 @@ impl TypeInfo {
             | TypeInfo::B256
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
+            | TypeInfo::StringSlice
             | TypeInfo::Custom { .. }
             | TypeInfo::Str(_)
             | TypeInfo::Array(_, _)
@@ -1071,7 +1384,10 @@ impl TypeInfo {
             | TypeInfo::SelfType
             | TypeInfo::Storage { .. }
             | TypeInfo::Placeholder(_)
-            | TypeInfo::TypeParam(_) => {
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::Closure { .. }
+            | TypeInfo::ConstGeneric { .. }
+            | TypeInfo::TraitObject { .. } => {
                 errors.push(CompileError::Unimplemented(
                     "implementing traits on this type is unsupported right now",
                     span.clone(),
@@ -1091,6 +1407,17 @@ impl TypeInfo {
         self,
         engines: Engines<'_>,
         span: &Span,
+    ) -> CompileResult<Vec<TypeInfo>> {
+        self.extract_nested_types_inner(engines, span, &mut vec![])
+    }
+
+    /// Implements [TypeInfo::extract_nested_types]. `in_progress` holds the
+    /// structs and enums which are being analyzed, as they may be recursive.
+    fn extract_nested_types_inner(
+        self,
+        engines: Engines<'_>,
+        span: &Span,
+        in_progress: &mut Vec<TypeInfo>,
     ) -> CompileResult<Vec<TypeInfo>> {
         let type_engine = engines.te();
         let decl_engine = engines.de();
@@ -1098,6 +1425,13 @@ impl TypeInfo {
         let mut warnings = vec![];
         let mut errors = vec![];
         let mut all_nested_types = vec![self.clone()];
+        let is_decl = matches!(self, TypeInfo::Enum(_) | TypeInfo::Struct(_));
+        if is_decl {
+            if in_progress.iter().any(|ty| ty.eq(&self, engines)) {
+                return ok(all_nested_types, warnings, errors);
+            }
+            in_progress.push(self.clone());
+        }
         match self {
             TypeInfo::Enum(decl_ref) => {
                 let decl = decl_engine.get_enum(&decl_ref);
@@ -1105,7 +1439,7 @@ impl TypeInfo {
                     let mut nested_types = check!(
                         type_engine
                             .get(type_parameter.type_id)
-                            .extract_nested_types(engines, span),
+                            .extract_nested_types_inner(engines, span, in_progress),
                         return err(warnings, errors),
                         warnings,
                         errors
@@ -1116,7 +1450,7 @@ impl TypeInfo {
                     let mut nested_types = check!(
                         type_engine
                             .get(variant_type.type_argument.type_id)
-                            .extract_nested_types(engines, span),
+                            .extract_nested_types_inner(engines, span, in_progress),
                         return err(warnings, errors),
                         warnings,
                         errors
@@ -1130,7 +1464,7 @@ impl TypeInfo {
                     let mut nested_types = check!(
                         type_engine
                             .get(type_parameter.type_id)
-                            .extract_nested_types(engines, span),
+                            .extract_nested_types_inner(engines, span, in_progress),
                         return err(warnings, errors),
                         warnings,
                         errors
@@ -1141,7 +1475,7 @@ impl TypeInfo {
                     let mut nested_types = check!(
                         type_engine
                             .get(field.type_argument.type_id)
-                            .extract_nested_types(engines, span),
+                            .extract_nested_types_inner(engines, span, in_progress),
                         return err(warnings, errors),
                         warnings,
                         errors
@@ -1154,7 +1488,7 @@ impl TypeInfo {
                     let mut nested_types = check!(
                         type_engine
                             .get(type_argument.type_id)
-                            .extract_nested_types(engines, span),
+                            .extract_nested_types_inner(engines, span, in_progress),
                         return err(warnings, errors),
                         warnings,
                         errors
@@ -1162,23 +1496,28 @@ impl TypeInfo {
                     all_nested_types.append(&mut nested_types);
                 }
             }
-            TypeInfo::Array(elem_ty, _) => {
+            TypeInfo::Array(elem_ty, length) => {
                 let mut nested_types = check!(
-                    type_engine
-                        .get(elem_ty.type_id)
-                        .extract_nested_types(engines, span),
+                    type_engine.get(elem_ty.type_id).extract_nested_types_inner(
+                        engines,
+                        span,
+                        in_progress
+                    ),
                     return err(warnings, errors),
                     warnings,
                     errors
                 );
                 all_nested_types.append(&mut nested_types);
+                if let Some(length_id) = length.type_id() {
+                    all_nested_types.push(type_engine.get(length_id));
+                }
             }
             TypeInfo::Storage { fields } => {
                 for field in fields.iter() {
                     let mut nested_types = check!(
                         type_engine
                             .get(field.type_argument.type_id)
-                            .extract_nested_types(engines, span),
+                            .extract_nested_types_inner(engines, span, in_progress),
                         return err(warnings, errors),
                         warnings,
                         errors
@@ -1194,7 +1533,7 @@ impl TypeInfo {
                         let mut nested_types = check!(
                             type_engine
                                 .get(type_arg.type_id)
-                                .extract_nested_types(engines, span),
+                                .extract_nested_types_inner(engines, span, in_progress),
                             return err(warnings, errors),
                             warnings,
                             errors
@@ -1203,6 +1542,25 @@ impl TypeInfo {
                     }
                 }
             }
+            TypeInfo::Closure { fn_ref, .. } => {
+                let decl = decl_engine.get_function(&fn_ref);
+                for type_argument in decl
+                    .parameters
+                    .iter()
+                    .map(|param| &param.type_argument)
+                    .chain(std::iter::once(&decl.return_type))
+                {
+                    let mut nested_types = check!(
+                        type_engine
+                            .get(type_argument.type_id)
+                            .extract_nested_types_inner(engines, span, in_progress),
+                        return err(warnings, errors),
+                        warnings,
+                        errors
+                    );
+                    all_nested_types.append(&mut nested_types);
+                }
+            }
             TypeInfo::Unknown
             | TypeInfo::Str(_)
             | TypeInfo::UnsignedInteger(_)
@@ -1212,9 +1570,12 @@ impl TypeInfo {
             | TypeInfo::Numeric
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
+            | TypeInfo::StringSlice
             | TypeInfo::Contract
             | TypeInfo::Placeholder(_)
-            | TypeInfo::TypeParam(_) => {}
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::ConstGeneric { .. }
+            | TypeInfo::TraitObject { .. } => {}
             TypeInfo::Custom { .. } | TypeInfo::SelfType => {
                 errors.push(CompileError::Internal(
                     "did not expect to find this type here",
@@ -1227,6 +1588,9 @@ impl TypeInfo {
                 return err(warnings, errors);
             }
         }
+        if is_decl {
+            in_progress.pop();
+        }
         ok(all_nested_types, warnings, errors)
     }
 
@@ -1246,7 +1610,13 @@ impl TypeInfo {
         let generics = HashSet::from_iter(
             nested_types
                 .into_iter()
-                .filter(|x| matches!(x, TypeInfo::UnknownGeneric { .. }))
+                .filter(|x| {
+                    matches!(
+                        x,
+                        TypeInfo::UnknownGeneric { .. }
+                            | TypeInfo::ConstGeneric { value: None, .. }
+                    )
+                })
                 .map(|thing| WithEngines::new(thing, engines)),
         );
         ok(generics, warnings, errors)
@@ -1346,6 +1716,10 @@ impl TypeInfo {
             (_, Self::UnknownGeneric { .. }) => {
                 return true;
             }
+            // any length is the subset of a const generic
+            (Self::ConstGeneric { .. }, Self::ConstGeneric { value: None, .. }) => {
+                return true;
+            }
             _ => {}
         }
 
@@ -1371,7 +1745,7 @@ impl TypeInfo {
                 type_engine
                     .get(l0.type_id)
                     .is_subset_of(&type_engine.get(r0.type_id), engines)
-                    && l1.val() == r1.val()
+                    && (l1.eq(r1, engines) || r1.resolve(type_engine).is_none())
             }
             (
                 Self::Custom {
@@ -1559,7 +1933,10 @@ impl TypeInfo {
             | TypeInfo::B256
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
-            | TypeInfo::ErrorRecovery => false,
+            | TypeInfo::StringSlice
+            | TypeInfo::ErrorRecovery
+            | TypeInfo::TraitObject { .. } => false,
+            TypeInfo::ConstGeneric { value, .. } => value.is_none(),
             TypeInfo::Unknown
             | TypeInfo::UnknownGeneric { .. }
             | TypeInfo::ContractCaller { .. }
@@ -1571,7 +1948,8 @@ impl TypeInfo {
             | TypeInfo::Storage { .. }
             | TypeInfo::Numeric
             | TypeInfo::Placeholder(_)
-            | TypeInfo::TypeParam(_) => true,
+            | TypeInfo::TypeParam(_)
+            | TypeInfo::Closure { .. } => true,
         }
     }
 
@@ -1772,7 +2150,7 @@ fn types_are_subset_of(engines: Engines<'_>, left: &[TypeInfo], right: &[TypeInf
     true
 }
 
-fn print_inner_types(
+pub(super) fn print_inner_types(
     engines: Engines<'_>,
     name: String,
     inner_types: impl Iterator<Item = TypeId>,
@@ -1790,3 +2168,19 @@ fn print_inner_types(
         }
     )
 }
+
+pub(crate) fn print_fn_signature(
+    engines: Engines<'_>,
+    parameters: &[TypeArgument],
+    return_type: &TypeArgument,
+) -> String {
+    let parameters = parameters
+        .iter()
+        .map(|param| engines.help_out(param.type_id).to_string())
+        .collect::<Vec<_>>();
+    format!(
+        "fn({}) -> {}",
+        parameters.join(", "),
+        engines.help_out(return_type.type_id)
+    )
+}
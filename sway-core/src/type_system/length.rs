@@ -1,19 +1,153 @@
-use sway_types::{span::Span, Spanned};
+use crate::{engine_threading::*, type_system::*};
+
+use sway_types::{span::Span, Ident, Spanned};
+
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
 
 /// Describes a fixed length for types that needs it such as arrays and strings
-#[derive(Debug, Clone, Hash)]
+#[derive(Debug, Clone)]
 pub struct Length {
-    val: usize,
+    kind: LengthKind,
     span: Span,
 }
 
+#[derive(Debug, Clone)]
+enum LengthKind {
+    /// A length that is known upfront, like the `3` in `[u64; 3]`.
+    Literal(usize),
+    /// A length given by a const generic parameter, like the `N` in
+    /// `[u64; N]`. The [TypeId] refers to the [TypeInfo::ConstGeneric] of the
+    /// parameter, which holds the value of the length once it is known.
+    Generic(TypeId),
+}
+
 impl Length {
     pub fn new(val: usize, span: Span) -> Self {
-        Length { val, span }
+        Length {
+            kind: LengthKind::Literal(val),
+            span,
+        }
     }
 
+    /// Creates a [Length] that refers to the const generic parameter with
+    /// the type id `type_id`.
+    pub fn generic(type_id: TypeId, span: Span) -> Self {
+        Length {
+            kind: LengthKind::Generic(type_id),
+            span,
+        }
+    }
+
+    /// Returns the value of a length that is known upfront.
+    ///
+    /// Lengths that refer to a const generic parameter have to be resolved
+    /// using [Length::resolve] instead, and yield `0` here.
     pub fn val(&self) -> usize {
-        self.val
+        match self.kind {
+            LengthKind::Literal(val) => val,
+            LengthKind::Generic(_) => 0,
+        }
+    }
+
+    /// Returns the type id of the const generic parameter this length refers
+    /// to, if any.
+    pub fn type_id(&self) -> Option<TypeId> {
+        match self.kind {
+            LengthKind::Literal(_) => None,
+            LengthKind::Generic(type_id) => Some(type_id),
+        }
+    }
+
+    /// Returns the value of the length, if it is known. The value of a length
+    /// that refers to a const generic parameter is only known once that
+    /// parameter has been monomorphized.
+    pub fn resolve(&self, type_engine: &TypeEngine) -> Option<usize> {
+        match self.kind {
+            LengthKind::Literal(val) => Some(val),
+            LengthKind::Generic(type_id) => match type_engine.get(type_id) {
+                TypeInfo::ConstGeneric { value, .. } => value,
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns a [TypeId] representing this length, so that it can take part
+    /// in unification. Literal lengths are inserted into the [TypeEngine] as
+    /// a [TypeInfo::ConstGeneric] whose value is known.
+    pub(crate) fn to_type_id(&self, engines: Engines<'_>) -> TypeId {
+        match self.kind {
+            LengthKind::Literal(val) => engines.te().insert(
+                engines.de(),
+                TypeInfo::ConstGeneric {
+                    name: Ident::new_with_override(val.to_string(), self.span.clone()),
+                    value: Some(val),
+                },
+            ),
+            LengthKind::Generic(type_id) => type_id,
+        }
+    }
+}
+
+impl HashWithEngines for Length {
+    fn hash<H: Hasher>(&self, state: &mut H, engines: Engines<'_>) {
+        match self.resolve(engines.te()) {
+            Some(val) => val.hash(state),
+            None => {
+                if let Some(type_id) = self.type_id() {
+                    engines.te().get(type_id).hash(state, engines);
+                }
+            }
+        }
+    }
+}
+
+impl EqWithEngines for Length {}
+impl PartialEqWithEngines for Length {
+    fn eq(&self, other: &Self, engines: Engines<'_>) -> bool {
+        let type_engine = engines.te();
+        match (self.resolve(type_engine), other.resolve(type_engine)) {
+            (Some(l), Some(r)) => l == r,
+            (None, None) => match (self.type_id(), other.type_id()) {
+                (Some(l), Some(r)) => type_engine.get(l).eq(&type_engine.get(r), engines),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl OrdWithEngines for Length {
+    fn cmp(&self, other: &Self, engines: Engines<'_>) -> Ordering {
+        let type_engine = engines.te();
+        match (self.resolve(type_engine), other.resolve(type_engine)) {
+            (Some(l), Some(r)) => l.cmp(&r),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => match (self.type_id(), other.type_id()) {
+                (Some(l), Some(r)) => type_engine.get(l).cmp(&type_engine.get(r), engines),
+                _ => Ordering::Equal,
+            },
+        }
+    }
+}
+
+impl SubstTypes for Length {
+    fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: Engines<'_>) {
+        if let LengthKind::Generic(type_id) = &mut self.kind {
+            type_id.subst(type_mapping, engines);
+        }
+    }
+}
+
+impl DisplayWithEngines for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>, engines: Engines<'_>) -> std::fmt::Result {
+        match self.kind {
+            LengthKind::Literal(val) => write!(f, "{val}"),
+            LengthKind::Generic(type_id) => write!(f, "{}", engines.help_out(type_id)),
+        }
     }
 }
 
@@ -11,6 +11,7 @@ use crate::{
     decl_engine::{DeclEngine, DeclEngineIndex},
     engine_threading::*,
 };
+use sway_types::Spanned;
 
 pub trait SubstTypes {
     fn subst_inner(&mut self, type_mapping: &TypeSubstMap, engines: Engines<'_>);
@@ -144,6 +145,12 @@ impl TypeSubstMap {
         self.mapping.is_empty()
     }
 
+    /// Returns an iterator over the ([SourceType], [DestinationType]) pairs
+    /// of the [TypeSubstMap].
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&SourceType, &DestinationType)> {
+        self.mapping.iter()
+    }
+
     /// Constructs a new [TypeSubstMap] from a list of [TypeParameter]s
     /// `type_parameters`. The [SourceType]s of the resulting [TypeSubstMap] are
     /// the [TypeId]s from `type_parameters` and the [DestinationType]s are the
@@ -220,7 +227,8 @@ impl TypeSubstMap {
         subset: TypeId,
     ) -> TypeSubstMap {
         match (type_engine.get(superset), type_engine.get(subset)) {
-            (TypeInfo::UnknownGeneric { .. }, _) => TypeSubstMap {
+            (TypeInfo::UnknownGeneric { .. }, _)
+            | (TypeInfo::ConstGeneric { value: None, .. }, _) => TypeSubstMap {
                 mapping: BTreeMap::from([(superset, subset)]),
             },
             (
@@ -299,12 +307,31 @@ impl TypeSubstMap {
                     type_arguments.iter().map(|x| x.type_id).collect::<Vec<_>>(),
                 )
             }
-            (TypeInfo::Array(type_parameter, _), TypeInfo::Array(type_argument, _)) => {
+            (
+                TypeInfo::ContractCaller {
+                    type_arguments: type_parameters,
+                    ..
+                },
+                TypeInfo::ContractCaller { type_arguments, .. },
+            ) => TypeSubstMap::from_superset_and_subset_helper(
+                type_engine,
+                decl_engine,
+                type_parameters
+                    .iter()
+                    .map(|x| x.type_id)
+                    .collect::<Vec<_>>(),
+                type_arguments.iter().map(|x| x.type_id).collect::<Vec<_>>(),
+            ),
+            (
+                TypeInfo::Array(type_parameter, length_parameter),
+                TypeInfo::Array(type_argument, length_argument),
+            ) => {
+                let engines = Engines::new(type_engine, decl_engine);
                 TypeSubstMap::from_superset_and_subset_helper(
                     type_engine,
                     decl_engine,
-                    vec![type_parameter.type_id],
-                    vec![type_argument.type_id],
+                    vec![type_parameter.type_id, length_parameter.to_type_id(engines)],
+                    vec![type_argument.type_id, length_argument.to_type_id(engines)],
                 )
             }
             (
@@ -338,8 +365,7 @@ impl TypeSubstMap {
             | (TypeInfo::Contract, TypeInfo::Contract)
             | (TypeInfo::ErrorRecovery, TypeInfo::ErrorRecovery)
             | (TypeInfo::Str(_), TypeInfo::Str(_))
-            | (TypeInfo::UnsignedInteger(_), TypeInfo::UnsignedInteger(_))
-            | (TypeInfo::ContractCaller { .. }, TypeInfo::ContractCaller { .. }) => TypeSubstMap {
+            | (TypeInfo::UnsignedInteger(_), TypeInfo::UnsignedInteger(_)) => TypeSubstMap {
                 mapping: BTreeMap::new(),
             },
             _ => TypeSubstMap {
@@ -394,16 +420,28 @@ impl TypeSubstMap {
     /// call `find_match` (via calling [SubstTypes]).
     ///
     /// A match can be found in two different circumstances:
-    /// - `type_id` is a [TypeInfo::Custom] or [TypeInfo::UnknownGeneric]
+    /// - `type_id` is a [TypeInfo::Custom], [TypeInfo::UnknownGeneric] or
+    ///     [TypeInfo::ConstGeneric]
     ///
     /// A match is potentially created (i.e. a new `TypeId` is created) in these
     /// circumstances:
     /// - `type_id` is a [TypeInfo::Struct], [TypeInfo::Enum],
-    ///     [TypeInfo::Array], or [TypeInfo::Tuple] and one of the sub-types
-    ///     finds a match in a recursive call to `find_match`
+    ///     [TypeInfo::Array], [TypeInfo::Tuple], [TypeInfo::ContractCaller], or
+    ///     [TypeInfo::Closure] and one of the sub-types finds a match in a recursive call to `find_match`
     ///
     /// A match cannot be found in any other circumstance.
     pub(crate) fn find_match(&self, type_id: TypeId, engines: Engines<'_>) -> Option<TypeId> {
+        self.find_match_inner(type_id, engines, &mut CopiesInProgress::default())
+    }
+
+    /// Implements [TypeSubstMap::find_match]. `in_progress` holds the structs
+    /// and enums whose copies are being created.
+    fn find_match_inner(
+        &self,
+        type_id: TypeId,
+        engines: Engines<'_>,
+        in_progress: &mut CopiesInProgress,
+    ) -> Option<TypeId> {
         let type_engine = engines.te();
         let decl_engine = engines.de();
         let type_info = type_engine.get(type_id);
@@ -411,65 +449,96 @@ impl TypeSubstMap {
             TypeInfo::Custom { .. } => iter_for_match(engines, self, &type_info),
             TypeInfo::UnknownGeneric { .. } => iter_for_match(engines, self, &type_info),
             TypeInfo::Placeholder(_) => iter_for_match(engines, self, &type_info),
+            TypeInfo::ConstGeneric { .. } => iter_for_match(engines, self, &type_info),
             TypeInfo::TypeParam(_) => None,
-            TypeInfo::Struct(decl_ref) => {
-                let mut decl = decl_engine.get_struct(&decl_ref);
+            TypeInfo::Struct(ref decl_ref) => {
+                if let Some(copy) = in_progress.get(engines, &type_info) {
+                    return Some(copy);
+                }
+                let mut decl = decl_engine.get_struct(decl_ref);
+                // The fields are determined by the type parameters, so they
+                // only change if the type parameters do.
                 let mut need_to_create_new = false;
-                for field in decl.fields.iter_mut() {
-                    if let Some(type_id) = self.find_match(field.type_argument.type_id, engines) {
+                for type_param in decl.type_parameters.iter_mut() {
+                    if let Some(type_id) =
+                        self.find_match_inner(type_param.type_id, engines, in_progress)
+                    {
                         need_to_create_new = true;
+                        type_param.type_id = type_id;
+                    }
+                }
+                if !need_to_create_new {
+                    return None;
+                }
+                in_progress.start(type_info);
+                for field in decl.fields.iter_mut() {
+                    if let Some(type_id) =
+                        self.find_match_inner(field.type_argument.type_id, engines, in_progress)
+                    {
                         field.type_argument.type_id = type_id;
                     }
                 }
+                let new_decl_ref = decl_engine.insert(decl);
+                Some(in_progress.finish(engines, TypeInfo::Struct(new_decl_ref)))
+            }
+            TypeInfo::Enum(ref decl_ref) => {
+                if let Some(copy) = in_progress.get(engines, &type_info) {
+                    return Some(copy);
+                }
+                let mut decl = decl_engine.get_enum(decl_ref);
+                // The variants are determined by the type parameters, so they
+                // only change if the type parameters do.
+                let mut need_to_create_new = false;
                 for type_param in decl.type_parameters.iter_mut() {
-                    if let Some(type_id) = self.find_match(type_param.type_id, engines) {
+                    if let Some(type_id) =
+                        self.find_match_inner(type_param.type_id, engines, in_progress)
+                    {
                         need_to_create_new = true;
                         type_param.type_id = type_id;
                     }
                 }
-                if need_to_create_new {
-                    let new_decl_ref = decl_engine.insert(decl);
-                    Some(type_engine.insert(decl_engine, TypeInfo::Struct(new_decl_ref)))
-                } else {
-                    None
+                if !need_to_create_new {
+                    return None;
                 }
-            }
-            TypeInfo::Enum(decl_ref) => {
-                let mut decl = decl_engine.get_enum(&decl_ref);
-                let mut need_to_create_new = false;
-
+                in_progress.start(type_info);
                 for variant in decl.variants.iter_mut() {
-                    if let Some(type_id) = self.find_match(variant.type_argument.type_id, engines) {
-                        need_to_create_new = true;
+                    if let Some(type_id) =
+                        self.find_match_inner(variant.type_argument.type_id, engines, in_progress)
+                    {
                         variant.type_argument.type_id = type_id;
                     }
                 }
-
-                for type_param in decl.type_parameters.iter_mut() {
-                    if let Some(type_id) = self.find_match(type_param.type_id, engines) {
-                        need_to_create_new = true;
-                        type_param.type_id = type_id;
-                    }
+                let new_decl_ref = decl_engine.insert(decl);
+                Some(in_progress.finish(engines, TypeInfo::Enum(new_decl_ref)))
+            }
+            TypeInfo::Array(mut elem_ty, mut count) => {
+                let mut need_to_create_new = false;
+                if let Some(type_id) = self.find_match_inner(elem_ty.type_id, engines, in_progress)
+                {
+                    need_to_create_new = true;
+                    elem_ty.type_id = type_id;
+                }
+                if let Some(type_id) = count
+                    .type_id()
+                    .and_then(|type_id| self.find_match_inner(type_id, engines, in_progress))
+                {
+                    need_to_create_new = true;
+                    count = Length::generic(type_id, count.span());
                 }
                 if need_to_create_new {
-                    let new_decl_ref = decl_engine.insert(decl);
-                    Some(type_engine.insert(decl_engine, TypeInfo::Enum(new_decl_ref)))
+                    Some(type_engine.insert(decl_engine, TypeInfo::Array(elem_ty, count)))
                 } else {
                     None
                 }
             }
-            TypeInfo::Array(mut elem_ty, count) => {
-                self.find_match(elem_ty.type_id, engines).map(|type_id| {
-                    elem_ty.type_id = type_id;
-                    type_engine.insert(decl_engine, TypeInfo::Array(elem_ty, count))
-                })
-            }
             TypeInfo::Tuple(fields) => {
                 let mut need_to_create_new = false;
                 let fields = fields
                     .into_iter()
                     .map(|mut field| {
-                        if let Some(type_id) = self.find_match(field.type_id, engines) {
+                        if let Some(type_id) =
+                            self.find_match_inner(field.type_id, engines, in_progress)
+                        {
                             need_to_create_new = true;
                             field.type_id = type_id;
                         }
@@ -482,12 +551,44 @@ impl TypeSubstMap {
                     None
                 }
             }
+            TypeInfo::ContractCaller {
+                abi_name,
+                type_arguments,
+                address,
+            } => {
+                let mut need_to_create_new = false;
+                let type_arguments = type_arguments
+                    .into_iter()
+                    .map(|mut type_arg| {
+                        if let Some(type_id) =
+                            self.find_match_inner(type_arg.type_id, engines, in_progress)
+                        {
+                            need_to_create_new = true;
+                            type_arg.type_id = type_id;
+                        }
+                        type_arg
+                    })
+                    .collect::<Vec<_>>();
+                if need_to_create_new {
+                    Some(type_engine.insert(
+                        decl_engine,
+                        TypeInfo::ContractCaller {
+                            abi_name,
+                            type_arguments,
+                            address,
+                        },
+                    ))
+                } else {
+                    None
+                }
+            }
             TypeInfo::Storage { fields } => {
                 let mut need_to_create_new = false;
                 let fields = fields
                     .into_iter()
                     .map(|mut field| {
-                        if let Some(type_id) = self.find_match(field.type_argument.type_id, engines)
+                        if let Some(type_id) =
+                            self.find_match_inner(field.type_argument.type_id, engines, in_progress)
                         {
                             need_to_create_new = true;
                             field.type_argument.type_id = type_id;
@@ -501,18 +602,86 @@ impl TypeSubstMap {
                     None
                 }
             }
+            TypeInfo::Closure { fn_ref, captures } => {
+                let mut decl = decl_engine.get_function(&fn_ref);
+                let need_to_create_new = decl
+                    .parameters
+                    .iter()
+                    .map(|param| param.type_argument.type_id)
+                    .chain(std::iter::once(decl.return_type.type_id))
+                    .any(|type_id| {
+                        self.find_match_inner(type_id, engines, in_progress)
+                            .is_some()
+                    });
+                if need_to_create_new {
+                    decl.subst(self, engines);
+                    let new_decl_ref = decl_engine.insert(decl);
+                    Some(type_engine.insert(
+                        decl_engine,
+                        TypeInfo::Closure {
+                            fn_ref: new_decl_ref,
+                            captures,
+                        },
+                    ))
+                } else {
+                    None
+                }
+            }
             TypeInfo::Unknown
             | TypeInfo::Str(..)
             | TypeInfo::UnsignedInteger(..)
             | TypeInfo::Boolean
-            | TypeInfo::ContractCaller { .. }
             | TypeInfo::SelfType
             | TypeInfo::B256
             | TypeInfo::Numeric
             | TypeInfo::RawUntypedPtr
             | TypeInfo::RawUntypedSlice
+            | TypeInfo::StringSlice
             | TypeInfo::Contract
-            | TypeInfo::ErrorRecovery => None,
+            | TypeInfo::ErrorRecovery
+            | TypeInfo::TraitObject { .. } => None,
+        }
+    }
+}
+
+/// The structs and enums whose copies are being created while substituting
+/// types.
+///
+/// A generic type may refer to itself through the type arguments of e.g. a
+/// `Box`, in which case its copy refers to itself as well. That reference is
+/// created before the copy is, and is filled in once the copy is complete.
+#[derive(Default)]
+pub(super) struct CopiesInProgress(Vec<(TypeInfo, Option<TypeId>)>);
+
+impl CopiesInProgress {
+    /// Returns the type referring to the copy of `type_info`, if that copy is
+    /// being created.
+    pub(super) fn get(&mut self, engines: Engines<'_>, type_info: &TypeInfo) -> Option<TypeId> {
+        let (_, copy) = self
+            .0
+            .iter_mut()
+            .rev()
+            .find(|(ty, _)| ty.eq(type_info, engines))?;
+        Some(*copy.get_or_insert_with(|| engines.te().insert(engines.de(), TypeInfo::Unknown)))
+    }
+
+    /// Starts creating a copy of `type_info`.
+    pub(super) fn start(&mut self, type_info: TypeInfo) {
+        self.0.push((type_info, None));
+    }
+
+    /// Completes the copy which was started last with `copy`, and returns the
+    /// type referring to it.
+    pub(super) fn finish(&mut self, engines: Engines<'_>, copy: TypeInfo) -> TypeId {
+        match self.0.pop().and_then(|(_, type_id)| type_id) {
+            Some(type_id) => {
+                engines
+                    .te()
+                    .slab
+                    .replace(type_id, &TypeInfo::Unknown, copy, engines);
+                type_id
+            }
+            None => engines.te().insert(engines.de(), copy),
         }
     }
 }
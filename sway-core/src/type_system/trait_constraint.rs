@@ -4,7 +4,7 @@ use std::{
 };
 
 use sway_error::error::CompileError;
-use sway_types::{Span, Spanned};
+use sway_types::{Ident, Span, Spanned};
 
 use crate::{
     engine_threading::*,
@@ -105,13 +105,74 @@ impl CollectTypesMetadata for TraitConstraint {
     }
 }
 
+/// The name of the built-in constraint carried by the type parameter of a
+/// parameter with a function type. `fn` is a keyword, so no user-defined trait
+/// can share it.
+const FN_SIGNATURE_CONSTRAINT_NAME: &str = "fn";
+
 impl TraitConstraint {
+    /// Creates the constraint for a parameter of type `fn(A, B) -> R`. Any
+    /// closure whose signature unifies with `(A, B) -> R` satisfies it.
+    pub(crate) fn new_fn_signature(
+        parameters: Vec<TypeArgument>,
+        return_type: TypeArgument,
+        span: Span,
+    ) -> TraitConstraint {
+        let mut type_arguments = parameters;
+        type_arguments.push(return_type);
+        TraitConstraint {
+            trait_name: CallPath {
+                prefixes: vec![],
+                suffix: Ident::new_with_override(FN_SIGNATURE_CONSTRAINT_NAME.to_string(), span),
+                is_absolute: false,
+            },
+            type_arguments,
+        }
+    }
+
+    /// Returns the parameter types and the return type, if this constraint is
+    /// a function signature created by [TraitConstraint::new_fn_signature].
+    pub(crate) fn fn_signature(&self) -> Option<(&[TypeArgument], &TypeArgument)> {
+        let is_fn_signature = self.trait_name.prefixes.is_empty()
+            && !self.trait_name.suffix.is_raw_ident()
+            && self.trait_name.suffix.as_str() == FN_SIGNATURE_CONSTRAINT_NAME;
+        if !is_fn_signature {
+            return None;
+        }
+        self.type_arguments
+            .split_last()
+            .map(|(return_type, parameters)| (parameters, return_type))
+    }
+
     pub(crate) fn type_check(&mut self, mut ctx: TypeCheckContext) -> CompileResult<()> {
         let mut warnings = vec![];
         let mut errors = vec![];
 
         let decl_engine = ctx.decl_engine;
 
+        // Function signatures only need their parameter and return types
+        // resolved.
+        if self.fn_signature().is_some() {
+            for type_argument in self.type_arguments.iter_mut() {
+                type_argument.type_id = check!(
+                    ctx.resolve_type_with_self(
+                        type_argument.type_id,
+                        &type_argument.span,
+                        EnforceTypeArguments::Yes,
+                        None
+                    ),
+                    ctx.type_engine.insert(decl_engine, TypeInfo::ErrorRecovery),
+                    warnings,
+                    errors
+                );
+            }
+            return if errors.is_empty() {
+                ok((), warnings, errors)
+            } else {
+                err(warnings, errors)
+            };
+        }
+
         // Right now we don't have the ability to support defining a type for a
         // trait constraint using a callpath directly, so we check to see if the
         // user has done this and we disallow it.
@@ -175,6 +236,11 @@ impl TraitConstraint {
 
         let decl_engine = ctx.decl_engine;
 
+        // Function signatures have no interface surface to insert.
+        if trait_constraint.fn_signature().is_some() {
+            return ok((), warnings, errors);
+        }
+
         let TraitConstraint {
             trait_name,
             type_arguments,
@@ -219,7 +285,7 @@ impl TraitConstraint {
                     insert_supertraits_into_namespace(
                         ctx.by_ref(),
                         type_id,
-                        &trait_decl.supertraits
+                        &mut trait_decl.supertraits
                     ),
                     return err(warnings, errors),
                     warnings,
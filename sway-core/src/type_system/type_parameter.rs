@@ -125,7 +125,6 @@ impl TypeParameter {
     pub(crate) fn type_check_type_params(
         mut ctx: TypeCheckContext,
         type_params: Vec<TypeParameter>,
-        disallow_trait_constraints: bool,
     ) -> CompileResult<Vec<TypeParameter>> {
         let mut warnings = vec![];
         let mut errors = vec![];
@@ -133,12 +132,6 @@ impl TypeParameter {
         let mut new_type_params: Vec<TypeParameter> = vec![];
 
         for type_param in type_params.into_iter() {
-            if disallow_trait_constraints && !type_param.trait_constraints.is_empty() {
-                let errors = vec![CompileError::WhereClauseNotYetSupported {
-                    span: type_param.trait_constraints_span,
-                }];
-                return err(vec![], errors);
-            }
             new_type_params.push(check!(
                 TypeParameter::type_check(ctx.by_ref(), type_param),
                 continue,
@@ -183,13 +176,24 @@ impl TypeParameter {
 
         // TODO: add check here to see if the type parameter has a valid name and does not have type parameters
 
-        let type_id = type_engine.insert(
-            decl_engine,
-            TypeInfo::UnknownGeneric {
-                name: name_ident.clone(),
-                trait_constraints: VecSet(trait_constraints.clone()),
-            },
-        );
+        let type_id = match type_engine.get(initial_type_id) {
+            // Const generic parameters never have trait constraints, this is
+            // checked when converting the parse tree.
+            TypeInfo::ConstGeneric { .. } => type_engine.insert(
+                decl_engine,
+                TypeInfo::ConstGeneric {
+                    name: name_ident.clone(),
+                    value: None,
+                },
+            ),
+            _ => type_engine.insert(
+                decl_engine,
+                TypeInfo::UnknownGeneric {
+                    name: name_ident.clone(),
+                    trait_constraints: VecSet(trait_constraints.clone()),
+                },
+            ),
+        };
 
         // Insert the trait constraints into the namespace.
         for trait_constraint in trait_constraints.iter() {
@@ -257,6 +261,11 @@ impl TypeParameter {
             );
 
             for trait_constraint in trait_constraints.iter() {
+                // Function signatures don't bring any trait items with them.
+                if trait_constraint.fn_signature().is_some() {
+                    continue;
+                }
+
                 let TraitConstraint {
                     trait_name,
                     type_arguments: trait_type_arguments,
@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{cell::RefCell, fmt};
 
 use sway_error::{
     type_error::TypeError,
@@ -13,6 +13,9 @@ pub(super) struct Unifier<'a> {
     engines: Engines<'a>,
     arguments_are_flipped: bool,
     help_text: String,
+    /// The pairs of structs and enums which are being unified, see
+    /// [Unifier::unify_decls].
+    in_progress: RefCell<Vec<(TypeInfo, TypeInfo)>>,
 }
 
 impl<'a> Unifier<'a> {
@@ -22,6 +25,7 @@ impl<'a> Unifier<'a> {
             engines,
             arguments_are_flipped: false,
             help_text: help_text.to_string(),
+            in_progress: RefCell::new(vec![]),
         }
     }
 
@@ -100,30 +104,42 @@ impl<'a> Unifier<'a> {
             (Contract, Contract) => (vec![], vec![]),
             (RawUntypedPtr, RawUntypedPtr) => (vec![], vec![]),
             (RawUntypedSlice, RawUntypedSlice) => (vec![], vec![]),
+            (StringSlice, StringSlice) => (vec![], vec![]),
             (Str(l), Str(r)) => self.unify_strs(received, expected, span, l.val(), r.val()),
             (Tuple(rfs), Tuple(efs)) if rfs.len() == efs.len() => self.unify_tuples(rfs, efs),
-            (Array(re, rc), Array(ee, ec)) if rc.val() == ec.val() => {
-                self.unify_arrays(received, expected, span, re.type_id, ee.type_id)
+            (Array(re, rc), Array(ee, ec)) => {
+                self.unify_arrays(received, expected, span, (re.type_id, rc), (ee.type_id, ec))
             }
             (Struct(r_decl_ref), Struct(e_decl_ref)) => {
                 let r_decl = self.engines.de().get_struct(&r_decl_ref);
                 let e_decl = self.engines.de().get_struct(&e_decl_ref);
 
-                self.unify_structs(
-                    received,
-                    expected,
-                    span,
-                    (
-                        r_decl.call_path.suffix,
-                        r_decl.type_parameters,
-                        r_decl.fields,
-                    ),
-                    (
-                        e_decl.call_path.suffix,
-                        e_decl.type_parameters,
-                        e_decl.fields,
-                    ),
-                )
+                // A struct which isn't generic is identified by its declaration.
+                // Its fields are not unified, as they may refer to the struct itself.
+                if r_decl.type_parameters.is_empty()
+                    && e_decl.type_parameters.is_empty()
+                    && r_decl.span == e_decl.span
+                {
+                    return (vec![], vec![]);
+                }
+
+                self.unify_decls(received, expected, || {
+                    self.unify_structs(
+                        received,
+                        expected,
+                        span,
+                        (
+                            r_decl.call_path.suffix,
+                            r_decl.type_parameters,
+                            r_decl.fields,
+                        ),
+                        (
+                            e_decl.call_path.suffix,
+                            e_decl.type_parameters,
+                            e_decl.fields,
+                        ),
+                    )
+                })
             }
             // Let empty enums to coerce to any other type. This is useful for Never enum.
             (Enum(r_decl_ref), _)
@@ -135,26 +151,42 @@ impl<'a> Unifier<'a> {
                 let r_decl = self.engines.de().get_enum(&r_decl_ref);
                 let e_decl = self.engines.de().get_enum(&e_decl_ref);
 
-                self.unify_enums(
-                    received,
-                    expected,
-                    span,
-                    (
-                        r_decl.call_path.suffix,
-                        r_decl.type_parameters,
-                        r_decl.variants,
-                    ),
-                    (
-                        e_decl.call_path.suffix,
-                        e_decl.type_parameters,
-                        e_decl.variants,
-                    ),
-                )
+                // An enum which isn't generic is identified by its declaration.
+                // Its variants are not unified, as they may refer to the enum itself.
+                if r_decl.type_parameters.is_empty()
+                    && e_decl.type_parameters.is_empty()
+                    && r_decl.span == e_decl.span
+                {
+                    return (vec![], vec![]);
+                }
+
+                self.unify_decls(received, expected, || {
+                    self.unify_enums(
+                        received,
+                        expected,
+                        span,
+                        (
+                            r_decl.call_path.suffix,
+                            r_decl.type_parameters,
+                            r_decl.variants,
+                        ),
+                        (
+                            e_decl.call_path.suffix,
+                            e_decl.type_parameters,
+                            e_decl.variants,
+                        ),
+                    )
+                })
             }
 
             // For integers and numerics, we (potentially) unify the numeric
-            // with the integer.
-            (UnsignedInteger(r), UnsignedInteger(e)) => self.unify_unsigned_ints(span, r, e),
+            // with the integer. A `u256` is represented differently from the
+            // narrower integers, so it is never implicitly cast to or from them.
+            (UnsignedInteger(r), UnsignedInteger(e))
+                if (r == IntegerBits::V256) == (e == IntegerBits::V256) =>
+            {
+                self.unify_unsigned_ints(span, r, e)
+            }
             (Numeric, e @ UnsignedInteger(_)) => {
                 self.replace_received_with_expected(received, expected, &Numeric, e, span)
             }
@@ -163,16 +195,21 @@ impl<'a> Unifier<'a> {
             }
 
             // For contract callers, we (potentially) unify them if they have
-            // the same name and their address is `None`
+            // the same name and type arguments and their address is `None`
             (
                 ref r @ TypeInfo::ContractCaller {
                     abi_name: ref ran,
+                    type_arguments: ref rta,
                     address: ref rra,
                 },
                 TypeInfo::ContractCaller {
-                    abi_name: ref ean, ..
+                    abi_name: ref ean,
+                    type_arguments: ref eta,
+                    ..
                 },
-            ) if (ran == ean && rra.is_none()) || matches!(ran, AbiName::Deferred) => {
+            ) if (ran == ean && rta.eq(eta, self.engines) && rra.is_none())
+                || matches!(ran, AbiName::Deferred) =>
+            {
                 // if one address is empty, coerce to the other one
                 self.replace_received_with_expected(
                     received,
@@ -184,13 +221,18 @@ impl<'a> Unifier<'a> {
             }
             (
                 TypeInfo::ContractCaller {
-                    abi_name: ref ran, ..
+                    abi_name: ref ran,
+                    type_arguments: ref rta,
+                    ..
                 },
                 ref e @ TypeInfo::ContractCaller {
                     abi_name: ref ean,
+                    type_arguments: ref eta,
                     address: ref ea,
                 },
-            ) if (ran == ean && ea.is_none()) || matches!(ean, AbiName::Deferred) => {
+            ) if (ran == ean && rta.eq(eta, self.engines) && ea.is_none())
+                || matches!(ean, AbiName::Deferred) =>
+            {
                 // if one address is empty, coerce to the other one
                 self.replace_expected_with_received(
                     received,
@@ -207,6 +249,36 @@ impl<'a> Unifier<'a> {
                 (vec![], vec![])
             }
 
+            // Every closure has its own type, so closures only unify with
+            // themselves.
+            (ref r @ TypeInfo::Closure { .. }, ref e @ TypeInfo::Closure { .. })
+                if r.eq(e, self.engines) =>
+            {
+                (vec![], vec![])
+            }
+
+            // Trait objects unify when they are of the same trait.
+            (ref r @ TypeInfo::TraitObject { .. }, ref e @ TypeInfo::TraitObject { .. })
+                if r.eq(e, self.engines) =>
+            {
+                (vec![], vec![])
+            }
+
+            // Const generics unify when their values are known to be equal,
+            // or when they are the same generic parameter. Parameters of
+            // different declarations may share a name, so they are told
+            // apart by the span of the declaring parameter.
+            (
+                ConstGeneric {
+                    name: rn,
+                    value: rv,
+                },
+                ConstGeneric {
+                    name: en,
+                    value: ev,
+                },
+            ) if rv == ev && (rv.is_some() || rn.span() == en.span()) => (vec![], vec![]),
+
             // When we don't know anything about either term, assume that
             // they match and make the one we know nothing about reference the
             // one we may know something about.
@@ -251,8 +323,8 @@ impl<'a> Unifier<'a> {
             // If no previous attempts to unify were successful, raise an error.
             (TypeInfo::ErrorRecovery, _) => (vec![], vec![]),
             (_, TypeInfo::ErrorRecovery) => (vec![], vec![]),
-            (r, e) => {
-                let (received, expected) = self.assign_args(r, e);
+            _ => {
+                let (received, expected) = self.assign_args(received, expected);
                 let errors = vec![TypeError::MismatchedType {
                     expected,
                     received,
@@ -345,6 +417,34 @@ impl<'a> Unifier<'a> {
         (warnings, vec![])
     }
 
+    /// Unifies the structs or enums `received` and `expected` using `unify`.
+    ///
+    /// A generic type may refer to itself through the type arguments of e.g.
+    /// a `Box`, so a pair of types which is already being unified is assumed
+    /// to unify, instead of being unified forever.
+    fn unify_decls(
+        &self,
+        received: TypeId,
+        expected: TypeId,
+        unify: impl FnOnce() -> (Vec<CompileWarning>, Vec<TypeError>),
+    ) -> (Vec<CompileWarning>, Vec<TypeError>) {
+        let engines = self.engines;
+        let received = engines.te().get(received);
+        let expected = engines.te().get(expected);
+        if self
+            .in_progress
+            .borrow()
+            .iter()
+            .any(|(r, e)| r.eq(&received, engines) && e.eq(&expected, engines))
+        {
+            return (vec![], vec![]);
+        }
+        self.in_progress.borrow_mut().push((received, expected));
+        let res = unify();
+        self.in_progress.borrow_mut().pop();
+        res
+    }
+
     fn unify_structs(
         &self,
         received: TypeId,
@@ -448,15 +548,33 @@ impl<'a> Unifier<'a> {
         received: TypeId,
         expected: TypeId,
         span: &Span,
-        r: TypeId,
-        e: TypeId,
+        r: (TypeId, Length),
+        e: (TypeId, Length),
     ) -> (Vec<CompileWarning>, Vec<TypeError>) {
-        let (warnings, new_errors) = self.unify(r, e, span);
+        let (r, rc) = r;
+        let (e, ec) = e;
+        let (mut warnings, new_errors) = self.unify(r, e, span);
+
+        // Lengths known upfront are compared directly, while lengths that
+        // refer to a const generic parameter are unified like types, which
+        // is what infers the value of the parameter.
+        let lengths_match = match (rc.type_id(), ec.type_id()) {
+            (None, None) => rc.val() == ec.val(),
+            _ => {
+                let (mut length_warnings, length_errors) = self.unify(
+                    rc.to_type_id(self.engines),
+                    ec.to_type_id(self.engines),
+                    span,
+                );
+                warnings.append(&mut length_warnings);
+                length_errors.is_empty()
+            }
+        };
 
         // If there was an error then we want to report the array types as mismatching, not
         // the elem types.
         let mut errors = vec![];
-        if !new_errors.is_empty() {
+        if !new_errors.is_empty() || !lengths_match {
             let (received, expected) = self.assign_args(received, expected);
             errors.push(TypeError::MismatchedType {
                 expected,
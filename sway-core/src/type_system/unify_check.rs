@@ -1,5 +1,5 @@
 use crate::{engine_threading::*, type_system::*};
-use sway_types::Spanned;
+use sway_types::{integer_bits::IntegerBits, Spanned};
 
 /// Helper struct to aid in type coercion.
 pub(super) struct UnifyCheck<'a> {
@@ -122,6 +122,9 @@ impl<'a> UnifyCheck<'a> {
             // any type can be coerced into generic
             (_, UnknownGeneric { .. }) => true,
 
+            // any length can be coerced into a const generic
+            (ConstGeneric { .. }, ConstGeneric { value: None, .. }) => true,
+
             (Unknown, _) => true,
             (_, Unknown) => true,
 
@@ -132,13 +135,21 @@ impl<'a> UnifyCheck<'a> {
             (Contract, Contract) => true,
             (RawUntypedPtr, RawUntypedPtr) => true,
             (RawUntypedSlice, RawUntypedSlice) => true,
-            (UnsignedInteger(_), UnsignedInteger(_)) => true,
+            (StringSlice, StringSlice) => true,
+            (l @ TraitObject { .. }, r @ TraitObject { .. }) => l.eq(&r, self.engines),
+            (UnsignedInteger(l), UnsignedInteger(r)) => {
+                (l == IntegerBits::V256) == (r == IntegerBits::V256)
+            }
             (Numeric, UnsignedInteger(_)) => true,
             (UnsignedInteger(_), Numeric) => true,
-            (Str(l), Str(r)) => l.val() == r.val(),
+            (Str(l), Str(r)) => l.eq(&r, self.engines),
 
             (Array(l0, l1), Array(r0, r1)) => {
-                self.check(l0.type_id, r0.type_id) && l1.val() == r1.val()
+                self.check(l0.type_id, r0.type_id)
+                    && match (l1.type_id(), r1.type_id()) {
+                        (None, None) => l1.val() == r1.val(),
+                        _ => self.check(l1.to_type_id(self.engines), r1.to_type_id(self.engines)),
+                    }
             }
             (Tuple(l_types), Tuple(r_types)) => {
                 let l_types = l_types.iter().map(|x| x.type_id).collect::<Vec<_>>();
@@ -234,21 +245,24 @@ impl<'a> UnifyCheck<'a> {
             }
 
             // For contract callers, they can be coerced if they have the same
-            // name and at least one has an address of `None`
+            // name and type arguments and at least one has an address of `None`
             (
                 ref r @ ContractCaller {
                     abi_name: ref ran,
+                    type_arguments: ref rta,
                     address: ref ra,
                 },
                 ref e @ ContractCaller {
                     abi_name: ref ean,
+                    type_arguments: ref eta,
                     address: ref ea,
                 },
             ) => {
+                let same_abi = ran == ean && rta.eq(eta, self.engines);
                 r.eq(e, self.engines)
-                    || (ran == ean && ra.is_none())
+                    || (same_abi && ra.is_none())
                     || matches!(ran, AbiName::Deferred)
-                    || (ran == ean && ea.is_none())
+                    || (same_abi && ea.is_none())
                     || matches!(ean, AbiName::Deferred)
             }
 
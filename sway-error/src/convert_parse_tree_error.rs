@@ -41,6 +41,8 @@ pub enum ConvertParseTreeError {
     U32LiteralOutOfRange { span: Span },
     #[error("u64 literal out of range")]
     U64LiteralOutOfRange { span: Span },
+    #[error("u256 literal out of range")]
+    U256LiteralOutOfRange { span: Span },
     #[error("signed integers are not supported")]
     SignedIntegersNotSupported { span: Span },
     #[error("ref variables are not supported")]
@@ -67,18 +69,18 @@ pub enum ConvertParseTreeError {
     PathsNotSupportedHere { span: Span },
     #[error("Fully specified types are not supported in this position. Try importing the type and referring to it here.")]
     FullySpecifiedTypesNotSupported { span: Span },
-    #[error("ContractCaller requires exactly one generic argument")]
+    #[error("ContractCaller requires an ABI as its first generic argument")]
     ContractCallerOneGenericArg { span: Span },
     #[error("ContractCaller requires a named type for its generic argument")]
     ContractCallerNamedTypeGenericArg { span: Span },
+    #[error("the type arguments of the ABI of a ContractCaller cannot be given both in the ABI path and as further generic arguments")]
+    ContractCallerAbiTypeArgsGivenTwice { span: Span },
     #[error("invalid argument for '{attribute}' attribute")]
     InvalidAttributeArgument { attribute: String, span: Span },
     #[error("cannot find type \"{ty_name}\" in this scope")]
     ConstrainedNonExistentType { ty_name: Ident, span: Span },
     #[error("__get_storage_key does not take arguments")]
     GetStorageKeyTooManyArgs { span: Span },
-    #[error("recursive types are not supported")]
-    RecursiveType { span: Span },
     #[error("enum variant \"{name}\" already declared")]
     DuplicateEnumVariant { name: Ident, span: Span },
     #[error("storage field \"{name}\" already declared")]
@@ -109,6 +111,16 @@ pub enum ConvertParseTreeError {
     DerefExprNotYetSupported { span: Span },
     #[error("Constant requires expression.")]
     ConstantRequiresExpression { span: Span },
+    #[error("Associated type requires a type.")]
+    AssociatedTypeRequiresType { span: Span },
+    #[error("Associated constants and types are not supported in ABIs.")]
+    AssociatedItemsInAbiNotSupported { span: Span },
+    #[error("Function types are only allowed as the types of function parameters.")]
+    FnTypeNotSupportedHere { span: Span },
+    #[error("Const generic parameters must be of type u64.")]
+    ConstGenericMustBeU64 { span: Span },
+    #[error("Const generic parameters cannot have trait constraints.")]
+    ConstGenericTraitConstraint { span: Span },
 }
 
 impl Spanned for ConvertParseTreeError {
@@ -133,6 +145,7 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::U16LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U32LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::U64LiteralOutOfRange { span } => span.clone(),
+            ConvertParseTreeError::U256LiteralOutOfRange { span } => span.clone(),
             ConvertParseTreeError::SignedIntegersNotSupported { span } => span.clone(),
             ConvertParseTreeError::RefVariablesNotSupported { span } => span.clone(),
             ConvertParseTreeError::LiteralPatternsNotSupportedHere { span } => span.clone(),
@@ -148,10 +161,10 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::FullySpecifiedTypesNotSupported { span } => span.clone(),
             ConvertParseTreeError::ContractCallerOneGenericArg { span } => span.clone(),
             ConvertParseTreeError::ContractCallerNamedTypeGenericArg { span } => span.clone(),
+            ConvertParseTreeError::ContractCallerAbiTypeArgsGivenTwice { span } => span.clone(),
             ConvertParseTreeError::InvalidAttributeArgument { span, .. } => span.clone(),
             ConvertParseTreeError::ConstrainedNonExistentType { span, .. } => span.clone(),
             ConvertParseTreeError::GetStorageKeyTooManyArgs { span, .. } => span.clone(),
-            ConvertParseTreeError::RecursiveType { span } => span.clone(),
             ConvertParseTreeError::DuplicateEnumVariant { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateStorageField { span, .. } => span.clone(),
             ConvertParseTreeError::DuplicateConfigurable { span, .. } => span.clone(),
@@ -167,6 +180,11 @@ impl Spanned for ConvertParseTreeError {
             ConvertParseTreeError::RefExprNotYetSupported { span } => span.clone(),
             ConvertParseTreeError::DerefExprNotYetSupported { span } => span.clone(),
             ConvertParseTreeError::ConstantRequiresExpression { span } => span.clone(),
+            ConvertParseTreeError::AssociatedTypeRequiresType { span } => span.clone(),
+            ConvertParseTreeError::AssociatedItemsInAbiNotSupported { span } => span.clone(),
+            ConvertParseTreeError::FnTypeNotSupportedHere { span } => span.clone(),
+            ConvertParseTreeError::ConstGenericMustBeU64 { span } => span.clone(),
+            ConvertParseTreeError::ConstGenericTraitConstraint { span } => span.clone(),
         }
     }
 }
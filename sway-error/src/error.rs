@@ -128,11 +128,39 @@ pub enum CompileError {
         interface_name: InterfaceName,
         span: Span,
     },
-    #[error("Functions are missing from this trait implementation: {missing_functions}")]
+    #[error("Functions are missing from this trait implementation: {}",
+        missing_functions.join("\n"))]
     MissingInterfaceSurfaceMethods {
-        missing_functions: String,
+        missing_functions: Vec<String>,
+        interface_name: InterfaceName,
+        span: Span,
+    },
+    #[error("Constant \"{name}\" is not a part of {interface_name}'s interface surface.")]
+    ConstantNotAPartOfInterfaceSurface {
+        name: Ident,
+        interface_name: InterfaceName,
         span: Span,
     },
+    #[error("Type \"{name}\" is not a part of {interface_name}'s interface surface.")]
+    TypeNotAPartOfInterfaceSurface {
+        name: Ident,
+        interface_name: InterfaceName,
+        span: Span,
+    },
+    #[error("Constants are missing from this trait implementation: {missing_constants}")]
+    MissingInterfaceSurfaceConstants {
+        missing_constants: String,
+        span: Span,
+    },
+    #[error("Associated types are missing from this trait implementation: {missing_types}")]
+    MissingInterfaceSurfaceTypes { missing_types: String, span: Span },
+    #[error("Associated types can only be declared in traits and trait implementations.")]
+    AssociatedTypeNotSupportedInImplSelf { span: Span },
+    #[error(
+        "Associated constant \"{name}\" has no value here. Associated constants without a \
+         default value can only be used once the trait has been implemented."
+    )]
+    AssociatedConstantWithoutValue { name: Ident, span: Span },
     #[error("Expected {} type {}, but instead found {}.", expected, if *expected == 1usize { "argument" } else { "arguments" }, given)]
     IncorrectNumberOfTypeArguments {
         given: usize,
@@ -356,6 +384,35 @@ pub enum CompileError {
     },
     #[error("The function \"{method_name}\" was called without parentheses. Try adding ().")]
     MissingParenthesesForFunction { span: Span, method_name: Ident },
+    #[error("\"{name}\" is of type \"{ty}\", which is not a closure, so it cannot be called.")]
+    NotAClosure { name: Ident, ty: String, span: Span },
+    #[error("\"{name}\" is not a const generic parameter, so it cannot be used as a length.")]
+    NotAConstGeneric { name: String, span: Span },
+    #[error("This closure is expected to take {expected} {} but it takes {received}.",
+        if *expected == 1usize { "parameter" } else { "parameters" },
+    )]
+    ClosureArityMismatch {
+        span: Span,
+        expected: usize,
+        received: usize,
+    },
+    #[error("This closure takes {parameters} {} but {arguments} {} supplied.",
+        if *parameters == 1usize { "parameter" } else { "parameters" },
+        if *arguments == 1usize { "argument was" } else { "arguments were" },
+    )]
+    ClosureCallArityMismatch {
+        span: Span,
+        parameters: usize,
+        arguments: usize,
+    },
+    #[error("Trait \"{trait_name}\" cannot be made into an object: {reason}")]
+    TraitNotObjectSafe {
+        trait_name: String,
+        reason: String,
+        span: Span,
+    },
+    #[error("Values of type \"{ty}\" cannot be made into a trait object.")]
+    InvalidTraitObjectSource { ty: String, span: Span },
     #[error("This type is invalid in a function selector. A contract ABI function selector must be a known sized type, not generic.")]
     InvalidAbiType { span: Span },
     #[error("This is a {actually_is}, not an ABI. An ABI cast requires a valid ABI to cast the address to.")]
@@ -365,6 +422,8 @@ pub enum CompileError {
     },
     #[error("An ABI can only be implemented for the `Contract` type, so this implementation of an ABI for type \"{ty}\" is invalid.")]
     ImplAbiForNonContract { span: Span, ty: String },
+    #[error("An implementation of the ABI \"{abi_name}\" cannot be generic. The type arguments of a generic ABI must be concrete types where it is implemented.")]
+    GenericImplAbi { abi_name: String, span: Span },
     #[error("Conflicting implementations of trait \"{trait_name}\" for type \"{type_implementing_for}\".")]
     ConflictingImplsForTraitAndType {
         trait_name: String,
@@ -392,24 +451,27 @@ pub enum CompileError {
         should_be: String,
         provided: String,
     },
-    #[error("Function {fn_name} is recursive, which is unsupported at this time.")]
-    RecursiveCall { fn_name: Ident, span: Span },
     #[error(
-        "Function {fn_name} is recursive via {call_chain}, which is unsupported at this time."
+        "Type {name} is recursive without indirection, which would give it an infinite size. \
+         Consider wrapping the recursive field in a `Box`."
     )]
-    RecursiveCallChain {
-        fn_name: Ident,
-        call_chain: String, // Pretty list of symbols, e.g., "a, b and c".
-        span: Span,
-    },
-    #[error("Type {name} is recursive, which is unsupported at this time.")]
     RecursiveType { name: Ident, span: Span },
-    #[error("Type {name} is recursive via {type_chain}, which is unsupported at this time.")]
+    #[error(
+        "Type {name} is recursive via {type_chain} without indirection, which would give it an \
+         infinite size. Consider wrapping one of the recursive fields in a `Box`."
+    )]
     RecursiveTypeChain {
         name: Ident,
         type_chain: String, // Pretty list of symbols, e.g., "a, b and c".
         span: Span,
     },
+    #[error("Generic type {name} may only refer to itself, and not to other recursive types.")]
+    RecursiveGenericType { name: Ident, span: Span },
+    #[error(
+        "Generic type {name} may only refer to itself with its own type parameters as the type \
+         arguments."
+    )]
+    RecursiveGenericTypeArguments { name: Ident, span: Span },
     #[error("The GM (get-metadata) opcode, when called from an external context, will cause the VM to panic.")]
     GMFromExternalContext { span: Span },
     #[error("The MINT opcode cannot be used in an external context.")]
@@ -439,6 +501,11 @@ pub enum CompileError {
         missing_patterns: String,
         span: Span,
     },
+    #[error("Refutable pattern in a binding. Missing patterns {missing_patterns}")]
+    RefutablePattern {
+        missing_patterns: String,
+        span: Span,
+    },
     #[error("Pattern does not mention {}: {}",
         if missing_fields.len() == 1 { "field" } else { "fields" },
         missing_fields.join(", "))]
@@ -539,10 +606,14 @@ pub enum CompileError {
     Lex { error: LexError },
     #[error("{}", error)]
     Parse { error: ParseError },
-    #[error("\"where\" clauses are not yet supported")]
-    WhereClauseNotYetSupported { span: Span },
     #[error("Could not evaluate initializer to a const declaration.")]
     NonConstantDeclValue { span: Span },
+    #[error(
+        "Loop did not terminate within {limit} iterations while being evaluated at compile time."
+    )]
+    ConstEvalLoopLimit { limit: usize, span: Span },
+    #[error("Function calls were nested deeper than {limit} levels while being evaluated at compile time.")]
+    ConstEvalCallDepthLimit { limit: usize, span: Span },
     #[error("Declaring storage in a {program_kind} is not allowed.")]
     StorageDeclarationInNonContract { program_kind: String, span: Span },
     #[error("Unsupported argument type to intrinsic \"{name}\". {hint}")]
@@ -582,6 +653,11 @@ pub enum CompileError {
             Consider converting it into a flat `raw_slice` first."
     )]
     NestedSliceReturnNotAllowedInMain { span: Span },
+    #[error(
+        "Returning a type containing `str` from `main()` is not allowed. \
+            Consider returning a flat `str` instead."
+    )]
+    NestedStringSliceReturnNotAllowedInMain { span: Span },
     #[error(
         "Register \"{name}\" is initialized and later reassigned which is not allowed. \
             Consider assigning to a different register inside the ASM block."
@@ -650,6 +726,12 @@ impl Spanned for CompileError {
             UnknownTrait { span, .. } => span.clone(),
             FunctionNotAPartOfInterfaceSurface { span, .. } => span.clone(),
             MissingInterfaceSurfaceMethods { span, .. } => span.clone(),
+            ConstantNotAPartOfInterfaceSurface { span, .. } => span.clone(),
+            TypeNotAPartOfInterfaceSurface { span, .. } => span.clone(),
+            MissingInterfaceSurfaceConstants { span, .. } => span.clone(),
+            MissingInterfaceSurfaceTypes { span, .. } => span.clone(),
+            AssociatedTypeNotSupportedInImplSelf { span } => span.clone(),
+            AssociatedConstantWithoutValue { span, .. } => span.clone(),
             IncorrectNumberOfTypeArguments { span, .. } => span.clone(),
             DoesNotTakeTypeArguments { span, .. } => span.clone(),
             DoesNotTakeTypeArgumentsAsPrefix { span, .. } => span.clone(),
@@ -698,19 +780,26 @@ impl Spanned for CompileError {
             TooManyArgumentsForFunction { span, .. } => span.clone(),
             TooFewArgumentsForFunction { span, .. } => span.clone(),
             MissingParenthesesForFunction { span, .. } => span.clone(),
+            NotAClosure { span, .. } => span.clone(),
+            NotAConstGeneric { span, .. } => span.clone(),
+            ClosureArityMismatch { span, .. } => span.clone(),
+            ClosureCallArityMismatch { span, .. } => span.clone(),
+            TraitNotObjectSafe { span, .. } => span.clone(),
+            InvalidTraitObjectSource { span, .. } => span.clone(),
             InvalidAbiType { span, .. } => span.clone(),
             NotAnAbi { span, .. } => span.clone(),
             ImplAbiForNonContract { span, .. } => span.clone(),
+            GenericImplAbi { span, .. } => span.clone(),
             ConflictingImplsForTraitAndType {
                 second_impl_span, ..
             } => second_impl_span.clone(),
             DuplicateDeclDefinedForType { span, .. } => span.clone(),
             IncorrectNumberOfInterfaceSurfaceFunctionParameters { span, .. } => span.clone(),
             ArgumentParameterTypeMismatch { span, .. } => span.clone(),
-            RecursiveCall { span, .. } => span.clone(),
-            RecursiveCallChain { span, .. } => span.clone(),
             RecursiveType { span, .. } => span.clone(),
             RecursiveTypeChain { span, .. } => span.clone(),
+            RecursiveGenericType { span, .. } => span.clone(),
+            RecursiveGenericTypeArguments { span, .. } => span.clone(),
             GMFromExternalContext { span, .. } => span.clone(),
             MintFromExternalContext { span, .. } => span.clone(),
             BurnFromExternalContext { span, .. } => span.clone(),
@@ -721,6 +810,7 @@ impl Spanned for CompileError {
             ShadowsOtherSymbol { name } => name.span(),
             GenericShadowsGeneric { name } => name.span(),
             MatchExpressionNonExhaustive { span, .. } => span.clone(),
+            RefutablePattern { span, .. } => span.clone(),
             MatchStructPatternMissingFields { span, .. } => span.clone(),
             NotAnEnum { span, .. } => span.clone(),
             StorageAccessMismatch { span, .. } => span.clone(),
@@ -754,12 +844,13 @@ impl Spanned for CompileError {
             UnexpectedDeclaration { span, .. } => span.clone(),
             ContractAddressMustBeKnown { span, .. } => span.clone(),
             ConvertParseTree { error } => error.span(),
-            WhereClauseNotYetSupported { span, .. } => span.clone(),
             Lex { error } => error.span(),
             Parse { error } => error.span.clone(),
             EnumNotFound { span, .. } => span.clone(),
             TupleIndexOutOfBounds { span, .. } => span.clone(),
             NonConstantDeclValue { span } => span.clone(),
+            ConstEvalLoopLimit { span, .. } => span.clone(),
+            ConstEvalCallDepthLimit { span, .. } => span.clone(),
             StorageDeclarationInNonContract { span, .. } => span.clone(),
             IntrinsicUnsupportedArgType { span, .. } => span.clone(),
             IntrinsicIncorrectNumArgs { span, .. } => span.clone(),
@@ -772,6 +863,7 @@ impl Spanned for CompileError {
             RefMutableNotAllowedInMain { span, .. } => span.clone(),
             PointerReturnNotAllowedInMain { span } => span.clone(),
             NestedSliceReturnNotAllowedInMain { span } => span.clone(),
+            NestedStringSliceReturnNotAllowedInMain { span } => span.clone(),
             InitializedRegisterReassignment { span, .. } => span.clone(),
             DisallowedControlFlowInstruction { span, .. } => span.clone(),
             CallingPrivateLibraryMethod { span, .. } => span.clone(),
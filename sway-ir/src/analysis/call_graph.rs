@@ -14,9 +14,15 @@ pub fn build_call_graph(ctx: &Context, functions: &[Function]) -> CallGraph {
         let entry = res.entry(*function);
         let entry = entry.or_insert_with(FxHashSet::default);
         for (_, inst) in function.instruction_iter(ctx) {
-            if let ValueDatum::Instruction(Instruction::Call(callee, _)) = ctx.values[inst.0].value
-            {
-                entry.insert(callee);
+            match &ctx.values[inst.0].value {
+                ValueDatum::Instruction(Instruction::Call(callee, _)) => {
+                    entry.insert(*callee);
+                }
+                // Functions in a vtable are called indirectly.
+                ValueDatum::Instruction(Instruction::VTable(callees)) => {
+                    entry.extend(callees.iter().copied());
+                }
+                _ => (),
             }
         }
     }
@@ -51,3 +57,27 @@ pub fn callee_first_order(cg: &CallGraph) -> Vec<Function> {
 
     res
 }
+
+/// Given a call graph, return the functions which may call themselves, either directly or via
+/// other functions.
+pub fn recursive_functions(cg: &CallGraph) -> FxHashSet<Function> {
+    fn reaches(
+        cg: &CallGraph,
+        visited: &mut FxHashSet<Function>,
+        node: Function,
+        target: Function,
+    ) -> bool {
+        if !visited.insert(node) {
+            return false;
+        }
+        cg.get(&node).map_or(false, |callees| {
+            callees
+                .iter()
+                .any(|callee| *callee == target || reaches(cg, visited, *callee, target))
+        })
+    }
+    cg.keys()
+        .filter(|node| reaches(cg, &mut FxHashSet::default(), **node, **node))
+        .copied()
+        .collect()
+}
@@ -16,6 +16,8 @@ pub enum ConstantValue {
     Unit,
     Bool(bool),
     Uint(u64),
+    /// A `u256` value as big-endian bytes.
+    U256([u8; 32]),
     B256([u8; 32]),
     String(Vec<u8>),
     Array(Vec<Constant>),
@@ -44,6 +46,13 @@ impl Constant {
         }
     }
 
+    pub fn new_uint256(context: &Context, bytes: [u8; 32]) -> Self {
+        Constant {
+            ty: Type::get_uint256(context),
+            value: ConstantValue::U256(bytes),
+        }
+    }
+
     pub fn new_b256(context: &Context, bytes: [u8; 32]) -> Self {
         Constant {
             ty: Type::get_b256(context),
@@ -94,6 +103,11 @@ impl Constant {
         Value::new_constant(context, new_const)
     }
 
+    pub fn get_uint256(context: &mut Context, value: [u8; 32]) -> Value {
+        let new_const = Constant::new_uint256(context, value);
+        Value::new_constant(context, new_const)
+    }
+
     pub fn get_b256(context: &mut Context, value: [u8; 32]) -> Value {
         let new_const = Constant::new_b256(context, value);
         Value::new_constant(context, new_const)
@@ -125,6 +139,7 @@ impl Constant {
                 (ConstantValue::Unit, ConstantValue::Unit) => true,
                 (ConstantValue::Bool(l0), ConstantValue::Bool(r0)) => l0 == r0,
                 (ConstantValue::Uint(l0), ConstantValue::Uint(r0)) => l0 == r0,
+                (ConstantValue::U256(l0), ConstantValue::U256(r0)) => l0 == r0,
                 (ConstantValue::B256(l0), ConstantValue::B256(r0)) => l0 == r0,
                 (ConstantValue::String(l0), ConstantValue::String(r0)) => l0 == r0,
                 (ConstantValue::Array(l0), ConstantValue::Array(r0))
@@ -33,6 +33,7 @@ pub enum IrError {
     VerifyBranchParamsMismatch,
     VerifyCallArgTypeMismatch(String),
     VerifyCallToMissingFunction(String),
+    VerifyCallIndirectNonIntegerAddress(String),
     VerifyCmpBadTypes(String, String),
     VerifyCmpTypeMismatch(String, String),
     VerifyCmpUnknownTypes,
@@ -193,6 +194,13 @@ impl fmt::Display for IrError {
                     "Verification failed: Call to invalid function '{callee}'."
                 )
             }
+            IrError::VerifyCallIndirectNonIntegerAddress(ty) => {
+                write!(
+                    f,
+                    "Verification failed: Indirect call to an address of type {ty} \
+                    which is not a u64."
+                )
+            }
             IrError::VerifyCmpBadTypes(lhs_ty, rhs_ty) => {
                 write!(
                     f,
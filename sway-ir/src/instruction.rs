@@ -45,6 +45,13 @@ pub enum Instruction {
     Branch(BranchToWithArgs),
     /// A function call with a list of arguments.
     Call(Function, Vec<Value>),
+    /// A call of the function at the code address `fn_addr`, read from a vtable, with a list of
+    /// arguments.  The callee isn't known statically so its return type is given explicitly.
+    CallIndirect {
+        fn_addr: Value,
+        args: Vec<Value>,
+        return_type: Type,
+    },
     /// Temporary! Cast between 'pointers' to reference types.  At this intermediate stage, where
     /// we have removed the old `get_ptr` instruction, we have no way to do the casting and
     /// offsetting it did, which was used almost exclusively by storage accesses.  When we
@@ -117,6 +124,9 @@ pub enum Instruction {
     Ret(Value, Type),
     /// Write a value to a memory pointer.
     Store { dst_val: Value, stored_val: Value },
+    /// The address of a read-only table holding the code addresses of a list of functions, in
+    /// order, to be called via `CallIndirect`.
+    VTable(Vec<Function>),
 }
 
 #[derive(Debug, Clone, DebugWithContext)]
@@ -241,6 +251,7 @@ impl Instruction {
             Instruction::BinaryOp { arg1, .. } => arg1.get_type(context),
             Instruction::BitCast(_, ty) => Some(*ty),
             Instruction::Call(function, _) => Some(context.functions[function.0].return_type),
+            Instruction::CallIndirect { return_type, .. } => Some(*return_type),
             Instruction::CastPtr(_val, ty, _offs) => Some(*ty),
             Instruction::Cmp(..) => Some(Type::get_bool(context)),
             Instruction::ContractCall { return_type, .. } => Some(*return_type),
@@ -291,6 +302,7 @@ impl Instruction {
             }
             Instruction::MemCopy { .. } => Some(Type::get_unit(context)),
             Instruction::Store { .. } => Some(Type::get_unit(context)),
+            Instruction::VTable(_) => Some(Type::get_uint64(context)),
 
             // No-op is also no-type.
             Instruction::Nop => None,
@@ -301,6 +313,8 @@ impl Instruction {
     pub fn get_aggregate(&self, context: &Context) -> Option<Type> {
         let ty = match self {
             Instruction::Call(func, _args) => Some(context.functions[func.0].return_type),
+            Instruction::CallIndirect { return_type, .. } => Some(*return_type),
+            Instruction::ContractCall { return_type, .. } => Some(*return_type),
             Instruction::GetLocal(local_var) => Some(local_var.get_type(context)),
             Instruction::ExtractElement { ty, .. } => ty.get_array_elem_type(context),
             Instruction::ExtractValue { ty, indices, .. } =>
@@ -322,6 +336,9 @@ impl Instruction {
             Instruction::BinaryOp { op: _, arg1, arg2 } => vec![*arg1, *arg2],
             Instruction::Branch(BranchToWithArgs { args, .. }) => args.clone(),
             Instruction::Call(_, vs) => vs.clone(),
+            Instruction::CallIndirect { fn_addr, args, .. } => std::iter::once(*fn_addr)
+                .chain(args.iter().cloned())
+                .collect(),
             Instruction::CastPtr(val, _ty, _offs) => vec![*val],
             Instruction::Cmp(_, lhs, rhs) => vec![*lhs, *rhs],
             Instruction::ConditionalBranch {
@@ -415,6 +432,7 @@ impl Instruction {
             Instruction::Load(v) => vec![*v],
             Instruction::Nop => vec![],
             Instruction::Ret(v, _) => vec![*v],
+            Instruction::VTable(_) => vec![],
             Instruction::Store {
                 dst_val,
                 stored_val,
@@ -448,6 +466,10 @@ impl Instruction {
                 block.args.iter_mut().for_each(replace);
             }
             Instruction::Call(_, args) => args.iter_mut().for_each(replace),
+            Instruction::CallIndirect { fn_addr, args, .. } => {
+                replace(fn_addr);
+                args.iter_mut().for_each(replace);
+            }
             Instruction::CastPtr(val, _ty, _offs) => replace(val),
             Instruction::Cmp(_, lhs_val, rhs_val) => {
                 replace(lhs_val);
@@ -566,6 +588,7 @@ impl Instruction {
             Instruction::Store { stored_val, .. } => {
                 replace(stored_val);
             }
+            Instruction::VTable(_) => (),
         }
     }
 
@@ -573,6 +596,7 @@ impl Instruction {
         match self {
             Instruction::AsmBlock(_, _)
                 | Instruction::Call(..)
+                | Instruction::CallIndirect { .. }
                 | Instruction::ContractCall { .. }
                 | Instruction::FuelVm(FuelVmInstruction::Log { .. })
                 | Instruction::FuelVm(FuelVmInstruction::Smo { .. })
@@ -604,6 +628,7 @@ impl Instruction {
                 | Instruction::Branch(_)
                 | Instruction::ConditionalBranch { .. }
                 | Instruction::Ret(..)
+                | Instruction::VTable(_)
                 | Instruction::Nop => false,
         }
     }
@@ -745,6 +770,17 @@ impl<'a> InstructionInserter<'a> {
         make_instruction!(self, Instruction::Call(function, args.to_vec()))
     }
 
+    pub fn call_indirect(self, fn_addr: Value, args: &[Value], return_type: Type) -> Value {
+        make_instruction!(
+            self,
+            Instruction::CallIndirect {
+                fn_addr,
+                args: args.to_vec(),
+                return_type,
+            }
+        )
+    }
+
     pub fn cast_ptr(self, val: Value, ty: Type, offs: u64) -> Value {
         make_instruction!(self, Instruction::CastPtr(val, ty, offs))
     }
@@ -1009,4 +1045,8 @@ impl<'a> InstructionInserter<'a> {
             }
         )
     }
+
+    pub fn vtable(self, functions: Vec<Function>) -> Value {
+        make_instruction!(self, Instruction::VTable(functions))
+    }
 }
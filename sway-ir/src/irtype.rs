@@ -19,12 +19,14 @@ pub enum TypeContent {
     Unit,
     Bool,
     Uint(u8),
+    U256,
     B256,
     String(u64),
     Array(Type, u64),
     Union(Vec<Type>),
     Struct(Vec<Type>),
     Slice,
+    StringSlice,
 }
 
 impl Type {
@@ -51,8 +53,10 @@ impl Type {
         Self::get_or_create_unique_type(context, TypeContent::Uint(8));
         Self::get_or_create_unique_type(context, TypeContent::Uint(32));
         Self::get_or_create_unique_type(context, TypeContent::Uint(64));
+        Self::get_or_create_unique_type(context, TypeContent::U256);
         Self::get_or_create_unique_type(context, TypeContent::B256);
         Self::get_or_create_unique_type(context, TypeContent::Slice);
+        Self::get_or_create_unique_type(context, TypeContent::StringSlice);
     }
 
     /// Get the content for this Type.
@@ -90,6 +94,11 @@ impl Type {
         Self::get_type(context, &TypeContent::Uint(64)).expect("create_basic_types not called")
     }
 
+    /// Get u256 type
+    pub fn get_uint256(context: &Context) -> Type {
+        Self::get_type(context, &TypeContent::U256).expect("create_basic_types not called")
+    }
+
     /// Get unsigned integer type
     pub fn get_uint(context: &Context, width: u8) -> Option<Type> {
         Self::get_type(context, &TypeContent::Uint(width))
@@ -125,6 +134,11 @@ impl Type {
         Self::get_type(context, &TypeContent::Slice).expect("create_basic_types not called")
     }
 
+    /// Get string slice type
+    pub fn get_string_slice(context: &mut Context) -> Type {
+        Self::get_type(context, &TypeContent::StringSlice).expect("create_basic_types not called")
+    }
+
     /// Return a string representation of type, used for printing.
     pub fn as_string(&self, context: &Context) -> String {
         let sep_types_str = |agg_content: &Vec<Type>, sep: &str| {
@@ -139,6 +153,7 @@ impl Type {
             TypeContent::Unit => "()".into(),
             TypeContent::Bool => "bool".into(),
             TypeContent::Uint(nbits) => format!("u{nbits}"),
+            TypeContent::U256 => "u256".into(),
             TypeContent::B256 => "b256".into(),
             TypeContent::String(n) => format!("string<{n}>"),
             TypeContent::Array(ty, cnt) => {
@@ -151,6 +166,7 @@ impl Type {
                 format!("{{ {} }}", sep_types_str(agg, ", "))
             }
             TypeContent::Slice => "slice".into(),
+            TypeContent::StringSlice => "str".into(),
         }
     }
 
@@ -161,6 +177,7 @@ impl Type {
             (TypeContent::Unit, TypeContent::Unit) => true,
             (TypeContent::Bool, TypeContent::Bool) => true,
             (TypeContent::Uint(l), TypeContent::Uint(r)) => l == r,
+            (TypeContent::U256, TypeContent::U256) => true,
             (TypeContent::B256, TypeContent::B256) => true,
             (TypeContent::String(l), TypeContent::String(r)) => l == r,
 
@@ -176,6 +193,7 @@ impl Type {
             (TypeContent::Union(l), _) => l.iter().any(|field_ty| other.eq(context, field_ty)),
 
             (TypeContent::Slice, TypeContent::Slice) => true,
+            (TypeContent::StringSlice, TypeContent::StringSlice) => true,
             _ => false,
         }
     }
@@ -215,6 +233,11 @@ impl Type {
         matches!(*self.get_content(context), TypeContent::Uint(width_) if width == width_)
     }
 
+    /// Is u256 type
+    pub fn is_uint256(&self, context: &Context) -> bool {
+        matches!(*self.get_content(context), TypeContent::U256)
+    }
+
     /// Is B256 type
     pub fn is_b256(&self, context: &Context) -> bool {
         matches!(*self.get_content(context), TypeContent::B256)
@@ -245,6 +268,11 @@ impl Type {
         matches!(*self.get_content(context), TypeContent::Slice)
     }
 
+    /// Returns true if this is a string slice type.
+    pub fn is_string_slice(&self, context: &Context) -> bool {
+        matches!(*self.get_content(context), TypeContent::StringSlice)
+    }
+
     /// Get width of an integer type.
     pub fn get_uint_width(&self, context: &Context) -> Option<u8> {
         if let TypeContent::Uint(width) = self.get_content(context) {
@@ -114,7 +114,8 @@ pub fn func_dce(
         .function_iter(context)
         .filter(|func| func.is_entry(context))
         .collect::<Vec<_>>();
-    // Recursively find all the functions called by an entry function.
+    // Recursively find all the functions called by an entry function.  Functions in a vtable may
+    // be called indirectly, so they are treated as called too.
     fn grow_called_function_set(
         context: &Context,
         caller: Function,
@@ -124,14 +125,13 @@ pub fn func_dce(
             // We haven't seen caller before.  Iterate for all that it calls.
             for func in caller
                 .instruction_iter(context)
-                .filter_map(|(_block, ins_value)| {
-                    ins_value
-                        .get_instruction(context)
-                        .and_then(|ins| match ins {
-                            Instruction::Call(f, _args) => Some(f),
-                            _otherwise => None,
-                        })
-                })
+                .flat_map(
+                    |(_block, ins_value)| match ins_value.get_instruction(context) {
+                        Some(Instruction::Call(f, _args)) => std::slice::from_ref(f),
+                        Some(Instruction::VTable(fs)) => fs.as_slice(),
+                        _otherwise => &[],
+                    },
+                )
             {
                 grow_called_function_set(context, *func, called_set);
             }
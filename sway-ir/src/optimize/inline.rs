@@ -114,11 +114,15 @@ pub fn inline_in_predicate_module(
         call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
 
     let functions = call_graph::callee_first_order(&cg);
+    let recursive_fns = call_graph::recursive_functions(&cg);
 
     let mut modified = false;
 
     for function in functions {
-        modified |= inline_all_function_calls(context, &function)?;
+        // Recursive functions can't be inlined, they'd only ever call themselves again.
+        modified |= inline_some_function_calls(context, &function, |_, callee, _| {
+            !recursive_fns.contains(callee)
+        })?;
     }
     Ok(modified)
 }
@@ -144,7 +148,16 @@ pub fn inline_in_non_predicate_module(
                 counts
             });
 
+    let cg =
+        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
+    let recursive_fns = call_graph::recursive_functions(&cg);
+
     let inline_heuristic = |ctx: &Context, func: &Function, _call_site: &Value| {
+        // Recursive functions are never inlined, they'd only ever call themselves again.
+        if recursive_fns.contains(func) {
+            return false;
+        }
+
         let attributed_inline = metadata_to_inline(ctx, func.get_metadata(ctx));
         match attributed_inline {
             Some(Inline::Always) => {
@@ -191,8 +204,6 @@ pub fn inline_in_non_predicate_module(
         false
     };
 
-    let cg =
-        call_graph::build_call_graph(context, &module.function_iter(context).collect::<Vec<_>>());
     let functions = call_graph::callee_first_order(&cg);
     let mut modified = false;
 
@@ -532,6 +543,18 @@ fn inline_instruction(
                     .collect::<Vec<Value>>()
                     .as_slice(),
             ),
+            Instruction::CallIndirect {
+                fn_addr,
+                args,
+                return_type,
+            } => new_block.ins(context).call_indirect(
+                map_value(fn_addr),
+                args.iter()
+                    .map(|old_val: &Value| map_value(*old_val))
+                    .collect::<Vec<Value>>()
+                    .as_slice(),
+                return_type,
+            ),
             Instruction::CastPtr(val, ty, offs) => {
                 new_block.ins(context).cast_ptr(map_value(val), ty, offs)
             }
@@ -683,6 +706,7 @@ fn inline_instruction(
             } => new_block
                 .ins(context)
                 .store(map_value(dst_val), map_value(stored_val)),
+            Instruction::VTable(functions) => new_block.ins(context).vtable(functions),
         }
         .add_metadatum(context, metadata);
 
@@ -169,6 +169,7 @@ mod ir_builder {
                 / op_branch()
                 / op_bitcast()
                 / op_binary()
+                / op_call_indirect()
                 / op_call()
                 / op_cast_ptr()
                 / op_cbr()
@@ -196,6 +197,7 @@ mod ir_builder {
                 / op_state_store_quad_word()
                 / op_state_store_word()
                 / op_store()
+                / op_vtable()
 
             rule op_addr_of() -> IrAstOperation
                 = "addr_of" _ val:id() {
@@ -235,6 +237,11 @@ mod ir_builder {
                     IrAstOperation::Call(callee, args)
                 }
 
+            rule op_call_indirect() -> IrAstOperation
+                = "call_indirect" _ ty:ast_ty() fn_addr:id() "(" _ args:(id() ** comma()) ")" _ {
+                    IrAstOperation::CallIndirect(ty, fn_addr, args)
+                }
+
             rule op_cast_ptr() -> IrAstOperation
                 = "cast_ptr" _ val:id() comma() ty:ast_ty() comma() offs:decimal() {
                     IrAstOperation::CastPtr(val, ty, offs)
@@ -375,6 +382,11 @@ mod ir_builder {
                     IrAstOperation::Store(val, dst)
                 }
 
+            rule op_vtable() -> IrAstOperation
+                = "vtable" _ "[" _ fns:(id() ** comma()) "]" _ {
+                    IrAstOperation::VTable(fns)
+                }
+
             rule cmp_pred() -> Predicate
                 = "eq" _ { Predicate::Equal }
                 / "gt" _ { Predicate::GreaterThan }
@@ -495,8 +507,11 @@ mod ir_builder {
                 = ("unit" / "()") _ { IrAstTy::Unit }
                 / "bool" _ { IrAstTy::Bool }
                 / "u64" _ { IrAstTy::U64 }
+                / "u256" _ { IrAstTy::U256 }
                 / "b256" _ { IrAstTy::B256 }
                 / "string" _ "<" _ sz:decimal() ">" _ { IrAstTy::String(sz) }
+                / "slice" !id_char() _ { IrAstTy::Slice }
+                / "str" !id_char() _ { IrAstTy::StringSlice }
                 / array_ty()
                 / struct_ty()
                 / union_ty()
@@ -677,6 +692,7 @@ mod ir_builder {
         BinaryOp(BinaryOpKind, String, String),
         Br(String, Vec<String>),
         Call(String, Vec<String>),
+        CallIndirect(IrAstTy, String, Vec<String>),
         CastPtr(String, IrAstTy, u64),
         Cbr(String, String, Vec<String>, String, Vec<String>),
         Cmp(Predicate, String, String),
@@ -704,6 +720,7 @@ mod ir_builder {
         StateStoreQuadWord(String, String, String),
         StateStoreWord(String, String),
         Store(String, String),
+        VTable(Vec<String>),
     }
 
     #[derive(Debug)]
@@ -773,6 +790,10 @@ mod ir_builder {
         }
 
         fn as_constant(&self, context: &mut Context, val_ty: IrAstTy) -> Constant {
+            // A `u256` constant is written as a 32 byte hex value, same as a `b256`.
+            if let (IrAstTy::U256, IrAstConstValue::B256(bs)) = (&val_ty, self) {
+                return Constant::new_uint256(context, *bs);
+            }
             Constant {
                 ty: val_ty.to_ir_type(context),
                 value: self.as_constant_value(context),
@@ -784,6 +805,9 @@ mod ir_builder {
                 IrAstConstValue::Undef(_) => unreachable!("Can't convert 'undef' to a value."),
                 IrAstConstValue::Unit => Constant::get_unit(context),
                 IrAstConstValue::Bool(b) => Constant::get_bool(context, *b),
+                IrAstConstValue::B256(bs) if matches!(val_ty, IrAstTy::U256) => {
+                    Constant::get_uint256(context, *bs)
+                }
                 IrAstConstValue::B256(bs) => Constant::get_b256(context, *bs),
                 IrAstConstValue::Number(n) => Constant::get_uint(context, 64, *n),
                 IrAstConstValue::String(s) => Constant::get_string(context, s.clone()),
@@ -804,11 +828,14 @@ mod ir_builder {
         Unit,
         Bool,
         U64,
+        U256,
         B256,
         String(u64),
         Array(Box<IrAstTy>, u64),
         Union(Vec<IrAstTy>),
         Struct(Vec<IrAstTy>),
+        Slice,
+        StringSlice,
     }
 
     impl IrAstTy {
@@ -817,6 +844,7 @@ mod ir_builder {
                 IrAstTy::Unit => Type::get_unit(context),
                 IrAstTy::Bool => Type::get_bool(context),
                 IrAstTy::U64 => Type::get_uint64(context),
+                IrAstTy::U256 => Type::get_uint256(context),
                 IrAstTy::B256 => Type::get_b256(context),
                 IrAstTy::String(n) => Type::new_string(context, *n),
                 IrAstTy::Array(el_ty, count) => {
@@ -831,6 +859,8 @@ mod ir_builder {
                     let tys = tys.iter().map(|ty| ty.to_ir_type(context)).collect();
                     Type::new_struct(context, tys)
                 }
+                IrAstTy::Slice => Type::get_slice(context),
+                IrAstTy::StringSlice => Type::get_string_slice(context),
             }
         }
     }
@@ -862,6 +892,7 @@ mod ir_builder {
             configs_map: build_configs_map(&mut ctx, &mut module, ir_ast_mod.configs, &md_map),
             md_map,
             unresolved_calls: Vec::new(),
+            unresolved_vtables: Vec::new(),
         };
 
         for fn_decl in ir_ast_mod.fn_decls {
@@ -877,6 +908,7 @@ mod ir_builder {
         configs_map: HashMap<String, Value>,
         md_map: HashMap<MdIdxRef, MetadataIndex>,
         unresolved_calls: Vec<PendingCall>,
+        unresolved_vtables: Vec<PendingVTable>,
     }
 
     struct PendingCall {
@@ -884,6 +916,11 @@ mod ir_builder {
         callee: String,
     }
 
+    struct PendingVTable {
+        vtable_val: Value,
+        functions: Vec<String>,
+    }
+
     impl IrBuilder {
         fn add_fn_decl(
             &mut self,
@@ -1090,6 +1127,21 @@ mod ir_builder {
                         self.unresolved_calls.push(PendingCall { call_val, callee });
                         call_val
                     }
+                    IrAstOperation::CallIndirect(ty, fn_addr, args) => {
+                        let ir_ty = ty.to_ir_type(context);
+                        block
+                            .ins(context)
+                            .call_indirect(
+                                *val_map.get(&fn_addr).unwrap(),
+                                &args
+                                    .iter()
+                                    .map(|arg_name| val_map.get(arg_name).unwrap())
+                                    .cloned()
+                                    .collect::<Vec<Value>>(),
+                                ir_ty,
+                            )
+                            .add_metadatum(context, opt_metadata)
+                    }
                     IrAstOperation::CastPtr(val, ty, offs) => {
                         let ir_ty = ty.to_ir_type(context);
                         block
@@ -1320,6 +1372,18 @@ mod ir_builder {
                             *val_map.get(&stored_val_name).unwrap(),
                         )
                         .add_metadatum(context, opt_metadata),
+                    IrAstOperation::VTable(functions) => {
+                        // Like calls, the functions are resolved in a second pass.
+                        let vtable_val = block
+                            .ins(context)
+                            .vtable(Vec::new())
+                            .add_metadatum(context, opt_metadata);
+                        self.unresolved_vtables.push(PendingVTable {
+                            vtable_val,
+                            functions,
+                        });
+                        vtable_val
+                    }
                 };
                 ins.value_name.map(|vn| val_map.insert(vn, ins_val));
             }
@@ -1350,6 +1414,28 @@ mod ir_builder {
                     *dummy_func = call_func;
                 }
             }
+
+            for pending_vtable in self.unresolved_vtables {
+                let vtable_funcs = pending_vtable
+                    .functions
+                    .iter()
+                    .map(|name| {
+                        context
+                            .functions
+                            .iter()
+                            .find_map(|(idx, content)| {
+                                (&content.name == name).then_some(Function(idx))
+                            })
+                            .unwrap()
+                    })
+                    .collect();
+
+                if let Some(Instruction::VTable(dummy_funcs)) =
+                    pending_vtable.vtable_val.get_instruction_mut(context)
+                {
+                    *dummy_funcs = vtable_funcs;
+                }
+            }
             Ok(())
         }
     }
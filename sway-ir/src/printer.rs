@@ -474,6 +474,29 @@ fn instruction_to_doc<'a>(
                     ))
                     .append(md_namer.md_idx_to_doc(context, metadata)),
                 )),
+            Instruction::CallIndirect {
+                fn_addr,
+                args,
+                return_type,
+            } => args
+                .iter()
+                .fold(Doc::Empty, |doc, arg_val| {
+                    doc.append(maybe_constant_to_doc(context, md_namer, namer, arg_val))
+                })
+                .append(Doc::line(
+                    Doc::text(format!(
+                        "{} = call_indirect {} {}",
+                        namer.name(context, ins_value),
+                        return_type.as_string(context),
+                        namer.name(context, fn_addr),
+                    ))
+                    .append(Doc::in_parens_comma_sep(
+                        args.iter()
+                            .map(|arg_val| Doc::text(namer.name(context, arg_val)))
+                            .collect(),
+                    ))
+                    .append(md_namer.md_idx_to_doc(context, metadata)),
+                )),
             Instruction::CastPtr(val, ty, offs) => Doc::line(
                 Doc::text(format!(
                     "{} = cast_ptr {}, {}, {offs}",
@@ -866,6 +889,18 @@ fn instruction_to_doc<'a>(
                 ))
                 .append(md_namer.md_idx_to_doc(context, metadata)),
             )),
+            Instruction::VTable(functions) => Doc::line(
+                Doc::text(format!(
+                    "{} = vtable [{}]",
+                    namer.name(context, ins_value),
+                    functions
+                        .iter()
+                        .map(|function| context.functions[function.0].name.clone())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ))
+                .append(md_namer.md_idx_to_doc(context, metadata)),
+            ),
         }
     } else {
         unreachable!("Unexpected non instruction for block contents.")
@@ -961,6 +996,13 @@ impl Constant {
             ConstantValue::Unit => "unit ()".into(),
             ConstantValue::Bool(b) => format!("bool {}", if *b { "true" } else { "false" }),
             ConstantValue::Uint(v) => format!("{} {}", self.ty.as_string(context), v),
+            ConstantValue::U256(bs) => format!(
+                "u256 0x{}",
+                bs.iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<Vec<String>>()
+                    .concat()
+            ),
             ConstantValue::B256(bs) => format!(
                 "b256 0x{}",
                 bs.iter()
@@ -168,6 +168,9 @@ impl<'a> InstructionVerifier<'a> {
                     }
                     Instruction::Branch(block) => self.verify_br(block)?,
                     Instruction::Call(func, args) => self.verify_call(func, args)?,
+                    Instruction::CallIndirect { fn_addr, args, .. } => {
+                        self.verify_call_indirect(fn_addr, args)?
+                    }
                     Instruction::CastPtr(val, ty, _offs) => self.verify_cast_ptr(val, ty)?,
                     Instruction::Cmp(pred, lhs_value, rhs_value) => {
                         self.verify_cmp(pred, lhs_value, rhs_value)?
@@ -270,6 +273,7 @@ impl<'a> InstructionVerifier<'a> {
                         dst_val,
                         stored_val,
                     } => self.verify_store(dst_val, stored_val)?,
+                    Instruction::VTable(functions) => self.verify_vtable(functions)?,
                 };
 
                 // Verify the instruction metadata too.
@@ -384,6 +388,23 @@ impl<'a> InstructionVerifier<'a> {
         Ok(())
     }
 
+    fn verify_call_indirect(&self, fn_addr: &Value, args: &[Value]) -> Result<(), IrError> {
+        // The callee is unknown so only the address and the presence of argument types can be
+        // checked.
+        let addr_ty = fn_addr
+            .get_type(self.context)
+            .ok_or(IrError::VerifyUntypedValuePassedToFunction)?;
+        if !addr_ty.is_uint64(self.context) {
+            return Err(IrError::VerifyCallIndirectNonIntegerAddress(
+                addr_ty.as_string(self.context),
+            ));
+        }
+        if args.iter().any(|arg| arg.get_type(self.context).is_none()) {
+            return Err(IrError::VerifyUntypedValuePassedToFunction);
+        }
+        Ok(())
+    }
+
     fn verify_cast_ptr(&self, val: &Value, ty: &Type) -> Result<(), IrError> {
         let non_pointer_type = |ty: &Type, context: &Context| {
             ty.is_unit(context) | ty.is_bool(context) | ty.is_uint(context)
@@ -654,6 +675,18 @@ impl<'a> InstructionVerifier<'a> {
         }
     }
 
+    fn verify_vtable(&self, functions: &[Function]) -> Result<(), IrError> {
+        match functions
+            .iter()
+            .find(|function| !self.cur_module.functions.contains(function))
+        {
+            Some(function) => Err(IrError::VerifyCallToMissingFunction(
+                self.context.functions[function.0].name.clone(),
+            )),
+            None => Ok(()),
+        }
+    }
+
     fn verify_int_to_ptr(&self, value: &Value, ty: &Type) -> Result<(), IrError> {
         // We want the source value to be an integer and the destination type to be a reference
         // type.
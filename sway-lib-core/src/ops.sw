@@ -30,6 +30,12 @@ impl Add for u8 {
     }
 }
 
+impl Add for u256 {
+    fn add(self, other: Self) -> Self {
+        __add(self, other)
+    }
+}
+
 pub trait Subtract {
     fn subtract(self, other: Self) -> Self;
 }
@@ -58,6 +64,12 @@ impl Subtract for u8 {
     }
 }
 
+impl Subtract for u256 {
+    fn subtract(self, other: Self) -> Self {
+        __sub(self, other)
+    }
+}
+
 pub trait Multiply {
     fn multiply(self, other: Self) -> Self;
 }
@@ -86,6 +98,12 @@ impl Multiply for u8 {
     }
 }
 
+impl Multiply for u256 {
+    fn multiply(self, other: Self) -> Self {
+        __mul(self, other)
+    }
+}
+
 pub trait Divide {
     fn divide(self, other: Self) -> Self;
 }
@@ -213,6 +231,12 @@ impl Eq for b256 {
     }
 }
 
+impl Eq for u256 {
+    fn eq(self, other: Self) -> bool {
+        __eq(self, other)
+    }
+}
+
 impl Eq for raw_ptr {
     fn eq(self, other: Self) -> bool {
         __eq(self, other)
@@ -296,6 +320,15 @@ impl Ord for b256 {
     }
 }
 
+impl Ord for u256 {
+    fn gt(self, other: Self) -> bool {
+        __gt(self, other)
+    }
+    fn lt(self, other: Self) -> bool {
+        __lt(self, other)
+    }
+}
+
 pub trait BitwiseAnd {
     fn binary_and(self, other: Self) -> Self;
 }
@@ -419,6 +452,14 @@ impl Not for u8 {
     }
 }
 
+impl Not for u256 {
+    fn not(self) -> Self {
+        let (word_1, word_2, word_3, word_4) = decompose(u256_to_b256(self));
+        let rebuilt = compose((word_1.not(), word_2.not(), word_3.not(), word_4.not()));
+        b256_to_u256(rebuilt)
+    }
+}
+
 impl BitwiseAnd for b256 {
     fn binary_and(val: self, other: Self) -> Self {
         let (value_word_1, value_word_2, value_word_3, value_word_4) = decompose(val);
@@ -458,6 +499,24 @@ impl BitwiseXor for b256 {
     }
 }
 
+impl BitwiseAnd for u256 {
+    fn binary_and(self, other: Self) -> Self {
+        __and(self, other)
+    }
+}
+
+impl BitwiseOr for u256 {
+    fn binary_or(self, other: Self) -> Self {
+        __or(self, other)
+    }
+}
+
+impl BitwiseXor for u256 {
+    fn binary_xor(self, other: Self) -> Self {
+        __xor(self, other)
+    }
+}
+
 trait OrdEq: Ord + Eq {
 } {
     fn ge(self, other: Self) -> bool {
@@ -477,6 +536,8 @@ impl OrdEq for u8 {
 }
 impl OrdEq for b256 {
 }
+impl OrdEq for u256 {
+}
 
 pub trait Shift {
     fn lsh(self, other: u64) -> Self;
@@ -611,6 +672,16 @@ impl Shift for b256 {
     }
 }
 
+impl Shift for u256 {
+    fn lsh(self, shift_amount: u64) -> Self {
+        b256_to_u256(u256_to_b256(self).lsh(shift_amount))
+    }
+
+    fn rsh(self, shift_amount: u64) -> Self {
+        b256_to_u256(u256_to_b256(self).rsh(shift_amount))
+    }
+}
+
 /////////////////////////////////////////////////
 // Internal Helpers
 /////////////////////////////////////////////////
@@ -640,6 +711,61 @@ fn decompose(val: b256) -> (u64, u64, u64, u64) {
     asm(r1: __addr_of(val)) { r1: (u64, u64, u64, u64) }
 }
 
+/// Reinterpret a u256 value as a b256 value. Both are stored as 4 big-endian words.
+fn u256_to_b256(val: u256) -> b256 {
+    asm(r1: __addr_of(val)) { r1: b256 }
+}
+
+/// Reinterpret a b256 value as a u256 value. Both are stored as 4 big-endian words.
+fn b256_to_u256(val: b256) -> u256 {
+    asm(r1: __addr_of(val)) { r1: u256 }
+}
+
+/// Divide two u256 values using binary long division, returning the quotient and the remainder.
+/// Panics with an arithmetic error on a division by zero, like `u64` division does.
+fn u256_div_rem(dividend: u256, divisor: u256) -> (u256, u256) {
+    if divisor.eq(0u256) {
+        asm(r1) {
+            div r1 one zero;
+        };
+    }
+
+    let mut quotient = 0u256;
+    let mut remainder = 0u256;
+    let mut bit = 256;
+    while bit.gt(0) {
+        bit = bit.subtract(1);
+        // The remainder is always less than the divisor, but shifting it may still overflow
+        // when the divisor is larger than 2^255.
+        let overflows = remainder.rsh(255).neq(0u256);
+        remainder = remainder.lsh(1).binary_or(dividend.rsh(bit).binary_and(1u256));
+        if overflows {
+            // The remainder is really `remainder + 2^256`, which the divisor is less than.
+            remainder = remainder.add(u256::max().subtract(divisor)).add(1u256);
+            quotient = quotient.binary_or(1u256.lsh(bit));
+        } else if remainder.ge(divisor) {
+            remainder = remainder.subtract(divisor);
+            quotient = quotient.binary_or(1u256.lsh(bit));
+        }
+    }
+    (quotient, remainder)
+}
+
+// Declared after `u256_div_rem`, so that it is type checked after the operators it uses.
+impl Divide for u256 {
+    fn divide(self, other: Self) -> Self {
+        let (quotient, _) = u256_div_rem(self, other);
+        quotient
+    }
+}
+
+impl Mod for u256 {
+    fn modulo(self, other: Self) -> Self {
+        let (_, remainder) = u256_div_rem(self, other);
+        remainder
+    }
+}
+
 #[test]
 fn test_compose() {
     let expected: b256 = 0x0000000000000001_0000000000000002_0000000000000003_0000000000000004;
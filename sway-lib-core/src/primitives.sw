@@ -16,6 +16,12 @@ impl u64 {
     pub fn bits() -> u32 {
         64
     }
+
+    /// Converts this value to a `u256`.
+    pub fn as_u256(self) -> u256 {
+        let words = (0, 0, 0, self);
+        asm(r1: __addr_of(words)) { r1: u256 }
+    }
 }
 
 impl u32 {
@@ -34,6 +40,12 @@ impl u32 {
     pub fn bits() -> u32 {
         32
     }
+
+    /// Converts this value to a `u256`.
+    pub fn as_u256(self) -> u256 {
+        let words = (0, 0, 0, asm(r1: self) { r1: u64 });
+        asm(r1: __addr_of(words)) { r1: u256 }
+    }
 }
 
 impl u16 {
@@ -52,6 +64,12 @@ impl u16 {
     pub fn bits() -> u32 {
         16
     }
+
+    /// Converts this value to a `u256`.
+    pub fn as_u256(self) -> u256 {
+        let words = (0, 0, 0, asm(r1: self) { r1: u64 });
+        asm(r1: __addr_of(words)) { r1: u256 }
+    }
 }
 
 impl u8 {
@@ -70,6 +88,12 @@ impl u8 {
     pub fn bits() -> u32 {
         8
     }
+
+    /// Converts this value to a `u256`.
+    pub fn as_u256(self) -> u256 {
+        let words = (0, 0, 0, asm(r1: self) { r1: u64 });
+        asm(r1: __addr_of(words)) { r1: u256 }
+    }
 }
 
 impl b256 {
@@ -89,3 +113,31 @@ impl b256 {
         256
     }
 }
+
+impl u256 {
+    /// The smallest value that can be represented by this integer type.
+    pub fn min() -> u256 {
+        0u256
+    }
+
+    /// The largest value that can be represented by this integer type,
+    /// 2<sup>256</sup> - 1.
+    pub fn max() -> u256 {
+        0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFu256
+    }
+
+    /// The size of this integer type in bits.
+    pub fn bits() -> u32 {
+        256
+    }
+
+    /// Converts this value to a `u64`. Panics with an arithmetic overflow if the value is
+    /// greater than `u64::max()`, unless wrapping arithmetic is enabled.
+    pub fn as_u64(self) -> u64 {
+        let (word_1, word_2, word_3, word_4) = asm(r1: __addr_of(self)) { r1: (u64, u64, u64, u64) };
+        asm(high: __or(__or(word_1, word_2), word_3), max: 18446744073709551615, sum) {
+            add sum max high;
+        };
+        word_4
+    }
+}
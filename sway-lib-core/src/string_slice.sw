@@ -0,0 +1,40 @@
+library;
+
+use ::raw_ptr::*;
+use ::ops::*;
+
+fn into_parts(s: str) -> (raw_ptr, u64) {
+    asm(ptr: s) { ptr: (raw_ptr, u64) }
+}
+
+impl str {
+    /// Returns the pointer to the bytes of the string slice.
+    pub fn as_ptr(self) -> raw_ptr {
+        into_parts(self).0
+    }
+
+    /// Returns the length of the string slice in bytes.
+    pub fn len(self) -> u64 {
+        into_parts(self).1
+    }
+}
+
+impl str {
+    /// Returns `true` if the string slice has a length of zero bytes.
+    pub fn is_empty(self) -> bool {
+        self.len().eq(0)
+    }
+}
+
+impl Eq for str {
+    fn eq(self, other: Self) -> bool {
+        if self.len().neq(other.len()) {
+            return false;
+        }
+
+        asm(result, r2: self.as_ptr(), r3: other.as_ptr(), r4: self.len()) {
+            meq result r2 r3 r4;
+            result: bool
+        }
+    }
+}
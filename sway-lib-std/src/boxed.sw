@@ -0,0 +1,76 @@
+//! A pointer type for heap allocation.
+//!
+//! `Box<T>` stores its value on the heap, so that a `Box<T>` has the size of
+//! a pointer regardless of `T`. This makes it possible to declare recursive
+//! types, such as linked lists and expression trees:
+//!
+//! ```sway
+//! enum List {
+//!     Nil: (),
+//!     Cons: (u64, Box<List>),
+//! }
+//! ```
+library;
+
+use ::alloc::alloc;
+use ::assert::assert;
+
+/// A pointer to a value of type `T` allocated on the heap.
+pub struct Box<T> {
+    ptr: raw_ptr,
+}
+
+impl<T> Box<T> {
+    /// Allocates memory on the heap and moves `value` into it.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::boxed::Box;
+    ///
+    /// let five = Box::new(5);
+    /// assert(five.get() == 5);
+    /// ```
+    pub fn new(value: T) -> Self {
+        let ptr = alloc::<T>(1);
+        ptr.write::<T>(value);
+        Self { ptr }
+    }
+
+    /// Returns a copy of the value stored on the heap.
+    pub fn get(self) -> T {
+        self.ptr.read::<T>()
+    }
+
+    /// Replaces the value stored on the heap with `value`.
+    ///
+    /// Every copy of this `Box` refers to the same allocation, so all of them
+    /// observe the new value.
+    pub fn set(self, value: T) {
+        self.ptr.write::<T>(value);
+    }
+
+    /// Returns the pointer to the value stored on the heap.
+    pub fn ptr(self) -> raw_ptr {
+        self.ptr
+    }
+}
+
+#[test]
+fn test_box_new_and_get() {
+    let b = Box::new(42);
+    assert(b.get() == 42);
+
+    let pair = Box::new((1, true));
+    let (n, flag) = pair.get();
+    assert(n == 1);
+    assert(flag);
+}
+
+#[test]
+fn test_box_set() {
+    let b = Box::new(1);
+    let alias = b;
+    b.set(2);
+    assert(alias.get() == 2);
+}
@@ -6,6 +6,7 @@ use ::assert::assert;
 use ::intrinsics::size_of_val;
 use ::option::Option;
 use ::convert::From;
+use ::iterator::Iterator;
 
 struct RawBytes {
     ptr: raw_ptr,
@@ -482,6 +483,54 @@ impl Bytes {
     }
 }
 
+impl Bytes {
+    /// Returns an iterator over the bytes, in order.
+    ///
+    /// The iterator works on a copy of the `Bytes`, so they must not be
+    /// modified while they are being iterated over.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::bytes::{Bytes, BytesIter};
+    ///
+    /// let mut bytes = Bytes::new();
+    /// bytes.push(5u8);
+    /// bytes.push(7u8);
+    ///
+    /// let mut sum = 0u8;
+    /// for byte in bytes.iter() {
+    ///     sum += byte;
+    /// }
+    /// assert(sum == 12u8);
+    /// ```
+    pub fn iter(self) -> BytesIter {
+        BytesIter {
+            bytes: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over the bytes of a `Bytes`, created by `Bytes::iter`.
+pub struct BytesIter {
+    bytes: Bytes,
+    index: u64,
+}
+
+impl Iterator for BytesIter {
+    type Item = u8;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.index >= self.bytes.len() {
+            return Option::None;
+        }
+
+        self.index += 1;
+        self.bytes.get(self.index - 1)
+    }
+}
+
 // Need to use seperate impl blocks for now: https://github.com/FuelLabs/sway/issues/1548
 impl Bytes {
     /// Creates a `Bytes` from a `Vec<u8>`.
@@ -683,6 +732,13 @@ impl core::ops::Eq for Bytes {
     }
 }
 
+impl AsRawSlice for Bytes {
+    /// Returns a raw slice of all of the bytes.
+    fn as_raw_slice(self) -> raw_slice {
+        raw_slice::from_parts::<u8>(self.buf.ptr(), self.len)
+    }
+}
+
 /// Methods for converting between the `Bytes` and the `b256` types.
 impl From<b256> for Bytes {
     fn from(b: b256) -> Bytes {
@@ -1148,3 +1204,21 @@ fn test_into_b256() {
 
     assert(value == expected);
 }
+
+#[test]
+fn test_iter() {
+    let mut bytes = Bytes::new();
+    bytes.push(5u8);
+    bytes.push(7u8);
+    bytes.push(9u8);
+
+    let mut sum = 0u8;
+    let mut count = 0;
+    for byte in bytes.iter() {
+        sum += byte;
+        count += 1;
+    }
+
+    assert(sum == 21u8);
+    assert(count == 3);
+}
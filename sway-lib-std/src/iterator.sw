@@ -0,0 +1,137 @@
+//! Composable external iteration.
+//!
+//! An iterator is any type implementing the `Iterator` trait. Calling
+//! `next` returns `Option::Some(item)` until the iterator is exhausted,
+//! after which it returns `Option::None`.
+//!
+//! `for` loops work with any iterator:
+//!
+//! ```sway
+//! let mut sum = 0;
+//! for i in Range::new(0, 10) {
+//!     sum += i;
+//! }
+//! assert(sum == 45);
+//! ```
+library;
+
+use ::assert::assert;
+use ::option::Option;
+
+/// An interface for dealing with iterators.
+pub trait Iterator {
+    /// The type of the elements being iterated over.
+    type Item;
+
+    /// Advances the iterator and returns the next value.
+    ///
+    /// Returns `Option::None` when iteration is finished.
+    fn next(ref mut self) -> Option<Self::Item>;
+}
+
+/// A half-open range of `u64` values, from `start` (inclusive) to `end` (exclusive).
+pub struct Range {
+    start: u64,
+    end: u64,
+}
+
+impl Range {
+    /// Creates a range yielding every value from `start` up to, but not including, `end`.
+    ///
+    /// The range is empty if `start >= end`.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::iterator::Range;
+    ///
+    /// let mut range = Range::new(1, 3);
+    /// assert(range.next().unwrap() == 1);
+    /// assert(range.next().unwrap() == 2);
+    /// assert(range.next().is_none());
+    /// ```
+    pub fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+}
+
+impl Iterator for Range {
+    type Item = u64;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.start >= self.end {
+            return Option::None;
+        }
+
+        let value = self.start;
+        self.start += 1;
+        Option::Some(value)
+    }
+}
+
+/// An iterator over the elements of an array of `N` elements of type `T`.
+pub struct ArrayIter<T, const N: u64> {
+    values: [T; N],
+    index: u64,
+}
+
+impl<T, const N: u64> [T; N] {
+    /// Returns an iterator over the elements of the array.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// let mut sum = 0;
+    /// for value in [1, 2, 3].iter() {
+    ///     sum += value;
+    /// }
+    /// assert(sum == 6);
+    /// ```
+    pub fn iter(self) -> ArrayIter<T, N> {
+        ArrayIter {
+            values: self,
+            index: 0,
+        }
+    }
+}
+
+impl<T, const N: u64> Iterator for ArrayIter<T, N> {
+    type Item = T;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.index >= N {
+            return Option::None;
+        }
+
+        let value = self.values[self.index];
+        self.index += 1;
+        Option::Some(value)
+    }
+}
+
+#[test]
+fn test_range() {
+    let mut sum = 0;
+    let mut count = 0;
+    for i in Range::new(3, 6) {
+        sum += i;
+        count += 1;
+    }
+    assert(sum == 12);
+    assert(count == 3);
+}
+
+#[test]
+fn test_empty_range() {
+    for _ in Range::new(6, 3) {
+        assert(false);
+    }
+}
+
+#[test]
+fn test_array_iter() {
+    let mut iter = [4, 5].iter();
+    assert(iter.next().unwrap() == 4);
+    assert(iter.next().unwrap() == 5);
+    assert(iter.next().is_none());
+}
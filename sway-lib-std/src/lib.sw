@@ -11,7 +11,9 @@ mod option;
 mod convert;
 mod intrinsics;
 mod assert;
+mod iterator;
 mod alloc;
+mod boxed;
 mod contract_id;
 mod constants;
 mod external;
@@ -24,6 +26,7 @@ mod address;
 mod identity;
 mod vec;
 mod bytes;
+mod string;
 mod r#storage;
 mod b256;
 mod tx;
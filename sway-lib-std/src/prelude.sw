@@ -9,8 +9,10 @@ use ::contract_id::ContractId;
 use ::identity::Identity;
 
 // Collections
+use ::boxed::Box;
+use ::string::String;
 use ::storage::StorageMap;
-use ::vec::Vec;
+use ::vec::{Vec, VecIter};
 
 // Error handling
 use ::assert::assert;
@@ -18,6 +20,9 @@ use ::option::Option;
 use ::result::Result;
 use ::revert::{require, revert};
 
+// Iteration
+use ::iterator::{ArrayIter, Iterator, Range};
+
 // Convert
 use ::convert::From;
 
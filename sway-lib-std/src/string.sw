@@ -0,0 +1,223 @@
+//! The `String` type is an owned, growable string of UTF-8 encoded bytes.
+library;
+
+use ::assert::assert;
+use ::bytes::Bytes;
+use ::convert::From;
+
+/// A UTF-8 encoded growable string.
+///
+/// A `String` is backed by `Bytes`.  Its length and capacity are in bytes.
+pub struct String {
+    bytes: Bytes,
+}
+
+impl String {
+    /// Constructs a new, empty `String`.
+    ///
+    /// The `String` will not allocate until bytes are pushed onto it.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::string::String;
+    ///
+    /// let string = String::new();
+    /// assert(string.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            bytes: Bytes::new(),
+        }
+    }
+
+    /// Constructs a new, empty `String` with the specified capacity in bytes.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::string::String;
+    ///
+    /// let string = String::with_capacity(10);
+    /// assert(string.capacity() == 10);
+    /// ```
+    pub fn with_capacity(capacity: u64) -> Self {
+        Self {
+            bytes: Bytes::with_capacity(capacity),
+        }
+    }
+
+    /// Constructs a `String` from `Bytes` holding ASCII encoded text.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::{bytes::Bytes, string::String};
+    ///
+    /// let mut bytes = Bytes::new();
+    /// bytes.push(72u8);
+    /// bytes.push(105u8);
+    ///
+    /// let string = String::from_ascii(bytes);
+    /// assert(string == String::from_ascii_str("Hi"));
+    /// ```
+    pub fn from_ascii(bytes: Bytes) -> Self {
+        Self { bytes }
+    }
+
+    /// Appends the bytes of the string slice `s` to the end of the `String`.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::string::String;
+    ///
+    /// let mut string = String::from_ascii_str("Fuel");
+    /// string.push_str(" Labs");
+    /// assert(string.as_str() == "Fuel Labs");
+    /// ```
+    pub fn push_str(ref mut self, s: str) {
+        let ptr = s.as_ptr();
+        let mut i = 0;
+        while i < s.len() {
+            self.bytes.push(ptr.add_uint_offset(i).read_byte());
+            i += 1;
+        }
+    }
+
+    /// Returns the bytes of the `String`.
+    pub fn as_bytes(self) -> Bytes {
+        self.bytes
+    }
+
+    /// Returns a string slice of the whole `String`.
+    ///
+    /// The slice points into the memory of the `String`, so the `String`
+    /// must not be modified while the slice is in use.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::string::String;
+    ///
+    /// let string = String::from_ascii_str("Fuel");
+    /// let s: str = string.as_str();
+    /// assert(s.len() == 4);
+    /// ```
+    pub fn as_str(self) -> str {
+        asm(ptr: (self.bytes.as_raw_slice().ptr(), self.bytes.len())) { ptr: str }
+    }
+
+    /// Returns the length of the `String` in bytes.
+    pub fn len(self) -> u64 {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the `String` has a length of zero bytes.
+    pub fn is_empty(self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the capacity of the `String` in bytes.
+    pub fn capacity(self) -> u64 {
+        self.bytes.capacity()
+    }
+
+    /// Truncates the `String` to a length of zero bytes.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::string::String;
+    ///
+    /// let mut string = String::from_ascii_str("Fuel");
+    /// string.clear();
+    /// assert(string.is_empty());
+    /// ```
+    pub fn clear(ref mut self) {
+        self.bytes.clear()
+    }
+}
+
+impl String {
+    /// Constructs a `String` holding a copy of the string slice `s`.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::string::String;
+    ///
+    /// let string = String::from_ascii_str("Fuel");
+    /// assert(string.len() == 4);
+    /// ```
+    pub fn from_ascii_str(s: str) -> Self {
+        let mut string = Self::with_capacity(s.len());
+        string.push_str(s);
+        string
+    }
+}
+
+impl From<Bytes> for String {
+    fn from(bytes: Bytes) -> String {
+        Self { bytes }
+    }
+
+    fn into(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl core::ops::Eq for String {
+    fn eq(self, other: Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+/// Concatenates two `String`s into a new `String`.  Neither operand is modified.
+impl core::ops::Add for String {
+    fn add(self, other: Self) -> Self {
+        let mut string = Self::with_capacity(self.len() + other.len());
+        string.push_str(self.as_str());
+        string.push_str(other.as_str());
+        string
+    }
+}
+
+// Tests
+//
+#[test()]
+fn test_new_string() {
+    let string = String::new();
+    assert(string.is_empty());
+    assert(string.len() == 0);
+}
+
+#[test()]
+fn test_from_ascii_str() {
+    let string = String::from_ascii_str("Fuel");
+    assert(string.len() == 4);
+    assert(string.as_str() == "Fuel");
+}
+
+#[test()]
+fn test_push_str() {
+    let mut string = String::new();
+    string.push_str("Fuel");
+    string.push_str(" Labs");
+    assert(string.as_str() == "Fuel Labs");
+}
+
+#[test()]
+fn test_add() {
+    let fuel = String::from_ascii_str("Fuel");
+    let labs = String::from_ascii_str(" Labs");
+    assert(fuel + labs == String::from_ascii_str("Fuel Labs"));
+    assert(fuel.as_str() == "Fuel");
+}
+
+#[test()]
+fn test_clear() {
+    let mut string = String::from_ascii_str("Fuel");
+    string.clear();
+    assert(string.is_empty());
+}
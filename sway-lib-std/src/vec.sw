@@ -4,6 +4,7 @@ use ::alloc::{alloc, realloc};
 use ::assert::assert;
 use ::option::Option;
 use ::convert::From;
+use ::iterator::Iterator;
 
 struct RawVec<T> {
     ptr: raw_ptr,
@@ -424,6 +425,54 @@ impl<T> Vec<T> {
     }
 }
 
+impl<T> Vec<T> {
+    /// Returns an iterator over the elements of the vector, in order.
+    ///
+    /// The iterator works on a copy of the vector, so the vector must not be
+    /// modified while it is being iterated over.
+    ///
+    /// ### Examples
+    ///
+    /// ```sway
+    /// use std::vec::Vec;
+    ///
+    /// let mut vec = Vec::new();
+    /// vec.push(5);
+    /// vec.push(10);
+    ///
+    /// let mut sum = 0;
+    /// for value in vec.iter() {
+    ///     sum += value;
+    /// }
+    /// assert(sum == 15);
+    /// ```
+    pub fn iter(self) -> VecIter<T> {
+        VecIter {
+            values: self,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over the elements of a `Vec`, created by `Vec::iter`.
+pub struct VecIter<T> {
+    values: Vec<T>,
+    index: u64,
+}
+
+impl<T> Iterator for VecIter<T> {
+    type Item = T;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.index >= self.values.len() {
+            return Option::None;
+        }
+
+        self.index += 1;
+        self.values.get(self.index - 1)
+    }
+}
+
 impl<T> AsRawSlice for Vec<T> {
     /// Returns a raw slice to all of the elements in the vector.
     fn as_raw_slice(self) -> raw_slice {
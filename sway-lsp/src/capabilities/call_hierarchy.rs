@@ -0,0 +1,255 @@
+use crate::core::{
+    session::Session,
+    token::{get_range_from_span, to_ident_key, TypedAstToken},
+};
+use std::sync::Arc;
+use sway_core::{
+    decl_engine::{DeclEngine, InterfaceDeclId},
+    language::{ty, Purity},
+};
+use sway_types::{Span, Spanned};
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Location, Position,
+    SymbolKind, Url,
+};
+
+/// Returns the [CallHierarchyItem] of the function or method that the token at the given
+/// [Position] refers to.
+pub fn prepare_call_hierarchy(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<Vec<CallHierarchyItem>> {
+    let (_, token) = session.token_map().token_at_position(url, position)?;
+    let decl_ident =
+        token.declared_token_ident(&session.type_engine.read(), &session.decl_engine.read())?;
+    let decl_token = session
+        .token_map()
+        .try_get(&to_ident_key(&decl_ident))
+        .try_unwrap()
+        .map(|item| item.value().clone())?;
+    let item = match decl_token.typed? {
+        TypedAstToken::TypedFunctionDeclaration(func_decl) => function_item(&session, &func_decl),
+        TypedAstToken::TypedTraitFn(trait_fn) => trait_fn_item(&session, &trait_fn),
+        _ => None,
+    }?;
+    Some(vec![item])
+}
+
+/// Returns the calls made from the body of the function of the `item`, grouped by the
+/// function being called.
+///
+/// Method applications and contract calls are type-checked into function applications,
+/// so they are included as well.
+pub fn outgoing_calls(
+    session: Arc<Session>,
+    url: &Url,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let (_, token) = session
+        .token_map()
+        .token_at_position(url, item.selection_range.start)?;
+    let func_decl = match token.typed? {
+        TypedAstToken::TypedFunctionDeclaration(func_decl) => func_decl,
+        // Trait and ABI methods without a body don't call anything.
+        _ => return Some(vec![]),
+    };
+
+    let decl_engine = &*session.decl_engine.read();
+    let mut calls: Vec<CallHierarchyOutgoingCall> = vec![];
+    for entry in session.token_map().iter() {
+        let ((ident, _), token) = entry.pair();
+        if !span_contains(&func_decl.span, &ident.span()) {
+            continue;
+        }
+        let callee_item = match called_function(decl_engine, &token.typed)
+            .and_then(|callee| function_item(&session, &callee))
+        {
+            Some(callee_item) => callee_item,
+            None => continue,
+        };
+        let from_range = get_range_from_span(&ident.span());
+        match calls
+            .iter_mut()
+            .find(|call| is_same_item(&call.to, &callee_item))
+        {
+            Some(call) => call.from_ranges.push(from_range),
+            None => calls.push(CallHierarchyOutgoingCall {
+                to: callee_item,
+                from_ranges: vec![from_range],
+            }),
+        }
+    }
+    Some(calls)
+}
+
+/// Returns the calls to the function of the `item` from all of the sessions of the
+/// workspace, grouped by the function they are made from.
+///
+/// Calls to the trait or ABI method that the function implements are included, so that
+/// contract calls reaching an ABI implementation are found as well.
+pub fn incoming_calls(
+    sessions: &[Arc<Session>],
+    session: Arc<Session>,
+    url: &Url,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let mut targets = vec![Location::new(item.uri.clone(), item.selection_range)];
+    let (_, token) = session
+        .token_map()
+        .token_at_position(url, item.selection_range.start)?;
+    if let Some(TypedAstToken::TypedFunctionDeclaration(func_decl)) = &token.typed {
+        let decl_engine = &*session.decl_engine.read();
+        if let Some(location) = interface_method_location(&session, decl_engine, func_decl) {
+            targets.push(location);
+        }
+    }
+
+    let mut calls: Vec<CallHierarchyIncomingCall> = vec![];
+    for session in sessions {
+        let decl_engine = &*session.decl_engine.read();
+        // The functions declared in this session, which calls can be made from.
+        let callers: Vec<ty::TyFunctionDeclaration> = session
+            .token_map()
+            .iter()
+            .filter_map(|entry| {
+                let ((ident, _), token) = entry.pair();
+                match &token.typed {
+                    Some(TypedAstToken::TypedFunctionDeclaration(func_decl))
+                        if func_decl.name.span() == ident.span() =>
+                    {
+                        Some(func_decl.clone())
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        for entry in session.token_map().iter() {
+            let ((ident, _), token) = entry.pair();
+            let calls_target = called_function(decl_engine, &token.typed)
+                .and_then(|callee| session.ident_location(&callee.name))
+                .map_or(false, |location| targets.contains(&location));
+            if !calls_target {
+                continue;
+            }
+            let caller_item = match callers
+                .iter()
+                .find(|caller| span_contains(&caller.span, &ident.span()))
+                .and_then(|caller| function_item(session, caller))
+            {
+                Some(caller_item) => caller_item,
+                None => continue,
+            };
+            let from_range = get_range_from_span(&ident.span());
+            match calls
+                .iter_mut()
+                .find(|call| is_same_item(&call.from, &caller_item))
+            {
+                Some(call) => {
+                    if !call.from_ranges.contains(&from_range) {
+                        call.from_ranges.push(from_range);
+                    }
+                }
+                None => calls.push(CallHierarchyIncomingCall {
+                    from: caller_item,
+                    from_ranges: vec![from_range],
+                }),
+            }
+        }
+    }
+    Some(calls)
+}
+
+/// Returns the function called by a function application token.
+fn called_function(
+    decl_engine: &DeclEngine,
+    typed_token: &Option<TypedAstToken>,
+) -> Option<ty::TyFunctionDeclaration> {
+    match typed_token {
+        Some(TypedAstToken::TypedExpression(ty::TyExpression {
+            expression: ty::TyExpressionVariant::FunctionApplication { fn_ref, .. },
+            ..
+        })) => Some(decl_engine.get_function(fn_ref)),
+        _ => None,
+    }
+}
+
+/// Returns the [Location] of the trait or ABI method that `func_decl` implements, if any.
+fn interface_method_location(
+    session: &Session,
+    decl_engine: &DeclEngine,
+    func_decl: &ty::TyFunctionDeclaration,
+) -> Option<Location> {
+    let impl_trait = match &func_decl.implementing_type {
+        Some(ty::TyDeclaration::ImplTrait { decl_id, .. }) => decl_engine.get_impl_trait(decl_id),
+        _ => return None,
+    };
+    let interface_surface = match impl_trait.trait_decl_ref?.id() {
+        InterfaceDeclId::Trait(decl_id) => decl_engine.get_trait(decl_id).interface_surface,
+        InterfaceDeclId::Abi(decl_id) => decl_engine.get_abi(decl_id).interface_surface,
+    };
+    interface_surface.iter().find_map(|item| match item {
+        ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
+            let trait_fn = decl_engine.get_trait_fn(decl_ref);
+            (trait_fn.name.as_str() == func_decl.name.as_str())
+                .then(|| session.ident_location(&trait_fn.name))
+                .flatten()
+        }
+        ty::TyTraitInterfaceItem::Constant(_) | ty::TyTraitInterfaceItem::Type(_) => None,
+    })
+}
+
+fn function_item(
+    session: &Session,
+    func_decl: &ty::TyFunctionDeclaration,
+) -> Option<CallHierarchyItem> {
+    let location = session.ident_location(&func_decl.name)?;
+    let kind = if func_decl.implementing_type.is_some() || func_decl.is_contract_call {
+        SymbolKind::METHOD
+    } else {
+        SymbolKind::FUNCTION
+    };
+    Some(CallHierarchyItem {
+        name: func_decl.name.as_str().to_string(),
+        kind,
+        tags: None,
+        detail: storage_detail(&func_decl.purity),
+        uri: location.uri,
+        range: get_range_from_span(&func_decl.span),
+        selection_range: location.range,
+        data: None,
+    })
+}
+
+fn trait_fn_item(session: &Session, trait_fn: &ty::TyTraitFn) -> Option<CallHierarchyItem> {
+    let location = session.ident_location(&trait_fn.name)?;
+    Some(CallHierarchyItem {
+        name: trait_fn.name.as_str().to_string(),
+        kind: SymbolKind::METHOD,
+        tags: None,
+        detail: None,
+        uri: location.uri,
+        range: location.range,
+        selection_range: location.range,
+        data: None,
+    })
+}
+
+/// Shows the storage access of a function, so that paths to functions writing to storage
+/// stand out.
+fn storage_detail(purity: &Purity) -> Option<String> {
+    match purity {
+        Purity::Pure => None,
+        _ => Some(format!("#[storage({})]", purity.to_attribute_syntax())),
+    }
+}
+
+fn is_same_item(a: &CallHierarchyItem, b: &CallHierarchyItem) -> bool {
+    a.uri == b.uri && a.selection_range == b.selection_range
+}
+
+/// Returns `true` if `inner` is within `outer`, in the same file.
+pub(crate) fn span_contains(outer: &Span, inner: &Span) -> bool {
+    outer.path() == inner.path() && outer.start() <= inner.start() && inner.end() <= outer.end()
+}
@@ -78,6 +78,9 @@ impl AbiImplCodeAction<'_> {
                                 None,
                             )
                         }
+                        // ABIs cannot declare associated constants or types.
+                        ty::TyTraitInterfaceItem::Constant(_)
+                        | ty::TyTraitInterfaceItem::Type(_) => String::new(),
                     }
                 })
                 .collect::<Vec<String>>()
@@ -1,4 +1,5 @@
 pub mod abi_decl;
+pub mod quick_fix;
 pub mod struct_decl;
 
 use crate::core::{
@@ -17,7 +18,8 @@ use sway_core::{
 use sway_types::Spanned;
 use tower_lsp::lsp_types::{
     CodeAction as LspCodeAction, CodeActionDisabled, CodeActionKind, CodeActionOrCommand,
-    CodeActionResponse, Position, Range, TextDocumentIdentifier, TextEdit, Url, WorkspaceEdit,
+    CodeActionResponse, Diagnostic, Position, Range, TextDocumentIdentifier, TextEdit, Url,
+    WorkspaceEdit,
 };
 
 pub(crate) const CODE_ACTION_IMPL_TITLE: &str = "Generate impl for";
@@ -33,15 +35,32 @@ pub(crate) struct CodeActionContext<'a> {
     uri: &'a Url,
 }
 
+/// Returns the quick fixes for the `diagnostics` the client sent along with the request, followed
+/// by the code actions for the token in the given [Range].
 pub(crate) fn code_actions(
     session: Arc<Session>,
     range: &Range,
     text_document: TextDocumentIdentifier,
-    temp_uri: &Url,
+    uri: &Url,
+    diagnostics: &[Diagnostic],
+    workspace_sessions: &[Arc<Session>],
 ) -> Option<CodeActionResponse> {
-    let (_, token) = session
-        .token_map()
-        .token_at_position(temp_uri, range.start)?;
+    let mut actions = quick_fix::code_actions(&session, uri, diagnostics, workspace_sessions);
+    actions.extend(token_code_actions(&session, range, text_document, uri).unwrap_or_default());
+    if actions.is_empty() {
+        None
+    } else {
+        Some(actions)
+    }
+}
+
+fn token_code_actions(
+    session: &Session,
+    range: &Range,
+    text_document: TextDocumentIdentifier,
+    uri: &Url,
+) -> Option<CodeActionResponse> {
+    let (_, token) = session.token_map().token_at_position(uri, range.start)?;
     let type_engine = session.type_engine.read();
     let decl_engine = session.decl_engine.read();
     let ctx = CodeActionContext {
@@ -0,0 +1,18 @@
+use super::{document_edit, insert_line_above, quick_fix};
+use sway_types::constants::{ALLOW_ATTRIBUTE_NAME, ALLOW_DEAD_CODE_NAME};
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Url};
+
+/// Returns a quick fix adding `#[allow(dead_code)]` to the unused declaration.
+pub(crate) fn code_actions(
+    uri: &Url,
+    text: &str,
+    diagnostic: &Diagnostic,
+) -> Vec<CodeActionOrCommand> {
+    let attribute = format!("#[{ALLOW_ATTRIBUTE_NAME}({ALLOW_DEAD_CODE_NAME})]");
+    let edit = insert_line_above(text, diagnostic.range.start.line, &attribute);
+    vec![quick_fix(
+        format!("Add `{attribute}`"),
+        diagnostic,
+        document_edit(uri, vec![edit]),
+    )]
+}
@@ -0,0 +1,135 @@
+use super::{document_edit, quick_fix};
+use crate::{
+    capabilities::code_actions::TAB,
+    core::{session::Session, token::TypedAstToken},
+};
+use sway_core::{
+    language::ty::{TyDeclaration, TyTraitFn, TyTraitInterfaceItem},
+    transform::AttributeKind,
+    TypeEngine,
+};
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Position, Range, TextEdit, Url};
+
+/// Returns a quick fix adding the missing functions of the ABI or trait named `interface` to its
+/// implementation, with empty bodies.
+pub(crate) fn code_actions(
+    session: &Session,
+    uri: &Url,
+    text: &str,
+    diagnostic: &Diagnostic,
+    interface: &str,
+    functions: &[String],
+) -> Vec<CodeActionOrCommand> {
+    let interface_surface = match interface_surface(session, interface) {
+        Some(interface_surface) => interface_surface,
+        None => return vec![],
+    };
+    let type_engine = session.type_engine.read();
+    let decl_engine = session.decl_engine.read();
+    let fn_signatures = interface_surface
+        .iter()
+        .filter_map(|item| match item {
+            TyTraitInterfaceItem::TraitFn(decl_ref) => Some(decl_engine.get_trait_fn(decl_ref)),
+            TyTraitInterfaceItem::Constant(_) | TyTraitInterfaceItem::Type(_) => None,
+        })
+        .filter(|trait_fn| functions.iter().any(|name| name == trait_fn.name.as_str()))
+        .map(|trait_fn| fn_signature_string(&trait_fn, &type_engine))
+        .collect::<Vec<String>>()
+        .join("\n");
+    if fn_signatures.is_empty() {
+        return vec![];
+    }
+
+    // The diagnostic spans the implementation, which ends with its closing brace.
+    let closing_brace = diagnostic.range.end;
+    let brace_line = match text.lines().nth(closing_brace.line as usize) {
+        Some(line) => line,
+        None => return vec![],
+    };
+    let brace_on_own_line = brace_line
+        .chars()
+        .take(closing_brace.character.saturating_sub(1) as usize)
+        .all(char::is_whitespace);
+    let (position, new_text) = if brace_on_own_line {
+        (
+            Position::new(closing_brace.line, 0),
+            format!("{fn_signatures}\n"),
+        )
+    } else {
+        (
+            Position::new(
+                closing_brace.line,
+                closing_brace.character.saturating_sub(1),
+            ),
+            format!("\n{fn_signatures}\n"),
+        )
+    };
+    let edit = TextEdit::new(Range::new(position, position), new_text);
+    vec![quick_fix(
+        format!("Implement missing functions of `{interface}`"),
+        diagnostic,
+        document_edit(uri, vec![edit]),
+    )]
+}
+
+/// Returns the interface surface of the ABI or trait named `interface`.
+fn interface_surface(session: &Session, interface: &str) -> Option<Vec<TyTraitInterfaceItem>> {
+    let decl_engine = session.decl_engine.read();
+    session
+        .token_map()
+        .iter()
+        .find_map(|item| match &item.value().typed {
+            Some(TypedAstToken::TypedDeclaration(TyDeclaration::TraitDeclaration {
+                name,
+                decl_id,
+                ..
+            })) if name.as_str() == interface => {
+                Some(decl_engine.get_trait(decl_id).interface_surface)
+            }
+            Some(TypedAstToken::TypedDeclaration(TyDeclaration::AbiDeclaration {
+                name,
+                decl_id,
+                ..
+            })) if name.as_str() == interface => {
+                Some(decl_engine.get_abi(decl_id).interface_surface)
+            }
+            _ => None,
+        })
+}
+
+/// Returns a [String] of the function declaration for the `trait_fn`, along with its storage
+/// attributes.
+fn fn_signature_string(trait_fn: &TyTraitFn, type_engine: &TypeEngine) -> String {
+    let attributes = trait_fn
+        .attributes
+        .get(&AttributeKind::Storage)
+        .into_iter()
+        .flatten()
+        .map(|attr| format!("{TAB}{}\n", attr.span.as_str()))
+        .collect::<String>();
+    let params = trait_fn
+        .parameters
+        .iter()
+        .map(|param| {
+            if param.is_self() {
+                match param.is_reference && param.is_mutable {
+                    true => "ref mut self".to_string(),
+                    false => "self".to_string(),
+                }
+            } else {
+                format!("{}: {}", param.name, param.type_argument.span.as_str())
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    // Unit is the implicit return type.
+    let return_type = if type_engine.get(trait_fn.return_type).is_unit() {
+        String::new()
+    } else {
+        format!(" -> {}", trait_fn.return_type_span.as_str())
+    };
+    format!(
+        "{attributes}{TAB}fn {}({params}){return_type} {{}}",
+        trait_fn.name
+    )
+}
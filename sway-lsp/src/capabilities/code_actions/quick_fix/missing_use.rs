@@ -0,0 +1,130 @@
+use super::{document_edit, quick_fix};
+use crate::core::{session::Session, token::get_range_from_span};
+use std::{collections::HashSet, path::Path};
+use sway_ast::{ItemKind, Module};
+use sway_core::{decl_engine::DeclEngine, language::lexed::LexedModule, namespace};
+use sway_types::Spanned;
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Position, Range, TextEdit, Url};
+
+/// Returns a quick fix importing the symbol for each public declaration named `name` in the
+/// modules of the project and its dependencies.
+pub(crate) fn code_actions(
+    session: &Session,
+    uri: &Url,
+    diagnostic: &Diagnostic,
+    name: &str,
+) -> Vec<CodeActionOrCommand> {
+    let compiled_program = session.compiled_program.read();
+    let (lexed, program) = match (&compiled_program.lexed, &compiled_program.typed) {
+        (Some(lexed), Some(program)) => (lexed, program),
+        _ => return vec![],
+    };
+    let module = match uri
+        .to_file_path()
+        .ok()
+        .and_then(|path| lexed_module(&lexed.root, &path))
+    {
+        Some(module) => module,
+        None => return vec![],
+    };
+    let root = &program.root.namespace;
+    // The dependencies of dependencies are submodules of those as well. They are already
+    // searched at the root, so skip them to suggest each declaration once.
+    let dependencies: HashSet<&str> = root.submodules().keys().map(String::as_str).collect();
+    let decl_engine = session.decl_engine.read();
+    let mut paths = vec![];
+    for (module_name, module) in root.submodules() {
+        collect_import_paths(
+            module,
+            &mut vec![module_name.clone()],
+            name,
+            &dependencies,
+            &decl_engine,
+            &mut paths,
+        );
+    }
+
+    let (position, separator) = use_statement_position(module);
+    paths
+        .into_iter()
+        .map(|path| {
+            let edit = TextEdit::new(
+                Range::new(position, position),
+                format!("{separator}use {path};\n"),
+            );
+            quick_fix(
+                format!("Import `{path}`"),
+                diagnostic,
+                document_edit(uri, vec![edit]),
+            )
+        })
+        .collect()
+}
+
+/// Collects the paths of the public declarations named `name` in the module at `module_path` and
+/// its submodules.
+fn collect_import_paths(
+    module: &namespace::Module,
+    module_path: &mut Vec<String>,
+    name: &str,
+    dependencies: &HashSet<&str>,
+    decl_engine: &DeclEngine,
+    paths: &mut Vec<String>,
+) {
+    let is_declared = module
+        .symbols()
+        .iter()
+        .any(|(ident, decl)| ident.as_str() == name && decl.visibility(decl_engine).is_public());
+    if is_declared {
+        paths.push(format!("{}::{name}", module_path.join("::")));
+    }
+    for (submodule_name, submodule) in module.submodules() {
+        if dependencies.contains(submodule_name.as_str()) {
+            continue;
+        }
+        module_path.push(submodule_name.clone());
+        collect_import_paths(
+            submodule,
+            module_path,
+            name,
+            dependencies,
+            decl_engine,
+            paths,
+        );
+        module_path.pop();
+    }
+}
+
+/// Returns the lexed [Module] of the file at `path`, searching `module` and its submodules.
+fn lexed_module<'a>(module: &'a LexedModule, path: &Path) -> Option<&'a Module> {
+    let span = module.tree.span();
+    if span.path().map(|module_path| module_path.as_path()) == Some(path) {
+        return Some(&module.tree);
+    }
+    module
+        .submodules
+        .iter()
+        .find_map(|(_, submodule)| lexed_module(&submodule.module, path))
+}
+
+/// Returns the [Position] to insert a `use` statement at, which is after the last `use`
+/// statement of the module. If there are none, it is after the program type and module
+/// declarations, separated by an empty line.
+fn use_statement_position(module: &Module) -> (Position, &'static str) {
+    let line_after = |span| Position::new(get_range_from_span(&span).end.line + 1, 0);
+    let last_use = module
+        .items
+        .iter()
+        .rev()
+        .find(|item| matches!(item.value, ItemKind::Use(_)));
+    if let Some(item) = last_use {
+        return (line_after(item.span()), "");
+    }
+    let header_end = module
+        .items
+        .iter()
+        .rev()
+        .find(|item| matches!(item.value, ItemKind::Submodule(_)))
+        .map_or_else(|| module.semicolon_token.span(), |item| item.span());
+    (line_after(header_end), "\n")
+}
@@ -0,0 +1,115 @@
+pub(crate) mod allow_dead_code;
+pub(crate) mod missing_methods;
+pub(crate) mod missing_use;
+pub(crate) mod naming_convention;
+pub(crate) mod storage_attribute;
+pub(crate) mod unreachable_code;
+
+use crate::{capabilities::diagnostic::DiagnosticData, core::session::Session};
+use std::{collections::HashMap, sync::Arc};
+use tower_lsp::lsp_types::{
+    CodeAction as LspCodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, Position, Range,
+    TextEdit, Url, WorkspaceEdit,
+};
+
+/// Returns the quick fixes for the diagnostics that the client sent along with the code action
+/// request. The fixes are created from the [DiagnosticData] attached to the diagnostics when
+/// they were published.
+///
+/// `workspace_sessions` are only searched by fixes which need the whole workspace, such as
+/// renaming a declaration. See [needs_workspace_sessions].
+pub(crate) fn code_actions(
+    session: &Arc<Session>,
+    uri: &Url,
+    diagnostics: &[Diagnostic],
+    workspace_sessions: &[Arc<Session>],
+) -> Vec<CodeActionOrCommand> {
+    let text = match session.get_text_document(uri) {
+        Ok(document) => document.get_text(),
+        Err(_) => return vec![],
+    };
+    diagnostics
+        .iter()
+        .filter_map(|diagnostic| diagnostic_data(diagnostic).map(|data| (diagnostic, data)))
+        .flat_map(|(diagnostic, data)| match data {
+            DiagnosticData::UnknownSymbol { name } => {
+                missing_use::code_actions(session, uri, diagnostic, &name)
+            }
+            DiagnosticData::MissingStorageAttribute { attrs } => {
+                storage_attribute::code_actions(session, uri, &text, diagnostic, &attrs)
+            }
+            DiagnosticData::MissingMethods {
+                interface,
+                functions,
+            } => missing_methods::code_actions(
+                session, uri, &text, diagnostic, &interface, &functions,
+            ),
+            DiagnosticData::DeadCode => allow_dead_code::code_actions(uri, &text, diagnostic),
+            DiagnosticData::NamingConvention { suggestion } => naming_convention::code_actions(
+                workspace_sessions,
+                session.clone(),
+                uri,
+                diagnostic,
+                suggestion,
+            ),
+            DiagnosticData::UnreachableCode => {
+                unreachable_code::code_actions(uri, &text, diagnostic)
+            }
+        })
+        .collect()
+}
+
+/// Returns whether any of the quick fixes for the `diagnostics` needs the sessions of the whole
+/// workspace, which may have to be parsed first.
+pub(crate) fn needs_workspace_sessions(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics.iter().any(|diagnostic| {
+        matches!(
+            diagnostic_data(diagnostic),
+            Some(DiagnosticData::NamingConvention { .. })
+        )
+    })
+}
+
+fn diagnostic_data(diagnostic: &Diagnostic) -> Option<DiagnosticData> {
+    serde_json::from_value(diagnostic.data.clone()?).ok()
+}
+
+/// Returns a quick fix [CodeActionOrCommand] fixing the `diagnostic` with the given edits.
+pub(crate) fn quick_fix(
+    title: String,
+    diagnostic: &Diagnostic,
+    edit: WorkspaceEdit,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(LspCodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic.clone()]),
+        edit: Some(edit),
+        ..Default::default()
+    })
+}
+
+/// Returns a [WorkspaceEdit] applying the given edits to the document at the given [Url].
+pub(crate) fn document_edit(uri: &Url, edits: Vec<TextEdit>) -> WorkspaceEdit {
+    WorkspaceEdit {
+        changes: Some(HashMap::from([(uri.clone(), edits)])),
+        ..Default::default()
+    }
+}
+
+/// Returns a [TextEdit] inserting `new_line` above the given line of the document, indented the
+/// same as that line.
+pub(crate) fn insert_line_above(text: &str, line: u32, new_line: &str) -> TextEdit {
+    let indentation: String = text
+        .lines()
+        .nth(line as usize)
+        .unwrap_or_default()
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+    let position = Position::new(line, 0);
+    TextEdit {
+        range: Range::new(position, position),
+        new_text: format!("{indentation}{new_line}\n"),
+    }
+}
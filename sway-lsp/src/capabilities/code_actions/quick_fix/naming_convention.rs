@@ -0,0 +1,36 @@
+use super::quick_fix;
+use crate::{capabilities::rename, core::session::Session};
+use std::sync::Arc;
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Url};
+
+/// Returns a quick fix renaming the declaration to the name suggested by the naming conventions,
+/// along with all references to it.
+pub(crate) fn code_actions(
+    sessions: &[Arc<Session>],
+    session: Arc<Session>,
+    uri: &Url,
+    diagnostic: &Diagnostic,
+    suggestion: String,
+) -> Vec<CodeActionOrCommand> {
+    let name = match session
+        .token_map()
+        .token_at_position(uri, diagnostic.range.start)
+    {
+        Some((ident, _)) => ident.to_string(),
+        None => return vec![],
+    };
+    let title = format!("Rename `{name}` to `{suggestion}`");
+    match rename::rename(
+        sessions,
+        session,
+        suggestion,
+        uri.clone(),
+        diagnostic.range.start,
+    ) {
+        Ok(edit) => vec![quick_fix(title, diagnostic, edit)],
+        Err(err) => {
+            tracing::error!("{}", err.to_string());
+            vec![]
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::{document_edit, insert_line_above, quick_fix};
+use crate::core::{
+    session::Session,
+    token::{get_range_from_span, TypedAstToken},
+};
+use sway_core::transform::AttributeKind;
+use sway_types::constants::STORAGE_PURITY_ATTRIBUTE_NAME;
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, TextEdit, Url};
+
+/// Returns a quick fix giving the function that the diagnostic is in the storage attributes it
+/// needs. The `attrs` include any storage access the function already has, so an existing
+/// storage attribute is replaced.
+pub(crate) fn code_actions(
+    session: &Session,
+    uri: &Url,
+    text: &str,
+    diagnostic: &Diagnostic,
+    attrs: &str,
+) -> Vec<CodeActionOrCommand> {
+    let fn_decl = match session
+        .token_map()
+        .tokens_at_position(uri, diagnostic.range.start, Some(true))
+        .into_iter()
+        .find_map(|(_, token)| match token.typed {
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) => Some(fn_decl),
+            _ => None,
+        }) {
+        Some(fn_decl) => fn_decl,
+        None => return vec![],
+    };
+    let attribute = format!("#[{STORAGE_PURITY_ATTRIBUTE_NAME}({attrs})]");
+    let edit = match fn_decl
+        .attributes
+        .get(&AttributeKind::Storage)
+        .and_then(|storage_attrs| storage_attrs.first())
+    {
+        Some(storage_attr) => {
+            TextEdit::new(get_range_from_span(&storage_attr.span), attribute.clone())
+        }
+        None => insert_line_above(
+            text,
+            get_range_from_span(&fn_decl.span).start.line,
+            &attribute,
+        ),
+    };
+    vec![quick_fix(
+        format!("Add `{attribute}` to `{}`", fn_decl.name),
+        diagnostic,
+        document_edit(uri, vec![edit]),
+    )]
+}
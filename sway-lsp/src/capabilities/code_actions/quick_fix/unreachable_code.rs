@@ -0,0 +1,36 @@
+use super::{document_edit, quick_fix};
+use tower_lsp::lsp_types::{CodeActionOrCommand, Diagnostic, Position, Range, TextEdit, Url};
+
+/// Returns a quick fix removing the unreachable code. If the code is on lines of its own, the
+/// lines are removed entirely.
+pub(crate) fn code_actions(
+    uri: &Url,
+    text: &str,
+    diagnostic: &Diagnostic,
+) -> Vec<CodeActionOrCommand> {
+    let Range { start, end } = diagnostic.range;
+    let lines: Vec<&str> = text.lines().collect();
+    let (start_line, end_line) =
+        match (lines.get(start.line as usize), lines.get(end.line as usize)) {
+            (Some(start_line), Some(end_line)) => (start_line, end_line),
+            _ => return vec![],
+        };
+    let owns_lines = start_line
+        .chars()
+        .take(start.character as usize)
+        .all(char::is_whitespace)
+        && end_line
+            .chars()
+            .skip(end.character as usize)
+            .all(|c| c.is_whitespace() || c == ';');
+    let range = if owns_lines {
+        Range::new(Position::new(start.line, 0), Position::new(end.line + 1, 0))
+    } else {
+        diagnostic.range
+    };
+    vec![quick_fix(
+        "Remove unreachable code".to_string(),
+        diagnostic,
+        document_edit(uri, vec![TextEdit::new(range, String::new())]),
+    )]
+}
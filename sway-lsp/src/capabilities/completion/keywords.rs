@@ -0,0 +1,106 @@
+use crate::utils::keyword_docs::KeywordDocs;
+use sway_types::constants::{
+    ALLOW_ATTRIBUTE_NAME, ALLOW_DEAD_CODE_NAME, DOC_COMMENT_ATTRIBUTE_NAME, INLINE_ALWAYS_NAME,
+    INLINE_ATTRIBUTE_NAME, INLINE_NEVER_NAME, STORAGE_PURITY_ATTRIBUTE_NAME,
+    STORAGE_PURITY_READ_NAME, STORAGE_PURITY_WRITE_NAME, VALID_ATTRIBUTE_NAMES,
+};
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, Documentation, InsertTextFormat, MarkupContent, MarkupKind,
+};
+
+/// The snippets offered where an item or statement may be typed, as their labels, details and
+/// bodies.
+const SNIPPETS: &[(&str, &str, &str)] = &[
+    ("fn", "function", "fn ${1:name}($2) {\n    $0\n}"),
+    (
+        "pub fn",
+        "public function",
+        "pub fn ${1:name}($2) {\n    $0\n}",
+    ),
+    (
+        "test",
+        "test function",
+        "#[test]\nfn ${1:test_name}() {\n    $0\n}",
+    ),
+    ("struct", "struct", "struct ${1:Name} {\n    $0\n}"),
+    ("enum", "enum", "enum ${1:Name} {\n    $0\n}"),
+    ("trait", "trait", "trait ${1:Name} {\n    $0\n}"),
+    ("abi", "abi", "abi ${1:Name} {\n    $0\n}"),
+    ("impl", "impl", "impl ${1:Type} {\n    $0\n}"),
+    (
+        "impl for",
+        "trait impl",
+        "impl ${1:Trait} for ${2:Type} {\n    $0\n}",
+    ),
+    ("storage", "storage declaration", "storage {\n    $0\n}"),
+    ("if", "if expression", "if ${1:condition} {\n    $0\n}"),
+    (
+        "if else",
+        "if else expression",
+        "if ${1:condition} {\n    $2\n} else {\n    $0\n}",
+    ),
+    ("match", "match expression", "match ${1:value} {\n    $0\n}"),
+    ("while", "while loop", "while ${1:condition} {\n    $0\n}"),
+    ("let", "variable declaration", "let ${1:name} = $0;"),
+];
+
+/// Returns the keywords of the language, documented with their [KeywordDocs].
+pub(crate) fn keyword_items(keyword_docs: &KeywordDocs) -> Vec<CompletionItem> {
+    let mut keywords: Vec<(&String, &String)> = keyword_docs.iter().collect();
+    keywords.sort();
+    keywords
+        .into_iter()
+        .map(|(keyword, documentation)| CompletionItem {
+            label: keyword.clone(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            documentation: Some(Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: documentation.clone(),
+            })),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Returns the snippets for common declarations and expressions.
+pub(crate) fn snippet_items() -> Vec<CompletionItem> {
+    SNIPPETS
+        .iter()
+        .map(|(label, detail, body)| CompletionItem {
+            label: label.to_string(),
+            kind: Some(CompletionItemKind::SNIPPET),
+            detail: Some(detail.to_string()),
+            insert_text: Some(body.to_string()),
+            insert_text_format: Some(InsertTextFormat::SNIPPET),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Returns the attributes that may be written within `#[]`, along with their arguments.
+pub(crate) fn attribute_items() -> Vec<CompletionItem> {
+    VALID_ATTRIBUTE_NAMES
+        .iter()
+        // Documentation comments are written with `///`, not as an attribute.
+        .filter(|name| **name != DOC_COMMENT_ATTRIBUTE_NAME)
+        .map(|name| {
+            let insert_text = match *name {
+                STORAGE_PURITY_ATTRIBUTE_NAME => format!(
+                    "{name}(${{1|{STORAGE_PURITY_READ_NAME},{STORAGE_PURITY_WRITE_NAME}|}})"
+                ),
+                INLINE_ATTRIBUTE_NAME => {
+                    format!("{name}(${{1|{INLINE_NEVER_NAME},{INLINE_ALWAYS_NAME}|}})")
+                }
+                ALLOW_ATTRIBUTE_NAME => format!("{name}({ALLOW_DEAD_CODE_NAME})"),
+                _ => name.to_string(),
+            };
+            CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::PROPERTY),
+                insert_text: Some(insert_text),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
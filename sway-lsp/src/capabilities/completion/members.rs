@@ -5,19 +5,21 @@ use sway_core::{
 };
 use sway_types::Ident;
 use tower_lsp::lsp_types::{
-    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionTextEdit, Position,
-    Range, TextEdit,
+    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, CompletionTextEdit, Range,
+    TextEdit,
 };
 
+/// Returns the fields and methods of the expression before the `.`, given by `ident_to_complete`.
+/// The method calls replace the given [Range], which covers any part of the name already typed.
 pub(crate) fn to_completion_items(
     namespace: &Items,
     engines: Engines<'_>,
     ident_to_complete: &Ident,
     fn_decl: &TyFunctionDeclaration,
-    position: Position,
+    range: Range,
 ) -> Vec<CompletionItem> {
     type_id_of_raw_ident(engines, namespace, ident_to_complete, fn_decl)
-        .map(|type_id| completion_items_for_type_id(engines, namespace, type_id, position))
+        .map(|type_id| completion_items_for_type_id(engines, namespace, type_id, range))
         .unwrap_or_default()
 }
 
@@ -26,7 +28,7 @@ fn completion_items_for_type_id(
     engines: Engines<'_>,
     namespace: &Items,
     type_id: TypeId,
-    position: Position,
+    range: Range,
 ) -> Vec<CompletionItem> {
     let mut completion_items = vec![];
     let type_info = engines.te().get(type_id);
@@ -71,10 +73,7 @@ fn completion_items_for_type_id(
                 kind: Some(CompletionItemKind::METHOD),
                 label: format!("{}{}", method.name().clone().as_str(), params_short),
                 text_edit: Some(CompletionTextEdit::Edit(TextEdit {
-                    range: Range {
-                        start: position,
-                        end: position,
-                    },
+                    range,
                     new_text: format!("{}({})", method.name().clone().as_str(), params_edit_str),
                 })),
                 label_details: Some(CompletionItemLabelDetails {
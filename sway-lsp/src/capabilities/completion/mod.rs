@@ -0,0 +1,262 @@
+pub(crate) mod keywords;
+pub(crate) mod members;
+pub(crate) mod paths;
+pub(crate) mod scope;
+
+use crate::{
+    core::{session::Session, token::TypedAstToken},
+    utils::keyword_docs::KeywordDocs,
+};
+use sway_core::{
+    language::ty::{TyFunctionDeclaration, TyModule},
+    namespace, Engines,
+};
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, Position, Range, Url,
+};
+
+/// The context that completion is requested in, as determined by the text on the line before
+/// the cursor. It decides which kinds of items are suggested.
+#[derive(Debug, PartialEq, Eq)]
+enum CompletionContext {
+    /// After a `.`, following the expression which ends at the given character.
+    Member { receiver_end: u32 },
+    /// After `storage.`.
+    StorageField,
+    /// After the given path segments and `::`. Within `use` statements, the segments may be
+    /// empty.
+    Path { segments: Vec<String>, in_use: bool },
+    /// Within `#[`.
+    Attribute,
+    /// Anywhere else, where any item, local variable or keyword may be typed.
+    Item,
+}
+
+/// Returns the completion items for the given [Position], depending on the context it is in.
+pub(crate) fn completion_items(
+    session: &Session,
+    uri: &Url,
+    position: Position,
+    keyword_docs: &KeywordDocs,
+) -> Option<Vec<CompletionItem>> {
+    let text = session.get_text_document(uri).ok()?.get_text();
+    let line = text.lines().nth(position.line as usize).unwrap_or_default();
+    let prefix: String = line.chars().take(position.character as usize).collect();
+    // The part of the name being completed that has already been typed.
+    let word_len = prefix
+        .chars()
+        .rev()
+        .take_while(|c| is_ident_char(*c))
+        .count() as u32;
+    let word_range = Range::new(
+        Position::new(position.line, position.character.saturating_sub(word_len)),
+        position,
+    );
+
+    let compiled_program = session.compiled_program.read();
+    let program = compiled_program.typed.as_ref();
+    let type_engine = session.type_engine.read();
+    let decl_engine = session.decl_engine.read();
+    let engines = Engines::new(&type_engine, &decl_engine);
+    let namespace = program.map(|program| module_namespace(&program.root, uri));
+
+    let items = match completion_context(&prefix) {
+        CompletionContext::Member { receiver_end } => {
+            let receiver_position = Position::new(position.line, receiver_end);
+            let (ident_to_complete, _) = session
+                .token_map()
+                .token_at_position(uri, receiver_position)?;
+            let fn_decl = enclosing_fn_decl(session, uri, receiver_position)?;
+            members::to_completion_items(
+                namespace?,
+                engines,
+                &ident_to_complete,
+                &fn_decl,
+                word_range,
+            )
+        }
+        CompletionContext::StorageField => namespace?
+            .get_declared_storage(&decl_engine)?
+            .fields
+            .iter()
+            .map(|field| CompletionItem {
+                label: field.name.as_str().to_string(),
+                kind: Some(CompletionItemKind::FIELD),
+                label_details: Some(CompletionItemLabelDetails {
+                    description: Some(field.type_argument.span.as_str().to_string()),
+                    detail: None,
+                }),
+                ..Default::default()
+            })
+            .collect(),
+        CompletionContext::Path { segments, in_use } => paths::completion_items(
+            &program?.root.namespace,
+            namespace?,
+            &decl_engine,
+            &segments,
+            in_use,
+        ),
+        CompletionContext::Attribute => keywords::attribute_items(),
+        CompletionContext::Item => {
+            let mut items = vec![];
+            if let Some(fn_decl) = enclosing_fn_decl(session, uri, position) {
+                items.extend(scope::local_items(engines, &fn_decl, position));
+            }
+            if let Some(namespace) = namespace {
+                items.extend(scope::scope_items(namespace));
+            }
+            items.extend(keywords::keyword_items(keyword_docs));
+            items.extend(keywords::snippet_items());
+            items
+        }
+    };
+    Some(items)
+}
+
+/// Returns the [CompletionContext] of the text before the cursor on its line.
+fn completion_context(prefix: &str) -> CompletionContext {
+    let statement = prefix.trim_start();
+    if statement.starts_with("#[") && !statement.contains(']') {
+        return CompletionContext::Attribute;
+    }
+    if let Some(use_path) = statement
+        .strip_prefix("use ")
+        .or_else(|| statement.strip_prefix("pub use "))
+    {
+        return CompletionContext::Path {
+            segments: path_segments(&nested_use_path(use_path)),
+            in_use: true,
+        };
+    }
+
+    let before_word = prefix.trim_end_matches(is_ident_char);
+    if let Some(receiver) = before_word.strip_suffix('.') {
+        let receiver_path = receiver.trim_end_matches(is_ident_char);
+        let receiver_name = &receiver[receiver_path.len()..];
+        if receiver_name == "storage" && !receiver_path.ends_with('.') {
+            return CompletionContext::StorageField;
+        }
+        return CompletionContext::Member {
+            receiver_end: (receiver.chars().count() as u32).saturating_sub(1),
+        };
+    }
+    if before_word.ends_with("::") {
+        let path_start = before_word.trim_end_matches(|c| is_ident_char(c) || c == ':');
+        return CompletionContext::Path {
+            segments: path_segments(&before_word[path_start.len()..]),
+            in_use: false,
+        };
+    }
+    CompletionContext::Item
+}
+
+/// Returns the full path of the import being typed at the end of a `use` statement, which may
+/// be nested within braces. For example, given `std::{constants::ZERO_B256, hash::`, it returns
+/// `std::hash::`.
+fn nested_use_path(use_path: &str) -> String {
+    let mut bases = vec![];
+    let mut current = String::new();
+    for c in use_path.chars() {
+        match c {
+            '{' => bases.push(std::mem::take(&mut current)),
+            '}' => {
+                bases.pop();
+                current.clear();
+            }
+            ',' => current.clear(),
+            c if c.is_whitespace() => {}
+            c => current.push(c),
+        }
+    }
+    bases.concat() + &current
+}
+
+/// Returns the segments of a path before its last `::`, leaving out the name being typed after
+/// it.
+fn path_segments(path: &str) -> Vec<String> {
+    path.trim_end_matches(is_ident_char)
+        .split("::")
+        .filter(|segment| !segment.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns the function declaration that the [Position] is in.
+fn enclosing_fn_decl(
+    session: &Session,
+    uri: &Url,
+    position: Position,
+) -> Option<TyFunctionDeclaration> {
+    session
+        .token_map()
+        .tokens_at_position(uri, position, Some(true))
+        .into_iter()
+        .find_map(|(_, token)| match token.typed {
+            Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) => Some(fn_decl),
+            _ => None,
+        })
+}
+
+/// Returns the namespace of the submodule whose source file is at the given [Url], or that of
+/// the root module otherwise.
+fn module_namespace<'a>(root: &'a TyModule, uri: &Url) -> &'a namespace::Module {
+    let file_path = match uri.to_file_path() {
+        Ok(file_path) => file_path,
+        Err(_) => return &root.namespace,
+    };
+    root.submodules_recursive()
+        .find(|(_, submodule)| {
+            submodule.module.span.path().map(|path| path.as_path()) == Some(file_path.as_path())
+        })
+        .map_or(&root.namespace, |(_, submodule)| {
+            &submodule.module.namespace
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completion_context_of_prefix() {
+        assert_eq!(
+            completion_context("    foo."),
+            CompletionContext::Member { receiver_end: 6 }
+        );
+        assert_eq!(
+            completion_context("    foo.ba"),
+            CompletionContext::Member { receiver_end: 6 }
+        );
+        assert_eq!(
+            completion_context("    storage.co"),
+            CompletionContext::StorageField
+        );
+        assert_eq!(
+            completion_context("    let x = Color::Bl"),
+            CompletionContext::Path {
+                segments: vec!["Color".to_string()],
+                in_use: false
+            }
+        );
+        assert_eq!(
+            completion_context("use std::{constants::ZERO_B256, hash::sh"),
+            CompletionContext::Path {
+                segments: vec!["std".to_string(), "hash".to_string()],
+                in_use: true
+            }
+        );
+        assert_eq!(
+            completion_context("use "),
+            CompletionContext::Path {
+                segments: vec![],
+                in_use: true
+            }
+        );
+        assert_eq!(completion_context("#[sto"), CompletionContext::Attribute);
+        assert_eq!(completion_context("    let x = "), CompletionContext::Item);
+    }
+}
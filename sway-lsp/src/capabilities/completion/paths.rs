@@ -0,0 +1,82 @@
+use super::scope::declaration_item;
+use sway_core::{decl_engine::DeclEngine, language::ty::TyDeclaration, namespace};
+use sway_types::Ident;
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind, CompletionItemLabelDetails};
+
+/// Returns the items that may follow the given path segments and `::`.
+///
+/// If the path names a module, resolved from the root of the package, these are its submodules
+/// and public items. Outside of `use` statements, the path may also name an enum in the scope of
+/// `namespace`, whose variants are returned.
+pub(crate) fn completion_items(
+    root: &namespace::Module,
+    namespace: &namespace::Items,
+    decl_engine: &DeclEngine,
+    segments: &[String],
+    in_use: bool,
+) -> Vec<CompletionItem> {
+    let path: Vec<Ident> = segments
+        .iter()
+        .map(|segment| Ident::new_no_span(segment.clone()))
+        .collect();
+    if let Some(module) = root.submodule(&path) {
+        return module_items(module, decl_engine);
+    }
+    match segments.last() {
+        Some(name) if !in_use => enum_variant_items(namespace, decl_engine, name),
+        _ => vec![],
+    }
+}
+
+/// Returns the submodules and public items of the given module.
+fn module_items(module: &namespace::Module, decl_engine: &DeclEngine) -> Vec<CompletionItem> {
+    let submodules = module.submodules().keys().map(|name| CompletionItem {
+        label: name.clone(),
+        kind: Some(CompletionItemKind::MODULE),
+        ..Default::default()
+    });
+    let items = module
+        .symbols()
+        .iter()
+        .filter(|(_, decl)| decl.visibility(decl_engine).is_public())
+        .filter_map(|(name, decl)| declaration_item(name, decl));
+    submodules.chain(items).collect()
+}
+
+/// Returns the variants of the enum named `name` in the given namespace.
+fn enum_variant_items(
+    namespace: &namespace::Items,
+    decl_engine: &DeclEngine,
+    name: &str,
+) -> Vec<CompletionItem> {
+    namespace
+        .symbols()
+        .iter()
+        .chain(namespace.get_all_imported_symbols())
+        .find_map(|(ident, decl)| match decl {
+            TyDeclaration::EnumDeclaration { decl_id, .. } if ident.as_str() == name => {
+                Some(decl_engine.get_enum(decl_id))
+            }
+            _ => None,
+        })
+        .map(|enum_decl| {
+            enum_decl
+                .variants
+                .iter()
+                .map(|variant| {
+                    // Unit variants may be declared without a type.
+                    let type_str = variant.type_argument.span.as_str();
+                    CompletionItem {
+                        label: variant.name.as_str().to_string(),
+                        kind: Some(CompletionItemKind::ENUM_MEMBER),
+                        label_details: Some(CompletionItemLabelDetails {
+                            description: (!type_str.is_empty()).then(|| type_str.to_string()),
+                            detail: None,
+                        }),
+                        ..Default::default()
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,136 @@
+use crate::core::token::get_range_from_span;
+use std::collections::HashSet;
+use sway_core::{
+    language::ty::{
+        TyAstNodeContent, TyCodeBlock, TyDeclaration, TyExpression, TyExpressionVariant,
+        TyFunctionDeclaration,
+    },
+    namespace::Items,
+    Engines, TypeId,
+};
+use sway_types::Ident;
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionItemLabelDetails, Position,
+};
+
+/// Returns the parameters of the given [TyFunctionDeclaration] and the variables declared before
+/// the [Position] in its body and in the nested blocks enclosing the [Position]. Variables
+/// shadowed by later declarations are left out.
+pub(crate) fn local_items(
+    engines: Engines<'_>,
+    fn_decl: &TyFunctionDeclaration,
+    position: Position,
+) -> Vec<CompletionItem> {
+    let mut locals: Vec<(&Ident, TypeId)> = fn_decl
+        .parameters
+        .iter()
+        .map(|param| (&param.name, param.type_argument.type_id))
+        .collect();
+    collect_variables(&fn_decl.body, position, &mut locals);
+
+    let mut seen = HashSet::new();
+    locals
+        .into_iter()
+        .rev()
+        .filter(|(name, _)| seen.insert(name.as_str().to_string()))
+        .map(|(name, type_id)| CompletionItem {
+            label: name.as_str().to_string(),
+            kind: Some(CompletionItemKind::VARIABLE),
+            label_details: Some(CompletionItemLabelDetails {
+                description: Some(engines.help_out(type_id).to_string()),
+                detail: None,
+            }),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Collects the variables declared in the [TyCodeBlock] before the [Position], followed by those
+/// of the nested block enclosing the [Position], if any.
+fn collect_variables<'a>(
+    block: &'a TyCodeBlock,
+    position: Position,
+    variables: &mut Vec<(&'a Ident, TypeId)>,
+) {
+    for node in &block.contents {
+        let range = get_range_from_span(&node.span);
+        if range.end <= position {
+            if let TyAstNodeContent::Declaration(TyDeclaration::VariableDeclaration(
+                variable_decl,
+            )) = &node.content
+            {
+                variables.push((&variable_decl.name, variable_decl.return_type));
+            }
+        } else if range.start <= position {
+            let expr = match &node.content {
+                TyAstNodeContent::Declaration(TyDeclaration::VariableDeclaration(
+                    variable_decl,
+                )) => Some(&variable_decl.body),
+                TyAstNodeContent::Expression(expr)
+                | TyAstNodeContent::ImplicitReturnExpression(expr) => Some(expr),
+                _ => None,
+            };
+            if let Some(nested) = expr.and_then(|expr| enclosing_block(expr, position)) {
+                collect_variables(nested, position, variables);
+            }
+            return;
+        }
+    }
+}
+
+/// Returns the innermost [TyCodeBlock] of the [TyExpression] that encloses the [Position].
+fn enclosing_block(expr: &TyExpression, position: Position) -> Option<&TyCodeBlock> {
+    let range = get_range_from_span(&expr.span);
+    if position < range.start || range.end < position {
+        return None;
+    }
+    match &expr.expression {
+        TyExpressionVariant::CodeBlock(block)
+        | TyExpressionVariant::WhileLoop { body: block, .. } => Some(block),
+        TyExpressionVariant::IfExp { then, r#else, .. } => {
+            enclosing_block(then, position).or_else(|| {
+                r#else
+                    .as_ref()
+                    .and_then(|r#else| enclosing_block(r#else, position))
+            })
+        }
+        TyExpressionVariant::MatchExp { desugared, .. } => enclosing_block(desugared, position),
+        TyExpressionVariant::Return(expr) => enclosing_block(expr, position),
+        _ => None,
+    }
+}
+
+/// Returns the items declared in or imported into the given namespace.
+pub(crate) fn scope_items(namespace: &Items) -> Vec<CompletionItem> {
+    namespace
+        .symbols()
+        .iter()
+        .chain(namespace.get_all_imported_symbols())
+        .filter_map(|(name, decl)| declaration_item(name, decl))
+        .collect()
+}
+
+/// Returns the [CompletionItem] for a declaration that can be referred to by its name.
+pub(crate) fn declaration_item(name: &Ident, decl: &TyDeclaration) -> Option<CompletionItem> {
+    let kind = match decl {
+        TyDeclaration::FunctionDeclaration { .. } => CompletionItemKind::FUNCTION,
+        TyDeclaration::ConstantDeclaration { .. } => CompletionItemKind::CONSTANT,
+        TyDeclaration::StructDeclaration { .. } | TyDeclaration::TypeAliasDeclaration { .. } => {
+            CompletionItemKind::STRUCT
+        }
+        TyDeclaration::EnumDeclaration { .. } => CompletionItemKind::ENUM,
+        TyDeclaration::TraitDeclaration { .. } | TyDeclaration::AbiDeclaration { .. } => {
+            CompletionItemKind::INTERFACE
+        }
+        _ => return None,
+    };
+    Some(CompletionItem {
+        label: name.as_str().to_string(),
+        kind: Some(kind),
+        label_details: Some(CompletionItemLabelDetails {
+            description: Some(decl.friendly_type_name().to_string()),
+            detail: None,
+        }),
+        ..Default::default()
+    })
+}
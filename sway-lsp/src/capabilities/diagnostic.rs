@@ -1,8 +1,12 @@
+use serde::{Deserialize, Serialize};
 use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, Position, Range};
 
 use sway_error::warning::CompileWarning;
-use sway_error::{error::CompileError, warning::Warning};
-use sway_types::{LineCol, Spanned};
+use sway_error::{
+    error::{CompileError, InterfaceName},
+    warning::Warning,
+};
+use sway_types::{style, LineCol, Spanned};
 
 #[derive(Debug)]
 pub struct Diagnostics {
@@ -10,22 +14,49 @@ pub struct Diagnostics {
     pub errors: Vec<Diagnostic>,
 }
 
+/// The data attached to a [Diagnostic], from which its quick fixes are created once the client
+/// requests the code actions for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DiagnosticData {
+    /// A symbol which isn't in scope, and may need to be imported.
+    UnknownSymbol { name: String },
+    /// A function which accesses storage without the attributes to do so.
+    MissingStorageAttribute { attrs: String },
+    /// An implementation of an ABI or trait which is missing some of its functions.
+    MissingMethods {
+        interface: String,
+        functions: Vec<String>,
+    },
+    /// A declaration which is never used.
+    DeadCode,
+    /// A name which doesn't follow the naming conventions.
+    NamingConvention { suggestion: String },
+    /// Code which is never executed.
+    UnreachableCode,
+}
+
 fn get_error_diagnostics(errors: &[CompileError]) -> Vec<Diagnostic> {
     Vec::from_iter(errors.iter().map(|error| Diagnostic {
         range: get_range(error.span().line_col()),
         severity: Some(DiagnosticSeverity::ERROR),
         message: format!("{error}"),
+        data: get_error_diagnostic_data(error).and_then(|data| serde_json::to_value(data).ok()),
         ..Default::default()
     }))
 }
 
 fn get_warning_diagnostics(warnings: &[CompileWarning]) -> Vec<Diagnostic> {
-    Vec::from_iter(warnings.iter().map(|warning| Diagnostic {
-        range: get_range(warning.span().line_col()),
-        severity: Some(DiagnosticSeverity::WARNING),
-        message: warning.to_friendly_warning_string(),
-        tags: get_warning_diagnostic_tags(&warning.warning_content),
-        ..Default::default()
+    Vec::from_iter(warnings.iter().map(|warning| {
+        Diagnostic {
+            range: get_range(warning.span().line_col()),
+            severity: Some(DiagnosticSeverity::WARNING),
+            message: warning.to_friendly_warning_string(),
+            tags: get_warning_diagnostic_tags(&warning.warning_content),
+            data: get_warning_diagnostic_data(&warning.warning_content)
+                .and_then(|data| serde_json::to_value(data).ok()),
+            ..Default::default()
+        }
     }))
 }
 
@@ -61,3 +92,57 @@ fn get_warning_diagnostic_tags(warning: &Warning) -> Option<Vec<DiagnosticTag>>
         _ => None,
     }
 }
+
+fn get_error_diagnostic_data(error: &CompileError) -> Option<DiagnosticData> {
+    match error {
+        CompileError::SymbolNotFound { name, .. }
+        | CompileError::UnknownVariable { var_name: name, .. } => {
+            Some(DiagnosticData::UnknownSymbol {
+                name: name.to_string(),
+            })
+        }
+        CompileError::StorageAccessMismatch { attrs, .. }
+        | CompileError::TraitImplPurityMismatch { attrs, .. }
+        | CompileError::ImpureInPureContext { attrs, .. } => {
+            Some(DiagnosticData::MissingStorageAttribute {
+                attrs: attrs.clone(),
+            })
+        }
+        CompileError::MissingInterfaceSurfaceMethods {
+            missing_functions,
+            interface_name: InterfaceName::Abi(name) | InterfaceName::Trait(name),
+            ..
+        } => Some(DiagnosticData::MissingMethods {
+            interface: name.to_string(),
+            functions: missing_functions.clone(),
+        }),
+        _ => None,
+    }
+}
+
+fn get_warning_diagnostic_data(warning: &Warning) -> Option<DiagnosticData> {
+    let suggestion = match warning {
+        Warning::NonClassCaseStructName { struct_name: name }
+        | Warning::NonClassCaseTypeParameter { name }
+        | Warning::NonClassCaseTraitName { name }
+        | Warning::NonClassCaseEnumName { enum_name: name }
+        | Warning::NonClassCaseEnumVariantName { variant_name: name } => {
+            style::to_upper_camel_case(name.as_str())
+        }
+        Warning::NonSnakeCaseStructFieldName { field_name: name }
+        | Warning::NonSnakeCaseFunctionName { name } => style::to_snake_case(name.as_str()),
+        Warning::NonScreamingSnakeCaseConstName { name } => {
+            style::to_screaming_snake_case(name.as_str())
+        }
+        // Only declarations which can be given attributes are fixed, unused variables can't be.
+        Warning::DeadEnumDeclaration
+        | Warning::DeadFunctionDeclaration
+        | Warning::DeadMethod
+        | Warning::DeadStructDeclaration
+        | Warning::DeadTrait
+        | Warning::StructFieldNeverRead => return Some(DiagnosticData::DeadCode),
+        Warning::UnreachableCode => return Some(DiagnosticData::UnreachableCode),
+        _ => return None,
+    };
+    Some(DiagnosticData::NamingConvention { suggestion })
+}
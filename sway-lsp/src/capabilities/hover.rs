@@ -4,13 +4,13 @@ use crate::{
         token::{get_range_from_span, to_ident_key, SymbolKind, Token, TypedAstToken},
     },
     utils::{
-        attributes::doc_comment_attributes, keyword_docs::KeywordDocs, markdown, markup::Markup,
+        attributes::format_doc_attributes, keyword_docs::KeywordDocs, markdown, markup::Markup,
     },
 };
 use std::sync::Arc;
 use sway_core::{
     language::{ty, Visibility},
-    Engines, TypeId,
+    Engines, TypeId, TypeInfo,
 };
 use sway_types::{Ident, Span, Spanned};
 use tower_lsp::lsp_types::{self, Position, Url};
@@ -79,20 +79,6 @@ fn extract_fn_signature(span: &Span) -> String {
     value.split('{').take(1).map(|v| v.trim()).collect()
 }
 
-fn format_doc_attributes(token: &Token) -> String {
-    let mut doc_comment = String::new();
-    if let Some(attributes) = doc_comment_attributes(token) {
-        doc_comment = attributes
-            .iter()
-            .map(|attribute| {
-                let comment = attribute.args.first().unwrap().name.as_str();
-                format!("{comment}\n")
-            })
-            .collect()
-    }
-    doc_comment
-}
-
 fn format_visibility_hover(visibility: Visibility, decl_name: &str, token_name: &str) -> String {
     format!(
         "{}{} {}",
@@ -168,11 +154,35 @@ fn hover_format(engines: Engines<'_>, token: &Token, ident: &Ident) -> lsp_types
                 ty::TyDeclaration::AbiDeclaration { .. } => {
                     Some(format!("{} {}", decl.friendly_type_name(), &token_name))
                 }
+                ty::TyDeclaration::TypeAliasDeclaration { decl_id, .. } => {
+                    let type_alias_decl = decl_engine.get_type_alias(decl_id);
+                    Some(format!(
+                        "{}type {} = {}",
+                        visibility_as_str(&type_alias_decl.visibility),
+                        &token_name,
+                        engines.help_out(type_alias_decl.ty.type_id)
+                    ))
+                }
                 _ => None,
             },
             TypedAstToken::TypedFunctionDeclaration(func) => {
                 Some(extract_fn_signature(&func.span()))
             }
+            TypedAstToken::TypedTraitType(type_decl) => {
+                match engines.te().get(type_decl.ty.type_id) {
+                    // An associated type without a definition, as declared in a trait.
+                    TypeInfo::UnknownGeneric { name, .. }
+                        if name.as_str() == format!("Self::{token_name}") =>
+                    {
+                        Some(format!("type {token_name}"))
+                    }
+                    _ => Some(format!(
+                        "type {} = {}",
+                        &token_name,
+                        engines.help_out(type_decl.ty.type_id)
+                    )),
+                }
+            }
             TypedAstToken::TypedFunctionParameter(param) => Some(format_name_with_type(
                 param.name.as_str(),
                 &param.type_argument.type_id,
@@ -0,0 +1,163 @@
+use crate::core::{
+    session::Session,
+    token::{to_ident_key, TypedAstToken},
+};
+use std::{collections::HashSet, sync::Arc};
+use sway_core::{
+    decl_engine::{DeclEngine, InterfaceDeclId},
+    language::ty,
+};
+use sway_types::{Ident, Span, Spanned};
+use tower_lsp::lsp_types::{Location, Position, Url};
+
+/// Returns the locations of the implementations of the trait or ABI, or of the trait or ABI
+/// method, that the token at the given [Position] refers to.
+///
+/// For a trait or ABI, these are the types named in its `impl` blocks. For a method, these
+/// are the names of the methods implementing it.
+pub fn implementations(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<Vec<Location>> {
+    let decl_engine = &*session.decl_engine.read();
+    let (_, token) = session.token_map().token_at_position(url, position)?;
+    let decl_ident = token.declared_token_ident(&session.type_engine.read(), decl_engine)?;
+    let decl_token = session
+        .token_map()
+        .try_get(&to_ident_key(&decl_ident))
+        .try_unwrap()
+        .map(|item| item.value().clone())?;
+
+    let (trait_ident, method_name) = match decl_token.typed? {
+        TypedAstToken::TypedDeclaration(
+            ty::TyDeclaration::TraitDeclaration { .. } | ty::TyDeclaration::AbiDeclaration { .. },
+        ) => (decl_ident, None),
+        TypedAstToken::TypedTraitFn(_) => {
+            let trait_ident = interface_of_method(&session, decl_engine, &decl_ident)?;
+            (trait_ident, Some(decl_ident))
+        }
+        TypedAstToken::TypedFunctionDeclaration(func_decl) => {
+            // Calls to methods resolve to the method of an impl block rather than to the trait.
+            let trait_ident = match &func_decl.implementing_type {
+                Some(ty::TyDeclaration::ImplTrait { decl_id, .. }) => decl_engine
+                    .get_impl_trait(decl_id)
+                    .trait_decl_ref
+                    .map(|decl_ref| interface_name(decl_engine, decl_ref.id()))?,
+                _ => interface_of_method(&session, decl_engine, &decl_ident)?,
+            };
+            (trait_ident, Some(decl_ident))
+        }
+        _ => return None,
+    };
+
+    let compiled_program = &*session.compiled_program.read();
+    let impl_spans: HashSet<Span> = compiled_program
+        .typed
+        .as_ref()?
+        .root
+        .namespace
+        .get_impls_for_trait_name(&trait_ident)
+        .into_iter()
+        .map(|(_, impl_span)| impl_span)
+        .collect();
+
+    let mut locations: Vec<Location> = vec![];
+    for item in session.token_map().iter() {
+        let impl_trait = match &item.value().typed {
+            Some(TypedAstToken::TypedDeclaration(ty::TyDeclaration::ImplTrait {
+                decl_id, ..
+            })) => decl_engine.get_impl_trait(decl_id),
+            _ => continue,
+        };
+        if !impl_spans.contains(&impl_trait.span) {
+            continue;
+        }
+        // Traits are looked up by name, so make sure the impl is of the trait we are looking for.
+        let implements_trait = impl_trait
+            .trait_decl_ref
+            .as_ref()
+            .map_or(false, |decl_ref| {
+                interface_name(decl_engine, decl_ref.id()).span() == trait_ident.span()
+            });
+        if !implements_trait {
+            continue;
+        }
+        let ident = match &method_name {
+            Some(method_name) => impl_trait.items.iter().find_map(|item| match item {
+                ty::TyTraitItem::Fn(decl_ref) => {
+                    let func_decl = decl_engine.get_function(decl_ref);
+                    (func_decl.name.as_str() == method_name.as_str()).then_some(func_decl.name)
+                }
+                ty::TyTraitItem::Constant(_) | ty::TyTraitItem::Type(_) => None,
+            }),
+            None => Some(Ident::new(impl_trait.implementing_for.span.clone())),
+        };
+        if let Some(location) = ident.and_then(|ident| session.ident_location(&ident)) {
+            // The same impl block is found in every module that imports it, and through each of
+            // the tokens referring to it.
+            if !locations.contains(&location) {
+                locations.push(location);
+            }
+        }
+    }
+    Some(locations)
+}
+
+/// Returns the name of the trait or ABI declaration that declares the method named
+/// `method_ident`, either in its interface surface or as a provided method.
+fn interface_of_method(
+    session: &Session,
+    decl_engine: &DeclEngine,
+    method_ident: &Ident,
+) -> Option<Ident> {
+    session.token_map().iter().find_map(|item| {
+        let ((ident, _), token) = item.pair();
+        let (name, interface_surface, items) = match &token.typed {
+            Some(TypedAstToken::TypedDeclaration(ty::TyDeclaration::TraitDeclaration {
+                decl_id,
+                ..
+            })) => {
+                let trait_decl = decl_engine.get_trait(decl_id);
+                (
+                    trait_decl.name,
+                    trait_decl.interface_surface,
+                    trait_decl.items,
+                )
+            }
+            Some(TypedAstToken::TypedDeclaration(ty::TyDeclaration::AbiDeclaration {
+                decl_id,
+                ..
+            })) => {
+                let abi_decl = decl_engine.get_abi(decl_id);
+                (abi_decl.name, abi_decl.interface_surface, abi_decl.items)
+            }
+            _ => return None,
+        };
+        // Only the declaration itself, not references to it, is of interest.
+        if name.span() != ident.span() {
+            return None;
+        }
+        let is_declared = interface_surface.iter().any(|item| match item {
+            ty::TyTraitInterfaceItem::TraitFn(decl_ref) => {
+                decl_engine.get_trait_fn(decl_ref).name.span() == method_ident.span()
+            }
+            ty::TyTraitInterfaceItem::Constant(_) | ty::TyTraitInterfaceItem::Type(_) => false,
+        });
+        let is_provided = items.iter().any(|item| match item {
+            ty::TyTraitItem::Fn(decl_ref) => {
+                decl_engine.get_function(decl_ref).name.span() == method_ident.span()
+            }
+            ty::TyTraitItem::Constant(_) | ty::TyTraitItem::Type(_) => false,
+        });
+        (is_declared || is_provided).then(|| ident.clone())
+    })
+}
+
+/// Returns the name of the trait or ABI declaration that `interface_id` refers to.
+fn interface_name(decl_engine: &DeclEngine, interface_id: &InterfaceDeclId) -> Ident {
+    match interface_id {
+        InterfaceDeclId::Trait(decl_id) => decl_engine.get_trait(decl_id).name,
+        InterfaceDeclId::Abi(decl_id) => decl_engine.get_abi(decl_id).name,
+    }
+}
@@ -0,0 +1,95 @@
+use super::{
+    keys::{keys_of_table, ManifestKey, TABLES},
+    manifest_entries, table_at_line,
+};
+use forc_pkg::BuildProfile;
+use toml_edit::Key;
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, Documentation, MarkupContent, MarkupKind, Position,
+};
+
+/// Returns the keys that may be written at the given [Position] of the manifest: the names of
+/// tables in a table header, and otherwise the keys of the current table or inline table.
+pub(crate) fn completion_items(text: &str, position: Position) -> Vec<CompletionItem> {
+    let line = text.lines().nth(position.line as usize).unwrap_or_default();
+    let prefix: String = line.chars().take(position.character as usize).collect();
+    let statement = prefix.trim_start();
+
+    if let Some(header) = statement.strip_prefix('[') {
+        let header = header.trim_start_matches('[');
+        return match header.split_once('.') {
+            None => TABLES.iter().map(table_item).collect(),
+            Some(("build-profile", _)) => [BuildProfile::DEBUG, BuildProfile::RELEASE]
+                .into_iter()
+                .map(|profile| CompletionItem {
+                    label: profile.to_string(),
+                    kind: Some(CompletionItemKind::MODULE),
+                    ..Default::default()
+                })
+                .collect(),
+            Some(_) => vec![],
+        };
+    }
+
+    // The line being edited is usually incomplete, so the table is found without it.
+    let others: Vec<&str> = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            if index == position.line as usize {
+                ""
+            } else {
+                line
+            }
+        })
+        .collect();
+    let entries = manifest_entries(&others.join("\n"));
+    let mut table = table_at_line(&entries, position.line);
+    if let Some((key, value)) = statement.split_once('=') {
+        // Only the keys of inline tables are completed after a `=`, such as the keys of
+        // `std = { path = "../std", `.
+        let inline_table = match value.rsplit_once('{') {
+            Some((_, inline_table)) if !inline_table.contains('}') => inline_table,
+            _ => return vec![],
+        };
+        let pair = inline_table.rsplit(',').next().unwrap_or_default();
+        if pair.contains('=') {
+            return vec![];
+        }
+        match Key::parse(key.trim()) {
+            Ok(keys) => table.extend(keys.iter().map(|key| key.get().to_string())),
+            Err(_) => return vec![],
+        }
+    }
+    keys_of_table(&table)
+        .unwrap_or_default()
+        .into_iter()
+        .map(key_item)
+        .collect()
+}
+
+fn table_item(table: &ManifestKey) -> CompletionItem {
+    CompletionItem {
+        label: table.name.to_string(),
+        kind: Some(CompletionItemKind::MODULE),
+        documentation: Some(documentation(table)),
+        ..Default::default()
+    }
+}
+
+fn key_item(key: &ManifestKey) -> CompletionItem {
+    CompletionItem {
+        label: key.name.to_string(),
+        kind: Some(CompletionItemKind::PROPERTY),
+        documentation: Some(documentation(key)),
+        insert_text: Some(format!("{} = ", key.name)),
+        ..Default::default()
+    }
+}
+
+fn documentation(key: &ManifestKey) -> Documentation {
+    Documentation::MarkupContent(MarkupContent {
+        kind: MarkupKind::Markdown,
+        value: key.docs.to_string(),
+    })
+}
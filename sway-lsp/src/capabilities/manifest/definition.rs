@@ -0,0 +1,41 @@
+use super::manifest_entries;
+use sway_utils::constants::MANIFEST_FILE_NAME;
+use tower_lsp::lsp_types::{GotoDefinitionResponse, Location, Position, Range, Url};
+
+/// Returns the location of the manifest of the path dependency declared at the given [Position],
+/// e.g. on `std` or `path = "../std"` in `std = { path = "../std" }`.
+pub(crate) fn goto_definition(
+    uri: &Url,
+    text: &str,
+    position: Position,
+) -> Option<GotoDefinitionResponse> {
+    let entries = manifest_entries(text);
+    let entry = entries
+        .iter()
+        .rev()
+        .find(|entry| entry.range.start.line == position.line && entry.range.start <= position)?;
+    let dependency_path_len = match entry.path.first()?.as_str() {
+        "dependencies" | "contract-dependencies" => 2,
+        "patch" => 3,
+        _ => return None,
+    };
+    let dependency_path = entry.path.get(..dependency_path_len)?;
+
+    let document = text.parse::<toml_edit::Document>().ok()?;
+    let dependency = dependency_path
+        .iter()
+        .try_fold(document.as_item(), |item, key| item.get(key.as_str()))?;
+    let relative_path = dependency.get("path")?.as_str()?;
+
+    let manifest_path = uri.to_file_path().ok()?;
+    let manifest_dir = manifest_path.parent()?;
+    let manifest_path = manifest_dir.join(relative_path).join(MANIFEST_FILE_NAME);
+    if !manifest_path.exists() {
+        return None;
+    }
+    let uri = Url::from_file_path(manifest_path.canonicalize().ok()?).ok()?;
+    Some(GotoDefinitionResponse::Scalar(Location::new(
+        uri,
+        Range::default(),
+    )))
+}
@@ -0,0 +1,267 @@
+use super::{entry_range, keys::keys_of_table, manifest_entries, ManifestEntry};
+use forc_pkg::{
+    manifest::{Dependency, MemberManifestFiles},
+    source::Source,
+    PackageManifest, WorkspaceManifest,
+};
+use std::path::Path;
+use sway_utils::constants::SRC_DIR;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range, Url};
+
+/// Returns the diagnostics of the `Forc.toml` at the given [Url] with the given contents.
+///
+/// The manifest is parsed and validated by `forc_pkg`. Its errors are placed at the keys they
+/// are about, and the keys which aren't part of the manifest format are warned about.
+pub(crate) fn diagnostics(uri: &Url, text: &str) -> Vec<Diagnostic> {
+    let manifest_dir = match Path::new(uri.path()).parent() {
+        Some(dir) => dir,
+        None => return vec![],
+    };
+    let entries = manifest_entries(text);
+    let is_workspace = entries
+        .iter()
+        .any(|entry| entry.path.first().map(String::as_str) == Some("workspace"));
+
+    let mut diagnostics = vec![];
+    let unused_keys = if is_workspace {
+        match WorkspaceManifest::from_string_with_unused_keys(text) {
+            Ok((manifest, unused_keys)) => {
+                if let Err(err) = manifest.validate(manifest_dir) {
+                    let range = entry_range(&entries, &["workspace", "members"]);
+                    diagnostics.push(error(range, err.to_string()));
+                }
+                unused_keys
+            }
+            Err(err) => return vec![parse_error(text, &err.to_string())],
+        }
+    } else {
+        match PackageManifest::from_string_with_unused_keys(text) {
+            Ok((manifest, unused_keys)) => {
+                diagnostics.extend(package_diagnostics(&manifest, manifest_dir, &entries));
+                unused_keys
+            }
+            Err(err) => return vec![parse_error(text, &err.to_string())],
+        }
+    };
+    diagnostics.extend(unused_key_warnings(&entries, unused_keys));
+    diagnostics
+}
+
+/// Returns the errors of validating the package manifest, and the sources of its dependencies.
+fn package_diagnostics(
+    manifest: &PackageManifest,
+    manifest_dir: &Path,
+    entries: &[ManifestEntry],
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    if let Err(err) = manifest.validate() {
+        let message = err.to_string();
+        // The names of the package and its organization are validated in turn.
+        let is_organization_error = manifest.project.organization.as_ref().map_or(false, |org| {
+            message.contains("organization name")
+                || (org != &manifest.project.name && message.contains(&format!("`{org}`")))
+        });
+        let key = if is_organization_error {
+            "organization"
+        } else {
+            "name"
+        };
+        diagnostics.push(error(entry_range(entries, &["project", key]), message));
+    }
+
+    // Mirrors `PackageManifestFile::validate`, which needs the manifest to be read from disk.
+    let entry_path = manifest_dir.join(SRC_DIR).join(&manifest.project.entry);
+    if !entry_path.exists() {
+        diagnostics.push(error(
+            entry_range(entries, &["project", "entry"]),
+            format!(
+                "failed to validate path from entry field {:?} in Forc manifest file.",
+                manifest.project.entry
+            ),
+        ));
+    }
+
+    let dependencies = manifest
+        .deps()
+        .map(|(name, dep)| (vec!["dependencies", name.as_str()], dep))
+        .chain(manifest.contract_deps().map(|(name, contract_dep)| {
+            (
+                vec!["contract-dependencies", name.as_str()],
+                &contract_dep.dependency,
+            )
+        }))
+        .chain(manifest.patches().flat_map(|(source, patches)| {
+            patches
+                .iter()
+                .map(move |(name, dep)| (vec!["patch", source.as_str(), name.as_str()], dep))
+        }));
+    for (path, dep) in dependencies {
+        diagnostics.extend(dependency_error(entries, &path, dep, manifest_dir));
+    }
+    diagnostics
+}
+
+/// Returns an error if the source of the dependency at the given path can't be determined from
+/// its declaration, e.g. if its `path` doesn't exist.
+fn dependency_error(
+    entries: &[ManifestEntry],
+    path: &[&str],
+    dep: &Dependency,
+    manifest_dir: &Path,
+) -> Option<Diagnostic> {
+    // Dependencies which aren't declared, such as the implicit `std`, can't be located.
+    let entry = entries.iter().find(|entry| {
+        entry
+            .path
+            .iter()
+            .map(String::as_str)
+            .eq(path.iter().copied())
+    })?;
+    Source::from_manifest_dep(manifest_dir, dep, &MemberManifestFiles::default())
+        .err()
+        .map(|err| error(entry.range, err.to_string()))
+}
+
+/// Returns warnings for the keys which aren't part of the manifest format. These are those
+/// reported by `forc_pkg`, and the unknown keys of dependencies, which it can't report.
+fn unused_key_warnings(entries: &[ManifestEntry], unused_keys: Vec<String>) -> Vec<Diagnostic> {
+    let mut unused_paths: Vec<String> = entries
+        .iter()
+        .filter(|entry| {
+            entry.path.split_last().map_or(false, |(name, table)| {
+                keys_of_table(table).map_or(false, |keys| {
+                    !keys.iter().any(|key| key.name == name.as_str())
+                })
+            })
+        })
+        .map(|entry| entry.path.join("."))
+        .chain(unused_keys)
+        .collect();
+    unused_paths.sort();
+    unused_paths.dedup();
+    unused_paths
+        .into_iter()
+        .filter_map(|unused_path| {
+            // Tables like `[unknown.table]` have no entry of their own.
+            let table_prefix = format!("{unused_path}.");
+            let entry = entries.iter().find(|entry| {
+                let path = entry.path.join(".");
+                path == unused_path || path.starts_with(&table_prefix)
+            })?;
+            Some(Diagnostic {
+                range: entry.range,
+                severity: Some(DiagnosticSeverity::WARNING),
+                message: format!("unused manifest key: {unused_path}"),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// Returns the error of a manifest that failed to parse. The TOML errors end with the line and
+/// column that they occurred at, e.g. `at line 3 column 6`, otherwise the error is placed at the
+/// start of the manifest.
+fn parse_error(text: &str, message: &str) -> Diagnostic {
+    let number_after = |pattern: &str| -> Option<u32> {
+        let start = message.rfind(pattern)? + pattern.len();
+        let digits: String = message[start..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        digits.parse().ok()
+    };
+    let range = match (number_after(" at line "), number_after(" column ")) {
+        (Some(line), Some(column)) => {
+            let line = line.saturating_sub(1);
+            let line_len = text
+                .lines()
+                .nth(line as usize)
+                .unwrap_or_default()
+                .chars()
+                .count() as u32;
+            Range::new(
+                Position::new(line, column.saturating_sub(1).min(line_len)),
+                Position::new(line, line_len),
+            )
+        }
+        _ => Range::default(),
+    };
+    error(range, message.to_string())
+}
+
+fn error(range: Range, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        message,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_lsp_test_utils::{get_absolute_path, get_url};
+
+    #[test]
+    fn diagnostics_of_unused_keys_and_dependencies() {
+        let uri = get_url(&get_absolute_path(
+            "sway-lsp/tests/fixtures/manifest/Forc.toml",
+        ));
+        let text = r#"[project]
+authors = ["Fuel Labs <contact@fuel.sh>"]
+entry = "main.sw"
+license = "Apache-2.0"
+name = "manifest"
+colour = "blue"
+
+[dependencies]
+std = { path = "../../../../sway-lib-std", brnch = "master" }
+missing = { path = "../missing" }
+"#;
+        let diagnostics = diagnostics(&uri, text);
+        assert_eq!(diagnostics.len(), 3, "{diagnostics:#?}");
+
+        let missing = &diagnostics[0];
+        assert_eq!(missing.severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            missing.range,
+            Range::new(Position::new(9, 0), Position::new(9, 7))
+        );
+        assert!(missing
+            .message
+            .starts_with("Failed to canonicalize dependency path"));
+
+        let warnings: Vec<(Range, &str)> = diagnostics[1..]
+            .iter()
+            .map(|diagnostic| {
+                assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::WARNING));
+                (diagnostic.range, diagnostic.message.as_str())
+            })
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                (
+                    Range::new(Position::new(8, 43), Position::new(8, 48)),
+                    "unused manifest key: dependencies.std.brnch"
+                ),
+                (
+                    Range::new(Position::new(5, 0), Position::new(5, 6)),
+                    "unused manifest key: project.colour"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diagnostics_of_parse_error() {
+        let uri = get_url(&get_absolute_path(
+            "sway-lsp/tests/fixtures/manifest/Forc.toml",
+        ));
+        let diagnostics = diagnostics(&uri, "[project]\nname = \n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diagnostics[0].range.start.line, 1);
+    }
+}
@@ -0,0 +1,22 @@
+use super::{entry_at_position, keys::manifest_key, manifest_entries};
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position};
+
+/// Returns the documentation of the manifest key or table header at the given [Position].
+pub(crate) fn hover_data(text: &str, position: Position) -> Option<Hover> {
+    let entries = manifest_entries(text);
+    let entry = entry_at_position(&entries, position)?;
+    let key = manifest_key(&entry.path)?;
+    let signature = if entry.is_table {
+        format!("[{}]", entry.path.join("."))
+    } else {
+        key.name.to_string()
+    };
+    let value = format!("```toml\n{signature}\n```\n\n---\n\n{}", key.docs);
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(entry.range),
+    })
+}
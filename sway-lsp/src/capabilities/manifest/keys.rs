@@ -0,0 +1,174 @@
+/// A key of the `Forc.toml` manifest format, along with its documentation.
+#[derive(Debug)]
+pub(crate) struct ManifestKey {
+    pub(crate) name: &'static str,
+    pub(crate) docs: &'static str,
+}
+
+const fn key(name: &'static str, docs: &'static str) -> ManifestKey {
+    ManifestKey { name, docs }
+}
+
+/// The tables at the root of the manifest.
+pub(crate) const TABLES: &[ManifestKey] = &[
+    key(
+        "project",
+        "Defines a Sway project: its name, authors, license and entry point.",
+    ),
+    key(
+        "dependencies",
+        "Defines the dependencies of the project. Each dependency is declared with a `path`, or \
+        a `git` repository along with an optional `branch`, `tag` or `rev`.",
+    ),
+    key("network", "Defines a network for forc to interact with."),
+    key(
+        "build-profile",
+        "Defines the build profiles, which customize compiler settings such as debug options. \
+        The `debug` and `release` profiles are available in every manifest, and are overridden \
+        by declaring `[build-profile.debug]` or `[build-profile.release]`.",
+    ),
+    key(
+        "patch",
+        "Defines the patches, which override the dependencies fetched from a git repository \
+        with other copies, e.g. `[patch.'https://github.com/fuellabs/sway']`.",
+    ),
+    key(
+        "contract-dependencies",
+        "Defines the contract dependencies, whose contract IDs are available to the project. \
+        They are declared like `[dependencies]`, along with an optional `salt`.",
+    ),
+    key(
+        "constants",
+        "Defines the configuration-time constants of the project.",
+    ),
+    key("build-target", "Defines settings for each build target."),
+    key(
+        "workspace",
+        "Defines a workspace of several packages. A workspace manifest has no `[project]` table.",
+    ),
+];
+
+/// The keys of the `[project]` table.
+const PROJECT_KEYS: &[ManifestKey] = &[
+    key("name", "The name of the project."),
+    key("authors", "The authors of the project."),
+    key("organization", "The organization of the project."),
+    key("license", "The project license."),
+    key(
+        "entry",
+        "The entry point for the compiler to start parsing from, relative to the `src` \
+        directory. Defaults to `main.sw`.",
+    ),
+    key(
+        "implicit-std",
+        "Controls whether the `std` version of the current `forc` version is added as a \
+        dependency implicitly. Defaults to `true`.",
+    ),
+    key(
+        "forc-version",
+        "The minimum forc version required for this project to work properly.",
+    ),
+];
+
+/// The keys of the `[network]` table.
+const NETWORK_KEYS: &[ManifestKey] = &[key(
+    "url",
+    "URL of the network. Defaults to `http://127.0.0.1:4000`.",
+)];
+
+/// The keys of a dependency, in the `[dependencies]`, `[contract-dependencies]` and `[patch]`
+/// tables.
+const DEPENDENCY_KEYS: &[ManifestKey] = &[
+    key("path", "The path of the dependency, if it is local."),
+    key("git", "The URL of the git repo hosting the dependency."),
+    key("branch", "The desired branch to fetch from the git repo."),
+    key("tag", "The desired tag to fetch from the git repo."),
+    key(
+        "rev",
+        "The desired rev (i.e. commit hash) reference to fetch from the git repo.",
+    ),
+    key("version", "The desired version of the dependency."),
+    key(
+        "package",
+        "The name of the package in the dependency's manifest, if it differs from the name of \
+        the dependency.",
+    ),
+];
+
+/// The keys of a contract dependency, in addition to those of other dependencies.
+const CONTRACT_DEPENDENCY_KEYS: &[ManifestKey] = &[key(
+    "salt",
+    "The salt used to compute the contract ID of the dependency. Defaults to zero.",
+)];
+
+/// The keys of a build profile, all of which must be provided.
+const BUILD_PROFILE_KEYS: &[ManifestKey] = &[
+    key(
+        "print-ast",
+        "Whether to print out the generated AST (true) or not (false).",
+    ),
+    key(
+        "print-dca-graph",
+        "Whether to print out the computed DCA graph (in GraphViz DOT format).",
+    ),
+    key(
+        "print-ir",
+        "Whether to compile to bytecode (false) or to print out the generated IR (true).",
+    ),
+    key(
+        "print-finalized-asm",
+        "Whether to compile to bytecode (false) or to print out the generated ASM (true).",
+    ),
+    key(
+        "print-intermediate-asm",
+        "Whether to compile to bytecode (false) or to print out the generated ASM (true).",
+    ),
+    key("terse", "Terse mode. Limited warning and error output."),
+    key(
+        "time-phases",
+        "Whether to output the time elapsed over each part of the compilation.",
+    ),
+    key(
+        "include-tests",
+        "Whether to include the unit tests in the compiled program.",
+    ),
+    key(
+        "json-abi-with-callpaths",
+        "Whether to output the JSON ABI with call paths instead of names for structs and enums.",
+    ),
+    key("error-on-warnings", "Whether to treat warnings as errors."),
+];
+
+/// The keys of the `[workspace]` table.
+const WORKSPACE_KEYS: &[ManifestKey] = &[key(
+    "members",
+    "The paths of the packages in the workspace, relative to its `Forc.toml`.",
+)];
+
+/// Returns the keys of the table at the given path, or `None` if the names of its keys are up
+/// to the user, such as those of the `[dependencies]` table.
+pub(crate) fn keys_of_table(table: &[String]) -> Option<Vec<&'static ManifestKey>> {
+    let table: Vec<&str> = table.iter().map(String::as_str).collect();
+    let keys: Vec<&ManifestKey> = match table.as_slice() {
+        [] => TABLES.iter().collect(),
+        ["project"] => PROJECT_KEYS.iter().collect(),
+        ["network"] => NETWORK_KEYS.iter().collect(),
+        ["workspace"] => WORKSPACE_KEYS.iter().collect(),
+        ["dependencies", _] | ["patch", _, _] => DEPENDENCY_KEYS.iter().collect(),
+        ["contract-dependencies", _] => DEPENDENCY_KEYS
+            .iter()
+            .chain(CONTRACT_DEPENDENCY_KEYS)
+            .collect(),
+        ["build-profile", _] => BUILD_PROFILE_KEYS.iter().collect(),
+        _ => return None,
+    };
+    Some(keys)
+}
+
+/// Returns the key at the given path, if it's a key of the manifest format.
+pub(crate) fn manifest_key(path: &[String]) -> Option<&'static ManifestKey> {
+    let (name, table) = path.split_last()?;
+    keys_of_table(table)?
+        .into_iter()
+        .find(|key| key.name == name.as_str())
+}
@@ -0,0 +1,267 @@
+pub(crate) mod completion;
+pub(crate) mod definition;
+pub(crate) mod diagnostic;
+pub(crate) mod hover;
+pub(crate) mod keys;
+
+use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_spanned::Spanned;
+use std::{fmt, path::Path};
+use sway_utils::constants::MANIFEST_FILE_NAME;
+use toml_edit::{Document, Item, TableLike, Value};
+use tower_lsp::lsp_types::{Position, Range, Url};
+
+/// A key written in a `Forc.toml`, or the header of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ManifestEntry {
+    /// The path of the key from the root of the manifest, e.g. `["dependencies", "std", "path"]`.
+    pub(crate) path: Vec<String>,
+    /// The range of the last segment of the path.
+    pub(crate) range: Range,
+    /// Whether this is the header of a table, e.g. `[project]`.
+    pub(crate) is_table: bool,
+}
+
+/// Returns true if the document at the given [Url] is a `Forc.toml` manifest.
+pub(crate) fn is_manifest(uri: &Url) -> bool {
+    Path::new(uri.path())
+        .file_name()
+        .map_or(false, |name| name == MANIFEST_FILE_NAME)
+}
+
+/// Returns the keys and table headers written in the manifest, in the order they appear. If the
+/// manifest isn't valid TOML, there are none.
+pub(crate) fn manifest_entries(text: &str) -> Vec<ManifestEntry> {
+    // Only the deserializer keeps the spans of the keys, while the document tells table headers
+    // apart from inline and dotted tables.
+    let (document, keys) = match (
+        text.parse::<Document>(),
+        toml_edit::de::from_str::<SpannedKeys>(text),
+    ) {
+        (Ok(document), Ok(keys)) => (document, keys),
+        _ => return vec![],
+    };
+    let mut entries = vec![];
+    push_entries(
+        &mut entries,
+        text,
+        &keys,
+        Some(document.as_table()),
+        &mut vec![],
+    );
+    entries.sort_by_key(|entry| entry.range.start);
+    entries
+}
+
+/// Returns the entry whose key is at the given [Position].
+pub(crate) fn entry_at_position(
+    entries: &[ManifestEntry],
+    position: Position,
+) -> Option<&ManifestEntry> {
+    entries
+        .iter()
+        .find(|entry| entry.range.start <= position && position <= entry.range.end)
+}
+
+/// Returns the path of the table that the given line belongs to, which is that of the last
+/// table header above it.
+pub(crate) fn table_at_line(entries: &[ManifestEntry], line: u32) -> Vec<String> {
+    entries
+        .iter()
+        .rev()
+        .find(|entry| entry.is_table && entry.range.start.line < line)
+        .map(|entry| entry.path.clone())
+        .unwrap_or_default()
+}
+
+/// Returns the range of the entry with the given path. If there is none, the range of its
+/// closest parent is returned, as the key may have been omitted in favor of a default value.
+pub(crate) fn entry_range(entries: &[ManifestEntry], path: &[&str]) -> Range {
+    (1..=path.len())
+        .rev()
+        .find_map(|len| {
+            entries.iter().find(|entry| {
+                entry
+                    .path
+                    .iter()
+                    .map(String::as_str)
+                    .eq(path[..len].iter().copied())
+            })
+        })
+        .map(|entry| entry.range)
+        .unwrap_or_default()
+}
+
+/// The keys of a TOML value along with their spans, which are the byte ranges of the keys as
+/// written in the manifest.
+enum SpannedKeys {
+    Table(Vec<(Spanned<String>, SpannedKeys)>),
+    Array(Vec<SpannedKeys>),
+    Value,
+}
+
+impl<'de> Deserialize<'de> for SpannedKeys {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SpannedKeysVisitor)
+    }
+}
+
+struct SpannedKeysVisitor;
+
+impl<'de> Visitor<'de> for SpannedKeysVisitor {
+    type Value = SpannedKeys;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a TOML value")
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(SpannedKeys::Value)
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(SpannedKeys::Value)
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(SpannedKeys::Value)
+    }
+
+    fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+        Ok(SpannedKeys::Value)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = vec![];
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(SpannedKeys::Array(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = vec![];
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(SpannedKeys::Table(entries))
+    }
+}
+
+/// Adds the entries of the keys of the value at `path`, and those of the values nested in it.
+/// `table` is the same value in the parsed [Document], if it's a table.
+fn push_entries(
+    entries: &mut Vec<ManifestEntry>,
+    text: &str,
+    keys: &SpannedKeys,
+    table: Option<&dyn TableLike>,
+    path: &mut Vec<String>,
+) {
+    let pairs = match keys {
+        SpannedKeys::Table(pairs) => pairs,
+        _ => return,
+    };
+    for (key, value) in pairs {
+        let item = table.and_then(|table| table.get(key.get_ref()));
+        path.push(key.get_ref().clone());
+        entries.push(ManifestEntry {
+            path: path.clone(),
+            range: Range::new(
+                position(text, key.span().start),
+                position(text, key.span().end),
+            ),
+            is_table: matches!(item, Some(Item::Table(table)) if !table.is_dotted())
+                || matches!(item, Some(Item::ArrayOfTables(_))),
+        });
+        match value {
+            SpannedKeys::Array(elements) => {
+                for (index, element) in elements.iter().enumerate() {
+                    let element_table = match item {
+                        Some(Item::ArrayOfTables(tables)) => {
+                            tables.get(index).map(|table| table as &dyn TableLike)
+                        }
+                        Some(Item::Value(Value::Array(array))) => array
+                            .get(index)
+                            .and_then(Value::as_inline_table)
+                            .map(|table| table as &dyn TableLike),
+                        _ => None,
+                    };
+                    push_entries(entries, text, element, element_table, path);
+                }
+            }
+            _ => push_entries(
+                entries,
+                text,
+                value,
+                item.and_then(Item::as_table_like),
+                path,
+            ),
+        }
+        path.pop();
+    }
+}
+
+/// Returns the [Position] of the byte at `offset` of the text.
+fn position(text: &str, offset: usize) -> Position {
+    let line_start = text[..offset].rfind('\n').map_or(0, |index| index + 1);
+    let line = text[..line_start].matches('\n').count();
+    let character = text[line_start..offset].encode_utf16().count();
+    Position::new(line as u32, character as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(text: &str) -> Vec<String> {
+        manifest_entries(text)
+            .into_iter()
+            .map(|entry| entry.path.join("."))
+            .collect()
+    }
+
+    #[test]
+    fn manifest_entries_of_tables_and_keys() {
+        let text = r#"[project]
+name = "app" # a comment
+description = "Fuel's [app]" # isn't = a key
+authors = [
+    "Fuel Labs",
+]
+
+[dependencies]
+std = { path = "../std", package = "std" }
+
+[patch.'https://github.com/fuellabs/sway']
+std = { git = "https://github.com/fuellabs/sway", branch = "test" }
+"#;
+        assert_eq!(
+            paths(text),
+            vec![
+                "project",
+                "project.name",
+                "project.description",
+                "project.authors",
+                "dependencies",
+                "dependencies.std",
+                "dependencies.std.path",
+                "dependencies.std.package",
+                "patch",
+                "patch.https://github.com/fuellabs/sway",
+                "patch.https://github.com/fuellabs/sway.std",
+                "patch.https://github.com/fuellabs/sway.std.git",
+                "patch.https://github.com/fuellabs/sway.std.branch",
+            ]
+        );
+        let entries = manifest_entries(text);
+        assert_eq!(
+            entries[6].range,
+            Range::new(Position::new(8, 8), Position::new(8, 12))
+        );
+        assert_eq!(table_at_line(&entries, 8), vec!["dependencies"]);
+        assert_eq!(
+            table_at_line(&entries, 12),
+            vec!["patch", "https://github.com/fuellabs/sway"]
+        );
+    }
+}
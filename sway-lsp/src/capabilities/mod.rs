@@ -1,3 +1,4 @@
+pub mod call_hierarchy;
 pub mod code_actions;
 pub mod completion;
 pub mod diagnostic;
@@ -5,11 +6,17 @@ pub mod document_symbol;
 pub mod formatting;
 pub mod highlight;
 pub mod hover;
+pub mod implementation;
 pub mod inlay_hints;
+pub mod manifest;
 pub mod on_enter;
+pub mod references;
 pub mod rename;
 pub mod runnable;
 pub mod semantic_tokens;
+pub mod signature_help;
+pub mod type_hierarchy;
+pub mod workspace_symbol;
 
 pub(crate) use code_actions::code_actions;
 pub(crate) use on_enter::on_enter;
@@ -22,7 +22,7 @@ pub(crate) async fn on_enter(
     config: &OnEnterConfig,
     client: &Client,
     session: &Arc<Session>,
-    temp_uri: &Url,
+    uri: &Url,
     params: &DidChangeTextDocumentParams,
 ) {
     if !(params.content_changes[0].text.contains(NEWLINE)) {
@@ -31,7 +31,7 @@ pub(crate) async fn on_enter(
 
     let mut workspace_edit = None;
     let text_document = session
-        .get_text_document(temp_uri)
+        .get_text_document(uri)
         .expect("could not get text document");
 
     if config.continue_doc_comments.unwrap_or(false) {
@@ -80,11 +80,7 @@ fn get_comment_workspace_edit(
             }
         });
         let edit = TextDocumentEdit {
-            text_document: OptionalVersionedTextDocumentIdentifier {
-                // Use the original uri to make updates, not the temporary one from the session.
-                uri,
-                version: None,
-            },
+            text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
             edits,
         };
         Some(WorkspaceEdit {
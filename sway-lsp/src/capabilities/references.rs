@@ -0,0 +1,47 @@
+use crate::core::session::Session;
+use std::{collections::HashSet, sync::Arc};
+use tower_lsp::lsp_types::{Location, Position, Range, Url};
+
+/// Returns the locations of all references to the declaration of the token at the given
+/// [Position], across all of the sessions of the workspace.
+pub fn references(
+    sessions: &[Arc<Session>],
+    session: Arc<Session>,
+    url: Url,
+    position: Position,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    let decl_location = session.declaration_location(&url, position)?;
+    let locations = references_of_declaration(sessions, &decl_location)
+        .into_iter()
+        .filter(|location| include_declaration || *location != decl_location)
+        .collect();
+    Some(locations)
+}
+
+/// Collects the references to the declaration at `decl_location` from all of the sessions,
+/// including the declaration itself.
+///
+/// Declarations of dependencies are collected by every session depending on them, so
+/// locations found by more than one session are only returned once.
+pub(crate) fn references_of_declaration(
+    sessions: &[Arc<Session>],
+    decl_location: &Location,
+) -> Vec<Location> {
+    let mut seen = HashSet::new();
+    sessions
+        .iter()
+        .flat_map(|session| session.references_of_declaration(decl_location))
+        .filter(|location| seen.insert(location_key(location)))
+        .collect()
+}
+
+/// Returns a hashable key identifying the [Location], which doesn't implement [std::hash::Hash].
+pub(crate) fn location_key(location: &Location) -> (Url, (u32, u32), (u32, u32)) {
+    let Range { start, end } = location.range;
+    (
+        location.uri.clone(),
+        (start.line, start.character),
+        (end.line, end.character),
+    )
+}
@@ -1,43 +1,81 @@
-use crate::core::{session::Session, token::get_range_from_span};
+use crate::{
+    capabilities::references::references_of_declaration,
+    core::{session::Session, token::get_range_from_span},
+    error::{LanguageServerError, RenameError},
+};
 use std::collections::HashMap;
 use std::sync::Arc;
+use sway_parse::RESERVED_KEYWORDS;
 use sway_types::Spanned;
-use tower_lsp::lsp_types::{Position, PrepareRenameResponse, TextEdit, Url, WorkspaceEdit};
+use tower_lsp::lsp_types::{
+    Location, Position, PrepareRenameResponse, TextEdit, Url, WorkspaceEdit,
+};
 
+/// Renames the declaration of the token at the given [Position] and all references to it,
+/// in all members of the workspace.
 pub fn rename(
+    sessions: &[Arc<Session>],
     session: Arc<Session>,
     new_name: String,
     url: Url,
     position: Position,
-) -> Option<WorkspaceEdit> {
-    let (_, token) = session.token_map().token_at_position(&url, position)?;
-    let mut edits = Vec::new();
-
-    // todo: currently only supports single file rename
-    for (ident, _) in session.token_map().all_references_of_token(
-        &token,
-        &session.type_engine.read(),
-        &session.decl_engine.read(),
-    ) {
-        let range = get_range_from_span(&ident.span());
-        edits.push(TextEdit::new(range, new_name.clone()));
+) -> Result<WorkspaceEdit, LanguageServerError> {
+    if !is_valid_identifier(&new_name) {
+        return Err(RenameError::InvalidName { name: new_name }.into());
     }
+    let decl_location = renamable_declaration(&session, &url, position)?;
 
-    let mut map_of_changes = HashMap::new();
-    session.sync.to_workspace_url(url).map(|url| {
-        map_of_changes.insert(url, edits);
-        WorkspaceEdit::new(map_of_changes)
-    })
+    let mut map_of_changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+    for Location { uri, range } in references_of_declaration(sessions, &decl_location) {
+        map_of_changes
+            .entry(uri)
+            .or_default()
+            .push(TextEdit::new(range, new_name.clone()));
+    }
+    Ok(WorkspaceEdit::new(map_of_changes))
 }
 
 pub fn prepare_rename(
     session: Arc<Session>,
     url: Url,
     position: Position,
-) -> Option<PrepareRenameResponse> {
-    let (ident, ..) = session.token_map().token_at_position(&url, position)?;
-    Some(PrepareRenameResponse::RangeWithPlaceholder {
+) -> Result<PrepareRenameResponse, LanguageServerError> {
+    let (ident, _) = session
+        .token_map()
+        .token_at_position(&url, position)
+        .ok_or(RenameError::TokenNotFound)?;
+    renamable_declaration(&session, &url, position)?;
+    Ok(PrepareRenameResponse::RangeWithPlaceholder {
         range: get_range_from_span(&ident.span()),
         placeholder: ident.as_str().to_string(),
     })
 }
+
+/// Returns the [Location] of the declaration of the token at the given [Position], if the
+/// declaration can be renamed. Declarations in the standard library and in dependencies
+/// which aren't members of the workspace can't be.
+fn renamable_declaration(
+    session: &Session,
+    url: &Url,
+    position: Position,
+) -> Result<Location, RenameError> {
+    let decl_location = session
+        .declaration_location(url, position)
+        .ok_or(RenameError::TokenNotFound)?;
+    if !session.sync.is_workspace_member_file(&decl_location.uri) {
+        return Err(RenameError::SymbolNotInWorkspace);
+    }
+    Ok(decl_location)
+}
+
+/// Checks that the name can be used as an identifier in Sway.
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    let starts_ok = chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_');
+    starts_ok
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+        && !RESERVED_KEYWORDS.contains(name)
+}
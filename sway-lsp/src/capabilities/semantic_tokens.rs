@@ -158,6 +158,7 @@ fn semantic_token_type(kind: &SymbolKind) -> SemanticTokenType {
         SymbolKind::Variant => SemanticTokenType::ENUM_MEMBER,
         SymbolKind::Trait | SymbolKind::Storage => SemanticTokenType::INTERFACE,
         SymbolKind::TypeParameter => SemanticTokenType::TYPE_PARAMETER,
+        SymbolKind::TypeAlias => SemanticTokenType::TYPE,
         SymbolKind::Module => SemanticTokenType::NAMESPACE,
         SymbolKind::StringLiteral => SemanticTokenType::STRING,
         SymbolKind::ByteLiteral | SymbolKind::NumericLiteral => SemanticTokenType::NUMBER,
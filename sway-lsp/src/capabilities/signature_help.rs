@@ -0,0 +1,435 @@
+use crate::{
+    core::{
+        session::Session,
+        token::{to_ident_key, TypedAstToken},
+    },
+    utils::attributes::format_doc_attributes,
+};
+use std::sync::Arc;
+use sway_core::{language::ty, Engines};
+use sway_types::constants::{
+    CONTRACT_CALL_ASSET_ID_PARAMETER_NAME, CONTRACT_CALL_COINS_PARAMETER_NAME,
+    CONTRACT_CALL_GAS_PARAMETER_NAME,
+};
+use tower_lsp::lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, Position,
+    SignatureHelp, SignatureInformation, Url,
+};
+
+/// The call parameters that can be passed to a contract call in curly braces, along with their types.
+const CONTRACT_CALL_PARAMS: [(&str, &str); 3] = [
+    (CONTRACT_CALL_GAS_PARAMETER_NAME, "u64"),
+    (CONTRACT_CALL_COINS_PARAMETER_NAME, "u64"),
+    (CONTRACT_CALL_ASSET_ID_PARAMETER_NAME, "b256"),
+];
+
+/// The part of a call that the cursor is in.
+#[derive(Debug, PartialEq, Eq)]
+struct CallContext {
+    /// The char offset of the start of the name of the called function.
+    name_start: usize,
+    /// Whether the name is preceded by a `.`, making this a method call.
+    is_method_call: bool,
+    /// Whether the cursor is in the `{ gas, coins, asset_id }` parameters of a contract call
+    /// rather than in the argument list.
+    in_call_params: bool,
+    /// The index of the argument or call parameter that the cursor is in.
+    active_index: u32,
+}
+
+/// Returns the signature of the function, method or contract call that the cursor is in,
+/// with the parameter at the cursor marked as active.
+pub fn signature_help(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<SignatureHelp> {
+    let text: Vec<char> = session
+        .get_text_document(url)
+        .ok()?
+        .get_text()
+        .chars()
+        .collect();
+    let offset = offset_of_position(&text, position)?;
+    let text = blank_strings_and_comments(&text[..offset]);
+    let context = call_context(&text, offset)?;
+    let (_, token) = session
+        .token_map()
+        .token_at_position(url, position_of_offset(&text, context.name_start))?;
+    let fn_ref = match token.typed {
+        Some(TypedAstToken::TypedExpression(ty::TyExpression {
+            expression: ty::TyExpressionVariant::FunctionApplication { fn_ref, .. },
+            ..
+        })) => fn_ref,
+        _ => return None,
+    };
+
+    let type_engine = &*session.type_engine.read();
+    let decl_engine = &*session.decl_engine.read();
+    let engines = Engines::new(type_engine, decl_engine);
+    let func_decl = decl_engine.get_function(&fn_ref);
+
+    let mut label = format!("fn {}", func_decl.name.as_str());
+    let mut parameters: Vec<ParameterInformation> = vec![];
+    // Parameter label offsets are in UTF-16 code units, like LSP positions.
+    let mut push_parameter = |label: &mut String, parameter: String| {
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(&parameter);
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    };
+
+    if func_decl.is_contract_call {
+        label.push_str(" { ");
+        for (i, (name, type_name)) in CONTRACT_CALL_PARAMS.iter().enumerate() {
+            if i > 0 {
+                label.push_str(", ");
+            }
+            push_parameter(&mut label, format!("{name}: {type_name}"));
+        }
+        label.push_str(" }");
+    }
+
+    label.push('(');
+    // The `self` parameter is passed as the receiver of a method call, not as an argument.
+    let skip_self = context.is_method_call
+        && func_decl
+            .parameters
+            .first()
+            .map_or(false, |param| param.is_self());
+    let params = func_decl.parameters.iter().skip(skip_self as usize);
+    for (i, param) in params.enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        push_parameter(
+            &mut label,
+            format!(
+                "{}: {}",
+                param.name.as_str(),
+                engines.help_out(param.type_argument.type_id)
+            ),
+        );
+    }
+    label.push(')');
+    label.push_str(&format!(
+        " -> {}",
+        engines.help_out(func_decl.return_type.type_id)
+    ));
+
+    let active_parameter = if func_decl.is_contract_call && !context.in_call_params {
+        context.active_index + CONTRACT_CALL_PARAMS.len() as u32
+    } else {
+        context.active_index
+    };
+
+    let doc_comment = session
+        .token_map()
+        .try_get(&to_ident_key(&func_decl.name))
+        .try_unwrap()
+        .map(|item| format_doc_attributes(item.value()))
+        .filter(|doc_comment| !doc_comment.is_empty());
+    let signature = SignatureInformation {
+        label,
+        documentation: doc_comment.map(|value| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value,
+            })
+        }),
+        parameters: Some(parameters),
+        active_parameter: Some(active_parameter),
+    };
+    Some(SignatureHelp {
+        signatures: vec![signature],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter),
+    })
+}
+
+/// Replaces the contents of string literals and comments with spaces, so that the delimiters
+/// and commas in them aren't mistaken for those of a call. Newlines are kept as they are.
+fn blank_strings_and_comments(text: &[char]) -> Vec<char> {
+    let mut blanked = text.to_vec();
+    let blank = |c: &mut char| {
+        if *c != '\n' {
+            *c = ' ';
+        }
+    };
+    let mut i = 0;
+    while i < text.len() {
+        match (text[i], text.get(i + 1)) {
+            ('/', Some('/')) => {
+                while i < text.len() && text[i] != '\n' {
+                    blank(&mut blanked[i]);
+                    i += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                let mut depth = 0;
+                while i < text.len() {
+                    match (text[i], text.get(i + 1)) {
+                        ('/', Some('*')) => {
+                            depth += 1;
+                            blank(&mut blanked[i]);
+                            i += 1;
+                        }
+                        ('*', Some('/')) => {
+                            depth -= 1;
+                            blank(&mut blanked[i]);
+                            i += 1;
+                        }
+                        _ => {}
+                    }
+                    blank(&mut blanked[i]);
+                    i += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            ('"', _) => {
+                // Keep the quotes so that a string argument still separates its neighbours.
+                i += 1;
+                while i < text.len() && text[i] != '"' {
+                    if text[i] == '\\' && i + 1 < text.len() {
+                        blank(&mut blanked[i]);
+                        i += 1;
+                    }
+                    blank(&mut blanked[i]);
+                    i += 1;
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+    blanked
+}
+
+/// Walks backwards from `offset` to the unmatched opening delimiter of the call that the
+/// cursor is in, counting the commas at the same nesting level along the way.
+///
+/// Returns `None` if the cursor isn't in the argument list of a call, or in the call
+/// parameters of a contract call.
+fn call_context(text: &[char], offset: usize) -> Option<CallContext> {
+    let mut depth = 0;
+    let mut commas = 0;
+    let mut i = offset.min(text.len());
+    while i > 0 {
+        i -= 1;
+        match text[i] {
+            ')' | ']' | '}' => depth += 1,
+            '(' | '[' | '{' if depth > 0 => depth -= 1,
+            ',' if depth == 0 => commas += 1,
+            ';' if depth == 0 => return None,
+            '(' => {
+                // A contract call with call parameters: `name { gas: .. }(`.
+                let mut name_end = skip_whitespace_backwards(text, i);
+                if name_end > 0 && text[name_end - 1] == '}' {
+                    name_end =
+                        skip_whitespace_backwards(text, matching_open_brace(text, name_end - 1)?);
+                }
+                match ident_start(text, name_end) {
+                    Some(name_start) => {
+                        return Some(CallContext {
+                            name_start,
+                            is_method_call: is_preceded_by_dot(text, name_start),
+                            in_call_params: false,
+                            active_index: commas,
+                        })
+                    }
+                    // A parenthesized expression or tuple, look further out for the call.
+                    None => commas = 0,
+                }
+            }
+            '[' => commas = 0,
+            '{' => {
+                let name_end = skip_whitespace_backwards(text, i);
+                return ident_start(text, name_end)
+                    .filter(|name_start| is_preceded_by_dot(text, *name_start))
+                    .map(|name_start| CallContext {
+                        name_start,
+                        is_method_call: true,
+                        in_call_params: true,
+                        active_index: commas,
+                    });
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the offset of the `{` matching the `}` at `close`.
+fn matching_open_brace(text: &[char], close: usize) -> Option<usize> {
+    let mut depth = 0;
+    for i in (0..=close).rev() {
+        match text[i] {
+            '}' => depth += 1,
+            '{' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns the offset just after the last non-whitespace char before `offset`.
+fn skip_whitespace_backwards(text: &[char], mut offset: usize) -> usize {
+    while offset > 0 && text[offset - 1].is_whitespace() {
+        offset -= 1;
+    }
+    offset
+}
+
+/// Returns the offset of the start of the identifier ending at `end`, if there is one.
+fn ident_start(text: &[char], end: usize) -> Option<usize> {
+    let mut start = end;
+    while start > 0 && (text[start - 1].is_alphanumeric() || text[start - 1] == '_') {
+        start -= 1;
+    }
+    (start < end && !text[start].is_numeric()).then_some(start)
+}
+
+fn is_preceded_by_dot(text: &[char], offset: usize) -> bool {
+    let offset = skip_whitespace_backwards(text, offset);
+    offset > 0 && text[offset - 1] == '.'
+}
+
+/// Returns the char offset of an LSP [Position], whose character is in UTF-16 code units.
+fn offset_of_position(text: &[char], position: Position) -> Option<usize> {
+    let mut line = 0;
+    let mut line_start = 0;
+    for (i, c) in text.iter().enumerate() {
+        if line == position.line {
+            break;
+        }
+        if *c == '\n' {
+            line += 1;
+            line_start = i + 1;
+        }
+    }
+    if line != position.line {
+        return None;
+    }
+    let mut offset = line_start;
+    let mut character = 0;
+    while character < position.character as usize && offset < text.len() && text[offset] != '\n' {
+        character += text[offset].len_utf16();
+        offset += 1;
+    }
+    Some(offset)
+}
+
+/// Returns the [Position] of a char offset, counting characters in chars as the token map does.
+fn position_of_offset(text: &[char], offset: usize) -> Position {
+    let before = &text[..offset];
+    let line = before.iter().filter(|c| **c == '\n').count();
+    let character = match before.iter().rposition(|c| *c == '\n') {
+        Some(newline) => offset - newline - 1,
+        None => offset,
+    };
+    Position::new(line as u32, character as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context_at_cursor(source: &str) -> Option<CallContext> {
+        let offset = source.find('|').unwrap();
+        let text: Vec<char> = source.replace('|', "").chars().collect();
+        call_context(&blank_strings_and_comments(&text[..offset]), offset)
+    }
+
+    #[test]
+    fn call_context_of_function_call() {
+        assert_eq!(
+            context_at_cursor("let x = foo(a, bar(b), |"),
+            Some(CallContext {
+                name_start: 8,
+                is_method_call: false,
+                in_call_params: false,
+                active_index: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn call_context_of_method_call() {
+        assert_eq!(
+            context_at_cursor("x.foo((a, b), |)"),
+            Some(CallContext {
+                name_start: 2,
+                is_method_call: true,
+                in_call_params: false,
+                active_index: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn call_context_of_contract_call() {
+        assert_eq!(
+            context_at_cursor("caller.foo { gas: 1, coins: | }(a)"),
+            Some(CallContext {
+                name_start: 7,
+                is_method_call: true,
+                in_call_params: true,
+                active_index: 1,
+            })
+        );
+        assert_eq!(
+            context_at_cursor("caller.foo { gas: 1 }(|)"),
+            Some(CallContext {
+                name_start: 7,
+                is_method_call: true,
+                in_call_params: false,
+                active_index: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn call_context_past_strings_and_comments() {
+        assert_eq!(
+            context_at_cursor(r#"foo("a, (b", /* ) */ c, // ,\n|"#),
+            Some(CallContext {
+                name_start: 0,
+                is_method_call: false,
+                in_call_params: false,
+                active_index: 2,
+            })
+        );
+        assert_eq!(
+            context_at_cursor(r#"foo("a \", b", |"#),
+            Some(CallContext {
+                name_start: 0,
+                is_method_call: false,
+                in_call_params: false,
+                active_index: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn offset_of_utf16_position() {
+        let text: Vec<char> = "a\n\"🚀\", b".chars().collect();
+        assert_eq!(offset_of_position(&text, Position::new(1, 4)), Some(5));
+        assert_eq!(position_of_offset(&text, 5), Position::new(1, 3));
+    }
+
+    #[test]
+    fn no_call_context_outside_of_calls() {
+        assert_eq!(context_at_cursor("foo(a); |"), None);
+        assert_eq!(context_at_cursor("let s = Foo { a: | }"), None);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::core::{
+    session::Session,
+    token::{get_range_from_span, ident_of_type_id, to_ident_key, TypedAstToken},
+};
+use std::{collections::HashSet, sync::Arc};
+use sway_core::{
+    decl_engine::{DeclEngine, InterfaceDeclId},
+    language::{parsed::Supertrait, ty},
+};
+use sway_types::{Ident, Span, Spanned};
+use tower_lsp::lsp_types::{CallHierarchyItem, Location, Position, SymbolKind, Url};
+
+/// An item of a type hierarchy has the same shape as an item of a call hierarchy.
+pub type TypeHierarchyItem = CallHierarchyItem;
+
+/// Returns the [TypeHierarchyItem] of the trait, ABI, struct or enum that the token at the
+/// given [Position] refers to.
+pub fn prepare_type_hierarchy(
+    session: Arc<Session>,
+    url: &Url,
+    position: Position,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let (_, token) = session.token_map().token_at_position(url, position)?;
+    let decl_ident =
+        token.declared_token_ident(&session.type_engine.read(), &session.decl_engine.read())?;
+    let decl_token = session
+        .token_map()
+        .try_get(&to_ident_key(&decl_ident))
+        .try_unwrap()
+        .map(|item| item.value().clone())?;
+    let decl = match decl_token.typed? {
+        TypedAstToken::TypedDeclaration(decl) => decl,
+        _ => return None,
+    };
+    let item = declaration_item(&session, &session.decl_engine.read(), &decl)?;
+    Some(vec![item])
+}
+
+/// Returns the supertraits of a trait or ABI, or the traits and ABIs implemented by a
+/// struct or enum.
+pub fn supertypes(
+    sessions: &[Arc<Session>],
+    session: Arc<Session>,
+    url: &Url,
+    item: &TypeHierarchyItem,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let decl = declaration_at(&session, url, item)?;
+    let mut items: Vec<TypeHierarchyItem> = vec![];
+    match decl {
+        ty::TyDeclaration::TraitDeclaration { .. } | ty::TyDeclaration::AbiDeclaration { .. } => {
+            let decl_engine = &*session.decl_engine.read();
+            for supertrait in supertraits(decl_engine, &decl) {
+                if let Some(item) = supertrait_item(&session, decl_engine, &supertrait) {
+                    push_item(&mut items, item);
+                }
+            }
+        }
+        ty::TyDeclaration::StructDeclaration { .. } | ty::TyDeclaration::EnumDeclaration { .. } => {
+            let target = item_location(item);
+            for session in sessions {
+                for impl_trait in TypeIndex::new(session).impl_traits {
+                    if implementing_type_location(session, &impl_trait).as_ref() != Some(&target) {
+                        continue;
+                    }
+                    let decl_engine = &*session.decl_engine.read();
+                    if let Some(item) = impl_trait
+                        .trait_decl_ref
+                        .and_then(|decl_ref| interface_item(session, decl_engine, decl_ref.id()))
+                    {
+                        push_item(&mut items, item);
+                    }
+                }
+            }
+        }
+        _ => return None,
+    }
+    Some(items)
+}
+
+/// Returns the traits and ABIs that have a trait or ABI as a supertrait, and the types
+/// implementing it.
+pub fn subtypes(
+    sessions: &[Arc<Session>],
+    session: Arc<Session>,
+    url: &Url,
+    item: &TypeHierarchyItem,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let decl = declaration_at(&session, url, item)?;
+    let mut items: Vec<TypeHierarchyItem> = vec![];
+    match decl {
+        ty::TyDeclaration::TraitDeclaration { .. } | ty::TyDeclaration::AbiDeclaration { .. } => {
+            let target = item_location(item);
+            for session in sessions {
+                let index = TypeIndex::new(session);
+                // Traits and ABIs declaring the trait as a supertrait.
+                for decl in index.interface_declarations {
+                    let decl_engine = &*session.decl_engine.read();
+                    let extends_target = supertraits(decl_engine, &decl).iter().any(|supertrait| {
+                        supertrait_item(session, decl_engine, supertrait)
+                            .map_or(false, |item| item_location(&item) == target)
+                    });
+                    if extends_target {
+                        if let Some(item) = declaration_item(session, decl_engine, &decl) {
+                            push_item(&mut items, item);
+                        }
+                    }
+                }
+                // Types implementing the trait.
+                for impl_trait in index.impl_traits {
+                    let decl_engine = &*session.decl_engine.read();
+                    let implements_target = impl_trait
+                        .trait_decl_ref
+                        .as_ref()
+                        .and_then(|decl_ref| interface_item(session, decl_engine, decl_ref.id()))
+                        .map_or(false, |item| item_location(&item) == target);
+                    if implements_target {
+                        if let Some(item) =
+                            implementing_type_item(session, decl_engine, &impl_trait)
+                        {
+                            push_item(&mut items, item);
+                        }
+                    }
+                }
+            }
+        }
+        ty::TyDeclaration::StructDeclaration { .. } | ty::TyDeclaration::EnumDeclaration { .. } => {
+            // Structs and enums can't be extended.
+        }
+        _ => return None,
+    }
+    Some(items)
+}
+
+/// Returns the declaration that the `item` was created from.
+fn declaration_at(
+    session: &Session,
+    url: &Url,
+    item: &TypeHierarchyItem,
+) -> Option<ty::TyDeclaration> {
+    let (_, token) = session
+        .token_map()
+        .token_at_position(url, item.selection_range.start)?;
+    match token.typed? {
+        TypedAstToken::TypedDeclaration(decl) => Some(decl),
+        _ => None,
+    }
+}
+
+/// The trait and ABI declarations and the `impl` blocks of traits and ABIs in a session,
+/// collected in a single pass over its tokens.
+struct TypeIndex {
+    interface_declarations: Vec<ty::TyDeclaration>,
+    impl_traits: Vec<ty::TyImplTrait>,
+}
+
+impl TypeIndex {
+    fn new(session: &Session) -> Self {
+        let decl_engine = &*session.decl_engine.read();
+        let mut interface_declarations = vec![];
+        let mut impl_traits = vec![];
+        let mut impl_trait_spans: HashSet<Span> = HashSet::new();
+        for entry in session.token_map().iter() {
+            let ((ident, _), token) = entry.pair();
+            match &token.typed {
+                // Only the tokens of the names of traits and ABIs, not those referring to them.
+                Some(TypedAstToken::TypedDeclaration(
+                    decl @ ty::TyDeclaration::TraitDeclaration { decl_id, .. },
+                )) if decl_engine.get_trait(decl_id).name.span() == ident.span() => {
+                    interface_declarations.push(decl.clone());
+                }
+                Some(TypedAstToken::TypedDeclaration(
+                    decl @ ty::TyDeclaration::AbiDeclaration { decl_id, .. },
+                )) if decl_engine.get_abi(decl_id).name.span() == ident.span() => {
+                    interface_declarations.push(decl.clone());
+                }
+                Some(TypedAstToken::TypedDeclaration(ty::TyDeclaration::ImplTrait {
+                    decl_id,
+                    ..
+                })) => {
+                    let impl_trait = decl_engine.get_impl_trait(decl_id);
+                    if impl_trait.trait_decl_ref.is_some()
+                        && impl_trait_spans.insert(impl_trait.span.clone())
+                    {
+                        impl_traits.push(impl_trait);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Self {
+            interface_declarations,
+            impl_traits,
+        }
+    }
+}
+
+fn supertraits(decl_engine: &DeclEngine, decl: &ty::TyDeclaration) -> Vec<Supertrait> {
+    match decl {
+        ty::TyDeclaration::TraitDeclaration { decl_id, .. } => {
+            decl_engine.get_trait(decl_id).supertraits
+        }
+        ty::TyDeclaration::AbiDeclaration { decl_id, .. } => {
+            decl_engine.get_abi(decl_id).supertraits
+        }
+        _ => vec![],
+    }
+}
+
+fn supertrait_item(
+    session: &Session,
+    decl_engine: &DeclEngine,
+    supertrait: &Supertrait,
+) -> Option<TypeHierarchyItem> {
+    let trait_decl = decl_engine.get_trait(supertrait.decl_ref.as_ref()?);
+    type_item(
+        session,
+        &trait_decl.name,
+        &trait_decl.span,
+        SymbolKind::INTERFACE,
+    )
+}
+
+fn interface_item(
+    session: &Session,
+    decl_engine: &DeclEngine,
+    interface_id: &InterfaceDeclId,
+) -> Option<TypeHierarchyItem> {
+    match interface_id {
+        InterfaceDeclId::Trait(decl_id) => {
+            let trait_decl = decl_engine.get_trait(decl_id);
+            type_item(
+                session,
+                &trait_decl.name,
+                &trait_decl.span,
+                SymbolKind::INTERFACE,
+            )
+        }
+        InterfaceDeclId::Abi(decl_id) => {
+            let abi_decl = decl_engine.get_abi(decl_id);
+            type_item(
+                session,
+                &abi_decl.name,
+                &abi_decl.span,
+                SymbolKind::INTERFACE,
+            )
+        }
+    }
+}
+
+fn declaration_item(
+    session: &Session,
+    decl_engine: &DeclEngine,
+    decl: &ty::TyDeclaration,
+) -> Option<TypeHierarchyItem> {
+    match decl {
+        ty::TyDeclaration::TraitDeclaration { decl_id, .. } => {
+            interface_item(session, decl_engine, &InterfaceDeclId::Trait(*decl_id))
+        }
+        ty::TyDeclaration::AbiDeclaration { decl_id, .. } => {
+            interface_item(session, decl_engine, &InterfaceDeclId::Abi(*decl_id))
+        }
+        ty::TyDeclaration::StructDeclaration { decl_id, .. } => {
+            let struct_decl = decl_engine.get_struct(decl_id);
+            type_item(
+                session,
+                &struct_decl.call_path.suffix,
+                &struct_decl.span,
+                SymbolKind::STRUCT,
+            )
+        }
+        ty::TyDeclaration::EnumDeclaration { decl_id, .. } => {
+            let enum_decl = decl_engine.get_enum(decl_id);
+            type_item(
+                session,
+                &enum_decl.call_path.suffix,
+                &enum_decl.span,
+                SymbolKind::ENUM,
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Returns the [Location] of the declaration of the type that `impl_trait` is for.
+fn implementing_type_location(session: &Session, impl_trait: &ty::TyImplTrait) -> Option<Location> {
+    let type_ident = ident_of_type_id(
+        &session.type_engine.read(),
+        &session.decl_engine.read(),
+        &impl_trait.implementing_for.type_id,
+    )?;
+    session.ident_location(&type_ident)
+}
+
+/// Returns the item of the type that `impl_trait` is for. Types without a declaration,
+/// such as `Contract`, are represented by their name in the `impl` block.
+fn implementing_type_item(
+    session: &Session,
+    decl_engine: &DeclEngine,
+    impl_trait: &ty::TyImplTrait,
+) -> Option<TypeHierarchyItem> {
+    let declaration = ident_of_type_id(
+        &session.type_engine.read(),
+        decl_engine,
+        &impl_trait.implementing_for.type_id,
+    )
+    .and_then(|type_ident| {
+        session
+            .token_map()
+            .try_get(&to_ident_key(&type_ident))
+            .try_unwrap()
+            .and_then(|item| match &item.value().typed {
+                Some(TypedAstToken::TypedDeclaration(decl)) => {
+                    declaration_item(session, decl_engine, decl)
+                }
+                _ => None,
+            })
+    });
+    declaration.or_else(|| {
+        let span = &impl_trait.implementing_for.span;
+        type_item(session, &Ident::new(span.clone()), span, SymbolKind::STRUCT)
+    })
+}
+
+fn type_item(
+    session: &Session,
+    name: &Ident,
+    span: &Span,
+    kind: SymbolKind,
+) -> Option<TypeHierarchyItem> {
+    let location = session.ident_location(name)?;
+    Some(TypeHierarchyItem {
+        name: name.as_str().to_string(),
+        kind,
+        tags: None,
+        detail: None,
+        uri: location.uri,
+        range: get_range_from_span(span),
+        selection_range: location.range,
+        data: None,
+    })
+}
+
+fn item_location(item: &TypeHierarchyItem) -> Location {
+    Location::new(item.uri.clone(), item.selection_range)
+}
+
+fn push_item(items: &mut Vec<TypeHierarchyItem>, item: TypeHierarchyItem) {
+    if !items
+        .iter()
+        .any(|existing| item_location(existing) == item_location(&item))
+    {
+        items.push(item);
+    }
+}
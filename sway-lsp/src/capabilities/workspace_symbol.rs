@@ -0,0 +1,152 @@
+use crate::{
+    capabilities::{document_symbol::symbol_kind, references::location_key},
+    core::{
+        session::Session,
+        token::{Token, TypedAstToken},
+    },
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use sway_core::{decl_engine::DeclEngine, language::ty, Engines, TypeEngine};
+use sway_types::{Ident, Spanned};
+use tower_lsp::lsp_types::{Location, SymbolInformation, Url};
+
+/// The maximum number of symbols returned for a query.
+const MAX_SYMBOLS: usize = 256;
+
+/// Returns the declarations of all of the sessions of the workspace, including the
+/// declarations of their dependencies, whose names fuzzy match the `query`. An empty
+/// query only returns the declarations of the workspace itself.
+///
+/// Symbols are ordered so that names starting with the query come first, followed by
+/// shorter names, and at most [MAX_SYMBOLS] of them are returned.
+pub fn workspace_symbols(sessions: &[Arc<Session>], query: &str) -> Vec<SymbolInformation> {
+    let query = query.to_lowercase();
+    let mut symbols: Vec<SymbolInformation> = vec![];
+    let mut seen = HashSet::new();
+    let mut is_member_file: HashMap<Url, bool> = HashMap::new();
+    for session in sessions {
+        let type_engine = &*session.type_engine.read();
+        let decl_engine = &*session.decl_engine.read();
+        for item in session.token_map().iter() {
+            let ((ident, _), token) = item.pair();
+            if !is_fuzzy_match(&query, &ident.as_str().to_lowercase())
+                || !is_declaration(ident, token, type_engine, decl_engine)
+            {
+                continue;
+            }
+            let location = match session.ident_location(ident) {
+                Some(location) => location,
+                None => continue,
+            };
+            if query.is_empty()
+                && !*is_member_file
+                    .entry(location.uri.clone())
+                    .or_insert_with(|| session.sync.is_workspace_member_file(&location.uri))
+            {
+                continue;
+            }
+            // Declarations of dependencies are collected by every session depending on them.
+            if !seen.insert(location_key(&location)) {
+                continue;
+            }
+            let container_name = container_name(token, type_engine, decl_engine);
+            symbols.push(symbol_info(ident, token, location, container_name));
+        }
+    }
+    symbols.sort_by_key(|symbol| {
+        (
+            !symbol.name.to_lowercase().starts_with(&query),
+            symbol.name.len(),
+            symbol.name.clone(),
+        )
+    });
+    symbols.truncate(MAX_SYMBOLS);
+    symbols
+}
+
+/// Returns `true` if all of the characters of the `query` appear in `name`, in order.
+fn is_fuzzy_match(query: &str, name: &str) -> bool {
+    let mut name_chars = name.chars();
+    query
+        .chars()
+        .all(|query_char| name_chars.any(|name_char| name_char == query_char))
+}
+
+/// Returns `true` if the token is the name of a function, struct, enum, trait, ABI,
+/// storage field or constant declaration.
+fn is_declaration(
+    ident: &Ident,
+    token: &Token,
+    type_engine: &TypeEngine,
+    decl_engine: &DeclEngine,
+) -> bool {
+    let is_declaration_kind = match &token.typed {
+        Some(TypedAstToken::TypedDeclaration(decl)) => matches!(
+            decl,
+            ty::TyDeclaration::FunctionDeclaration { .. }
+                | ty::TyDeclaration::StructDeclaration { .. }
+                | ty::TyDeclaration::EnumDeclaration { .. }
+                | ty::TyDeclaration::TraitDeclaration { .. }
+                | ty::TyDeclaration::AbiDeclaration { .. }
+                | ty::TyDeclaration::ConstantDeclaration { .. }
+        ),
+        Some(TypedAstToken::TypedFunctionDeclaration(_))
+        | Some(TypedAstToken::TypedTraitFn(_))
+        | Some(TypedAstToken::TypedStorageField(_))
+        | Some(TypedAstToken::TypedConstantDeclaration(_)) => true,
+        _ => false,
+    };
+    is_declaration_kind
+        && token
+            .declared_token_span(type_engine, decl_engine)
+            .map_or(false, |span| span == ident.span())
+}
+
+/// Returns the name of the type, trait or ABI that a method is declared in, or `storage`
+/// for storage fields.
+fn container_name(
+    token: &Token,
+    type_engine: &TypeEngine,
+    decl_engine: &DeclEngine,
+) -> Option<String> {
+    match &token.typed {
+        Some(TypedAstToken::TypedFunctionDeclaration(func_decl)) => {
+            match func_decl.implementing_type.as_ref()? {
+                ty::TyDeclaration::ImplTrait { decl_id, .. } => {
+                    let impl_trait = decl_engine.get_impl_trait(decl_id);
+                    let engines = Engines::new(type_engine, decl_engine);
+                    Some(format!(
+                        "{}",
+                        engines.help_out(impl_trait.implementing_for.type_id)
+                    ))
+                }
+                ty::TyDeclaration::TraitDeclaration { name, .. }
+                | ty::TyDeclaration::AbiDeclaration { name, .. } => Some(name.as_str().to_string()),
+                _ => None,
+            }
+        }
+        Some(TypedAstToken::TypedStorageField(_)) => Some("storage".to_string()),
+        _ => None,
+    }
+}
+
+#[allow(warnings)]
+// TODO: the "deprecated: None" field is deprecated according to this library
+fn symbol_info(
+    ident: &Ident,
+    token: &Token,
+    location: Location,
+    container_name: Option<String>,
+) -> SymbolInformation {
+    SymbolInformation {
+        name: ident.as_str().to_string(),
+        kind: symbol_kind(&token.kind),
+        location,
+        tags: None,
+        container_name,
+        deprecated: None,
+    }
+}
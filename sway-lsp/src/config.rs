@@ -14,6 +14,8 @@ pub struct Config {
     pub diagnostic: DiagnosticConfig,
     #[serde(default)]
     pub on_enter: OnEnterConfig,
+    #[serde(default)]
+    pub compilation: CompilationConfig,
     #[serde(default, skip_serializing)]
     trace: TraceConfig,
 }
@@ -53,6 +55,20 @@ impl Default for DiagnosticConfig {
     }
 }
 
+// Options for configuring when the project is recompiled.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilationConfig {
+    /// Milliseconds to wait for further edits after an edit, before recompiling the project.
+    pub debounce_ms: u64,
+}
+
+impl Default for CompilationConfig {
+    fn default() -> Self {
+        Self { debounce_ms: 100 }
+    }
+}
+
 // Options for confguring server logging.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LoggingConfig {
@@ -26,6 +26,15 @@ impl TextDocument {
             .map_err(|_| DocumentError::DocumentNotFound { path: path.into() })
     }
 
+    pub fn build_from_text(path: &str, text: &str) -> Self {
+        Self {
+            language_id: "sway".into(),
+            version: 1,
+            uri: path.into(),
+            content: Rope::from_str(text),
+        }
+    }
+
     pub fn get_uri(&self) -> &str {
         &self.uri
     }
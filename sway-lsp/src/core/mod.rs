@@ -3,3 +3,4 @@ pub mod session;
 pub(crate) mod sync;
 pub(crate) mod token;
 pub(crate) mod token_map;
+pub mod vfs;
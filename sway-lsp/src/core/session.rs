@@ -8,19 +8,28 @@ use crate::{
     core::{
         document::TextDocument,
         sync::SyncWorkspace,
-        token::{get_range_from_span, TypedAstToken},
+        token::{get_range_from_span, ident_of_type_id, type_id_of_typed_token, TypeDefinition},
         token_map::TokenMap,
+        vfs::Vfs,
     },
     error::{DocumentError, LanguageServerError},
     traverse::{
         dependency, lexed_tree, parsed_tree::ParsedTree, typed_tree::TypedTree, ParseContext,
     },
+    utils::keyword_docs::KeywordDocs,
 };
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use forc_pkg as pkg;
-use parking_lot::RwLock;
-use pkg::{manifest::ManifestFile, Programs};
-use std::{fs::File, io::Write, path::PathBuf, sync::Arc, vec};
+use parking_lot::{Mutex, RwLock};
+use pkg::{manifest::ManifestFile, CheckCache, Programs};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    vec,
+};
 use sway_core::{
     decl_engine::DeclEngine,
     language::{
@@ -30,7 +39,8 @@ use sway_core::{
     },
     BuildTarget, CompileResult, Engines, TypeEngine,
 };
-use sway_types::{Span, Spanned};
+use sway_error::{error::CompileError, warning::CompileWarning};
+use sway_types::{Ident, Span, Spanned};
 use sway_utils::helpers::get_sway_files;
 use tower_lsp::lsp_types::{
     CompletionItem, GotoDefinitionResponse, Location, Position, Range, SymbolInformation,
@@ -40,6 +50,10 @@ use tower_lsp::lsp_types::{
 pub type Documents = DashMap<String, TextDocument>;
 pub type ProjectDirectory = PathBuf;
 
+/// The number of compilations reusing the results of previous compilations, after which the
+/// project is compiled from scratch.
+const MAX_INCREMENTAL_COMPILATIONS: usize = 50;
+
 #[derive(Default, Debug)]
 pub struct CompiledProgram {
     pub lexed: Option<LexedProgram>,
@@ -47,6 +61,15 @@ pub struct CompiledProgram {
     pub typed: Option<ty::TyProgram>,
 }
 
+/// The state shared between the compilations of a session.
+#[derive(Default, Debug)]
+struct Compilation {
+    /// The results of checking the packages of the project, to reuse for unchanged packages.
+    cache: CheckCache,
+    /// The number of compilations since the project was last compiled from scratch.
+    incremental_compilations: usize,
+}
+
 /// A `Session` is used to store information about a single member in a workspace.
 /// It stores the parsed and typed Tokens, as well as the [TypeEngine] associated with the project.
 ///
@@ -60,6 +83,12 @@ pub struct Session {
     pub type_engine: RwLock<TypeEngine>,
     pub decl_engine: RwLock<DeclEngine>,
     pub sync: SyncWorkspace,
+    /// Locked for the duration of a compilation, so that only one runs at a time.
+    compilation: Mutex<Compilation>,
+    /// Incremented on every edit of a document of the session.
+    edit_version: AtomicUsize,
+    /// The manifest directories of the packages that have changed since the last compilation.
+    changed_packages: DashSet<PathBuf>,
 }
 
 impl Session {
@@ -72,35 +101,36 @@ impl Session {
             type_engine: <_>::default(),
             decl_engine: <_>::default(),
             sync: SyncWorkspace::new(),
+            compilation: Mutex::new(Default::default()),
+            edit_version: AtomicUsize::new(0),
+            changed_packages: DashSet::new(),
         }
     }
 
     pub fn init(&self, uri: &Url) -> Result<ProjectDirectory, LanguageServerError> {
-        let manifest_dir = PathBuf::from(uri.path());
-
-        // Create a new temp dir that clones the current workspace
-        // and store manifest and temp paths
-        self.sync.create_temp_dir_from_workspace(&manifest_dir)?;
-
-        self.sync.clone_manifest_dir_to_temp()?;
+        self.sync.init_manifest_dir(&PathBuf::from(uri.path()))?;
 
         // iterate over the project dir, parse all sway files
         let _ = self.store_sway_files();
 
-        self.sync.watch_and_sync_manifest();
-
         self.sync.manifest_dir().map_err(Into::into)
     }
 
-    pub fn shutdown(&self) {
-        // shutdown the thread watching the manifest file
-        let handle = self.sync.notify_join_handle.read();
-        if let Some(join_handle) = &*handle {
-            join_handle.abort();
-        }
+    /// Records an edit of a document of the session, returning the version of the session after
+    /// the edit.
+    pub fn record_edit(&self) -> usize {
+        self.edit_version.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Returns the number of edits made to the documents of the session.
+    pub fn edit_version(&self) -> usize {
+        self.edit_version.load(Ordering::SeqCst)
+    }
 
-        // Delete the temporary directory.
-        self.sync.remove_temp_dir();
+    /// Marks the package in the given manifest directory as changed, so that it is checked again
+    /// rather than reused by the next compilation.
+    pub fn invalidate_package(&self, manifest_dir: &Path) {
+        self.changed_packages.insert(manifest_dir.to_path_buf());
     }
 
     /// Return a reference to the [TokenMap] of the current session.
@@ -108,9 +138,24 @@ impl Session {
         &self.token_map
     }
 
-    pub fn parse_project(&self, uri: &Url) -> Result<Diagnostics, LanguageServerError> {
-        self.token_map.clear();
-        self.runnables.clear();
+    /// Compiles the project that the file at the given [Url] belongs to. Documents in the [Vfs]
+    /// are compiled from their contents there rather than from disk.
+    ///
+    /// Only one compilation runs at a time. If the session is edited before the compilation has
+    /// finished, its results are outdated and discarded with [LanguageServerError::CompilationCancelled].
+    pub fn parse_project(&self, uri: &Url, vfs: &Vfs) -> Result<Diagnostics, LanguageServerError> {
+        let mut compilation = self.compilation.lock();
+        let edit_version = self.edit_version();
+        let changed_packages: Vec<PathBuf> = self
+            .changed_packages
+            .iter()
+            .map(|manifest_dir| manifest_dir.clone())
+            .collect();
+        for manifest_dir in &changed_packages {
+            self.changed_packages.remove(manifest_dir);
+            compilation.cache.invalidate(manifest_dir);
+        }
+        let source_overlay = vfs.source_overlay();
 
         let manifest_dir = PathBuf::from(uri.path());
         let locked = false;
@@ -145,45 +190,82 @@ impl Session {
             errors: vec![],
         };
 
-        *self.type_engine.write() = <_>::default();
-        *self.decl_engine.write() = <_>::default();
+        // Only the packages that have changed, and the packages depending on them, are checked
+        // again. The outdated programs are never removed from the engines though, so start over
+        // once in a while to keep them from growing indefinitely.
+        if compilation.incremental_compilations >= MAX_INCREMENTAL_COMPILATIONS {
+            compilation.cache.clear();
+        }
+        if compilation.cache.is_empty() {
+            compilation.incremental_compilations = 0;
+            // The tokens refer to the engines, so they are outdated as well.
+            self.token_map.clear();
+            self.runnables.clear();
+            *self.type_engine.write() = <_>::default();
+            *self.decl_engine.write() = <_>::default();
+        } else {
+            compilation.incremental_compilations += 1;
+        }
+
         let type_engine = &*self.type_engine.read();
         let decl_engine = &*self.decl_engine.read();
         let engines = Engines::new(type_engine, decl_engine);
         let tests_enabled = true;
-        let results = pkg::check(&plan, BuildTarget::default(), true, tests_enabled, engines)
-            .map_err(LanguageServerError::FailedToCompile)?;
+        // A newer edit stops the compilation before it checks the next package.
+        let cancelled = || self.edit_version() != edit_version;
+        let results = pkg::check_with_cache(
+            &plan,
+            BuildTarget::default(),
+            true,
+            tests_enabled,
+            source_overlay,
+            &mut compilation.cache,
+            &cancelled,
+            engines,
+        );
+
+        if cancelled() {
+            // The packages may have been checked with outdated sources, so check them again next time.
+            for manifest_dir in changed_packages {
+                self.changed_packages.insert(manifest_dir);
+            }
+            return Err(LanguageServerError::CompilationCancelled);
+        }
+        let results = results.map_err(LanguageServerError::FailedToCompile)?;
+        self.token_map.clear();
+        self.runnables.clear();
+
         let results_len = results.len();
-        for (i, res) in results.into_iter().enumerate() {
+        for (i, res) in results.iter().enumerate() {
+            // The results of unchanged packages are shared with the cache, so they are borrowed.
             // We can convert these destructured elements to a Vec<Diagnostic> later on.
             let CompileResult {
                 value,
                 warnings,
                 errors,
-            } = res;
+            } = &**res;
 
-            if value.is_none() {
-                continue;
-            }
             let Programs {
                 lexed,
                 parsed,
                 typed,
-            } = value.unwrap();
+            } = match value {
+                Some(programs) => programs,
+                None => continue,
+            };
 
-            let ast_res = CompileResult::new(typed, warnings, errors);
-            let typed_program = self.compile_res_to_typed_program(&ast_res)?;
+            let typed_program = self.compile_res_to_typed_program(typed, warnings, errors)?;
             let ctx = ParseContext::new(&self.token_map, engines);
 
             // The final element in the results is the main program.
             if i == results_len - 1 {
                 // First, populate our token_map with sway keywords.
-                lexed_tree::parse(&lexed, &ctx);
+                lexed_tree::parse(lexed, &ctx);
 
                 // Next, populate our token_map with un-typed yet parsed ast nodes.
                 let parsed_tree = ParsedTree::new(&ctx);
-                parsed_tree.collect_module_spans(&parsed);
-                self.parse_ast_to_tokens(&parsed, &ctx, |an, _ctx| parsed_tree.traverse_node(an));
+                parsed_tree.collect_module_spans(parsed);
+                self.parse_ast_to_tokens(parsed, &ctx, |an, _ctx| parsed_tree.traverse_node(an));
 
                 // Finally, create runnables and populate our token_map with typed ast nodes.
                 self.create_runnables(typed_program);
@@ -194,14 +276,14 @@ impl Session {
                     typed_tree.traverse_node(node)
                 });
 
-                self.save_lexed_program(lexed.to_owned().clone());
-                self.save_parsed_program(parsed.to_owned().clone());
+                self.save_lexed_program(lexed.clone());
+                self.save_parsed_program(parsed.clone());
                 self.save_typed_program(typed_program.to_owned().clone());
 
-                diagnostics = get_diagnostics(&ast_res.warnings, &ast_res.errors);
+                diagnostics = get_diagnostics(warnings, errors);
             } else {
                 // Collect tokens from dependencies and the standard library prelude.
-                self.parse_ast_to_tokens(&parsed, &ctx, |an, ctx| {
+                self.parse_ast_to_tokens(parsed, &ctx, |an, ctx| {
                     dependency::collect_parsed_declaration(an, ctx)
                 });
 
@@ -229,59 +311,85 @@ impl Session {
         uri: Url,
         position: Position,
     ) -> Option<GotoDefinitionResponse> {
+        self.declaration_location(&uri, position)
+            .map(GotoDefinitionResponse::Scalar)
+    }
+
+    /// Returns the [Location] in the users workspace of the declaration of the token
+    /// at the given [Position].
+    pub fn declaration_location(&self, url: &Url, position: Position) -> Option<Location> {
         self.token_map
-            .token_at_position(&uri, position)
+            .token_at_position(url, position)
             .and_then(|(_, token)| {
                 token.declared_token_ident(&self.type_engine.read(), &self.decl_engine.read())
             })
-            .and_then(|decl_ident| {
-                let range = get_range_from_span(&decl_ident.span());
-                decl_ident.span().path().and_then(|path| {
-                    // We use ok() here because we don't care about propagating the error from from_file_path
-                    Url::from_file_path(path.as_ref()).ok().and_then(|url| {
-                        self.sync
-                            .to_workspace_url(url)
-                            .map(|url| GotoDefinitionResponse::Scalar(Location::new(url, range)))
-                    })
-                })
+            .and_then(|decl_ident| self.ident_location(&decl_ident))
+    }
+
+    /// Returns the [Location] in the users workspace of the declaration of the type of the
+    /// token at the given [Position], such as the struct or enum of a variable.
+    pub fn type_definition_location(&self, url: &Url, position: Position) -> Option<Location> {
+        let (_, token) = self.token_map.token_at_position(url, position)?;
+        let type_id = match token.type_def {
+            Some(TypeDefinition::TypeId(type_id)) => type_id,
+            _ => type_id_of_typed_token(token.typed.as_ref()?)?,
+        };
+        let type_ident =
+            ident_of_type_id(&self.type_engine.read(), &self.decl_engine.read(), &type_id)?;
+        self.ident_location(&type_ident)
+    }
+
+    /// Returns the locations of all tokens in this session which refer to the declaration
+    /// at `decl_location`, including the declaration itself.
+    ///
+    /// Locations are compared rather than spans, so that references to a declaration in
+    /// another member of the workspace can be found as well. A reference always has the name
+    /// of the declaration, unlike e.g. a field whose type is the declaration.
+    pub fn references_of_declaration(&self, decl_location: &Location) -> Vec<Location> {
+        let type_engine = &*self.type_engine.read();
+        let decl_engine = &*self.decl_engine.read();
+        self.token_map
+            .iter()
+            .filter_map(|item| {
+                let ((ident, _), token) = item.pair();
+                let location = self.ident_location(ident)?;
+                let refers_to_decl = location == *decl_location
+                    || token
+                        .declared_token_ident(type_engine, decl_engine)
+                        .filter(|decl_ident| decl_ident.as_str() == ident.as_str())
+                        .and_then(|decl_ident| self.ident_location(&decl_ident))
+                        .map_or(false, |location| location == *decl_location);
+                refers_to_decl.then_some(location)
             })
+            .collect()
+    }
+
+    /// Converts the span of the [Ident] into a [Location] in the users workspace.
+    pub(crate) fn ident_location(&self, ident: &Ident) -> Option<Location> {
+        let range = get_range_from_span(&ident.span());
+        ident.span().path().and_then(|path| {
+            // We use ok() here because we don't care about propagating the error from from_file_path
+            Url::from_file_path(path.as_ref())
+                .ok()
+                .map(|url| Location::new(url, range))
+        })
     }
 
     pub fn completion_items(
         &self,
         uri: &Url,
         position: Position,
-        trigger_char: String,
+        keyword_docs: &KeywordDocs,
     ) -> Option<Vec<CompletionItem>> {
-        let shifted_position = Position {
-            line: position.line,
-            character: position.character - trigger_char.len() as u32 - 1,
-        };
-        let (ident_to_complete, _) = self.token_map.token_at_position(uri, shifted_position)?;
-        let fn_tokens = self
-            .token_map
-            .tokens_at_position(uri, shifted_position, Some(true));
-        let (_, fn_token) = fn_tokens.first()?;
-        let compiled_program = &*self.compiled_program.read();
-
-        if let Some(TypedAstToken::TypedFunctionDeclaration(fn_decl)) = fn_token.typed.clone() {
-            let program = compiled_program.typed.clone()?;
-            return Some(capabilities::completion::to_completion_items(
-                &program.root.namespace,
-                Engines::new(&self.type_engine.read(), &self.decl_engine.read()),
-                &ident_to_complete,
-                &fn_decl,
-                position,
-            ));
-        }
-        None
+        capabilities::completion::completion_items(self, uri, position, keyword_docs)
     }
 
     pub fn symbol_information(&self, url: &Url) -> Option<Vec<SymbolInformation>> {
         let tokens = self.token_map.tokens_for_file(url);
-        self.sync
-            .to_workspace_url(url.clone())
-            .map(|url| capabilities::document_symbol::to_symbol_information(tokens, url))
+        Some(capabilities::document_symbol::to_symbol_information(
+            tokens,
+            url.clone(),
+        ))
     }
 
     pub fn format_text(&self, url: &Url) -> Result<Vec<TextEdit>, LanguageServerError> {
@@ -297,35 +405,20 @@ impl Session {
             .map(|page_text_edit| vec![page_text_edit])
     }
 
-    pub fn handle_open_file(&self, uri: &Url) {
-        if !self.documents.contains_key(uri.path()) {
-            if let Ok(text_document) = TextDocument::build_from_path(uri.path()) {
-                let _ = self.store_document(text_document);
-            }
-        }
+    /// Store the text of a document opened in the client, which may differ from the file on disk.
+    pub fn handle_open_file(&self, uri: &Url, text: &str) {
+        self.documents.insert(
+            uri.path().to_string(),
+            TextDocument::build_from_text(uri.path(), text),
+        );
     }
 
-    /// Writes the changes to the file and updates the document.
-    pub fn write_changes_to_file(
-        &self,
-        uri: &Url,
-        changes: Vec<TextDocumentContentChangeEvent>,
-    ) -> Result<(), LanguageServerError> {
-        let src = self.update_text_document(uri, changes).ok_or_else(|| {
-            DocumentError::DocumentNotFound {
-                path: uri.path().to_string(),
-            }
-        })?;
-        let mut file =
-            File::create(uri.path()).map_err(|err| DocumentError::UnableToCreateFile {
-                path: uri.path().to_string(),
-                err: err.to_string(),
-            })?;
-        writeln!(&mut file, "{src}").map_err(|err| DocumentError::UnableToWriteFile {
-            path: uri.path().to_string(),
-            err: err.to_string(),
-        })?;
-        Ok(())
+    /// Read the document back from disk once it's closed in the client, discarding any unsaved
+    /// changes.
+    pub fn handle_close_file(&self, uri: &Url) {
+        if let Ok(text_document) = TextDocument::build_from_path(uri.path()) {
+            self.documents.insert(uri.path().to_string(), text_document);
+        }
     }
 
     /// Get the document at the given [Url].
@@ -413,13 +506,14 @@ impl Session {
     /// Get a reference to the [ty::TyProgram] AST.
     fn compile_res_to_typed_program<'a>(
         &'a self,
-        ast_res: &'a CompileResult<ty::TyProgram>,
+        typed: &'a Option<ty::TyProgram>,
+        warnings: &[CompileWarning],
+        errors: &[CompileError],
     ) -> Result<&'a ty::TyProgram, LanguageServerError> {
-        ast_res
-            .value
+        typed
             .as_ref()
-            .ok_or(LanguageServerError::FailedToParse {
-                diagnostics: get_diagnostics(&ast_res.warnings, &ast_res.errors),
+            .ok_or_else(|| LanguageServerError::FailedToParse {
+                diagnostics: get_diagnostics(warnings, errors),
             })
     }
 
@@ -475,9 +569,12 @@ impl Session {
 
     /// Populate [Documents] with sway files found in the workspace.
     fn store_sway_files(&self) -> Result<(), LanguageServerError> {
-        let temp_dir = self.sync.temp_dir()?;
+        let manifest_dir = self.sync.manifest_dir()?;
         // Store the documents.
-        for path in get_sway_files(temp_dir).iter().filter_map(|fp| fp.to_str()) {
+        for path in get_sway_files(manifest_dir)
+            .iter()
+            .filter_map(|fp| fp.to_str())
+        {
             self.store_document(TextDocument::build_from_path(path)?)?;
         }
         Ok(())
@@ -487,7 +584,7 @@ impl Session {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use sway_lsp_test_utils::{get_absolute_path, get_url};
+    use sway_lsp_test_utils::{doc_comments_dir, get_absolute_path, get_url};
 
     #[test]
     fn store_document_returns_empty_tuple() {
@@ -515,8 +612,8 @@ mod tests {
         let session = Session::new();
         let dir = get_absolute_path("sway-lsp/tests/fixtures");
         let uri = get_url(&dir);
-        let result =
-            Session::parse_project(&session, &uri).expect_err("expected ManifestFileNotFound");
+        let result = Session::parse_project(&session, &uri, &Vfs::new())
+            .expect_err("expected ManifestFileNotFound");
         assert!(matches!(
             result,
             LanguageServerError::DocumentError(
@@ -525,4 +622,32 @@ mod tests {
             if test_dir == dir
         ));
     }
+
+    #[test]
+    fn parse_project_reuses_unchanged_dependencies() {
+        let session = Session::new();
+        let vfs = Vfs::new();
+        let project_dir = doc_comments_dir();
+        let std_dir = PathBuf::from(get_absolute_path("sway-lib-std"));
+        let uri = get_url(project_dir.join("src/main.sw").to_str().unwrap());
+        let cached_result = |manifest_dir: &Path| {
+            session
+                .compilation
+                .lock()
+                .cache
+                .result(manifest_dir)
+                .cloned()
+                .expect("expected the package to be cached")
+        };
+
+        session.parse_project(&uri, &vfs).unwrap();
+        let std_result = cached_result(&std_dir);
+        let project_result = cached_result(&project_dir);
+
+        // Only the project is checked again, the result of `std` is shared with the cache.
+        session.invalidate_package(&project_dir);
+        session.parse_project(&uri, &vfs).unwrap();
+        assert!(Arc::ptr_eq(&std_result, &cached_result(&std_dir)));
+        assert!(!Arc::ptr_eq(&project_result, &cached_result(&project_dir)));
+    }
 }
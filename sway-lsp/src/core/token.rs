@@ -7,7 +7,7 @@ use sway_core::{
             EnumVariant, Expression, FunctionApplicationExpression, FunctionParameter,
             MethodApplicationExpression, Scrutinee, StorageField, StructExpression,
             StructExpressionField, StructField, StructScrutineeField, Supertrait, TraitFn,
-            UseStatement,
+            TraitTypeDeclaration, UseStatement,
         },
         ty,
     },
@@ -49,6 +49,7 @@ pub enum AstToken {
     Supertrait(Supertrait),
     TraitConstraint(TraitConstraint),
     TraitFn(TraitFn),
+    TraitTypeDeclaration(TraitTypeDeclaration),
     TypeArgument(TypeArgument),
     TypeParameter(TypeParameter),
     UseStatement(UseStatement),
@@ -66,6 +67,7 @@ pub enum TypedAstToken {
     TypedStructField(ty::TyStructField),
     TypedEnumVariant(ty::TyEnumVariant),
     TypedTraitFn(ty::TyTraitFn),
+    TypedTraitType(ty::TyTraitType),
     TypedSupertrait(Supertrait),
     TypedStorageField(ty::TyStorageField),
     TyStorageResassignment(Box<ty::TyStorageReassignment>),
@@ -101,6 +103,7 @@ pub enum SymbolKind {
     DeriveHelper,
     Module,
     TypeParameter,
+    TypeAlias,
     Keyword,
     Unknown,
 }
@@ -189,6 +192,7 @@ pub fn ident_of_type_id(
 ) -> Option<Ident> {
     match type_engine.get(*type_id) {
         TypeInfo::UnknownGeneric { name, .. } => Some(name),
+        TypeInfo::ConstGeneric { name, .. } => Some(name),
         TypeInfo::Enum(decl_ref) => Some(decl_engine.get_enum(&decl_ref).call_path.suffix),
         TypeInfo::Struct(decl_ref) => Some(decl_engine.get_struct(&decl_ref).call_path.suffix),
         TypeInfo::Custom { call_path, .. } => Some(call_path.suffix),
@@ -196,13 +200,35 @@ pub fn ident_of_type_id(
     }
 }
 
+/// Returns the [TypeId] of the value, field or parameter that the [TypedAstToken] represents,
+/// or the return type of a function declaration.
+pub fn type_id_of_typed_token(typed_token: &TypedAstToken) -> Option<TypeId> {
+    match typed_token {
+        TypedAstToken::TypedDeclaration(ty::TyDeclaration::VariableDeclaration(var_decl)) => {
+            Some(var_decl.return_type)
+        }
+        TypedAstToken::TypedExpression(expr) => Some(expr.return_type),
+        TypedAstToken::TypedConstantDeclaration(const_decl) => {
+            Some(const_decl.type_ascription.type_id)
+        }
+        TypedAstToken::TypedFunctionDeclaration(func_decl) => Some(func_decl.return_type.type_id),
+        TypedAstToken::TypedFunctionParameter(param) => Some(param.type_argument.type_id),
+        TypedAstToken::TypedStructField(field) => Some(field.type_argument.type_id),
+        TypedAstToken::TypedEnumVariant(variant) => Some(variant.type_argument.type_id),
+        TypedAstToken::TypedStorageField(field) => Some(field.type_argument.type_id),
+        TypedAstToken::TypedArgument(type_arg) => Some(type_arg.type_id),
+        _ => None,
+    }
+}
+
 /// Intended to be used during traversal of the [sway_core::language::parsed::ParseProgram] AST.
 /// We can then use the [TypeInfo] to infer the semantic type of the token before type-checking.
 pub fn type_info_to_symbol_kind(type_engine: &TypeEngine, type_info: &TypeInfo) -> SymbolKind {
     match type_info {
-        TypeInfo::UnsignedInteger(..) | TypeInfo::Boolean | TypeInfo::B256 => {
-            SymbolKind::BuiltinType
-        }
+        TypeInfo::UnsignedInteger(..)
+        | TypeInfo::Boolean
+        | TypeInfo::B256
+        | TypeInfo::StringSlice => SymbolKind::BuiltinType,
         TypeInfo::Numeric | TypeInfo::Str(..) => SymbolKind::NumericLiteral,
         TypeInfo::Custom { .. } | TypeInfo::Struct { .. } | TypeInfo::Contract => {
             SymbolKind::Struct
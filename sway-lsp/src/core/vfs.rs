@@ -0,0 +1,70 @@
+use dashmap::DashMap;
+use std::{path::PathBuf, sync::Arc};
+use sway_core::SourceOverlay;
+use tower_lsp::lsp_types::Url;
+
+/// An in-memory file system holding the contents of the documents that are open in the client.
+///
+/// Open documents may have unsaved changes, so their contents are compiled in place of the files
+/// on disk. The same [Vfs] is shared by all sessions, so that unsaved changes to a dependency are
+/// seen by the packages depending on it.
+#[derive(Debug, Default)]
+pub struct Vfs {
+    files: DashMap<PathBuf, Arc<str>>,
+}
+
+impl Vfs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the contents of the document at the given [Url].
+    pub fn set_contents(&self, uri: &Url, contents: &str) {
+        self.files.insert(canonical_path(uri), Arc::from(contents));
+    }
+
+    /// Remove the document at the given [Url], so that the file on disk is used again.
+    pub fn remove(&self, uri: &Url) {
+        self.files.remove(&canonical_path(uri));
+    }
+
+    /// Returns a snapshot of the contents of all documents, to be passed to the compiler.
+    pub fn source_overlay(&self) -> Arc<SourceOverlay> {
+        Arc::new(
+            self.files
+                .iter()
+                .map(|item| (item.key().clone(), item.value().clone()))
+                .collect(),
+        )
+    }
+}
+
+/// The compiler refers to files by their canonical paths. Files which don't exist on disk yet
+/// can't be canonicalized, so their paths are used as is.
+fn canonical_path(uri: &Url) -> PathBuf {
+    let path = uri
+        .to_file_path()
+        .unwrap_or_else(|_| PathBuf::from(uri.path()));
+    path.canonicalize().unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sway_lsp_test_utils::{get_absolute_path, get_url};
+
+    #[test]
+    fn source_overlay_contains_documents_until_removed() {
+        let vfs = Vfs::new();
+        let path = get_absolute_path("sway-lsp/tests/fixtures/cats.txt");
+        let uri = get_url(&path);
+        vfs.set_contents(&uri, "unsaved");
+        let canonical_path = PathBuf::from(&path).canonicalize().unwrap();
+        assert_eq!(
+            vfs.source_overlay().get(&canonical_path).map(|src| &**src),
+            Some("unsaved")
+        );
+        vfs.remove(&uri);
+        assert!(vfs.source_overlay().is_empty());
+    }
+}
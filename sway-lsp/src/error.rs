@@ -10,6 +10,8 @@ pub enum LanguageServerError {
     DocumentError(#[from] DocumentError),
     #[error(transparent)]
     DirectoryError(#[from] DirectoryError),
+    #[error(transparent)]
+    RenameError(#[from] RenameError),
 
     // Top level errors
     #[error("Failed to create build plan. {0}")]
@@ -18,6 +20,8 @@ pub enum LanguageServerError {
     FailedToCompile(anyhow::Error),
     #[error("Failed to parse document")]
     FailedToParse { diagnostics: Diagnostics },
+    #[error("Compilation was cancelled by a newer edit")]
+    CompilationCancelled,
     #[error("Error formatting document: {0}")]
     FormatError(FormatterError),
 }
@@ -34,28 +38,22 @@ pub enum DocumentError {
     ManifestsLockPathFailed { dir: String },
     #[error("Document is already stored at {:?}", path)]
     DocumentAlreadyStored { path: String },
-    #[error("File wasn't able to be created at path {:?} : {:?}", path, err)]
-    UnableToCreateFile { path: String, err: String },
-    #[error("Unable to write string to file at {:?} : {:?}", path, err)]
-    UnableToWriteFile { path: String, err: String },
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum DirectoryError {
-    #[error("Can't find temporary directory")]
-    TempDirNotFound,
     #[error("Can't find manifest directory")]
     ManifestDirNotFound,
-    #[error("Can't extract project name from {:?}", dir)]
-    CantExtractProjectName { dir: String },
-    #[error("Failed to create temp directory")]
-    TempDirFailed,
     #[error("Failed to canonicalize path")]
     CanonicalizeFailed,
-    #[error("Failed to copy workspace contents to temp directory")]
-    CopyContentsFailed,
-    #[error("Failed to create build plan. {0}")]
-    StripPrefixError(std::path::StripPrefixError),
-    #[error("Unable to create Url from path {:?}", path)]
-    UrlFromPathFailed { path: String },
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RenameError {
+    #[error("No symbol was found at the given position")]
+    TokenNotFound,
+    #[error("Only symbols declared in the workspace can be renamed")]
+    SymbolNotInWorkspace,
+    #[error("Invalid name {:?}: not an identifier", name)]
+    InvalidName { name: String },
 }
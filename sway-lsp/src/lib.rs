@@ -1,5 +1,6 @@
 #![recursion_limit = "256"]
 
+use tower::ServiceExt;
 use tower_lsp::{LspService, Server};
 
 mod capabilities;
@@ -17,7 +18,15 @@ pub async fn start() {
 
     let (service, socket) = LspService::build(Backend::new)
         .custom_method("sway/show_ast", Backend::show_ast)
+        // `tower_lsp` doesn't handle these standard requests yet.
         .custom_method("textDocument/inlayHint", Backend::inlay_hints)
+        .custom_method(
+            "textDocument/prepareTypeHierarchy",
+            Backend::prepare_type_hierarchy,
+        )
+        .custom_method("typeHierarchy/supertypes", Backend::supertypes)
+        .custom_method("typeHierarchy/subtypes", Backend::subtypes)
         .finish();
+    let service = service.map_response(server::with_type_hierarchy_provider);
     Server::new(stdin, stdout, socket).serve(service).await;
 }
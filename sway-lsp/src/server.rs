@@ -1,8 +1,12 @@
 pub use crate::error::DocumentError;
 use crate::{
-    capabilities::{self, diagnostic::Diagnostics},
+    capabilities::{self, diagnostic::Diagnostics, type_hierarchy::TypeHierarchyItem},
     config::{Config, Warnings},
-    core::{session::Session, sync},
+    core::{
+        document::TextDocument,
+        session::{Documents, Session},
+        vfs::Vfs,
+    },
     error::{DirectoryError, LanguageServerError},
     utils::{debug, keyword_docs::KeywordDocs},
 };
@@ -17,9 +21,16 @@ use std::{
     ops::Deref,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
 use sway_types::{Ident, Spanned};
-use tower_lsp::lsp_types::*;
+use tower_lsp::lsp_types::{
+    request::{
+        GotoDeclarationParams, GotoDeclarationResponse, GotoImplementationParams,
+        GotoImplementationResponse, GotoTypeDefinitionParams, GotoTypeDefinitionResponse,
+    },
+    *,
+};
 use tower_lsp::{jsonrpc, Client, LanguageServer};
 use tracing::metadata::LevelFilter;
 
@@ -29,6 +40,10 @@ pub struct Backend {
     pub config: RwLock<Config>,
     pub keyword_docs: KeywordDocs,
     sessions: DashMap<PathBuf, Arc<Session>>,
+    vfs: Arc<Vfs>,
+    /// The `Forc.toml` manifests open in the client. They aren't compiled, so they are kept apart
+    /// from the documents of the sessions.
+    manifests: Documents,
 }
 
 impl Backend {
@@ -36,12 +51,16 @@ impl Backend {
         let sessions = DashMap::new();
         let config = RwLock::new(Default::default());
         let keyword_docs = KeywordDocs::new();
+        let vfs = Arc::new(Vfs::new());
+        let manifests = DashMap::new();
 
         Backend {
             client,
             config,
             keyword_docs,
             sessions,
+            vfs,
+            manifests,
         }
     }
 
@@ -52,20 +71,30 @@ impl Backend {
         Ok(())
     }
 
-    fn get_uri_and_session(
-        &self,
-        workspace_uri: &Url,
-    ) -> Result<(Url, Arc<Session>), LanguageServerError> {
-        let session = self.url_to_session(workspace_uri)?;
-        let uri = session.sync.workspace_to_temp_url(workspace_uri)?;
-        Ok((uri, session))
+    fn get_uri_and_session(&self, uri: &Url) -> Result<(Url, Arc<Session>), LanguageServerError> {
+        let session = self.url_to_session(uri)?;
+        Ok((uri.clone(), session))
     }
 
-    async fn parse_project(&self, uri: Url, workspace_uri: Url, session: Arc<Session>) {
-        // pass in the temp Url into parse_project, we can now get the updated AST's back.
-        let diagnostics = match session.parse_project(&uri) {
-            Ok(diagnostics) => diagnostics,
+    async fn parse_project(&self, uri: Url, session: Arc<Session>) {
+        // The documents open in the client are compiled from the VFS, files on disk are never written.
+        // Compiling blocks, so it mustn't hold up the other requests.
+        let (parsed_uri, parsed_session, vfs) = (uri.clone(), session.clone(), self.vfs.clone());
+        let result =
+            tokio::task::spawn_blocking(move || parsed_session.parse_project(&parsed_uri, &vfs))
+                .await;
+        let diagnostics = match result {
+            Ok(Ok(diagnostics)) => diagnostics,
+            // The compilation for the newer edit publishes the diagnostics instead.
+            Ok(Err(LanguageServerError::CompilationCancelled)) => return,
             Err(err) => {
+                tracing::error!("{}", err.to_string().as_str());
+                Diagnostics {
+                    warnings: vec![],
+                    errors: vec![],
+                }
+            }
+            Ok(Err(err)) => {
                 tracing::error!("{}", err.to_string().as_str());
                 if let LanguageServerError::FailedToParse { diagnostics } = err {
                     diagnostics
@@ -77,7 +106,42 @@ impl Backend {
                 }
             }
         };
-        self.publish_diagnostics(&uri, &workspace_uri, session, diagnostics)
+        self.publish_diagnostics(&uri, session, diagnostics).await;
+    }
+
+    /// Marks the package that the file at the given [Url] belongs to as changed, so that it is
+    /// checked again by the next compilation of every session depending on it.
+    fn invalidate_package(&self, uri: &Url) {
+        if let Ok(manifest) = PackageManifestFile::from_dir(Path::new(uri.path())) {
+            for item in self.sessions.iter() {
+                item.value().invalidate_package(manifest.dir());
+            }
+        }
+    }
+
+    /// Returns the contents of the `Forc.toml` at the given [Url], if it's open in the client.
+    fn manifest_text(&self, uri: &Url) -> Option<String> {
+        self.manifests
+            .try_get(uri.path())
+            .try_unwrap()
+            .map(|document| document.get_text())
+    }
+
+    /// Validates the contents of the `Forc.toml` at the given [Url] and publishes its diagnostics.
+    async fn publish_manifest_diagnostics(&self, uri: &Url) {
+        let mut diagnostics = match self.manifest_text(uri) {
+            Some(text) => capabilities::manifest::diagnostic::diagnostics(uri, &text),
+            None => vec![],
+        };
+        {
+            let config = &self.config.read().diagnostic;
+            diagnostics.retain(|diagnostic| match diagnostic.severity {
+                Some(DiagnosticSeverity::WARNING) => config.show_warnings,
+                _ => config.show_errors,
+            });
+        }
+        self.client
+            .publish_diagnostics(uri.clone(), diagnostics, None)
             .await;
     }
 }
@@ -103,21 +167,59 @@ pub fn capabilities() -> ServerCapabilities {
         ),
         document_symbol_provider: Some(OneOf::Left(true)),
         completion_provider: Some(CompletionOptions {
-            trigger_characters: Some(vec![".".to_string()]),
+            trigger_characters: Some(vec![".".to_string(), ":".to_string(), "[".to_string()]),
             ..Default::default()
         }),
         document_formatting_provider: Some(OneOf::Left(true)),
         definition_provider: Some(OneOf::Left(true)),
+        declaration_provider: Some(DeclarationCapability::Simple(true)),
+        type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
+        implementation_provider: Some(ImplementationProviderCapability::Simple(true)),
         inlay_hint_provider: Some(OneOf::Left(true)),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
         code_lens_provider: Some(CodeLensOptions {
             resolve_provider: Some(false),
         }),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".to_string(), ",".to_string(), "{".to_string()]),
+            retrigger_characters: None,
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        }),
+        references_provider: Some(OneOf::Left(true)),
+        call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        rename_provider: Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        })),
         ..ServerCapabilities::default()
     }
 }
 
+/// Adds the type hierarchy provider to the server capabilities in the result of the
+/// `initialize` request, the only result that has them.
+///
+/// Type hierarchies were added in LSP 3.17, so [ServerCapabilities] doesn't have a field for
+/// them yet. Their requests are routed to the custom methods in `sway_lsp::start`.
+pub fn with_type_hierarchy_provider(
+    response: Option<jsonrpc::Response>,
+) -> Option<jsonrpc::Response> {
+    response.map(|response| {
+        let (id, result) = response.into_parts();
+        let result = result.map(|mut result| {
+            if let Some(capabilities) = result
+                .get_mut("capabilities")
+                .and_then(serde_json::Value::as_object_mut)
+            {
+                capabilities.insert("typeHierarchyProvider".into(), true.into());
+            }
+            result
+        });
+        jsonrpc::Response::from_parts(id, result)
+    })
+}
+
 impl Backend {
     fn url_to_session(&self, uri: &Url) -> Result<Arc<Session>, LanguageServerError> {
         let path = PathBuf::from(uri.path());
@@ -150,10 +252,46 @@ impl Backend {
         Ok(session)
     }
 
+    /// Returns the sessions of all members of the workspace that `session` belongs to,
+    /// starting with `session` itself. Members which haven't been opened yet are parsed
+    /// so that their tokens can be searched as well. Their sessions are kept, so each member
+    /// is only parsed once.
+    async fn workspace_sessions(&self, session: Arc<Session>) -> Vec<Arc<Session>> {
+        let mut sessions = vec![session.clone()];
+        for member_dir in session.sync.workspace_member_dirs() {
+            let workspace_uri = match Url::from_file_path(&member_dir) {
+                Ok(uri) => uri,
+                Err(_) => continue,
+            };
+            match self.get_uri_and_session(&workspace_uri) {
+                Ok((uri, member_session)) => {
+                    if sessions.iter().any(|s| Arc::ptr_eq(s, &member_session)) {
+                        continue;
+                    }
+                    if member_session.token_map().is_empty() {
+                        // Compiling blocks, so it mustn't hold up the other requests.
+                        let (parsed_session, vfs) = (member_session.clone(), self.vfs.clone());
+                        let result = tokio::task::spawn_blocking(move || {
+                            parsed_session.parse_project(&uri, &vfs)
+                        })
+                        .await;
+                        match result {
+                            Ok(Err(err)) => tracing::error!("{}", err.to_string()),
+                            Err(err) => tracing::error!("{}", err.to_string()),
+                            Ok(Ok(_)) => {}
+                        }
+                    }
+                    sessions.push(member_session);
+                }
+                Err(err) => tracing::error!("{}", err.to_string()),
+            }
+        }
+        sessions
+    }
+
     async fn publish_diagnostics(
         &self,
         uri: &Url,
-        workspace_uri: &Url,
         session: Arc<Session>,
         diagnostics: Diagnostics,
     ) {
@@ -185,7 +323,7 @@ impl Backend {
         // Note: Even if the computed diagnostics vec is empty, we still have to push the empty Vec
         // in order to clear former diagnostics. Newly pushed diagnostics always replace previously pushed diagnostics.
         self.client
-            .publish_diagnostics(workspace_uri.clone(), diagnostics_res, None)
+            .publish_diagnostics(uri.clone(), diagnostics_res, None)
             .await;
     }
 }
@@ -227,28 +365,40 @@ impl LanguageServer for Backend {
 
     async fn shutdown(&self) -> jsonrpc::Result<()> {
         tracing::info!("Shutting Down the Sway Language Server");
-
-        let _ = self.sessions.iter().map(|item| {
-            let session = item.value();
-            session.shutdown();
-        });
-
         Ok(())
     }
 
     // Document Handlers
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = &params.text_document.uri;
+        if capabilities::manifest::is_manifest(uri) {
+            let document = TextDocument::build_from_text(uri.path(), &params.text_document.text);
+            self.manifests.insert(uri.path().to_string(), document);
+            self.publish_manifest_diagnostics(uri).await;
+            return;
+        }
         match self.get_uri_and_session(&params.text_document.uri) {
             Ok((uri, session)) => {
-                session.handle_open_file(&uri);
-                self.parse_project(uri, params.text_document.uri, session.clone())
-                    .await;
+                session.handle_open_file(&uri, &params.text_document.text);
+                self.vfs.set_contents(&uri, &params.text_document.text);
+                self.invalidate_package(&uri);
+                self.parse_project(uri, session).await;
             }
             Err(err) => tracing::error!("{}", err.to_string()),
         }
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let uri = &params.text_document.uri;
+        if capabilities::manifest::is_manifest(uri) {
+            if let Some(mut document) = self.manifests.try_get_mut(uri.path()).try_unwrap() {
+                params.content_changes.iter().for_each(|change| {
+                    document.apply_change(change);
+                });
+            }
+            self.publish_manifest_diagnostics(uri).await;
+            return;
+        }
         let config = self.config.read().on_enter.clone();
         match self.get_uri_and_session(&params.text_document.uri) {
             Ok((uri, session)) => {
@@ -256,13 +406,27 @@ impl LanguageServer for Backend {
                 capabilities::on_enter(&config, &self.client, &session, &uri.clone(), &params)
                     .await;
 
-                // update this file with the new changes and write to disk
-                match session.write_changes_to_file(&uri, params.content_changes) {
-                    Ok(_) => {
-                        self.parse_project(uri, params.text_document.uri.clone(), session.clone())
-                            .await;
+                // update this document with the new changes and store its contents in the VFS
+                match session.update_text_document(&uri, params.content_changes) {
+                    Some(text) => {
+                        self.vfs.set_contents(&uri, &text);
+                        self.invalidate_package(&uri);
+                        let edit_version = session.record_edit();
+
+                        // Wait for further edits, so that typing compiles the project only once.
+                        let debounce =
+                            Duration::from_millis(self.config.read().compilation.debounce_ms);
+                        tokio::time::sleep(debounce).await;
+                        if session.edit_version() == edit_version {
+                            self.parse_project(uri, session).await;
+                        }
                     }
-                    Err(err) => tracing::error!("{}", err.to_string()),
+                    None => tracing::error!(
+                        "{}",
+                        DocumentError::DocumentNotFound {
+                            path: uri.path().to_string(),
+                        }
+                    ),
                 }
             }
             Err(err) => tracing::error!("{}", err.to_string()),
@@ -272,23 +436,36 @@ impl LanguageServer for Backend {
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         match self.get_uri_and_session(&params.text_document.uri) {
             Ok((uri, session)) => {
-                // overwrite the contents of the tmp/folder with everything in
-                // the current workspace. (resync)
-                if let Err(err) = session.sync.clone_manifest_dir_to_temp() {
-                    tracing::error!("{}", err.to_string().as_str());
-                }
+                // Files that aren't open in the client, such as the Forc.toml, may have changed.
+                self.invalidate_package(&uri);
+                self.parse_project(uri, session).await;
+            }
+            Err(err) => tracing::error!("{}", err.to_string()),
+        }
+        // The diagnostics of the compilation are replaced by those of the manifest itself.
+        let uri = &params.text_document.uri;
+        if capabilities::manifest::is_manifest(uri) {
+            self.publish_manifest_diagnostics(uri).await;
+        }
+    }
 
-                let _ = session
-                    .sync
-                    .manifest_path()
-                    .and_then(|manifest_path| PackageManifestFile::from_dir(&manifest_path).ok())
-                    .map(|manifest| {
-                        if let Some(temp_manifest_path) = &session.sync.temp_manifest_path() {
-                            sync::edit_manifest_dependency_paths(&manifest, temp_manifest_path)
-                        }
-                    });
-                self.parse_project(uri, params.text_document.uri, session)
-                    .await;
+    async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = &params.text_document.uri;
+        if capabilities::manifest::is_manifest(uri) {
+            self.manifests.remove(uri.path());
+            self.client
+                .publish_diagnostics(uri.clone(), vec![], None)
+                .await;
+            return;
+        }
+        // Unsaved changes are discarded when a document is closed, so the file on disk is used again.
+        self.vfs.remove(&params.text_document.uri);
+        match self.get_uri_and_session(&params.text_document.uri) {
+            Ok((uri, session)) => {
+                session.handle_close_file(&uri);
+                self.invalidate_package(&uri);
+                session.record_edit();
+                self.parse_project(uri, session).await;
             }
             Err(err) => tracing::error!("{}", err.to_string()),
         }
@@ -296,6 +473,7 @@ impl LanguageServer for Backend {
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
         for event in params.changes {
+            self.invalidate_package(&event.uri);
             if event.typ == FileChangeType::DELETED {
                 match self.get_uri_and_session(&event.uri) {
                     Ok((uri, session)) => {
@@ -308,6 +486,13 @@ impl LanguageServer for Backend {
     }
 
     async fn hover(&self, params: HoverParams) -> jsonrpc::Result<Option<Hover>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        if capabilities::manifest::is_manifest(uri) {
+            let position = params.text_document_position_params.position;
+            return Ok(self
+                .manifest_text(uri)
+                .and_then(|text| capabilities::manifest::hover::hover_data(&text, position)));
+        }
         match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
             Ok((uri, session)) => {
                 let position = params.text_document_position_params.position;
@@ -325,17 +510,47 @@ impl LanguageServer for Backend {
         }
     }
 
+    async fn signature_help(
+        &self,
+        params: SignatureHelpParams,
+    ) -> jsonrpc::Result<Option<SignatureHelp>> {
+        match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position_params.position;
+                Ok(capabilities::signature_help::signature_help(
+                    session, &uri, position,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
     async fn code_action(
         &self,
         params: CodeActionParams,
     ) -> jsonrpc::Result<Option<CodeActionResponse>> {
         match self.get_uri_and_session(&params.text_document.uri) {
-            Ok((temp_uri, session)) => Ok(capabilities::code_actions(
-                session,
-                &params.range,
-                params.text_document,
-                &temp_uri,
-            )),
+            Ok((uri, session)) => {
+                let diagnostics = &params.context.diagnostics;
+                let workspace_sessions =
+                    if capabilities::code_actions::quick_fix::needs_workspace_sessions(diagnostics)
+                    {
+                        self.workspace_sessions(session.clone()).await
+                    } else {
+                        vec![session.clone()]
+                    };
+                Ok(capabilities::code_actions(
+                    session,
+                    &params.range,
+                    params.text_document,
+                    &uri,
+                    diagnostics,
+                    &workspace_sessions,
+                ))
+            }
             Err(err) => {
                 tracing::error!("{}", err.to_string());
                 Ok(None)
@@ -369,15 +584,18 @@ impl LanguageServer for Backend {
         &self,
         params: CompletionParams,
     ) -> jsonrpc::Result<Option<CompletionResponse>> {
-        let trigger_char = params
-            .context
-            .map(|ctx| ctx.trigger_character)
-            .unwrap_or_default()
-            .unwrap_or("".to_string());
         let position = params.text_document_position.position;
-        match self.get_uri_and_session(&params.text_document_position.text_document.uri) {
+        let uri = &params.text_document_position.text_document.uri;
+        if capabilities::manifest::is_manifest(uri) {
+            return Ok(self.manifest_text(uri).map(|text| {
+                CompletionResponse::Array(capabilities::manifest::completion::completion_items(
+                    &text, position,
+                ))
+            }));
+        }
+        match self.get_uri_and_session(uri) {
             Ok((uri, session)) => Ok(session
-                .completion_items(&uri, position, trigger_char)
+                .completion_items(&uri, position, &self.keyword_docs)
                 .map(CompletionResponse::Array)),
             Err(err) => {
                 tracing::error!("{}", err.to_string());
@@ -401,6 +619,30 @@ impl LanguageServer for Backend {
         }
     }
 
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> jsonrpc::Result<Option<Vec<SymbolInformation>>> {
+        // The request isn't tied to a document, so search the workspaces of all open sessions.
+        let open_sessions: Vec<Arc<Session>> = self
+            .sessions
+            .iter()
+            .map(|item| item.value().clone())
+            .collect();
+        let mut sessions: Vec<Arc<Session>> = vec![];
+        for session in open_sessions {
+            for session in self.workspace_sessions(session).await {
+                if !sessions.iter().any(|s| Arc::ptr_eq(s, &session)) {
+                    sessions.push(session);
+                }
+            }
+        }
+        Ok(Some(capabilities::workspace_symbol::workspace_symbols(
+            &sessions,
+            &params.query,
+        )))
+    }
+
     async fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
@@ -438,6 +680,86 @@ impl LanguageServer for Backend {
         &self,
         params: GotoDefinitionParams,
     ) -> jsonrpc::Result<Option<GotoDefinitionResponse>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        if capabilities::manifest::is_manifest(uri) {
+            let position = params.text_document_position_params.position;
+            return Ok(self.manifest_text(uri).and_then(|text| {
+                capabilities::manifest::definition::goto_definition(uri, &text, position)
+            }));
+        }
+        match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position_params.position;
+                Ok(session.token_definition_response(uri, position))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> jsonrpc::Result<Option<Vec<CallHierarchyItem>>> {
+        match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position_params.position;
+                Ok(capabilities::call_hierarchy::prepare_call_hierarchy(
+                    session, &uri, position,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> jsonrpc::Result<Option<Vec<CallHierarchyIncomingCall>>> {
+        match self.get_uri_and_session(&params.item.uri) {
+            Ok((uri, session)) => {
+                let sessions = self.workspace_sessions(session.clone()).await;
+                Ok(capabilities::call_hierarchy::incoming_calls(
+                    &sessions,
+                    session,
+                    &uri,
+                    &params.item,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> jsonrpc::Result<Option<Vec<CallHierarchyOutgoingCall>>> {
+        match self.get_uri_and_session(&params.item.uri) {
+            Ok((uri, session)) => Ok(capabilities::call_hierarchy::outgoing_calls(
+                session,
+                &uri,
+                &params.item,
+            )),
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn goto_declaration(
+        &self,
+        params: GotoDeclarationParams,
+    ) -> jsonrpc::Result<Option<GotoDeclarationResponse>> {
+        // Sway has no forward declarations, so the declaration of a token is its definition.
         match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
             Ok((uri, session)) => {
                 let position = params.text_document_position_params.position;
@@ -450,6 +772,43 @@ impl LanguageServer for Backend {
         }
     }
 
+    async fn goto_type_definition(
+        &self,
+        params: GotoTypeDefinitionParams,
+    ) -> jsonrpc::Result<Option<GotoTypeDefinitionResponse>> {
+        match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position_params.position;
+                Ok(session
+                    .type_definition_location(&uri, position)
+                    .map(GotoTypeDefinitionResponse::Scalar))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn goto_implementation(
+        &self,
+        params: GotoImplementationParams,
+    ) -> jsonrpc::Result<Option<GotoImplementationResponse>> {
+        match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position_params.position;
+                Ok(
+                    capabilities::implementation::implementations(session, &uri, position)
+                        .map(GotoImplementationResponse::Array),
+                )
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
     async fn formatting(
         &self,
         params: DocumentFormattingParams,
@@ -462,14 +821,35 @@ impl LanguageServer for Backend {
             })
     }
 
+    async fn references(&self, params: ReferenceParams) -> jsonrpc::Result<Option<Vec<Location>>> {
+        match self.get_uri_and_session(&params.text_document_position.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position.position;
+                let sessions = self.workspace_sessions(session.clone()).await;
+                Ok(capabilities::references::references(
+                    &sessions,
+                    session,
+                    uri,
+                    position,
+                    params.context.include_declaration,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
     async fn rename(&self, params: RenameParams) -> jsonrpc::Result<Option<WorkspaceEdit>> {
         match self.get_uri_and_session(&params.text_document_position.text_document.uri) {
             Ok((uri, session)) => {
                 let new_name = params.new_name;
                 let position = params.text_document_position.position;
-                Ok(capabilities::rename::rename(
-                    session, new_name, uri, position,
-                ))
+                let sessions = self.workspace_sessions(session.clone()).await;
+                capabilities::rename::rename(&sessions, session, new_name, uri, position)
+                    .map(Some)
+                    .map_err(|err| jsonrpc::Error::invalid_params(err.to_string()))
             }
             Err(err) => {
                 tracing::error!("{}", err.to_string());
@@ -485,7 +865,9 @@ impl LanguageServer for Backend {
         match self.get_uri_and_session(&params.text_document.uri) {
             Ok((uri, session)) => {
                 let position = params.position;
-                Ok(capabilities::rename::prepare_rename(session, uri, position))
+                capabilities::rename::prepare_rename(session, uri, position)
+                    .map(Some)
+                    .map_err(|err| jsonrpc::Error::invalid_params(err.to_string()))
             }
             Err(err) => {
                 tracing::error!("{}", err.to_string());
@@ -503,6 +885,30 @@ pub struct ShowAstParams {
     pub save_path: Url,
 }
 
+/// The params of the `textDocument/prepareTypeHierarchy` request.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeHierarchyPrepareParams {
+    #[serde(flatten)]
+    pub text_document_position_params: TextDocumentPositionParams,
+    #[serde(flatten)]
+    pub work_done_progress_params: WorkDoneProgressParams,
+}
+
+/// The params of the `typeHierarchy/supertypes` request.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeHierarchySupertypesParams {
+    pub item: TypeHierarchyItem,
+    #[serde(flatten)]
+    pub work_done_progress_params: WorkDoneProgressParams,
+    #[serde(flatten)]
+    pub partial_result_params: PartialResultParams,
+}
+
+/// The params of the `typeHierarchy/subtypes` request.
+pub type TypeHierarchySubtypesParams = TypeHierarchySupertypesParams;
+
 // Custom LSP-Server Methods
 impl Backend {
     pub async fn inlay_hints(
@@ -526,6 +932,66 @@ impl Backend {
         }
     }
 
+    pub async fn prepare_type_hierarchy(
+        &self,
+        params: TypeHierarchyPrepareParams,
+    ) -> jsonrpc::Result<Option<Vec<TypeHierarchyItem>>> {
+        match self.get_uri_and_session(&params.text_document_position_params.text_document.uri) {
+            Ok((uri, session)) => {
+                let position = params.text_document_position_params.position;
+                Ok(capabilities::type_hierarchy::prepare_type_hierarchy(
+                    session, &uri, position,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    pub async fn supertypes(
+        &self,
+        params: TypeHierarchySupertypesParams,
+    ) -> jsonrpc::Result<Option<Vec<TypeHierarchyItem>>> {
+        match self.get_uri_and_session(&params.item.uri) {
+            Ok((uri, session)) => {
+                let sessions = self.workspace_sessions(session.clone()).await;
+                Ok(capabilities::type_hierarchy::supertypes(
+                    &sessions,
+                    session,
+                    &uri,
+                    &params.item,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
+    pub async fn subtypes(
+        &self,
+        params: TypeHierarchySubtypesParams,
+    ) -> jsonrpc::Result<Option<Vec<TypeHierarchyItem>>> {
+        match self.get_uri_and_session(&params.item.uri) {
+            Ok((uri, session)) => {
+                let sessions = self.workspace_sessions(session.clone()).await;
+                Ok(capabilities::type_hierarchy::subtypes(
+                    &sessions,
+                    session,
+                    &uri,
+                    &params.item,
+                ))
+            }
+            Err(err) => {
+                tracing::error!("{}", err.to_string());
+                Ok(None)
+            }
+        }
+    }
+
     /// This method is triggered by a command palette request in VScode
     /// The 3 commands are: "show lexed ast", "show parsed ast" or "show typed ast"
     ///
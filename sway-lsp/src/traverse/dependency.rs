@@ -21,6 +21,7 @@ pub fn collect_parsed_declaration(node: &AstNode, ctx: &ParseContext) {
             Declaration::FunctionDeclaration(decl) => (decl.name.clone(), SymbolKind::Function),
             Declaration::ConstantDeclaration(decl) => (decl.name.clone(), SymbolKind::Const),
             Declaration::EnumDeclaration(decl) => (decl.name.clone(), SymbolKind::Enum),
+            Declaration::TypeAliasDeclaration(decl) => (decl.name.clone(), SymbolKind::TypeAlias),
             _ => return,
         };
 
@@ -41,7 +42,8 @@ pub fn collect_typed_declaration(node: &ty::TyAstNode, ctx: &ParseContext) {
             | ty::TyDeclaration::EnumDeclaration { name, .. }
             | ty::TyDeclaration::TraitDeclaration { name, .. }
             | ty::TyDeclaration::FunctionDeclaration { name, .. }
-            | ty::TyDeclaration::ConstantDeclaration { name, .. } => name.clone(),
+            | ty::TyDeclaration::ConstantDeclaration { name, .. }
+            | ty::TyDeclaration::TypeAliasDeclaration { name, .. } => name.clone(),
             _ => return,
         };
         let ident = token::to_ident_key(&ident);
@@ -6,9 +6,9 @@ use sway_ast::{
     expr::LoopControlFlow, ty::TyTupleDescriptor, Assignable, CodeBlockContents, ConfigurableField,
     Expr, ExprArrayDescriptor, ExprStructField, ExprTupleDescriptor, FnArg, FnArgs, FnSignature,
     IfCondition, IfExpr, ItemAbi, ItemConfigurable, ItemConst, ItemEnum, ItemFn, ItemImpl,
-    ItemImplItem, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemUse, MatchBranchKind,
-    ModuleKind, Pattern, PatternStructField, Statement, StatementLet, StorageField, Ty, TypeField,
-    UseTree,
+    ItemImplItem, ItemKind, ItemStorage, ItemStruct, ItemTrait, ItemTypeAlias, ItemUse,
+    MatchBranchKind, ModuleKind, Pattern, PatternStructField, Statement, StatementLet,
+    StorageField, TraitType, Ty, TypeField, UseTree,
 };
 use sway_core::language::lexed::LexedProgram;
 use sway_types::{Ident, Span, Spanned};
@@ -86,6 +86,9 @@ impl Parse for ItemKind {
             ItemKind::Configurable(item_configurable) => {
                 item_configurable.parse(ctx);
             }
+            ItemKind::TypeAlias(item_type_alias) => {
+                item_type_alias.parse(ctx);
+            }
         }
     }
 }
@@ -147,12 +150,33 @@ impl Parse for Expr {
                 condition.parse(ctx);
                 block.get().parse(ctx);
             }
+            Expr::For {
+                for_token,
+                value_pattern,
+                in_token,
+                iterator,
+                block,
+            } => {
+                insert_keyword(ctx, for_token.span());
+                value_pattern.parse(ctx);
+                insert_keyword(ctx, in_token.span());
+                iterator.parse(ctx);
+                block.get().parse(ctx);
+            }
             Expr::FuncApp { func, args } => {
                 func.parse(ctx);
                 for expr in args.get().into_iter() {
                     expr.parse(ctx);
                 }
             }
+            Expr::Closure { params, body } => {
+                for param in params.clone().into_params() {
+                    if let Some((_, ty)) = &param.ty_opt {
+                        ty.parse(ctx);
+                    }
+                }
+                body.parse(ctx);
+            }
             Expr::Index { target, arg } => {
                 target.parse(ctx);
                 arg.get().parse(ctx);
@@ -298,6 +322,8 @@ impl Parse for ItemTrait {
             .iter()
             .for_each(|(annotated, _)| match &annotated.value {
                 sway_ast::ItemTraitItem::Fn(fn_sig) => fn_sig.parse(ctx),
+                sway_ast::ItemTraitItem::Const(item_const) => item_const.parse(ctx),
+                sway_ast::ItemTraitItem::Type(trait_type) => trait_type.parse(ctx),
             });
 
         if let Some(trait_defs_opt) = &self.trait_defs_opt {
@@ -328,6 +354,8 @@ impl Parse for ItemImpl {
             .iter()
             .for_each(|item| match &item.value {
                 ItemImplItem::Fn(fn_decl) => fn_decl.parse(ctx),
+                ItemImplItem::Const(item_const) => item_const.parse(ctx),
+                ItemImplItem::Type(trait_type) => trait_type.parse(ctx),
             });
     }
 }
@@ -341,6 +369,8 @@ impl Parse for ItemAbi {
             .iter()
             .for_each(|(annotated, _)| match &annotated.value {
                 sway_ast::ItemTraitItem::Fn(fn_sig) => fn_sig.parse(ctx),
+                sway_ast::ItemTraitItem::Const(item_const) => item_const.parse(ctx),
+                sway_ast::ItemTraitItem::Type(trait_type) => trait_type.parse(ctx),
             });
 
         if let Some(abi_defs_opt) = self.abi_defs_opt.as_ref() {
@@ -352,6 +382,16 @@ impl Parse for ItemAbi {
     }
 }
 
+impl Parse for TraitType {
+    fn parse(&self, ctx: &ParseContext) {
+        insert_keyword(ctx, self.type_token.span());
+
+        if let Some(ty) = self.ty_opt.as_ref() {
+            ty.parse(ctx);
+        }
+    }
+}
+
 impl Parse for ItemConst {
     fn parse(&self, ctx: &ParseContext) {
         if let Some(visibility) = &self.visibility {
@@ -369,6 +409,17 @@ impl Parse for ItemConst {
     }
 }
 
+impl Parse for ItemTypeAlias {
+    fn parse(&self, ctx: &ParseContext) {
+        if let Some(visibility) = &self.visibility {
+            insert_keyword(ctx, visibility.span());
+        }
+        insert_keyword(ctx, self.type_token.span());
+
+        self.ty.parse(ctx);
+    }
+}
+
 impl Parse for ItemStorage {
     fn parse(&self, ctx: &ParseContext) {
         insert_keyword(ctx, self.storage_token.span());
@@ -445,6 +496,25 @@ impl Parse for Ty {
                 insert_keyword(ctx, str_token.span());
                 length.get().parse(ctx);
             }
+            Ty::StringSlice(str_token) => {
+                insert_keyword(ctx, str_token.span());
+            }
+            Ty::Fn {
+                fn_token,
+                parameters,
+                return_type_opt,
+            } => {
+                insert_keyword(ctx, fn_token.span());
+                for ty in parameters.get().into_iter() {
+                    ty.parse(ctx);
+                }
+                if let Some((_, ty)) = return_type_opt {
+                    ty.parse(ctx);
+                }
+            }
+            Ty::TraitObject { dyn_token, .. } => {
+                insert_keyword(ctx, dyn_token.span());
+            }
             _ => {}
         }
     }
@@ -25,8 +25,8 @@ use sway_core::{
             Scrutinee, StorageAccessExpression, StorageDeclaration, StorageField,
             StructDeclaration, StructExpression, StructExpressionField, StructField,
             StructScrutineeField, SubfieldExpression, Supertrait, TraitDeclaration, TraitFn,
-            TraitItem, TupleIndexExpression, UseStatement, VariableDeclaration,
-            WhileLoopExpression,
+            TraitItem, TraitTypeDeclaration, TupleIndexExpression, TypeAliasDeclaration,
+            UseStatement, VariableDeclaration, WhileLoopExpression,
         },
         CallPathTree, Literal,
     },
@@ -34,7 +34,9 @@ use sway_core::{
     type_system::{TypeArgument, TypeParameter},
     TraitConstraint, TypeInfo,
 };
-use sway_types::constants::{DESTRUCTURE_PREFIX, MATCH_RETURN_VAR_NAME_PREFIX, TUPLE_NAME_PREFIX};
+use sway_types::constants::{
+    DESTRUCTURE_PREFIX, FOR_LOOP_VAR_NAME_PREFIX, MATCH_RETURN_VAR_NAME_PREFIX, TUPLE_NAME_PREFIX,
+};
 use sway_types::{Ident, Span, Spanned};
 
 pub struct ParsedTree<'a> {
@@ -126,6 +128,7 @@ impl Parse for Declaration {
             Declaration::AbiDeclaration(decl) => decl.parse(ctx),
             Declaration::ConstantDeclaration(decl) => decl.parse(ctx),
             Declaration::StorageDeclaration(decl) => decl.parse(ctx),
+            Declaration::TypeAliasDeclaration(decl) => decl.parse(ctx),
         }
     }
 }
@@ -193,6 +196,7 @@ impl Parse for Expression {
             ExpressionKind::Variable(name) => {
                 if !name.as_str().contains(TUPLE_NAME_PREFIX)
                     && !name.as_str().contains(MATCH_RETURN_VAR_NAME_PREFIX)
+                    && !name.as_str().contains(FOR_LOOP_VAR_NAME_PREFIX)
                 {
                     let symbol_kind = if name.as_str().contains(DESTRUCTURE_PREFIX) {
                         SymbolKind::Struct
@@ -307,6 +311,19 @@ impl Parse for Expression {
             ExpressionKind::Return(expr) => {
                 expr.parse(ctx);
             }
+            ExpressionKind::Closure(closure) => {
+                for param in &closure.parameters {
+                    ctx.tokens.insert(
+                        to_ident_key(&param.name),
+                        Token::from_parsed(
+                            AstToken::Ident(param.name.clone()),
+                            SymbolKind::ValueParam,
+                        ),
+                    );
+                    param.type_argument.parse(ctx);
+                }
+                closure.body.parse(ctx);
+            }
             // We are collecting these tokens in the lexed phase.
             ExpressionKind::Break | ExpressionKind::Continue => {}
         }
@@ -368,6 +385,9 @@ impl Parse for AbiCastExpression {
             to_ident_key(&self.abi_name.suffix),
             Token::from_parsed(AstToken::AbiCastExpression(self.clone()), SymbolKind::Trait),
         );
+        self.type_arguments
+            .iter()
+            .for_each(|type_arg| type_arg.parse(ctx));
         self.address.parse(ctx);
     }
 }
@@ -639,9 +659,10 @@ impl Parse for FunctionApplicationExpression {
 impl Parse for VariableDeclaration {
     fn parse(&self, ctx: &ParseContext) {
         // Don't collect tokens if the ident's name contains __tuple_ || __match_return_var_name_
-        // The individual elements are handled in the subsequent VariableDeclaration's
+        // || __for_loop_. The individual elements are handled in the subsequent VariableDeclaration's
         if !self.name.as_str().contains(TUPLE_NAME_PREFIX)
             && !self.name.as_str().contains(MATCH_RETURN_VAR_NAME_PREFIX)
+            && !self.name.as_str().contains(FOR_LOOP_VAR_NAME_PREFIX)
         {
             let symbol_kind = if self.name.as_str().contains(DESTRUCTURE_PREFIX) {
                 SymbolKind::Struct
@@ -702,6 +723,8 @@ impl Parse for TraitDeclaration {
         );
         self.interface_surface.iter().for_each(|item| match item {
             TraitItem::TraitFn(trait_fn) => trait_fn.parse(ctx),
+            TraitItem::Constant(const_decl) => const_decl.parse(ctx),
+            TraitItem::Type(type_decl) => type_decl.parse(ctx),
         });
         self.methods.iter().for_each(|func_dec| {
             func_dec.parse(ctx);
@@ -750,6 +773,23 @@ impl Parse for EnumDeclaration {
     }
 }
 
+impl Parse for TypeAliasDeclaration {
+    fn parse(&self, ctx: &ParseContext) {
+        ctx.tokens.insert(
+            to_ident_key(&self.name),
+            Token::from_parsed(
+                AstToken::Declaration(Declaration::TypeAliasDeclaration(self.clone())),
+                SymbolKind::TypeAlias,
+            ),
+        );
+        self.type_parameters.iter().for_each(|type_param| {
+            type_param.parse(ctx);
+        });
+        self.ty.parse(ctx);
+        self.attributes.parse(ctx);
+    }
+}
+
 impl Parse for ImplTrait {
     fn parse(&self, ctx: &ParseContext) {
         for ident in &self.trait_name.prefixes {
@@ -771,6 +811,8 @@ impl Parse for ImplTrait {
         });
         self.items.iter().for_each(|item| match item {
             ImplItem::Fn(fn_decl) => fn_decl.parse(ctx),
+            ImplItem::Constant(const_decl) => const_decl.parse(ctx),
+            ImplItem::Type(type_decl) => type_decl.parse(ctx),
         });
     }
 }
@@ -800,6 +842,8 @@ impl Parse for ImplSelf {
         });
         self.items.iter().for_each(|item| match item {
             ImplItem::Fn(fn_decl) => fn_decl.parse(ctx),
+            ImplItem::Constant(const_decl) => const_decl.parse(ctx),
+            ImplItem::Type(type_decl) => type_decl.parse(ctx),
         });
     }
 }
@@ -815,6 +859,8 @@ impl Parse for AbiDeclaration {
         );
         self.interface_surface.iter().for_each(|item| match item {
             TraitItem::TraitFn(trait_fn) => trait_fn.parse(ctx),
+            TraitItem::Constant(const_decl) => const_decl.parse(ctx),
+            TraitItem::Type(type_decl) => type_decl.parse(ctx),
         });
         self.supertraits.iter().for_each(|supertrait| {
             supertrait.parse(ctx);
@@ -870,6 +916,22 @@ impl Parse for Supertrait {
     }
 }
 
+impl Parse for TraitTypeDeclaration {
+    fn parse(&self, ctx: &ParseContext) {
+        ctx.tokens.insert(
+            to_ident_key(&self.name),
+            Token::from_parsed(
+                AstToken::TraitTypeDeclaration(self.clone()),
+                SymbolKind::TypeParameter,
+            ),
+        );
+        if let Some(ty) = &self.ty_opt {
+            ty.parse(ctx);
+        }
+        self.attributes.parse(ctx);
+    }
+}
+
 impl Parse for TraitFn {
     fn parse(&self, ctx: &ParseContext) {
         ctx.tokens.insert(
@@ -1046,6 +1108,7 @@ fn literal_to_symbol_kind(value: &Literal) -> SymbolKind {
         | Literal::U16(..)
         | Literal::U32(..)
         | Literal::U64(..)
+        | Literal::U256(..)
         | Literal::Numeric(..) => SymbolKind::NumericLiteral,
         Literal::String(..) => SymbolKind::StringLiteral,
         Literal::B256(..) => SymbolKind::ByteLiteral,
@@ -111,6 +111,14 @@ impl<'a> TypedTree<'a> {
                             let trait_fn = decl_engine.get_trait_fn(trait_fn_decl_ref);
                             self.collect_typed_trait_fn_token(&trait_fn);
                         }
+                        ty::TyTraitInterfaceItem::Constant(const_decl_ref) => {
+                            let const_decl = decl_engine.get_constant(const_decl_ref);
+                            self.collect_const_decl(&const_decl);
+                        }
+                        ty::TyTraitInterfaceItem::Type(type_decl_ref) => {
+                            let type_decl = decl_engine.get_trait_type(type_decl_ref);
+                            self.collect_trait_type_decl(&type_decl);
+                        }
                     }
                 }
                 for supertrait in trait_decl.supertraits {
@@ -237,6 +245,14 @@ impl<'a> TypedTree<'a> {
                             let method = decl_engine.get_function(&method_ref);
                             self.collect_typed_fn_decl(&method);
                         }
+                        ty::TyTraitItem::Constant(const_decl_ref) => {
+                            let const_decl = decl_engine.get_constant(&const_decl_ref);
+                            self.collect_const_decl(&const_decl);
+                        }
+                        ty::TyTraitItem::Type(type_decl_ref) => {
+                            let type_decl = decl_engine.get_trait_type(&type_decl_ref);
+                            self.collect_trait_type_decl(&type_decl);
+                        }
                     }
                 }
 
@@ -272,6 +288,14 @@ impl<'a> TypedTree<'a> {
                             let trait_fn = decl_engine.get_trait_fn(trait_fn_decl_ref);
                             self.collect_typed_trait_fn_token(&trait_fn);
                         }
+                        ty::TyTraitInterfaceItem::Constant(const_decl_ref) => {
+                            let const_decl = decl_engine.get_constant(const_decl_ref);
+                            self.collect_const_decl(&const_decl);
+                        }
+                        ty::TyTraitInterfaceItem::Type(type_decl_ref) => {
+                            let type_decl = decl_engine.get_trait_type(type_decl_ref);
+                            self.collect_trait_type_decl(&type_decl);
+                        }
                     }
                 }
 
@@ -279,6 +303,32 @@ impl<'a> TypedTree<'a> {
                     self.collect_supertrait(&supertrait);
                 }
             }
+            ty::TyDeclaration::TypeAliasDeclaration { decl_id, .. } => {
+                let type_alias_decl = decl_engine.get_type_alias(decl_id);
+                if let Some(mut token) = self
+                    .ctx
+                    .tokens
+                    .try_get_mut(&to_ident_key(&type_alias_decl.call_path.suffix))
+                    .try_unwrap()
+                {
+                    token.typed = Some(TypedAstToken::TypedDeclaration(declaration.clone()));
+                    token.type_def = Some(TypeDefinition::Ident(type_alias_decl.call_path.suffix));
+                }
+
+                for type_param in &type_alias_decl.type_parameters {
+                    if let Some(mut token) = self
+                        .ctx
+                        .tokens
+                        .try_get_mut(&to_ident_key(&type_param.name_ident))
+                        .try_unwrap()
+                    {
+                        token.typed = Some(TypedAstToken::TypedParameter(type_param.clone()));
+                        token.type_def = Some(TypeDefinition::TypeId(type_param.type_id));
+                    }
+                }
+
+                self.collect_type_argument(&type_alias_decl.ty);
+            }
             ty::TyDeclaration::GenericTypeForFunctionScope { name, type_id } => {
                 if let Some(mut token) = self
                     .ctx
@@ -738,7 +788,8 @@ impl<'a> TypedTree<'a> {
                 self.handle_intrinsic_function(kind);
             }
             ty::TyExpressionVariant::AbiName { .. } => {}
-            ty::TyExpressionVariant::EnumTag { exp } => {
+            ty::TyExpressionVariant::EnumTag { exp }
+            | ty::TyExpressionVariant::TraitObject { exp, .. } => {
                 self.handle_expression(exp);
             }
             ty::TyExpressionVariant::UnsafeDowncast { exp, variant } => {
@@ -757,6 +808,17 @@ impl<'a> TypedTree<'a> {
             } => self.handle_while_loop(body, condition),
             ty::TyExpressionVariant::Break => (),
             ty::TyExpressionVariant::Continue => (),
+            ty::TyExpressionVariant::ConstGenericValue { name, .. } => {
+                if let Some(mut token) = self
+                    .ctx
+                    .tokens
+                    .try_get_mut(&to_ident_key(&Ident::new(expression.span.clone())))
+                    .try_unwrap()
+                {
+                    token.typed = Some(TypedAstToken::TypedExpression(expression.clone()));
+                    token.type_def = Some(TypeDefinition::Ident(name.clone()));
+                }
+            }
             ty::TyExpressionVariant::Reassignment(reassignment) => {
                 self.handle_expression(&reassignment.rhs);
 
@@ -885,6 +947,29 @@ impl<'a> TypedTree<'a> {
                 self.handle_expression(&storage_reassignment.rhs);
             }
             ty::TyExpressionVariant::Return(exp) => self.handle_expression(exp),
+            ty::TyExpressionVariant::Closure { fn_ref, captures } => {
+                // The captures are synthesized from the variables used in the body,
+                // so only the closure's own parameters and its body are collected.
+                let func_decl = decl_engine.get_function(fn_ref);
+                for param in &func_decl.parameters[captures.len()..] {
+                    self.collect_typed_fn_param_token(param);
+                }
+                for node in &func_decl.body.contents {
+                    self.traverse_node(node);
+                }
+            }
+            ty::TyExpressionVariant::ClosureCall {
+                closure: object,
+                arguments,
+            }
+            | ty::TyExpressionVariant::DynamicMethodCall {
+                object, arguments, ..
+            } => {
+                self.handle_expression(object);
+                for arg in arguments {
+                    self.handle_expression(arg);
+                }
+            }
         }
     }
 
@@ -1087,6 +1172,19 @@ impl<'a> TypedTree<'a> {
         }
     }
 
+    fn collect_trait_type_decl(&self, type_decl: &ty::TyTraitType) {
+        if let Some(mut token) = self
+            .ctx
+            .tokens
+            .try_get_mut(&to_ident_key(&type_decl.name))
+            .try_unwrap()
+        {
+            token.typed = Some(TypedAstToken::TypedTraitType(type_decl.clone()));
+            token.type_def = Some(TypeDefinition::TypeId(type_decl.ty.type_id));
+        }
+        self.collect_type_argument(&type_decl.ty);
+    }
+
     fn collect_typed_fn_param_token(&self, param: &ty::TyFunctionParameter) {
         let typed_token = TypedAstToken::TypedFunctionParameter(param.clone());
         if let Some(mut token) = self
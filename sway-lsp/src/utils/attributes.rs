@@ -11,11 +11,13 @@ pub fn attributes_map(token: &Token) -> Option<&transform::AttributesMap> {
             Declaration::ConstantDeclaration(decl) => Some(&decl.attributes),
             Declaration::StorageDeclaration(decl) => Some(&decl.attributes),
             Declaration::AbiDeclaration(decl) => Some(&decl.attributes),
+            Declaration::TypeAliasDeclaration(decl) => Some(&decl.attributes),
             _ => None,
         },
         AstToken::StorageField(field) => Some(&field.attributes),
         AstToken::StructField(field) => Some(&field.attributes),
         AstToken::TraitFn(trait_fn) => Some(&trait_fn.attributes),
+        AstToken::TraitTypeDeclaration(trait_type) => Some(&trait_type.attributes),
         AstToken::EnumVariant(variant) => Some(&variant.attributes),
         _ => None,
     }
@@ -27,6 +29,20 @@ pub fn doc_comment_attributes(token: &Token) -> Option<&[transform::Attribute]>
         .map(Vec::as_slice)
 }
 
+pub fn format_doc_attributes(token: &Token) -> String {
+    let mut doc_comment = String::new();
+    if let Some(attributes) = doc_comment_attributes(token) {
+        doc_comment = attributes
+            .iter()
+            .map(|attribute| {
+                let comment = attribute.args.first().unwrap().name.as_str();
+                format!("{comment}\n")
+            })
+            .collect()
+    }
+    doc_comment
+}
+
 pub fn storage_attributes(token: &Token) -> Option<&[transform::Attribute]> {
     attributes_map(token)
         .and_then(|attributes| attributes.get(&transform::AttributeKind::Storage))
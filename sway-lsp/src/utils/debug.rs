@@ -51,6 +51,7 @@ fn literal_to_string(literal: &Literal) -> String {
         Literal::U16(_) => "u16".into(),
         Literal::U32(_) => "u32".into(),
         Literal::U64(_) => "u64".into(),
+        Literal::U256(_) => "u256".into(),
         Literal::Numeric(_) => "u64".into(),
         Literal::String(len) => format!("str[{}]", len.as_str().len()),
         Literal::Boolean(_) => "bool".into(),
@@ -97,6 +98,10 @@ pub(crate) fn print_decl_engine_types(
                     let storage_decl = decl_engine.get_storage(decl_id);
                     format!("{storage_decl:#?}")
                 }
+                ty::TyDeclaration::TypeAliasDeclaration { decl_id, .. } => {
+                    let type_alias_decl = decl_engine.get_type_alias(decl_id);
+                    format!("{type_alias_decl:#?}")
+                }
                 _ => format!("{declaration:#?}"),
             },
             ty::TyAstNodeContent::Expression(expression)
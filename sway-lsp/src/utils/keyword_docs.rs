@@ -685,11 +685,75 @@ impl KeywordDocs {
             mod str_keyword {}
         };
 
-        // TODO
         let for_keyword: ItemMod = parse_quote! {
+            /// Iteration with [`in`], trait implementation with [`impl`].
+            ///
+            /// The `for` keyword is used in two places:
+            ///
+            /// - `for` loops, which iterate over any value implementing the `Iterator` trait.
+            /// - When implementing traits, as in `impl Trait for Type`.
+            ///
+            /// A `for` loop calls `next` on the iterator until it returns `None`, binding each
+            /// item to the given pattern:
+            ///
+            /// ```sway
+            /// let mut sum = 0;
+            ///
+            /// for i in Range::new(0, 10) {
+            ///     sum += i;
+            /// }
+            /// ```
+            ///
+            /// Like [`while`], a `for` loop supports [`break`] and [`continue`] and always
+            /// evaluates to `()`.
             mod for_keyword {}
         };
 
+        let in_keyword: ItemMod = parse_quote! {
+            /// Iterate over a series of values with [`for`].
+            ///
+            /// The expression immediately following `in` must evaluate to a value whose type
+            /// implements the `Iterator` trait.
+            ///
+            /// ```sway
+            /// for value in vec.iter() {
+            ///     log(value);
+            /// }
+            /// ```
+            mod in_keyword {}
+        };
+
+        let dyn_keyword: ItemMod = parse_quote! {
+            /// Name the type of a trait object, whose methods are dispatched at runtime.
+            ///
+            /// A value of any type that implements the trait can be coerced to `dyn Trait`
+            /// where a `dyn Trait` is expected.
+            ///
+            /// ```sway
+            /// trait Shape {
+            ///     fn area(self) -> u64;
+            /// }
+            ///
+            /// fn total_area(a: dyn Shape, b: dyn Shape) -> u64 {
+            ///     a.area() + b.area()
+            /// }
+            /// ```
+            mod dyn_keyword {}
+        };
+
+        let type_keyword: ItemMod = parse_quote! {
+            /// Define an alias for an existing type, or declare an associated type in a trait.
+            ///
+            /// ```sway
+            /// type Balance = u64;
+            ///
+            /// trait Iterator {
+            ///     type Item;
+            /// }
+            /// ```
+            mod type_keyword {}
+        };
+
         // TODO
         let where_keyword: ItemMod = parse_quote! {
             mod where_keyword {}
@@ -788,6 +852,9 @@ impl KeywordDocs {
             asm_keyword,
             deref_keyword,
             configurable_keyword,
+            type_keyword,
+            in_keyword,
+            dyn_keyword,
         ];
 
         keywords.iter().for_each(|keyword| {
@@ -0,0 +1,14 @@
+contract;
+
+abi MyContract {
+    fn get_value() -> b256;
+    fn set_value(value: u64);
+}
+
+impl MyContract for Contract {
+    fn get_value() -> b256 {
+        ZERO_B256
+    }
+}
+
+fn badName() {}
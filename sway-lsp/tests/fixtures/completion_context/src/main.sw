@@ -0,0 +1,40 @@
+contract;
+
+use std::constants::ZERO_B256;
+
+enum Color {
+    Red: (),
+    Blue: u64,
+}
+
+storage {
+    counter: u64 = 0,
+    owner: b256 = ZERO_B256,
+}
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64;
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64 {
+        let color = Color::Blue(amount);
+        let current = storage.counter + amount;
+        storage.counter = current;
+        current
+    }
+}
+
+fn scale(amount: u64) -> u64 {
+    let factor = 2;
+    if amount > 10 {
+        let doubled = amount * factor;
+        let tripled = doubled + amount;
+        tripled
+    } else {
+        let halved = amount / factor;
+        halved
+    }
+}
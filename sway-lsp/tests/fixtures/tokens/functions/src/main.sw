@@ -34,3 +34,12 @@ pub enum DumbError {
 pub fn func(r: Rezult<u8, DumbError>) -> Rezult<u8, DumbError> {
     Rezult::Ok(1u8)
 }
+
+/// Scales a point by `factor`, labelling it with `label`
+fn scale(p: Point, label: str, factor: u32) -> Point {
+    Point { x: p.x * factor, y: p.y * factor }
+}
+
+fn test_scale() {
+    let p = scale(foo(), /* ) */ "🚀, (", 2);
+}
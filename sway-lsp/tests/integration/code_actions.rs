@@ -320,3 +320,246 @@ pub(crate) async fn code_action_struct_existing_impl_request(
     assert_json_eq!(expected, response.ok().unwrap());
     code_action
 }
+
+/// Sends a code action request for the range of the given diagnostic, as clients do to request
+/// the quick fixes for it.
+async fn quick_fix_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+    diagnostic: serde_json::Value,
+) -> (Request, Option<Response>) {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "range": diagnostic["range"],
+        "context": {
+            "diagnostics": [diagnostic],
+            "triggerKind": 1
+        }
+    });
+    let code_action = build_request_with_id("textDocument/codeAction", params, 1);
+    let response = call_request(service, code_action.clone()).await;
+    (code_action, response.ok().unwrap())
+}
+
+pub(crate) async fn code_action_missing_use_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let diagnostic = json!({
+        "range": {
+            "start": {
+                "line": 9,
+                "character": 8
+            },
+            "end": {
+                "line": 9,
+                "character": 17
+            }
+        },
+        "severity": 1,
+        "message": "Variable \"ZERO_B256\" does not exist in this scope.",
+        "data": {
+            "kind": "unknownSymbol",
+            "name": "ZERO_B256"
+        }
+    });
+    let (code_action, response) = quick_fix_request(service, uri, diagnostic.clone()).await;
+    let uri_string = uri.to_string();
+    let expected = Response::from_ok(
+        1.into(),
+        json!([{
+            "diagnostics": [diagnostic],
+            "edit": {
+              "changes": {
+                uri_string: [
+                  {
+                    "newText": "\nuse std::constants::ZERO_B256;\n",
+                    "range": {
+                      "end": {
+                        "character": 0,
+                        "line": 1
+                      },
+                      "start": {
+                        "character": 0,
+                        "line": 1
+                      }
+                    }
+                  }
+                ]
+              }
+            },
+            "kind": "quickfix",
+            "title": "Import `std::constants::ZERO_B256`"
+        }]),
+    );
+    assert_json_eq!(expected, response);
+    code_action
+}
+
+pub(crate) async fn code_action_missing_methods_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let diagnostic = json!({
+        "range": {
+            "start": {
+                "line": 7,
+                "character": 0
+            },
+            "end": {
+                "line": 11,
+                "character": 1
+            }
+        },
+        "severity": 1,
+        "message": "Functions are missing from this trait implementation: set_value",
+        "data": {
+            "kind": "missingMethods",
+            "interface": "MyContract",
+            "functions": ["set_value"]
+        }
+    });
+    let (code_action, response) = quick_fix_request(service, uri, diagnostic.clone()).await;
+    let uri_string = uri.to_string();
+    let expected = Response::from_ok(
+        1.into(),
+        json!([{
+            "diagnostics": [diagnostic],
+            "edit": {
+              "changes": {
+                uri_string: [
+                  {
+                    "newText": "    fn set_value(value: u64) {}\n",
+                    "range": {
+                      "end": {
+                        "character": 0,
+                        "line": 11
+                      },
+                      "start": {
+                        "character": 0,
+                        "line": 11
+                      }
+                    }
+                  }
+                ]
+              }
+            },
+            "kind": "quickfix",
+            "title": "Implement missing functions of `MyContract`"
+        }]),
+    );
+    assert_json_eq!(expected, response);
+    code_action
+}
+
+pub(crate) async fn code_action_naming_convention_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let diagnostic = json!({
+        "range": {
+            "start": {
+                "line": 13,
+                "character": 3
+            },
+            "end": {
+                "line": 13,
+                "character": 10
+            }
+        },
+        "severity": 2,
+        "message": "Function name \"badName\" is not idiomatic. Function names should be snake_case, like \"bad_name\".",
+        "data": {
+            "kind": "namingConvention",
+            "suggestion": "bad_name"
+        }
+    });
+    let (code_action, response) = quick_fix_request(service, uri, diagnostic.clone()).await;
+    let uri_string = uri.to_string();
+    let expected = Response::from_ok(
+        1.into(),
+        json!([{
+            "diagnostics": [diagnostic],
+            "edit": {
+              "changes": {
+                uri_string: [
+                  {
+                    "newText": "bad_name",
+                    "range": {
+                      "end": {
+                        "character": 10,
+                        "line": 13
+                      },
+                      "start": {
+                        "character": 3,
+                        "line": 13
+                      }
+                    }
+                  }
+                ]
+              }
+            },
+            "kind": "quickfix",
+            "title": "Rename `badName` to `bad_name`"
+        }]),
+    );
+    assert_json_eq!(expected, response);
+    code_action
+}
+
+pub(crate) async fn code_action_allow_dead_code_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let diagnostic = json!({
+        "range": {
+            "start": {
+                "line": 42,
+                "character": 3
+            },
+            "end": {
+                "line": 42,
+                "character": 12
+            }
+        },
+        "severity": 2,
+        "message": "This function is never called.",
+        "tags": [1],
+        "data": {
+            "kind": "deadCode"
+        }
+    });
+    let (code_action, response) = quick_fix_request(service, uri, diagnostic.clone()).await;
+    let uri_string = uri.to_string();
+    let expected = Response::from_ok(
+        1.into(),
+        json!([{
+            "diagnostics": [diagnostic],
+            "edit": {
+              "changes": {
+                uri_string: [
+                  {
+                    "newText": "#[allow(dead_code)]\n",
+                    "range": {
+                      "end": {
+                        "character": 0,
+                        "line": 42
+                      },
+                      "start": {
+                        "character": 0,
+                        "line": 42
+                      }
+                    }
+                  }
+                ]
+              }
+            },
+            "kind": "quickfix",
+            "title": "Add `#[allow(dead_code)]`"
+        }]),
+    );
+    assert_json_eq!(expected, response);
+    code_action
+}
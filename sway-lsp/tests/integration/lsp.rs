@@ -10,7 +10,7 @@ use sway_lsp::server::{self, Backend};
 use sway_lsp_test_utils::extract_result_array;
 use tower::{Service, ServiceExt};
 use tower_lsp::{
-    jsonrpc::{Id, Request, Response},
+    jsonrpc::{ErrorCode, Id, Request, Response},
     lsp_types::*,
     ExitedError, LspService,
 };
@@ -234,6 +234,411 @@ pub(crate) async fn highlight_request(service: &mut LspService<Backend>, uri: &U
     highlight
 }
 
+pub(crate) async fn references_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": {
+            "line": 17,
+            "character": 15
+        },
+        "context": {
+            "includeDeclaration": true
+        }
+    });
+    let references = build_request_with_id("textDocument/references", params, 1);
+    let response = call_request(service, references.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let locations: Vec<Location> = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected a list of locations");
+    let mut actual_results: Vec<(String, Range)> = locations
+        .into_iter()
+        .map(|location| (location.uri.to_string(), location.range))
+        .collect();
+    actual_results.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let expected_results = vec![
+        (
+            "fields/src/foo.sw",
+            Range::new(Position::new(2, 11), Position::new(2, 14)),
+        ),
+        (
+            "fields/src/main.sw",
+            Range::new(Position::new(17, 14), Position::new(17, 17)),
+        ),
+    ];
+    assert_eq!(actual_results.len(), expected_results.len());
+    for ((uri, range), (path, expected_range)) in actual_results.iter().zip(expected_results) {
+        assert!(uri.ends_with(path), "{uri} doesn't end with {path}");
+        assert_eq!(*range, expected_range);
+    }
+    references
+}
+
+pub(crate) async fn type_definition_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": {
+            "line": 20,
+            "character": 17
+        }
+    });
+    let type_definition = build_request_with_id("textDocument/typeDefinition", params, 1);
+    let response = call_request(service, type_definition.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let location: Location =
+        serde_json::from_value(response.result().unwrap().clone()).expect("expected a location");
+    assert!(location.uri.path().ends_with("functions/src/main.sw"));
+    assert_eq!(
+        location.range,
+        Range::new(Position::new(2, 7), Position::new(2, 12))
+    );
+    type_definition
+}
+
+pub(crate) async fn implementation_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    // From the ABI to the type it is implemented for.
+    let _ = implementation_check(
+        service,
+        uri,
+        Position::new(5, 6),
+        Range::new(Position::new(9, 20), Position::new(9, 28)),
+    )
+    .await;
+    // From the ABI method to the method implementing it.
+    implementation_check(
+        service,
+        uri,
+        Position::new(6, 9),
+        Range::new(Position::new(10, 7), Position::new(10, 20)),
+    )
+    .await
+}
+
+pub(crate) async fn implementation_of_trait_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    // From the trait to the type of an impl block without any items.
+    implementation_check(
+        service,
+        uri,
+        Position::new(6, 10),
+        Range::new(Position::new(10, 15), Position::new(10, 16)),
+    )
+    .await
+}
+
+async fn implementation_check(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+    position: Position,
+    expected_range: Range,
+) -> Request {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": position,
+    });
+    let implementation = build_request_with_id("textDocument/implementation", params, 1);
+    let response = call_request(service, implementation.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let locations: Vec<Location> = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected a list of locations");
+    assert_eq!(locations.len(), 1);
+    assert_eq!(locations[0].uri.path(), uri.path());
+    assert_eq!(locations[0].range, expected_range);
+    implementation
+}
+
+pub(crate) async fn signature_help_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": {
+            "line": 20,
+            "character": 17
+        }
+    });
+    let signature_help_request = build_request_with_id("textDocument/signatureHelp", params, 1);
+    let response = call_request(service, signature_help_request.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let signature_help: SignatureHelp = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected signature help");
+    let signature = &signature_help.signatures[0];
+    assert_eq!(signature.label, "fn bar(p: Point) -> Point");
+    assert_eq!(
+        signature.parameters,
+        Some(vec![ParameterInformation {
+            label: ParameterLabel::LabelOffsets([7, 15]),
+            documentation: None,
+        }])
+    );
+    assert_eq!(signature_help.active_parameter, Some(0));
+    match &signature.documentation {
+        Some(Documentation::MarkupContent(content)) => assert_eq!(
+            content.value.trim(),
+            "A function declaration with struct as a function parameter"
+        ),
+        _ => panic!("expected the doc comment of the function"),
+    }
+    signature_help_request
+}
+
+pub(crate) async fn signature_help_past_strings_and_comments_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    // The cursor is just after the string literal, which contains an astral char that is
+    // two UTF-16 code units long.
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": {
+            "line": 43,
+            "character": 40
+        }
+    });
+    let signature_help_request = build_request_with_id("textDocument/signatureHelp", params, 1);
+    let response = call_request(service, signature_help_request.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let signature_help: SignatureHelp = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected signature help");
+    let signature = &signature_help.signatures[0];
+    assert_eq!(
+        signature.label,
+        "fn scale(p: Point, label: str, factor: u32) -> Point"
+    );
+    assert_eq!(signature_help.active_parameter, Some(1));
+    match &signature.documentation {
+        Some(Documentation::MarkupContent(content)) => assert_eq!(
+            content.value.trim(),
+            "Scales a point by `factor`, labelling it with `label`"
+        ),
+        _ => panic!("expected the doc comment of the function"),
+    }
+    signature_help_request
+}
+
+pub(crate) async fn workspace_symbol_request(
+    service: &mut LspService<Backend>,
+    _uri: &Url,
+) -> Request {
+    let params = json!({
+        "query": "test",
+    });
+    let workspace_symbol = build_request_with_id("workspace/symbol", params, 1);
+    let response = call_request(service, workspace_symbol.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let symbols: Vec<SymbolInformation> =
+        serde_json::from_value(response.result().unwrap().clone())
+            .expect("expected a list of symbols");
+    let storage_field = symbols
+        .iter()
+        .find(|symbol| {
+            symbol.name == "test" && symbol.location.uri.path().ends_with("fields/src/main.sw")
+        })
+        .expect("expected the storage field to be found");
+    assert_eq!(storage_field.kind, SymbolKind::FIELD);
+    assert_eq!(storage_field.container_name, Some("storage".to_string()));
+    assert_eq!(
+        storage_field.location.range,
+        Range::new(Position::new(13, 4), Position::new(13, 8))
+    );
+
+    // An empty query doesn't return the declarations of dependencies.
+    let params = json!({
+        "query": "",
+    });
+    let workspace_symbol = build_request_with_id("workspace/symbol", params, 2);
+    let response = call_request(service, workspace_symbol.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let symbols: Vec<SymbolInformation> =
+        serde_json::from_value(response.result().unwrap().clone())
+            .expect("expected a list of symbols");
+    assert!(!symbols.is_empty());
+    assert!(symbols
+        .iter()
+        .all(|symbol| symbol.location.uri.path().contains("/fields/src/")));
+    workspace_symbol
+}
+
+pub(crate) async fn call_hierarchy_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": {
+            "line": 20,
+            "character": 14
+        }
+    });
+    let prepare = build_request_with_id("textDocument/prepareCallHierarchy", params, 1);
+    let response = call_request(service, prepare.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let items: Vec<CallHierarchyItem> = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected a list of call hierarchy items");
+    assert_eq!(items.len(), 1);
+    let bar = &items[0];
+    assert_eq!(bar.name, "bar");
+    assert_eq!(
+        bar.selection_range,
+        Range::new(Position::new(13, 7), Position::new(13, 10))
+    );
+
+    let incoming = build_request_with_id("callHierarchy/incomingCalls", json!({ "item": bar }), 2);
+    let response = call_request(service, incoming).await.unwrap().unwrap();
+    let incoming_calls: Vec<CallHierarchyIncomingCall> =
+        serde_json::from_value(response.result().unwrap().clone())
+            .expect("expected a list of incoming calls");
+    assert_eq!(incoming_calls.len(), 1);
+    let test = &incoming_calls[0].from;
+    assert_eq!(test.name, "test");
+    assert_eq!(
+        incoming_calls[0].from_ranges,
+        vec![Range::new(Position::new(20, 13), Position::new(20, 16))]
+    );
+
+    let outgoing = build_request_with_id("callHierarchy/outgoingCalls", json!({ "item": test }), 3);
+    let response = call_request(service, outgoing).await.unwrap().unwrap();
+    let outgoing_calls: Vec<CallHierarchyOutgoingCall> =
+        serde_json::from_value(response.result().unwrap().clone())
+            .expect("expected a list of outgoing calls");
+    let mut callees: Vec<&str> = outgoing_calls
+        .iter()
+        .map(|call| call.to.name.as_str())
+        .collect();
+    callees.sort();
+    assert_eq!(callees, vec!["bar", "foo"]);
+    prepare
+}
+
+pub(crate) async fn type_hierarchy_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    // From the trait to the traits extending it and the types implementing it.
+    let test1 = prepare_type_hierarchy(service, uri, Position::new(10, 6), "Test1").await;
+    let subtypes = build_request_with_id("typeHierarchy/subtypes", json!({ "item": test1 }), 2);
+    let response = call_request(service, subtypes.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let items: Vec<CallHierarchyItem> = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected a list of type hierarchy items");
+    let mut names: Vec<&str> = items.iter().map(|item| item.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["A", "B", "S"]);
+
+    // From the struct to the trait it implements.
+    let s = prepare_type_hierarchy(service, uri, Position::new(9, 7), "S").await;
+    let supertypes = build_request_with_id("typeHierarchy/supertypes", json!({ "item": s }), 3);
+    let response = call_request(service, supertypes).await.unwrap().unwrap();
+    let items: Vec<CallHierarchyItem> = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected a list of type hierarchy items");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, "Test1");
+    subtypes
+}
+
+async fn prepare_type_hierarchy(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+    position: Position,
+    expected_name: &str,
+) -> CallHierarchyItem {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": position,
+    });
+    let prepare = build_request_with_id("textDocument/prepareTypeHierarchy", params, 1);
+    let response = call_request(service, prepare).await.unwrap().unwrap();
+    let mut items: Vec<CallHierarchyItem> =
+        serde_json::from_value(response.result().unwrap().clone())
+            .expect("expected a list of type hierarchy items");
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].name, expected_name);
+    items.remove(0)
+}
+
+pub(crate) async fn rename_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": {
+            "line": 17,
+            "character": 15
+        },
+        "newName": "Baz"
+    });
+    let rename = build_request_with_id("textDocument/rename", params, 1);
+    let response = call_request(service, rename.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    let edit: WorkspaceEdit = serde_json::from_value(response.result().unwrap().clone())
+        .expect("expected a workspace edit");
+    let changes = edit.changes.expect("expected changes");
+    assert_eq!(changes.len(), 2);
+    for (uri, edits) in changes {
+        assert_eq!(edits.len(), 1, "expected a single edit in {uri}");
+        assert_eq!(edits[0].new_text, "Baz");
+    }
+
+    // Symbols of the standard library can't be renamed.
+    let params = json!({
+        "textDocument": {
+            "uri": uri,
+        },
+        "position": {
+            "line": 5,
+            "character": 8
+        },
+        "newName": "Maybe"
+    });
+    let rename_std = build_request_with_id("textDocument/rename", params, 2);
+    let response = call_request(service, rename_std).await.unwrap().unwrap();
+    let error = response.error().expect("expected an error");
+    assert_eq!(error.code, ErrorCode::InvalidParams);
+    rename
+}
+
 pub(crate) async fn code_lens_request(service: &mut LspService<Backend>, uri: &Url) -> Request {
     let params = json!({
         "textDocument": {
@@ -371,6 +776,246 @@ pub(crate) async fn completion_request(service: &mut LspService<Backend>, uri: &
     completion
 }
 
+/// Requests completion at the given position without a trigger character, as when invoked
+/// manually in the editor.
+async fn invoked_completion_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+    line: u32,
+    character: u32,
+) -> (Request, Vec<serde_json::Value>) {
+    let params = json!({
+        "textDocument": {
+          "uri": uri
+        },
+        "position": {
+          "line": line,
+          "character": character
+        },
+        "context": {
+          "triggerKind": 1
+        }
+    });
+    let completion = build_request_with_id("textDocument/completion", params, 1);
+    let response = call_request(service, completion.clone()).await;
+    (completion, extract_result_array(response))
+}
+
+pub(crate) async fn completion_enum_variants_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let (completion, actual_results) = invoked_completion_request(service, uri, 22, 27).await;
+    let expected_results = vec![
+        json!({
+          "kind": 20,
+          "label": "Red",
+          "labelDetails": {
+            "description": "()"
+          }
+        }),
+        json!({
+          "kind": 20,
+          "label": "Blue",
+          "labelDetails": {
+            "description": "u64"
+          }
+        }),
+    ];
+    assert_json_eq!(actual_results, expected_results);
+    completion
+}
+
+pub(crate) async fn completion_storage_fields_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let (completion, actual_results) = invoked_completion_request(service, uri, 23, 30).await;
+    let expected_results = vec![
+        json!({
+          "kind": 5,
+          "label": "counter",
+          "labelDetails": {
+            "description": "u64"
+          }
+        }),
+        json!({
+          "kind": 5,
+          "label": "owner",
+          "labelDetails": {
+            "description": "b256"
+          }
+        }),
+    ];
+    assert_json_eq!(actual_results, expected_results);
+    completion
+}
+
+pub(crate) async fn completion_use_path_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let (completion, actual_results) = invoked_completion_request(service, uri, 2, 20).await;
+    let expected = json!({
+      "kind": 21,
+      "label": "ZERO_B256",
+      "labelDetails": {
+        "description": "constant"
+      }
+    });
+    assert!(
+        actual_results.contains(&expected),
+        "Expected {actual_results:?} to contain {expected:?}"
+    );
+    completion
+}
+
+pub(crate) async fn completion_items_in_scope_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let (completion, actual_results) = invoked_completion_request(service, uri, 25, 8).await;
+    let expected_results = [
+        json!({
+          "kind": 6,
+          "label": "current",
+          "labelDetails": {
+            "description": "u64"
+          }
+        }),
+        json!({
+          "kind": 6,
+          "label": "amount",
+          "labelDetails": {
+            "description": "u64"
+          }
+        }),
+        json!({
+          "kind": 13,
+          "label": "Color",
+          "labelDetails": {
+            "description": "enum"
+          }
+        }),
+    ];
+    for expected in expected_results.iter() {
+        assert!(
+            actual_results.contains(expected),
+            "Expected {actual_results:?} to contain {expected:?}"
+        );
+    }
+    // Keywords and snippets are offered along with the items in scope.
+    for (label, kind) in [("match", 14), ("fn", 14), ("fn", 15)] {
+        assert!(
+            actual_results
+                .iter()
+                .any(|item| item["label"] == label && item["kind"] == kind),
+            "Expected {actual_results:?} to contain {label} of kind {kind}"
+        );
+    }
+    completion
+}
+
+pub(crate) async fn completion_nested_locals_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let (completion, actual_results) = invoked_completion_request(service, uri, 34, 8).await;
+    let labels: Vec<_> = actual_results
+        .iter()
+        .filter(|item| item["kind"] == 6)
+        .map(|item| item["label"].as_str().unwrap())
+        .collect();
+    // The variables of the enclosing `if` block are in scope, but not those of the `else` block.
+    for expected in ["tripled", "doubled", "factor", "amount"] {
+        assert!(
+            labels.contains(&expected),
+            "Expected {labels:?} to contain {expected}"
+        );
+    }
+    assert!(!labels.contains(&"halved"));
+    completion
+}
+
+pub(crate) async fn manifest_completion_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    // The keys of the table above the empty line.
+    let (_, actual_results) = invoked_completion_request(service, uri, 5, 0).await;
+    let labels: Vec<&str> = actual_results
+        .iter()
+        .filter_map(|item| item["label"].as_str())
+        .collect();
+    assert!(
+        labels.contains(&"forc-version") && !labels.contains(&"path"),
+        "Expected the keys of [project], got {labels:?}"
+    );
+
+    // The keys of the inline table of a dependency.
+    let (completion, actual_results) = invoked_completion_request(service, uri, 7, 8).await;
+    let expected = json!({
+      "documentation": {
+        "kind": "markdown",
+        "value": "The URL of the git repo hosting the dependency."
+      },
+      "insertText": "git = ",
+      "kind": 10,
+      "label": "git"
+    });
+    assert!(
+        actual_results.contains(&expected),
+        "Expected {actual_results:?} to contain {expected:?}"
+    );
+    completion
+}
+
+pub(crate) async fn manifest_hover_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let mut i = 0..;
+    let _ = hover_request(
+        service,
+        &HoverDocumentation {
+            req_uri: uri,
+            req_line: 6,
+            req_char: 3,
+            documentation: "```toml\n[dependencies]\n```\n\n---\n\nDefines the dependencies of the project. Each dependency is declared with a `path`, or a `git` repository along with an optional `branch`, `tag` or `rev`.",
+        },
+        &mut i,
+    )
+    .await;
+    hover_request(
+        service,
+        &HoverDocumentation {
+            req_uri: uri,
+            req_line: 2,
+            req_char: 2,
+            documentation: "```toml\nentry\n```\n\n---\n\nThe entry point for the compiler to start parsing from, relative to the `src` directory. Defaults to `main.sw`.",
+        },
+        &mut i,
+    )
+    .await
+}
+
+pub(crate) async fn manifest_definition_request(
+    service: &mut LspService<Backend>,
+    uri: &Url,
+) -> Request {
+    let go_to = GotoDefinition {
+        req_uri: uri,
+        req_line: 7,
+        req_char: 1,
+        def_line: 0,
+        def_start_char: 0,
+        def_end_char: 0,
+        def_path: "sway-lib-std/Forc.toml",
+    };
+    let mut i = 0..;
+    definition_check(service, &go_to, &mut i).await
+}
+
 pub(crate) async fn definition_check<'a>(
     service: &mut LspService<Backend>,
     go_to: &'a GotoDefinition<'a>,
@@ -1,8 +1,8 @@
 pub mod integration;
 
 use crate::integration::{code_actions, lsp};
-use std::{fs, path::PathBuf};
-use sway_lsp::server::Backend;
+use std::{fs, path::PathBuf, time::Duration};
+use sway_lsp::server::{self, Backend};
 use sway_lsp_test_utils::{
     assert_server_requests, dir_contains_forc_manifest, doc_comments_dir, e2e_language_dir,
     e2e_test_dir, generic_impl_self_dir, get_fixture, load_sway_example, runnables_test_dir,
@@ -167,6 +167,17 @@ async fn did_change() {
     shutdown_and_exit(&mut service).await;
 }
 
+#[tokio::test]
+async fn did_change_does_not_write_to_disk() {
+    let (mut service, _) = LspService::new(Backend::new);
+    let entry_point = doc_comments_dir().join("src/main.sw");
+    let contents = fs::read_to_string(&entry_point).unwrap();
+    let uri = init_and_open(&mut service, entry_point.clone()).await;
+    let _ = lsp::did_change_request(&mut service, &uri).await;
+    assert_eq!(fs::read_to_string(&entry_point).unwrap(), contents);
+    shutdown_and_exit(&mut service).await;
+}
+
 #[tokio::test]
 async fn lsp_syncs_with_workspace_edits() {
     let (mut service, _) = LspService::new(Backend::new);
@@ -188,6 +199,28 @@ async fn lsp_syncs_with_workspace_edits() {
     shutdown_and_exit(&mut service).await;
 }
 
+#[tokio::test]
+async fn lsp_syncs_with_consecutive_workspace_edits() {
+    let (mut service, _) = LspService::new(Backend::new);
+    let uri = init_and_open(&mut service, doc_comments_dir().join("src/main.sw")).await;
+    let mut i = 0..;
+    let mut go_to = GotoDefinition {
+        req_uri: &uri,
+        req_line: 44,
+        req_char: 24,
+        def_line: 19,
+        def_start_char: 7,
+        def_end_char: 11,
+        def_path: uri.as_str(),
+    };
+    // The dependencies checked by the first edit are reused by the second one.
+    let _ = lsp::did_change_request(&mut service, &uri).await;
+    let _ = lsp::did_change_request(&mut service, &uri).await;
+    go_to.def_line = 21;
+    definition_check_with_req_offset(&mut service, &mut go_to, 46, 24, &mut i).await;
+    shutdown_and_exit(&mut service).await;
+}
+
 #[tokio::test]
 async fn show_ast() {
     let (mut service, _) = LspService::build(Backend::new)
@@ -199,6 +232,38 @@ async fn show_ast() {
     shutdown_and_exit(&mut service).await;
 }
 
+#[tokio::test]
+async fn type_hierarchy() {
+    let (mut service, _) = LspService::build(Backend::new)
+        .custom_method(
+            "textDocument/prepareTypeHierarchy",
+            Backend::prepare_type_hierarchy,
+        )
+        .custom_method("typeHierarchy/supertypes", Backend::supertypes)
+        .custom_method("typeHierarchy/subtypes", Backend::subtypes)
+        .finish();
+
+    let uri = init_and_open(
+        &mut service,
+        test_fixtures_dir().join("tokens/traits/src/main.sw"),
+    )
+    .await;
+    let _ = lsp::type_hierarchy_request(&mut service, &uri).await;
+    shutdown_and_exit(&mut service).await;
+}
+
+#[test]
+fn type_hierarchy_provider() {
+    let initialize_result = serde_json::json!({ "capabilities": server::capabilities() });
+    let response =
+        server::with_type_hierarchy_provider(Some(Response::from_ok(1.into(), initialize_result)))
+            .unwrap();
+    assert_eq!(
+        response.result().unwrap()["capabilities"]["typeHierarchyProvider"],
+        true
+    );
+}
+
 //------------------- GO TO DEFINITION -------------------//
 
 #[tokio::test]
@@ -841,7 +906,7 @@ async fn go_to_definition_for_paths() {
         req_uri: &uri,
         req_line: 13,
         req_char: 17,
-        def_line: 74,
+        def_line: 98,
         def_start_char: 5,
         def_end_char: 9,
         def_path: "sway-lib-core/src/primitives.sw",
@@ -1587,7 +1652,9 @@ async fn publish_diagnostics_dead_code_warning() {
     let (mut service, socket) = LspService::new(Backend::new);
     let fixture = get_fixture(test_fixtures_dir().join("diagnostics/dead_code/expected.json"));
     let expected_requests = vec![fixture];
-    let socket_handle = assert_server_requests(socket, expected_requests, None).await;
+    // The project is compiled off the runtime of the test, so the timeout includes compiling it.
+    let socket_handle =
+        assert_server_requests(socket, expected_requests, Some(Duration::from_secs(60))).await;
     let _ = init_and_open(
         &mut service,
         test_fixtures_dir().join("diagnostics/dead_code/src/main.sw"),
@@ -1643,6 +1710,51 @@ lsp_capability_test!(
     lsp::highlight_request,
     doc_comments_dir().join("src/main.sw")
 );
+lsp_capability_test!(
+    references,
+    lsp::references_request,
+    test_fixtures_dir().join("tokens/fields/src/main.sw")
+);
+lsp_capability_test!(
+    type_definition,
+    lsp::type_definition_request,
+    test_fixtures_dir().join("tokens/functions/src/main.sw")
+);
+lsp_capability_test!(
+    implementation,
+    lsp::implementation_request,
+    test_fixtures_dir().join("tokens/abi/src/main.sw")
+);
+lsp_capability_test!(
+    implementation_of_trait,
+    lsp::implementation_of_trait_request,
+    test_fixtures_dir().join("tokens/traits/src/main.sw")
+);
+lsp_capability_test!(
+    signature_help,
+    lsp::signature_help_request,
+    test_fixtures_dir().join("tokens/functions/src/main.sw")
+);
+lsp_capability_test!(
+    signature_help_past_strings_and_comments,
+    lsp::signature_help_past_strings_and_comments_request,
+    test_fixtures_dir().join("tokens/functions/src/main.sw")
+);
+lsp_capability_test!(
+    call_hierarchy,
+    lsp::call_hierarchy_request,
+    test_fixtures_dir().join("tokens/functions/src/main.sw")
+);
+lsp_capability_test!(
+    workspace_symbol,
+    lsp::workspace_symbol_request,
+    test_fixtures_dir().join("tokens/fields/src/main.sw")
+);
+lsp_capability_test!(
+    rename,
+    lsp::rename_request,
+    test_fixtures_dir().join("tokens/fields/src/main.sw")
+);
 lsp_capability_test!(
     code_action_abi,
     code_actions::code_action_abi_request,
@@ -1663,6 +1775,26 @@ lsp_capability_test!(
     code_actions::code_action_struct_existing_impl_request,
     self_impl_reassignment_dir().join("src/main.sw")
 );
+lsp_capability_test!(
+    code_action_missing_use,
+    code_actions::code_action_missing_use_request,
+    test_fixtures_dir().join("code_actions/quick_fixes/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_missing_methods,
+    code_actions::code_action_missing_methods_request,
+    test_fixtures_dir().join("code_actions/quick_fixes/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_naming_convention,
+    code_actions::code_action_naming_convention_request,
+    test_fixtures_dir().join("code_actions/quick_fixes/src/main.sw")
+);
+lsp_capability_test!(
+    code_action_allow_dead_code,
+    code_actions::code_action_allow_dead_code_request,
+    test_fixtures_dir().join("diagnostics/dead_code/src/main.sw")
+);
 lsp_capability_test!(
     code_lens,
     lsp::code_lens_request,
@@ -1673,3 +1805,43 @@ lsp_capability_test!(
     lsp::completion_request,
     test_fixtures_dir().join("completion/src/main.sw")
 );
+lsp_capability_test!(
+    completion_enum_variants,
+    lsp::completion_enum_variants_request,
+    test_fixtures_dir().join("completion_context/src/main.sw")
+);
+lsp_capability_test!(
+    completion_storage_fields,
+    lsp::completion_storage_fields_request,
+    test_fixtures_dir().join("completion_context/src/main.sw")
+);
+lsp_capability_test!(
+    completion_use_path,
+    lsp::completion_use_path_request,
+    test_fixtures_dir().join("completion_context/src/main.sw")
+);
+lsp_capability_test!(
+    completion_items_in_scope,
+    lsp::completion_items_in_scope_request,
+    test_fixtures_dir().join("completion_context/src/main.sw")
+);
+lsp_capability_test!(
+    completion_nested_locals,
+    lsp::completion_nested_locals_request,
+    test_fixtures_dir().join("completion_context/src/main.sw")
+);
+lsp_capability_test!(
+    manifest_completion,
+    lsp::manifest_completion_request,
+    test_fixtures_dir().join("manifest/Forc.toml")
+);
+lsp_capability_test!(
+    manifest_hover,
+    lsp::manifest_hover_request,
+    test_fixtures_dir().join("manifest/Forc.toml")
+);
+lsp_capability_test!(
+    manifest_definition,
+    lsp::manifest_definition_request,
+    test_fixtures_dir().join("manifest/Forc.toml")
+);
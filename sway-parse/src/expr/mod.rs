@@ -5,16 +5,16 @@ use sway_ast::expr::{LoopControlFlow, ReassignmentOp, ReassignmentOpVariant};
 use sway_ast::keywords::{
     AbiToken, AddEqToken, AsmToken, CommaToken, ConfigurableToken, ConstToken, DivEqToken,
     DoubleColonToken, EnumToken, EqToken, FalseToken, FnToken, IfToken, ImplToken, LetToken,
-    OpenAngleBracketToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken, StarEqToken,
-    StorageToken, StructToken, SubEqToken, Token, TraitToken, TrueToken, UseToken,
+    OpenAngleBracketToken, PipeToken, PubToken, SemicolonToken, ShlEqToken, ShrEqToken,
+    StarEqToken, StorageToken, StructToken, SubEqToken, Token, TraitToken, TrueToken, UseToken,
 };
 use sway_ast::literal::{LitBool, LitBoolType};
 use sway_ast::punctuated::Punctuated;
 use sway_ast::token::Delimiter;
 use sway_ast::{
-    AbiCastArgs, CodeBlockContents, Expr, ExprArrayDescriptor, ExprStructField,
-    ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal, MatchBranch,
-    MatchBranchKind, PathExpr, PathExprSegment, Statement, StatementLet,
+    AbiCastArgs, ClosureParam, ClosureParams, CodeBlockContents, Expr, ExprArrayDescriptor,
+    ExprStructField, ExprTupleDescriptor, GenericArgs, IfCondition, IfExpr, LitInt, Literal,
+    MatchBranch, MatchBranchKind, PathExpr, PathExprSegment, Statement, StatementLet,
 };
 use sway_error::parser_error::ParseErrorKind;
 use sway_types::{Ident, Span, Spanned};
@@ -616,6 +616,10 @@ fn parse_func_app(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
 }
 
 fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
+    if let Some(params) = parse_closure_params(parser)? {
+        let body = parser.parse()?;
+        return Ok(Expr::Closure { params, body });
+    }
     if let Some(code_block_inner) = Braces::try_parse(parser)? {
         return Ok(Expr::Block(code_block_inner));
     }
@@ -694,7 +698,7 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
         });
     }
     if let Some(while_token) = parser.take() {
-        let condition = Box::new(parse_condition(parser)?);
+        let condition = parser.parse()?;
         let block = parser.parse()?;
         return Ok(Expr::While {
             while_token,
@@ -702,6 +706,19 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
             block,
         });
     }
+    if let Some(for_token) = parser.take() {
+        let value_pattern = parser.parse()?;
+        let in_token = parser.parse()?;
+        let iterator = Box::new(parse_condition(parser)?);
+        let block = parser.parse()?;
+        return Ok(Expr::For {
+            for_token,
+            value_pattern,
+            in_token,
+            iterator,
+            block,
+        });
+    }
     if parser.peek::<OpenAngleBracketToken>().is_some()
         || parser.peek::<DoubleColonToken>().is_some()
         || parser.peek::<Ident>().is_some()
@@ -726,6 +743,48 @@ fn parse_atom(parser: &mut Parser, ctx: ParseExprCtx) -> ParseResult<Expr> {
     Err(parser.emit_error(ParseErrorKind::ExpectedExpression))
 }
 
+/// Parses the `|a, b: u64|` (or `||`) that starts a closure, if there is one.
+fn parse_closure_params(parser: &mut Parser) -> ParseResult<Option<ClosureParams>> {
+    if let Some(double_pipe_token) = parser.take() {
+        return Ok(Some(ClosureParams::Empty(double_pipe_token)));
+    }
+    let open_pipe_token = match parser.take() {
+        Some(open_pipe_token) => open_pipe_token,
+        None => return Ok(None),
+    };
+    let mut value_separator_pairs = Vec::new();
+    let final_value_opt = loop {
+        if parser.peek::<PipeToken>().is_some() {
+            break None;
+        }
+        let param: ClosureParam = parser.parse()?;
+        match parser.take() {
+            Some(comma_token) => value_separator_pairs.push((param, comma_token)),
+            None => break Some(Box::new(param)),
+        }
+    };
+    let close_pipe_token = parser.parse()?;
+    Ok(Some(ClosureParams::Params {
+        open_pipe_token,
+        params: Punctuated {
+            value_separator_pairs,
+            final_value_opt,
+        },
+        close_pipe_token,
+    }))
+}
+
+impl Parse for ClosureParam {
+    fn parse(parser: &mut Parser) -> ParseResult<ClosureParam> {
+        let name = parser.parse()?;
+        let ty_opt = match parser.take() {
+            Some(colon_token) => Some((colon_token, parser.parse()?)),
+            None => None,
+        };
+        Ok(ClosureParam { name, ty_opt })
+    }
+}
+
 impl Parse for ExprStructField {
     fn parse(parser: &mut Parser) -> ParseResult<ExprStructField> {
         let field_name = parser.parse()?;
@@ -1,8 +1,8 @@
 use crate::{Parse, ParseResult, Parser};
 
-use sway_ast::keywords::CommaToken;
+use sway_ast::keywords::{CommaToken, ConstToken};
 use sway_ast::punctuated::Punctuated;
-use sway_ast::{AngleBrackets, GenericArgs, GenericParams};
+use sway_ast::{AngleBrackets, GenericArgs, GenericParam, GenericParams};
 
 impl Parse for GenericParams {
     fn parse(parser: &mut Parser) -> ParseResult<GenericParams> {
@@ -10,6 +10,20 @@ impl Parse for GenericParams {
     }
 }
 
+impl Parse for GenericParam {
+    fn parse(parser: &mut Parser) -> ParseResult<GenericParam> {
+        match parser.take::<ConstToken>() {
+            Some(const_token) => Ok(GenericParam::Const {
+                const_token,
+                name: parser.parse()?,
+                colon_token: parser.parse()?,
+                ty: Box::new(parser.parse()?),
+            }),
+            None => Ok(GenericParam::Type(parser.parse()?)),
+        }
+    }
+}
+
 impl Parse for GenericArgs {
     fn parse(parser: &mut Parser) -> ParseResult<GenericArgs> {
         parse_angle_comma(parser).map(|parameters| GenericArgs { parameters })
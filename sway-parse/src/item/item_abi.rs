@@ -1,12 +1,14 @@
 use crate::{Parse, ParseBracket, ParseResult, Parser};
 
 use sway_ast::attribute::Annotated;
+use sway_ast::keywords::OpenAngleBracketToken;
 use sway_ast::{Braces, ItemAbi, ItemFn, ItemTraitItem};
 
 impl Parse for ItemAbi {
     fn parse(parser: &mut Parser) -> ParseResult<ItemAbi> {
         let abi_token = parser.parse()?;
         let name = parser.parse()?;
+        let generics = parser.guarded_parse::<OpenAngleBracketToken, _>()?;
         let super_traits = match parser.take() {
             Some(colon_token) => {
                 let traits = parser.parse()?;
@@ -16,9 +18,14 @@ impl Parse for ItemAbi {
         };
         let abi_items: Braces<Vec<(Annotated<ItemTraitItem>, _)>> = parser.parse()?;
         for (annotated, _) in abi_items.get().iter() {
-            #[allow(irrefutable_let_patterns)]
-            if let ItemTraitItem::Fn(fn_signature) = &annotated.value {
-                parser.ban_visibility_qualifier(&fn_signature.visibility)?;
+            match &annotated.value {
+                ItemTraitItem::Fn(fn_signature) => {
+                    parser.ban_visibility_qualifier(&fn_signature.visibility)?;
+                }
+                ItemTraitItem::Const(const_decl) => {
+                    parser.ban_visibility_qualifier(&const_decl.visibility)?;
+                }
+                ItemTraitItem::Type(_) => {}
             }
         }
         let abi_defs_opt: Option<Braces<Vec<Annotated<ItemFn>>>> = Braces::try_parse(parser)?;
@@ -30,6 +37,7 @@ impl Parse for ItemAbi {
         Ok(ItemAbi {
             abi_token,
             name,
+            generics,
             super_traits,
             abi_items,
             abi_defs_opt,
@@ -1,8 +1,11 @@
-use crate::{Parse, ParseResult, Parser};
+use crate::{Parse, ParseBracket, ParseResult, Parser};
 
 use sway_ast::attribute::Annotated;
-use sway_ast::keywords::{FnToken, OpenAngleBracketToken, WhereToken};
-use sway_ast::{Braces, ItemImpl, ItemImplItem, PubToken, Ty};
+use sway_ast::brackets::SquareBrackets;
+use sway_ast::keywords::{
+    ConstToken, FnToken, OpenAngleBracketToken, SemicolonToken, StrToken, TypeToken, WhereToken,
+};
+use sway_ast::{Braces, ItemConst, ItemImpl, ItemImplItem, PubToken, TraitType, Ty};
 use sway_error::parser_error::ParseErrorKind;
 
 impl Parse for ItemImplItem {
@@ -10,6 +13,12 @@ impl Parse for ItemImplItem {
         if parser.peek::<PubToken>().is_some() || parser.peek::<FnToken>().is_some() {
             let fn_decl = parser.parse()?;
             Ok(ItemImplItem::Fn(fn_decl))
+        } else if let Some(const_decl) = parser.guarded_parse::<ConstToken, ItemConst>()? {
+            parser.take::<SemicolonToken>();
+            Ok(ItemImplItem::Const(const_decl))
+        } else if let Some(trait_type) = parser.guarded_parse::<TypeToken, TraitType>()? {
+            parser.take::<SemicolonToken>();
+            Ok(ItemImplItem::Type(trait_type))
         } else {
             Err(parser.emit_error(ParseErrorKind::ExpectedAnItem))
         }
@@ -20,18 +29,30 @@ impl Parse for ItemImpl {
     fn parse(parser: &mut Parser) -> ParseResult<ItemImpl> {
         let impl_token = parser.parse()?;
         let generic_params_opt = parser.guarded_parse::<OpenAngleBracketToken, _>()?;
-        let path_type = parser.parse()?;
-        let (trait_opt, ty) = match parser.take() {
-            Some(for_token) => (Some((path_type, for_token)), parser.parse()?),
-            None => (None, Ty::Path(path_type)),
+        // The `str` and array types aren't paths, so they can't be the name of a trait either.
+        let (trait_opt, ty) = if parser.peek::<StrToken>().is_some() {
+            (None, parser.parse()?)
+        } else if let Some(descriptor) = SquareBrackets::try_parse(parser)? {
+            (None, Ty::Array(descriptor))
+        } else {
+            let path_type = parser.parse()?;
+            match parser.take() {
+                Some(for_token) => (Some((path_type, for_token)), parser.parse()?),
+                None => (None, Ty::Path(path_type)),
+            }
         };
         let where_clause_opt = parser.guarded_parse::<WhereToken, _>()?;
         let contents: Braces<Vec<Annotated<ItemImplItem>>> = parser.parse()?;
         if trait_opt.is_some() {
             for annotated in contents.get().iter() {
-                #[allow(irrefutable_let_patterns)]
-                if let ItemImplItem::Fn(item_fn) = &annotated.value {
-                    parser.ban_visibility_qualifier(&item_fn.fn_signature.visibility)?;
+                match &annotated.value {
+                    ItemImplItem::Fn(item_fn) => {
+                        parser.ban_visibility_qualifier(&item_fn.fn_signature.visibility)?;
+                    }
+                    ItemImplItem::Const(const_decl) => {
+                        parser.ban_visibility_qualifier(&const_decl.visibility)?;
+                    }
+                    ItemImplItem::Type(_) => {}
                 }
             }
         }
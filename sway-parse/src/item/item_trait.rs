@@ -1,8 +1,8 @@
 use crate::{Parse, ParseBracket, ParseResult, Parser};
 
 use sway_ast::attribute::Annotated;
-use sway_ast::keywords::{FnToken, OpenAngleBracketToken, WhereToken};
-use sway_ast::{Braces, ItemFn, ItemTrait, ItemTraitItem, PubToken, Traits};
+use sway_ast::keywords::{ConstToken, FnToken, OpenAngleBracketToken, TypeToken, WhereToken};
+use sway_ast::{Braces, ItemConst, ItemFn, ItemTrait, ItemTraitItem, PubToken, TraitType, Traits};
 use sway_error::parser_error::ParseErrorKind;
 
 impl Parse for ItemTraitItem {
@@ -10,12 +10,38 @@ impl Parse for ItemTraitItem {
         if parser.peek::<PubToken>().is_some() || parser.peek::<FnToken>().is_some() {
             let fn_decl = parser.parse()?;
             Ok(ItemTraitItem::Fn(fn_decl))
+        } else if let Some(const_decl) = parser.guarded_parse::<ConstToken, ItemConst>()? {
+            Ok(ItemTraitItem::Const(const_decl))
+        } else if let Some(trait_type) = parser.guarded_parse::<TypeToken, TraitType>()? {
+            Ok(ItemTraitItem::Type(trait_type))
         } else {
             Err(parser.emit_error(ParseErrorKind::ExpectedAnItem))
         }
     }
 }
 
+impl Parse for TraitType {
+    fn parse(parser: &mut Parser) -> ParseResult<TraitType> {
+        let type_token = parser.parse()?;
+        let name = parser.parse()?;
+        let eq_token_opt = parser.take();
+        let ty_opt = match &eq_token_opt {
+            Some(_eq) => Some(parser.parse()?),
+            None => None,
+        };
+        // Like associated consts, the semicolon is consumed by the enclosing
+        // item list, so we only peek at it here.
+        let semicolon_token = parser.peek().unwrap_or_default();
+        Ok(TraitType {
+            type_token,
+            name,
+            eq_token_opt,
+            ty_opt,
+            semicolon_token,
+        })
+    }
+}
+
 impl Parse for ItemTrait {
     fn parse(parser: &mut Parser) -> ParseResult<ItemTrait> {
         let visibility = parser.take();
@@ -33,9 +59,14 @@ impl Parse for ItemTrait {
 
         let trait_items: Braces<Vec<(Annotated<ItemTraitItem>, _)>> = parser.parse()?;
         for (annotated, _) in trait_items.get().iter() {
-            #[allow(irrefutable_let_patterns)]
-            if let ItemTraitItem::Fn(fn_sig) = &annotated.value {
-                parser.ban_visibility_qualifier(&fn_sig.visibility)?;
+            match &annotated.value {
+                ItemTraitItem::Fn(fn_sig) => {
+                    parser.ban_visibility_qualifier(&fn_sig.visibility)?;
+                }
+                ItemTraitItem::Const(const_decl) => {
+                    parser.ban_visibility_qualifier(&const_decl.visibility)?;
+                }
+                ItemTraitItem::Type(_) => {}
             }
         }
 
@@ -0,0 +1,25 @@
+use crate::{Parse, ParseResult, Parser};
+
+use sway_ast::keywords::OpenAngleBracketToken;
+use sway_ast::ItemTypeAlias;
+
+impl Parse for ItemTypeAlias {
+    fn parse(parser: &mut Parser) -> ParseResult<ItemTypeAlias> {
+        let visibility = parser.take();
+        let type_token = parser.parse()?;
+        let name = parser.parse()?;
+        let generics = parser.guarded_parse::<OpenAngleBracketToken, _>()?;
+        let eq_token = parser.parse()?;
+        let ty = parser.parse()?;
+        let semicolon_token = parser.parse()?;
+        Ok(ItemTypeAlias {
+            visibility,
+            type_token,
+            name,
+            generics,
+            eq_token,
+            ty,
+            semicolon_token,
+        })
+    }
+}
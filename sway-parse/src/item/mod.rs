@@ -3,11 +3,11 @@ use crate::{Parse, ParseResult, ParseToEnd, Parser, ParserConsumed};
 use sway_ast::keywords::{
     AbiToken, ClassToken, ConfigurableToken, ConstToken, EnumToken, FnToken, ImplToken, ModToken,
     MutToken, OpenAngleBracketToken, RefToken, SelfToken, SemicolonToken, StorageToken,
-    StructToken, TraitToken, UseToken, WhereToken,
+    StructToken, TraitToken, TypeToken, UseToken, WhereToken,
 };
 use sway_ast::{
     FnArg, FnArgs, FnSignature, ItemConst, ItemEnum, ItemFn, ItemKind, ItemStruct, ItemTrait,
-    ItemUse, Submodule, TypeField,
+    ItemTypeAlias, ItemUse, Submodule, TypeField,
 };
 use sway_error::parser_error::ParseErrorKind;
 
@@ -20,6 +20,7 @@ mod item_impl;
 mod item_storage;
 mod item_struct;
 mod item_trait;
+mod item_type_alias;
 mod item_use;
 
 impl Parse for ItemKind {
@@ -62,6 +63,9 @@ impl Parse for ItemKind {
             ItemKind::Storage(item)
         } else if let Some(item) = parser.guarded_parse::<ConfigurableToken, _>()? {
             ItemKind::Configurable(item)
+        } else if let Some(mut item) = parser.guarded_parse::<TypeToken, ItemTypeAlias>()? {
+            item.visibility = visibility.take();
+            ItemKind::TypeAlias(item)
         } else {
             return Err(parser.emit_error(ParseErrorKind::ExpectedAnItem));
         };
@@ -168,7 +172,10 @@ impl Parse for FnSignature {
 mod tests {
     use super::*;
     use crate::test_utils::parse;
-    use sway_ast::{AttributeDecl, Item, ItemTraitItem};
+    use sway_ast::{
+        AttributeDecl, Expr, FnArgs, GenericParam, Item, ItemImplItem, ItemTraitItem, Statement,
+        StatementLet, Ty,
+    };
 
     // Attribute name and its list of parameters
     type ParameterizedAttr<'a> = (&'a str, Option<Vec<&'a str>>);
@@ -531,6 +538,206 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_trait_associated_items() {
+        let item = parse::<Item>(
+            r#"
+            trait T {
+                type Item;
+                const ID: u64;
+                const DEFAULT: u64 = 5;
+                fn f(self) -> Self::Item;
+            }
+            "#,
+        );
+
+        let item_trait = match item.value {
+            ItemKind::Trait(item_trait) => item_trait,
+            _ => panic!("Parsed trait is not a trait."),
+        };
+        let items = item_trait
+            .trait_items
+            .get()
+            .iter()
+            .map(|(annotated, _)| &annotated.value)
+            .collect::<Vec<_>>();
+        assert_eq!(items.len(), 4);
+        assert!(
+            matches!(items[0], ItemTraitItem::Type(trait_type) if trait_type.name.as_str() == "Item" && trait_type.ty_opt.is_none())
+        );
+        assert!(
+            matches!(items[1], ItemTraitItem::Const(item_const) if item_const.name.as_str() == "ID" && item_const.expr_opt.is_none())
+        );
+        assert!(
+            matches!(items[2], ItemTraitItem::Const(item_const) if item_const.name.as_str() == "DEFAULT" && item_const.expr_opt.is_some())
+        );
+        assert!(matches!(items[3], ItemTraitItem::Fn(_)));
+    }
+
+    #[test]
+    fn parse_impl_associated_items() {
+        let item = parse::<Item>(
+            r#"
+            impl T for S {
+                type Item = u64;
+                const ID: u64 = 1;
+                fn f(self) -> Self::Item {
+                    Self::ID
+                }
+            }
+            "#,
+        );
+
+        let item_impl = match item.value {
+            ItemKind::Impl(item_impl) => item_impl,
+            _ => panic!("Parsed impl is not an impl."),
+        };
+        let items = item_impl
+            .contents
+            .get()
+            .iter()
+            .map(|annotated| &annotated.value)
+            .collect::<Vec<_>>();
+        assert_eq!(items.len(), 3);
+        assert!(matches!(items[0], ItemImplItem::Type(trait_type) if trait_type.ty_opt.is_some()));
+        assert!(
+            matches!(items[1], ItemImplItem::Const(item_const) if item_const.expr_opt.is_some())
+        );
+        assert!(matches!(items[2], ItemImplItem::Fn(_)));
+    }
+
+    #[test]
+    fn parse_impl_for_array() {
+        let item = parse::<Item>(
+            r#"
+            impl<T, const N: u64> [T; N] {
+                fn len(self) -> u64 {
+                    N
+                }
+            }
+            "#,
+        );
+
+        let item_impl = match item.value {
+            ItemKind::Impl(item_impl) => item_impl,
+            _ => panic!("Parsed impl is not an impl."),
+        };
+        assert!(item_impl.trait_opt.is_none());
+        assert!(matches!(item_impl.ty, Ty::Array(_)));
+    }
+
+    #[test]
+    fn parse_type_alias() {
+        let item = parse::<Item>(
+            r#"
+            pub type Pair<T> = (T, T);
+            "#,
+        );
+
+        let item_type_alias = match item.value {
+            ItemKind::TypeAlias(item_type_alias) => item_type_alias,
+            _ => panic!("Parsed type alias is not a type alias."),
+        };
+        assert!(item_type_alias.visibility.is_some());
+        assert_eq!(item_type_alias.name.as_str(), "Pair");
+        assert!(item_type_alias.generics.is_some());
+        assert!(matches!(item_type_alias.ty, Ty::Tuple(_)));
+    }
+
+    #[test]
+    fn parse_closures() {
+        let item = parse::<Item>(
+            r#"
+            fn apply_twice(f: fn(u64) -> u64, x: u64) -> u64 {
+                let g = |y: u64, z| f(y) + z;
+                g(f(x), 1)
+            }
+            "#,
+        );
+
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed function is not a function."),
+        };
+        let args = match item_fn.fn_signature.arguments.into_inner() {
+            FnArgs::Static(args) => args.into_iter().collect::<Vec<_>>(),
+            _ => panic!("Expected static function arguments."),
+        };
+        assert!(matches!(
+            args[0].ty,
+            Ty::Fn {
+                return_type_opt: Some(_),
+                ..
+            }
+        ));
+
+        let contents = item_fn.body.into_inner();
+        let params = match &contents.statements[0] {
+            Statement::Let(StatementLet {
+                expr: Expr::Closure { params, .. },
+                ..
+            }) => params.clone().into_params(),
+            _ => panic!("Expected a closure bound by a `let` statement."),
+        };
+        assert_eq!(params.len(), 2);
+        assert!(params[0].ty_opt.is_some());
+        assert!(params[1].ty_opt.is_none());
+    }
+
+    #[test]
+    fn parse_const_generics() {
+        let item = parse::<Item>(
+            r#"
+            fn sum<T, const N: u64>(values: [T; N]) -> u64 {
+                N
+            }
+            "#,
+        );
+
+        let item_fn = match item.value {
+            ItemKind::Fn(item_fn) => item_fn,
+            _ => panic!("Parsed function is not a function."),
+        };
+        let params = item_fn
+            .fn_signature
+            .generics
+            .expect("Expected generic parameters.")
+            .parameters
+            .into_inner()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(params.len(), 2);
+        assert!(matches!(&params[0], GenericParam::Type(name) if name.as_str() == "T"));
+        assert!(
+            matches!(&params[1], GenericParam::Const { name, .. } if name.as_str() == "N")
+        );
+    }
+
+    #[test]
+    fn parse_generic_abi() {
+        let item = parse::<Item>(
+            r#"
+            abi Token<M> {
+                fn metadata() -> M;
+            }
+            "#,
+        );
+
+        let item_abi = match item.value {
+            ItemKind::Abi(item_abi) => item_abi,
+            _ => panic!("Parsed ABI is not an ABI."),
+        };
+        let params = item_abi
+            .generics
+            .expect("Expected generic parameters.")
+            .parameters
+            .into_inner()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(params.len(), 1);
+        assert!(matches!(&params[0], GenericParam::Type(name) if name.as_str() == "M"));
+    }
+
     #[test]
     fn parse_attributes_abi() {
         let item = parse::<Item>(
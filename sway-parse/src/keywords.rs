@@ -70,7 +70,10 @@ keyword_impls! {
     FalseToken,
     BreakToken,
     ContinueToken,
-    ConfigurableToken
+    ConfigurableToken,
+    TypeToken,
+    InToken,
+    DynToken
 }
 
 fn peek_token<T: Token>(peeker: Peeker<'_>) -> Option<T> {
@@ -188,4 +191,7 @@ pub const RESERVED_KEYWORDS: phf::Set<&'static str> = phf::phf_set! {
     "break",
     "continue",
     "configurable",
+    "type",
+    "in",
+    "dyn",
 };
@@ -35,14 +35,36 @@ impl Parse for Ty {
             return Ok(Ty::Array(descriptor));
         };
         if let Some(str_token) = parser.take() {
-            let length = SquareBrackets::parse_all_inner(parser, |mut parser| {
+            let length = SquareBrackets::try_parse_all_inner(parser, |mut parser| {
                 parser.emit_error(ParseErrorKind::UnexpectedTokenAfterStrLength)
             })?;
-            return Ok(Ty::Str { str_token, length });
+            return Ok(match length {
+                Some(length) => Ty::Str { str_token, length },
+                None => Ty::StringSlice(str_token),
+            });
         }
         if let Some(underscore_token) = parser.take() {
             return Ok(Ty::Infer { underscore_token });
         }
+        if let Some(fn_token) = parser.take() {
+            let parameters = parser.parse()?;
+            let return_type_opt = match parser.take() {
+                Some(right_arrow_token) => Some((right_arrow_token, parser.parse()?)),
+                None => None,
+            };
+            return Ok(Ty::Fn {
+                fn_token,
+                parameters,
+                return_type_opt,
+            });
+        }
+        if let Some(dyn_token) = parser.take() {
+            let trait_path = parser.parse()?;
+            return Ok(Ty::TraitObject {
+                dyn_token,
+                trait_path,
+            });
+        }
         if parser.peek::<OpenAngleBracketToken>().is_some()
             || parser.peek::<DoubleColonToken>().is_some()
             || parser.peek::<Ident>().is_some()
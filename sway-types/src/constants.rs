@@ -27,6 +27,16 @@ pub const DESTRUCTURE_PREFIX: &str = "__destructure_";
 /// The default prefix for the compiler generated names of match
 pub const MATCH_RETURN_VAR_NAME_PREFIX: &str = "__match_return_var_name_";
 
+/// The default prefix for the compiler generated names of the variables used by `for` loops
+pub const FOR_LOOP_VAR_NAME_PREFIX: &str = "__for_loop_";
+
+/// The default prefix for the compiler generated names of the values bound by refutable-looking
+/// patterns in `let` statements
+pub const PATTERN_BINDINGS_VAR_NAME_PREFIX: &str = "__pattern_bindings_";
+
+/// The default prefix for the compiler generated names of destructured function parameters
+pub const FN_ARG_NAME_PREFIX: &str = "__fn_arg_";
+
 /// The valid attribute strings related to storage and purity.
 pub const STORAGE_PURITY_ATTRIBUTE_NAME: &str = "storage";
 pub const STORAGE_PURITY_READ_NAME: &str = "read";
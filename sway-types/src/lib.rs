@@ -20,6 +20,8 @@ pub mod state;
 
 pub mod style;
 
+pub mod u256;
+
 pub type Id = [u8; Bytes32::LEN];
 pub type Contract = [u8; ContractId::LEN];
 
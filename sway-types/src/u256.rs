@@ -0,0 +1,147 @@
+//! A 256-bit unsigned integer, the value of `u256` literals and constants.
+
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct U256(BigUint);
+
+impl U256 {
+    pub const BYTES: usize = 32;
+    pub const BITS: u64 = 256;
+
+    /// The largest value of a `u256`.
+    pub fn max_value() -> U256 {
+        U256(BigUint::from_bytes_be(&[0xFF; U256::BYTES]))
+    }
+
+    pub fn from_be_bytes(bytes: &[u8; U256::BYTES]) -> U256 {
+        U256(BigUint::from_bytes_be(bytes))
+    }
+
+    /// Returns `None` if `value` doesn't fit in 256 bits.
+    pub fn from_big_uint(value: BigUint) -> Option<U256> {
+        if value.bits() > U256::BITS {
+            None
+        } else {
+            Some(U256(value))
+        }
+    }
+
+    /// The big-endian representation of the value, which is also its
+    /// representation in memory.
+    pub fn to_be_bytes(&self) -> [u8; U256::BYTES] {
+        let bytes = self.0.to_bytes_be();
+        let mut full_bytes = [0u8; U256::BYTES];
+        full_bytes[(U256::BYTES - bytes.len())..].copy_from_slice(&bytes);
+        full_bytes
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn checked_add(&self, other: &U256) -> Option<U256> {
+        U256::from_big_uint(&self.0 + &other.0)
+    }
+
+    pub fn checked_sub(&self, other: &U256) -> Option<U256> {
+        if self.0 < other.0 {
+            None
+        } else {
+            Some(U256(&self.0 - &other.0))
+        }
+    }
+
+    pub fn checked_mul(&self, other: &U256) -> Option<U256> {
+        U256::from_big_uint(&self.0 * &other.0)
+    }
+
+    pub fn checked_div(&self, other: &U256) -> Option<U256> {
+        if other.is_zero() {
+            None
+        } else {
+            Some(U256(&self.0 / &other.0))
+        }
+    }
+
+    pub fn checked_rem(&self, other: &U256) -> Option<U256> {
+        if other.is_zero() {
+            None
+        } else {
+            Some(U256(&self.0 % &other.0))
+        }
+    }
+
+    /// Shifts left, discarding the bits shifted out of the 256 bits.
+    pub fn shl(&self, amount: u64) -> U256 {
+        if amount >= U256::BITS {
+            return U256::default();
+        }
+        U256((&self.0 << amount) & &U256::max_value().0)
+    }
+
+    pub fn shr(&self, amount: u64) -> U256 {
+        U256(&self.0 >> amount)
+    }
+
+    pub fn bitand(&self, other: &U256) -> U256 {
+        U256(&self.0 & &other.0)
+    }
+
+    pub fn bitor(&self, other: &U256) -> U256 {
+        U256(&self.0 | &other.0)
+    }
+
+    pub fn bitxor(&self, other: &U256) -> U256 {
+        U256(&self.0 ^ &other.0)
+    }
+
+    /// Returns `None` if the value doesn't fit in a `u64`.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> U256 {
+        U256(BigUint::from(value))
+    }
+}
+
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_round_trip() {
+        let mut bytes = [0u8; U256::BYTES];
+        bytes[0] = 0x80;
+        bytes[31] = 0x01;
+        assert_eq!(U256::from_be_bytes(&bytes).to_be_bytes(), bytes);
+        assert_eq!(U256::from(1).to_be_bytes()[31], 1);
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let max = U256::max_value();
+        assert_eq!(max.checked_add(&U256::from(1)), None);
+        assert_eq!(U256::from(1).checked_sub(&U256::from(2)), None);
+        assert_eq!(max.checked_mul(&U256::from(2)), None);
+        assert_eq!(U256::from(7).checked_div(&U256::default()), None);
+        assert_eq!(
+            max.checked_sub(&U256::from(1))
+                .and_then(|x| x.checked_add(&U256::from(1))),
+            Some(max.clone())
+        );
+        assert_eq!(max.shl(255).shr(255), U256::from(1));
+        assert_eq!(U256::from(5).shl(256), U256::default());
+    }
+}
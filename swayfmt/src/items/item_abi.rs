@@ -22,6 +22,11 @@ impl Format for ItemAbi {
         write!(formatted_code, "{} ", self.abi_token.span().as_str())?;
         self.name.format(formatted_code, formatter)?;
 
+        // `<T>`
+        if let Some(generics) = &self.generics {
+            generics.format(formatted_code, formatter)?;
+        }
+
         // ` : super_trait + super_trait`
         if let Some((colon_token, traits)) = &self.super_traits {
             write!(formatted_code, " {} ", colon_token.ident().as_str())?;
@@ -88,3 +88,13 @@ fmt_test_item!(  fn_nested_if_lets
     };
 }"
 );
+
+fmt_test_item!(  fn_const_generics
+"fn first<T, const N: u64>(values: [T; N]) -> T {
+    values[0]
+}",
+            intermediate_whitespace
+"fn first<T,const   N :u64>(values: [T; N]) -> T {
+    values[0]
+}"
+);
@@ -71,6 +71,8 @@ impl Format for ItemImplItem {
     ) -> Result<(), FormatterError> {
         match self {
             ItemImplItem::Fn(fn_decl) => fn_decl.format(formatted_code, formatter),
+            ItemImplItem::Const(const_decl) => const_decl.format(formatted_code, formatter),
+            ItemImplItem::Type(trait_type) => trait_type.format(formatted_code, formatter),
         }
     }
 }
@@ -126,6 +128,8 @@ impl LeafSpans for ItemImplItem {
         let mut collected_spans = vec![];
         match self {
             ItemImplItem::Fn(fn_decl) => collected_spans.append(&mut fn_decl.leaf_spans()),
+            ItemImplItem::Const(const_decl) => collected_spans.append(&mut const_decl.leaf_spans()),
+            ItemImplItem::Type(trait_type) => collected_spans.append(&mut trait_type.leaf_spans()),
         }
         collected_spans
     }
@@ -90,3 +90,21 @@ fmt_test_item!(    impl_empty_fn_args
 }
 "
 );
+
+fmt_test_item!(  impl_with_associated_items
+"impl Iterator for Counter {
+    type Item = u64;
+    const START: u64 = 0;
+    fn next(ref mut self) -> Option<Self::Item> {
+        Option::None
+    }
+}",
+            intermediate_whitespace
+"impl Iterator for Counter {
+    type Item   =   u64;
+    const  START : u64  =  0;
+    fn next(ref mut self) -> Option<Self::Item> {
+        Option::None
+    }
+}"
+);
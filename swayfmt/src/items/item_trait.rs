@@ -8,7 +8,7 @@ use crate::{
     },
 };
 use std::fmt::Write;
-use sway_ast::{keywords::Token, token::Delimiter, ItemTrait, ItemTraitItem, Traits};
+use sway_ast::{keywords::Token, token::Delimiter, ItemTrait, ItemTraitItem, TraitType, Traits};
 use sway_types::Spanned;
 
 impl Format for ItemTrait {
@@ -78,6 +78,26 @@ impl Format for ItemTrait {
                         fn_signature.format(formatted_code, formatter)?;
                         writeln!(formatted_code, "{}", semicolon_token.ident().as_str())?;
                     }
+                    sway_ast::ItemTraitItem::Const(const_decl) => {
+                        // format `Annotated<ItemConst>`, which writes its own semicolon
+                        write!(
+                            formatted_code,
+                            "{}",
+                            formatter.shape.indent.to_string(&formatter.config)?,
+                        )?;
+                        const_decl.format(formatted_code, formatter)?;
+                        writeln!(formatted_code)?;
+                    }
+                    sway_ast::ItemTraitItem::Type(trait_type) => {
+                        // format `Annotated<TraitType>`, which writes its own semicolon
+                        write!(
+                            formatted_code,
+                            "{}",
+                            formatter.shape.indent.to_string(&formatter.config)?,
+                        )?;
+                        trait_type.format(formatted_code, formatter)?;
+                        writeln!(formatted_code)?;
+                    }
                 }
             }
         }
@@ -118,7 +138,31 @@ impl Format for ItemTraitItem {
     ) -> Result<(), FormatterError> {
         match self {
             ItemTraitItem::Fn(fn_decl) => fn_decl.format(formatted_code, formatter),
+            ItemTraitItem::Const(const_decl) => const_decl.format(formatted_code, formatter),
+            ItemTraitItem::Type(trait_type) => trait_type.format(formatted_code, formatter),
+        }
+    }
+}
+
+impl Format for TraitType {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        // `type name`
+        write!(formatted_code, "{} ", self.type_token.span().as_str())?;
+        self.name.format(formatted_code, formatter)?;
+        // ` = ty`
+        if let Some(eq_token) = &self.eq_token_opt {
+            write!(formatted_code, " {} ", eq_token.ident().as_str())?;
+        }
+        if let Some(ty) = &self.ty_opt {
+            ty.format(formatted_code, formatter)?;
         }
+        write!(formatted_code, "{}", self.semicolon_token.ident().as_str())?;
+
+        Ok(())
     }
 }
 
@@ -196,11 +240,32 @@ impl LeafSpans for ItemTraitItem {
         let mut collected_spans = Vec::new();
         match &self {
             ItemTraitItem::Fn(fn_sig) => collected_spans.append(&mut fn_sig.leaf_spans()),
+            ItemTraitItem::Const(const_decl) => {
+                collected_spans.append(&mut const_decl.leaf_spans())
+            }
+            ItemTraitItem::Type(trait_type) => collected_spans.append(&mut trait_type.leaf_spans()),
         };
         collected_spans
     }
 }
 
+impl LeafSpans for TraitType {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = vec![
+            ByteSpan::from(self.type_token.span()),
+            ByteSpan::from(self.name.span()),
+        ];
+        if let Some(eq_token) = &self.eq_token_opt {
+            collected_spans.push(ByteSpan::from(eq_token.span()));
+        }
+        if let Some(ty) = &self.ty_opt {
+            collected_spans.append(&mut ty.leaf_spans());
+        }
+        collected_spans.push(ByteSpan::from(self.semicolon_token.span()));
+        collected_spans
+    }
+}
+
 impl LeafSpans for Traits {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
         let mut collected_spans = self.prefix.leaf_spans();
@@ -0,0 +1,86 @@
+use crate::{
+    formatter::*,
+    utils::map::byte_span::{ByteSpan, LeafSpans},
+};
+use std::fmt::Write;
+use sway_ast::{ClosureParam, ClosureParams};
+use sway_types::Spanned;
+
+impl Format for ClosureParams {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Empty(double_pipe_token) => {
+                write!(formatted_code, "{}", double_pipe_token.span().as_str())?;
+            }
+            Self::Params {
+                open_pipe_token,
+                params,
+                close_pipe_token,
+            } => {
+                formatter.with_shape(
+                    formatter.shape.with_default_code_line(),
+                    |formatter| -> Result<(), FormatterError> {
+                        write!(formatted_code, "{}", open_pipe_token.span().as_str())?;
+                        params.format(formatted_code, formatter)?;
+                        write!(formatted_code, "{}", close_pipe_token.span().as_str())?;
+
+                        Ok(())
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Format for ClosureParam {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        self.name.format(formatted_code, formatter)?;
+        if let Some((colon_token, ty)) = &self.ty_opt {
+            write!(formatted_code, "{} ", colon_token.span().as_str())?;
+            ty.format(formatted_code, formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LeafSpans for ClosureParams {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        match self {
+            ClosureParams::Empty(double_pipe_token) => {
+                vec![ByteSpan::from(double_pipe_token.span())]
+            }
+            ClosureParams::Params {
+                open_pipe_token,
+                params,
+                close_pipe_token,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(open_pipe_token.span())];
+                collected_spans.append(&mut params.leaf_spans());
+                collected_spans.push(ByteSpan::from(close_pipe_token.span()));
+                collected_spans
+            }
+        }
+    }
+}
+
+impl LeafSpans for ClosureParam {
+    fn leaf_spans(&self) -> Vec<ByteSpan> {
+        let mut collected_spans = vec![ByteSpan::from(self.name.span())];
+        if let Some((colon_token, ty)) = &self.ty_opt {
+            collected_spans.push(ByteSpan::from(colon_token.span()));
+            collected_spans.append(&mut ty.leaf_spans());
+        }
+        collected_spans
+    }
+}
@@ -21,6 +21,7 @@ use sway_types::Spanned;
 pub(crate) mod abi_cast;
 pub(crate) mod asm_block;
 pub(crate) mod assignable;
+pub(crate) mod closure;
 pub(crate) mod code_block;
 pub(crate) mod collections;
 pub(crate) mod conditional;
@@ -190,6 +191,21 @@ impl Format for Expr {
                 block.get().format(formatted_code, formatter)?;
                 CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
             }
+            Self::For {
+                for_token,
+                value_pattern,
+                in_token,
+                iterator,
+                block,
+            } => {
+                write!(formatted_code, "{} ", for_token.span().as_str())?;
+                value_pattern.format(formatted_code, formatter)?;
+                write!(formatted_code, " {} ", in_token.span().as_str())?;
+                iterator.format(formatted_code, formatter)?;
+                CodeBlockContents::open_curly_brace(formatted_code, formatter)?;
+                block.get().format(formatted_code, formatter)?;
+                CodeBlockContents::close_curly_brace(formatted_code, formatter)?;
+            }
             Self::FuncApp { func, args } => {
                 formatter.with_shape(
                     formatter.shape.with_default_code_line(),
@@ -211,6 +227,11 @@ impl Format for Expr {
                     },
                 )?;
             }
+            Self::Closure { params, body } => {
+                params.format(formatted_code, formatter)?;
+                write!(formatted_code, " ")?;
+                body.format(formatted_code, formatter)?;
+            }
             Self::Index { target, arg } => {
                 target.format(formatted_code, formatter)?;
                 Self::open_square_bracket(formatted_code, formatter)?;
@@ -791,12 +812,31 @@ fn expr_leaf_spans(expr: &Expr) -> Vec<ByteSpan> {
             collected_spans.append(&mut block.leaf_spans());
             collected_spans
         }
+        Expr::For {
+            for_token,
+            value_pattern,
+            in_token,
+            iterator,
+            block,
+        } => {
+            let mut collected_spans = vec![ByteSpan::from(for_token.span())];
+            collected_spans.append(&mut value_pattern.leaf_spans());
+            collected_spans.push(ByteSpan::from(in_token.span()));
+            collected_spans.append(&mut iterator.leaf_spans());
+            collected_spans.append(&mut block.leaf_spans());
+            collected_spans
+        }
         Expr::FuncApp { func, args } => {
             let mut collected_spans = Vec::new();
             collected_spans.append(&mut func.leaf_spans());
             collected_spans.append(&mut args.leaf_spans());
             collected_spans
         }
+        Expr::Closure { params, body } => {
+            let mut collected_spans = params.leaf_spans();
+            collected_spans.append(&mut body.leaf_spans());
+            collected_spans
+        }
         Expr::Index { target, arg } => {
             let mut collected_spans = Vec::new();
             collected_spans.append(&mut target.leaf_spans());
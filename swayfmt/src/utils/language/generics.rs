@@ -2,7 +2,9 @@ use crate::{
     formatter::*,
     utils::{close_angle_bracket, open_angle_bracket},
 };
-use sway_ast::{GenericArgs, GenericParams};
+use std::fmt::Write;
+use sway_ast::{GenericArgs, GenericParam, GenericParams};
+use sway_types::Spanned;
 
 impl Format for GenericParams {
     fn format(
@@ -29,6 +31,31 @@ impl Format for GenericParams {
     }
 }
 
+impl Format for GenericParam {
+    fn format(
+        &self,
+        formatted_code: &mut FormattedCode,
+        formatter: &mut Formatter,
+    ) -> Result<(), FormatterError> {
+        match self {
+            Self::Type(name) => name.format(formatted_code, formatter)?,
+            Self::Const {
+                const_token,
+                name,
+                colon_token,
+                ty,
+            } => {
+                write!(formatted_code, "{} ", const_token.span().as_str())?;
+                name.format(formatted_code, formatter)?;
+                write!(formatted_code, "{} ", colon_token.span().as_str())?;
+                ty.format(formatted_code, formatter)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Format for GenericArgs {
     fn format(
         &self,
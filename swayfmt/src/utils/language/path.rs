@@ -105,7 +105,7 @@ impl Format for PathTypeSegment {
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         // name
-        write!(formatted_code, "{}", self.name.span().as_str())?;
+        self.name.format(formatted_code, formatter)?;
         // generics `::<args>`
         if let Some((double_colon_opt, generic_args)) = &self.generics_opt {
             if let Some(double_colon_token) = &double_colon_opt {
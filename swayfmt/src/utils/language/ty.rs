@@ -4,9 +4,10 @@ use crate::{
 };
 use std::fmt::Write;
 use sway_ast::{
-    brackets::SquareBrackets,
+    brackets::{Parens, SquareBrackets},
     expr::Expr,
-    keywords::{StrToken, Token, UnderscoreToken},
+    keywords::{CommaToken, FnToken, RightArrowToken, StrToken, Token, UnderscoreToken},
+    punctuated::Punctuated,
     token::Delimiter,
     ty::{Ty, TyArrayDescriptor, TyTupleDescriptor},
 };
@@ -30,12 +31,34 @@ impl Format for Ty {
             Self::Str { str_token, length } => {
                 format_str(formatted_code, str_token.clone(), length.clone())
             }
+            Self::StringSlice(str_token) => {
+                write!(formatted_code, "{}", str_token.span().as_str())?;
+                Ok(())
+            }
             Self::Tuple(tup_descriptor) => {
                 write!(formatted_code, "{}", Delimiter::Parenthesis.as_open_char())?;
                 tup_descriptor.get().format(formatted_code, formatter)?;
                 write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
                 Ok(())
             }
+            Self::Fn {
+                fn_token,
+                parameters,
+                return_type_opt,
+            } => format_fn(
+                formatted_code,
+                formatter,
+                fn_token,
+                parameters,
+                return_type_opt,
+            ),
+            Self::TraitObject {
+                dyn_token,
+                trait_path,
+            } => {
+                write!(formatted_code, "{} ", dyn_token.span().as_str())?;
+                trait_path.format(formatted_code, formatter)
+            }
         }
     }
 }
@@ -49,6 +72,37 @@ fn format_infer(
     Ok(())
 }
 
+/// Formats a function type, e.g. `fn(u64, bool) -> u64`.
+fn format_fn(
+    formatted_code: &mut FormattedCode,
+    formatter: &mut Formatter,
+    fn_token: &FnToken,
+    parameters: &Parens<Punctuated<Ty, CommaToken>>,
+    return_type_opt: &Option<(RightArrowToken, Box<Ty>)>,
+) -> Result<(), FormatterError> {
+    formatter.with_shape(
+        formatter.shape.with_default_code_line(),
+        |formatter| -> Result<(), FormatterError> {
+            write!(
+                formatted_code,
+                "{}{}",
+                fn_token.span().as_str(),
+                Delimiter::Parenthesis.as_open_char()
+            )?;
+            parameters.get().format(formatted_code, formatter)?;
+            write!(formatted_code, "{}", Delimiter::Parenthesis.as_close_char())?;
+            if let Some((right_arrow_token, ty)) = return_type_opt {
+                write!(formatted_code, " {} ", right_arrow_token.span().as_str())?;
+                ty.format(formatted_code, formatter)?;
+            }
+
+            Ok(())
+        },
+    )?;
+
+    Ok(())
+}
+
 impl Format for TyArrayDescriptor {
     fn format(
         &self,
@@ -117,7 +171,29 @@ impl LeafSpans for Ty {
                 collected_spans.append(&mut length.leaf_spans());
                 collected_spans
             }
+            Ty::StringSlice(str_token) => vec![ByteSpan::from(str_token.span())],
             Ty::Infer { underscore_token } => vec![ByteSpan::from(underscore_token.span())],
+            Ty::Fn {
+                fn_token,
+                parameters,
+                return_type_opt,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(fn_token.span())];
+                collected_spans.append(&mut parameters.leaf_spans());
+                if let Some((right_arrow_token, ty)) = return_type_opt {
+                    collected_spans.push(ByteSpan::from(right_arrow_token.span()));
+                    collected_spans.append(&mut ty.leaf_spans());
+                }
+                collected_spans
+            }
+            Ty::TraitObject {
+                dyn_token,
+                trait_path,
+            } => {
+                let mut collected_spans = vec![ByteSpan::from(dyn_token.span())];
+                collected_spans.append(&mut trait_path.leaf_spans());
+                collected_spans
+            }
         }
     }
 }
@@ -0,0 +1,15 @@
+script;
+
+trait Shape {
+    const SIDES: u64;
+}
+
+struct Square {}
+
+impl Shape for Square {
+    const SIDES: u64 = 4;
+    const CORNERS: u64 = 4;
+    type Unit = u64;
+}
+
+fn main() {}
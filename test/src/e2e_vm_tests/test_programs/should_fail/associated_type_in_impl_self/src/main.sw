@@ -0,0 +1,9 @@
+script;
+
+struct Square {}
+
+impl Square {
+    type Unit = u64;
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+script;
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn main() -> u64 {
+    let x = 5;
+    let a = x(1);
+
+    let add = |a: u64, b: u64| a + b;
+    let b = add(1);
+
+    let c = apply(|a, b| a + b, 2);
+    a + b + c
+}
@@ -0,0 +1,13 @@
+script;
+
+fn forever() -> u64 {
+    while true {
+    }
+    0
+}
+
+const NEVER: u64 = forever();
+
+fn main() -> u64 {
+    NEVER
+}
@@ -0,0 +1,9 @@
+script;
+
+fn first<const N: u32>(a: [u64; N]) -> u64 {
+    a[0]
+}
+
+fn main() -> u64 {
+    first([1, 2])
+}
@@ -0,0 +1,11 @@
+script;
+
+trait Size {}
+
+fn last<const N: u64>(a: [u64; N]) -> u64 where N: Size {
+    a[N - 1]
+}
+
+fn main() -> u64 {
+    last([1, 2])
+}
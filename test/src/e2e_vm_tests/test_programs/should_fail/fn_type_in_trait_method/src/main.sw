@@ -0,0 +1,9 @@
+script;
+
+trait Apply {
+    fn apply(self, f: fn(u64) -> u64) -> u64;
+}
+
+fn main() -> u64 {
+    0
+}
@@ -0,0 +1,6 @@
+script;
+
+fn main() -> u64 {
+    let f: fn(u64) -> u64 = |x| x + 1;
+    f(1)
+}
@@ -0,0 +1,17 @@
+script;
+
+const ADDRESS = 0x1234123412341234123412341234123412341234123412341234123412341234;
+
+struct Meta {
+    decimals: u8,
+}
+
+abi Token<M> {
+    fn metadata() -> M;
+}
+
+fn main() -> u64 {
+    // `Token<u64>` and `Token<Meta>` are different ABIs.
+    let caller: ContractCaller<Token<u64>> = abi(Token<Meta>, ADDRESS);
+    42
+}
@@ -0,0 +1,11 @@
+contract;
+
+abi Token<M> {
+    fn metadata() -> M;
+}
+
+impl<T> Token<T> for Contract {
+    fn metadata() -> T {
+        __revert(0)
+    }
+}
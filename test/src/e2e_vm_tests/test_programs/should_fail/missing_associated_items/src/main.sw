@@ -0,0 +1,12 @@
+script;
+
+trait Shape {
+    type Unit;
+    const SIDES: u64;
+}
+
+struct Square {}
+
+impl Shape for Square {}
+
+fn main() {}
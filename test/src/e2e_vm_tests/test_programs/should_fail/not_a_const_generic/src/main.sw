@@ -0,0 +1,9 @@
+script;
+
+fn first<T>(a: [u64; T]) -> u64 {
+    a[0]
+}
+
+fn main() -> u64 {
+    first([1, 2])
+}
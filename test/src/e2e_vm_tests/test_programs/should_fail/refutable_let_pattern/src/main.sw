@@ -0,0 +1,11 @@
+script;
+
+enum Maybe {
+    Just: u64,
+    Nothing: (),
+}
+
+fn main() -> u64 {
+    let Maybe::Just(value) = Maybe::Just(42);
+    value
+}
@@ -0,0 +1,11 @@
+script;
+
+struct HasStringSlice {
+    name: str,
+}
+
+fn main() -> HasStringSlice {
+    HasStringSlice {
+        name: "Fuel",
+    }
+}
@@ -0,0 +1,27 @@
+script;
+
+trait Compare {
+    fn same(self, other: Self) -> bool;
+}
+
+impl Compare for u64 {
+    fn same(self, other: Self) -> bool {
+        true
+    }
+}
+
+trait Show {
+    fn show(self) -> u64;
+}
+
+impl Show for u64 {
+    fn show(self) -> u64 {
+        self
+    }
+}
+
+fn main() {
+    let x: u64 = 1;
+    let c: dyn Compare = x;
+    let s: dyn Show = true;
+}
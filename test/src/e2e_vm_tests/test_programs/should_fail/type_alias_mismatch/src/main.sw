@@ -0,0 +1,13 @@
+script;
+
+struct Wrapper<T> {
+    value: T,
+}
+
+type Pair = (u64, u64);
+type Wrapped<T> = Wrapper<T>;
+
+fn main() {
+    let pair: Pair = true;
+    let wrapped: Wrapped<bool> = Wrapper { value: 0 };
+}
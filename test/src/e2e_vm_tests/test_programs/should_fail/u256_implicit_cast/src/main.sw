@@ -0,0 +1,6 @@
+script;
+
+fn main() -> u256 {
+    let small = 42u64;
+    small
+}
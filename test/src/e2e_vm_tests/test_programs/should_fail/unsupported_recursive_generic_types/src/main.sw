@@ -0,0 +1,20 @@
+script;
+
+// A generic type may not refer to itself with other type arguments.
+enum Nested<T> {
+    Leaf: T,
+    Node: Box<Nested<(T, T)>>,
+}
+
+// Generic types may not be mutually recursive.
+struct Tree<T> {
+    value: T,
+    children: Forest<T>,
+}
+
+enum Forest<T> {
+    Empty: (),
+    Node: (Box<Tree<T>>, Box<Forest<T>>),
+}
+
+fn main() {}
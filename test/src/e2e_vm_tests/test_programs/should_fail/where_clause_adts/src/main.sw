@@ -33,14 +33,21 @@ struct MyPoint<T> where T: MyAdd {
     y: T,
 }
 
+enum MyOption<T> where T: MyAdd {
+    Some: T,
+    None: (),
+}
+
 fn main() -> u8 {
     let foo = MyPoint {
-        x: 1u32,
-        y: 2u64,
+        x: 1u8,
+        y: 2u8,
     };
     let bar = MyPoint {
-        x: 3u32,
-        y: 4u64,
+        x: MyU32 { value: 3 },
+        y: MyU32 { value: 4 },
     };
+    let baz = MyOption::Some(5u8);
+    let qux = MyOption::Some(MyU64 { value: 6 });
     0u8
 }
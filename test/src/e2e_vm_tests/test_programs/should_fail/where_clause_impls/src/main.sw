@@ -44,12 +44,12 @@ impl<T> MyAdd for MyPoint<T> where T: MyAdd {
 
 fn main() -> u8 {
     let foo = MyPoint {
-        x: 1u32,
-        y: 2u64,
+        x: 1u8,
+        y: 2u8,
     };
     let bar = MyPoint {
-        x: 3u32,
-        y: 4u64,
+        x: 3u8,
+        y: 4u8,
     };
     let baz = foo.my_add(bar);
     baz.y
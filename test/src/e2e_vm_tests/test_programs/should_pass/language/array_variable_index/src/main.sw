@@ -0,0 +1,33 @@
+script;
+
+fn decrement(ref mut i: u64) {
+    i -= 2;
+}
+
+fn main() -> u64 {
+    let a = [1, 2, 3];
+    let mut sum = 0;
+
+    // The index takes a different value on each iteration.
+    let mut i = 3;
+    while i > 0 {
+        i -= 1;
+        sum += a[i];
+    }
+
+    // The index is out of bounds before the loop, but not where it is used.
+    let mut j = 5;
+    while j > 0 {
+        j -= 1;
+        if j < 3 {
+            sum += a[j];
+        }
+    }
+
+    // The index is only in bounds once it has been updated through a reference.
+    let mut k = 4;
+    decrement(k);
+    sum += a[k];
+
+    sum
+}
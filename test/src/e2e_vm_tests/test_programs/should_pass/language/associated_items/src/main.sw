@@ -0,0 +1,63 @@
+script;
+
+trait Shape {
+    type Unit;
+    const SIDES: u64;
+    const SCALE: u64 = 1;
+
+    fn area(self) -> Self::Unit;
+}
+
+struct Square {
+    side: u64,
+}
+
+impl Square {
+    const DEFAULT_SIDE: u64 = 2;
+
+    fn new() -> Self {
+        Square {
+            side: Self::DEFAULT_SIDE,
+        }
+    }
+}
+
+impl Shape for Square {
+    type Unit = u64;
+    const SIDES: u64 = 4;
+
+    fn area(self) -> Self::Unit {
+        self.side * self.side * Self::SCALE
+    }
+}
+
+struct Triangle {
+    base: u32,
+    height: u32,
+}
+
+impl Shape for Triangle {
+    type Unit = u32;
+    const SIDES: u64 = 3;
+    const SCALE: u64 = 2;
+
+    fn area(self) -> Self::Unit {
+        self.base * self.height / 2
+    }
+}
+
+fn main() -> u64 {
+    let square = Square::new();
+    let square_area: u64 = square.area();
+    let triangle = Triangle {
+        base: 2,
+        height: 3,
+    };
+    let triangle_area: u32 = triangle.area();
+    if triangle_area != 3u32 {
+        return 0;
+    }
+
+    // 4 + 4 + 3 + 2 + 1 + 3
+    square_area + Square::SIDES + Triangle::SIDES + Triangle::SCALE + Square::SCALE + 3
+}
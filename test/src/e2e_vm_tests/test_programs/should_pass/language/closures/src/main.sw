@@ -0,0 +1,70 @@
+script;
+
+fn apply(f: fn(u64) -> u64, x: u64) -> u64 {
+    f(x)
+}
+
+fn apply_twice<T>(f: fn(T) -> T, x: T) -> T {
+    f(f(x))
+}
+
+fn fold(values: [u64; 4], init: u64, f: fn(u64, u64) -> u64) -> u64 {
+    let mut acc = init;
+    let mut i = 0;
+    while i < 4 {
+        acc = f(acc, values[i]);
+        i += 1;
+    }
+    acc
+}
+
+struct Counter {
+    step: u64,
+}
+
+impl Counter {
+    fn advance(self, from: u64, by: fn(u64) -> u64) -> u64 {
+        by(from) + self.step
+    }
+}
+
+fn main() -> u64 {
+    let offset = 10;
+    let add_offset = |x: u64| x + offset;
+    if add_offset(1) != 11 {
+        return 0;
+    }
+
+    if apply(|x| x * 2, 4) != 8 {
+        return 0;
+    }
+    if apply(add_offset, 5) != 15 {
+        return 0;
+    }
+    if apply_twice(|x| x + 3, 1u64) != 7 {
+        return 0;
+    }
+
+    let sum = fold([1, 2, 3, 4], 0, |acc, x| acc + x);
+    if sum != 10 {
+        return 0;
+    }
+
+    let clamp = |x: u64| {
+        if x > 100 {
+            return 100;
+        }
+        x
+    };
+    if clamp(250) != 100 || clamp(7) != 7 {
+        return 0;
+    }
+
+    let counter = Counter { step: 2 };
+    if counter.advance(3, |x| x + offset) != 15 {
+        return 0;
+    }
+
+    let get_offset = || offset;
+    get_offset() + 32
+}
@@ -0,0 +1,98 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+enum Shape {
+    Dot: Point,
+    Line: (Point, Point),
+}
+
+fn factorial(n: u64) -> u64 {
+    let mut result = 1;
+    let mut i = 2;
+    while i <= n {
+        result = result * i;
+        i += 1;
+    }
+    result
+}
+
+fn fib(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    fib(n - 1) + fib(n - 2)
+}
+
+// Sums the values until the sum exceeds 20, skipping zeros.
+fn capped_sum(values: [u64; 6]) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < 6 {
+        let value = values[i];
+        i += 1;
+        if value == 0 {
+            continue;
+        }
+        sum += value;
+        if sum > 20 {
+            break;
+        }
+    }
+    sum
+}
+
+fn length(shape: Shape) -> u64 {
+    match shape {
+        Shape::Dot(_) => 0,
+        Shape::Line(line) => (line.1.x - line.0.x) + (line.1.y - line.0.y),
+    }
+}
+
+fn shifted(p: Point, dx: u64) -> Point {
+    let mut p = p;
+    p.x = p.x + dx;
+    p
+}
+
+fn squares() -> [u64; 4] {
+    let mut squares = [0; 4];
+    let mut i = 0;
+    while i < 4 {
+        squares[i] = i * i;
+        i += 1;
+    }
+    squares
+}
+
+const FACTORIAL_5: u64 = factorial(5);
+const FIB_10: u64 = fib(10);
+const CAPPED_SUM: u64 = capped_sum([5, 0, 7, 9, 11, 13]);
+const LENGTH: u64 = length(Shape::Line((Point { x: 1, y: 1 }, Point { x: 4, y: 6 })));
+const ORIGIN: Point = Point { x: 0, y: 0 };
+const SHIFTED: Point = shifted(ORIGIN, 3);
+const SQUARES: [u64; 4] = squares();
+const BIG: bool = {
+    let limit = factorial(4);
+    FIB_10 > limit && LENGTH < limit
+};
+
+configurable {
+    DEFAULT_LIMIT: u64 = factorial(3) + fib(3),
+}
+
+fn main() -> u64 {
+    assert(FACTORIAL_5 == 120);
+    assert(FIB_10 == 55);
+    assert(CAPPED_SUM == 21);
+    assert(LENGTH == 8);
+    assert(SHIFTED.x == 3 && SHIFTED.y == 0);
+    assert(SQUARES[3] == 9);
+    assert(BIG);
+    assert(DEFAULT_LIMIT == 8);
+
+    FIB_10 - SQUARES[2] - SHIFTED.x - LENGTH + DEFAULT_LIMIT - 6
+}
@@ -0,0 +1,40 @@
+script;
+
+struct Buffer<const N: u64> {
+    data: [u64; N],
+}
+
+fn len<const N: u64>(_array: [u64; N]) -> u64 {
+    N
+}
+
+fn sum<const N: u64>(array: [u64; N]) -> u64 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < N {
+        total += array[i];
+        i += 1;
+    }
+    total
+}
+
+fn capacity<const N: u64>(_buffer: Buffer<N>) -> u64 {
+    N
+}
+
+fn main() -> u64 {
+    let small = [1, 2, 3];
+    let large = [4, 5, 6, 7, 8];
+
+    assert(len(small) == 3);
+    assert(len(large) == 5);
+    assert(sum(small) == 6);
+    assert(sum(large) == 30);
+
+    let buffer = Buffer {
+        data: [0, 0, 0, 0],
+    };
+    assert(capacity(buffer) == 4);
+
+    sum(small) + sum(large) + len(small) + len(large) - capacity(buffer) + 2
+}
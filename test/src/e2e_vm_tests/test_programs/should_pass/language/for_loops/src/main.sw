@@ -0,0 +1,82 @@
+script;
+
+use std::bytes::{Bytes, BytesIter};
+
+struct Countdown {
+    remaining: u64,
+}
+
+impl Iterator for Countdown {
+    type Item = u64;
+
+    fn next(ref mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return Option::None;
+        }
+        self.remaining -= 1;
+        Option::Some(self.remaining + 1)
+    }
+}
+
+fn sum_range() -> u64 {
+    let mut sum = 0;
+    for i in Range::new(0, 5) {
+        sum += i;
+    }
+    sum
+}
+
+fn sum_pairs() -> u64 {
+    let mut pairs = Vec::new();
+    pairs.push((1, 2));
+    pairs.push((3, 4));
+    pairs.push((100, 100));
+
+    let mut sum = 0;
+    for (a, b) in pairs.iter() {
+        if a == 100 {
+            break;
+        }
+        sum += a * b;
+    }
+    sum
+}
+
+fn sum_odd_bytes() -> u64 {
+    let mut bytes = Bytes::new();
+    bytes.push(1u8);
+    bytes.push(2u8);
+    bytes.push(3u8);
+
+    let mut sum = 0;
+    for byte in bytes.iter() {
+        if byte == 2u8 {
+            continue;
+        }
+        sum += 1;
+    }
+    sum
+}
+
+fn sum_array() -> u64 {
+    let mut sum = 0;
+    for value in [1, 3].iter() {
+        sum += value;
+    }
+    sum
+}
+
+fn nested() -> u64 {
+    let mut count = 0;
+    for _ in (Countdown { remaining: 2 }) {
+        for j in (Countdown { remaining: 3 }) {
+            count += j;
+        }
+    }
+    count
+}
+
+fn main() -> u64 {
+    // 10 + 14 + 2 + 4 + 12
+    sum_range() + sum_pairs() + sum_odd_bytes() + sum_array() + nested()
+}
@@ -0,0 +1,73 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+enum Wrapper {
+    Value: u64,
+}
+
+fn length((start, end): (Point, Point)) -> u64 {
+    (end.x - start.x) + (end.y - start.y)
+}
+
+fn sum_coordinates(Point { x, y }: Point, offset: u64) -> u64 {
+    x + y + offset
+}
+
+fn unwrap(Wrapper::Value(value): Wrapper) -> u64 {
+    value
+}
+
+impl Line {
+    fn from_points(Point { x: x0, y: y0 }: Point, Point { x: x1, y: y1 }: Point) -> Line {
+        Line {
+            start: Point { x: x0, y: y0 },
+            end: Point { x: x1, y: y1 },
+        }
+    }
+}
+
+fn main() -> u64 {
+    let p = Point { x: 1, y: 2 };
+    let Point { x, y } = p;
+    assert(x == 1 && y == 2);
+
+    let Point { x: a, .. } = p;
+    assert(a == 1);
+
+    let (first, (second, third)) = (1, (2, 3));
+    assert(first + second + third == 6);
+
+    let Line {
+        start: Point { x: x0, y: y0 },
+        end,
+    } = Line::from_points(Point { x: 1, y: 1 }, Point { x: 4, y: 6 });
+    assert(x0 == 1 && y0 == 1 && end.x == 4 && end.y == 6);
+
+    // Patterns containing constructors are checked to be irrefutable.
+    let Wrapper::Value(mut value) = Wrapper::Value(5);
+    value += 1;
+    assert(value == 6);
+
+    let (Wrapper::Value(v), Point { x: px, .. }) = (Wrapper::Value(7), p);
+    assert(v == 7 && px == 1);
+
+    let line_length = length((Point { x: 1, y: 1 }, Point { x: 4, y: 6 }));
+    assert(line_length == 8);
+
+    let sum = sum_coordinates(Point { x: 10, y: 20 }, 3);
+    assert(sum == 33);
+
+    let unwrapped = unwrap(Wrapper::Value(1));
+    assert(unwrapped == 1);
+
+    line_length + sum + unwrapped
+}
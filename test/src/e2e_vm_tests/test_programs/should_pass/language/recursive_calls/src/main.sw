@@ -0,0 +1,82 @@
+script;
+
+struct Node {
+    value: u64,
+    depth: u64,
+}
+
+// a -> a
+fn factorial(n: u64) -> u64 {
+    if n == 0 {
+        1
+    } else {
+        n * factorial(n - 1)
+    }
+}
+
+fn fib(n: u64) -> u64 {
+    if n < 2 {
+        n
+    } else {
+        fib(n - 1) + fib(n - 2)
+    }
+}
+
+// is_even -> is_odd -> is_even
+fn is_even(n: u64) -> bool {
+    if n == 0 { true } else { is_odd(n - 1) }
+}
+
+fn is_odd(n: u64) -> bool {
+    if n == 0 { false } else { is_even(n - 1) }
+}
+
+// d -> e -> f -> d
+fn d(n: u64) -> u64 {
+    if n == 0 { 0 } else { 1 + e(n - 1) }
+}
+
+fn e(n: u64) -> u64 {
+    if n == 0 { 0 } else { 2 + f(n - 1) }
+}
+
+fn f(n: u64) -> u64 {
+    if n == 0 { 0 } else { 3 + d(n - 1) }
+}
+
+// Each call has its own copy of `node`, which must survive the nested calls.
+fn sum_depths(node: Node) -> u64 {
+    if node.depth == 0 {
+        return node.value;
+    }
+    let child = Node {
+        value: node.value + 1,
+        depth: node.depth - 1,
+    };
+    let below = sum_depths(child);
+    node.value + below
+}
+
+fn count<T>(value: T, n: u64) -> u64 {
+    if n == 0 { 0 } else { 1 + count(value, n - 1) }
+}
+
+fn main() -> u64 {
+    assert(factorial(0) == 1);
+    assert(factorial(5) == 120);
+    assert(fib(10) == 55);
+
+    assert(is_even(10));
+    assert(is_odd(7));
+    assert(!is_even(7));
+
+    assert(d(6) == 12);
+    assert(e(4) == 8);
+
+    assert(sum_depths(Node { value: 1, depth: 3 }) == 10);
+
+    assert(count(true, 3) == 3);
+    assert(count((1, 2), 5) == 5);
+
+    42
+}
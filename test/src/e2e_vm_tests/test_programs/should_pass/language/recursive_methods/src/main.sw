@@ -0,0 +1,64 @@
+script;
+
+struct Counter {
+    step: u64,
+}
+
+impl Counter {
+    fn count_down(self, n: u64) -> u64 {
+        if n == 0 {
+            0
+        } else {
+            let next = Counter { step: self.step };
+            self.step + next.count_down(n - 1)
+        }
+    }
+
+    // is_even -> is_odd -> is_even
+    fn is_even(self, n: u64) -> bool {
+        if n == 0 { true } else { self.is_odd(n - 1) }
+    }
+
+    fn is_odd(self, n: u64) -> bool {
+        if n == 0 { false } else { self.is_even(n - 1) }
+    }
+}
+
+struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn depth(self, n: u64) -> u64 {
+        if n == 0 { 0 } else { 1 + self.depth(n - 1) }
+    }
+}
+
+trait Power {
+    fn power(self, exponent: u64) -> u64;
+}
+
+impl Power for u64 {
+    fn power(self, exponent: u64) -> u64 {
+        if exponent == 0 {
+            1
+        } else {
+            self * self.power(exponent - 1)
+        }
+    }
+}
+
+fn main() -> u64 {
+    let counter = Counter { step: 3 };
+    assert(counter.count_down(4) == 12);
+    assert(counter.is_even(10));
+    assert(counter.is_odd(7));
+    assert(!counter.is_even(7));
+
+    assert(Wrapper { value: true }.depth(3) == 3);
+    assert(Wrapper { value: (1, 2) }.depth(5) == 5);
+
+    assert(2.power(5) == 32);
+
+    42
+}
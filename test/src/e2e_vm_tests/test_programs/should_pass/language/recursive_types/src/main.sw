@@ -0,0 +1,120 @@
+script;
+
+enum List {
+    Nil: (),
+    Cons: (u64, Box<List>),
+}
+
+fn sum(list: List) -> u64 {
+    match list {
+        List::Nil => 0,
+        List::Cons(cons) => cons.0 + sum(cons.1.get()),
+    }
+}
+
+enum Expr {
+    Num: u64,
+    Add: (Box<Expr>, Box<Expr>),
+    Mul: (Box<Expr>, Box<Expr>),
+}
+
+fn eval(expr: Expr) -> u64 {
+    match expr {
+        Expr::Num(n) => n,
+        Expr::Add(operands) => eval(operands.0.get()) + eval(operands.1.get()),
+        Expr::Mul(operands) => eval(operands.0.get()) * eval(operands.1.get()),
+    }
+}
+
+// `Tree` and `Forest` are mutually recursive.
+struct Tree {
+    value: u64,
+    children: Forest,
+}
+
+enum Forest {
+    Empty: (),
+    Node: (Box<Tree>, Box<Forest>),
+}
+
+fn tree_sum(tree: Tree) -> u64 {
+    tree.value + forest_sum(tree.children)
+}
+
+fn forest_sum(forest: Forest) -> u64 {
+    match forest {
+        Forest::Empty => 0,
+        Forest::Node(node) => tree_sum(node.0.get()) + forest_sum(node.1.get()),
+    }
+}
+
+// Generic types may refer to themselves, with their own type parameters.
+enum GenericList<T> {
+    Nil: (),
+    Cons: (T, Box<GenericList<T>>),
+}
+
+fn len<T>(list: GenericList<T>) -> u64 {
+    match list {
+        GenericList::Nil => 0,
+        GenericList::Cons(cons) => 1 + len(cons.1.get()),
+    }
+}
+
+struct Node<T> {
+    value: T,
+    next: Option<Box<Node<T>>>,
+}
+
+fn last<T>(node: Node<T>) -> T {
+    match node.next {
+        Option::Some(next) => last(next.get()),
+        Option::None => node.value,
+    }
+}
+
+fn main() -> u64 {
+    // 1 + 2 + 3
+    let list = List::Cons((1, Box::new(List::Cons((2, Box::new(List::Cons((3, Box::new(List::Nil)))))))));
+    let list_sum = sum(list);
+    assert(list_sum == 6);
+
+    // (2 + 3) * 4
+    let expr = Expr::Mul((
+        Box::new(Expr::Add((Box::new(Expr::Num(2)), Box::new(Expr::Num(3))))),
+        Box::new(Expr::Num(4)),
+    ));
+    let expr_value = eval(expr);
+    assert(expr_value == 20);
+
+    // A tree with value 10, and a single child with value 6.
+    let leaf = Tree {
+        value: 6,
+        children: Forest::Empty,
+    };
+    let tree = Tree {
+        value: 10,
+        children: Forest::Node((Box::new(leaf), Box::new(Forest::Empty))),
+    };
+    let tree_value = tree_sum(tree);
+    assert(tree_value == 16);
+
+    let generic_list = GenericList::Cons((true, Box::new(GenericList::Cons((false, Box::new(GenericList::Nil))))));
+    assert(len(generic_list) == 2);
+
+    let node = Node {
+        value: 1u8,
+        next: Option::Some(Box::new(Node {
+            value: 2u8,
+            next: Option::None,
+        })),
+    };
+    assert(last(node) == 2u8);
+
+    // A box may be updated in place.
+    let counter = Box::new(0);
+    counter.set(counter.get() + 10);
+    assert(counter.get() == 10);
+
+    list_sum + expr_value + tree_value
+}
@@ -0,0 +1,52 @@
+script;
+
+use std::{assert::assert, string::String};
+
+struct Token {
+    name: str,
+    decimals: u8,
+}
+
+fn greeting() -> str {
+    "Hello"
+}
+
+fn name_len(name: str) -> u64 {
+    name.len()
+}
+
+fn main() -> u64 {
+    // String literals are coerced into string slices where one is expected.
+    let hello: str = "Hello";
+    assert(hello.len() == 5);
+    assert(!hello.is_empty());
+    assert(hello == greeting());
+    assert(hello != "World");
+    assert(name_len("Fuel") == 4);
+
+    let token = Token {
+        name: "Ether",
+        decimals: 9,
+    };
+    assert(token.name == "Ether");
+
+    // `String` owns a copy of its bytes.
+    let mut uri = String::from_ascii_str("https://");
+    uri.push_str("fuel.network");
+    assert(uri.len() == 20);
+    assert(uri.as_str() == "https://fuel.network");
+
+    let full = uri + String::from_ascii_str("/docs");
+    assert(full.as_str() == "https://fuel.network/docs");
+    assert(uri.len() == 20);
+
+    let mut empty = String::new();
+    assert(empty.is_empty());
+    empty.push_str("");
+    assert(empty == String::new());
+
+    uri.clear();
+    assert(uri.is_empty());
+
+    42
+}
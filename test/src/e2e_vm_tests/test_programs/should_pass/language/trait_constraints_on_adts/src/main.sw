@@ -0,0 +1,71 @@
+script;
+
+trait MyAdd {
+    fn my_add(self, other: Self) -> Self;
+}
+
+impl MyAdd for u64 {
+    fn my_add(self, other: Self) -> Self {
+        self + other
+    }
+}
+
+struct Wrapper<T> where T: MyAdd {
+    value: T,
+}
+
+impl<T> Wrapper<T> where T: MyAdd {
+    fn double(self) -> T {
+        self.value.my_add(self.value)
+    }
+}
+
+impl<T> MyAdd for Wrapper<T> where T: MyAdd {
+    fn my_add(self, other: Self) -> Self {
+        Wrapper {
+            value: self.value.my_add(other.value),
+        }
+    }
+}
+
+enum Either<T> where T: MyAdd {
+    Left: T,
+    Right: T,
+}
+
+impl<T> Either<T> where T: MyAdd {
+    fn add(self, other: T) -> T {
+        match self {
+            Either::Left(value) => value.my_add(other),
+            Either::Right(value) => other.my_add(value),
+        }
+    }
+}
+
+trait Accumulate<T> where T: MyAdd {
+    fn accumulate(self, value: T) -> T;
+}
+
+impl Accumulate<u64> for Wrapper<u64> {
+    fn accumulate(self, value: u64) -> u64 {
+        self.value.my_add(value)
+    }
+}
+
+fn main() -> u64 {
+    let a = Wrapper { value: 10 };
+    assert(a.double() == 20);
+
+    let b = a.my_add(Wrapper { value: 11 });
+    assert(b.value == 21);
+
+    let nested = Wrapper { value: Wrapper { value: 3 } };
+    assert(nested.double().value == 6);
+
+    assert(Either::Left(5).add(6) == 11);
+    assert(Either::Right(7).add(8) == 15);
+
+    assert(b.accumulate(1) == 22);
+
+    b.value + a.double() + 1
+}
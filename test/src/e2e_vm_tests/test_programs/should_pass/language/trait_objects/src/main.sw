@@ -0,0 +1,60 @@
+script;
+
+trait FeeStrategy {
+    fn fee(self, amount: u64) -> u64;
+}
+
+struct Flat {
+    fee: u64,
+}
+
+struct Percent {
+    percent: u64,
+    minimum: u64,
+}
+
+impl FeeStrategy for Flat {
+    fn fee(self, _amount: u64) -> u64 {
+        self.fee
+    }
+}
+
+impl FeeStrategy for Percent {
+    fn fee(self, amount: u64) -> u64 {
+        let fee = amount * self.percent / 100;
+        if fee < self.minimum { self.minimum } else { fee }
+    }
+}
+
+impl FeeStrategy for u64 {
+    fn fee(self, _amount: u64) -> u64 {
+        self
+    }
+}
+
+fn fee_of(strategy: dyn FeeStrategy, amount: u64) -> u64 {
+    strategy.fee(amount)
+}
+
+fn main() -> u64 {
+    let flat: dyn FeeStrategy = Flat { fee: 5 };
+    let fixed: u64 = 7;
+    let strategies: [dyn FeeStrategy; 3] = [flat, Percent {
+        percent: 10,
+        minimum: 3,
+    }, fixed];
+
+    // 5 + 10 + 7
+    let mut total = 0;
+    let mut i = 0;
+    while i < 3 {
+        total += strategies[i].fee(100);
+        i += 1;
+    }
+
+    // 22 + 20
+    total + fee_of(Percent {
+        percent: 1,
+        minimum: 20,
+    }, 100)
+}
@@ -0,0 +1,42 @@
+script;
+
+struct Point {
+    x: u64,
+    y: u64,
+}
+
+type Coord = Point;
+type Number = u64;
+type Pair<T> = (T, T);
+type Points = [Coord; 2];
+
+/// Aliases can refer to other aliases.
+pub type NumberPair = Pair<Number>;
+
+fn sum(pair: Pair<u64>) -> Number {
+    pair.0 + pair.1
+}
+
+fn swap<T>(pair: Pair<T>) -> Pair<T> {
+    (pair.1, pair.0)
+}
+
+fn main() -> u64 {
+    let c: Coord = Point { x: 1, y: 2 };
+    let p: Point = c;
+
+    let n: Number = 3;
+    let pair: NumberPair = (p.x + p.y, n);
+    let swapped = swap(pair);
+    if swapped.0 != 3 {
+        return 0;
+    }
+
+    let points: Points = [Coord { x: 10, y: 20 }, Point { x: 3, y: 0 }];
+    let bools: Pair<bool> = (true, false);
+    if !bools.0 || bools.1 {
+        return 0;
+    }
+
+    sum(swapped) + points[0].x + points[0].y + points[1].x + points[1].y
+}
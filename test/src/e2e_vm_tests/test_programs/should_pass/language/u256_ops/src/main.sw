@@ -0,0 +1,55 @@
+script;
+
+use std::assert::assert;
+
+const TWO_POW_64: u256 = 0x0000000000000000000000000000000000000000000000010000000000000000u256;
+const FOLDED: u256 = TWO_POW_64 * 3u256 + 7u256;
+
+configurable {
+    MAX: u256 = 0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffu256,
+}
+
+fn double(x: u256) -> u256 {
+    x * 2
+}
+
+fn main() -> u64 {
+    let u64_max = 18446744073709551615u256;
+
+    // Carries and borrows across words.
+    assert(u64_max + 1u256 == TWO_POW_64);
+    assert(TWO_POW_64 - 1u256 == u64_max);
+    assert(u64_max * u64_max == 0x00000000000000000000000000000000fffffffffffffffe0000000000000001u256);
+    assert(u256::max() - u64_max + u64_max == u256::max());
+    assert(MAX == u256::max());
+
+    // Numeric literals are inferred to be `u256`.
+    let x: u256 = 5;
+    assert(x * 2 == 10u256);
+    assert(double(21) == 42u256);
+
+    // Comparisons look at the most significant words first.
+    assert(TWO_POW_64 > u64_max);
+    assert(u64_max < TWO_POW_64);
+    assert(!(TWO_POW_64 < TWO_POW_64));
+    assert(TWO_POW_64 >= TWO_POW_64);
+    assert(0u256 <= u256::min());
+
+    // Bitwise operations and shifts.
+    assert((TWO_POW_64 | 1u256) & u64_max == 1u256);
+    assert((TWO_POW_64 ^ TWO_POW_64) == 0u256);
+    assert(!0u256 == u256::max());
+    assert(1u256 << 64 == TWO_POW_64);
+    assert(TWO_POW_64 >> 63 == 2u256);
+
+    // Division and remainder.
+    assert(FOLDED / TWO_POW_64 == 3u256);
+    assert(FOLDED % TWO_POW_64 == 7u256);
+    assert(u256::max() / u256::max() == 1u256);
+    assert(u256::max() % (TWO_POW_64 << 128) == (TWO_POW_64 << 128) - 1u256);
+
+    // Conversions.
+    assert(42u64.as_u256() == 42u256);
+    assert(7u8.as_u256() + 35u32.as_u256() == 42u256);
+    (FOLDED - TWO_POW_64 * 3u256 + 35u256).as_u64()
+}
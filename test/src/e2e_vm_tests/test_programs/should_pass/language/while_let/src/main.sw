@@ -0,0 +1,51 @@
+script;
+
+fn below_ten(n: u64) -> Option<u64> {
+    if n < 10 {
+        Option::Some(n)
+    } else {
+        Option::None
+    }
+}
+
+fn main() -> u64 {
+    let mut stack = Vec::new();
+    stack.push(1);
+    stack.push(2);
+    stack.push(3);
+    let mut sum = 0;
+    while let Option::Some(value) = stack.pop() {
+        sum += value;
+    }
+    assert(sum == 6);
+    assert(stack.len() == 0);
+
+    // `continue` and `break` work inside of a `while let` loop.
+    let mut i = 0;
+    let mut even_sum = 0;
+    while let Option::Some(n) = below_ten(i) {
+        i += 1;
+        if n == 1 || n == 3 || n == 5 || n == 7 {
+            continue;
+        }
+        if n == 8 {
+            break;
+        }
+        even_sum += n;
+    }
+    assert(even_sum == 12);
+    assert(i == 9);
+
+    // The loop ends at the first value that does not match the pattern.
+    let mut count = 0;
+    while let Option::Some(_) = below_ten(count) {
+        count += 1;
+    }
+    assert(count == 10);
+
+    let value = Option::Some(14);
+    let unwrapped = if let Option::Some(v) = value { v } else { 0 };
+    assert(unwrapped == 14);
+
+    sum + even_sum + count + unwrapped
+}
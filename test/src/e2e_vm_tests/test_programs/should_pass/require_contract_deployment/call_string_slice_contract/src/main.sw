@@ -0,0 +1,24 @@
+script;
+
+use string_slice_abi::StringSlices;
+
+fn len_of(s: str) -> u64 {
+    asm(s: s, len) {
+        lw len s i1;
+        len: u64
+    }
+}
+
+fn main() -> u64 {
+    let the_abi = abi(StringSlices, 0x34338add7310f36ea09d7da9d4f7327077481f742d951d53cbe14e3b800f3c31);
+
+    let greeting = the_abi.greeting();
+    assert(len_of(greeting) == 4);
+    log(greeting);
+
+    let echoed = the_abi.echo("Hello, Fuel");
+    assert(len_of(echoed) == 11);
+    log(echoed);
+
+    the_abi.len_of("Sway")
+}
@@ -0,0 +1,16 @@
+script;
+
+use generic_token_abi::{Meta, Token};
+
+const ADDRESS = 0x47cc906eba6475f200e309021f0d7d8be0a40a4802867af809af5448cc5fd627;
+
+fn main() -> u64 {
+    let caller: ContractCaller<Token<Meta>> = abi(Token<Meta>, ADDRESS);
+    let metadata = caller.metadata();
+    assert(metadata.decimals == 9u8);
+    assert(caller.set_metadata(metadata));
+
+    // The type arguments of the ABI may be given as further generic arguments.
+    let other: ContractCaller<Token, Meta> = abi(Token<Meta>, ADDRESS);
+    other.total_supply()
+}
@@ -0,0 +1,12 @@
+library;
+
+pub struct Meta {
+    decimals: u8,
+    symbol: str[4],
+}
+
+abi Token<M> {
+    fn metadata() -> M;
+    fn total_supply() -> u64;
+    fn set_metadata(metadata: M) -> bool;
+}
@@ -0,0 +1,7 @@
+library;
+
+abi StringSlices {
+    fn greeting() -> str;
+    fn echo(s: str) -> str;
+    fn len_of(s: str) -> u64;
+}
@@ -0,0 +1,31 @@
+contract;
+
+use generic_token_abi::{Meta, Token};
+
+impl Token<Meta> for Contract {
+    fn metadata() -> Meta {
+        Meta {
+            decimals: 9u8,
+            symbol: "FUEL",
+        }
+    }
+
+    fn total_supply() -> u64 {
+        1000
+    }
+
+    fn set_metadata(metadata: Meta) -> bool {
+        metadata.decimals > 0u8
+    }
+}
+
+abi Registry {
+    fn token_decimals(token: b256) -> u8;
+}
+
+impl Registry for Contract {
+    fn token_decimals(token: b256) -> u8 {
+        let caller: ContractCaller<Token<Meta>> = abi(Token<Meta>, token);
+        caller.metadata().decimals
+    }
+}
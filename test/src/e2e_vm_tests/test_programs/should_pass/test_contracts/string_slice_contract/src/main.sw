@@ -0,0 +1,20 @@
+contract;
+
+use string_slice_abi::StringSlices;
+
+impl StringSlices for Contract {
+    fn greeting() -> str {
+        "Fuel"
+    }
+
+    fn echo(s: str) -> str {
+        s
+    }
+
+    fn len_of(s: str) -> u64 {
+        asm(s: s, len) {
+            lw len s i1;
+            len: u64
+        }
+    }
+}
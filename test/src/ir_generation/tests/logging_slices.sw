@@ -0,0 +1,32 @@
+script;
+
+fn main() {
+    let s: str = "Fuel";
+    __log(s);
+
+    let r = asm(r: s) { r: raw_slice };
+    __log(r);
+}
+
+// ::check-ir::
+
+// check: fn main() -> ()
+// check: log str $VAL, $VAL
+// check: log slice $VAL, $VAL
+
+// ::check-asm::
+
+// regex: REG=\$r\d+
+// regex: ID=[_[:alpha:]][_0-9[:alpha:]]*
+
+// A string slice is logged as the bytes it refers to.
+// check: lw   $(ptr=$REG) $(s=$REG) i0
+// nextln: lw   $(len=$REG) $s i1
+// nextln: logd $$zero $$zero $ptr $len
+
+// A `raw_slice` is logged as its pointer and length.
+// check: lw   $(size=$REG) $(size_data=$ID)
+// nextln: logd $$zero $$one $REG $size
+
+// check: .data:
+// check: $size_data .word 16